@@ -29,12 +29,10 @@ impl Supplier for MockSupplier {
         if self.should_fail {
             Err(SupplierError::Internal(format!("{} failed", self.name)))
         } else {
-            Ok(SupplierResponse {
-                data: json!({
-                    "supplier": self.name,
-                    "params": request.params
-                }),
-            })
+            Ok(SupplierResponse::new(json!({
+                "supplier": self.name,
+                "params": request.params
+            })))
         }
     }
 }
@@ -58,10 +56,7 @@ fn main() -> Result<(), SupplierError> {
         }
     }
 
-    let request = SupplierRequest {
-        operation: SupplierOperation::Search,
-        params: json!({ "keyword": "laptop" }),
-    };
+    let request = SupplierRequest::new(SupplierOperation::Search, json!({ "keyword": "laptop" }));
 
     let result: SupplierGroupResult = group.query(request);
 
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+use serde_json::json;
+use supplier_kit::bench::StrategyBench;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::QueryStrategy;
+use supplier_kit::testing::fixtures::FixtureLoader;
+
+fn main() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/marketplace");
+    let suppliers: Vec<Arc<dyn Supplier>> = FixtureLoader::new(dir)
+        .with_latency_scale(1.0)
+        .load()
+        .expect("fixtures load")
+        .into_iter()
+        .map(|supplier| Arc::new(supplier) as Arc<dyn Supplier>)
+        .collect();
+
+    let workload = vec![SupplierRequest::new(SupplierOperation::Search, json!({ "q": "laptop" })); 20];
+    let bench = StrategyBench::new(suppliers, workload);
+
+    for report in bench.compare(&[
+        QueryStrategy::Sequential,
+        QueryStrategy::Parallel,
+        QueryStrategy::Race,
+        QueryStrategy::Failover,
+    ]) {
+        println!("{report}");
+    }
+}
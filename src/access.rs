@@ -0,0 +1,123 @@
+use crate::errors::SupplierError;
+use crate::models::{SupplierOperation, SupplierRequest};
+
+/// The caller pattern matching every caller, including requests without one.
+pub const ANY_CALLER: &str = "*";
+
+/// The operations an access rule grants.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OperationGrant {
+    /// Every operation.
+    All,
+    /// Operations that do not change state (see [`SupplierOperation::is_write`]).
+    Reads,
+    /// Only the listed operations.
+    Only(Vec<SupplierOperation>),
+}
+
+impl OperationGrant {
+    /// Returns whether the grant covers `operation`.
+    pub fn covers(&self, operation: &SupplierOperation) -> bool {
+        match self {
+            OperationGrant::All => true,
+            OperationGrant::Reads => !operation.is_write(),
+            OperationGrant::Only(operations) => operations.contains(operation),
+        }
+    }
+}
+
+/// One `caller → operations` entry of an `AccessPolicy`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessRule {
+    /// The caller the rule applies to, or [`ANY_CALLER`].
+    pub caller: String,
+    /// The operations granted to the caller.
+    pub operations: OperationGrant,
+}
+
+/// Declares which callers may invoke which operations on a supplier or group.
+///
+/// Callers are identified by `RequestContext::caller`. A policy denies everything that no rule
+/// grants; rules only add permissions, so their order does not matter. The caller must be set
+/// by the gateway from an authenticated identity, never copied from untrusted request bodies.
+///
+/// Attach a policy to a supplier with `AccessControlledSupplier`, or to a whole group with
+/// `BasicSupplierGroup::with_access_policy`.
+///
+/// # Example
+/// ```
+/// use supplier_kit::access::AccessPolicy;
+/// use supplier_kit::context::RequestContext;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+///
+/// let policy = AccessPolicy::new().allow_all("team-b").allow_reads("team-a");
+/// let request = |caller: &str, operation| {
+///     SupplierRequest::new(operation, serde_json::json!({}))
+///         .with_context(RequestContext::new().with_caller(caller))
+/// };
+///
+/// assert!(policy.check(&request("team-a", SupplierOperation::Search)).is_ok());
+/// assert!(policy.check(&request("team-a", SupplierOperation::Create)).is_err());
+/// assert!(policy.check(&request("team-b", SupplierOperation::Create)).is_ok());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AccessPolicy {
+    rules: Vec<AccessRule>,
+}
+
+impl AccessPolicy {
+    /// Creates a policy denying every call.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Grants `caller` the given operations.
+    pub fn allow(self, caller: &str, operations: &[SupplierOperation]) -> Self {
+        self.grant(caller, OperationGrant::Only(operations.to_vec()))
+    }
+
+    /// Grants `caller` every operation.
+    pub fn allow_all(self, caller: &str) -> Self {
+        self.grant(caller, OperationGrant::All)
+    }
+
+    /// Grants `caller` every operation that does not change state.
+    pub fn allow_reads(self, caller: &str) -> Self {
+        self.grant(caller, OperationGrant::Reads)
+    }
+
+    /// Adds a rule granting `operations` to `caller`, which may be [`ANY_CALLER`].
+    pub fn grant(mut self, caller: &str, operations: OperationGrant) -> Self {
+        self.rules.push(AccessRule {
+            caller: caller.to_string(),
+            operations,
+        });
+        self
+    }
+
+    /// Returns the rules of the policy.
+    pub fn rules(&self) -> &[AccessRule] {
+        &self.rules
+    }
+
+    /// Returns whether `caller` may invoke `operation`.
+    ///
+    /// Requests without a caller are only granted what [`ANY_CALLER`] rules allow.
+    pub fn permits(&self, caller: Option<&str>, operation: &SupplierOperation) -> bool {
+        self.rules.iter().any(|rule| {
+            (rule.caller == ANY_CALLER || Some(rule.caller.as_str()) == caller) && rule.operations.covers(operation)
+        })
+    }
+
+    /// Checks the caller of `request` against the policy.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::Unauthorized` if the policy does not grant the operation.
+    pub fn check(&self, request: &SupplierRequest) -> Result<(), SupplierError> {
+        if self.permits(request.context.caller.as_deref(), &request.operation) {
+            Ok(())
+        } else {
+            Err(SupplierError::Unauthorized)
+        }
+    }
+}
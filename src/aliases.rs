@@ -0,0 +1,122 @@
+use std::collections::BTreeMap;
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::models::SupplierOperation;
+
+/// Maps alternative and legacy operation names onto canonical operations.
+///
+/// Operation vocabularies drift: a caller written against an older API sends
+/// `Other("find")` where the kit now expects `Search`. An alias table resolves such names
+/// before a request is dispatched, so renaming an operation does not break its callers.
+/// Names are compared after normalization (see [`SupplierOperation::normalize`]), so
+/// `"Find"`, `"find"` and `"FIND"` are the same alias.
+///
+/// Suppliers can also declare the legacy names of their operations in their descriptors
+/// (see `OperationDescriptor::with_alias`); [`OperationAliases::from_descriptors`] collects
+/// them into a table.
+///
+/// # Example
+/// ```
+/// use supplier_kit::aliases::OperationAliases;
+/// use supplier_kit::models::SupplierOperation;
+///
+/// let aliases = OperationAliases::new()
+///     .with_alias("find", SupplierOperation::Search)
+///     .with_alias("place_order", SupplierOperation::Create);
+///
+/// assert_eq!(aliases.resolve(SupplierOperation::Other("Find".into())), SupplierOperation::Search);
+/// assert_eq!(aliases.resolve(SupplierOperation::GetDetail), SupplierOperation::GetDetail);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OperationAliases {
+    aliases: BTreeMap<String, SupplierOperation>,
+}
+
+impl OperationAliases {
+    /// Creates an empty alias table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes `alias` resolve to `canonical`, replacing any previous mapping of `alias`.
+    pub fn with_alias(mut self, alias: &str, canonical: SupplierOperation) -> Self {
+        self.aliases.insert(Self::key(alias), canonical.normalize());
+        self
+    }
+
+    /// Collects the aliases declared by the operations of `descriptors`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if an alias is declared for two different
+    /// operations, or names an operation of its own, such as `search`.
+    pub fn from_descriptors<'a, I>(descriptors: I) -> Result<Self, SupplierError>
+    where
+        I: IntoIterator<Item = &'a SupplierDescriptor>,
+    {
+        let mut aliases = Self::new();
+        for descriptor in descriptors {
+            for operation in &descriptor.operations {
+                let canonical = operation.operation.clone().normalize();
+                for alias in &operation.aliases {
+                    let key = Self::key(alias);
+                    if !matches!(SupplierOperation::from(key.as_str()), SupplierOperation::Other(_)) {
+                        return Err(SupplierError::InvalidInput(format!(
+                            "'{}' of supplier '{}' is an operation, not an alias",
+                            alias, descriptor.name
+                        )));
+                    }
+                    match aliases.aliases.get(&key) {
+                        Some(existing) if *existing != canonical => {
+                            return Err(SupplierError::InvalidInput(format!(
+                                "alias '{}' of supplier '{}' maps to '{}', but is already an alias of '{}'",
+                                key,
+                                descriptor.name,
+                                canonical.as_str(),
+                                existing.as_str()
+                            )));
+                        }
+                        _ => {
+                            aliases.aliases.insert(key, canonical.clone());
+                        }
+                    }
+                }
+            }
+        }
+        Ok(aliases)
+    }
+
+    /// Adds the aliases of `other`, which take precedence over aliases of the same name.
+    pub fn merge(mut self, other: &OperationAliases) -> Self {
+        self.aliases.extend(other.aliases.iter().map(|(alias, canonical)| (alias.clone(), canonical.clone())));
+        self
+    }
+
+    /// Returns the canonical operation for `operation`: the aliased operation if `operation`
+    /// is an alias, otherwise `operation` itself, normalized.
+    pub fn resolve(&self, operation: SupplierOperation) -> SupplierOperation {
+        let operation = operation.normalize();
+        match &operation {
+            SupplierOperation::Other(name) => self.aliases.get(name).cloned().unwrap_or(operation),
+            _ => operation,
+        }
+    }
+
+    /// Returns the operation `alias` resolves to, if it is an alias.
+    pub fn canonical(&self, alias: &str) -> Option<&SupplierOperation> {
+        self.aliases.get(&Self::key(alias))
+    }
+
+    /// Returns every alias with its canonical operation, sorted by alias.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &SupplierOperation)> {
+        self.aliases.iter().map(|(alias, canonical)| (alias.as_str(), canonical))
+    }
+
+    /// Returns whether the table has no aliases.
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty()
+    }
+
+    fn key(alias: &str) -> String {
+        SupplierOperation::Other(alias.to_string()).normalize().as_str().to_string()
+    }
+}
@@ -0,0 +1,415 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::path::SupplierPath;
+use crate::redaction::Redactor;
+use crate::supplier::Supplier;
+
+/// The outcome of an audited supplier call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum AuditOutcome {
+    /// The supplier answered successfully.
+    Success,
+    /// The supplier failed.
+    Failure {
+        /// The error code, see `SupplierError::code`.
+        code: String,
+        /// The error message. Supplier-provided text is redacted unless the log keeps it (see
+        /// `AuditLog::with_error_messages`).
+        message: String,
+    },
+}
+
+/// One audited supplier call.
+///
+/// Records are chained: `hash` covers the record's content and the `prev_hash` of the record
+/// before it, so removing, reordering or editing a record breaks the chain (see [`verify_chain`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// Position of the record in its audit log, starting at 1.
+    pub sequence: u64,
+
+    /// When the call completed, in milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+
+    /// The group that made the call, if it was made through a group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+
+    /// The supplier that was called.
+    pub supplier: String,
+
+    /// The requested operation.
+    pub operation: String,
+
+    /// The request parameters, after the log's parameter filter was applied.
+    pub params: Value,
+
+    /// Whether the call succeeded.
+    pub outcome: AuditOutcome,
+
+    /// How long the call took, in microseconds.
+    pub duration_us: u64,
+
+    /// The request ID of the call, used to correlate records across systems.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+
+    /// Hash of the previous record, or an empty string for the first record.
+    pub prev_hash: String,
+
+    /// SHA-256 of this record (with `hash` empty) as hex.
+    pub hash: String,
+}
+
+impl AuditRecord {
+    /// Returns the hierarchical name of the called supplier: `group/supplier` for calls made
+    /// through a group, the supplier's name otherwise.
+    pub fn path(&self) -> SupplierPath {
+        match &self.group {
+            Some(group) => SupplierPath::new(group).child(&self.supplier),
+            None => SupplierPath::new(&self.supplier),
+        }
+    }
+
+    fn compute_hash(&self) -> String {
+        let mut unsealed = self.clone();
+        unsealed.hash = String::new();
+        let bytes = serde_json::to_vec(&unsealed).expect("audit records serialize");
+        Sha256::digest(&bytes).iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
+
+/// Checks that records form an unbroken hash chain from the first record of a log: the first
+/// record must have sequence `1` and an empty `prev_hash`, so dropping records from the start
+/// breaks the chain too. Use [`verify_chain_from`] for records continuing an earlier segment.
+///
+/// # Errors
+/// Returns the index of the first record that was altered or does not follow its predecessor.
+pub fn verify_chain(records: &[AuditRecord]) -> Result<(), usize> {
+    verify_chain_from(records, "")
+}
+
+/// Checks that records form an unbroken hash chain continuing the record whose hash is
+/// `prev_hash`, e.g. the last record of an already verified segment. An empty `prev_hash`
+/// anchors the chain at the first record of a log, like [`verify_chain`].
+///
+/// # Errors
+/// Returns the index of the first record that was altered or does not follow its predecessor.
+pub fn verify_chain_from(records: &[AuditRecord], prev_hash: &str) -> Result<(), usize> {
+    let mut prev_hash = prev_hash.to_string();
+    // The sequence before the first record is only known at the start of a log.
+    let mut prev_sequence = prev_hash.is_empty().then_some(0);
+    for (index, record) in records.iter().enumerate() {
+        if record.prev_hash != prev_hash
+            || prev_sequence.is_some_and(|sequence| record.sequence != sequence + 1)
+            || record.hash != record.compute_hash()
+        {
+            return Err(index);
+        }
+        prev_hash = record.hash.clone();
+        prev_sequence = Some(record.sequence);
+    }
+    Ok(())
+}
+
+/// Destination of audit records.
+pub trait AuditSink: Send + Sync {
+    /// Persists one record.
+    fn write(&self, record: &AuditRecord) -> io::Result<()>;
+}
+
+/// Writes every record as one JSON line to a writer, such as stdout or a file.
+pub struct JsonLinesSink<W> {
+    writer: Mutex<W>,
+}
+
+impl<W: Write + Send> JsonLinesSink<W> {
+    /// Creates a sink writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+        }
+    }
+}
+
+impl JsonLinesSink<io::Stdout> {
+    /// Creates a sink writing to standard output.
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl JsonLinesSink<File> {
+    /// Creates a sink appending to the file at `path` (created if missing).
+    pub fn file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self::new(OpenOptions::new().create(true).append(true).open(path)?))
+    }
+}
+
+impl<W: Write + Send> AuditSink for JsonLinesSink<W> {
+    fn write(&self, record: &AuditRecord) -> io::Result<()> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+        let mut writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        writer.write_all(&line)?;
+        writer.flush()
+    }
+}
+
+/// Sends every record over a channel, e.g. to a background shipper.
+pub struct ChannelSink {
+    sender: Mutex<mpsc::Sender<AuditRecord>>,
+}
+
+impl ChannelSink {
+    /// Creates a sink and the receiver of its records.
+    pub fn new() -> (Self, mpsc::Receiver<AuditRecord>) {
+        let (sender, receiver) = mpsc::channel();
+        (
+            Self {
+                sender: Mutex::new(sender),
+            },
+            receiver,
+        )
+    }
+}
+
+impl AuditSink for ChannelSink {
+    fn write(&self, record: &AuditRecord) -> io::Result<()> {
+        self.sender
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .send(record.clone())
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "audit receiver dropped"))
+    }
+}
+
+type ParamFilter = Arc<dyn Fn(&Value) -> Value + Send + Sync>;
+
+/// Replaces recorded values, the way [`Redactor`] does by default.
+const REDACTED: &str = "[REDACTED]";
+
+/// Builds hash-chained audit records and hands them to a sink.
+///
+/// Attach a log to a single supplier with [`AuditedSupplier`] or to every call of a group with
+/// `BasicSupplierGroup::with_audit`. Parameters pass through the configured filter or
+/// [`Redactor`] before they are recorded; without one, every parameter value is redacted and
+/// only the keys are kept, so secrets never reach the sink. Likewise, failures are recorded
+/// without their supplier-provided text unless [`with_error_messages`](Self::with_error_messages)
+/// keeps it.
+///
+/// A record the sink fails to write is dropped and counted in
+/// [`write_failures`](Self::write_failures); the next record continues the chain from the last
+/// written one, so the stored log stays verifiable.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use serde_json::json;
+/// use supplier_kit::audit::{verify_chain, AuditLog, AuditedSupplier, ChannelSink};
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let (sink, records) = ChannelSink::new();
+/// let log = Arc::new(AuditLog::new(sink).with_param_filter(|params| {
+///     let mut params = params.clone();
+///     params["token"] = json!("***");
+///     params
+/// }));
+/// let supplier = AuditedSupplier::new(MockSupplierBuilder::new("partner").respond_default(json!({})).build(), log);
+///
+/// supplier.query(SupplierRequest::new(SupplierOperation::Search, json!({ "token": "secret" }))).unwrap();
+/// let record = records.recv().unwrap();
+/// assert_eq!(record.params["token"], "***");
+/// assert!(verify_chain(&[record]).is_ok());
+/// ```
+pub struct AuditLog {
+    sink: Box<dyn AuditSink>,
+    param_filter: ParamFilter,
+    error_messages: bool,
+    // The sequence number and hash of the last record.
+    chain: Mutex<(u64, String)>,
+    write_failures: AtomicU64,
+}
+
+impl AuditLog {
+    /// Creates a log writing to `sink`.
+    pub fn new<S: AuditSink + 'static>(sink: S) -> Self {
+        Self {
+            sink: Box::new(sink),
+            param_filter: Arc::new(redact_values),
+            error_messages: false,
+            chain: Mutex::new((0, String::new())),
+            write_failures: AtomicU64::new(0),
+        }
+    }
+
+    /// Creates a log writing to `sink` that continues the chain after `last`, the latest record
+    /// written before, e.g. read back from the file of a `JsonLinesSink` after a restart.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use serde_json::json;
+    /// use supplier_kit::audit::{verify_chain, AuditLog, AuditedSupplier, ChannelSink};
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier::Supplier;
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let partner = MockSupplierBuilder::new("partner").respond_default(json!({})).build();
+    /// let request = SupplierRequest::new(SupplierOperation::Search, json!({}));
+    /// let (sink, records) = ChannelSink::new();
+    /// AuditedSupplier::new(partner.clone(), Arc::new(AuditLog::new(sink))).query(request.clone()).unwrap();
+    /// let first = records.recv().unwrap();
+    ///
+    /// let (sink, records) = ChannelSink::new();
+    /// AuditedSupplier::new(partner, Arc::new(AuditLog::resume(sink, &first))).query(request).unwrap();
+    /// let second = records.recv().unwrap();
+    /// assert_eq!(second.sequence, 2);
+    /// assert!(verify_chain(&[first, second]).is_ok());
+    /// ```
+    pub fn resume<S: AuditSink + 'static>(sink: S, last: &AuditRecord) -> Self {
+        let log = Self::new(sink);
+        *log.chain.lock().unwrap_or_else(|e| e.into_inner()) = (last.sequence, last.hash.clone());
+        log
+    }
+
+    /// Transforms request parameters before they are recorded, e.g. to redact secrets. The
+    /// filter replaces the default one redacting every value; `|params| params.clone()`
+    /// records parameters as they are.
+    pub fn with_param_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&Value) -> Value + Send + Sync + 'static,
+    {
+        self.param_filter = Arc::new(filter);
+        self
+    }
+
+    /// Redacts request parameters with the `params.*` rules of `redactor` before they are recorded.
+    pub fn with_redactor(self, redactor: Redactor) -> Self {
+        self.with_param_filter(move |params| redactor.redact_field("params", params))
+    }
+
+    /// Sets whether failures are recorded with their full message, including text the supplier
+    /// provided, rather than with that text redacted.
+    pub fn with_error_messages(mut self, keep: bool) -> Self {
+        self.error_messages = keep;
+        self
+    }
+
+    /// Returns how many records the sink failed to write.
+    pub fn write_failures(&self) -> u64 {
+        self.write_failures.load(Ordering::Relaxed)
+    }
+
+    /// Records one supplier call.
+    pub fn record(
+        &self,
+        group: Option<&str>,
+        supplier: &str,
+        request: &SupplierRequest,
+        result: &Result<SupplierResponse, SupplierError>,
+        duration: Duration,
+    ) {
+        let params = (self.param_filter)(&request.params);
+        let outcome = match result {
+            Ok(_) => AuditOutcome::Success,
+            Err(e) => AuditOutcome::Failure {
+                code: e.code().to_string(),
+                message: if self.error_messages { e.to_string() } else { e.redacted(REDACTED).to_string() },
+            },
+        };
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as u64);
+
+        // Hold the chain lock while writing so records reach the sink in chain order.
+        let mut chain = self.chain.lock().unwrap_or_else(|e| e.into_inner());
+        let mut record = AuditRecord {
+            sequence: chain.0 + 1,
+            timestamp_ms,
+            group: group.map(str::to_string),
+            supplier: supplier.to_string(),
+            operation: request.operation.as_str().to_string(),
+            params,
+            outcome,
+            duration_us: duration.as_micros() as u64,
+            correlation_id: request.context.request_id.clone(),
+            prev_hash: chain.1.clone(),
+            hash: String::new(),
+        };
+        record.hash = record.compute_hash();
+
+        // Only a stored record may be chained to, or one failed write breaks the stored log.
+        match self.sink.write(&record) {
+            Ok(()) => *chain = (record.sequence, record.hash),
+            Err(_) => {
+                self.write_failures.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+/// Replaces every value of `params` with `[REDACTED]`, keeping object keys and array lengths.
+fn redact_values(params: &Value) -> Value {
+    match params {
+        Value::Object(fields) => Value::Object(fields.iter().map(|(key, value)| (key.clone(), redact_values(value))).collect()),
+        Value::Array(items) => Value::Array(items.iter().map(redact_values).collect()),
+        Value::Null => Value::Null,
+        _ => Value::String(REDACTED.into()),
+    }
+}
+
+/// A decorator recording every query of the inner supplier to an [`AuditLog`].
+pub struct AuditedSupplier<S> {
+    inner: S,
+    log: Arc<AuditLog>,
+}
+
+impl<S: Supplier> AuditedSupplier<S> {
+    /// Wraps `inner`, recording its calls to `log`.
+    pub fn new(inner: S, log: Arc<AuditLog>) -> Self {
+        Self { inner, log }
+    }
+}
+
+impl<S: Supplier> Supplier for AuditedSupplier<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let started = Instant::now();
+        let result = self.inner.query(request.clone());
+        self.log.record(None, self.inner.name(), &request, &result, started.elapsed());
+        result
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
@@ -0,0 +1,239 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::secrets::SecretsProvider;
+use crate::supplier::Supplier;
+
+/// A credential presented to a supplier's upstream API.
+///
+/// `Debug` output never includes the secret parts.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Credential {
+    /// An `Authorization: Bearer <token>` header.
+    Bearer(String),
+    /// An API key sent in a custom header.
+    ApiKey {
+        /// The header name, e.g. `X-Api-Key`.
+        header: String,
+        /// The key.
+        value: String,
+    },
+    /// HTTP basic authentication.
+    Basic {
+        /// The user name.
+        username: String,
+        /// The password.
+        password: String,
+    },
+}
+
+impl Credential {
+    /// Returns the HTTP header (name, value) carrying the credential.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::auth::Credential;
+    /// let header = Credential::Bearer("t-1".into()).header();
+    /// assert_eq!(header, ("Authorization".to_string(), "Bearer t-1".to_string()));
+    /// ```
+    pub fn header(&self) -> (String, String) {
+        match self {
+            Credential::Bearer(token) => ("Authorization".into(), format!("Bearer {}", token)),
+            Credential::ApiKey { header, value } => (header.clone(), value.clone()),
+            Credential::Basic { username, password } => (
+                "Authorization".into(),
+                format!("Basic {}", STANDARD.encode(format!("{}:{}", username, password))),
+            ),
+        }
+    }
+}
+
+impl fmt::Debug for Credential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Credential::Bearer(_) => f.write_str("Bearer(***)"),
+            Credential::ApiKey { header, .. } => write!(f, "ApiKey {{ header: {:?}, value: *** }}", header),
+            Credential::Basic { username, .. } => write!(f, "Basic {{ username: {:?}, password: *** }}", username),
+        }
+    }
+}
+
+/// Supplies the credential a supplier authenticates with.
+///
+/// Suppliers should ask for the credential once per call and use that value for the whole
+/// call, so a rotation never mixes credentials within one request.
+pub trait AuthProvider: Send + Sync {
+    /// Returns the credential to use for the next call.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::Unauthorized` or `SupplierError::InvalidInput` if no usable
+    /// credential is available.
+    fn credential(&self) -> Result<Credential, SupplierError>;
+}
+
+impl AuthProvider for Credential {
+    fn credential(&self) -> Result<Credential, SupplierError> {
+        Ok(self.clone())
+    }
+}
+
+/// Reads a bearer token or API key from a [`SecretsProvider`] on every call, so rotating the
+/// secret at its source (e.g. a mounted file) takes effect without rebuilding the supplier.
+pub struct SecretAuth {
+    secrets: Arc<dyn SecretsProvider>,
+    name: String,
+    header: Option<String>,
+}
+
+impl SecretAuth {
+    /// Sends secret `name` as a bearer token.
+    pub fn bearer(secrets: Arc<dyn SecretsProvider>, name: &str) -> Self {
+        Self {
+            secrets,
+            name: name.to_string(),
+            header: None,
+        }
+    }
+
+    /// Sends secret `name` as an API key in `header`.
+    pub fn api_key(secrets: Arc<dyn SecretsProvider>, name: &str, header: &str) -> Self {
+        Self {
+            header: Some(header.to_string()),
+            ..Self::bearer(secrets, name)
+        }
+    }
+}
+
+impl AuthProvider for SecretAuth {
+    fn credential(&self) -> Result<Credential, SupplierError> {
+        let value = self
+            .secrets
+            .secret(&self.name)?
+            .ok_or_else(|| SupplierError::InvalidInput(format!("secret '{}' is not defined", self.name)))?;
+        Ok(match &self.header {
+            None => Credential::Bearer(value),
+            Some(header) => Credential::ApiKey {
+                header: header.clone(),
+                value,
+            },
+        })
+    }
+}
+
+/// An auth provider that can be replaced atomically while suppliers use it.
+///
+/// Share one `RotatingAuth` between a supplier and whoever rotates its credentials, typically
+/// through `SupplierRegistry::register_auth` and `SupplierRegistry::rotate_auth`. Calls that
+/// already took a credential finish with it; calls starting after [`RotatingAuth::rotate`]
+/// returns use the new provider.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use supplier_kit::auth::{AuthProvider, Credential, RotatingAuth};
+///
+/// let auth = RotatingAuth::new(Arc::new(Credential::Bearer("old".into())));
+/// let in_flight = auth.credential().unwrap();
+///
+/// auth.rotate(Arc::new(Credential::Bearer("new".into()))).unwrap();
+/// assert_eq!(in_flight, Credential::Bearer("old".into()));
+/// assert_eq!(auth.credential().unwrap(), Credential::Bearer("new".into()));
+/// assert_eq!(auth.generation(), 1);
+/// ```
+pub struct RotatingAuth {
+    current: RwLock<Arc<dyn AuthProvider>>,
+    generation: AtomicU64,
+}
+
+impl RotatingAuth {
+    /// Starts with `provider`, at generation 0.
+    pub fn new(provider: Arc<dyn AuthProvider>) -> Self {
+        Self {
+            current: RwLock::new(provider),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// Replaces the provider and returns the new generation.
+    ///
+    /// The new provider must produce a credential first; otherwise the current provider stays
+    /// in place, so a bad rotation cannot take the supplier down.
+    ///
+    /// # Errors
+    /// Returns the new provider's error if it cannot produce a credential.
+    pub fn rotate(&self, provider: Arc<dyn AuthProvider>) -> Result<u64, SupplierError> {
+        provider.credential()?;
+        let mut current = self.current.write().unwrap_or_else(|e| e.into_inner());
+        *current = provider;
+        Ok(self.generation.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+
+    /// Returns how many times the provider has been rotated.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    /// Returns the current provider.
+    pub fn current(&self) -> Arc<dyn AuthProvider> {
+        self.current.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+impl AuthProvider for RotatingAuth {
+    fn credential(&self) -> Result<Credential, SupplierError> {
+        self.current().credential()
+    }
+}
+
+type AuthenticatedQuery = dyn Fn(&Credential, SupplierRequest) -> Result<SupplierResponse, SupplierError> + Send + Sync;
+
+/// A supplier built from a function that receives the credential taken for each call.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use supplier_kit::auth::{AuthenticatedSupplier, Credential};
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::supplier::Supplier;
+///
+/// let supplier = AuthenticatedSupplier::new("partner", Arc::new(Credential::Bearer("t-1".into())), |credential, _request| {
+///     let (_, value) = credential.header();
+///     Ok(SupplierResponse::new(serde_json::json!({ "sent": value })))
+/// });
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}));
+/// assert_eq!(supplier.query(request).unwrap().data["sent"], "Bearer t-1");
+/// ```
+pub struct AuthenticatedSupplier {
+    name: String,
+    auth: Arc<dyn AuthProvider>,
+    query: Box<AuthenticatedQuery>,
+}
+
+impl AuthenticatedSupplier {
+    /// Creates a supplier named `name` calling `query` with a credential from `auth`.
+    pub fn new<F>(name: &str, auth: Arc<dyn AuthProvider>, query: F) -> Self
+    where
+        F: Fn(&Credential, SupplierRequest) -> Result<SupplierResponse, SupplierError> + Send + Sync + 'static,
+    {
+        Self {
+            name: name.to_string(),
+            auth,
+            query: Box::new(query),
+        }
+    }
+}
+
+impl Supplier for AuthenticatedSupplier {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let credential = self.auth.credential()?;
+        (self.query)(&credential, request)
+    }
+}
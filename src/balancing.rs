@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use crate::supplier::Supplier;
+use crate::utils::{unit, SplitMix64};
+
+/// The recent load of one supplier, as seen by adaptive routing.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SupplierLoad {
+    /// Calls currently in flight.
+    pub in_flight: usize,
+    /// Exponentially weighted latency of completed calls; failures count as the failure penalty.
+    pub latency: Duration,
+    /// Number of completed calls.
+    pub samples: u64,
+}
+
+impl SupplierLoad {
+    /// Returns the routing score, lower is better: the weighted latency times one plus the
+    /// calls in flight.
+    pub fn score(&self) -> f64 {
+        self.latency.as_secs_f64() * (self.in_flight + 1) as f64
+    }
+}
+
+/// Tracks per-supplier latency and in-flight calls and picks suppliers with the
+/// power-of-two-choices rule.
+///
+/// Every group keeps a tracker fed by all of its supplier calls; `QueryStrategy::Adaptive`
+/// uses it to route each query to one supplier. Two candidates are sampled at random and the
+/// one with the lower [`SupplierLoad::score`] wins, which avoids both herding onto the single
+/// fastest supplier and the staleness of static weights. Suppliers without samples score zero,
+/// so new suppliers receive traffic right away.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::balancing::LoadTracker;
+///
+/// let tracker = LoadTracker::new().with_seed(7);
+/// tracker.record("fast", Duration::from_millis(20), true);
+/// tracker.record("slow", Duration::from_millis(400), true);
+///
+/// assert!(tracker.load("fast").score() < tracker.load("slow").score());
+/// assert_eq!(tracker.load("unknown").samples, 0);
+/// ```
+pub struct LoadTracker {
+    loads: Mutex<HashMap<String, SupplierLoad>>,
+    smoothing: f64,
+    failure_penalty: Duration,
+    rng: Mutex<SplitMix64>,
+}
+
+impl Default for LoadTracker {
+    fn default() -> Self {
+        let seed = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos() as u64;
+        Self {
+            loads: Mutex::new(HashMap::new()),
+            smoothing: 0.3,
+            failure_penalty: Duration::from_secs(1),
+            rng: Mutex::new(SplitMix64(seed)),
+        }
+    }
+}
+
+impl LoadTracker {
+    /// Creates a tracker with a smoothing factor of `0.3` and a one-second failure penalty.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seeds the candidate sampling, making routing decisions reproducible.
+    pub fn with_seed(self, seed: u64) -> Self {
+        *self.rng.lock().unwrap_or_else(|e| e.into_inner()) = SplitMix64(seed);
+        self
+    }
+
+    /// Sets the weight of the newest sample in the latency average, between `0.0` and `1.0`.
+    pub fn with_smoothing(mut self, smoothing: f64) -> Self {
+        self.smoothing = smoothing.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the latency a failed call counts as, at least; failing fast must not attract traffic.
+    pub fn with_failure_penalty(mut self, penalty: Duration) -> Self {
+        self.failure_penalty = penalty;
+        self
+    }
+
+    /// Returns the current load of `supplier`.
+    pub fn load(&self, supplier: &str) -> SupplierLoad {
+        self.lock().get(supplier).copied().unwrap_or_default()
+    }
+
+    /// Picks one of `candidates` by the power of two choices and returns its index.
+    ///
+    /// Returns `None` if there are no candidates.
+    pub fn choose(&self, candidates: &[Arc<dyn Supplier>]) -> Option<usize> {
+        match candidates.len() {
+            0 => None,
+            1 => Some(0),
+            len => {
+                let (first, second) = {
+                    let mut rng = self.rng.lock().unwrap_or_else(|e| e.into_inner());
+                    let first = (rng.next() % len as u64) as usize;
+                    let offset = 1 + (rng.next() % (len as u64 - 1)) as usize;
+                    (first, (first + offset) % len)
+                };
+                let loads = self.lock();
+                let score = |index: usize| loads.get(candidates[index].name()).map_or(0.0, SupplierLoad::score);
+                Some(if score(second) < score(first) { second } else { first })
+            }
+        }
+    }
+
+    /// Picks one of `candidates` by the power of two choices, weighing each candidate with the
+    /// weight at the same index of `weights`, and returns its index.
+    ///
+    /// Candidates are sampled in proportion to their weight and their scores are divided by
+    /// it, so the lower its weight, the less traffic a candidate receives. Candidates of weight
+    /// `0.0` are only picked when no candidate has a positive weight. Returns `None` if there
+    /// are no candidates.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use supplier_kit::balancing::LoadTracker;
+    /// use supplier_kit::supplier::Supplier;
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let tracker = LoadTracker::new().with_seed(7);
+    /// let replicas: Vec<Arc<dyn Supplier>> = vec![
+    ///     Arc::new(MockSupplierBuilder::new("old").build()),
+    ///     Arc::new(MockSupplierBuilder::new("new").build()),
+    /// ];
+    /// assert_eq!(tracker.choose_weighted(&replicas, &[0.0, 1.0]), Some(1));
+    /// ```
+    pub fn choose_weighted(&self, candidates: &[Arc<dyn Supplier>], weights: &[f64]) -> Option<usize> {
+        let weight = |index: usize| weights.get(index).copied().unwrap_or(1.0).max(0.0);
+        let positive: Vec<usize> = (0..candidates.len()).filter(|&index| weight(index) > 0.0).collect();
+        match positive.len() {
+            0 => return self.choose(candidates),
+            1 => return Some(positive[0]),
+            // Equal weights change nothing.
+            len if len == candidates.len() && positive.iter().all(|&index| weight(index) == weight(0)) => {
+                return self.choose(candidates);
+            }
+            _ => {}
+        }
+        let (first, second) = {
+            let mut rng = self.rng.lock().unwrap_or_else(|e| e.into_inner());
+            let first = draw(&mut rng, &positive, &weight, None);
+            (first, draw(&mut rng, &positive, &weight, Some(first)))
+        };
+        let loads = self.lock();
+        let score = |index: usize| loads.get(candidates[index].name()).map_or(0.0, SupplierLoad::score) / weight(index);
+        Some(if score(second) < score(first) { second } else { first })
+    }
+
+    /// Marks a call to `supplier` as started.
+    pub fn begin(&self, supplier: &str) {
+        self.lock().entry(supplier.to_string()).or_default().in_flight += 1;
+    }
+
+    /// Marks a call to `supplier` started with [`LoadTracker::begin`] as finished.
+    pub fn finish(&self, supplier: &str, latency: Duration, succeeded: bool) {
+        let mut loads = self.lock();
+        let load = loads.entry(supplier.to_string()).or_default();
+        load.in_flight = load.in_flight.saturating_sub(1);
+        self.sample(load, latency, succeeded);
+    }
+
+    /// Records a completed call that was not tracked with [`LoadTracker::begin`].
+    pub fn record(&self, supplier: &str, latency: Duration, succeeded: bool) {
+        let mut loads = self.lock();
+        self.sample(loads.entry(supplier.to_string()).or_default(), latency, succeeded);
+    }
+
+    fn sample(&self, load: &mut SupplierLoad, latency: Duration, succeeded: bool) {
+        let latency = if succeeded { latency } else { latency.max(self.failure_penalty) };
+        load.latency = if load.samples == 0 {
+            latency
+        } else {
+            load.latency.mul_f64(1.0 - self.smoothing) + latency.mul_f64(self.smoothing)
+        };
+        load.samples += 1;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, SupplierLoad>> {
+        self.loads.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Draws one of `indices` other than `skip`, each in proportion to its weight.
+fn draw(rng: &mut SplitMix64, indices: &[usize], weight: &impl Fn(usize) -> f64, skip: Option<usize>) -> usize {
+    let eligible = || indices.iter().copied().filter(|&index| Some(index) != skip);
+    let mut remaining = unit(rng.next()) * eligible().map(weight).sum::<f64>();
+    let mut drawn = indices[0];
+    for index in eligible() {
+        drawn = index;
+        remaining -= weight(index);
+        if remaining < 0.0 {
+            break;
+        }
+    }
+    drawn
+}
+
+/// Pins sessions to the supplier that served their first request.
+///
+/// Some partners keep conversational state, such as a cart or a price quote, on the replica
+/// that created it. A group with `QueryStrategy::Adaptive` and a `SessionAffinity` routes the
+/// first request of a session (see `RequestContext::session_id`) as usual, then sends the
+/// following requests of that session to the same supplier. A pin expires once the session
+/// has been idle for the TTL, and is dropped when its supplier is no longer available to the
+/// group, e.g. because it was removed or its circuit is open.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::balancing::SessionAffinity;
+///
+/// let affinity = SessionAffinity::new(Duration::from_secs(900));
+/// affinity.pin("checkout-7", "shop_b");
+/// assert_eq!(affinity.pinned("checkout-7").as_deref(), Some("shop_b"));
+/// assert_eq!(affinity.release("checkout-7").as_deref(), Some("shop_b"));
+/// assert_eq!(affinity.pinned("checkout-7"), None);
+/// ```
+pub struct SessionAffinity {
+    ttl: Duration,
+    sessions: Mutex<HashMap<String, (String, Instant)>>,
+}
+
+impl SessionAffinity {
+    /// Creates an affinity table whose pins expire after `ttl` without requests.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the idle time after which a session loses its pin.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the supplier `session` is pinned to, extending the pin, or `None` if the session
+    /// is unknown or its pin expired.
+    pub fn pinned(&self, session: &str) -> Option<String> {
+        let mut sessions = self.lock();
+        let now = Instant::now();
+        match sessions.get_mut(session) {
+            Some((supplier, expires_at)) if *expires_at > now => {
+                *expires_at = now + self.ttl;
+                Some(supplier.clone())
+            }
+            Some(_) => {
+                sessions.remove(session);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Pins `session` to `supplier`, replacing any previous pin.
+    pub fn pin(&self, session: &str, supplier: &str) {
+        let mut sessions = self.lock();
+        let now = Instant::now();
+        sessions.retain(|_, (_, expires_at)| *expires_at > now);
+        sessions.insert(session.to_string(), (supplier.to_string(), now + self.ttl));
+    }
+
+    /// Removes the pin of `session`, e.g. when its checkout completes, and returns its supplier.
+    pub fn release(&self, session: &str) -> Option<String> {
+        self.lock().remove(session).map(|(supplier, _)| supplier)
+    }
+
+    /// Returns the number of sessions with an unexpired pin.
+    pub fn len(&self) -> usize {
+        let now = Instant::now();
+        self.lock().values().filter(|(_, expires_at)| *expires_at > now).count()
+    }
+
+    /// Returns whether no session is pinned.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, (String, Instant)>> {
+        self.sessions.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
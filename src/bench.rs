@@ -0,0 +1,264 @@
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+use crate::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+
+/// Calls, errors and latency observed for one supplier during a benchmark run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SupplierBenchStats {
+    /// The supplier name.
+    pub name: String,
+
+    /// Number of queries the supplier received.
+    pub calls: usize,
+
+    /// Number of those queries that failed.
+    pub errors: usize,
+
+    /// Sum of the latencies of all queries.
+    pub total_latency: Duration,
+}
+
+impl SupplierBenchStats {
+    /// Returns the average latency, or zero if the supplier was never called.
+    pub fn average_latency(&self) -> Duration {
+        if self.calls == 0 {
+            Duration::ZERO
+        } else {
+            self.total_latency.div_f64(self.calls as f64)
+        }
+    }
+}
+
+/// The outcome of running a workload through one group strategy.
+#[derive(Debug, Clone)]
+pub struct StrategyReport {
+    /// The strategy that was measured.
+    pub strategy: QueryStrategy,
+
+    /// Wall-clock time of the whole workload.
+    pub elapsed: Duration,
+
+    /// Latency of every group query, sorted ascending.
+    pub latencies: Vec<Duration>,
+
+    /// Number of supplier responses that were returned successfully.
+    pub successes: usize,
+
+    /// Number of supplier failures that were returned.
+    pub failures: usize,
+
+    /// Per-supplier statistics, in group order.
+    pub suppliers: Vec<SupplierBenchStats>,
+}
+
+impl StrategyReport {
+    /// Returns the number of group queries completed per second.
+    pub fn throughput(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs == 0.0 {
+            0.0
+        } else {
+            self.latencies.len() as f64 / secs
+        }
+    }
+
+    /// Returns the group query latency at the given percentile (between `0.0` and `100.0`).
+    pub fn percentile(&self, percentile: f64) -> Duration {
+        if self.latencies.is_empty() {
+            return Duration::ZERO;
+        }
+        let rank = (percentile.clamp(0.0, 100.0) / 100.0 * (self.latencies.len() - 1) as f64).round();
+        self.latencies[rank as usize]
+    }
+}
+
+impl fmt::Display for StrategyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:?}: {} queries in {:?} ({:.1}/s), p50 {:?}, p95 {:?}, max {:?}, {} ok / {} failed",
+            self.strategy,
+            self.latencies.len(),
+            self.elapsed,
+            self.throughput(),
+            self.percentile(50.0),
+            self.percentile(95.0),
+            self.percentile(100.0),
+            self.successes,
+            self.failures,
+        )?;
+        for supplier in &self.suppliers {
+            writeln!(
+                f,
+                "  {}: {} calls, {} errors, avg {:?}",
+                supplier.name,
+                supplier.calls,
+                supplier.errors,
+                supplier.average_latency()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Replays a workload through different group strategies and reports throughput and latency.
+///
+/// For every strategy, a fresh `BasicSupplierGroup` is built over the same suppliers, each
+/// wrapped to measure its own calls. Workload requests run one after another, so the numbers
+/// describe the latency of a single group query and the load each strategy puts on suppliers.
+/// Replay suppliers loaded from fixtures with simulated latency make realistic workloads.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use supplier_kit::bench::StrategyBench;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::supplier_group::QueryStrategy;
+///
+/// struct Slow(&'static str, u64);
+///
+/// impl Supplier for Slow {
+///     fn name(&self) -> &str { self.0 }
+///     fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         std::thread::sleep(Duration::from_millis(self.1));
+///         Ok(SupplierResponse::new(serde_json::json!({})))
+///     }
+/// }
+///
+/// let suppliers: Vec<Arc<dyn Supplier>> = vec![Arc::new(Slow("a", 5)), Arc::new(Slow("b", 1))];
+/// let workload = vec![SupplierRequest::new(SupplierOperation::Search, serde_json::json!({})); 3];
+/// let reports = StrategyBench::new(suppliers, workload)
+///     .compare(&[QueryStrategy::Sequential, QueryStrategy::Failover]);
+///
+/// assert_eq!(reports[0].suppliers[1].calls, 3);
+/// assert_eq!(reports[1].suppliers[1].calls, 0);
+/// println!("{}", reports[0]);
+/// ```
+pub struct StrategyBench {
+    suppliers: Vec<Arc<dyn Supplier>>,
+    workload: Vec<SupplierRequest>,
+    max_concurrency: Option<usize>,
+}
+
+impl StrategyBench {
+    /// Creates a benchmark running `workload` against `suppliers`.
+    pub fn new(suppliers: Vec<Arc<dyn Supplier>>, workload: Vec<SupplierRequest>) -> Self {
+        Self {
+            suppliers,
+            workload,
+            max_concurrency: None,
+        }
+    }
+
+    /// Bounds the concurrency of the measured groups, see `BasicSupplierGroup::with_max_concurrency`.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = Some(max_concurrency);
+        self
+    }
+
+    /// Runs the workload through a group using `strategy`.
+    pub fn run(&self, strategy: QueryStrategy) -> StrategyReport {
+        let measured: Vec<Arc<Measured>> = self
+            .suppliers
+            .iter()
+            .map(|inner| {
+                Arc::new(Measured {
+                    inner: inner.clone(),
+                    stats: Mutex::new(SupplierBenchStats {
+                        name: inner.name().to_string(),
+                        ..SupplierBenchStats::default()
+                    }),
+                })
+            })
+            .collect();
+
+        let mut group = BasicSupplierGroup::new("bench").with_strategy(strategy);
+        if let Some(limit) = self.max_concurrency {
+            group = group.with_max_concurrency(limit);
+        }
+        for supplier in &measured {
+            group.add_supplier_arc(supplier.clone());
+        }
+
+        let mut latencies = Vec::with_capacity(self.workload.len());
+        let (mut successes, mut failures) = (0, 0);
+        let started = Instant::now();
+        for request in &self.workload {
+            let query_started = Instant::now();
+            let result = group.query(request.clone());
+            latencies.push(query_started.elapsed());
+            successes += result.successes.len();
+            failures += result.failures.len();
+        }
+        let elapsed = started.elapsed();
+        latencies.sort();
+
+        // Abandoned race participants may still be running; their calls are included once they finish.
+        let suppliers = measured
+            .iter()
+            .map(|m| m.stats.lock().unwrap_or_else(|e| e.into_inner()).clone())
+            .collect();
+
+        StrategyReport {
+            strategy,
+            elapsed,
+            latencies,
+            successes,
+            failures,
+            suppliers,
+        }
+    }
+
+    /// Runs the workload through every strategy in turn.
+    pub fn compare(&self, strategies: &[QueryStrategy]) -> Vec<StrategyReport> {
+        strategies.iter().map(|strategy| self.run(*strategy)).collect()
+    }
+}
+
+/// Wraps a supplier to record its calls, errors and latency.
+struct Measured {
+    inner: Arc<dyn Supplier>,
+    stats: Mutex<SupplierBenchStats>,
+}
+
+impl Supplier for Measured {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let started = Instant::now();
+        let result = self.inner.query(request);
+        let elapsed = started.elapsed();
+
+        let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        stats.calls += 1;
+        stats.errors += usize::from(result.is_err());
+        stats.total_latency += elapsed;
+        result
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
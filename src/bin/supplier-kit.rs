@@ -0,0 +1,322 @@
+//! `supplier-kit`: inspect and query the suppliers and groups declared in a manifest.
+//!
+//! ```text
+//! supplier-kit --manifest suppliers.json list
+//! supplier-kit query --group marketplaces --op search --params '{"q":"laptop"}'
+//! supplier-kit query --group marketplaces --op search --params '{}' --dry-run
+//! supplier-kit query --supplier shop_a --op get_detail --params '{"id":7}' --json
+//! supplier-kit describe --supplier shop_a
+//! supplier-kit repl
+//! ```
+//!
+//! Supplier configs may reference secrets as `{ "$secret": "<name>" }`; they are read from
+//! environment variables (see `EnvSecrets`) and then from `--secrets-dir`.
+
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+use clap::error::ErrorKind;
+use clap::{Args, Parser, Subcommand};
+use serde_json::Value;
+use supplier_kit::config::{LoadedManifest, Manifest, SupplierFactory};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::secrets::{ChainedSecrets, EnvSecrets, FileSecrets};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, PlannedSupplier, SupplierGroup, SupplierGroupResult};
+
+#[derive(Parser)]
+#[command(name = "supplier-kit", version, about = "Inspect and query suppliers declared in a manifest")]
+struct Cli {
+    /// Path of the JSON manifest declaring suppliers and groups.
+    #[arg(long, short, env = "SUPPLIER_KIT_MANIFEST", default_value = "supplier-kit.json")]
+    manifest: PathBuf,
+
+    /// Directory holding one file per secret, consulted after environment variables.
+    #[arg(long, env = "SUPPLIER_KIT_SECRETS_DIR")]
+    secrets_dir: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// One line typed at the REPL prompt, parsed with the same commands as the CLI.
+#[derive(Parser)]
+#[command(name = "", no_binary_name = true, disable_version_flag = true)]
+struct ReplLine {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List the suppliers and groups of the manifest.
+    List,
+    /// Query a group or a single supplier and print the result.
+    Query(QueryArgs),
+    /// Print the descriptors of all suppliers, or of one supplier.
+    Describe {
+        /// Only describe this supplier.
+        #[arg(long)]
+        supplier: Option<String>,
+    },
+    /// Read commands interactively; type `help` for the list and `exit` to leave.
+    Repl,
+}
+
+#[derive(Args)]
+struct QueryArgs {
+    /// The group to query.
+    #[arg(long, conflicts_with = "supplier", required_unless_present = "supplier")]
+    group: Option<String>,
+
+    /// The supplier to query directly.
+    #[arg(long)]
+    supplier: Option<String>,
+
+    /// The operation, e.g. `search` or `get_detail`.
+    #[arg(long)]
+    op: String,
+
+    /// The request params as JSON.
+    #[arg(long, default_value = "{}")]
+    params: String,
+
+    /// Print the raw JSON result instead of a summary.
+    #[arg(long)]
+    json: bool,
+
+    /// Validate params and show which suppliers would be queried, without calling them.
+    #[arg(long)]
+    dry_run: bool,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let mut secrets = ChainedSecrets::new().with(Arc::new(EnvSecrets::new()));
+    if let Some(dir) = &cli.secrets_dir {
+        secrets = secrets.with(Arc::new(FileSecrets::new(dir)));
+    }
+    let loaded = Manifest::from_file(&cli.manifest).and_then(|manifest| {
+        let loaded = SupplierFactory::new().with_secrets(Arc::new(secrets)).load(&manifest)?;
+        Ok((manifest, loaded))
+    });
+    let (manifest, loaded) = match loaded {
+        Ok(loaded) => loaded,
+        Err(error) => return fail(&error),
+    };
+
+    let outcome = match cli.command {
+        Command::Repl => repl(&manifest, &loaded),
+        command => run(&manifest, &loaded, command),
+    };
+    match outcome {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => fail(&error),
+    }
+}
+
+fn fail(error: &SupplierError) -> ExitCode {
+    eprintln!("error: {}", error);
+    ExitCode::from(2)
+}
+
+/// Runs one command and returns whether it fully succeeded.
+fn run(manifest: &Manifest, loaded: &LoadedManifest, command: Command) -> Result<bool, SupplierError> {
+    match command {
+        Command::List => {
+            list(manifest);
+            Ok(true)
+        }
+        Command::Query(args) if args.dry_run => dry_run(loaded, &args),
+        Command::Query(args) => query(loaded, &args),
+        Command::Describe { supplier } => {
+            let mut descriptors = loaded.registry.describe_all();
+            if let Some(name) = supplier {
+                descriptors.retain(|key, _| *key == name);
+                if descriptors.is_empty() {
+                    return Err(SupplierError::InvalidInput(format!("unknown supplier '{}'", name)));
+                }
+            }
+            println!("{}", pretty(&serde_json::to_value(descriptors).unwrap_or_default()));
+            Ok(true)
+        }
+        Command::Repl => Err(SupplierError::InvalidInput("already in the REPL".into())),
+    }
+}
+
+fn repl(manifest: &Manifest, loaded: &LoadedManifest) -> Result<bool, SupplierError> {
+    println!("supplier-kit REPL: {} suppliers, {} groups. Type `help` or `exit`.", manifest.suppliers.len(), manifest.groups.len());
+    let stdin = io::stdin();
+    loop {
+        print!("> ");
+        let _ = io::stdout().flush();
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap_or(0) == 0 {
+            println!();
+            return Ok(true);
+        }
+        let words = match split_words(&line) {
+            Ok(words) => words,
+            Err(message) => {
+                eprintln!("error: {}", message);
+                continue;
+            }
+        };
+        match words.first().map(String::as_str) {
+            None => continue,
+            Some("exit" | "quit") => return Ok(true),
+            Some(_) => {}
+        }
+
+        match ReplLine::try_parse_from(&words) {
+            Ok(parsed) => {
+                if let Err(error) = run(manifest, loaded, parsed.command) {
+                    eprintln!("error: {}", error);
+                }
+            }
+            Err(error) if matches!(error.kind(), ErrorKind::DisplayHelp | ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand) => {
+                println!("{}", error);
+            }
+            Err(error) => eprintln!("{}", error),
+        }
+    }
+}
+
+/// Splits a REPL line into words, honouring single and double quotes.
+fn split_words(line: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut current: Option<String> = None;
+    let mut quote: Option<char> = None;
+
+    for c in line.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => current.get_or_insert_with(String::new).push(c),
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                current.get_or_insert_with(String::new);
+            }
+            (None, c) if c.is_whitespace() => words.extend(current.take()),
+            (None, c) => current.get_or_insert_with(String::new).push(c),
+        }
+    }
+    if quote.is_some() {
+        return Err("unterminated quote".into());
+    }
+    words.extend(current);
+    Ok(words)
+}
+
+fn list(manifest: &Manifest) {
+    println!("suppliers:");
+    for spec in &manifest.suppliers {
+        println!("  {:<24} {}", spec.name, spec.kind);
+    }
+    println!("groups:");
+    for group in &manifest.groups {
+        println!("  {:<24} {:?}: {}", group.name, group.strategy, group.suppliers.join(", "));
+    }
+}
+
+fn request(args: &QueryArgs) -> Result<SupplierRequest, SupplierError> {
+    let params: Value = serde_json::from_str(&args.params)
+        .map_err(|e| SupplierError::InvalidInput(format!("--params is not valid JSON: {}", e)))?;
+    Ok(SupplierRequest::new(SupplierOperation::from(args.op.as_str()), params))
+}
+
+fn query(loaded: &LoadedManifest, args: &QueryArgs) -> Result<bool, SupplierError> {
+    let request = request(args)?;
+    let result = match (&args.group, &args.supplier) {
+        (Some(name), _) => group(loaded, name)?.query(request),
+        (None, Some(name)) => single_result(name, supplier(loaded, name)?.query(request)),
+        (None, None) => unreachable!("clap requires --group or --supplier"),
+    };
+
+    if args.json {
+        println!("{}", pretty(&serde_json::to_value(&result).unwrap_or_default()));
+    } else {
+        print_result(&result);
+    }
+    Ok(!result.successes.is_empty())
+}
+
+fn dry_run(loaded: &LoadedManifest, args: &QueryArgs) -> Result<bool, SupplierError> {
+    let request = request(args)?;
+    let plan = match (&args.group, &args.supplier) {
+        (Some(name), _) => group(loaded, name)?.plan(&request),
+        (None, Some(name)) => vec![PlannedSupplier::new(supplier(loaded, name)?.as_ref(), &request)],
+        (None, None) => unreachable!("clap requires --group or --supplier"),
+    };
+
+    if args.json {
+        println!("{}", pretty(&serde_json::to_value(&plan).unwrap_or_default()));
+    } else {
+        print_plan(request.operation.as_str(), &plan);
+    }
+    let queried = plan.iter().filter(|p| p.would_query()).count();
+    Ok(queried > 0 && plan.iter().all(|p| p.param_errors.is_empty()))
+}
+
+fn group<'a>(loaded: &'a LoadedManifest, name: &str) -> Result<&'a BasicSupplierGroup, SupplierError> {
+    loaded
+        .groups
+        .get(name)
+        .ok_or_else(|| SupplierError::InvalidInput(format!("unknown group '{}'", name)))
+}
+
+fn supplier(loaded: &LoadedManifest, name: &str) -> Result<Arc<dyn Supplier>, SupplierError> {
+    loaded
+        .registry
+        .get(name)
+        .ok_or_else(|| SupplierError::InvalidInput(format!("unknown supplier '{}'", name)))
+}
+
+fn single_result(name: &str, result: Result<SupplierResponse, SupplierError>) -> SupplierGroupResult {
+    let mut group = SupplierGroupResult::default();
+    match result {
+        Ok(response) => group.successes.push((name.to_string(), response)),
+        Err(error) => group.failures.push((name.to_string(), error)),
+    }
+    group
+}
+
+fn print_result(result: &SupplierGroupResult) {
+    println!("{} succeeded, {} failed", result.successes.len(), result.failures.len());
+    for (name, response) in &result.successes {
+        println!("\nOK   {}", name);
+        for line in pretty(&response.data).lines() {
+            println!("     {}", line);
+        }
+    }
+    for (name, error) in &result.failures {
+        println!("\nFAIL {}: [{}] {}", name, error.code(), error);
+    }
+}
+
+fn print_plan(operation: &str, plan: &[PlannedSupplier]) {
+    println!("dry run of '{}': no supplier was called", operation);
+    for entry in plan {
+        match &entry.skip_reason {
+            None => print!("  QUERY {}", entry.name),
+            Some(reason) => print!("  SKIP  {} ({})", entry.name, reason),
+        }
+        if entry.declares_operation == Some(false) {
+            print!(" [operation not declared]");
+        }
+        println!();
+        for error in &entry.param_errors {
+            println!("        invalid params: {}", error);
+        }
+    }
+    let queried = plan.iter().filter(|p| p.would_query()).count();
+    let invalid = plan.iter().filter(|p| !p.param_errors.is_empty()).count();
+    println!("{} of {} suppliers would be queried, {} with invalid params", queried, plan.len(), invalid);
+}
+
+fn pretty(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string())
+}
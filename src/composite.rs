@@ -0,0 +1,162 @@
+use std::sync::{Arc, Mutex};
+use serde_json::{Map, Value};
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::{SupplierError, SupplierGroupError};
+use crate::group_registry::SharedGroup;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+use crate::supplier_group::{SupplierGroup, SupplierGroupResult};
+
+/// A supplier answering from a whole supplier group, so groups can be members of other groups.
+///
+/// Each query fans out to the nested group. The response data is an object mapping every
+/// successful member to its data. When the nested group fails as a whole (see
+/// `SupplierGroupResult::error`) or no member succeeds, the composite fails with
+/// `SupplierError::Upstream`.
+/// The composite takes the group's name unless renamed with [`CompositeSupplier::with_name`].
+///
+/// A `BasicSupplierGroup` built with `with_flattened_results` lists the members of nested
+/// groups instead of the composite, under hierarchical names such as `eu/amazon_de`.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use serde_json::json;
+/// use supplier_kit::composite::CompositeSupplier;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::supplier_group::BasicSupplierGroup;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let mut eu = BasicSupplierGroup::new("eu");
+/// eu.add_supplier(MockSupplierBuilder::new("amazon_de").respond_default(json!(["de"])).build());
+/// eu.add_supplier(MockSupplierBuilder::new("amazon_fr").respond_default(json!(["fr"])).build());
+///
+/// let composite = CompositeSupplier::new(Arc::new(eu));
+/// assert_eq!(composite.name(), "eu");
+/// let response = composite.query(SupplierRequest::new(SupplierOperation::Search, json!({}))).unwrap();
+/// assert_eq!(response.data, json!({ "amazon_de": ["de"], "amazon_fr": ["fr"] }));
+/// ```
+pub struct CompositeSupplier {
+    name: String,
+    group: SharedGroup,
+}
+
+impl CompositeSupplier {
+    /// Wraps `group` as a supplier named after the group.
+    pub fn new(group: SharedGroup) -> Self {
+        Self {
+            name: group.group_name().to_string(),
+            group,
+        }
+    }
+
+    /// Names the supplier `name` instead of the group's name.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Returns the nested group.
+    pub fn group(&self) -> &SharedGroup {
+        &self.group
+    }
+}
+
+impl Supplier for CompositeSupplier {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        respond(self.group.group_name(), &self.group.query(request))
+    }
+
+    fn as_group(&self) -> Option<&(dyn SupplierGroup + Send + Sync)> {
+        Some(self.group.as_ref())
+    }
+}
+
+/// Merges a nested group's result into a single response, as `CompositeSupplier` answers.
+///
+/// Fails with the group's error, or when no member succeeded even though the group's policy
+/// tolerated it.
+fn respond(group: &str, result: &SupplierGroupResult) -> Result<SupplierResponse, SupplierError> {
+    let error = result.error.clone().or_else(|| {
+        let group = group.to_string();
+        match (result.successes.is_empty(), result.failures.is_empty()) {
+            (false, _) => None,
+            (true, true) => Some(SupplierGroupError::NoSuppliers { group }),
+            (true, false) => Some(SupplierGroupError::AllFailed { group, failures: result.failures.clone() }),
+        }
+    });
+    if let Some(error) = error {
+        return Err(SupplierError::Upstream(error.to_string()));
+    }
+    let data: Map<String, Value> = result
+        .successes
+        .iter()
+        .map(|(name, response)| (name.clone(), response.data.clone()))
+        .collect();
+    Ok(SupplierResponse::new(Value::Object(data)))
+}
+
+/// A nested group's result kept by `CapturingSupplier`.
+pub(crate) struct Captured {
+    pub(crate) result: SupplierGroupResult,
+    /// Whether the nested group names its entries by path.
+    pub(crate) flattened: bool,
+}
+
+/// Queries a nested group like `CompositeSupplier` and keeps the group's result, so a
+/// flattening group can list the nested members instead of the composite.
+pub(crate) struct CapturingSupplier {
+    inner: Arc<dyn Supplier>,
+    captured: Mutex<Option<Captured>>,
+}
+
+impl CapturingSupplier {
+    pub(crate) fn new(inner: Arc<dyn Supplier>) -> Self {
+        Self {
+            inner,
+            captured: Mutex::new(None),
+        }
+    }
+
+    /// Takes the nested group's result of the latest query, if it finished.
+    pub(crate) fn take(&self) -> Option<Captured> {
+        self.captured.lock().unwrap_or_else(|e| e.into_inner()).take()
+    }
+}
+
+impl Supplier for CapturingSupplier {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let Some(group) = self.inner.as_group() else {
+            return self.inner.query(request);
+        };
+        let result = group.query(request);
+        let response = respond(group.group_name(), &result);
+        let captured = Captured {
+            result,
+            flattened: group.flattens_results(),
+        };
+        *self.captured.lock().unwrap_or_else(|e| e.into_inner()) = Some(captured);
+        response
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+
+    fn as_group(&self) -> Option<&(dyn SupplierGroup + Send + Sync)> {
+        self.inner.as_group()
+    }
+}
@@ -0,0 +1,257 @@
+use std::borrow::Cow;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use crate::errors::SupplierError;
+
+/// Identifies the algorithm used to compress a payload.
+///
+/// Every payload produced by [`compress`] starts with a one-byte tag holding this value,
+/// so [`decompress`] can read it back without knowing how it was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Compression {
+    /// No compression; the payload is stored as is.
+    #[default]
+    None,
+    /// Zstandard compression at the given level (requires the `zstd` feature).
+    #[cfg(feature = "zstd")]
+    Zstd(i32),
+    /// LZ4 block compression (requires the `lz4` feature).
+    #[cfg(feature = "lz4")]
+    Lz4,
+    /// Gzip compression at the given level, from 0 to 9 (requires the `gzip` feature).
+    #[cfg(feature = "gzip")]
+    Gzip(u32),
+}
+
+const TAG_NONE: u8 = 0;
+const TAG_ZSTD: u8 = 1;
+const TAG_LZ4: u8 = 2;
+const TAG_GZIP: u8 = 3;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+const LZ4_MAGIC: &[u8] = &[0x04, 0x22, 0x4d, 0x18];
+
+/// Compresses `bytes` with the given algorithm and prefixes the result with its tag.
+///
+/// # Example
+/// ```
+/// use supplier_kit::compression::{compress, decompress, Compression};
+/// let packed = compress(b"catalog", Compression::None).unwrap();
+/// assert_eq!(decompress(&packed).unwrap(), b"catalog");
+/// ```
+pub fn compress(bytes: &[u8], compression: Compression) -> Result<Vec<u8>, SupplierError> {
+    match compression {
+        Compression::None => Ok(tagged(TAG_NONE, bytes.to_vec())),
+        #[cfg(feature = "zstd")]
+        Compression::Zstd(level) => zstd::encode_all(bytes, level)
+            .map(|body| tagged(TAG_ZSTD, body))
+            .map_err(|e| SupplierError::Internal(format!("zstd compression failed: {}", e))),
+        #[cfg(feature = "lz4")]
+        Compression::Lz4 => Ok(tagged(TAG_LZ4, lz4_flex::compress_prepend_size(bytes))),
+        #[cfg(feature = "gzip")]
+        Compression::Gzip(level) => gzip(bytes, level).map(|body| tagged(TAG_GZIP, body)),
+    }
+}
+
+/// Decompresses a payload produced by [`compress`], whatever algorithm was used.
+///
+/// # Returns
+/// `Err(SupplierError::InvalidInput)` if the payload is empty, corrupt, or was compressed
+/// with an algorithm whose feature is not enabled in this build.
+pub fn decompress(bytes: &[u8]) -> Result<Vec<u8>, SupplierError> {
+    let (&tag, body) = bytes
+        .split_first()
+        .ok_or_else(|| SupplierError::InvalidInput("empty compressed payload".into()))?;
+
+    match tag {
+        TAG_NONE => Ok(body.to_vec()),
+        #[cfg(feature = "zstd")]
+        TAG_ZSTD => zstd::decode_all(body)
+            .map_err(|e| SupplierError::InvalidInput(format!("corrupt zstd payload: {}", e))),
+        #[cfg(feature = "lz4")]
+        TAG_LZ4 => lz4_flex::decompress_size_prepended(body)
+            .map_err(|e| SupplierError::InvalidInput(format!("corrupt lz4 payload: {}", e))),
+        #[cfg(feature = "gzip")]
+        TAG_GZIP => read_all(flate2::read::MultiGzDecoder::new(body), None, "gzip"),
+        #[cfg(not(feature = "zstd"))]
+        TAG_ZSTD => Err(feature_disabled("zstd")),
+        #[cfg(not(feature = "lz4"))]
+        TAG_LZ4 => Err(feature_disabled("lz4")),
+        #[cfg(not(feature = "gzip"))]
+        TAG_GZIP => Err(feature_disabled("gzip")),
+        other => Err(SupplierError::InvalidInput(format!("unknown compression tag {}", other))),
+    }
+}
+
+/// Serializes `value` as JSON and compresses it.
+///
+/// This is the format of compressed entries of the response cache. Queue payloads and replay
+/// recordings use [`compress_stream`] instead; registry snapshots and audit logs are not
+/// compressed.
+///
+/// # Example
+/// ```
+/// use supplier_kit::compression::{from_compressed_json, to_compressed_json, Compression};
+/// use supplier_kit::models::SupplierResponse;
+///
+/// let response = SupplierResponse::new(serde_json::json!({ "sku": "A-1" }));
+/// let packed = to_compressed_json(&response, Compression::None).unwrap();
+/// let unpacked: SupplierResponse = from_compressed_json(&packed).unwrap();
+/// assert_eq!(unpacked, response);
+/// ```
+pub fn to_compressed_json<T: Serialize>(value: &T, compression: Compression) -> Result<Vec<u8>, SupplierError> {
+    let json = serde_json::to_vec(value)
+        .map_err(|e| SupplierError::Internal(format!("serialization failed: {}", e)))?;
+    compress(&json, compression)
+}
+
+/// Decompresses a payload and deserializes it from JSON.
+pub fn from_compressed_json<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, SupplierError> {
+    let json = decompress(bytes)?;
+    serde_json::from_slice(&json).map_err(|e| SupplierError::InvalidInput(format!("invalid JSON payload: {}", e)))
+}
+
+/// Compresses `bytes` as a standard stream of the algorithm, without a tag: a gzip member, a
+/// zstd frame or an LZ4 frame. `Compression::None` returns the bytes unchanged.
+///
+/// Unlike [`compress`], the output can be read by other tools (`zcat`, `zstdcat`, `lz4cat`),
+/// and streams written one after another still form a valid stream, so compressed files can
+/// be appended to. Use [`inflate`] to read them back.
+///
+/// # Example
+/// ```
+/// use supplier_kit::compression::{compress_stream, inflate, Compression};
+/// let stream = compress_stream(b"catalog", Compression::None).unwrap();
+/// assert_eq!(inflate(&stream, None).unwrap().as_ref(), b"catalog");
+/// ```
+pub fn compress_stream(bytes: &[u8], compression: Compression) -> Result<Vec<u8>, SupplierError> {
+    match compression {
+        Compression::None => Ok(bytes.to_vec()),
+        #[cfg(feature = "zstd")]
+        Compression::Zstd(level) => zstd::encode_all(bytes, level)
+            .map_err(|e| SupplierError::Internal(format!("zstd compression failed: {}", e))),
+        #[cfg(feature = "lz4")]
+        Compression::Lz4 => {
+            use std::io::Write;
+            let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+            encoder
+                .write_all(bytes)
+                .map_err(|e| SupplierError::Internal(format!("lz4 compression failed: {}", e)))?;
+            encoder
+                .finish()
+                .map_err(|e| SupplierError::Internal(format!("lz4 compression failed: {}", e)))
+        }
+        #[cfg(feature = "gzip")]
+        Compression::Gzip(level) => gzip(bytes, level),
+    }
+}
+
+/// Decompresses a gzip, zstd or LZ4 stream, recognised by its magic number; any other payload,
+/// such as plain JSON, is returned unchanged without copying.
+///
+/// Concatenated streams are decompressed as a whole. `max_len` bounds the decompressed size,
+/// so a small compressed reply cannot expand into gigabytes of memory.
+///
+/// # Returns
+/// `Err(SupplierError::ResponseTooLarge)` if the decompressed payload exceeds `max_len`, and
+/// `Err(SupplierError::InvalidInput)` if the stream is corrupt or its algorithm's feature is
+/// not enabled in this build.
+#[cfg_attr(not(any(feature = "zstd", feature = "lz4", feature = "gzip")), allow(unused_variables))]
+pub fn inflate(bytes: &[u8], max_len: Option<usize>) -> Result<Cow<'_, [u8]>, SupplierError> {
+    if bytes.starts_with(GZIP_MAGIC) {
+        #[cfg(feature = "gzip")]
+        return read_all(flate2::read::MultiGzDecoder::new(bytes), max_len, "gzip").map(Cow::Owned);
+        #[cfg(not(feature = "gzip"))]
+        return Err(feature_disabled("gzip"));
+    }
+    if bytes.starts_with(ZSTD_MAGIC) {
+        #[cfg(feature = "zstd")]
+        {
+            let decoder = zstd::stream::read::Decoder::new(bytes)
+                .map_err(|e| SupplierError::InvalidInput(format!("corrupt zstd payload: {}", e)))?;
+            return read_all(decoder, max_len, "zstd").map(Cow::Owned);
+        }
+        #[cfg(not(feature = "zstd"))]
+        return Err(feature_disabled("zstd"));
+    }
+    if bytes.starts_with(LZ4_MAGIC) {
+        #[cfg(feature = "lz4")]
+        return read_all(Lz4Frames { rest: bytes, decoder: None }, max_len, "lz4").map(Cow::Owned);
+        #[cfg(not(feature = "lz4"))]
+        return Err(feature_disabled("lz4"));
+    }
+    Ok(Cow::Borrowed(bytes))
+}
+
+/// Reads a decoder to the end, failing once more than `max_len` bytes come out.
+#[cfg(any(feature = "zstd", feature = "lz4", feature = "gzip"))]
+fn read_all<R: std::io::Read>(decoder: R, max_len: Option<usize>, algorithm: &str) -> Result<Vec<u8>, SupplierError> {
+    use std::io::Read;
+    let mut out = Vec::new();
+    let limit = max_len.map_or(u64::MAX, |max| max as u64 + 1);
+    decoder
+        .take(limit)
+        .read_to_end(&mut out)
+        .map_err(|e| SupplierError::InvalidInput(format!("corrupt {} payload: {}", algorithm, e)))?;
+    if let Some(max) = max_len
+        && out.len() > max
+    {
+        return Err(SupplierError::ResponseTooLarge(format!(
+            "decompressed payload exceeds the limit of {} bytes",
+            max
+        )));
+    }
+    Ok(out)
+}
+
+/// Reads concatenated LZ4 frames, which `lz4_flex::frame::FrameDecoder` stops after the first of.
+#[cfg(feature = "lz4")]
+struct Lz4Frames<'a> {
+    rest: &'a [u8],
+    decoder: Option<lz4_flex::frame::FrameDecoder<&'a [u8]>>,
+}
+
+#[cfg(feature = "lz4")]
+impl std::io::Read for Lz4Frames<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            if let Some(decoder) = &mut self.decoder {
+                let read = decoder.read(buf)?;
+                if read > 0 || buf.is_empty() {
+                    return Ok(read);
+                }
+                self.rest = self.decoder.take().map(|d| d.into_inner()).unwrap_or_default();
+            }
+            if self.rest.is_empty() {
+                return Ok(0);
+            }
+            self.decoder = Some(lz4_flex::frame::FrameDecoder::new(self.rest));
+        }
+    }
+}
+
+#[cfg(feature = "gzip")]
+fn gzip(bytes: &[u8], level: u32) -> Result<Vec<u8>, SupplierError> {
+    use std::io::Write;
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::new(level.min(9)));
+    encoder
+        .write_all(bytes)
+        .and_then(|_| encoder.finish())
+        .map_err(|e| SupplierError::Internal(format!("gzip compression failed: {}", e)))
+}
+
+#[cfg(any(not(feature = "zstd"), not(feature = "lz4"), not(feature = "gzip")))]
+fn feature_disabled(feature: &str) -> SupplierError {
+    SupplierError::InvalidInput(format!(
+        "payload is {}-compressed but the `{}` feature is not enabled",
+        feature, feature
+    ))
+}
+
+fn tagged(tag: u8, body: Vec<u8>) -> Vec<u8> {
+    let mut out = Vec::with_capacity(body.len() + 1);
+    out.push(tag);
+    out.extend(body);
+    out
+}
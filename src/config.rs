@@ -0,0 +1,439 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::conversion::ValueConverter;
+use crate::decorators::size_limit::SizeLimitedSupplier;
+use crate::descriptor::OperationDescriptor;
+use crate::errors::SupplierError;
+use crate::mapping::{MappingSpec, NormalizingSupplier};
+use crate::models::SupplierOperation;
+use crate::routing::{RoutingRule, RoutingRules};
+use crate::secrets::{references_secrets, resolve_secrets, SecretsProvider};
+use crate::supplier::{Supplier, SupplierRegistry};
+use crate::supplier_group::{BasicSupplierGroup, QueryStrategy};
+use crate::testing::mock::MockSupplierBuilder;
+use crate::testing::replay::{read_exchanges, ReplaySupplier};
+
+/// A declarative description of suppliers and groups, usually loaded from a JSON file.
+///
+/// ```json
+/// {
+///   "suppliers": [
+///     { "name": "shop_a", "type": "replay", "config": { "path": "recordings/shop_a.ndjson" } },
+///     { "name": "shop_b", "type": "mock", "config": { "responses": { "search": [] } } }
+///   ],
+///   "groups": [
+///     { "name": "marketplaces", "suppliers": ["shop_a", "shop_b"], "strategy": "parallel" }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Manifest {
+    /// The suppliers to construct, in registration order.
+    #[serde(default)]
+    pub suppliers: Vec<SupplierSpec>,
+
+    /// The groups to assemble from the suppliers.
+    #[serde(default)]
+    pub groups: Vec<GroupSpec>,
+
+    /// Directory that relative paths in supplier configs are resolved against.
+    ///
+    /// Set by [`Manifest::from_file`] to the manifest's directory; defaults to the working directory.
+    #[serde(skip)]
+    pub base_dir: PathBuf,
+}
+
+/// One supplier entry of a [`Manifest`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SupplierSpec {
+    /// The name the supplier is registered under.
+    pub name: String,
+
+    /// The constructor to use, as registered in the [`SupplierFactory`].
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    /// Constructor-specific settings.
+    #[serde(default)]
+    pub config: Value,
+
+    /// Maximum size of the supplier's responses in bytes of JSON; larger responses fail with
+    /// `SupplierError::ResponseTooLarge` (see `SizeLimitedSupplier`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_response_bytes: Option<usize>,
+
+    /// Field mapping applied to the supplier's responses (see `MappingSpec`), so mappings can be
+    /// maintained in the manifest instead of in code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mapping: Option<MappingSpec>,
+}
+
+/// One group entry of a [`Manifest`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GroupSpec {
+    /// The group name.
+    pub name: String,
+
+    /// Names of the member suppliers, in query order.
+    pub suppliers: Vec<String>,
+
+    /// How the group runs its queries.
+    #[serde(default)]
+    pub strategy: QueryStrategy,
+
+    /// Maximum number of suppliers queried concurrently.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrency: Option<usize>,
+
+    /// Content-based routing rules, tried in order; see `RoutingRules`.
+    ///
+    /// ```json
+    /// { "suppliers": ["book_depot"], "operations": ["search"],
+    ///   "conditions": [{ "path": "$.category", "equals": "books" }] }
+    /// ```
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub routes: Vec<RoutingRule>,
+}
+
+impl Manifest {
+    /// Parses a manifest from JSON.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the JSON does not describe a manifest.
+    pub fn from_json(json: &str) -> Result<Self, SupplierError> {
+        serde_json::from_str(json).map_err(|e| SupplierError::InvalidInput(format!("invalid manifest: {}", e)))
+    }
+
+    /// Reads a manifest from a JSON file; relative paths in it resolve against the file's directory.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the file cannot be read or parsed.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, SupplierError> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)
+            .map_err(|e| SupplierError::InvalidInput(format!("cannot read manifest '{}': {}", path.display(), e)))?;
+        let mut manifest = Self::from_json(&json)
+            .map_err(|e| SupplierError::InvalidInput(format!("{} ({})", e, path.display())))?;
+        manifest.base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        Ok(manifest)
+    }
+}
+
+/// Information available to supplier constructors besides the supplier's own spec.
+#[derive(Clone, Default)]
+pub struct BuildContext {
+    /// Directory that relative paths are resolved against.
+    pub base_dir: PathBuf,
+
+    /// Provider resolving `{ "$secret": "<name>" }` references in supplier configs.
+    pub secrets: Option<Arc<dyn SecretsProvider>>,
+}
+
+impl fmt::Debug for BuildContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BuildContext")
+            .field("base_dir", &self.base_dir)
+            .field("secrets", &self.secrets.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+impl BuildContext {
+    /// Resolves `path` against the base directory unless it is absolute.
+    pub fn resolve<P: AsRef<Path>>(&self, path: P) -> PathBuf {
+        self.base_dir.join(path)
+    }
+
+    /// Returns the secret called `name`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if no provider is configured or it does not define
+    /// the secret, or the provider's error if it cannot read it.
+    pub fn secret(&self, name: &str) -> Result<String, SupplierError> {
+        let provider = self.secrets.as_ref().ok_or_else(|| {
+            SupplierError::InvalidInput(format!("secret '{}' is referenced but no secrets provider is configured", name))
+        })?;
+        provider
+            .secret(name)?
+            .ok_or_else(|| SupplierError::InvalidInput(format!("secret '{}' is not defined", name)))
+    }
+}
+
+/// Builds a supplier from its manifest entry.
+pub type SupplierConstructor =
+    Arc<dyn Fn(&SupplierSpec, &BuildContext) -> Result<Arc<dyn Supplier>, SupplierError> + Send + Sync>;
+
+/// The suppliers and groups built from a [`Manifest`].
+pub struct LoadedManifest {
+    /// Every supplier, registered under its manifest name.
+    pub registry: SupplierRegistry,
+
+    /// Every group, keyed by name.
+    pub groups: BTreeMap<String, BasicSupplierGroup>,
+}
+
+/// Turns manifests into suppliers and groups using constructors registered per supplier `type`.
+///
+/// `SupplierFactory::new()` comes with two built-in types:
+/// - `mock`: answers from `config.responses` (operation name to data), falling back to
+///   `config.default`; unknown operations fail with `UnsupportedOperation`. `config.delay_ms`
+///   adds a fixed latency, `config.operations` lists `OperationDescriptor`s for `describe` and
+///   `config.locales` the locales it declares.
+/// - `replay`: replays the exchanges recorded for `config.supplier` (default: the manifest name)
+///   from the NDJSON file at `config.path` (see `ReplaySupplier`), with optional
+///   `config.latency_scale`.
+///
+/// # Example
+/// ```
+/// use supplier_kit::config::{Manifest, SupplierFactory};
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier_group::SupplierGroup;
+///
+/// let manifest = Manifest::from_json(r#"{
+///     "suppliers": [
+///         { "name": "shop_a", "type": "mock", "config": { "responses": { "search": ["lamp"] } } },
+///         { "name": "shop_b", "type": "mock", "config": { "default": [] } }
+///     ],
+///     "groups": [{ "name": "marketplaces", "suppliers": ["shop_a", "shop_b"], "strategy": "parallel" }]
+/// }"#).unwrap();
+///
+/// let loaded = SupplierFactory::new().load(&manifest).unwrap();
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({ "q": "lamp" }));
+/// let result = loaded.groups["marketplaces"].query(request);
+/// assert_eq!(result.successes.len(), 2);
+/// ```
+pub struct SupplierFactory {
+    constructors: HashMap<String, SupplierConstructor>,
+    secrets: Option<Arc<dyn SecretsProvider>>,
+    converters: HashMap<String, Arc<dyn ValueConverter>>,
+}
+
+impl Default for SupplierFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SupplierFactory {
+    /// Creates a factory with the built-in `mock` and `replay` types.
+    pub fn new() -> Self {
+        Self::empty().register("mock", build_mock).register("replay", build_replay)
+    }
+
+    /// Creates a factory without any supplier types.
+    pub fn empty() -> Self {
+        Self {
+            constructors: HashMap::new(),
+            secrets: None,
+            converters: HashMap::new(),
+        }
+    }
+
+    /// Registers the constructor for supplier `kind`, replacing any previous one.
+    pub fn register<F>(mut self, kind: &str, constructor: F) -> Self
+    where
+        F: Fn(&SupplierSpec, &BuildContext) -> Result<Arc<dyn Supplier>, SupplierError> + Send + Sync + 'static,
+    {
+        self.constructors.insert(kind.to_string(), Arc::new(constructor));
+        self
+    }
+
+    /// Resolves secret references in the configs of manifests loaded with [`SupplierFactory::load`].
+    pub fn with_secrets(mut self, provider: Arc<dyn SecretsProvider>) -> Self {
+        self.secrets = Some(provider);
+        self
+    }
+
+    /// Registers `converter` under `name` for the `convert` entries of supplier mappings, e.g. a
+    /// `CurrencyConverter` backed by the live rates of the deployment.
+    pub fn with_converter<C: ValueConverter + 'static>(mut self, name: &str, converter: C) -> Self {
+        self.converters.insert(name.to_string(), Arc::new(converter));
+        self
+    }
+
+    /// Returns the registered supplier types, sorted.
+    pub fn kinds(&self) -> Vec<String> {
+        let mut kinds: Vec<String> = self.constructors.keys().cloned().collect();
+        kinds.sort();
+        kinds
+    }
+
+    /// Builds a single supplier.
+    ///
+    /// Every `{ "$secret": "<name>" }` object in the spec's config is replaced with the secret
+    /// from `context.secrets` before the constructor runs, so manifests never hold credentials.
+    /// A `max_response_bytes` limit wraps the built supplier in a `SizeLimitedSupplier`, and a
+    /// `mapping` in a `NormalizingSupplier` applied to the responses that passed the limit.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the type is unknown, a referenced secret cannot
+    /// be resolved, or the mapping does not compile or names an unregistered converter;
+    /// otherwise the constructor's error.
+    pub fn build(&self, spec: &SupplierSpec, context: &BuildContext) -> Result<Arc<dyn Supplier>, SupplierError> {
+        let constructor = self.constructors.get(&spec.kind).ok_or_else(|| {
+            SupplierError::InvalidInput(format!("supplier '{}' has unknown type '{}'", spec.name, spec.kind))
+        })?;
+        let mapping = spec
+            .mapping
+            .as_ref()
+            .map(|mapping| {
+                let compiled = self
+                    .converters
+                    .iter()
+                    .fold(mapping.compile()?, |compiled, (name, converter)| {
+                        compiled.with_shared_converter(name, converter.clone())
+                    });
+                compiled.validate().map(|_| compiled)
+            })
+            .transpose()
+            .map_err(|e| match e {
+                SupplierError::InvalidInput(reason) => invalid_config(spec, &reason),
+                other => other,
+            })?;
+        let supplier = if references_secrets(&spec.config) {
+            let provider = context.secrets.as_ref().ok_or_else(|| {
+                invalid_config(spec, "config references secrets but no secrets provider is configured")
+            })?;
+            let config = resolve_secrets(&spec.config, provider.as_ref()).map_err(|e| match e {
+                SupplierError::InvalidInput(reason) => invalid_config(spec, &reason),
+                other => other,
+            })?;
+            let resolved = SupplierSpec {
+                config,
+                ..spec.clone()
+            };
+            constructor(&resolved, context)?
+        } else {
+            constructor(spec, context)?
+        };
+        let supplier: Arc<dyn Supplier> = match spec.max_response_bytes {
+            Some(limit) => Arc::new(SizeLimitedSupplier::new(supplier, limit)),
+            None => supplier,
+        };
+        Ok(match mapping {
+            Some(mapping) => Arc::new(NormalizingSupplier::new(supplier, mapping)),
+            None => supplier,
+        })
+    }
+
+    /// Builds every supplier and group of `manifest`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` for duplicate supplier or group names, groups referring
+    /// to unknown suppliers, routes to non-members or with invalid paths, and unknown supplier
+    /// types; constructor errors are returned unchanged.
+    pub fn load(&self, manifest: &Manifest) -> Result<LoadedManifest, SupplierError> {
+        let context = BuildContext {
+            base_dir: manifest.base_dir.clone(),
+            secrets: self.secrets.clone(),
+        };
+
+        let mut registry = SupplierRegistry::new();
+        let mut built: HashMap<&str, Arc<dyn Supplier>> = HashMap::new();
+        for spec in &manifest.suppliers {
+            if built.contains_key(spec.name.as_str()) {
+                return Err(SupplierError::InvalidInput(format!("supplier '{}' is declared twice", spec.name)));
+            }
+            let supplier = self.build(spec, &context)?;
+            registry.register(&spec.name, supplier.clone());
+            built.insert(&spec.name, supplier);
+        }
+
+        let mut groups = BTreeMap::new();
+        let mut names = HashSet::new();
+        for spec in &manifest.groups {
+            if !names.insert(spec.name.as_str()) {
+                return Err(SupplierError::InvalidInput(format!("group '{}' is declared twice", spec.name)));
+            }
+            let mut group = BasicSupplierGroup::new(&spec.name).with_strategy(spec.strategy);
+            if let Some(max) = spec.max_concurrency {
+                group = group.with_max_concurrency(max);
+            }
+            for member in &spec.suppliers {
+                let supplier = built.get(member.as_str()).ok_or_else(|| {
+                    SupplierError::InvalidInput(format!("group '{}' refers to unknown supplier '{}'", spec.name, member))
+                })?;
+                group.add_supplier_arc(supplier.clone());
+            }
+            if !spec.routes.is_empty() {
+                for rule in &spec.routes {
+                    rule.validate().map_err(|e| match e {
+                        SupplierError::InvalidInput(reason) => SupplierError::InvalidInput(format!("group '{}': {}", spec.name, reason)),
+                        other => other,
+                    })?;
+                    if let Some(unknown) = rule.suppliers.iter().find(|s| !spec.suppliers.contains(s)) {
+                        return Err(SupplierError::InvalidInput(format!(
+                            "group '{}' routes to '{}', which is not a member",
+                            spec.name, unknown
+                        )));
+                    }
+                }
+                group = group.with_routing(RoutingRules::new(spec.routes.clone()));
+            }
+            groups.insert(spec.name.clone(), group);
+        }
+
+        Ok(LoadedManifest { registry, groups })
+    }
+}
+
+fn build_mock(spec: &SupplierSpec, _context: &BuildContext) -> Result<Arc<dyn Supplier>, SupplierError> {
+    let mut builder = MockSupplierBuilder::new(&spec.name);
+    if let Some(responses) = spec.config.get("responses") {
+        let responses = responses.as_object().ok_or_else(|| invalid_config(spec, "'responses' must be an object"))?;
+        for (operation, data) in responses {
+            builder = builder.respond(SupplierOperation::from(operation.as_str()), data.clone());
+        }
+    }
+    if let Some(data) = spec.config.get("default") {
+        builder = builder.respond_default(data.clone());
+    }
+    if let Some(operations) = spec.config.get("operations") {
+        let operations: Vec<OperationDescriptor> = serde_json::from_value(operations.clone())
+            .map_err(|e| invalid_config(spec, &format!("invalid 'operations': {}", e)))?;
+        for operation in operations {
+            builder = builder.describe_operation(operation);
+        }
+    }
+    if let Some(locales) = spec.config.get("locales") {
+        let locales: Vec<String> = serde_json::from_value(locales.clone())
+            .map_err(|_| invalid_config(spec, "'locales' must be an array of strings"))?;
+        for locale in locales {
+            builder = builder.with_locale(&locale);
+        }
+    }
+    if let Some(delay) = spec.config.get("delay_ms") {
+        let delay = delay.as_u64().ok_or_else(|| invalid_config(spec, "'delay_ms' must be a number"))?;
+        builder = builder.with_delay(Duration::from_millis(delay));
+    }
+    Ok(Arc::new(builder.build()))
+}
+
+fn build_replay(spec: &SupplierSpec, context: &BuildContext) -> Result<Arc<dyn Supplier>, SupplierError> {
+    let path = spec
+        .config
+        .get("path")
+        .and_then(Value::as_str)
+        .ok_or_else(|| invalid_config(spec, "'path' is required"))?;
+    let path = context.resolve(path);
+    let recorded = spec.config.get("supplier").and_then(Value::as_str).unwrap_or(&spec.name);
+    let exchanges = read_exchanges(&path)
+        .map_err(|e| invalid_config(spec, &format!("cannot read '{}': {}", path.display(), e)))?
+        .into_iter()
+        .filter(|exchange| exchange.supplier == recorded);
+    let mut supplier = ReplaySupplier::new(&spec.name, exchanges);
+    if let Some(scale) = spec.config.get("latency_scale").and_then(Value::as_f64) {
+        supplier = supplier.with_simulated_latency(scale);
+    }
+    Ok(Arc::new(supplier))
+}
+
+fn invalid_config(spec: &SupplierSpec, reason: &str) -> SupplierError {
+    SupplierError::InvalidInput(format!("supplier '{}' ({}): {}", spec.name, spec.kind, reason))
+}
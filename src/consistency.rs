@@ -0,0 +1,284 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::supplier_group::SupplierGroupResult;
+
+/// A place where two JSON values disagree.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Divergence {
+    /// JSON pointer of the disagreeing value; empty for the root.
+    pub path: String,
+
+    /// The value on the left side, or `None` if the left side has nothing at `path`.
+    pub left: Option<Value>,
+
+    /// The value on the right side, or `None` if the right side has nothing at `path`.
+    pub right: Option<Value>,
+}
+
+/// How one supplier's response differs from the reference response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SupplierDivergence {
+    /// The supplier name.
+    pub supplier: String,
+
+    /// The divergences, with the reference on the left and this supplier on the right.
+    pub divergences: Vec<Divergence>,
+}
+
+/// The outcome of comparing the responses several suppliers returned for the same request.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConsistencyReport {
+    /// The supplier whose response the others were compared against: the one agreeing with
+    /// the most other suppliers, the earliest on ties. `None` if no supplier succeeded.
+    pub reference: Option<String>,
+
+    /// The suppliers that agree with the reference, including the reference itself.
+    pub agreeing: Vec<String>,
+
+    /// The suppliers that disagree with the reference, and where.
+    pub divergent: Vec<SupplierDivergence>,
+
+    /// The suppliers that failed and could not be compared.
+    pub failed: Vec<String>,
+}
+
+impl ConsistencyReport {
+    /// Returns whether every successful supplier agrees with the reference.
+    pub fn is_consistent(&self) -> bool {
+        self.divergent.is_empty()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Rule {
+    Ignore,
+    AbsoluteTolerance(f64),
+    RelativeTolerance(f64),
+    Unordered,
+}
+
+/// Compares JSON responses structurally, with tolerance rules for expected differences.
+///
+/// Rules target JSON pointers in which a `*` segment matches any single key or array index,
+/// e.g. `/offers/*/price`. [`ConsistencyChecker::ignore`] also covers everything below the
+/// pointer; the other rules apply exactly at the matching paths. When several rules match a
+/// path, the one added first wins.
+///
+/// Without rules, any difference in value, type, object keys or array length diverges.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::consistency::ConsistencyChecker;
+///
+/// let checker = ConsistencyChecker::new()
+///     .ignore("/fetched_at")
+///     .absolute_tolerance("/offers/*/price", 0.01)
+///     .unordered("/tags");
+///
+/// let a = json!({ "fetched_at": 1, "tags": ["new", "sale"], "offers": [{ "price": 9.99, "stock": 3 }] });
+/// let b = json!({ "fetched_at": 2, "tags": ["sale", "new"], "offers": [{ "price": 10.0, "stock": 4 }] });
+///
+/// let divergences = checker.compare(&a, &b);
+/// assert_eq!(divergences.len(), 1);
+/// assert_eq!(divergences[0].path, "/offers/0/stock");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConsistencyChecker {
+    rules: Vec<(Vec<String>, Rule)>,
+}
+
+impl ConsistencyChecker {
+    /// Creates a checker without tolerance rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ignores every difference at or below the matching paths, e.g. timestamps or trace IDs.
+    pub fn ignore(self, pointer: &str) -> Self {
+        self.rule(pointer, Rule::Ignore)
+    }
+
+    /// Treats numbers at the matching paths as equal when they differ by at most `tolerance`.
+    pub fn absolute_tolerance(self, pointer: &str, tolerance: f64) -> Self {
+        self.rule(pointer, Rule::AbsoluteTolerance(tolerance))
+    }
+
+    /// Treats numbers at the matching paths as equal when they differ by at most `ratio`
+    /// of the larger magnitude, e.g. `0.02` for 2%.
+    pub fn relative_tolerance(self, pointer: &str, ratio: f64) -> Self {
+        self.rule(pointer, Rule::RelativeTolerance(ratio))
+    }
+
+    /// Compares arrays at the matching paths as multisets, ignoring element order.
+    ///
+    /// Elements are matched with the checker's rules; if the arrays cannot be matched
+    /// element for element, the whole array diverges.
+    pub fn unordered(self, pointer: &str) -> Self {
+        self.rule(pointer, Rule::Unordered)
+    }
+
+    fn rule(mut self, pointer: &str, rule: Rule) -> Self {
+        self.rules.push((segments(pointer), rule));
+        self
+    }
+
+    /// Returns where `left` and `right` disagree, in document order.
+    pub fn compare(&self, left: &Value, right: &Value) -> Vec<Divergence> {
+        let mut divergences = Vec::new();
+        self.diff(&mut Vec::new(), left, right, &mut divergences);
+        divergences
+    }
+
+    /// Returns whether `left` and `right` agree.
+    pub fn agrees(&self, left: &Value, right: &Value) -> bool {
+        self.compare(left, right).is_empty()
+    }
+
+    /// Compares the response data of every successful supplier in `result`.
+    ///
+    /// The reference is the response agreeing with the most other responses, so a single
+    /// disagreeing supplier among three is reported as the divergent one.
+    pub fn check(&self, result: &SupplierGroupResult) -> ConsistencyReport {
+        let successes = &result.successes;
+        let mut report = ConsistencyReport {
+            failed: result.failures.iter().map(|(name, _)| name.clone()).collect(),
+            ..ConsistencyReport::default()
+        };
+
+        let mut best: Option<(usize, usize)> = None;
+        for (i, (_, left)) in successes.iter().enumerate() {
+            let votes = successes
+                .iter()
+                .enumerate()
+                .filter(|(j, (_, right))| i != *j && self.agrees(&left.data, &right.data))
+                .count();
+            if best.is_none_or(|(_, most)| votes > most) {
+                best = Some((i, votes));
+            }
+        }
+        let Some((reference, _)) = best else { return report };
+
+        let (reference_name, reference_response) = &successes[reference];
+        report.reference = Some(reference_name.clone());
+        for (name, response) in successes {
+            let divergences = self.compare(&reference_response.data, &response.data);
+            if divergences.is_empty() {
+                report.agreeing.push(name.clone());
+            } else {
+                report.divergent.push(SupplierDivergence {
+                    supplier: name.clone(),
+                    divergences,
+                });
+            }
+        }
+        report
+    }
+
+    fn rule_at(&self, path: &[String]) -> Option<&Rule> {
+        self.rules.iter().find_map(|(pattern, rule)| {
+            let matches = match rule {
+                Rule::Ignore => pattern.len() <= path.len() && matches_segments(pattern, &path[..pattern.len()]),
+                _ => matches_segments(pattern, path),
+            };
+            matches.then_some(rule)
+        })
+    }
+
+    fn diff(&self, path: &mut Vec<String>, left: &Value, right: &Value, out: &mut Vec<Divergence>) {
+        let rule = self.rule_at(path);
+        match (rule, left, right) {
+            (Some(Rule::Ignore), _, _) => {}
+            (Some(Rule::AbsoluteTolerance(tolerance)), Value::Number(l), Value::Number(r)) => {
+                let (l, r) = (l.as_f64().unwrap_or_default(), r.as_f64().unwrap_or_default());
+                if (l - r).abs() > *tolerance {
+                    out.push(divergence(path, Some(left), Some(right)));
+                }
+            }
+            (Some(Rule::RelativeTolerance(ratio)), Value::Number(l), Value::Number(r)) => {
+                let (l, r) = (l.as_f64().unwrap_or_default(), r.as_f64().unwrap_or_default());
+                if (l - r).abs() > ratio * l.abs().max(r.abs()) {
+                    out.push(divergence(path, Some(left), Some(right)));
+                }
+            }
+            (Some(Rule::Unordered), Value::Array(l), Value::Array(r)) => {
+                let same = self.same_elements(path, l, r);
+                if !same {
+                    out.push(divergence(path, Some(left), Some(right)));
+                }
+            }
+            (_, Value::Object(l), Value::Object(r)) => {
+                let mut keys: Vec<&String> = l.keys().chain(r.keys().filter(|k| !l.contains_key(*k))).collect();
+                keys.sort();
+                for key in keys {
+                    path.push(key.clone());
+                    match (l.get(key), r.get(key)) {
+                        (Some(l), Some(r)) => self.diff(path, l, r, out),
+                        (l, r) => {
+                            if !matches!(self.rule_at(path), Some(Rule::Ignore)) {
+                                out.push(divergence(path, l, r));
+                            }
+                        }
+                    }
+                    path.pop();
+                }
+            }
+            (_, Value::Array(l), Value::Array(r)) if l.len() == r.len() => {
+                for (index, (l, r)) in l.iter().zip(r).enumerate() {
+                    path.push(index.to_string());
+                    self.diff(path, l, r, out);
+                    path.pop();
+                }
+            }
+            _ if left != right => out.push(divergence(path, Some(left), Some(right))),
+            _ => {}
+        }
+    }
+
+    /// Returns whether every element of `left` can be paired with an agreeing element of `right`.
+    fn same_elements(&self, path: &mut Vec<String>, left: &[Value], right: &[Value]) -> bool {
+        if left.len() != right.len() {
+            return false;
+        }
+        let mut unmatched: Vec<&Value> = right.iter().collect();
+        for l in left {
+            path.push("*".to_string());
+            let found = unmatched.iter().position(|r| {
+                let mut divergences = Vec::new();
+                self.diff(path, l, r, &mut divergences);
+                divergences.is_empty()
+            });
+            path.pop();
+            match found {
+                Some(index) => {
+                    unmatched.swap_remove(index);
+                }
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+fn segments(pointer: &str) -> Vec<String> {
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|s| s.replace("~1", "/").replace("~0", "~"))
+        .collect()
+}
+
+fn matches_segments(pattern: &[String], path: &[String]) -> bool {
+    pattern.len() == path.len() && pattern.iter().zip(path).all(|(p, s)| p == "*" || p == s)
+}
+
+fn divergence(path: &[String], left: Option<&Value>, right: Option<&Value>) -> Divergence {
+    Divergence {
+        path: path
+            .iter()
+            .map(|s| format!("/{}", s.replace('~', "~0").replace('/', "~1")))
+            .collect(),
+        left: left.cloned(),
+        right: right.cloned(),
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::BTreeSet;
+use serde::{Deserialize, Serialize};
+use crate::execution::QueryMemo;
+use crate::id::IdGenerator;
+
+/// How important a request is, used to decide what to drop under load.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+    /// Work that can be dropped first, such as prefetching or background refreshes.
+    Low,
+    /// Regular traffic; requests without a priority count as normal.
+    #[default]
+    Normal,
+    /// User-facing traffic that should survive moderate overload.
+    High,
+    /// Traffic that must never be shed, such as checkout.
+    Critical,
+}
+
+/// Cross-cutting information that travels with a `SupplierRequest`.
+///
+/// The context is not part of the operation's parameters; it carries identifiers
+/// and other metadata that decorators, groups, and suppliers may use.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RequestContext {
+    /// A unique identifier of the request, used for tracing and event correlation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+
+    /// The identity of the team or service making the call, checked by `AccessPolicy`.
+    ///
+    /// Gateways should set it from an authenticated identity rather than trusting clients.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caller: Option<String>,
+
+    /// The tenant the request is made for, used by `TenantRegistry` to pick the tenant's
+    /// suppliers and reported to metrics recorders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+
+    /// The priority of the request, checked by load shedding; `None` counts as `Priority::Normal`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Priority>,
+
+    /// The session the request belongs to, e.g. a checkout flow; groups with `SessionAffinity`
+    /// route all requests of a session to the same supplier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+
+    /// The preferred locale of the caller as a BCP 47 tag, such as `id-ID` or `pt-BR`.
+    ///
+    /// Groups pass every member the closest locale it declares in its descriptor (see
+    /// `SupplierDescriptor::locales`), and `MessageCatalog` uses it to translate failures.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+
+    /// Suppliers that fan-out queries must skip, e.g. stores the end user blocked.
+    ///
+    /// Groups leave them out like suppliers their routing rules do not select; requests
+    /// addressed to a supplier by name, as with `SupplierGroup::query_each`, still reach it.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub excluded_suppliers: BTreeSet<String>,
+
+    /// The results of the supplier calls made for the query serving the request, shared by the
+    /// nested groups and pipeline stages it reaches.
+    #[serde(skip)]
+    pub(crate) memo: Option<QueryMemo>,
+}
+
+impl RequestContext {
+    /// Creates an empty context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the context with the given request ID.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::context::RequestContext;
+    /// let context = RequestContext::new().with_request_id("req-42");
+    /// assert_eq!(context.request_id.as_deref(), Some("req-42"));
+    /// ```
+    pub fn with_request_id(mut self, request_id: &str) -> Self {
+        self.request_id = Some(request_id.to_string());
+        self
+    }
+
+    /// Returns the context with the given caller.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::context::RequestContext;
+    /// let context = RequestContext::new().with_caller("team-a");
+    /// assert_eq!(context.caller.as_deref(), Some("team-a"));
+    /// ```
+    pub fn with_caller(mut self, caller: &str) -> Self {
+        self.caller = Some(caller.to_string());
+        self
+    }
+
+    /// Returns the context with the given tenant.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::context::RequestContext;
+    /// let context = RequestContext::new().with_tenant("acme");
+    /// assert_eq!(context.tenant.as_deref(), Some("acme"));
+    /// ```
+    pub fn with_tenant(mut self, tenant: &str) -> Self {
+        self.tenant = Some(tenant.to_string());
+        self
+    }
+
+    /// Returns the context with the given priority.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::context::{Priority, RequestContext};
+    /// let context = RequestContext::new().with_priority(Priority::Low);
+    /// assert_eq!(context.priority(), Priority::Low);
+    /// assert_eq!(RequestContext::new().priority(), Priority::Normal);
+    /// ```
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Returns the context with the given session ID.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::context::RequestContext;
+    /// let context = RequestContext::new().with_session_id("checkout-7");
+    /// assert_eq!(context.session_id.as_deref(), Some("checkout-7"));
+    /// ```
+    pub fn with_session_id(mut self, session_id: &str) -> Self {
+        self.session_id = Some(session_id.to_string());
+        self
+    }
+
+    /// Returns the context with the given locale.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::context::RequestContext;
+    /// let context = RequestContext::new().with_locale("id-ID");
+    /// assert_eq!(context.locale.as_deref(), Some("id-ID"));
+    /// ```
+    pub fn with_locale(mut self, locale: &str) -> Self {
+        self.locale = Some(locale.to_string());
+        self
+    }
+
+    /// Returns the context with `supplier` added to the excluded suppliers.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::context::RequestContext;
+    /// let context = RequestContext::new().with_excluded_supplier("shop_b");
+    /// assert!(context.excludes("shop_b"));
+    /// assert!(!context.excludes("shop_a"));
+    /// ```
+    pub fn with_excluded_supplier(mut self, supplier: &str) -> Self {
+        self.excluded_suppliers.insert(supplier.to_string());
+        self
+    }
+
+    /// Returns whether fan-out queries of the request must skip `supplier`.
+    pub fn excludes(&self, supplier: &str) -> bool {
+        self.excluded_suppliers.contains(supplier)
+    }
+
+    /// Returns the priority of the request, `Priority::Normal` if none is set.
+    pub fn priority(&self) -> Priority {
+        self.priority.unwrap_or_default()
+    }
+
+    /// Assigns a request ID from `generator` unless one is already set, and returns it.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::context::RequestContext;
+    /// use supplier_kit::id::UuidV7Generator;
+    ///
+    /// let mut context = RequestContext::new();
+    /// let id = context.ensure_request_id(&UuidV7Generator).to_string();
+    /// assert_eq!(context.ensure_request_id(&UuidV7Generator), id);
+    /// ```
+    pub fn ensure_request_id(&mut self, generator: &dyn IdGenerator) -> &str {
+        self.request_id.get_or_insert_with(|| generator.generate())
+    }
+}
@@ -0,0 +1,447 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use serde_json::{Map, Number, Value};
+use crate::errors::SupplierError;
+use crate::json_path::JsonPath;
+use crate::mapping::ResponseNormalizer;
+use crate::models::SupplierResponse;
+
+/// Converts a value of a supplier response into its canonical form, e.g. a price into the
+/// reference currency or a weight into kilograms.
+///
+/// Converters are plugged into field mappings (`FieldMapping::with_converter`) or applied at
+/// paths of whole responses with [`Conversions`]. Closures taking a `&Value` are converters.
+pub trait ValueConverter: Send + Sync {
+    /// Returns the canonical form of `value`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::Upstream` if the value does not have the expected shape.
+    fn convert(&self, value: &Value) -> Result<Value, SupplierError>;
+}
+
+impl<F> ValueConverter for F
+where
+    F: Fn(&Value) -> Result<Value, SupplierError> + Send + Sync,
+{
+    fn convert(&self, value: &Value) -> Result<Value, SupplierError> {
+        self(value)
+    }
+}
+
+/// Provides exchange rates for a [`CurrencyConverter`], e.g. from a treasury service or a
+/// periodically refreshed rates table.
+pub trait RateSource: Send + Sync {
+    /// Returns how many units of currency `to` one unit of currency `from` is worth.
+    ///
+    /// Currency codes are passed in upper case, as in ISO 4217.
+    ///
+    /// # Errors
+    /// Returns an error if no rate is known for the pair.
+    fn rate(&self, from: &str, to: &str) -> Result<f64, SupplierError>;
+}
+
+/// A fixed table of exchange rates against a base currency.
+///
+/// # Example
+/// ```
+/// use supplier_kit::conversion::{FixedRates, RateSource};
+///
+/// let rates = FixedRates::new("USD").with_rate("EUR", 1.10).with_rate("GBP", 1.25);
+/// assert_eq!(rates.rate("EUR", "USD").unwrap(), 1.10);
+/// assert!((rates.rate("GBP", "EUR").unwrap() - 1.25 / 1.10).abs() < 1e-12);
+/// assert!(rates.rate("JPY", "USD").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedRates {
+    base: String,
+    rates: HashMap<String, f64>,
+}
+
+impl FixedRates {
+    /// Creates a table against the `base` currency.
+    pub fn new(base: &str) -> Self {
+        Self {
+            base: base.to_ascii_uppercase(),
+            rates: HashMap::new(),
+        }
+    }
+
+    /// Sets the value of one unit of `currency` in the base currency.
+    pub fn with_rate(mut self, currency: &str, value: f64) -> Self {
+        self.rates.insert(currency.to_ascii_uppercase(), value);
+        self
+    }
+
+    fn value(&self, currency: &str) -> Result<f64, SupplierError> {
+        if currency == self.base {
+            return Ok(1.0);
+        }
+        self.rates
+            .get(currency)
+            .copied()
+            .ok_or_else(|| SupplierError::Internal(format!("no exchange rate for '{}'", currency)))
+    }
+}
+
+impl RateSource for FixedRates {
+    fn rate(&self, from: &str, to: &str) -> Result<f64, SupplierError> {
+        Ok(self.value(from)? / self.value(to)?)
+    }
+}
+
+/// Converts prices into a target currency.
+///
+/// Prices are objects holding an amount and a currency code (by default under `amount` and
+/// `currency`); the amount may be a number or a numeric string. The converted price keeps its
+/// other members, and its amount is rounded to 2 decimal places unless configured otherwise.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::conversion::{CurrencyConverter, FixedRates, ValueConverter};
+///
+/// let converter = CurrencyConverter::new("USD", FixedRates::new("USD").with_rate("EUR", 1.1));
+/// assert_eq!(
+///     converter.convert(&json!({ "amount": "12.50", "currency": "eur", "tax_included": true })).unwrap(),
+///     json!({ "amount": 13.75, "currency": "USD", "tax_included": true })
+/// );
+/// ```
+#[derive(Clone)]
+pub struct CurrencyConverter {
+    target: String,
+    rates: Arc<dyn RateSource>,
+    amount_key: String,
+    currency_key: String,
+    precision: Option<u32>,
+}
+
+impl fmt::Debug for CurrencyConverter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CurrencyConverter")
+            .field("target", &self.target)
+            .field("amount_key", &self.amount_key)
+            .field("currency_key", &self.currency_key)
+            .field("precision", &self.precision)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CurrencyConverter {
+    /// Creates a converter into the `target` currency using `rates`.
+    pub fn new<R: RateSource + 'static>(target: &str, rates: R) -> Self {
+        Self::with_rate_source(target, Arc::new(rates))
+    }
+
+    /// Creates a converter into the `target` currency using a shared rate source.
+    pub fn with_rate_source(target: &str, rates: Arc<dyn RateSource>) -> Self {
+        Self {
+            target: target.to_ascii_uppercase(),
+            rates,
+            amount_key: "amount".to_string(),
+            currency_key: "currency".to_string(),
+            precision: Some(2),
+        }
+    }
+
+    /// Sets the members holding the amount and the currency code.
+    pub fn with_keys(mut self, amount: &str, currency: &str) -> Self {
+        self.amount_key = amount.to_string();
+        self.currency_key = currency.to_string();
+        self
+    }
+
+    /// Sets the number of decimal places converted amounts are rounded to; `None` keeps them
+    /// unrounded.
+    pub fn with_precision(mut self, digits: Option<u32>) -> Self {
+        self.precision = digits;
+        self
+    }
+
+    /// Returns the target currency.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+}
+
+impl ValueConverter for CurrencyConverter {
+    fn convert(&self, value: &Value) -> Result<Value, SupplierError> {
+        let invalid = |reason: &str| SupplierError::Upstream(format!("invalid price {}: {}", value, reason));
+        let object = value.as_object().ok_or_else(|| invalid("expected an object"))?;
+        let amount = object
+            .get(&self.amount_key)
+            .and_then(number)
+            .ok_or_else(|| invalid(&format!("'{}' must be a number", self.amount_key)))?;
+        let currency = object
+            .get(&self.currency_key)
+            .and_then(Value::as_str)
+            .map(|code| code.trim().to_ascii_uppercase())
+            .filter(|code| !code.is_empty())
+            .ok_or_else(|| invalid(&format!("'{}' must be a currency code", self.currency_key)))?;
+        let rate = if currency == self.target { 1.0 } else { self.rates.rate(&currency, &self.target)? };
+
+        let mut converted = object.clone();
+        converted.insert(self.amount_key.clone(), to_number(amount * rate, self.precision)?);
+        converted.insert(self.currency_key.clone(), Value::String(self.target.clone()));
+        Ok(Value::Object(converted))
+    }
+}
+
+/// A physical quantity measured by a [`UnitConverter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    /// Weights: `mg`, `g`, `kg`, `t`, `oz`, `lb`.
+    Weight,
+    /// Lengths, such as package dimensions: `mm`, `cm`, `m`, `km`, `in`, `ft`, `yd`.
+    Length,
+}
+
+impl Dimension {
+    /// Returns the size of `unit` in the base unit of the dimension (grams or millimetres),
+    /// accepting common spellings such as `lbs`, `pounds` or `inches`.
+    fn factor(self, unit: &str) -> Option<f64> {
+        let unit = unit.trim().to_ascii_lowercase();
+        let factor = match (self, unit.as_str()) {
+            (Dimension::Weight, "mg" | "milligram" | "milligrams") => 0.001,
+            (Dimension::Weight, "g" | "gram" | "grams") => 1.0,
+            (Dimension::Weight, "kg" | "kgs" | "kilogram" | "kilograms") => 1_000.0,
+            (Dimension::Weight, "t" | "tonne" | "tonnes") => 1_000_000.0,
+            (Dimension::Weight, "oz" | "ounce" | "ounces") => 28.349_523_125,
+            (Dimension::Weight, "lb" | "lbs" | "pound" | "pounds") => 453.592_37,
+            (Dimension::Length, "mm" | "millimeter" | "millimeters" | "millimetre" | "millimetres") => 1.0,
+            (Dimension::Length, "cm" | "centimeter" | "centimeters" | "centimetre" | "centimetres") => 10.0,
+            (Dimension::Length, "m" | "meter" | "meters" | "metre" | "metres") => 1_000.0,
+            (Dimension::Length, "km" | "kilometer" | "kilometers" | "kilometre" | "kilometres") => 1_000_000.0,
+            (Dimension::Length, "in" | "inch" | "inches") => 25.4,
+            (Dimension::Length, "ft" | "foot" | "feet") => 304.8,
+            (Dimension::Length, "yd" | "yard" | "yards") => 914.4,
+            _ => return None,
+        };
+        Some(factor)
+    }
+}
+
+/// Converts weights or lengths into a target unit.
+///
+/// Accepts objects with a unit member (by default `unit`), converting every other numeric
+/// member, so both `{ "value": 2, "unit": "lb" }` and package dimensions such as
+/// `{ "length": 10, "width": 4, "unit": "in" }` work; and strings such as `"2.5 lb"`, which
+/// are converted to strings in the target unit. Results are rounded to 3 decimal places unless
+/// configured otherwise.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::conversion::{UnitConverter, ValueConverter};
+///
+/// let kilograms = UnitConverter::weight("kg").unwrap();
+/// assert_eq!(kilograms.convert(&json!({ "value": 2, "unit": "lb" })).unwrap(), json!({ "value": 0.907, "unit": "kg" }));
+/// assert_eq!(kilograms.convert(&json!("500 g")).unwrap(), json!("0.5 kg"));
+///
+/// let centimetres = UnitConverter::length("cm").unwrap();
+/// assert_eq!(
+///     centimetres.convert(&json!({ "length": 10, "width": 4, "unit": "in" })).unwrap(),
+///     json!({ "length": 25.4, "width": 10.16, "unit": "cm" })
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnitConverter {
+    dimension: Dimension,
+    target: String,
+    target_factor: f64,
+    unit_key: String,
+    precision: Option<u32>,
+}
+
+impl UnitConverter {
+    /// Creates a converter of `dimension` into the `target` unit.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if `target` is not a unit of `dimension`.
+    pub fn new(dimension: Dimension, target: &str) -> Result<Self, SupplierError> {
+        let target_factor = dimension
+            .factor(target)
+            .ok_or_else(|| SupplierError::InvalidInput(format!("unknown {:?} unit '{}'", dimension, target)))?;
+        Ok(Self {
+            dimension,
+            target: target.trim().to_string(),
+            target_factor,
+            unit_key: "unit".to_string(),
+            precision: Some(3),
+        })
+    }
+
+    /// Creates a converter of weights into the `target` unit, e.g. `kg`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if `target` is not a unit of weight.
+    pub fn weight(target: &str) -> Result<Self, SupplierError> {
+        Self::new(Dimension::Weight, target)
+    }
+
+    /// Creates a converter of lengths into the `target` unit, e.g. `cm`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if `target` is not a unit of length.
+    pub fn length(target: &str) -> Result<Self, SupplierError> {
+        Self::new(Dimension::Length, target)
+    }
+
+    /// Sets the member holding the unit of objects.
+    pub fn with_unit_key(mut self, key: &str) -> Self {
+        self.unit_key = key.to_string();
+        self
+    }
+
+    /// Sets the number of decimal places converted values are rounded to; `None` keeps them
+    /// unrounded.
+    pub fn with_precision(mut self, digits: Option<u32>) -> Self {
+        self.precision = digits;
+        self
+    }
+
+    fn scale(&self, unit: &str, value: &Value) -> Result<f64, SupplierError> {
+        let factor = self.dimension.factor(unit).ok_or_else(|| {
+            SupplierError::Upstream(format!("invalid quantity {}: unknown {:?} unit '{}'", value, self.dimension, unit))
+        })?;
+        Ok(factor / self.target_factor)
+    }
+}
+
+impl ValueConverter for UnitConverter {
+    fn convert(&self, value: &Value) -> Result<Value, SupplierError> {
+        let invalid = |reason: &str| SupplierError::Upstream(format!("invalid quantity {}: {}", value, reason));
+        match value {
+            Value::String(text) => {
+                let split = text.trim().find(|c: char| c.is_alphabetic()).ok_or_else(|| invalid("missing unit"))?;
+                let (amount, unit) = text.trim().split_at(split);
+                let amount: f64 = amount.trim().parse().map_err(|_| invalid("expected a number"))?;
+                let converted = round(amount * self.scale(unit, value)?, self.precision);
+                Ok(Value::String(format!("{} {}", converted, self.target)))
+            }
+            Value::Object(object) => {
+                let unit = object
+                    .get(&self.unit_key)
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| invalid(&format!("'{}' must be a unit", self.unit_key)))?;
+                let scale = self.scale(unit, value)?;
+                let mut converted = Map::new();
+                for (key, member) in object {
+                    let member = match number(member) {
+                        Some(amount) if *key != self.unit_key => to_number(amount * scale, self.precision)?,
+                        _ => member.clone(),
+                    };
+                    converted.insert(key.clone(), member);
+                }
+                converted.insert(self.unit_key.clone(), Value::String(self.target.clone()));
+                Ok(Value::Object(converted))
+            }
+            _ => Err(invalid("expected an object or a string")),
+        }
+    }
+}
+
+/// A normalizer applying converters at paths of the response data, e.g. to bring the prices
+/// of every supplier of a group into one currency before their offers are compared.
+///
+/// Every value a path selects is replaced with its converted form; `null` values are left
+/// alone. Wrap suppliers with a `NormalizingSupplier`, or call it from
+/// `BasicSupplierGroup::query_transformed` to convert during aggregation.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::conversion::{Conversions, CurrencyConverter, FixedRates, UnitConverter};
+/// use supplier_kit::json_path::JsonPath;
+/// use supplier_kit::mapping::ResponseNormalizer;
+/// use supplier_kit::models::SupplierResponse;
+///
+/// let conversions = Conversions::new()
+///     .with_conversion(
+///         JsonPath::parse("$.offers[*].price").unwrap(),
+///         CurrencyConverter::new("EUR", FixedRates::new("EUR").with_rate("USD", 0.5)),
+///     )
+///     .with_conversion(JsonPath::parse("$.offers[*].weight").unwrap(), UnitConverter::weight("kg").unwrap());
+///
+/// let response = SupplierResponse::new(json!({ "offers": [
+///     { "price": { "amount": 10, "currency": "USD" }, "weight": "1500 g" },
+///     { "price": { "amount": 7, "currency": "EUR" }, "weight": null }
+/// ] }));
+/// assert_eq!(
+///     conversions.normalize_response(response).unwrap().data,
+///     json!({ "offers": [
+///         { "price": { "amount": 5.0, "currency": "EUR" }, "weight": "1.5 kg" },
+///         { "price": { "amount": 7.0, "currency": "EUR" }, "weight": null }
+///     ] })
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct Conversions {
+    rules: Vec<(JsonPath, Arc<dyn ValueConverter>)>,
+}
+
+impl fmt::Debug for Conversions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.rules.iter().map(|(path, _)| path.as_str())).finish()
+    }
+}
+
+impl Conversions {
+    /// Creates a normalizer without conversions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Converts the values at `path` with `converter`; conversions run in the order added.
+    pub fn with_conversion<C: ValueConverter + 'static>(mut self, path: JsonPath, converter: C) -> Self {
+        self.rules.push((path, Arc::new(converter)));
+        self
+    }
+
+    /// Converts the values `path` selects in `data` in place.
+    ///
+    /// # Errors
+    /// Returns the first error of a converter.
+    pub fn apply(&self, data: &mut Value) -> Result<(), SupplierError> {
+        for (path, converter) in &self.rules {
+            for pointer in path.locate(data) {
+                if let Some(value) = data.pointer_mut(&pointer).filter(|value| !value.is_null()) {
+                    *value = converter.convert(value)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ResponseNormalizer for Conversions {
+    fn normalize_response(&self, mut response: SupplierResponse) -> Result<SupplierResponse, SupplierError> {
+        self.apply(&mut response.data)?;
+        Ok(response)
+    }
+}
+
+/// Reads a number or a numeric string.
+fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn round(value: f64, precision: Option<u32>) -> f64 {
+    match precision {
+        Some(digits) => {
+            let scale = 10f64.powi(digits as i32);
+            (value * scale).round() / scale
+        }
+        None => value,
+    }
+}
+
+fn to_number(value: f64, precision: Option<u32>) -> Result<Value, SupplierError> {
+    Number::from_f64(round(value, precision))
+        .map(Value::Number)
+        .ok_or_else(|| SupplierError::Upstream(format!("conversion produced {}", value)))
+}
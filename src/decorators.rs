@@ -0,0 +1,44 @@
+use crate::models::SupplierRequest;
+
+/// Bulkhead decorator that bounds the number of in-flight queries per supplier.
+pub mod bulkhead;
+
+/// Hedging decorator that races a secondary supplier against a slow primary.
+pub mod hedging;
+
+/// Coalescing decorator that shares one upstream call among identical concurrent requests.
+pub mod coalescing;
+
+/// Shadow decorator that mirrors requests to a second supplier and reports disagreements.
+pub mod shadow;
+
+/// Canary decorator that sends a configurable share of traffic to a new implementation.
+pub mod canary;
+
+/// Idempotency decorator that applies each idempotency key at most once.
+pub mod idempotency;
+
+/// Caching decorator with TTLs and stale-while-revalidate background refresh.
+pub mod cache;
+
+/// Access-control decorator that rejects calls not granted by an `AccessPolicy`.
+pub mod access;
+
+/// Quota decorator that charges calls to a `QuotaTracker` and rejects them over the hard limit.
+pub mod quota;
+
+/// Migration decorator that moves payloads between the caller's and the supplier's operation versions.
+pub mod migration;
+
+/// Size-limit decorator that rejects responses larger than a configured number of bytes.
+pub mod size_limit;
+
+/// Throttle decorator that holds calls back for as long as a supplier asks after throttling them.
+pub mod throttle;
+
+/// Returns the key under which decorators treat requests as identical: the tenant, locale,
+/// operation, operation version and serialized params.
+pub(crate) fn request_key(request: &SupplierRequest) -> String {
+    let context = &request.context;
+    format!("{:?}\n{:?}\n{}\n{:?}\n{}", context.tenant, context.locale, request.operation.as_str(), request.version, request.params)
+}
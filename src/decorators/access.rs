@@ -0,0 +1,75 @@
+use crate::access::AccessPolicy;
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+
+/// A supplier decorator that rejects calls its `AccessPolicy` does not grant.
+///
+/// Rejected calls fail with `SupplierError::Unauthorized` and never reach the inner supplier.
+///
+/// # Example
+/// ```
+/// use supplier_kit::access::AccessPolicy;
+/// use supplier_kit::context::RequestContext;
+/// use supplier_kit::decorators::access::AccessControlledSupplier;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let orders = MockSupplierBuilder::new("team_b_orders")
+///     .respond_default(serde_json::json!({ "ok": true }))
+///     .build();
+/// let supplier = AccessControlledSupplier::new(orders, AccessPolicy::new().allow_all("team-b").allow_reads("*"));
+///
+/// let create = |caller: &str| {
+///     SupplierRequest::new(SupplierOperation::Create, serde_json::json!({}))
+///         .with_context(RequestContext::new().with_caller(caller))
+/// };
+/// assert!(supplier.query(create("team-b")).is_ok());
+/// assert!(matches!(supplier.query(create("team-a")), Err(SupplierError::Unauthorized)));
+/// ```
+pub struct AccessControlledSupplier<S> {
+    inner: S,
+    policy: AccessPolicy,
+}
+
+impl<S: Supplier> AccessControlledSupplier<S> {
+    /// Wraps `inner`, admitting only calls granted by `policy`.
+    pub fn new(inner: S, policy: AccessPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    /// Returns the policy enforced by the decorator.
+    pub fn policy(&self) -> &AccessPolicy {
+        &self.policy
+    }
+}
+
+impl<S: Supplier> Supplier for AccessControlledSupplier<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        self.policy.check(&request)?;
+        self.inner.query(request)
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
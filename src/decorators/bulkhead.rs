@@ -0,0 +1,138 @@
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+
+/// What a `Bulkhead` does with a query that arrives while all permits are taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkheadMode {
+    /// Reject the query immediately with `SupplierError::ConcurrencyLimitExceeded`.
+    Reject,
+    /// Wait up to the given duration for a permit, then reject.
+    Queue(Duration),
+}
+
+/// A decorator that bounds the number of simultaneous in-flight queries to a supplier.
+///
+/// Excess queries are either rejected immediately or queued for a limited time,
+/// depending on the configured `BulkheadMode`.
+///
+/// # Example
+/// ```
+/// use supplier_kit::decorators::bulkhead::{Bulkhead, BulkheadMode};
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierRequest, SupplierResponse};
+/// use supplier_kit::supplier::Supplier;
+///
+/// struct DummySupplier;
+///
+/// impl Supplier for DummySupplier {
+///     fn name(&self) -> &str { "dummy" }
+///     fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         Ok(SupplierResponse::new(serde_json::json!({ "ok": true })))
+///     }
+/// }
+///
+/// let supplier = Bulkhead::new(DummySupplier, 4, BulkheadMode::Reject);
+/// assert_eq!(supplier.name(), "dummy");
+/// assert_eq!(supplier.in_flight(), 0);
+/// ```
+pub struct Bulkhead<S> {
+    inner: S,
+    max_in_flight: usize,
+    mode: BulkheadMode,
+    in_flight: Mutex<usize>,
+    released: Condvar,
+}
+
+impl<S: Supplier> Bulkhead<S> {
+    /// Wraps `inner`, allowing at most `max_in_flight` simultaneous queries.
+    ///
+    /// A `max_in_flight` of zero is treated as one.
+    pub fn new(inner: S, max_in_flight: usize, mode: BulkheadMode) -> Self {
+        Self {
+            inner,
+            max_in_flight: max_in_flight.max(1),
+            mode,
+            in_flight: Mutex::new(0),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Returns the number of queries currently in flight.
+    pub fn in_flight(&self) -> usize {
+        *self.in_flight.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn acquire(&self) -> Result<Permit<'_, S>, SupplierError> {
+        let mut in_flight = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let BulkheadMode::Queue(timeout) = self.mode {
+            let deadline = Instant::now() + timeout;
+            while *in_flight >= self.max_in_flight {
+                let now = Instant::now();
+                if now >= deadline {
+                    break;
+                }
+                in_flight = self
+                    .released
+                    .wait_timeout(in_flight, deadline - now)
+                    .unwrap_or_else(|e| e.into_inner())
+                    .0;
+            }
+        }
+
+        if *in_flight >= self.max_in_flight {
+            return Err(SupplierError::ConcurrencyLimitExceeded(format!(
+                "supplier '{}' already has {} queries in flight",
+                self.inner.name(),
+                self.max_in_flight
+            )));
+        }
+
+        *in_flight += 1;
+        Ok(Permit { bulkhead: self })
+    }
+}
+
+impl<S: Supplier> Supplier for Bulkhead<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let _permit = self.acquire()?;
+        self.inner.query(request)
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
+
+/// Releases a bulkhead slot when dropped, even if the inner query panics.
+struct Permit<'a, S> {
+    bulkhead: &'a Bulkhead<S>,
+}
+
+impl<S> Drop for Permit<'_, S> {
+    fn drop(&mut self) {
+        let mut in_flight = self.bulkhead.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+        *in_flight -= 1;
+        self.bulkhead.released.notify_one();
+    }
+}
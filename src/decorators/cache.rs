@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use crate::compression::{from_compressed_json, to_compressed_json, Compression};
+use crate::decorators::request_key;
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::executor::{Executor, ThreadExecutor};
+use crate::models::{ResponseSource, SupplierRequest, SupplierResponse};
+use crate::supplier::{query_isolated, Supplier};
+
+/// A decorator caching successful read responses, optionally serving stale entries while
+/// they are refreshed in the background (stale-while-revalidate).
+///
+/// Requests are cached by tenant (`RequestContext::tenant`), locale (`RequestContext::locale`),
+/// operation, operation version (`SupplierRequest::version`) and serialized params, so tenants
+/// sharing the decorated supplier never see each other's responses, nor callers responses in
+/// another locale or payload version. A cached response is fresh for
+/// `ttl`; after that it is stale for the `stale_while_revalidate` window. A stale hit returns
+/// the cached response immediately and starts one background refresh; later stale hits keep
+/// receiving the old response until the refresh lands. Past the stale window the entry is
+/// dropped and the caller waits for the upstream call.
+///
+/// Errors are never cached, and a failed background refresh leaves the stale entry in place
+/// until its window ends. Write operations (see `SupplierOperation::is_write`) bypass the cache.
+/// Refreshes run on a [`ThreadExecutor`] unless given another executor with
+/// [`with_executor`](Self::with_executor).
+/// Cached responses are marked as `ResponseSource::Cache`.
+///
+/// The cache holds at most 10 000 responses unless configured otherwise with
+/// [`with_max_entries`](Self::with_max_entries). Storing into a full cache first drops every
+/// entry past its stale window, then the oldest entries.
+///
+/// Large responses, such as full catalogs, can be kept compressed with
+/// [`with_compression`](Self::with_compression).
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::decorators::cache::CachingSupplier;
+/// use supplier_kit::models::{ResponseSource, SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let catalog = MockSupplierBuilder::new("catalog")
+///     .respond_default(serde_json::json!({ "items": [] }))
+///     .build();
+/// let cached = CachingSupplier::new(catalog.clone(), Duration::from_secs(60))
+///     .with_stale_while_revalidate(Duration::from_secs(600));
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({ "q": "lamp" }));
+///
+/// assert_eq!(cached.query(request.clone()).unwrap().source(), ResponseSource::Live);
+/// assert_eq!(cached.query(request).unwrap().source(), ResponseSource::Cache);
+/// assert_eq!(catalog.calls(), 1);
+/// ```
+pub struct CachingSupplier<S> {
+    inner: Arc<S>,
+    ttl: Duration,
+    stale_while_revalidate: Duration,
+    compression: Compression,
+    max_entries: usize,
+    entries: Arc<Mutex<HashMap<String, CacheEntry>>>,
+    executor: Arc<dyn Executor>,
+}
+
+struct CacheEntry {
+    response: StoredResponse,
+    stored: Instant,
+    refreshing: bool,
+}
+
+/// A cached response, kept as is or serialized and compressed.
+enum StoredResponse {
+    Plain(SupplierResponse),
+    Compressed(Vec<u8>),
+}
+
+impl StoredResponse {
+    /// Stores `response` with `compression`, keeping it as is if compression is off or fails.
+    fn new(response: SupplierResponse, compression: Compression) -> Self {
+        if compression == Compression::None {
+            return StoredResponse::Plain(response);
+        }
+        match to_compressed_json(&response, compression) {
+            Ok(bytes) => StoredResponse::Compressed(bytes),
+            Err(_) => StoredResponse::Plain(response),
+        }
+    }
+
+    /// Returns the cached response, or `None` if a compressed entry cannot be read back.
+    fn load(&self) -> Option<SupplierResponse> {
+        match self {
+            StoredResponse::Plain(response) => Some(response.clone()),
+            StoredResponse::Compressed(bytes) => from_compressed_json(bytes).ok(),
+        }
+    }
+}
+
+impl<S: Supplier + 'static> CachingSupplier<S> {
+    /// Wraps `inner`, keeping successful responses fresh for `ttl`.
+    pub fn new(inner: S, ttl: Duration) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            ttl,
+            stale_while_revalidate: Duration::ZERO,
+            compression: Compression::None,
+            max_entries: 10_000,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+            executor: Arc::new(ThreadExecutor),
+        }
+    }
+
+    /// Keeps at most `max_entries` responses, evicting expired and then the oldest entries.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use serde_json::json;
+    /// use supplier_kit::decorators::cache::CachingSupplier;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier::Supplier;
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let catalog = MockSupplierBuilder::new("catalog").respond_default(json!([])).build();
+    /// let cached = CachingSupplier::new(catalog, Duration::from_secs(60)).with_max_entries(2);
+    /// for q in ["lamp", "desk", "chair"] {
+    ///     cached.query(SupplierRequest::new(SupplierOperation::Search, json!({ "q": q }))).unwrap();
+    /// }
+    /// assert_eq!(cached.len(), 2);
+    /// ```
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries.max(1);
+        self
+    }
+
+    /// Runs background refreshes on `executor`.
+    pub fn with_executor(mut self, executor: Arc<dyn Executor>) -> Self {
+        self.executor = executor;
+        self
+    }
+
+    /// Keeps cached responses serialized as JSON and compressed with `compression`, trading
+    /// a decompression on every hit for memory.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use supplier_kit::compression::Compression;
+    /// use supplier_kit::decorators::cache::CachingSupplier;
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let catalog = MockSupplierBuilder::new("catalog").build();
+    /// let cached = CachingSupplier::new(catalog, Duration::from_secs(60)).with_compression(Compression::None);
+    /// assert_eq!(cached.compression(), Compression::None);
+    /// ```
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Returns the compression applied to cached responses.
+    pub fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    /// Serves entries for up to `window` past their TTL while refreshing them in the background.
+    pub fn with_stale_while_revalidate(mut self, window: Duration) -> Self {
+        self.stale_while_revalidate = window;
+        self
+    }
+
+    /// Returns the number of cached responses, including stale ones.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Returns whether nothing is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops the cached response for `request`, so its next query reaches the supplier.
+    pub fn invalidate(&self, request: &SupplierRequest) {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).remove(&request_key(request));
+    }
+
+    /// Drops every cached response.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+
+    fn limits(&self) -> Limits {
+        Limits {
+            lifetime: self.ttl + self.stale_while_revalidate,
+            max_entries: self.max_entries,
+        }
+    }
+
+    /// Refreshes `key` in the background, on the executor.
+    fn spawn_refresh(&self, key: String, request: SupplierRequest) {
+        let inner = self.inner.clone();
+        let entries = self.entries.clone();
+        let compression = self.compression;
+        let limits = self.limits();
+        self.executor.spawn(Box::new(move || {
+            let result = query_isolated(inner.as_ref(), request);
+            let mut entries = entries.lock().unwrap_or_else(|e| e.into_inner());
+            match result {
+                Ok(response) => {
+                    let entry = CacheEntry {
+                        response: StoredResponse::new(response, compression),
+                        stored: Instant::now(),
+                        refreshing: false,
+                    };
+                    store(&mut entries, key, entry, limits);
+                }
+                Err(_) => {
+                    if let Some(entry) = entries.get_mut(&key) {
+                        entry.refreshing = false;
+                    }
+                }
+            }
+        }));
+    }
+}
+
+impl<S: Supplier + 'static> Supplier for CachingSupplier<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        if request.operation.is_write() {
+            return query_isolated(self.inner.as_ref(), request);
+        }
+
+        let key = request_key(&request);
+        {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(entry) = entries.get_mut(&key) {
+                let age = entry.stored.elapsed();
+                if age < self.ttl + self.stale_while_revalidate
+                    && let Some(mut response) = entry.response.load()
+                {
+                    response.metadata.source = ResponseSource::Cache;
+                    if age >= self.ttl && !entry.refreshing {
+                        entry.refreshing = true;
+                        drop(entries);
+                        self.spawn_refresh(key, request);
+                    }
+                    return Ok(response);
+                }
+                entries.remove(&key);
+            }
+        }
+
+        let response = query_isolated(self.inner.as_ref(), request)?;
+        let entry = CacheEntry {
+            response: StoredResponse::new(response.clone(), self.compression),
+            stored: Instant::now(),
+            refreshing: false,
+        };
+        store(&mut self.entries.lock().unwrap_or_else(|e| e.into_inner()), key, entry, self.limits());
+        Ok(response)
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
+
+/// How long entries live and how many the cache holds.
+#[derive(Clone, Copy)]
+struct Limits {
+    lifetime: Duration,
+    max_entries: usize,
+}
+
+/// Inserts `entry` under `key`, first evicting expired and then the oldest entries if the
+/// cache is full.
+fn store(entries: &mut HashMap<String, CacheEntry>, key: String, entry: CacheEntry, limits: Limits) {
+    if !entries.contains_key(&key) && entries.len() >= limits.max_entries {
+        entries.retain(|_, entry| entry.stored.elapsed() < limits.lifetime);
+        while entries.len() >= limits.max_entries {
+            let Some(oldest) = entries.iter().min_by_key(|(_, entry)| entry.stored).map(|(key, _)| key.clone()) else {
+                break;
+            };
+            entries.remove(&oldest);
+        }
+    }
+    entries.insert(key, entry);
+}
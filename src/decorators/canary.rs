@@ -0,0 +1,197 @@
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+
+const FULL_SCALE: u64 = 10_000;
+
+/// The two implementations a `CanarySupplier` splits traffic between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CanaryArm {
+    /// The established implementation.
+    Stable,
+    /// The implementation being rolled out.
+    Canary,
+}
+
+/// Traffic observed by one arm of a `CanarySupplier`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ArmStats {
+    /// Number of requests routed to the arm.
+    pub requests: u64,
+
+    /// Number of those requests that failed.
+    pub failures: u64,
+
+    /// Sum of the latencies of all requests.
+    pub total_latency: Duration,
+}
+
+impl ArmStats {
+    /// Returns the share of failed requests, or `0.0` if the arm received none.
+    pub fn error_rate(&self) -> f64 {
+        if self.requests == 0 {
+            0.0
+        } else {
+            self.failures as f64 / self.requests as f64
+        }
+    }
+
+    /// Returns the average latency, or zero if the arm received no requests.
+    pub fn average_latency(&self) -> Duration {
+        if self.requests == 0 {
+            Duration::ZERO
+        } else {
+            self.total_latency.div_f64(self.requests as f64)
+        }
+    }
+}
+
+/// Per-arm traffic of a `CanarySupplier`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CanaryStats {
+    /// Traffic of the stable implementation.
+    pub stable: ArmStats,
+
+    /// Traffic of the canary implementation.
+    pub canary: ArmStats,
+}
+
+/// A decorator that routes a configurable percentage of requests to a canary implementation
+/// and the rest to the stable one.
+///
+/// Requests are spread evenly: with 10%, exactly one request in ten goes to the canary.
+/// The percentage can be changed while the supplier is in use, allowing a gradual rollout,
+/// and [`CanarySupplier::stats`] reports the request count, error rate and latency of each arm.
+///
+/// # Example
+/// ```
+/// use supplier_kit::decorators::canary::CanarySupplier;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::supplier::Supplier;
+///
+/// struct Connector(&'static str);
+///
+/// impl Supplier for Connector {
+///     fn name(&self) -> &str { self.0 }
+///     fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         Ok(SupplierResponse::new(serde_json::json!({ "from": self.0 })))
+///     }
+/// }
+///
+/// let supplier = CanarySupplier::new(Connector("v1"), Connector("v2"), 25.0);
+/// for _ in 0..8 {
+///     supplier.query(SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}))).unwrap();
+/// }
+/// assert_eq!(supplier.stats().canary.requests, 2);
+/// assert_eq!(supplier.stats().stable.requests, 6);
+/// ```
+pub struct CanarySupplier {
+    stable: Arc<dyn Supplier>,
+    canary: Arc<dyn Supplier>,
+    // Share of canary traffic in hundredths of a percent.
+    share: AtomicU32,
+    counter: AtomicU64,
+    stats: Mutex<CanaryStats>,
+}
+
+impl CanarySupplier {
+    /// Creates a supplier sending `percentage` percent of requests to `canary`.
+    pub fn new<S, C>(stable: S, canary: C, percentage: f64) -> Self
+    where
+        S: Supplier + 'static,
+        C: Supplier + 'static,
+    {
+        Self::from_arcs(Arc::new(stable), Arc::new(canary), percentage)
+    }
+
+    /// Creates a canary supplier from already shared suppliers, e.g. taken from a registry.
+    pub fn from_arcs(stable: Arc<dyn Supplier>, canary: Arc<dyn Supplier>, percentage: f64) -> Self {
+        let supplier = Self {
+            stable,
+            canary,
+            share: AtomicU32::new(0),
+            counter: AtomicU64::new(0),
+            stats: Mutex::new(CanaryStats::default()),
+        };
+        supplier.set_percentage(percentage);
+        supplier
+    }
+
+    /// Changes the percentage of requests sent to the canary, clamped to `0.0..=100.0`.
+    pub fn set_percentage(&self, percentage: f64) {
+        let share = (percentage.clamp(0.0, 100.0) * 100.0).round() as u32;
+        self.share.store(share, Ordering::Relaxed);
+    }
+
+    /// Returns the percentage of requests sent to the canary.
+    pub fn percentage(&self) -> f64 {
+        f64::from(self.share.load(Ordering::Relaxed)) / 100.0
+    }
+
+    /// Returns the traffic observed by each arm so far.
+    pub fn stats(&self) -> CanaryStats {
+        *self.stats.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Picks the arm for the next request.
+    fn next_arm(&self) -> CanaryArm {
+        let share = u64::from(self.share.load(Ordering::Relaxed));
+        let n = self.counter.fetch_add(1, Ordering::Relaxed) % FULL_SCALE;
+        // The canary takes request `n` whenever its cumulative quota grows past a whole request.
+        if (n + 1) * share / FULL_SCALE > n * share / FULL_SCALE {
+            CanaryArm::Canary
+        } else {
+            CanaryArm::Stable
+        }
+    }
+}
+
+impl Supplier for CanarySupplier {
+    fn name(&self) -> &str {
+        self.stable.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let arm = self.next_arm();
+        let supplier = match arm {
+            CanaryArm::Stable => &self.stable,
+            CanaryArm::Canary => &self.canary,
+        };
+
+        let started = Instant::now();
+        let result = supplier.query(request);
+        let elapsed = started.elapsed();
+
+        let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        let arm_stats = match arm {
+            CanaryArm::Stable => &mut stats.stable,
+            CanaryArm::Canary => &mut stats.canary,
+        };
+        arm_stats.requests += 1;
+        arm_stats.failures += u64::from(result.is_err());
+        arm_stats.total_latency += elapsed;
+        result
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.stable.warm_up()?;
+        self.canary.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.stable.is_ready() && self.canary.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.stable.health_check().and(self.canary.health_check())
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.stable.describe()
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+use crate::decorators::request_key;
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::{query_isolated, Supplier};
+
+/// A decorator that shares one upstream call among concurrent identical requests (singleflight).
+///
+/// Requests are considered identical when their tenant, locale, operation, operation version
+/// and serialized params are equal.
+/// While a query is in flight, other callers with an identical request wait for it and receive
+/// a clone of its result instead of calling the inner supplier themselves.
+///
+/// Nothing is cached: once the in-flight query finishes, the next identical request
+/// triggers a new upstream call. Write operations (see `SupplierOperation::is_write`) are
+/// only shared when they carry an idempotency key; without one, each write reaches the
+/// inner supplier.
+///
+/// # Example
+/// ```
+/// use supplier_kit::decorators::coalescing::CoalescingSupplier;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::supplier::Supplier;
+///
+/// struct DummySupplier;
+///
+/// impl Supplier for DummySupplier {
+///     fn name(&self) -> &str { "dummy" }
+///     fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         Ok(SupplierResponse::new(request.params))
+///     }
+/// }
+///
+/// let supplier = CoalescingSupplier::new(DummySupplier);
+/// let request = SupplierRequest::new(
+///     SupplierOperation::GetDetail,
+///     serde_json::json!({ "id": 7 }),
+/// );
+/// assert_eq!(supplier.query(request).unwrap().data["id"], 7);
+/// assert_eq!(supplier.in_flight(), 0);
+/// ```
+pub struct CoalescingSupplier<S> {
+    inner: S,
+    flights: Mutex<HashMap<String, Arc<Flight>>>,
+}
+
+/// A single in-flight upstream call that other callers can wait on.
+#[derive(Default)]
+struct Flight {
+    result: Mutex<Option<Result<SupplierResponse, SupplierError>>>,
+    done: Condvar,
+}
+
+impl<S: Supplier> CoalescingSupplier<S> {
+    /// Wraps `inner` so that concurrent identical requests share a single call.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            flights: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the number of distinct requests currently in flight.
+    pub fn in_flight(&self) -> usize {
+        self.flights.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+}
+
+impl<S: Supplier> Supplier for CoalescingSupplier<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        if request.operation.is_write() && request.idempotency_key.is_none() {
+            return query_isolated(&self.inner, request);
+        }
+
+        let key = request_key(&request);
+
+        let (flight, is_leader) = {
+            let mut flights = self.flights.lock().unwrap_or_else(|e| e.into_inner());
+            match flights.get(&key) {
+                Some(flight) => (flight.clone(), false),
+                None => {
+                    let flight = Arc::new(Flight::default());
+                    flights.insert(key.clone(), flight.clone());
+                    (flight, true)
+                }
+            }
+        };
+
+        if is_leader {
+            let result = query_isolated(&self.inner, request);
+            *flight.result.lock().unwrap_or_else(|e| e.into_inner()) = Some(result.clone());
+            self.flights.lock().unwrap_or_else(|e| e.into_inner()).remove(&key);
+            flight.done.notify_all();
+            return result;
+        }
+
+        let mut result = flight.result.lock().unwrap_or_else(|e| e.into_inner());
+        while result.is_none() {
+            result = flight.done.wait(result).unwrap_or_else(|e| e.into_inner());
+        }
+        result.clone().expect("result is set before waiters are notified")
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
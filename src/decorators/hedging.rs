@@ -0,0 +1,141 @@
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::Duration;
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::executor::{Executor, ThreadExecutor};
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::{query_isolated, Supplier};
+
+/// A decorator that hedges slow queries by sending a duplicate request to a secondary supplier.
+///
+/// The primary supplier is queried first. If it has not answered within `threshold`,
+/// the same request is also sent to the secondary supplier and whichever succeeds first wins.
+/// If the first answer is an error, the decorator waits for the other one before giving up.
+/// Write operations (see `SupplierOperation::is_write`) are never hedged: they go to the
+/// primary alone, on the caller's thread.
+///
+/// The losing call keeps running in the background until it finishes; its result is discarded.
+/// Both calls run on a [`ThreadExecutor`] unless given another executor with
+/// [`with_executor`](Self::with_executor).
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::decorators::hedging::HedgingSupplier;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::supplier::Supplier;
+///
+/// struct DummySupplier(&'static str);
+///
+/// impl Supplier for DummySupplier {
+///     fn name(&self) -> &str { self.0 }
+///     fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         Ok(SupplierResponse::new(serde_json::json!({ "from": self.0 })))
+///     }
+/// }
+///
+/// let hedged = HedgingSupplier::new(DummySupplier("primary"), DummySupplier("mirror"), Duration::from_millis(50));
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}));
+/// let response = hedged.query(request).unwrap();
+/// assert_eq!(response.data["from"], "primary");
+/// ```
+pub struct HedgingSupplier {
+    primary: Arc<dyn Supplier>,
+    secondary: Arc<dyn Supplier>,
+    threshold: Duration,
+    executor: Arc<dyn Executor>,
+}
+
+impl HedgingSupplier {
+    /// Creates a hedging supplier that falls back to `secondary` after `threshold`.
+    pub fn new<P, S>(primary: P, secondary: S, threshold: Duration) -> Self
+    where
+        P: Supplier + 'static,
+        S: Supplier + 'static,
+    {
+        Self::from_arcs(Arc::new(primary), Arc::new(secondary), threshold)
+    }
+
+    /// Creates a hedging supplier from already shared suppliers, e.g. taken from a registry.
+    pub fn from_arcs(primary: Arc<dyn Supplier>, secondary: Arc<dyn Supplier>, threshold: Duration) -> Self {
+        Self {
+            primary,
+            secondary,
+            threshold,
+            executor: Arc::new(ThreadExecutor),
+        }
+    }
+
+    /// Runs the primary and hedge calls on `executor`.
+    pub fn with_executor(mut self, executor: Arc<dyn Executor>) -> Self {
+        self.executor = executor;
+        self
+    }
+
+    /// Returns the latency threshold after which the hedge request is sent.
+    pub fn threshold(&self) -> Duration {
+        self.threshold
+    }
+}
+
+impl Supplier for HedgingSupplier {
+    fn name(&self) -> &str {
+        self.primary.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        if request.operation.is_write() {
+            return query_isolated(self.primary.as_ref(), request);
+        }
+
+        let (tx, rx) = mpsc::channel();
+        spawn_query(self.executor.as_ref(), &self.primary, request.clone(), tx.clone());
+
+        // `None` means the primary is still running when the hedge is sent.
+        let mut last_error = match rx.recv_timeout(self.threshold) {
+            Ok(Ok(response)) => return Ok(response),
+            Ok(Err(error)) => Some(error),
+            Err(_) => None,
+        };
+        let pending = if last_error.is_some() { 1 } else { 2 };
+        spawn_query(self.executor.as_ref(), &self.secondary, request, tx);
+
+        for _ in 0..pending {
+            match rx.recv() {
+                Ok(Ok(response)) => return Ok(response),
+                Ok(Err(error)) => last_error = Some(error),
+                Err(_) => break,
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| SupplierError::Internal("hedged queries did not report a result".into())))
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.primary.warm_up()?;
+        self.secondary.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.primary.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.primary.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.primary.describe()
+    }
+}
+
+type QueryResult = Result<SupplierResponse, SupplierError>;
+
+fn spawn_query(executor: &dyn Executor, supplier: &Arc<dyn Supplier>, request: SupplierRequest, tx: mpsc::Sender<QueryResult>) {
+    let supplier = supplier.clone();
+    executor.spawn(Box::new(move || {
+        let _ = tx.send(query_isolated(supplier.as_ref(), request));
+    }));
+}
@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+use crate::decorators::request_key;
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::models::{ResponseSource, SupplierRequest, SupplierResponse};
+use crate::supplier::{query_isolated, Supplier};
+
+/// A decorator that applies each idempotency key at most once within a retention window.
+///
+/// Requests carrying `SupplierRequest::idempotency_key` are forwarded to the inner supplier the
+/// first time only. Repeats with the same key receive the stored response, marked as
+/// `ResponseSource::Cache`; repeats arriving while the first call is still running wait for it.
+/// Reusing a key for a different locale, operation, operation version or params fails with
+/// `SupplierError::InvalidInput`.
+///
+/// Keys are scoped by `RequestContext::tenant`: tenants reusing each other's keys never see
+/// each other's responses. Failed calls are not stored, so a retry with the same key reaches
+/// the supplier again.
+/// Requests without a key pass straight through.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::decorators::idempotency::IdempotentSupplier;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{ResponseSource, SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::supplier::Supplier;
+///
+/// struct Orders;
+///
+/// impl Supplier for Orders {
+///     fn name(&self) -> &str { "orders" }
+///     fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         Ok(SupplierResponse::new(serde_json::json!({ "order": request.params["sku"] })))
+///     }
+/// }
+///
+/// let orders = IdempotentSupplier::new(Orders, Duration::from_secs(3600));
+/// let place = SupplierRequest::new(SupplierOperation::Create, serde_json::json!({ "sku": "A1" }))
+///     .with_idempotency_key("checkout-42");
+///
+/// assert_eq!(orders.query(place.clone()).unwrap().source(), ResponseSource::Live);
+/// assert_eq!(orders.query(place).unwrap().source(), ResponseSource::Cache);
+/// assert_eq!(orders.len(), 1);
+/// ```
+pub struct IdempotentSupplier<S> {
+    inner: S,
+    retention: Duration,
+    entries: Mutex<HashMap<ScopedKey, Arc<Entry>>>,
+}
+
+/// An idempotency key with the tenant it belongs to.
+type ScopedKey = (Option<String>, String);
+
+/// The first call made for an idempotency key.
+struct Entry {
+    fingerprint: String,
+    created: Instant,
+    result: Mutex<Option<Result<SupplierResponse, SupplierError>>>,
+    done: Condvar,
+}
+
+impl<S: Supplier> IdempotentSupplier<S> {
+    /// Wraps `inner`, remembering successful responses for `retention`.
+    pub fn new(inner: S, retention: Duration) -> Self {
+        Self {
+            inner,
+            retention,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the number of idempotency keys currently remembered.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Returns whether no idempotency key is remembered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forgets `key` for every tenant, so its next request reaches the supplier again.
+    pub fn forget(&self, key: &str) {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).retain(|(_, k), _| k != key);
+    }
+}
+
+impl<S: Supplier> Supplier for IdempotentSupplier<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let Some(idempotency_key) = request.idempotency_key.clone() else {
+            return self.inner.query(request);
+        };
+        let key = (request.context.tenant.clone(), idempotency_key);
+        let fingerprint = request_key(&request);
+
+        let (entry, is_first) = {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            entries.retain(|_, entry| entry.created.elapsed() < self.retention);
+            match entries.get(&key) {
+                Some(entry) => (entry.clone(), false),
+                None => {
+                    let entry = Arc::new(Entry {
+                        fingerprint: fingerprint.clone(),
+                        created: Instant::now(),
+                        result: Mutex::new(None),
+                        done: Condvar::new(),
+                    });
+                    entries.insert(key.clone(), entry.clone());
+                    (entry, true)
+                }
+            }
+        };
+
+        if entry.fingerprint != fingerprint {
+            return Err(SupplierError::InvalidInput(format!(
+                "idempotency key '{}' was already used for a different request",
+                key.1
+            )));
+        }
+
+        if is_first {
+            let result = query_isolated(&self.inner, request);
+            if result.is_err() {
+                self.entries.lock().unwrap_or_else(|e| e.into_inner()).remove(&key);
+            }
+            *entry.result.lock().unwrap_or_else(|e| e.into_inner()) = Some(result.clone());
+            entry.done.notify_all();
+            return result;
+        }
+
+        let mut result = entry.result.lock().unwrap_or_else(|e| e.into_inner());
+        while result.is_none() {
+            result = entry.done.wait(result).unwrap_or_else(|e| e.into_inner());
+        }
+        result.clone().expect("result is set before waiters are notified").map(|mut response| {
+            response.metadata.source = ResponseSource::Cache;
+            response
+        })
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::migration::MigrationRegistry;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+
+/// A supplier decorator that migrates payloads between the caller's and the supplier's
+/// operation versions.
+///
+/// The supplier's versions are read once from its descriptor (see
+/// `OperationDescriptor::with_version`). A request with a `version` is migrated to the
+/// supplier's version before the call, and the response data back to the request's version
+/// after it. Requests without a version, and operations the descriptor does not version, pass
+/// through unchanged.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use serde_json::json;
+/// use supplier_kit::decorators::migration::MigratingSupplier;
+/// use supplier_kit::descriptor::OperationDescriptor;
+/// use supplier_kit::migration::{Migration, MigrationRegistry};
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let migrations = MigrationRegistry::new().with(
+///     Migration::new(SupplierOperation::GetDetail, 1)
+///         .downgrade_data(|data| Ok(json!({ "price": data["price"]["amount"] }))),
+/// );
+/// let partner = MockSupplierBuilder::new("partner")
+///     .describe_operation(OperationDescriptor::new(SupplierOperation::GetDetail).with_version(2))
+///     .respond_default(json!({ "price": { "amount": 12.5, "currency": "EUR" } }))
+///     .build();
+/// let supplier = MigratingSupplier::new(partner, Arc::new(migrations));
+///
+/// let v1 = SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": 7 })).with_version(1);
+/// assert_eq!(supplier.query(v1).unwrap().data, json!({ "price": 12.5 }));
+/// ```
+pub struct MigratingSupplier<S> {
+    inner: S,
+    migrations: Arc<MigrationRegistry>,
+    versions: HashMap<String, u32>,
+}
+
+impl<S: Supplier> MigratingSupplier<S> {
+    /// Wraps `inner`, migrating payloads with `migrations` to the versions its descriptor declares.
+    pub fn new(inner: S, migrations: Arc<MigrationRegistry>) -> Self {
+        let versions = inner
+            .describe()
+            .operations
+            .into_iter()
+            .filter_map(|o| Some((o.operation.normalize().as_str().to_string(), o.version?)))
+            .collect();
+        Self {
+            inner,
+            migrations,
+            versions,
+        }
+    }
+
+    /// Returns the migrations applied around the supplier.
+    pub fn migrations(&self) -> &Arc<MigrationRegistry> {
+        &self.migrations
+    }
+}
+
+impl<S: Supplier> Supplier for MigratingSupplier<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let operation = request.operation.clone().normalize();
+        let (Some(caller), Some(&supplier)) = (request.version, self.versions.get(operation.as_str())) else {
+            return self.inner.query(request);
+        };
+        let request = self.migrations.migrate_request(request, supplier)?;
+        let response = self.inner.query(request)?;
+        self.migrations.migrate_response(&operation, response, supplier, caller)
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::quota::QuotaTracker;
+use crate::supplier::Supplier;
+
+/// A supplier decorator that charges every call against the supplier's quota.
+///
+/// Calls are charged before they are sent, whether or not they succeed, because partners bill
+/// attempts. Calls over the hard limit fail with `SupplierError::RateLimited` and never reach
+/// the inner supplier.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use supplier_kit::decorators::quota::QuotaSupplier;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::quota::{Quota, QuotaTracker};
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let tracker = Arc::new(QuotaTracker::new());
+/// tracker.set_quota("partner", Quota::monthly().with_hard_limit(1.0));
+/// let partner = MockSupplierBuilder::new("partner").respond_default(serde_json::json!({})).build();
+/// let supplier = QuotaSupplier::new(partner, tracker);
+///
+/// let search = || SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}));
+/// assert!(supplier.query(search()).is_ok());
+/// assert!(matches!(supplier.query(search()), Err(SupplierError::RateLimited(_))));
+/// ```
+pub struct QuotaSupplier<S> {
+    inner: S,
+    tracker: Arc<QuotaTracker>,
+}
+
+impl<S: Supplier> QuotaSupplier<S> {
+    /// Wraps `inner`, charging its calls to the quota `tracker` holds under its name.
+    pub fn new(inner: S, tracker: Arc<QuotaTracker>) -> Self {
+        Self { inner, tracker }
+    }
+
+    /// Returns the tracker calls are charged to.
+    pub fn tracker(&self) -> &Arc<QuotaTracker> {
+        &self.tracker
+    }
+}
+
+impl<S: Supplier> Supplier for QuotaSupplier<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        self.tracker.try_consume(self.inner.name(), &request.operation)?;
+        self.inner.query(request)
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
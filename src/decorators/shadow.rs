@@ -0,0 +1,207 @@
+use std::sync::{Arc, Mutex};
+use crate::consistency::ConsistencyChecker;
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::executor::{Executor, ThreadExecutor};
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::{query_isolated, Supplier};
+
+type QueryResult = Result<SupplierResponse, SupplierError>;
+
+/// The primary and shadow results of one request, whichever are known yet.
+type Results = Arc<Mutex<(Option<QueryResult>, Option<QueryResult>)>>;
+
+/// Callback receiving every disagreement between the primary and the shadow supplier.
+pub type ShadowListener = Arc<dyn Fn(&ShadowDiff) + Send + Sync>;
+
+/// A disagreement between the primary and the shadow supplier for one request.
+#[derive(Debug, Clone)]
+pub struct ShadowDiff {
+    /// The request sent to both suppliers.
+    pub request: SupplierRequest,
+
+    /// The result returned to the caller.
+    pub primary: QueryResult,
+
+    /// The result of the shadow supplier, which was discarded.
+    pub shadow: QueryResult,
+
+    /// JSON pointers of the response data that differ. Empty when at least one side failed.
+    pub paths: Vec<String>,
+}
+
+/// A decorator that mirrors every request to a shadow supplier and reports where it disagrees
+/// with the primary.
+///
+/// The caller always receives the primary's result; the shadow is queried in the background,
+/// on a [`ThreadExecutor`] unless given another executor with
+/// [`with_executor`](Self::with_executor), so it adds no latency and its failures never reach
+/// the caller. Once both results are known, they are compared in the background and the
+/// listener is called if the response data differ, if only one side failed, or if both failed
+/// with different error codes.
+///
+/// Write operations (see `SupplierOperation::is_write`) are never mirrored: they go to the
+/// primary alone, so the shadow cannot apply them a second time.
+///
+/// # Example
+/// ```
+/// use std::sync::mpsc;
+/// use std::sync::{Arc, Mutex};
+/// use supplier_kit::decorators::shadow::ShadowSupplier;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::supplier::Supplier;
+///
+/// struct Catalog(&'static str, f64);
+///
+/// impl Supplier for Catalog {
+///     fn name(&self) -> &str { self.0 }
+///     fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         Ok(SupplierResponse::new(serde_json::json!({ "price": self.1 })))
+///     }
+/// }
+///
+/// let (tx, rx) = mpsc::channel();
+/// let tx = Mutex::new(tx);
+/// let shadowed = ShadowSupplier::new(Catalog("legacy", 10.0), Catalog("rewrite", 12.0))
+///     .on_diff(move |diff| { let _ = tx.lock().unwrap().send(diff.paths.clone()); });
+///
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}));
+/// assert_eq!(shadowed.query(request).unwrap().data["price"], 10.0);
+/// assert_eq!(rx.recv().unwrap(), vec!["/price".to_string()]);
+/// ```
+pub struct ShadowSupplier {
+    primary: Arc<dyn Supplier>,
+    shadow: Arc<dyn Supplier>,
+    listener: Option<ShadowListener>,
+    executor: Arc<dyn Executor>,
+}
+
+impl ShadowSupplier {
+    /// Creates a supplier answering from `primary` while mirroring requests to `shadow`.
+    pub fn new<P, S>(primary: P, shadow: S) -> Self
+    where
+        P: Supplier + 'static,
+        S: Supplier + 'static,
+    {
+        Self::from_arcs(Arc::new(primary), Arc::new(shadow))
+    }
+
+    /// Creates a shadowing supplier from already shared suppliers, e.g. taken from a registry.
+    pub fn from_arcs(primary: Arc<dyn Supplier>, shadow: Arc<dyn Supplier>) -> Self {
+        Self {
+            primary,
+            shadow,
+            listener: None,
+            executor: Arc::new(ThreadExecutor),
+        }
+    }
+
+    /// Runs the shadow calls and comparisons on `executor`.
+    pub fn with_executor(mut self, executor: Arc<dyn Executor>) -> Self {
+        self.executor = executor;
+        self
+    }
+
+    /// Registers the callback receiving disagreements. It runs in the background, on the executor.
+    pub fn on_diff<F>(mut self, listener: F) -> Self
+    where
+        F: Fn(&ShadowDiff) + Send + Sync + 'static,
+    {
+        self.listener = Some(Arc::new(listener));
+        self
+    }
+}
+
+impl Supplier for ShadowSupplier {
+    fn name(&self) -> &str {
+        self.primary.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        if request.operation.is_write() {
+            return self.primary.query(request);
+        }
+
+        let results: Results = Arc::default();
+        let shadow = self.shadow.clone();
+        let listener = self.listener.clone();
+        let mirrored = request.clone();
+        let pending = results.clone();
+        self.executor.spawn(Box::new(move || {
+            let shadow_result = query_isolated(shadow.as_ref(), mirrored.clone());
+            let mut results = pending.lock().unwrap_or_else(|e| e.into_inner());
+            match results.0.take() {
+                Some(primary) => {
+                    drop(results);
+                    report(listener, mirrored, primary, shadow_result);
+                }
+                None => results.1 = Some(shadow_result),
+            }
+        }));
+
+        let result = self.primary.query(request.clone());
+        let mut results = results.lock().unwrap_or_else(|e| e.into_inner());
+        match results.1.take() {
+            // The shadow finished first: compare in the background rather than on the caller.
+            Some(shadow_result) => {
+                drop(results);
+                let listener = self.listener.clone();
+                let primary = result.clone();
+                self.executor.spawn(Box::new(move || report(listener, request, primary, shadow_result)));
+            }
+            None => results.0 = Some(result.clone()),
+        }
+        result
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        // The shadow must never keep the primary out of service.
+        let _ = self.shadow.warm_up();
+        self.primary.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.primary.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.primary.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.primary.describe()
+    }
+}
+
+fn report(listener: Option<ShadowListener>, request: SupplierRequest, primary: QueryResult, shadow: QueryResult) {
+    if let Some(listener) = listener
+        && let Some(diff) = compare(request, primary, shadow)
+    {
+        listener(&diff);
+    }
+}
+
+fn compare(request: SupplierRequest, primary: QueryResult, shadow: QueryResult) -> Option<ShadowDiff> {
+    let paths = match (&primary, &shadow) {
+        (Ok(p), Ok(s)) => {
+            let paths: Vec<String> = ConsistencyChecker::new()
+                .compare(&p.data, &s.data)
+                .into_iter()
+                .map(|d| d.path)
+                .collect();
+            if paths.is_empty() {
+                return None;
+            }
+            paths
+        }
+        (Err(p), Err(s)) if p.code() == s.code() => return None,
+        _ => Vec::new(),
+    };
+    Some(ShadowDiff {
+        request,
+        primary,
+        shadow,
+        paths,
+    })
+}
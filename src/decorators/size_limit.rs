@@ -0,0 +1,125 @@
+use std::io::{self, Write};
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+
+/// A supplier decorator that rejects responses whose data serializes to more than a maximum
+/// number of bytes of JSON.
+///
+/// The raw payload of responses that carry a `body` counts towards the same limit.
+///
+/// Oversized responses fail with `SupplierError::ResponseTooLarge`, so an aggregator never
+/// merges, caches or forwards them. The size is measured without building the serialized
+/// document, and measuring stops as soon as the limit is passed.
+///
+/// The inner supplier has already decoded the response by the time it is measured; transports
+/// that receive raw bytes, such as `QueueSupplier::with_max_response_size` and
+/// `GrpcSupplier::with_max_response_size`, also stop reading oversized bodies before decoding.
+///
+/// # Example
+/// ```
+/// use supplier_kit::decorators::size_limit::SizeLimitedSupplier;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let partner = MockSupplierBuilder::new("partner")
+///     .respond_default(serde_json::json!({ "items": vec!["x"; 1000] }))
+///     .build();
+/// let supplier = SizeLimitedSupplier::new(partner, 1024);
+///
+/// let search = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}));
+/// assert!(matches!(supplier.query(search), Err(SupplierError::ResponseTooLarge(_))));
+/// ```
+pub struct SizeLimitedSupplier<S> {
+    inner: S,
+    max_bytes: usize,
+}
+
+impl<S: Supplier> SizeLimitedSupplier<S> {
+    /// Wraps `inner`, rejecting responses larger than `max_bytes` of JSON.
+    pub fn new(inner: S, max_bytes: usize) -> Self {
+        Self { inner, max_bytes }
+    }
+
+    /// Returns the maximum response size in bytes.
+    pub fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+
+    fn check(&self, result: Result<SupplierResponse, SupplierError>) -> Result<SupplierResponse, SupplierError> {
+        let response = result?;
+        let mut counter = ByteCounter {
+            written: response.body.as_ref().map_or(0, |body| body.len()),
+            limit: self.max_bytes,
+        };
+        if counter.written > counter.limit || serde_json::to_writer(&mut counter, &response.data).is_err() {
+            return Err(too_large(self.inner.name(), self.max_bytes));
+        }
+        Ok(response)
+    }
+}
+
+impl<S: Supplier> Supplier for SizeLimitedSupplier<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        self.check(self.inner.query(request))
+    }
+
+    fn query_batch(&self, requests: Vec<SupplierRequest>) -> Vec<Result<SupplierResponse, SupplierError>> {
+        self.inner
+            .query_batch(requests)
+            .into_iter()
+            .map(|result| self.check(result))
+            .collect()
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
+
+/// Returns the error for a response of `supplier` over `max_bytes`.
+pub(crate) fn too_large(supplier: &str, max_bytes: usize) -> SupplierError {
+    SupplierError::ResponseTooLarge(format!(
+        "response of supplier '{}' exceeds the limit of {} bytes",
+        supplier, max_bytes
+    ))
+}
+
+/// A writer that only counts bytes and fails once more than `limit` were written.
+struct ByteCounter {
+    written: usize,
+    limit: usize,
+}
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.written += buf.len();
+        if self.written > self.limit {
+            return Err(io::Error::other("limit exceeded"));
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
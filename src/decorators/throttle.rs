@@ -0,0 +1,143 @@
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+
+/// The longest a throttle holds back calls, however long the supplier asked to wait.
+const MAX_HOLD_BACK: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// A supplier decorator that honours the waits suppliers request with `SupplierError::Throttled`.
+///
+/// Once the inner supplier throttles a call, further calls are held back until its
+/// `retry_after` has passed (or the default backoff, when it sent none). Calls that would
+/// have to wait longer than the configured maximum fail immediately with
+/// `SupplierError::Throttled` carrying the remaining wait, without reaching the supplier;
+/// shorter waits are slept off. A throttled call is retried once, and only if its wait fits
+/// within the maximum.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::decorators::throttle::ThrottleGuard;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let partner = MockSupplierBuilder::new("partner")
+///     .then_fail(SupplierError::Throttled { retry_after: Some(Duration::from_secs(60)) })
+///     .respond_default(serde_json::json!({ "ok": true }))
+///     .build();
+/// let supplier = ThrottleGuard::new(partner.clone());
+///
+/// let search = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}));
+/// assert!(matches!(supplier.query(search.clone()), Err(SupplierError::Throttled { .. })));
+/// assert!(matches!(supplier.query(search), Err(SupplierError::Throttled { retry_after: Some(_) })));
+/// assert_eq!(partner.requests().len(), 1);
+/// ```
+pub struct ThrottleGuard<S> {
+    inner: S,
+    default_backoff: Duration,
+    max_wait: Duration,
+    blocked_until: Mutex<Option<Instant>>,
+}
+
+impl<S: Supplier> ThrottleGuard<S> {
+    /// Wraps `inner` with a one-second default backoff and no waiting.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            default_backoff: Duration::from_secs(1),
+            max_wait: Duration::ZERO,
+            blocked_until: Mutex::new(None),
+        }
+    }
+
+    /// Sets how long to hold back calls after a throttle that did not say when to retry.
+    pub fn with_default_backoff(mut self, backoff: Duration) -> Self {
+        self.default_backoff = backoff;
+        self
+    }
+
+    /// Lets calls sleep up to `max_wait` for the supplier to accept calls again, instead of
+    /// failing straight away.
+    pub fn with_max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+
+    /// Returns how long calls are still held back, or `None` if the supplier accepts calls.
+    pub fn blocked_for(&self) -> Option<Duration> {
+        let mut blocked_until = self.blocked_until.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        blocked_until.take_if(|until| *until <= now);
+        blocked_until.map(|until| until - now)
+    }
+
+    /// Holds back calls for `wait`, capped at `MAX_HOLD_BACK`, unless they are already held
+    /// back for longer.
+    fn block(&self, wait: Duration) {
+        let Some(until) = Instant::now().checked_add(wait.min(MAX_HOLD_BACK)) else {
+            return;
+        };
+        let mut blocked_until = self.blocked_until.lock().unwrap_or_else(|e| e.into_inner());
+        if blocked_until.is_none_or(|current| current < until) {
+            *blocked_until = Some(until);
+        }
+    }
+
+    /// Sleeps off a hold-back within `max_wait`, or fails with the remaining wait.
+    fn admit(&self) -> Result<(), SupplierError> {
+        match self.blocked_for() {
+            Some(wait) if wait > self.max_wait => Err(SupplierError::Throttled { retry_after: Some(wait) }),
+            Some(wait) => {
+                thread::sleep(wait);
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl<S: Supplier> Supplier for ThrottleGuard<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let mut retried = false;
+        loop {
+            self.admit()?;
+            match self.inner.query(request.clone()) {
+                Err(SupplierError::Throttled { retry_after }) => {
+                    let wait = retry_after.unwrap_or(self.default_backoff);
+                    self.block(wait);
+                    if retried || wait > self.max_wait {
+                        return Err(SupplierError::Throttled { retry_after: Some(wait) });
+                    }
+                    retried = true;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
@@ -0,0 +1,97 @@
+use std::time::{Duration, SystemTime};
+
+/// Describes the planned sunset of a supplier.
+///
+/// A deprecated supplier keeps working until `sunset_at`, but its routing weight
+/// is ramped down linearly from `1.0` to `0.0` over the `ramp` window that ends at the sunset,
+/// so traffic moves away from it gradually instead of all at once. Groups built with
+/// `BasicSupplierGroup::with_deprecations` route by this weight.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Deprecation {
+    /// The moment the supplier is considered fully retired.
+    pub sunset_at: SystemTime,
+
+    /// The length of the ramp-down window before `sunset_at`.
+    pub ramp: Duration,
+
+    /// A human-readable explanation, e.g. the replacement supplier to migrate to.
+    pub reason: String,
+}
+
+/// A stage in the lifecycle of a deprecated supplier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DeprecationMilestone {
+    /// The supplier is deprecated but still receives full traffic.
+    Deprecated,
+    /// The supplier is inside its ramp-down window and receives reduced traffic.
+    RampingDown,
+    /// The sunset date has passed; the supplier should no longer receive traffic.
+    SunsetReached,
+}
+
+/// A notification emitted by the registry for a deprecated supplier.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeprecationNotice {
+    /// A deprecated supplier was looked up or queried.
+    Used {
+        /// The supplier name.
+        supplier: String,
+        /// The deprecation schedule of the supplier.
+        deprecation: Deprecation,
+    },
+    /// A deprecated supplier entered a new lifecycle stage. Emitted once per milestone.
+    MilestoneReached {
+        /// The supplier name.
+        supplier: String,
+        /// The milestone that was reached.
+        milestone: DeprecationMilestone,
+    },
+}
+
+impl Deprecation {
+    /// Creates a deprecation that sunsets at `sunset_at` after ramping down over `ramp`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use supplier_kit::deprecation::Deprecation;
+    ///
+    /// let sunset = SystemTime::now() + Duration::from_secs(30 * 24 * 3600);
+    /// let deprecation = Deprecation::new(sunset, Duration::from_secs(7 * 24 * 3600))
+    ///     .with_reason("migrate to `acme_v2`");
+    /// assert_eq!(deprecation.weight_at(SystemTime::now()), 1.0);
+    /// assert_eq!(deprecation.weight_at(sunset), 0.0);
+    /// ```
+    pub fn new(sunset_at: SystemTime, ramp: Duration) -> Self {
+        Self {
+            sunset_at,
+            ramp,
+            reason: String::new(),
+        }
+    }
+
+    /// Sets the human-readable reason for the deprecation.
+    pub fn with_reason(mut self, reason: &str) -> Self {
+        self.reason = reason.to_string();
+        self
+    }
+
+    /// Returns the routing weight multiplier at `now`, between `0.0` and `1.0`.
+    pub fn weight_at(&self, now: SystemTime) -> f64 {
+        match self.sunset_at.duration_since(now) {
+            Err(_) => 0.0,
+            Ok(remaining) if remaining.is_zero() => 0.0,
+            Ok(remaining) if remaining >= self.ramp => 1.0,
+            Ok(remaining) => remaining.as_secs_f64() / self.ramp.as_secs_f64(),
+        }
+    }
+
+    /// Returns the lifecycle stage at `now`.
+    pub fn milestone_at(&self, now: SystemTime) -> DeprecationMilestone {
+        match self.weight_at(now) {
+            w if w <= 0.0 => DeprecationMilestone::SunsetReached,
+            w if w < 1.0 => DeprecationMilestone::RampingDown,
+            _ => DeprecationMilestone::Deprecated,
+        }
+    }
+}
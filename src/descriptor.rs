@@ -0,0 +1,402 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::localization::negotiate;
+use crate::models::SupplierOperation;
+use crate::supplier_group::QueryStrategy;
+
+/// Machine-readable description of one operation a supplier supports.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperationDescriptor {
+    /// The operation.
+    pub operation: SupplierOperation,
+
+    /// Human-readable summary of the operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
+    /// JSON Schema of the request parameters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params_schema: Option<Value>,
+
+    /// JSON Schema of the response data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_schema: Option<Value>,
+
+    /// Legacy or alternative names of the operation, see `aliases::OperationAliases`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+
+    /// The payload version the supplier implements; requests of other versions are migrated
+    /// by `MigratingSupplier`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+}
+
+impl OperationDescriptor {
+    /// Describes `operation` without summary or schemas.
+    pub fn new(operation: SupplierOperation) -> Self {
+        Self {
+            operation,
+            summary: None,
+            params_schema: None,
+            response_schema: None,
+            aliases: Vec::new(),
+            version: None,
+        }
+    }
+
+    /// Sets the human-readable summary.
+    pub fn with_summary(mut self, summary: &str) -> Self {
+        self.summary = Some(summary.to_string());
+        self
+    }
+
+    /// Sets the JSON Schema of the request parameters.
+    pub fn with_params_schema(mut self, schema: Value) -> Self {
+        self.params_schema = Some(schema);
+        self
+    }
+
+    /// Sets the JSON Schema of the response data.
+    pub fn with_response_schema(mut self, schema: Value) -> Self {
+        self.response_schema = Some(schema);
+        self
+    }
+
+    /// Sets the payload version the supplier implements.
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Declares a legacy or alternative name of the operation, e.g. `find` for `Search`.
+    pub fn with_alias(mut self, alias: &str) -> Self {
+        self.aliases.push(alias.to_string());
+        self
+    }
+
+    /// Returns whether `operation` is this operation or one of its aliases.
+    pub fn answers(&self, operation: &SupplierOperation) -> bool {
+        let operation = operation.clone().normalize();
+        self.operation.clone().normalize() == operation
+            || self.aliases.iter().any(|alias| SupplierOperation::Other(alias.clone()).normalize() == operation)
+    }
+}
+
+/// A rate limit a supplier is subject to: at most `max_requests` per `window_ms` milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimit {
+    /// Maximum number of requests in one window.
+    pub max_requests: u64,
+
+    /// Length of the window in milliseconds.
+    pub window_ms: u64,
+}
+
+/// Machine-readable metadata about a supplier, returned by `Supplier::describe`.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::descriptor::{OperationDescriptor, RateLimit, SupplierDescriptor};
+/// use supplier_kit::models::SupplierOperation;
+///
+/// let descriptor = SupplierDescriptor::new("shop")
+///     .with_version("2.1.0")
+///     .with_operation(
+///         OperationDescriptor::new(SupplierOperation::Search)
+///             .with_params_schema(json!({ "type": "object", "required": ["q"] })),
+///     )
+///     .with_rate_limit(RateLimit { max_requests: 10, window_ms: 1000 });
+///
+/// assert!(descriptor.supports(&SupplierOperation::Search));
+/// assert_eq!(serde_json::to_value(&descriptor).unwrap()["version"], "2.1.0");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SupplierDescriptor {
+    /// The supplier name.
+    pub name: String,
+
+    /// The version of the supplier integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    /// Human-readable description of the supplier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The supported operations. Empty when the supplier does not declare them.
+    #[serde(default)]
+    pub operations: Vec<OperationDescriptor>,
+
+    /// Rate limits the supplier is subject to.
+    #[serde(default)]
+    pub rate_limits: Vec<RateLimit>,
+
+    /// The locales the supplier serves, as BCP 47 tags, its default first. Empty when the
+    /// supplier does not declare them and takes any locale.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locales: Vec<String>,
+}
+
+impl SupplierDescriptor {
+    /// Describes a supplier by name only.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            version: None,
+            description: None,
+            operations: Vec::new(),
+            rate_limits: Vec::new(),
+            locales: Vec::new(),
+        }
+    }
+
+    /// Sets the version of the supplier integration.
+    pub fn with_version(mut self, version: &str) -> Self {
+        self.version = Some(version.to_string());
+        self
+    }
+
+    /// Sets the human-readable description.
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Declares a supported operation.
+    pub fn with_operation(mut self, operation: OperationDescriptor) -> Self {
+        self.operations.push(operation);
+        self
+    }
+
+    /// Declares a rate limit.
+    pub fn with_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limits.push(limit);
+        self
+    }
+
+    /// Declares a supported locale; the first one declared is the supplier's default.
+    pub fn with_locale(mut self, locale: &str) -> Self {
+        self.locales.push(locale.to_string());
+        self
+    }
+
+    /// Returns the locale the supplier should be queried in for a caller preferring
+    /// `requested`: the closest declared locale (see `localization::negotiate`), or the
+    /// supplier's default if none is close.
+    ///
+    /// Returns `requested` unchanged when the supplier declares no locales.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::descriptor::SupplierDescriptor;
+    ///
+    /// let descriptor = SupplierDescriptor::new("shop").with_locale("en-US").with_locale("pt-BR");
+    /// assert_eq!(descriptor.select_locale(Some("pt")), Some("pt-BR"));
+    /// assert_eq!(descriptor.select_locale(Some("ja-JP")), Some("en-US"));
+    /// assert_eq!(descriptor.select_locale(None), None);
+    /// ```
+    pub fn select_locale<'a>(&'a self, requested: Option<&'a str>) -> Option<&'a str> {
+        let requested = requested?;
+        if self.locales.is_empty() {
+            return Some(requested);
+        }
+        negotiate(requested, &self.locales).or(self.locales.first().map(String::as_str))
+    }
+
+    /// Returns whether `operation` is declared as supported, directly or through an alias.
+    pub fn supports(&self, operation: &SupplierOperation) -> bool {
+        self.operations.iter().any(|o| o.answers(operation))
+    }
+}
+
+/// Machine-readable description of a supplier group, as returned by `SupplierGroup::describe`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupDescriptor {
+    /// The group name.
+    pub name: String,
+
+    /// How the group runs its queries, when the group has a `QueryStrategy`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<QueryStrategy>,
+
+    /// Names of the member suppliers, in query order.
+    #[serde(default)]
+    pub suppliers: Vec<String>,
+}
+
+impl GroupDescriptor {
+    /// Describes a group by name only.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            strategy: None,
+            suppliers: Vec::new(),
+        }
+    }
+
+    /// Sets the query strategy.
+    pub fn with_strategy(mut self, strategy: QueryStrategy) -> Self {
+        self.strategy = Some(strategy);
+        self
+    }
+
+    /// Declares a member supplier.
+    pub fn with_supplier(mut self, name: &str) -> Self {
+        self.suppliers.push(name.to_string());
+        self
+    }
+}
+
+impl OperationDescriptor {
+    /// Checks `params` against the declared params schema; see [`validate_schema`].
+    ///
+    /// Returns no violations when no schema is declared.
+    pub fn validate_params(&self, params: &Value) -> Vec<String> {
+        self.params_schema.as_ref().map(|schema| validate_schema(schema, params)).unwrap_or_default()
+    }
+}
+
+/// Validates `value` against a JSON Schema and returns the violations, each prefixed with the
+/// JSON pointer of the offending value (empty for the root).
+///
+/// Only the commonly used keywords are checked: `type`, `enum`, `const`, `required`,
+/// `properties`, `additionalProperties: false`, `items`, `minItems`, `maxItems`, `minLength`,
+/// `maxLength`, `minimum` and `maximum`. Other keywords are ignored.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::descriptor::validate_schema;
+///
+/// let schema = json!({
+///     "type": "object",
+///     "required": ["q"],
+///     "properties": { "q": { "type": "string" }, "limit": { "type": "integer", "maximum": 100 } }
+/// });
+/// assert!(validate_schema(&schema, &json!({ "q": "lamp", "limit": 10 })).is_empty());
+/// assert_eq!(
+///     validate_schema(&schema, &json!({ "limit": 500 })),
+///     ["missing required property 'q'", "/limit: must be at most 100"]
+/// );
+/// ```
+pub fn validate_schema(schema: &Value, value: &Value) -> Vec<String> {
+    let mut violations = Vec::new();
+    check(schema, value, "", &mut violations);
+    violations
+}
+
+fn check(schema: &Value, value: &Value, pointer: &str, violations: &mut Vec<String>) {
+    if let Some(expected) = schema.get("type") {
+        let types: Vec<&str> = match expected {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !types.is_empty() && !types.iter().any(|t| has_type(value, t)) {
+            violations.push(at(pointer, format!("expected {}, found {}", types.join(" or "), type_name(value))));
+            return;
+        }
+    }
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array)
+        && !allowed.contains(value)
+    {
+        violations.push(at(pointer, format!("must be one of {}", Value::Array(allowed.clone()))));
+    }
+    if let Some(constant) = schema.get("const")
+        && constant != value
+    {
+        violations.push(at(pointer, format!("must be {}", constant)));
+    }
+    if let Some(number) = value.as_f64() {
+        if let Some(min) = schema.get("minimum").and_then(Value::as_f64)
+            && number < min
+        {
+            violations.push(at(pointer, format!("must be at least {}", min)));
+        }
+        if let Some(max) = schema.get("maximum").and_then(Value::as_f64)
+            && number > max
+        {
+            violations.push(at(pointer, format!("must be at most {}", max)));
+        }
+    }
+    if let Some(text) = value.as_str() {
+        let length = text.chars().count() as u64;
+        if let Some(min) = schema.get("minLength").and_then(Value::as_u64)
+            && length < min
+        {
+            violations.push(at(pointer, format!("must be at least {} characters", min)));
+        }
+        if let Some(max) = schema.get("maxLength").and_then(Value::as_u64)
+            && length > max
+        {
+            violations.push(at(pointer, format!("must be at most {} characters", max)));
+        }
+    }
+    if let Some(items) = value.as_array() {
+        let length = items.len() as u64;
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64)
+            && length < min
+        {
+            violations.push(at(pointer, format!("must have at least {} items", min)));
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64)
+            && length > max
+        {
+            violations.push(at(pointer, format!("must have at most {} items", max)));
+        }
+        if let Some(item_schema) = schema.get("items") {
+            for (index, item) in items.iter().enumerate() {
+                check(item_schema, item, &format!("{}/{}", pointer, index), violations);
+            }
+        }
+    }
+    if let Some(object) = value.as_object() {
+        for name in schema.get("required").and_then(Value::as_array).into_iter().flatten() {
+            if let Some(name) = name.as_str()
+                && !object.contains_key(name)
+            {
+                violations.push(at(pointer, format!("missing required property '{}'", name)));
+            }
+        }
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (name, property) in object {
+            let path = format!("{}/{}", pointer, name.replace('~', "~0").replace('/', "~1"));
+            match properties.and_then(|p| p.get(name)) {
+                Some(property_schema) => check(property_schema, property, &path, violations),
+                None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                    violations.push(at(&path, "unexpected property".to_string()));
+                }
+                None => {}
+            }
+        }
+    }
+}
+
+fn at(pointer: &str, message: String) -> String {
+    if pointer.is_empty() {
+        message
+    } else {
+        format!("{}: {}", pointer, message)
+    }
+}
+
+fn has_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "integer" => value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0),
+        "number" => value.is_number(),
+        other => type_name(value) == other,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
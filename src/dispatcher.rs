@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use crate::aliases::OperationAliases;
+use crate::errors::SupplierError;
+use crate::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use crate::supplier::{query_isolated, Supplier, SupplierRegistry};
+
+/// Routes every operation to the one supplier that owns it.
+///
+/// Groups fan a request out to several interchangeable suppliers; a dispatcher is for the
+/// opposite setup, where the kit fronts a set of services and each operation, including
+/// custom `Other` operations, is served by exactly one of them. Owners are assigned
+/// explicitly with [`Dispatcher::assign`] or taken from the operations the suppliers of a
+/// registry declare in their descriptors. Assigning an operation a second owner is an error,
+/// so ambiguities surface when the dispatcher is built rather than as misrouted calls.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use serde_json::json;
+/// use supplier_kit::dispatcher::Dispatcher;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let catalog = MockSupplierBuilder::new("catalog").respond_default(json!({ "items": [] })).build();
+/// let orders = MockSupplierBuilder::new("orders").respond_default(json!({ "order": 7 })).build();
+///
+/// let mut dispatcher = Dispatcher::new();
+/// dispatcher.assign(SupplierOperation::Search, "catalog", Arc::new(catalog)).unwrap();
+/// dispatcher.assign(SupplierOperation::Create, "orders", Arc::new(orders.clone())).unwrap();
+/// assert!(dispatcher.assign(SupplierOperation::Create, "catalog", Arc::new(orders)).is_err());
+///
+/// let response = dispatcher.query(SupplierRequest::new(SupplierOperation::Create, json!({}))).unwrap();
+/// assert_eq!(response.data, json!({ "order": 7 }));
+/// assert_eq!(dispatcher.owner(&SupplierOperation::Search), Some("catalog"));
+/// ```
+#[derive(Default)]
+pub struct Dispatcher {
+    owners: BTreeMap<String, (String, Arc<dyn Supplier>)>,
+    aliases: OperationAliases,
+}
+
+impl Dispatcher {
+    /// Creates a dispatcher without any operation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a dispatcher from the operations declared by the suppliers of `registry`.
+    ///
+    /// Suppliers that declare no operations are ignored. The aliases the suppliers declare
+    /// for their operations are resolved by the dispatcher.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` naming the operation and both suppliers if two
+    /// suppliers declare the same operation, and for conflicting aliases.
+    pub fn from_registry(registry: &SupplierRegistry) -> Result<Self, SupplierError> {
+        let descriptors = registry.describe_all();
+        let mut dispatcher = Self::new().with_aliases(OperationAliases::from_descriptors(descriptors.values())?);
+        for (name, descriptor) in descriptors {
+            let Some(supplier) = registry.get(&name) else {
+                continue;
+            };
+            for operation in descriptor.operations {
+                dispatcher.assign(operation.operation, &name, supplier.clone())?;
+            }
+        }
+        Ok(dispatcher)
+    }
+
+    /// Resolves the operations of requests through `aliases` before picking the owner;
+    /// `aliases` take precedence over aliases already known.
+    pub fn with_aliases(mut self, aliases: OperationAliases) -> Self {
+        self.aliases = self.aliases.merge(&aliases);
+        self
+    }
+
+    /// Makes supplier `name` the owner of `operation`.
+    ///
+    /// `Other` operations are normalized first (see [`SupplierOperation::normalize`]), so
+    /// `"Track Order"` and `"track_order"` are the same operation. Assigning an operation to
+    /// its current owner again replaces the supplier.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if another supplier already owns `operation`.
+    pub fn assign(&mut self, operation: SupplierOperation, name: &str, supplier: Arc<dyn Supplier>) -> Result<(), SupplierError> {
+        let key = Self::key(&operation);
+        if let Some((owner, _)) = self.owners.get(&key)
+            && owner != name
+        {
+            return Err(SupplierError::InvalidInput(format!(
+                "operation '{}' is owned by both '{}' and '{}'",
+                key, owner, name
+            )));
+        }
+        self.owners.insert(key, (name.to_string(), supplier));
+        Ok(())
+    }
+
+    /// Returns the name of the supplier owning `operation` or the operation it is an alias of.
+    pub fn owner(&self, operation: &SupplierOperation) -> Option<&str> {
+        let operation = self.aliases.resolve(operation.clone());
+        self.owners.get(&Self::key(&operation)).map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the dispatched operations, sorted by name.
+    pub fn operations(&self) -> Vec<SupplierOperation> {
+        self.owners.keys().map(|key| SupplierOperation::from(key.as_str())).collect()
+    }
+
+    /// Queries the owner of the request's operation, after resolving aliases.
+    ///
+    /// Panics raised by the owner are caught and reported as `SupplierError::Internal`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::UnsupportedOperation` if no supplier owns the operation, and
+    /// otherwise any error of the owner.
+    pub fn query(&self, mut request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        request.operation = self.aliases.resolve(request.operation);
+        let key = Self::key(&request.operation);
+        let (_, supplier) = self
+            .owners
+            .get(&key)
+            .ok_or_else(|| SupplierError::UnsupportedOperation(format!("no supplier owns operation '{}'", key)))?;
+        query_isolated(supplier.as_ref(), request)
+    }
+
+    fn key(operation: &SupplierOperation) -> String {
+        operation.clone().normalize().as_str().to_string()
+    }
+}
@@ -1,7 +1,12 @@
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Represents all possible errors that can occur in the supplier framework.
-#[derive(Debug, Error, Clone)]
+///
+/// Errors serialize as `{"code": ..., "message": ...}`, using the same codes as [`SupplierError::code`].
+#[derive(Debug, Error, Clone, Serialize, Deserialize)]
+#[serde(tag = "code", content = "message", rename_all = "snake_case")]
 pub enum SupplierError {
     /// The operation timed out, possibly due to a slow or unresponsive supplier.
     #[error("timeout")]
@@ -30,4 +35,239 @@ pub enum SupplierError {
     /// The requested operation is not supported by the supplier implementation.
     #[error("unsupported operation: {0}")]
     UnsupportedOperation(String),
+
+    /// The supplier already has the maximum number of queries in flight and rejected this one.
+    #[error("concurrency limit exceeded: {0}")]
+    ConcurrencyLimitExceeded(String),
+
+    /// A rate limit or call quota for the supplier is exhausted.
+    #[error("rate limited: {0}")]
+    RateLimited(String),
+
+    /// The group is shedding load and rejected the request before querying any supplier.
+    #[error("overloaded: {0}")]
+    Overloaded(String),
+
+    /// A supplier is already registered under the name.
+    #[error("already exists: {0}")]
+    AlreadyExists(String),
+
+    /// The supplier's response exceeded the configured maximum size and was discarded.
+    #[error("response too large: {0}")]
+    ResponseTooLarge(String),
+
+    /// The supplier asked the caller to slow down, e.g. with HTTP 429 or gRPC `RESOURCE_EXHAUSTED`.
+    ///
+    /// `retry_after` is the wait the supplier requested, if it sent one; calling again sooner
+    /// is likely to be rejected and may get the caller's credentials banned. It serializes as
+    /// `{"retry_after_ms": ...}`.
+    #[error("throttled{}", retry_after.map(|wait| format!(": retry after {:?}", wait)).unwrap_or_default())]
+    Throttled {
+        #[serde(rename = "retry_after_ms", default, skip_serializing_if = "Option::is_none", with = "millis")]
+        retry_after: Option<Duration>,
+    },
+
+    /// The group cannot reach enough of the suppliers its requirements demand for the
+    /// operation, e.g. because they are ejected or unhealthy, and queried none of them.
+    #[error("insufficient suppliers: {0}")]
+    InsufficientSuppliers(String),
+}
+
+impl SupplierError {
+    /// Returns a stable, machine-readable code for the error kind.
+    ///
+    /// Unlike the `Display` output, the code never contains supplier-provided text,
+    /// which makes it safe to expose to end users or use as a lookup key.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::errors::SupplierError;
+    /// assert_eq!(SupplierError::Timeout.code(), "timeout");
+    /// assert_eq!(SupplierError::Upstream("HTTP 502".into()).code(), "upstream");
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match self {
+            SupplierError::Timeout => "timeout",
+            SupplierError::Unauthorized => "unauthorized",
+            SupplierError::NotFound => "not_found",
+            SupplierError::Internal(_) => "internal",
+            SupplierError::Upstream(_) => "upstream",
+            SupplierError::InvalidInput(_) => "invalid_input",
+            SupplierError::UnsupportedOperation(_) => "unsupported_operation",
+            SupplierError::ConcurrencyLimitExceeded(_) => "concurrency_limit_exceeded",
+            SupplierError::RateLimited(_) => "rate_limited",
+            SupplierError::Overloaded(_) => "overloaded",
+            SupplierError::AlreadyExists(_) => "already_exists",
+            SupplierError::ResponseTooLarge(_) => "response_too_large",
+            SupplierError::Throttled { .. } => "throttled",
+            SupplierError::InsufficientSuppliers(_) => "insufficient_suppliers",
+        }
+    }
+
+    /// Returns how long the supplier asked the caller to wait before calling again, if it did.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use supplier_kit::errors::SupplierError;
+    /// let error = SupplierError::Throttled { retry_after: Some(Duration::from_secs(30)) };
+    /// assert_eq!(error.retry_after(), Some(Duration::from_secs(30)));
+    /// assert_eq!(error.to_string(), "throttled: retry after 30s");
+    /// assert_eq!(SupplierError::Timeout.retry_after(), None);
+    /// ```
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            SupplierError::Throttled { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Returns the error with its supplier-provided text replaced by `replacement`.
+    pub(crate) fn redacted(&self, replacement: &str) -> SupplierError {
+        let text = replacement.to_string();
+        match self {
+            SupplierError::Internal(_) => SupplierError::Internal(text),
+            SupplierError::Upstream(_) => SupplierError::Upstream(text),
+            SupplierError::InvalidInput(_) => SupplierError::InvalidInput(text),
+            SupplierError::UnsupportedOperation(_) => SupplierError::UnsupportedOperation(text),
+            SupplierError::ConcurrencyLimitExceeded(_) => SupplierError::ConcurrencyLimitExceeded(text),
+            SupplierError::RateLimited(_) => SupplierError::RateLimited(text),
+            SupplierError::Overloaded(_) => SupplierError::Overloaded(text),
+            SupplierError::AlreadyExists(_) => SupplierError::AlreadyExists(text),
+            SupplierError::ResponseTooLarge(_) => SupplierError::ResponseTooLarge(text),
+            SupplierError::InsufficientSuppliers(_) => SupplierError::InsufficientSuppliers(text),
+            SupplierError::Timeout | SupplierError::Unauthorized | SupplierError::NotFound | SupplierError::Throttled { .. } => self.clone(),
+        }
+    }
+}
+
+/// Explains why a group query failed as a whole, with the per-supplier errors behind it.
+///
+/// Set as `SupplierGroupResult::error` by the group's `PartialFailurePolicy` or deadline, and
+/// returned by `SupplierGroupResult::into_result`. Errors serialize as
+/// `{"code": ..., "group": ..., ...}`, using the codes of [`SupplierGroupError::code`].
+///
+/// # Example
+/// ```
+/// use supplier_kit::errors::{SupplierError, SupplierGroupError};
+///
+/// let error = SupplierGroupError::AllFailed {
+///     group: "marketplaces".into(),
+///     failures: vec![("shop_a".into(), SupplierError::Timeout), ("shop_b".into(), SupplierError::Unauthorized)],
+/// };
+/// assert_eq!(error.code(), "all_failed");
+/// assert_eq!(error.to_string(), "all 2 queried suppliers of group 'marketplaces' failed");
+/// assert!(matches!(error.supplier_error("shop_b"), Some(SupplierError::Unauthorized)));
+/// ```
+#[derive(Debug, Error, Clone, Serialize, Deserialize)]
+#[serde(tag = "code", rename_all = "snake_case")]
+pub enum SupplierGroupError {
+    /// No supplier was queried: the group is empty, or every member was skipped or routed away.
+    #[error("group '{group}' had no suppliers to query")]
+    NoSuppliers {
+        /// The name of the group.
+        group: String,
+    },
+
+    /// Every queried supplier failed.
+    #[error("all {} queried suppliers of group '{group}' failed", failures.len())]
+    AllFailed {
+        /// The name of the group.
+        group: String,
+        /// The failed suppliers and their errors.
+        failures: Vec<(String, SupplierError)>,
+    },
+
+    /// Fewer suppliers succeeded than the group's policy requires.
+    #[error("group '{group}' needed {required} successful suppliers but got {succeeded}")]
+    QuorumNotMet {
+        /// The name of the group.
+        group: String,
+        /// How many suppliers had to succeed.
+        required: usize,
+        /// How many suppliers succeeded.
+        succeeded: usize,
+        /// The failed suppliers and their errors.
+        failures: Vec<(String, SupplierError)>,
+    },
+
+    /// The group query took longer than the group's deadline.
+    #[error("group '{group}' took {elapsed_ms}ms, exceeding its {deadline_ms}ms deadline")]
+    DeadlineExceeded {
+        /// The name of the group.
+        group: String,
+        /// The deadline, in milliseconds.
+        deadline_ms: u64,
+        /// How long the query took, in milliseconds.
+        elapsed_ms: u64,
+        /// The failed suppliers and their errors.
+        failures: Vec<(String, SupplierError)>,
+    },
+
+    /// Suppliers marked `SupplierSeverity::Critical` failed.
+    #[error("critical suppliers of group '{group}' failed: {}", suppliers.join(", "))]
+    CriticalFailed {
+        /// The name of the group.
+        group: String,
+        /// The failed critical suppliers, in failure order.
+        suppliers: Vec<String>,
+        /// The failed suppliers and their errors, critical or not.
+        failures: Vec<(String, SupplierError)>,
+    },
+}
+
+impl SupplierGroupError {
+    /// Returns a stable, machine-readable code for the error kind.
+    pub fn code(&self) -> &'static str {
+        match self {
+            SupplierGroupError::NoSuppliers { .. } => "no_suppliers",
+            SupplierGroupError::AllFailed { .. } => "all_failed",
+            SupplierGroupError::QuorumNotMet { .. } => "quorum_not_met",
+            SupplierGroupError::DeadlineExceeded { .. } => "deadline_exceeded",
+            SupplierGroupError::CriticalFailed { .. } => "critical_failed",
+        }
+    }
+
+    /// Returns the name of the group that failed.
+    pub fn group(&self) -> &str {
+        match self {
+            SupplierGroupError::NoSuppliers { group }
+            | SupplierGroupError::AllFailed { group, .. }
+            | SupplierGroupError::QuorumNotMet { group, .. }
+            | SupplierGroupError::DeadlineExceeded { group, .. }
+            | SupplierGroupError::CriticalFailed { group, .. } => group,
+        }
+    }
+
+    /// Returns the failed suppliers and their errors.
+    pub fn failures(&self) -> &[(String, SupplierError)] {
+        match self {
+            SupplierGroupError::NoSuppliers { .. } => &[],
+            SupplierGroupError::AllFailed { failures, .. }
+            | SupplierGroupError::QuorumNotMet { failures, .. }
+            | SupplierGroupError::DeadlineExceeded { failures, .. }
+            | SupplierGroupError::CriticalFailed { failures, .. } => failures,
+        }
+    }
+
+    /// Returns the error of supplier `name`, if it failed.
+    pub fn supplier_error(&self, name: &str) -> Option<&SupplierError> {
+        self.failures().iter().find(|(n, _)| n == name).map(|(_, error)| error)
+    }
+}
+
+mod millis {
+    use std::time::Duration;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(duration) => serializer.serialize_u64(duration.as_millis() as u64),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Duration>, D::Error> {
+        Ok(Option::<u64>::deserialize(deserializer)?.map(Duration::from_millis))
+    }
 }
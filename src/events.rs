@@ -0,0 +1,246 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use crate::errors::SupplierError;
+
+/// A lifecycle or health change of a supplier, published on an [`EventBus`].
+///
+/// `group` is the name of the group that made the call, or `None` for calls made through
+/// `SupplierRegistry::query`.
+#[derive(Debug, Clone)]
+pub enum SupplierEvent {
+    /// A supplier was registered, or replaced another one under the same name.
+    Registered {
+        /// The registered name.
+        supplier: String,
+    },
+    /// A supplier was removed from the registry.
+    Removed {
+        /// The removed name.
+        supplier: String,
+    },
+    /// A query is about to be sent to a supplier.
+    QueryStarted {
+        /// The calling group.
+        group: Option<String>,
+        /// The supplier name.
+        supplier: String,
+        /// The operation name.
+        operation: String,
+    },
+    /// A supplier answered a query.
+    QuerySucceeded {
+        /// The calling group.
+        group: Option<String>,
+        /// The supplier name.
+        supplier: String,
+        /// The operation name.
+        operation: String,
+        /// How long the call took.
+        latency: Duration,
+    },
+    /// A supplier failed a query.
+    QueryFailed {
+        /// The calling group.
+        group: Option<String>,
+        /// The supplier name.
+        supplier: String,
+        /// The operation name.
+        operation: String,
+        /// The error returned.
+        error: SupplierError,
+        /// How long the call took.
+        latency: Duration,
+    },
+    /// A supplier's credentials were rotated through `SupplierRegistry::rotate_auth`.
+    CredentialsRotated {
+        /// The supplier name.
+        supplier: String,
+        /// The rotation count of the supplier's `RotatingAuth` after this rotation.
+        generation: u64,
+    },
+    /// A supplier's quota usage reached its soft limit in the current window.
+    QuotaSoftLimitReached {
+        /// The supplier name.
+        supplier: String,
+        /// The usage after the call that crossed the limit.
+        used: f64,
+        /// The soft limit.
+        limit: f64,
+    },
+    /// A circuit breaker stopped sending traffic to a supplier.
+    ///
+    /// Nothing in this crate opens circuits; breakers publish this event themselves with
+    /// [`EventBus::publish`].
+    CircuitOpened {
+        /// The supplier name.
+        supplier: String,
+        /// Why the circuit opened.
+        reason: String,
+    },
+    /// An outlier detector or error ejector ejected a supplier from a group's fan-out.
+    Ejected {
+        /// The group whose detector or ejector ejected the supplier.
+        group: String,
+        /// The supplier name.
+        supplier: String,
+        /// How many times the supplier has been ejected by the same detector or ejector so
+        /// far, this time included.
+        ejections: u64,
+    },
+    /// A `HealthMonitor` found a supplier turned unhealthy, or healthy again.
+    HealthChanged {
+        /// The supplier name.
+        supplier: String,
+        /// Whether the supplier is healthy now.
+        healthy: bool,
+        /// The error of the check that made it unhealthy; `None` when it recovered.
+        error: Option<SupplierError>,
+    },
+}
+
+/// Identifies a subscription so it can be cancelled with [`EventBus::unsubscribe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+enum Subscriber {
+    Callback(Arc<dyn Fn(&SupplierEvent) + Send + Sync>),
+    Channel(Sender<SupplierEvent>),
+}
+
+/// Delivers [`SupplierEvent`]s to subscribers.
+///
+/// Cloning is cheap and clones share subscribers, so one bus can be handed to a registry and
+/// several groups. Subscribers run synchronously on the thread that published the event, so
+/// callbacks should be quick; use [`EventBus::subscribe_channel`] to handle events elsewhere.
+/// Channel subscriptions end automatically once their receiver is dropped.
+///
+/// # Example
+/// ```
+/// use supplier_kit::events::{EventBus, SupplierEvent};
+/// use supplier_kit::supplier::SupplierRegistry;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let bus = EventBus::new();
+/// let events = bus.subscribe_channel();
+///
+/// let mut registry = SupplierRegistry::new();
+/// registry.set_event_bus(bus);
+/// registry.register("shop", MockSupplierBuilder::new("shop").build());
+///
+/// assert!(matches!(events.try_recv().unwrap(), SupplierEvent::Registered { supplier } if supplier == "shop"));
+/// ```
+#[derive(Clone, Default)]
+pub struct EventBus {
+    subscribers: Arc<RwLock<Vec<(SubscriptionId, Subscriber)>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl EventBus {
+    /// Creates a bus without subscribers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calls `callback` with every event published from now on.
+    pub fn subscribe<F>(&self, callback: F) -> SubscriptionId
+    where
+        F: Fn(&SupplierEvent) + Send + Sync + 'static,
+    {
+        self.add(Subscriber::Callback(Arc::new(callback)))
+    }
+
+    /// Returns a receiver getting a copy of every event published from now on.
+    pub fn subscribe_channel(&self) -> Receiver<SupplierEvent> {
+        let (tx, rx) = mpsc::channel();
+        self.add(Subscriber::Channel(tx));
+        rx
+    }
+
+    /// Cancels a subscription, returning whether it was still active.
+    pub fn unsubscribe(&self, id: SubscriptionId) -> bool {
+        let mut subscribers = self.subscribers.write().unwrap_or_else(|e| e.into_inner());
+        let before = subscribers.len();
+        subscribers.retain(|(existing, _)| *existing != id);
+        subscribers.len() != before
+    }
+
+    /// Returns whether anyone is subscribed.
+    pub fn has_subscribers(&self) -> bool {
+        !self.subscribers.read().unwrap_or_else(|e| e.into_inner()).is_empty()
+    }
+
+    /// Delivers `event` to every subscriber, in subscription order.
+    pub fn publish(&self, event: SupplierEvent) {
+        let mut disconnected = Vec::new();
+        {
+            let subscribers = self.subscribers.read().unwrap_or_else(|e| e.into_inner());
+            for (id, subscriber) in subscribers.iter() {
+                match subscriber {
+                    Subscriber::Callback(callback) => callback(&event),
+                    Subscriber::Channel(tx) => {
+                        if tx.send(event.clone()).is_err() {
+                            disconnected.push(*id);
+                        }
+                    }
+                }
+            }
+        }
+        for id in disconnected {
+            self.unsubscribe(id);
+        }
+    }
+
+    fn add(&self, subscriber: Subscriber) -> SubscriptionId {
+        let id = SubscriptionId(self.next_id.fetch_add(1, Ordering::Relaxed));
+        self.subscribers
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .push((id, subscriber));
+        id
+    }
+}
+
+impl EventBus {
+    /// Publishes `QueryStarted` if anyone is subscribed.
+    pub(crate) fn query_started(&self, group: Option<&str>, supplier: &str, operation: &str) {
+        if self.has_subscribers() {
+            self.publish(SupplierEvent::QueryStarted {
+                group: group.map(str::to_string),
+                supplier: supplier.to_string(),
+                operation: operation.to_string(),
+            });
+        }
+    }
+
+    /// Publishes `QuerySucceeded` or `QueryFailed` for `result` if anyone is subscribed.
+    pub(crate) fn query_finished<T>(
+        &self,
+        group: Option<&str>,
+        supplier: &str,
+        operation: &str,
+        result: &Result<T, SupplierError>,
+        latency: Duration,
+    ) {
+        if !self.has_subscribers() {
+            return;
+        }
+        let (group, supplier, operation) = (group.map(str::to_string), supplier.to_string(), operation.to_string());
+        self.publish(match result {
+            Ok(_) => SupplierEvent::QuerySucceeded {
+                group,
+                supplier,
+                operation,
+                latency,
+            },
+            Err(error) => SupplierEvent::QueryFailed {
+                group,
+                supplier,
+                operation,
+                error: error.clone(),
+                latency,
+            },
+        });
+    }
+}
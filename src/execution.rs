@@ -0,0 +1,446 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use crate::access::AccessPolicy;
+use crate::audit::AuditLog;
+use crate::balancing::LoadTracker;
+use crate::errors::SupplierError;
+use crate::events::{EventBus, SupplierEvent};
+use crate::executor::{Executor, Task};
+use crate::localization::select_locale;
+use crate::metrics::{GroupQuery, MetricsRecorder, SupplierCall};
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::outlier::{ErrorEjector, OutlierDetector};
+use crate::reputation::ReputationTracker;
+use crate::supplier::{query_batch_isolated, query_isolated, Supplier};
+
+type QueryResult = Result<SupplierResponse, SupplierError>;
+
+/// The result of one supplier call and how long the call took.
+#[derive(Clone)]
+pub(crate) struct Call {
+    pub(crate) result: QueryResult,
+    pub(crate) elapsed: Duration,
+    /// Whether the result of an earlier, identical call was reused instead of calling again.
+    pub(crate) reused: bool,
+    /// When the call finished; reused results keep the time of the call they reuse.
+    pub(crate) finished: Instant,
+}
+
+impl Call {
+    /// Returns the same result marked as reused by another job.
+    pub(crate) fn reused(&self) -> Self {
+        Self {
+            result: self.result.clone(),
+            elapsed: Duration::ZERO,
+            reused: true,
+            finished: self.finished,
+        }
+    }
+}
+
+/// Cross-cutting observers notified about every supplier call made by a group.
+///
+/// Cloning is cheap, so the hooks can be moved into worker threads.
+#[derive(Clone, Default)]
+pub(crate) struct QueryHooks {
+    pub(crate) group: Arc<str>,
+    pub(crate) reputation: Option<Arc<ReputationTracker>>,
+    pub(crate) outliers: Option<Arc<OutlierDetector>>,
+    pub(crate) error_ejection: Option<Arc<ErrorEjector>>,
+    pub(crate) metrics: Option<Arc<dyn MetricsRecorder>>,
+    pub(crate) audit: Option<Arc<AuditLog>>,
+    pub(crate) events: EventBus,
+    pub(crate) access: Option<Arc<AccessPolicy>>,
+    pub(crate) load: Arc<LoadTracker>,
+}
+
+impl QueryHooks {
+    /// Explains why `supplier` is currently ejected from fan-out queries, if it is.
+    pub(crate) fn ejection_reason(&self, supplier: &dyn Supplier) -> Option<&'static str> {
+        if self.outliers.as_ref().is_some_and(|o| o.is_ejected(supplier.name())) {
+            Some("ejected as an outlier")
+        } else if self.error_ejection.as_ref().is_some_and(|e| e.is_ejected(supplier.name())) {
+            Some("ejected after unauthorized or throttled calls")
+        } else if self.reputation.as_ref().is_some_and(|r| r.is_ejected(supplier.name())) {
+            Some("ejected for a low reputation weight")
+        } else {
+            None
+        }
+    }
+
+    /// Queries `supplier` with panic isolation and reports the outcome to every observer.
+    ///
+    /// Calls denied by the group's access policy fail without reaching or being reported for
+    /// the supplier. The request's locale is narrowed to the closest one the supplier declares.
+    pub(crate) fn call(&self, supplier: &dyn Supplier, mut request: SupplierRequest) -> Call {
+        select_locale(supplier, &mut request);
+        if let Some(access) = &self.access
+            && let Err(error) = access.check(&request)
+        {
+            return Call { result: Err(error), elapsed: Duration::ZERO, reused: false, finished: Instant::now() };
+        }
+        let audited = self.audit.as_ref().map(|_| request.clone());
+        let operation = request.operation.clone();
+        let tenant = request.context.tenant.clone();
+        self.events.query_started(Some(&self.group), supplier.name(), operation.as_str());
+        self.load.begin(supplier.name());
+        let started = Instant::now();
+        let result = query_isolated(supplier, request);
+        let elapsed = started.elapsed();
+        self.load.finish(supplier.name(), elapsed, result.is_ok());
+        self.observe(supplier.name(), operation.as_str(), tenant.as_deref(), &result, elapsed);
+        if let (Some(audit), Some(request)) = (&self.audit, &audited) {
+            audit.record(Some(&self.group), supplier.name(), request, &result, elapsed);
+        }
+        Call { result, elapsed, reused: false, finished: Instant::now() }
+    }
+
+    /// Batch counterpart of [`QueryHooks::call`]; the elapsed time is split evenly across results.
+    pub(crate) fn invoke_batch(&self, supplier: &dyn Supplier, mut requests: Vec<SupplierRequest>) -> Vec<Call> {
+        requests.iter_mut().for_each(|request| select_locale(supplier, request));
+        if let Some(access) = &self.access {
+            let checks: Vec<Result<(), SupplierError>> = requests.iter().map(|r| access.check(r)).collect();
+            if checks.iter().any(Result::is_err) {
+                // Only the granted requests reach the supplier; denied ones keep their slot.
+                let allowed = requests.into_iter().zip(&checks).filter(|(_, c)| c.is_ok()).map(|(r, _)| r).collect();
+                let mut results = self.invoke_batch(supplier, allowed).into_iter();
+                return checks
+                    .into_iter()
+                    .map(|check| match check {
+                        Ok(()) => results.next().unwrap_or_else(|| Call {
+                            result: Err(SupplierError::Internal("missing batch result".into())),
+                            elapsed: Duration::ZERO,
+                            reused: false,
+                            finished: Instant::now(),
+                        }),
+                        Err(error) => Call { result: Err(error), elapsed: Duration::ZERO, reused: false, finished: Instant::now() },
+                    })
+                    .collect();
+            }
+        }
+        let originals = requests.clone();
+        for request in &originals {
+            self.events.query_started(Some(&self.group), supplier.name(), request.operation.as_str());
+        }
+        self.load.begin(supplier.name());
+        let started = Instant::now();
+        let results = query_batch_isolated(supplier, requests);
+        let per_result = started.elapsed() / results.len().max(1) as u32;
+        self.load.finish(supplier.name(), per_result, results.iter().all(Result::is_ok));
+        for (request, result) in originals.iter().zip(&results) {
+            self.observe(supplier.name(), request.operation.as_str(), request.context.tenant.as_deref(), result, per_result);
+            if let Some(audit) = &self.audit {
+                audit.record(Some(&self.group), supplier.name(), request, result, per_result);
+            }
+        }
+        let finished = Instant::now();
+        results
+            .into_iter()
+            .map(|result| Call { result, elapsed: per_result, reused: false, finished })
+            .collect()
+    }
+
+    /// Reports a completed group query.
+    pub(crate) fn observe_group(&self, tenant: Option<&str>, fan_out: usize, successes: usize, failures: usize, elapsed: Duration) {
+        if let Some(metrics) = &self.metrics {
+            metrics.on_group_query(&GroupQuery {
+                group: &self.group,
+                tenant,
+                fan_out,
+                successes,
+                failures,
+                latency: elapsed,
+            });
+        }
+    }
+
+    fn observe(&self, supplier: &str, operation: &str, tenant: Option<&str>, result: &QueryResult, elapsed: Duration) {
+        if let Some(reputation) = &self.reputation {
+            reputation.record(supplier, result.is_ok(), elapsed);
+        }
+        if let Some(outliers) = &self.outliers {
+            let ejections = outliers.ejections(supplier);
+            outliers.record(supplier, result.is_ok(), elapsed);
+            self.publish_ejection(supplier, ejections, outliers.ejections(supplier));
+        }
+        if let Some(ejector) = &self.error_ejection {
+            let ejections = ejector.ejections(supplier);
+            ejector.record(supplier, result.as_ref().err());
+            self.publish_ejection(supplier, ejections, ejector.ejections(supplier));
+        }
+        self.events
+            .query_finished(Some(&self.group), supplier, operation, result, elapsed);
+        if let Some(metrics) = &self.metrics {
+            metrics.on_supplier_call(&SupplierCall {
+                group: &self.group,
+                supplier,
+                tenant,
+                operation,
+                error: result.as_ref().err(),
+                latency: elapsed,
+            });
+        }
+    }
+
+    fn publish_ejection(&self, supplier: &str, before: u64, after: u64) {
+        if after > before {
+            self.events.publish(SupplierEvent::Ejected {
+                group: self.group.to_string(),
+                supplier: supplier.to_string(),
+                ejections: after,
+            });
+        }
+    }
+}
+
+/// A supplier paired with the request it should answer.
+pub(crate) type Job = (Arc<dyn Supplier>, SupplierRequest);
+
+/// Removes duplicate jobs (same supplier instance and equal request).
+///
+/// Returns the distinct jobs and, for every original job, the index of its distinct job.
+pub(crate) fn dedupe_jobs(jobs: &[Job]) -> (Vec<Job>, Vec<usize>) {
+    let mut unique: Vec<Job> = Vec::new();
+    let slots = jobs
+        .iter()
+        .map(|job| {
+            unique
+                .iter()
+                .position(|u| Arc::ptr_eq(&u.0, &job.0) && u.1 == job.1)
+                .unwrap_or_else(|| {
+                    unique.push(job.clone());
+                    unique.len() - 1
+                })
+        })
+        .collect();
+    (unique, slots)
+}
+
+/// Runs every job as its own task on `executor`, with at most `max_concurrency` in flight.
+///
+/// Results are yielded as `(job index, call)` in completion order. Jobs are submitted lazily
+/// while the results are consumed, so dropping the iterator (e.g. once a race is won) submits
+/// no further jobs, and a job an executor runs on the calling thread never delays results of
+/// jobs that already finished. Calls already in progress run to completion in the background.
+pub(crate) fn spawn_jobs<'a>(
+    executor: &'a dyn Executor,
+    jobs: Vec<Job>,
+    max_concurrency: usize,
+    hooks: &QueryHooks,
+) -> SpawnedJobs<'a> {
+    let (tx, rx) = mpsc::channel();
+    SpawnedJobs {
+        executor,
+        jobs: jobs.into_iter().enumerate(),
+        max_concurrency: max_concurrency.max(1),
+        in_flight: 0,
+        hooks: hooks.clone(),
+        memo: QueryMemo::default(),
+        tx,
+        rx,
+    }
+}
+
+/// The results of [`spawn_jobs`], submitting the remaining jobs as they are consumed.
+pub(crate) struct SpawnedJobs<'a> {
+    executor: &'a dyn Executor,
+    jobs: std::iter::Enumerate<std::vec::IntoIter<Job>>,
+    max_concurrency: usize,
+    in_flight: usize,
+    hooks: QueryHooks,
+    // Memoizes the jobs whose requests carry no memo of their own.
+    memo: QueryMemo,
+    tx: mpsc::Sender<(usize, Call)>,
+    rx: mpsc::Receiver<(usize, Call)>,
+}
+
+impl Iterator for SpawnedJobs<'_> {
+    type Item = (usize, Call);
+
+    fn next(&mut self) -> Option<(usize, Call)> {
+        loop {
+            if let Ok(finished) = self.rx.try_recv() {
+                self.in_flight -= 1;
+                return Some(finished);
+            }
+            if self.in_flight < self.max_concurrency
+                && let Some((index, (supplier, request))) = self.jobs.next()
+            {
+                self.in_flight += 1;
+                let hooks = self.hooks.clone();
+                let memo = request.context.memo.clone().unwrap_or_else(|| self.memo.clone());
+                let tx = self.tx.clone();
+                self.executor.spawn(Box::new(move || {
+                    // The receiver is gone once the caller stopped reading results.
+                    let _ = tx.send((index, memo.call(&supplier, &request, &hooks)));
+                }));
+                continue;
+            }
+            if self.in_flight == 0 {
+                return None;
+            }
+            // Calls are panic-isolated, so every submitted job eventually reports back.
+            let finished = self.rx.recv().ok()?;
+            self.in_flight -= 1;
+            return Some(finished);
+        }
+    }
+}
+
+/// Applies `f` to every item on at most `max_concurrency` workers run by `executor`,
+/// returning the results in the same order as `items`.
+pub(crate) fn parallel_map<T, R, F>(executor: &dyn Executor, items: &[T], max_concurrency: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+    let workers = max_concurrency.clamp(1, items.len().max(1));
+
+    let worker = || loop {
+        let index = next.fetch_add(1, Ordering::SeqCst);
+        let Some(item) = items.get(index) else { break };
+        let result = f(item);
+        results.lock().unwrap_or_else(|e| e.into_inner())[index] = Some(result);
+    };
+    executor.run_all((0..workers).map(|_| Box::new(&worker) as Task<'_>).collect());
+
+    results
+        .into_inner()
+        .unwrap_or_else(|e| e.into_inner())
+        .into_iter()
+        .map(|r| r.expect("every item is processed by a worker"))
+        .collect()
+}
+
+/// Remembers supplier results for the duration of one query, across every group and pipeline
+/// stage it reaches.
+///
+/// When the same underlying supplier instance (the same `Arc`) is reached more than once with
+/// an identical request (request ids aside), the first result is reused instead of calling the supplier again;
+/// callers arriving while the first call is still running wait for it. The query that opens
+/// the memo carries it to nested groups in `RequestContext`, and closes it when it ends, so
+/// requests kept beyond the query (e.g. by a background refresh) reach their suppliers again.
+#[derive(Clone, Default)]
+pub(crate) struct QueryMemo {
+    state: Arc<MemoState>,
+}
+
+/// A memoized call: the supplier key, the request, and the result once the call finished.
+type MemoEntry = (usize, SupplierRequest, Arc<OnceLock<Call>>);
+
+#[derive(Default)]
+struct MemoState {
+    entries: Mutex<Vec<MemoEntry>>,
+    closed: AtomicBool,
+}
+
+impl QueryMemo {
+    /// Returns the memo of the query serving `request`, opening one and attaching it to
+    /// `request` if none serves it yet. The returned scope closes a newly opened memo when dropped.
+    pub(crate) fn open(request: &mut SupplierRequest) -> (QueryMemo, Option<MemoScope>) {
+        if let Some(memo) = &request.context.memo {
+            return (memo.clone(), None);
+        }
+        let memo = QueryMemo::default();
+        request.context.memo = Some(memo.clone());
+        (memo.clone(), Some(memo.scope()))
+    }
+
+    /// Returns a scope closing the memo when dropped.
+    pub(crate) fn scope(&self) -> MemoScope {
+        MemoScope(self.clone())
+    }
+
+    /// Queries `supplier` with `request`, or returns the memoized result of an identical earlier call.
+    pub(crate) fn query(&self, supplier: &Arc<dyn Supplier>, request: &SupplierRequest, hooks: &QueryHooks) -> QueryResult {
+        self.call(supplier, request, hooks).result
+    }
+
+    /// [`QueryMemo::query`], also returning how long the call took and whether it was memoized.
+    pub(crate) fn call(&self, supplier: &Arc<dyn Supplier>, request: &SupplierRequest, hooks: &QueryHooks) -> Call {
+        // A result memoized for another group must not bypass this group's access policy.
+        if let Some(access) = &hooks.access
+            && let Err(error) = access.check(request)
+        {
+            return Call { result: Err(error), elapsed: Duration::ZERO, reused: false, finished: Instant::now() };
+        }
+        self.call_with(supplier, request, || hooks.call(supplier.as_ref(), request.clone()))
+    }
+
+    /// Runs `query` for `supplier` and `request` outside any group, or returns the memoized
+    /// result of an identical earlier call.
+    pub(crate) fn query_with(&self, supplier: &Arc<dyn Supplier>, request: &SupplierRequest, query: impl FnOnce() -> QueryResult) -> QueryResult {
+        let call = || {
+            let started = Instant::now();
+            let result = query();
+            Call { result, elapsed: started.elapsed(), reused: false, finished: Instant::now() }
+        };
+        self.call_with(supplier, request, call).result
+    }
+
+    /// Runs `call` for `supplier` and `request`, or returns the memoized result of an identical
+    /// earlier call.
+    pub(crate) fn call_with(&self, supplier: &Arc<dyn Supplier>, request: &SupplierRequest, call: impl FnOnce() -> Call) -> Call {
+        if self.state.closed.load(Ordering::SeqCst) {
+            return call();
+        }
+        let key = Arc::as_ptr(supplier) as *const () as usize;
+        // Stored requests must not hold the memo itself, or it would never be freed. Request ids
+        // only trace calls, so stages tracing the same request differently still share results.
+        let mut stored = request.clone();
+        stored.context.memo = None;
+        stored.context.request_id = None;
+        let slot = {
+            let mut entries = self.state.entries.lock().unwrap_or_else(|e| e.into_inner());
+            match entries.iter().find(|(ptr, req, _)| *ptr == key && *req == stored) {
+                Some((_, _, slot)) => slot.clone(),
+                None => {
+                    let slot = Arc::new(OnceLock::new());
+                    entries.push((key, stored, slot.clone()));
+                    slot
+                }
+            }
+        };
+
+        let mut called = false;
+        let result = slot.get_or_init(|| {
+            called = true;
+            call()
+        });
+        if called { result.clone() } else { result.reused() }
+    }
+
+    /// Forgets every result and stops memoizing.
+    fn close(&self) {
+        self.state.closed.store(true, Ordering::SeqCst);
+        self.state.entries.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
+
+/// The memo is bookkeeping of the query serving a request, not part of what the request asks
+/// for, so requests compare equal whatever memo they carry.
+impl PartialEq for QueryMemo {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl fmt::Debug for QueryMemo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QueryMemo").finish_non_exhaustive()
+    }
+}
+
+/// Closes the memo opened by a query once the query ends.
+pub(crate) struct MemoScope(QueryMemo);
+
+impl Drop for MemoScope {
+    fn drop(&mut self) {
+        self.0.close();
+    }
+}
@@ -0,0 +1,189 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+/// A unit of work run by an [`Executor`].
+pub type Task<'a> = Box<dyn FnOnce() + Send + 'a>;
+
+/// Runs the parallel work of supplier groups: the fan-out of the `Parallel` and `Race`
+/// strategies, streamed and batched queries, and background warm-ups.
+///
+/// The background work of `HedgingSupplier`, `ShadowSupplier`, `CachingSupplier` refreshes and
+/// `HealthMonitor` checks runs on an executor too, given with their `with_executor`, except the
+/// loop of a started `HealthMonitor`, which keeps a thread of its own.
+///
+/// Groups use [`ThreadExecutor`] unless given another executor with
+/// `BasicSupplierGroup::with_executor`. Implement the trait to run group work on an existing
+/// pool, e.g. rayon's `ThreadPool::in_place_scope` for `run_all` and `ThreadPool::spawn`
+/// for `spawn`. Executors may run tasks on the calling thread, which only makes the group's
+/// work less parallel: groups spawn one task per supplier call and submit the next call only
+/// as results are consumed, so a race won by the first call run on the calling thread makes
+/// no further calls.
+///
+/// # Example
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+/// use serde_json::json;
+/// use supplier_kit::executor::{Executor, Task};
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// /// Runs everything on the calling thread, counting the tasks.
+/// #[derive(Default)]
+/// struct Inline(AtomicUsize);
+///
+/// impl Executor for Inline {
+///     fn run_all<'a>(&self, tasks: Vec<Task<'a>>) {
+///         self.0.fetch_add(tasks.len(), Ordering::SeqCst);
+///         tasks.into_iter().for_each(|task| task());
+///     }
+///
+///     fn spawn(&self, task: Task<'static>) {
+///         self.0.fetch_add(1, Ordering::SeqCst);
+///         task();
+///     }
+/// }
+///
+/// let executor = Arc::new(Inline::default());
+/// let mut group = BasicSupplierGroup::new("marketplaces")
+///     .with_strategy(QueryStrategy::Parallel)
+///     .with_executor(executor.clone());
+/// group.add_supplier(MockSupplierBuilder::new("a").respond_default(json!([])).build());
+/// group.add_supplier(MockSupplierBuilder::new("b").respond_default(json!([])).build());
+///
+/// let result = group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+/// assert_eq!(result.successes.len(), 2);
+/// assert_eq!(executor.0.load(Ordering::SeqCst), 2);
+/// ```
+pub trait Executor: Send + Sync {
+    /// Runs every task, in parallel where the executor allows, and returns once all of them
+    /// have finished. Tasks may borrow from the caller.
+    fn run_all<'a>(&self, tasks: Vec<Task<'a>>);
+
+    /// Starts `task` without waiting for it to finish.
+    fn spawn(&self, task: Task<'static>);
+}
+
+/// Runs every task on a new OS thread: scoped threads for `run_all`, detached threads for
+/// `spawn`. The number of threads is not bounded beyond the group's `with_max_concurrency`.
+///
+/// This is the default executor of supplier groups.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ThreadExecutor;
+
+impl Executor for ThreadExecutor {
+    fn run_all<'a>(&self, tasks: Vec<Task<'a>>) {
+        thread::scope(|scope| {
+            for task in tasks {
+                scope.spawn(task);
+            }
+        });
+    }
+
+    fn spawn(&self, task: Task<'static>) {
+        thread::spawn(task);
+    }
+}
+
+/// Runs tasks on at most `max_threads` threads at a time, shared by everything using the
+/// executor; when all of them are busy, tasks run on the calling thread instead, so work never
+/// waits for a thread and nested groups sharing the executor cannot deadlock.
+///
+/// Share one executor between groups to cap the threads of a whole process.
+/// `BoundedExecutor::new(0)` never starts a thread.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use serde_json::json;
+/// use supplier_kit::executor::BoundedExecutor;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let executor = Arc::new(BoundedExecutor::new(4));
+/// let mut group = BasicSupplierGroup::new("marketplaces")
+///     .with_strategy(QueryStrategy::Parallel)
+///     .with_executor(executor.clone());
+/// for name in ["a", "b", "c", "d", "e", "f"] {
+///     group.add_supplier(MockSupplierBuilder::new(name).respond_default(json!([])).build());
+/// }
+///
+/// let result = group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+/// assert_eq!(result.successes.len(), 6);
+/// assert_eq!(executor.active_threads(), 0);
+/// ```
+#[derive(Debug)]
+pub struct BoundedExecutor {
+    max_threads: usize,
+    active: Arc<AtomicUsize>,
+}
+
+impl BoundedExecutor {
+    /// Creates an executor running at most `max_threads` threads at a time.
+    pub fn new(max_threads: usize) -> Self {
+        Self {
+            max_threads,
+            active: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Returns the maximum number of threads the executor runs at a time.
+    pub fn max_threads(&self) -> usize {
+        self.max_threads
+    }
+
+    /// Returns the number of threads currently running tasks.
+    pub fn active_threads(&self) -> usize {
+        self.active.load(Ordering::SeqCst)
+    }
+
+    /// Reserves a thread, if fewer than `max_threads` are running.
+    fn acquire(&self) -> Option<ThreadPermit> {
+        self.active
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |active| (active < self.max_threads).then_some(active + 1))
+            .ok()
+            .map(|_| ThreadPermit(self.active.clone()))
+    }
+}
+
+impl Executor for BoundedExecutor {
+    fn run_all<'a>(&self, tasks: Vec<Task<'a>>) {
+        thread::scope(|scope| {
+            for task in tasks {
+                match self.acquire() {
+                    Some(permit) => {
+                        scope.spawn(move || {
+                            let _permit = permit;
+                            task();
+                        });
+                    }
+                    None => task(),
+                }
+            }
+        });
+    }
+
+    fn spawn(&self, task: Task<'static>) {
+        match self.acquire() {
+            Some(permit) => {
+                thread::spawn(move || {
+                    let _permit = permit;
+                    task();
+                });
+            }
+            None => task(),
+        }
+    }
+}
+
+/// A reserved thread of a `BoundedExecutor`, released on drop.
+struct ThreadPermit(Arc<AtomicUsize>);
+
+impl Drop for ThreadPermit {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,2 @@
+/// CSV and TSV parsing into JSON arrays of objects.
+pub mod csv;
@@ -0,0 +1,328 @@
+use std::collections::{HashMap, HashSet};
+use serde_json::{Map, Value};
+use crate::errors::SupplierError;
+use crate::mapping::ResponseNormalizer;
+use crate::models::SupplierResponse;
+
+/// The type a CSV column is coerced to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    /// Booleans and numbers are detected per cell; everything else stays a string.
+    Auto,
+    /// The cell is kept as a string, e.g. for SKUs or postal codes that look like numbers.
+    String,
+    /// The cell must be an integer.
+    Integer,
+    /// The cell must be a number.
+    Float,
+    /// The cell must be `true` or `false` (case-insensitive).
+    Boolean,
+}
+
+/// Where the column names of a CSV payload come from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsvHeader {
+    /// The first row holds the column names.
+    FirstRow,
+    /// The first row holds the column names if it looks like a header: none of its cells is a
+    /// number or boolean while the row below has one in the same column.
+    Infer,
+    /// The payload has no header row; columns are named `column_1`, `column_2`, and so on.
+    None,
+    /// The payload has no header row; columns get the given names.
+    Names(Vec<String>),
+}
+
+/// Parses CSV and TSV payloads into a JSON array with one object per row, keyed by column name.
+///
+/// Fields follow RFC 4180: they may be quoted, quoted fields may contain delimiters, line
+/// breaks and doubled quotes, and rows may end in `\n` or `\r\n`. Blank lines and a leading
+/// byte order mark are ignored. Empty header cells are named after their position and
+/// repeated names get a numeric suffix (`price`, `price_2`).
+///
+/// By default every column uses `ColumnType::Auto` and empty cells become `null`; numbers with
+/// leading zeros, such as `007`, stay strings.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::formats::csv::{ColumnType, CsvParser};
+///
+/// let parser = CsvParser::new().with_column_type("sku", ColumnType::String);
+/// let rows = parser
+///     .parse("sku,name,price,in_stock\n100,\"Lamp, brass\",12.50,true\n101,Chair,,false\n")
+///     .unwrap();
+/// assert_eq!(
+///     rows,
+///     json!([
+///         { "sku": "100", "name": "Lamp, brass", "price": 12.5, "in_stock": true },
+///         { "sku": "101", "name": "Chair", "price": null, "in_stock": false }
+///     ])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsvParser {
+    delimiter: char,
+    header: CsvHeader,
+    coerce: bool,
+    column_types: HashMap<String, ColumnType>,
+}
+
+impl Default for CsvParser {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            header: CsvHeader::FirstRow,
+            coerce: true,
+            column_types: HashMap::new(),
+        }
+    }
+}
+
+impl CsvParser {
+    /// Creates a parser for comma-separated payloads with a header row.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a parser for tab-separated payloads with a header row.
+    pub fn tsv() -> Self {
+        Self::new().with_delimiter('\t')
+    }
+
+    /// Sets the field delimiter, e.g. `;` for spreadsheets exported with a European locale.
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets where the column names come from.
+    pub fn with_header(mut self, header: CsvHeader) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Sets whether cells are coerced to the type of their column. When off, every non-empty
+    /// cell is a string and empty cells are empty strings.
+    pub fn with_type_coercion(mut self, coerce: bool) -> Self {
+        self.coerce = coerce;
+        self
+    }
+
+    /// Sets the type of the column named `column`, overriding `ColumnType::Auto`.
+    pub fn with_column_type(mut self, column: &str, column_type: ColumnType) -> Self {
+        self.column_types.insert(column.to_string(), column_type);
+        self
+    }
+
+    /// Parses a payload into a JSON array of row objects.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::Upstream` if a quoted field is not closed, a row has more
+    /// fields than there are columns, or a cell does not match its column type. Rows with
+    /// fewer fields are padded with `null`.
+    pub fn parse(&self, text: &str) -> Result<Value, SupplierError> {
+        let rows = self.records(text.strip_prefix('\u{feff}').unwrap_or(text))?;
+        let (names, data_start) = match &self.header {
+            CsvHeader::FirstRow => (rows.first().map(|(_, row)| row.clone()).unwrap_or_default(), 1),
+            CsvHeader::Infer if rows.first().is_some_and(|_| self.looks_like_header(&rows)) => (rows[0].1.clone(), 1),
+            CsvHeader::Infer | CsvHeader::None => (Vec::new(), 0),
+            CsvHeader::Names(names) => (names.clone(), 0),
+        };
+        let width = rows.iter().map(|(_, row)| row.len()).max().unwrap_or(0);
+        let names = column_names(names, if data_start == 1 { 0 } else { width });
+
+        let mut objects = Vec::new();
+        for (line, row) in rows.iter().skip(data_start) {
+            if row.len() > names.len() {
+                return Err(invalid(*line, &format!("expected {} fields, found {}", names.len(), row.len())));
+            }
+            let mut object = Map::new();
+            for (index, name) in names.iter().enumerate() {
+                let value = match row.get(index) {
+                    Some(cell) => self.coerce(name, cell).map_err(|e| invalid(*line, &e))?,
+                    None => Value::Null,
+                };
+                object.insert(name.clone(), value);
+            }
+            objects.push(Value::Object(object));
+        }
+        Ok(Value::Array(objects))
+    }
+
+    /// Parses the CSV body of `response` into its `data`, removing the body.
+    ///
+    /// Bodies of type `text/csv` are parsed with this parser and bodies of type
+    /// `text/tab-separated-values` with a tab delimiter; responses without a body, or with a
+    /// body of another type, are returned unchanged.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::Upstream` if the body is not valid UTF-8 or cannot be parsed.
+    pub fn parse_response(&self, mut response: SupplierResponse) -> Result<SupplierResponse, SupplierError> {
+        let Some(body) = response.body.take_if(|body| delimited(&body.content_type).is_some()) else {
+            return Ok(response);
+        };
+        let text = body
+            .as_text()
+            .ok_or_else(|| SupplierError::Upstream("CSV response is not valid UTF-8".to_string()))?;
+        response.data = match delimited(&body.content_type) {
+            Some('\t') => self.clone().with_delimiter('\t').parse(text)?,
+            _ => self.parse(text)?,
+        };
+        Ok(response)
+    }
+
+    /// Splits the payload into records, each with the line number it starts on.
+    fn records(&self, text: &str) -> Result<Vec<(usize, Vec<String>)>, SupplierError> {
+        let mut records = Vec::new();
+        let mut record = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut line = 1;
+        let mut start = 1;
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if quoted {
+                match c {
+                    '"' if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    '"' => quoted = false,
+                    c => {
+                        if c == '\n' {
+                            line += 1;
+                        }
+                        field.push(c);
+                    }
+                }
+                continue;
+            }
+            match c {
+                '"' if field.is_empty() => quoted = true,
+                c if c == self.delimiter => record.push(std::mem::take(&mut field)),
+                '\r' if chars.peek() == Some(&'\n') => {}
+                '\n' => {
+                    push_record(&mut records, &mut record, &mut field, start);
+                    line += 1;
+                    start = line;
+                }
+                c => field.push(c),
+            }
+        }
+        if quoted {
+            return Err(invalid(start, "unterminated quoted field"));
+        }
+        push_record(&mut records, &mut record, &mut field, start);
+        Ok(records)
+    }
+
+    fn looks_like_header(&self, rows: &[(usize, Vec<String>)]) -> bool {
+        let (first, second) = (&rows[0].1, rows.get(1).map(|(_, row)| row));
+        let typed = |cell: &str| !matches!(auto(cell), Value::String(_) | Value::Null);
+        if first.iter().any(|cell| typed(cell)) {
+            return false;
+        }
+        second.is_none_or(|second| second.iter().any(|cell| typed(cell)))
+    }
+
+    fn coerce(&self, column: &str, cell: &str) -> Result<Value, String> {
+        if !self.coerce {
+            return Ok(Value::String(cell.to_string()));
+        }
+        let trimmed = cell.trim();
+        if trimmed.is_empty() {
+            return Ok(Value::Null);
+        }
+        let column_type = self.column_types.get(column).copied().unwrap_or(ColumnType::Auto);
+        let mismatch = || format!("column '{}' expects {:?}, found '{}'", column, column_type, cell);
+        match column_type {
+            ColumnType::Auto => Ok(auto(trimmed)),
+            ColumnType::String => Ok(Value::String(cell.to_string())),
+            ColumnType::Integer => trimmed.parse::<i64>().map(Value::from).map_err(|_| mismatch()),
+            ColumnType::Float => trimmed
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number)
+                .ok_or_else(mismatch),
+            ColumnType::Boolean => match trimmed.to_ascii_lowercase().as_str() {
+                "true" => Ok(Value::Bool(true)),
+                "false" => Ok(Value::Bool(false)),
+                _ => Err(mismatch()),
+            },
+        }
+    }
+}
+
+impl ResponseNormalizer for CsvParser {
+    fn normalize_response(&self, response: SupplierResponse) -> Result<SupplierResponse, SupplierError> {
+        self.parse_response(response)
+    }
+}
+
+/// Detects booleans and numbers; numbers with leading zeros stay strings.
+fn auto(cell: &str) -> Value {
+    let trimmed = cell.trim();
+    match trimmed {
+        "" => return Value::Null,
+        "true" | "TRUE" | "True" => return Value::Bool(true),
+        "false" | "FALSE" | "False" => return Value::Bool(false),
+        _ => {}
+    }
+    let digits = trimmed.strip_prefix('-').unwrap_or(trimmed);
+    let leading_zero = digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.");
+    if !leading_zero && digits.starts_with(|c: char| c.is_ascii_digit()) {
+        if let Ok(n) = trimmed.parse::<i64>() {
+            return Value::from(n);
+        }
+        if let Some(n) = trimmed.parse::<f64>().ok().and_then(serde_json::Number::from_f64) {
+            return Value::Number(n);
+        }
+    }
+    Value::String(cell.to_string())
+}
+
+fn push_record(records: &mut Vec<(usize, Vec<String>)>, record: &mut Vec<String>, field: &mut String, line: usize) {
+    if record.is_empty() && field.is_empty() {
+        return;
+    }
+    record.push(std::mem::take(field));
+    records.push((line, std::mem::take(record)));
+}
+
+/// Names blank columns after their position, de-duplicates repeated names and adds positional
+/// names up to `width` columns.
+fn column_names(names: Vec<String>, width: usize) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let count = names.len().max(width);
+    (0..count)
+        .map(|index| {
+            let base = match names.get(index).map(|name| name.trim()) {
+                Some(name) if !name.is_empty() => name.to_string(),
+                _ => format!("column_{}", index + 1),
+            };
+            let mut name = base.clone();
+            let mut suffix = 2;
+            while !seen.insert(name.clone()) {
+                name = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+            name
+        })
+        .collect()
+}
+
+/// Returns the delimiter for CSV and TSV content types.
+fn delimited(content_type: &str) -> Option<char> {
+    let essence = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+    match essence.as_str() {
+        "text/csv" | "application/csv" => Some(','),
+        "text/tab-separated-values" => Some('\t'),
+        _ => None,
+    }
+}
+
+fn invalid(line: usize, message: &str) -> SupplierError {
+    SupplierError::Upstream(format!("invalid CSV response: line {}: {}", line, message))
+}
@@ -0,0 +1,120 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use crate::descriptor::GroupDescriptor;
+use crate::errors::SupplierError;
+use crate::models::SupplierRequest;
+use crate::supplier::RegistrationOutcome;
+use crate::supplier_group::{SupplierGroup, SupplierGroupResult};
+
+/// A supplier group that can be shared between threads.
+pub type SharedGroup = Arc<dyn SupplierGroup + Send + Sync>;
+
+/// A registry for managing supplier groups by name, the group counterpart of
+/// `SupplierRegistry`.
+///
+/// Services that take a group name from an HTTP path or a message can look the group up here
+/// instead of keeping their own map of groups.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::group_registry::GroupRegistry;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier_group::BasicSupplierGroup;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let mut marketplaces = BasicSupplierGroup::new("marketplaces");
+/// marketplaces.add_supplier(MockSupplierBuilder::new("shop").respond_default(json!({ "items": [] })).build());
+///
+/// let mut groups = GroupRegistry::new();
+/// groups.register("marketplaces", marketplaces);
+///
+/// let request = SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }));
+/// let result = groups.query("marketplaces", request).unwrap();
+/// assert_eq!(result.successes.len(), 1);
+/// assert_eq!(groups.describe_all()["marketplaces"].suppliers, ["shop"]);
+/// ```
+#[derive(Default)]
+pub struct GroupRegistry {
+    groups: HashMap<String, SharedGroup>,
+}
+
+impl GroupRegistry {
+    /// Creates a new, empty group registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `group` under `name`, replacing any group with the same name.
+    ///
+    /// The name may differ from the group's own `group_name`, e.g. to expose one group under
+    /// a versioned route.
+    pub fn register<G>(&mut self, name: &str, group: G) -> RegistrationOutcome
+    where
+        G: SupplierGroup + Send + Sync + 'static,
+    {
+        self.register_arc(name, Arc::new(group))
+    }
+
+    /// Registers an already shared group under `name`, replacing any group with the same name.
+    pub fn register_arc(&mut self, name: &str, group: SharedGroup) -> RegistrationOutcome {
+        match self.groups.insert(name.to_string(), group) {
+            Some(_) => RegistrationOutcome::Replaced,
+            None => RegistrationOutcome::Added,
+        }
+    }
+
+    /// Registers `group` under `name` only if the name is free.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::AlreadyExists` if a group is already registered under `name`.
+    pub fn try_register<G>(&mut self, name: &str, group: G) -> Result<(), SupplierError>
+    where
+        G: SupplierGroup + Send + Sync + 'static,
+    {
+        if self.contains(name) {
+            return Err(SupplierError::AlreadyExists(name.to_string()));
+        }
+        self.register(name, group);
+        Ok(())
+    }
+
+    /// Removes the group registered under `name`, returning it if it existed.
+    pub fn remove(&mut self, name: &str) -> Option<SharedGroup> {
+        self.groups.remove(name)
+    }
+
+    /// Retrieves the group registered under `name`.
+    pub fn get(&self, name: &str) -> Option<SharedGroup> {
+        self.groups.get(name).cloned()
+    }
+
+    /// Returns whether a group is registered under `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.groups.contains_key(name)
+    }
+
+    /// Retrieves the names of all registered groups, sorted.
+    pub fn all_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.groups.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Describes every registered group, keyed by the name it is registered under.
+    pub fn describe_all(&self) -> BTreeMap<String, GroupDescriptor> {
+        self.groups
+            .iter()
+            .map(|(name, group)| (name.clone(), group.describe()))
+            .collect()
+    }
+
+    /// Queries the group registered under `name`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::NotFound` if no group is registered under `name`.
+    pub fn query(&self, name: &str, request: SupplierRequest) -> Result<SupplierGroupResult, SupplierError> {
+        let group = self.groups.get(name).ok_or(SupplierError::NotFound)?;
+        Ok(group.query(request))
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+use prost::Message;
+use prost_reflect::{DescriptorPool, DeserializeOptions, DynamicMessage, MessageDescriptor, MethodDescriptor, SerializeOptions};
+use tokio::runtime::{Builder, Runtime};
+use tonic::client::Grpc;
+use tonic::codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder};
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Code, Status};
+use crate::decorators::size_limit::too_large;
+use crate::descriptor::{OperationDescriptor, SupplierDescriptor};
+use crate::errors::SupplierError;
+use crate::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+use crate::throttling::parse_retry_after;
+
+/// A supplier backed by a gRPC service, with JSON params translated to protobuf at runtime.
+///
+/// Each supported operation is mapped to a unary method of the service, identified by its full
+/// name (`package.Service/Method`). Request params are converted to the method's input message
+/// using the descriptor set, and the output message is converted back to JSON with protobuf
+/// field names (`snake_case`), default values included and 64-bit integers as numbers.
+///
+/// gRPC status codes are mapped to supplier errors: `DEADLINE_EXCEEDED` becomes `Timeout`,
+/// `UNAUTHENTICATED`/`PERMISSION_DENIED` become `Unauthorized`, `NOT_FOUND` becomes `NotFound`,
+/// `INVALID_ARGUMENT` becomes `InvalidInput`, `UNIMPLEMENTED` becomes `UnsupportedOperation`,
+/// `RESOURCE_EXHAUSTED` becomes `Throttled` (with the wait from `retry-after` metadata, if
+/// present), and everything else becomes `Upstream`.
+///
+/// Calls run on a private tokio runtime, so `query` blocks the calling thread and must not be
+/// called from inside an async task. The connection is established lazily on the first query.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use supplier_kit::grpc::GrpcSupplier;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier::Supplier;
+///
+/// let descriptor_set = std::fs::read("catalog.bin").unwrap();
+/// let supplier = GrpcSupplier::new("catalog", "http://127.0.0.1:50051", &descriptor_set)
+///     .unwrap()
+///     .with_method(SupplierOperation::Search, "shop.v1.Catalog/Search")
+///     .unwrap()
+///     .with_timeout(Duration::from_secs(2));
+///
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({ "query": "lamp" }));
+/// let response = supplier.query(request).unwrap();
+/// println!("{}", response.data["items"]);
+/// ```
+pub struct GrpcSupplier {
+    name: String,
+    pool: DescriptorPool,
+    methods: HashMap<String, (SupplierOperation, MethodDescriptor)>,
+    channel: Channel,
+    timeout: Option<Duration>,
+    max_response_size: Option<usize>,
+    runtime: Runtime,
+}
+
+impl GrpcSupplier {
+    /// Creates a supplier for the service at `endpoint` from an encoded `FileDescriptorSet`,
+    /// as produced by `protoc --descriptor_set_out --include_imports`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the descriptor set or endpoint is invalid.
+    pub fn new(name: &str, endpoint: &str, descriptor_set: &[u8]) -> Result<Self, SupplierError> {
+        let pool = DescriptorPool::decode(descriptor_set)
+            .map_err(|e| SupplierError::InvalidInput(format!("invalid descriptor set: {}", e)))?;
+        Self::from_pool(name, endpoint, pool)
+    }
+
+    /// Creates a supplier for the service at `endpoint` from an already decoded descriptor pool.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the endpoint is not a valid URI, or
+    /// `SupplierError::Internal` if the private runtime cannot be started.
+    pub fn from_pool(name: &str, endpoint: &str, pool: DescriptorPool) -> Result<Self, SupplierError> {
+        let endpoint = Endpoint::from_shared(endpoint.to_string())
+            .map_err(|e| SupplierError::InvalidInput(format!("invalid endpoint '{}': {}", endpoint, e)))?;
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .map_err(|e| SupplierError::Internal(format!("failed to start gRPC runtime: {}", e)))?;
+        let channel = {
+            let _guard = runtime.enter();
+            endpoint.connect_lazy()
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            pool,
+            methods: HashMap::new(),
+            channel,
+            timeout: None,
+            max_response_size: None,
+            runtime,
+        })
+    }
+
+    /// Maps `operation` to the unary method `method`, written as `package.Service/Method`
+    /// (or `package.Service.Method`).
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the method is not in the descriptor pool, or
+    /// `SupplierError::UnsupportedOperation` if it is a streaming method.
+    pub fn with_method(mut self, operation: SupplierOperation, method: &str) -> Result<Self, SupplierError> {
+        let descriptor = self.find_method(method)?;
+        if descriptor.is_client_streaming() || descriptor.is_server_streaming() {
+            return Err(SupplierError::UnsupportedOperation(format!(
+                "{} is a streaming method; only unary methods are supported",
+                descriptor.full_name()
+            )));
+        }
+        self.methods.insert(operation.as_str().to_string(), (operation, descriptor));
+        Ok(self)
+    }
+
+    /// Fails calls that take longer than `timeout` with `SupplierError::Timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Fails responses whose encoded message is larger than `bytes` with
+    /// `SupplierError::ResponseTooLarge`; the message is not decoded. Without a limit, tonic's
+    /// default of 4 MiB applies.
+    pub fn with_max_response_size(mut self, bytes: usize) -> Self {
+        self.max_response_size = Some(bytes);
+        self
+    }
+
+    fn find_method(&self, method: &str) -> Result<MethodDescriptor, SupplierError> {
+        let (service, name) = method
+            .rsplit_once('/')
+            .or_else(|| method.rsplit_once('.'))
+            .ok_or_else(|| SupplierError::InvalidInput(format!("invalid gRPC method '{}'", method)))?;
+        self.pool
+            .get_service_by_name(service.trim_start_matches('/'))
+            .and_then(|service| service.methods().find(|m| m.name() == name))
+            .ok_or_else(|| SupplierError::InvalidInput(format!("unknown gRPC method '{}'", method)))
+    }
+
+    async fn call(&self, method: &MethodDescriptor, message: DynamicMessage) -> Result<DynamicMessage, Status> {
+        let path = format!("/{}/{}", method.parent_service().full_name(), method.name());
+        let path = PathAndQuery::try_from(path).map_err(|e| Status::internal(e.to_string()))?;
+
+        let mut client = Grpc::new(self.channel.clone());
+        if let Some(limit) = self.max_response_size {
+            client = client.max_decoding_message_size(limit);
+        }
+        client.ready().await.map_err(|e| Status::unavailable(e.to_string()))?;
+        let response = client.unary(tonic::Request::new(message), path, DynamicCodec::new(method.output())).await?;
+        Ok(response.into_inner())
+    }
+}
+
+impl Supplier for GrpcSupplier {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let (_, method) = self
+            .methods
+            .get(request.operation.as_str())
+            .ok_or_else(|| SupplierError::UnsupportedOperation(request.operation.as_str().to_string()))?;
+
+        let message = DynamicMessage::deserialize_with_options(
+            method.input(),
+            request.params,
+            &DeserializeOptions::new().deny_unknown_fields(true),
+        )
+        .map_err(|e| SupplierError::InvalidInput(format!("params do not match {}: {}", method.input().full_name(), e)))?;
+
+        let call = self.call(method, message);
+        let response = match self.timeout {
+            Some(timeout) => self
+                .runtime
+                .block_on(async { tokio::time::timeout(timeout, call).await })
+                .map_err(|_| SupplierError::Timeout)?,
+            None => self.runtime.block_on(call),
+        }
+        .map_err(|status| match self.max_response_size {
+            Some(limit) if status.code() == Code::OutOfRange && status.message().contains("message length too large") => {
+                too_large(&self.name, limit)
+            }
+            _ => status_to_error(status),
+        })?;
+
+        let options = SerializeOptions::new()
+            .use_proto_field_name(true)
+            .skip_default_fields(false)
+            .stringify_64_bit_integers(false);
+        let data = response
+            .serialize_with_options(serde_json::value::Serializer, &options)
+            .map_err(|e| SupplierError::Internal(format!("failed to convert gRPC response: {}", e)))?;
+        Ok(SupplierResponse::new(data))
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        let mut methods: Vec<_> = self.methods.values().collect();
+        methods.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+        methods.into_iter().fold(SupplierDescriptor::new(&self.name), |descriptor, (operation, method)| {
+            descriptor.with_operation(
+                OperationDescriptor::new(operation.clone())
+                    .with_summary(&format!("{}/{}", method.parent_service().full_name(), method.name())),
+            )
+        })
+    }
+}
+
+fn status_to_error(status: Status) -> SupplierError {
+    let message = status.message().to_string();
+    match status.code() {
+        Code::DeadlineExceeded => SupplierError::Timeout,
+        Code::Unauthenticated | Code::PermissionDenied => SupplierError::Unauthorized,
+        Code::NotFound => SupplierError::NotFound,
+        Code::InvalidArgument => SupplierError::InvalidInput(message),
+        Code::Unimplemented => SupplierError::UnsupportedOperation(message),
+        Code::ResourceExhausted => SupplierError::Throttled {
+            retry_after: status
+                .metadata()
+                .get("retry-after")
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| parse_retry_after(value, SystemTime::now())),
+        },
+        code => SupplierError::Upstream(format!("{:?}: {}", code, message)),
+    }
+}
+
+/// A tonic codec for `DynamicMessage`s: encodes any message and decodes as a fixed message type.
+///
+/// Clients decode with the method's output descriptor; servers decode with its input descriptor.
+#[derive(Debug, Clone)]
+pub struct DynamicCodec {
+    decode_as: MessageDescriptor,
+}
+
+impl DynamicCodec {
+    /// Creates a codec that decodes messages as `decode_as`.
+    pub fn new(decode_as: MessageDescriptor) -> Self {
+        Self { decode_as }
+    }
+}
+
+impl Codec for DynamicCodec {
+    type Encode = DynamicMessage;
+    type Decode = DynamicMessage;
+    type Encoder = DynamicCodec;
+    type Decoder = DynamicCodec;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        self.clone()
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        self.clone()
+    }
+}
+
+impl Encoder for DynamicCodec {
+    type Item = DynamicMessage;
+    type Error = Status;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+        item.encode(dst).map_err(|e| Status::internal(e.to_string()))
+    }
+}
+
+impl Decoder for DynamicCodec {
+    type Item = DynamicMessage;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+        DynamicMessage::decode(self.decode_as.clone(), src)
+            .map(Some)
+            .map_err(|e| Status::internal(e.to_string()))
+    }
+}
+
@@ -0,0 +1,357 @@
+use std::collections::{BTreeMap, HashSet};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Condvar, Mutex, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use crate::errors::SupplierError;
+use crate::events::{EventBus, SupplierEvent};
+use crate::executor::{Executor, ThreadExecutor};
+use crate::supplier::{Supplier, SupplierRegistry};
+
+/// The latest health check outcome of one supplier.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupplierHealth {
+    /// Whether groups consulting the health map should query the supplier.
+    pub healthy: bool,
+
+    /// The error of the latest check, if it failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<SupplierError>,
+
+    /// How many checks in a row have failed, the latest included.
+    pub consecutive_failures: u32,
+
+    /// When the latest check finished, in milliseconds since the Unix epoch.
+    pub checked_at_ms: u64,
+
+    /// How long the latest check took, in microseconds.
+    pub latency_us: u64,
+}
+
+/// The outcome of a readiness or liveness probe, see `SupplierRegistry::readiness` and
+/// `SupplierRegistry::liveness`.
+///
+/// Serves as the body of Kubernetes-style probe endpoints: answer `200` when `ok` is set and
+/// `503` otherwise.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProbeReport {
+    /// Whether the probe passed.
+    pub ok: bool,
+
+    /// How many of the considered suppliers had to pass.
+    pub required: usize,
+
+    /// The considered suppliers that passed, sorted.
+    pub passing: Vec<String>,
+
+    /// The considered suppliers that failed, sorted.
+    pub failing: Vec<String>,
+
+    /// Why the probe failed; `None` when it passed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl ProbeReport {
+    /// Builds a report that passes when at least `required` suppliers pass.
+    pub(crate) fn new(required: usize, mut passing: Vec<String>, mut failing: Vec<String>, failure: impl FnOnce(usize) -> String) -> Self {
+        passing.sort();
+        failing.sort();
+        let ok = passing.len() >= required;
+        let reason = (!ok).then(|| failure(passing.len()));
+        Self { ok, required, passing, failing, reason }
+    }
+}
+
+/// Health of suppliers as last checked by a `HealthMonitor`, shared with the groups that
+/// consult it before fan-out.
+///
+/// Suppliers that were never checked count as healthy.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::health::HealthMap;
+///
+/// let health = HealthMap::new();
+/// assert!(health.is_healthy("partner"));
+/// health.record("partner", Err(SupplierError::Timeout), Duration::from_millis(5), 1);
+/// assert!(!health.is_healthy("partner"));
+/// assert_eq!(health.unhealthy(), vec!["partner".to_string()]);
+/// ```
+#[derive(Debug, Default)]
+pub struct HealthMap {
+    entries: RwLock<BTreeMap<String, SupplierHealth>>,
+}
+
+impl HealthMap {
+    /// Creates an empty health map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the outcome of one check of `supplier`, marking it unhealthy once
+    /// `failure_threshold` checks in a row have failed.
+    ///
+    /// Returns the supplier's updated health.
+    pub fn record(&self, supplier: &str, result: Result<(), SupplierError>, latency: Duration, failure_threshold: u32) -> SupplierHealth {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        let failures = entries.get(supplier).map_or(0, |h| h.consecutive_failures);
+        let consecutive_failures = if result.is_ok() { 0 } else { failures.saturating_add(1) };
+        let health = SupplierHealth {
+            healthy: consecutive_failures < failure_threshold.max(1),
+            error: result.err(),
+            consecutive_failures,
+            checked_at_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or_default(),
+            latency_us: latency.as_micros() as u64,
+        };
+        entries.insert(supplier.to_string(), health.clone());
+        health
+    }
+
+    /// Returns the latest health of `supplier`, if it was checked.
+    pub fn get(&self, supplier: &str) -> Option<SupplierHealth> {
+        self.entries.read().unwrap_or_else(|e| e.into_inner()).get(supplier).cloned()
+    }
+
+    /// Returns whether `supplier` passed its latest checks; unchecked suppliers are healthy.
+    pub fn is_healthy(&self, supplier: &str) -> bool {
+        self.get(supplier).is_none_or(|h| h.healthy)
+    }
+
+    /// Returns the names of all unhealthy suppliers, sorted.
+    pub fn unhealthy(&self) -> Vec<String> {
+        self.entries
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter(|(_, h)| !h.healthy)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Returns the health of every checked supplier, by name.
+    pub fn snapshot(&self) -> BTreeMap<String, SupplierHealth> {
+        self.entries.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Forgets the health of `supplier`, e.g. after it was removed from the registry.
+    pub fn remove(&self, supplier: &str) {
+        self.entries.write().unwrap_or_else(|e| e.into_inner()).remove(supplier);
+    }
+}
+
+/// Periodically runs `Supplier::health_check` on every supplier of a registry and records
+/// the outcomes in a shared `HealthMap`.
+///
+/// Groups attached to the map with `BasicSupplierGroup::with_health` skip unhealthy
+/// suppliers, so an outage is noticed by the monitor rather than by failing user traffic.
+/// Checks of one round run concurrently, each on its own thread unless given an executor.
+/// A panicking check counts as a failure, and a check still running after the check timeout
+/// (see [`with_check_timeout`](HealthMonitor::with_check_timeout)) counts as a
+/// `SupplierError::Timeout`; it keeps running in the background, but no longer holds up the
+/// round. Until it returns, later rounds count the supplier as timed out again instead of
+/// starting another check. An executor running checks on the calling thread cannot time them out.
+///
+/// Outcomes are recorded under the supplier's registered name; groups look suppliers up by
+/// their own name, so register suppliers checked for groups under it. A supplier turning
+/// unhealthy or healthy again publishes `SupplierEvent::HealthChanged` on the configured event bus.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use supplier_kit::health::HealthMonitor;
+/// use supplier_kit::supplier::SupplierRegistry;
+/// use supplier_kit::supplier_group::BasicSupplierGroup;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let mut registry = SupplierRegistry::new();
+/// registry.register("partner", MockSupplierBuilder::new("partner").build());
+///
+/// let monitor = HealthMonitor::new(Arc::new(registry), Duration::from_secs(10));
+/// let group = BasicSupplierGroup::new("group1").with_health(monitor.health());
+/// monitor.check_now();
+/// assert!(monitor.health().get("partner").unwrap().healthy);
+///
+/// let handle = monitor.start();
+/// handle.stop();
+/// ```
+pub struct HealthMonitor {
+    registry: Arc<SupplierRegistry>,
+    interval: Duration,
+    failure_threshold: u32,
+    health: Arc<HealthMap>,
+    events: EventBus,
+    executor: Arc<dyn Executor>,
+    check_timeout: Duration,
+    // Registered names of the suppliers whose latest check has not returned yet.
+    running: Arc<Mutex<HashSet<String>>>,
+}
+
+impl HealthMonitor {
+    /// Creates a monitor checking every supplier of `registry` once per `interval`.
+    ///
+    /// By default, a single failed check marks a supplier unhealthy, and a check times out
+    /// after `interval`.
+    pub fn new(registry: Arc<SupplierRegistry>, interval: Duration) -> Self {
+        Self {
+            registry,
+            interval,
+            failure_threshold: 1,
+            health: Arc::new(HealthMap::new()),
+            events: EventBus::new(),
+            executor: Arc::new(ThreadExecutor),
+            check_timeout: interval,
+            running: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    /// Counts checks running longer than `timeout` as failed with `SupplierError::Timeout`.
+    pub fn with_check_timeout(mut self, timeout: Duration) -> Self {
+        self.check_timeout = timeout;
+        self
+    }
+
+    /// Marks suppliers unhealthy only after `failures` checks in a row have failed.
+    pub fn with_failure_threshold(mut self, failures: u32) -> Self {
+        self.failure_threshold = failures.max(1);
+        self
+    }
+
+    /// Records outcomes in `health` instead of a map of the monitor's own.
+    pub fn with_health_map(mut self, health: Arc<HealthMap>) -> Self {
+        self.health = health;
+        self
+    }
+
+    /// Publishes `SupplierEvent::HealthChanged` on `events`.
+    pub fn with_event_bus(mut self, events: EventBus) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Runs the checks on `executor` instead of a thread per supplier. The loop of
+    /// [`start`](Self::start) keeps a dedicated thread, since it runs until stopped.
+    pub fn with_executor(mut self, executor: Arc<dyn Executor>) -> Self {
+        self.executor = executor;
+        self
+    }
+
+    /// Returns the map the monitor records outcomes in.
+    pub fn health(&self) -> Arc<HealthMap> {
+        self.health.clone()
+    }
+
+    /// Checks every supplier once, waiting for all checks to finish or time out.
+    pub fn check_now(&self) {
+        // The snapshot keeps checks from counting as uses of deprecated suppliers.
+        let snapshot = self.registry.snapshot();
+        let mut names = snapshot.all_names();
+        names.sort();
+        let suppliers: Vec<(String, Arc<dyn Supplier>)> = names
+            .into_iter()
+            .filter_map(|name| snapshot.get(&name).map(|supplier| (name, supplier)))
+            .collect();
+
+        let (tx, rx) = mpsc::channel();
+        for (index, (name, supplier)) in suppliers.iter().enumerate() {
+            // A check still hanging from an earlier round is not started again.
+            if !self.running.lock().unwrap_or_else(|e| e.into_inner()).insert(name.clone()) {
+                continue;
+            }
+            let (name, supplier, tx, running) = (name.clone(), supplier.clone(), tx.clone(), self.running.clone());
+            self.executor.spawn(Box::new(move || {
+                let started = Instant::now();
+                let result = panic::catch_unwind(AssertUnwindSafe(|| supplier.health_check())).unwrap_or_else(|_| {
+                    Err(SupplierError::Internal(format!("health check of supplier '{}' panicked", supplier.name())))
+                });
+                running.lock().unwrap_or_else(|e| e.into_inner()).remove(&name);
+                let _ = tx.send((index, result, started.elapsed()));
+            }));
+        }
+        drop(tx);
+        let mut outcomes: Vec<Option<CheckOutcome>> = suppliers.iter().map(|_| None).collect();
+        let deadline = Instant::now() + self.check_timeout;
+        while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+            match rx.recv_timeout(remaining) {
+                Ok((index, result, latency)) => outcomes[index] = Some((result, latency)),
+                Err(_) => break,
+            }
+        }
+
+        for ((name, _), outcome) in suppliers.iter().zip(outcomes) {
+            let (result, latency) = outcome.unwrap_or((Err(SupplierError::Timeout), self.check_timeout));
+            let was_healthy = self.health.is_healthy(name);
+            let health = self.health.record(name, result, latency, self.failure_threshold);
+            if health.healthy != was_healthy {
+                self.events.publish(SupplierEvent::HealthChanged {
+                    supplier: name.clone(),
+                    healthy: health.healthy,
+                    error: health.error,
+                });
+            }
+        }
+    }
+
+    /// Starts checking on a background thread, first right away and then once per interval.
+    pub fn start(self) -> HealthMonitorHandle {
+        let stop = Arc::new((Mutex::new(false), Condvar::new()));
+        let signal = stop.clone();
+        let thread = thread::spawn(move || {
+            let (stopped, wake) = &*signal;
+            loop {
+                self.check_now();
+                let deadline = Instant::now() + self.interval;
+                let mut guard = stopped.lock().unwrap_or_else(|e| e.into_inner());
+                loop {
+                    if *guard {
+                        return;
+                    }
+                    let now = Instant::now();
+                    if now >= deadline {
+                        break;
+                    }
+                    guard = wake.wait_timeout(guard, deadline - now).unwrap_or_else(|e| e.into_inner()).0;
+                }
+            }
+        });
+        HealthMonitorHandle { stop, thread: Some(thread) }
+    }
+}
+
+/// The result and latency of one health check.
+type CheckOutcome = (Result<(), SupplierError>, Duration);
+
+/// Stops a started [`HealthMonitor`]; dropping the handle stops it too.
+pub struct HealthMonitorHandle {
+    stop: Arc<(Mutex<bool>, Condvar)>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl HealthMonitorHandle {
+    /// Stops the monitor and waits for a round of checks in progress to finish.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        let (stopped, wake) = &*self.stop;
+        *stopped.lock().unwrap_or_else(|e| e.into_inner()) = true;
+        wake.notify_all();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for HealthMonitorHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
@@ -0,0 +1,111 @@
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Generates unique identifiers for requests and event correlation.
+///
+/// Implement this trait to plug in an organization's own ID conventions.
+pub trait IdGenerator: Send + Sync {
+    /// Returns a new unique identifier.
+    fn generate(&self) -> String;
+}
+
+impl<F> IdGenerator for F
+where
+    F: Fn() -> String + Send + Sync,
+{
+    fn generate(&self) -> String {
+        self()
+    }
+}
+
+/// Generates time-ordered UUIDv7 identifiers. This is the default generator.
+///
+/// # Example
+/// ```
+/// use supplier_kit::id::{IdGenerator, UuidV7Generator};
+/// let id = UuidV7Generator.generate();
+/// assert_eq!(id.len(), 36);
+/// assert_ne!(id, UuidV7Generator.generate());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidV7Generator;
+
+impl IdGenerator for UuidV7Generator {
+    fn generate(&self) -> String {
+        uuid::Uuid::now_v7().to_string()
+    }
+}
+
+/// Generates ULID identifiers (requires the `ulid` feature).
+#[cfg(feature = "ulid")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UlidGenerator;
+
+#[cfg(feature = "ulid")]
+impl IdGenerator for UlidGenerator {
+    fn generate(&self) -> String {
+        ulid::Ulid::generate().to_string()
+    }
+}
+
+/// Generates 64-bit snowflake identifiers: 41 bits of milliseconds since `epoch_ms`,
+/// 10 bits of worker ID, and a 12-bit per-millisecond sequence.
+///
+/// # Example
+/// ```
+/// use supplier_kit::id::{IdGenerator, SnowflakeGenerator};
+/// let generator = SnowflakeGenerator::new(7);
+/// let a: u64 = generator.generate().parse().unwrap();
+/// let b: u64 = generator.generate().parse().unwrap();
+/// assert!(b > a);
+/// ```
+#[derive(Debug)]
+pub struct SnowflakeGenerator {
+    worker_id: u64,
+    epoch_ms: u64,
+    state: Mutex<(u64, u64)>,
+}
+
+impl SnowflakeGenerator {
+    /// The default epoch: 2020-01-01T00:00:00Z.
+    pub const DEFAULT_EPOCH_MS: u64 = 1_577_836_800_000;
+
+    /// Creates a generator for the given worker ID (only the lowest 10 bits are used).
+    pub fn new(worker_id: u16) -> Self {
+        Self::with_epoch(worker_id, Self::DEFAULT_EPOCH_MS)
+    }
+
+    /// Creates a generator with a custom epoch in milliseconds since the Unix epoch.
+    pub fn with_epoch(worker_id: u16, epoch_ms: u64) -> Self {
+        Self {
+            worker_id: u64::from(worker_id) & 0x3FF,
+            epoch_ms,
+            state: Mutex::new((0, 0)),
+        }
+    }
+
+    fn now_ms(&self) -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        now.saturating_sub(self.epoch_ms)
+    }
+}
+
+impl IdGenerator for SnowflakeGenerator {
+    fn generate(&self) -> String {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let (last_ms, sequence) = *state;
+        let mut now = self.now_ms().max(last_ms);
+
+        let next_sequence = if now == last_ms { (sequence + 1) & 0xFFF } else { 0 };
+        if now == last_ms && next_sequence == 0 {
+            // The sequence overflowed within this millisecond; borrow the next one.
+            now += 1;
+        }
+        *state = (now, next_sequence);
+
+        ((now << 22) | (self.worker_id << 12) | next_sequence).to_string()
+    }
+}
@@ -0,0 +1,254 @@
+use std::fmt;
+use std::str::FromStr;
+use serde_json::Value;
+use crate::errors::SupplierError;
+
+/// A parsed JSONPath expression, used to read values out of request params and response data.
+///
+/// The supported subset covers what routing rules, pipelines and response extraction need:
+///
+/// - the root `$`, which every expression starts with
+/// - member names, as `.name` or `['name']` (also `["name"]`)
+/// - array indexes, as `[0]`; negative indexes such as `[-1]` count from the end
+/// - wildcards, as `.*` or `[*]`, selecting every member of an object or element of an array
+/// - recursive descent, as `..name`, `..*` or `..[0]`, selecting matches at any depth
+///
+/// A JSON pointer such as `/items/0/sku` is accepted as well and selects at most one value;
+/// the empty string selects the whole document.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::json_path::JsonPath;
+///
+/// let data = json!({ "items": [{ "sku": "A1", "price": 5 }, { "sku": "B2", "price": 7 }] });
+///
+/// let skus = JsonPath::parse("$.items[*].sku").unwrap();
+/// assert_eq!(skus.find(&data), vec![&json!("A1"), &json!("B2")]);
+///
+/// let last = JsonPath::parse("$.items[-1]['price']").unwrap();
+/// assert_eq!(last.first(&data), Some(&json!(7)));
+///
+/// assert_eq!(JsonPath::parse("$..price").unwrap().find(&data).len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonPath {
+    source: String,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Child(Selector),
+    Descendant(Selector),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Selector {
+    /// A member of an object.
+    Name(String),
+    /// An element of an array, negative from the end.
+    Index(i64),
+    /// A JSON pointer token: a member of an object or, if numeric, an element of an array.
+    Token(String),
+    Wildcard,
+}
+
+impl JsonPath {
+    /// Parses a JSONPath expression or a JSON pointer.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` naming the path if it is not supported.
+    pub fn parse(path: &str) -> Result<Self, SupplierError> {
+        let segments = if path.is_empty() || path.starts_with('/') {
+            pointer_segments(path)
+        } else {
+            path_segments(path)
+                .ok_or_else(|| SupplierError::InvalidInput(format!("unsupported JSON path '{}'", path)))?
+        };
+        Ok(Self {
+            source: path.to_string(),
+            segments,
+        })
+    }
+
+    /// Returns the expression as written.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns whether the path selects at most one value, i.e. has no wildcard or recursive
+    /// descent.
+    pub fn is_definite(&self) -> bool {
+        self.segments
+            .iter()
+            .all(|segment| matches!(segment, Segment::Child(selector) if *selector != Selector::Wildcard))
+    }
+
+    /// Returns every value the path selects in `value`, in document order.
+    pub fn find<'a>(&self, value: &'a Value) -> Vec<&'a Value> {
+        self.walk(value, false).into_iter().map(|(_, value)| value).collect()
+    }
+
+    /// Returns the JSON pointer of every value the path selects in `value`, in document order,
+    /// e.g. to update the selected values with `Value::pointer_mut`.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::json_path::JsonPath;
+    /// let data = serde_json::json!({ "items": [{ "sku": "A1" }, { "sku": "B2" }] });
+    /// assert_eq!(JsonPath::parse("$..sku").unwrap().locate(&data), ["/items/0/sku", "/items/1/sku"]);
+    /// ```
+    pub fn locate(&self, value: &Value) -> Vec<String> {
+        self.walk(value, true).into_iter().map(|(pointer, _)| pointer).collect()
+    }
+
+    /// Returns the first value the path selects in `value`, if any.
+    pub fn first<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        self.find(value).into_iter().next()
+    }
+
+    /// Evaluates the path, pairing every match with its JSON pointer when `pointers` is set.
+    fn walk<'a>(&self, value: &'a Value, pointers: bool) -> Vec<Node<'a>> {
+        let mut current = vec![(String::new(), value)];
+        for segment in &self.segments {
+            let mut next = Vec::new();
+            for node in current {
+                match segment {
+                    Segment::Child(selector) => select(selector, node, pointers, &mut next),
+                    Segment::Descendant(selector) => {
+                        let mut nodes = Vec::new();
+                        descendants(node, pointers, &mut nodes);
+                        for node in nodes {
+                            select(selector, node, pointers, &mut next);
+                        }
+                    }
+                }
+            }
+            current = next;
+        }
+        current
+    }
+}
+
+impl FromStr for JsonPath {
+    type Err = SupplierError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        Self::parse(path)
+    }
+}
+
+impl fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+fn pointer_segments(pointer: &str) -> Vec<Segment> {
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|token| Segment::Child(Selector::Token(token.replace("~1", "/").replace("~0", "~"))))
+        .collect()
+}
+
+fn path_segments(path: &str) -> Option<Vec<Segment>> {
+    let mut rest = path.strip_prefix('$')?;
+    let mut segments = Vec::new();
+    while !rest.is_empty() {
+        let (descendant, tail) = match rest.strip_prefix("..") {
+            Some(tail) => (true, tail),
+            None => (false, rest),
+        };
+        let (selector, tail) = if let Some(tail) = tail.strip_prefix('[') {
+            bracket(tail)?
+        } else if let Some(tail) = if descendant { Some(tail) } else { tail.strip_prefix('.') } {
+            let end = tail.find(['.', '[']).unwrap_or(tail.len());
+            let name = &tail[..end];
+            let selector = match name {
+                "" => return None,
+                "*" => Selector::Wildcard,
+                name => Selector::Name(name.to_string()),
+            };
+            (selector, &tail[end..])
+        } else {
+            return None;
+        };
+        segments.push(if descendant {
+            Segment::Descendant(selector)
+        } else {
+            Segment::Child(selector)
+        });
+        rest = tail;
+    }
+    Some(segments)
+}
+
+/// Parses the inside of a bracket, after the `[`, returning the selector and what follows `]`.
+fn bracket(tail: &str) -> Option<(Selector, &str)> {
+    for quote in ['\'', '"'] {
+        if let Some(quoted) = tail.strip_prefix(quote) {
+            let end = quoted.find(quote)?;
+            let rest = quoted[end + 1..].strip_prefix(']')?;
+            return Some((Selector::Name(quoted[..end].to_string()), rest));
+        }
+    }
+    let end = tail.find(']')?;
+    let selector = match tail[..end].trim() {
+        "*" => Selector::Wildcard,
+        index => Selector::Index(index.parse().ok()?),
+    };
+    Some((selector, &tail[end + 1..]))
+}
+
+type Node<'a> = (String, &'a Value);
+
+fn select<'a>(selector: &Selector, (pointer, node): Node<'a>, pointers: bool, out: &mut Vec<Node<'a>>) {
+    let mut push = |key: &str, value: &'a Value| out.push((child_pointer(&pointer, key, pointers), value));
+    match (selector, node) {
+        (Selector::Name(name) | Selector::Token(name), Value::Object(map)) => {
+            if let Some(value) = map.get(name) {
+                push(name, value);
+            }
+        }
+        (Selector::Index(index), Value::Array(items)) => {
+            let index = if *index < 0 { items.len() as i64 + index } else { *index };
+            if let Some((index, value)) = usize::try_from(index).ok().and_then(|i| Some((i, items.get(i)?))) {
+                push(&index.to_string(), value);
+            }
+        }
+        (Selector::Token(token), Value::Array(items)) => {
+            if let Some(value) = token.parse::<usize>().ok().and_then(|index| items.get(index)) {
+                push(token, value);
+            }
+        }
+        (Selector::Wildcard, Value::Object(map)) => map.iter().for_each(|(key, value)| push(key, value)),
+        (Selector::Wildcard, Value::Array(items)) => {
+            items.iter().enumerate().for_each(|(index, value)| push(&index.to_string(), value))
+        }
+        _ => {}
+    }
+}
+
+/// Collects `node` and every value nested in it, parents before children.
+fn descendants<'a>(node: Node<'a>, pointers: bool, out: &mut Vec<Node<'a>>) {
+    let (pointer, value) = (node.0.clone(), node.1);
+    out.push(node);
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .for_each(|(key, child)| descendants((child_pointer(&pointer, key, pointers), child), pointers, out)),
+        Value::Array(items) => items.iter().enumerate().for_each(|(index, child)| {
+            descendants((child_pointer(&pointer, &index.to_string(), pointers), child), pointers, out)
+        }),
+        _ => {}
+    }
+}
+
+fn child_pointer(parent: &str, key: &str, pointers: bool) -> String {
+    if !pointers {
+        return String::new();
+    }
+    format!("{}/{}", parent, key.replace('~', "~0").replace('/', "~1"))
+}
@@ -72,12 +72,10 @@
 //!         if self.should_fail {
 //!             Err(SupplierError::Internal(format!("{} failed", self.name)))
 //!         } else {
-//!             Ok(SupplierResponse {
-//!                 data: json!({
-//!                     "supplier": self.name,
-//!                     "params": request.params
-//!                 }),
-//!             })
+//!             Ok(SupplierResponse::new(json!({
+//!                 "supplier": self.name,
+//!                 "params": request.params
+//!             })))
 //!         }
 //!     }
 //! }
@@ -101,10 +99,7 @@
 //!         }
 //!     }
 //!
-//!     let request = SupplierRequest {
-//!         operation: SupplierOperation::Search,
-//!         params: json!({ "keyword": "laptop" }),
-//!     };
+//!     let request = SupplierRequest::new(SupplierOperation::Search, json!({ "keyword": "laptop" }));
 //!
 //!     let result: SupplierGroupResult = group.query(request);
 //!
@@ -180,7 +175,211 @@ pub mod supplier_group;
 /// such as serialization or validation functions that aren't specific to one part of the crate.
 pub mod utils;
 
+/// Module for JSONPath expressions that read values out of request params and response data.
+pub mod json_path;
+
+/// Module for declarative field mappings and other normalizers applied to supplier responses.
+pub mod mapping;
+
+/// Module for converting currencies and units into canonical forms while normalizing responses.
+pub mod conversion;
+
+/// Module for reading throttling hints, such as `Retry-After`, from upstream responses.
+pub mod throttling;
+
 /// Macros used throughout the supplier kit to improve ergonomics and reduce boilerplate code.
 /// 
 /// For example, macros for registering multiple suppliers in a concise manner.
 pub mod macros;
+
+/// Module for turning supplier errors into localized, user-safe messages.
+///
+/// It provides a `MessageCatalog` that maps error codes (optionally per supplier)
+/// to messages per locale, so raw vendor error text never reaches end users.
+pub mod localization;
+
+/// Module containing supplier decorators.
+///
+/// Decorators wrap any `Supplier` and add cross-cutting behavior such as
+/// concurrency limiting, while still implementing `Supplier` themselves.
+pub mod decorators;
+
+/// Module for scheduling the deprecation and sunset of suppliers.
+///
+/// It defines the `Deprecation` schedule used by `SupplierRegistry` to warn on use,
+/// ramp routing weights down toward zero, and report lifecycle milestones.
+pub mod deprecation;
+
+/// Module for compressing serialized payloads.
+///
+/// It provides tagged compression (optionally zstd, lz4 or gzip, behind the `zstd`, `lz4`
+/// and `gzip` features) with transparent decompression, used for anything the kit writes to
+/// storage, and standard compressed streams for transports and fixture files.
+pub mod compression;
+
+/// Module for auditing and comparing supplier prices over time.
+///
+/// It provides `PriceAudit`, which repeatedly fans a request out to a group,
+/// stores normalized price and availability per supplier, and produces comparison reports.
+pub mod pricing_audit;
+
+/// Module for request context propagation.
+///
+/// It defines `RequestContext`, the cross-cutting metadata (such as the request ID)
+/// carried by every `SupplierRequest`.
+pub mod context;
+
+/// Module for pluggable identifier generation.
+///
+/// It defines the `IdGenerator` trait with UUIDv7 (default), snowflake,
+/// and ULID (behind the `ulid` feature) implementations.
+pub mod id;
+
+/// Module for paginating group queries.
+///
+/// It provides an opaque `GroupCursor` that tracks each supplier's own cursor,
+/// and a `Paginator` that resumes every supplier from its own position.
+pub mod pagination;
+
+/// Module for ranking items across aggregated supplier responses.
+///
+/// It defines the `Ranker` trait, common rankers (price, supplier weight, weighted
+/// combinations), and the `ranked_merge` helper producing a single ordered list.
+pub mod ranking;
+
+/// Tracks per-supplier weights derived from a manual base weight and the observed
+/// success rate and latency of each supplier.
+pub mod reputation;
+
+/// Detects misbehaving suppliers from their rolling error rate and latency, or from
+/// errors that retries cannot fix, and temporarily ejects them from group fan-out.
+pub mod outlier;
+
+/// Module for active health checks: a background `HealthMonitor` and the shared `HealthMap`
+/// that groups consult before fan-out.
+pub mod health;
+
+/// Utilities for testing code built on the kit: mock and fault-injecting suppliers
+/// and assertions on group results.
+pub mod testing;
+
+/// Benchmark harness replaying a workload through different group strategies and
+/// reporting throughput and latency per strategy and per supplier.
+pub mod bench;
+
+/// Metrics hooks for group query paths, with a Prometheus text recorder and an
+/// optional OpenTelemetry exporter behind the `otel` feature.
+pub mod metrics;
+
+/// Tamper-evident audit log of supplier interactions with pluggable sinks.
+pub mod audit;
+
+/// Path-based redaction of sensitive request and response fields.
+pub mod redaction;
+
+/// Machine-readable supplier metadata: operations, schemas and rate limits.
+pub mod descriptor;
+
+/// OpenAPI 3 document generation from the suppliers of a registry.
+#[cfg(feature = "openapi")]
+pub mod openapi;
+
+/// HTTP endpoints for supplier groups, built on axum.
+#[cfg(feature = "axum")]
+pub mod server;
+
+/// Adapters between suppliers and `tower::Service`, in both directions.
+#[cfg(feature = "tower")]
+pub mod service;
+
+/// gRPC supplier translating JSON params to protobuf through a descriptor set.
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
+/// Translation of XML supplier responses into JSON data.
+#[cfg(feature = "xml")]
+pub mod xml;
+
+/// Parsers turning non-JSON supplier payloads, such as CSV exports, into JSON data.
+pub mod formats;
+
+/// Compact wire formats (MessagePack, CBOR) for exchanging requests, responses and group results.
+pub mod wire;
+
+/// Protobuf types for the core models, generated from `proto/supplier_kit/v1/models.proto`.
+#[cfg(feature = "proto")]
+pub mod proto;
+
+/// Suppliers implemented by remote services behind a message broker (request/reply).
+pub mod queue;
+
+/// Declarative manifests and the factory that builds suppliers and groups from them.
+pub mod config;
+
+/// Secrets providers resolving credentials referenced by name in manifests.
+pub mod secrets;
+
+/// Credentials and auth providers, including atomic rotation on live suppliers.
+pub mod auth;
+
+/// Access policies restricting which callers may invoke which operations.
+pub mod access;
+
+/// Tenant-scoped supplier registries layered over an optional shared base.
+pub mod tenant;
+
+/// Per-supplier call quotas with hard and soft limits per time window.
+pub mod quota;
+
+/// Priority-based load shedding for supplier groups.
+pub mod shedding;
+
+/// Latency-aware supplier selection and session affinity for adaptive routing.
+pub mod balancing;
+
+/// Dispatch of each operation to the single supplier that owns it.
+pub mod dispatcher;
+
+/// Content-based routing rules selecting which group members receive a request.
+pub mod routing;
+
+/// Alias tables mapping legacy operation names onto canonical operations.
+pub mod aliases;
+
+/// Per-operation minimum supplier sets that groups must be able to reach before fanning out.
+pub mod requirements;
+
+/// Versioned operation payloads and the migrations between versions.
+pub mod migration;
+
+/// A registry of named supplier groups, mirroring `SupplierRegistry`.
+pub mod group_registry;
+
+/// Executors running the parallel work of supplier groups, with an optional thread bound.
+pub mod executor;
+
+/// Hierarchical supplier names such as `eu/amazon_de`, for nested groups, metrics and audit logs.
+pub mod path;
+
+/// Suppliers answering from a whole supplier group, for nesting groups in groups.
+pub mod composite;
+
+/// Multi-supplier orchestration: sagas with compensating operations and call pipelines.
+pub mod orchestration;
+
+/// Structural comparison of redundant suppliers' responses, with tolerance rules.
+pub mod consistency;
+
+/// Reconciliation jobs joining full supplier datasets by key.
+pub mod reconciliation;
+
+/// Background polling of suppliers and groups on intervals or cron schedules.
+pub mod scheduler;
+
+/// Ingestion of data that suppliers push proactively, with an optional webhook receiver.
+pub mod push;
+
+/// Event bus publishing registry and group lifecycle and health events.
+pub mod events;
+
+mod execution;
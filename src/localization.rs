@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::context::RequestContext;
+use crate::errors::SupplierError;
+use crate::models::SupplierRequest;
+use crate::supplier::Supplier;
+use crate::supplier_group::SupplierGroupResult;
+
+/// A user-safe description of a supplier failure.
+///
+/// It carries a machine-readable `code` (see [`SupplierError::code`]) and a localized `message`
+/// taken from a [`MessageCatalog`]. Raw vendor error text is never copied into it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UserMessage {
+    /// The machine-readable error code, e.g. `timeout` or `upstream`.
+    pub code: String,
+
+    /// The localized, user-facing message.
+    pub message: String,
+}
+
+/// A catalog of localized messages keyed by locale and error code.
+///
+/// Lookups are resolved in the following order:
+/// 1. a supplier-specific entry (`"<supplier>:<code>"`) in the requested locale
+/// 2. a generic entry (`"<code>"`) in the requested locale
+/// 3. the same two lookups in the language of the requested locale, e.g. `pt` for `pt-BR`
+/// 4. the same lookups in the default locale
+/// 5. a built-in English fallback
+///
+/// The catalog can be built in code or deserialized from configuration:
+///
+/// ```json
+/// {
+///   "default_locale": "en",
+///   "messages": {
+///     "id": { "timeout": "Pemasok tidak merespons tepat waktu." }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MessageCatalog {
+    /// The locale used when the requested locale has no matching entry.
+    pub default_locale: String,
+
+    /// Messages per locale, each mapping a key (`code` or `supplier:code`) to a message.
+    #[serde(default)]
+    pub messages: HashMap<String, HashMap<String, String>>,
+}
+
+impl Default for MessageCatalog {
+    fn default() -> Self {
+        Self::new("en")
+    }
+}
+
+impl MessageCatalog {
+    /// Creates an empty catalog with the given default locale.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::localization::MessageCatalog;
+    /// let catalog = MessageCatalog::new("en");
+    /// assert_eq!(catalog.default_locale, "en");
+    /// ```
+    pub fn new(default_locale: &str) -> Self {
+        Self {
+            default_locale: default_locale.to_string(),
+            messages: HashMap::new(),
+        }
+    }
+
+    /// Adds (or replaces) a message for an error code in a locale.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::localization::MessageCatalog;
+    /// let catalog = MessageCatalog::new("en")
+    ///     .with_message("id", "timeout", "Pemasok tidak merespons tepat waktu.");
+    /// ```
+    pub fn with_message(mut self, locale: &str, code: &str, message: &str) -> Self {
+        self.messages
+            .entry(locale.to_string())
+            .or_default()
+            .insert(code.to_string(), message.to_string());
+        self
+    }
+
+    /// Adds (or replaces) a message for an error code of one specific supplier in a locale.
+    pub fn with_supplier_message(mut self, locale: &str, supplier: &str, code: &str, message: &str) -> Self {
+        self.messages
+            .entry(locale.to_string())
+            .or_default()
+            .insert(format!("{}:{}", supplier, code), message.to_string());
+        self
+    }
+
+    /// Maps an error returned by `supplier` into a localized, user-safe message.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::localization::MessageCatalog;
+    ///
+    /// let catalog = MessageCatalog::new("en")
+    ///     .with_message("id", "upstream", "Layanan mitra sedang bermasalah.");
+    ///
+    /// let err = SupplierError::Upstream("HTTP 502 from api.vendor.internal".into());
+    /// let msg = catalog.localize(&err, "vendor", "id");
+    /// assert_eq!(msg.code, "upstream");
+    /// assert_eq!(msg.message, "Layanan mitra sedang bermasalah.");
+    ///
+    /// let fallback = catalog.localize(&err, "vendor", "fr");
+    /// assert!(!fallback.message.contains("api.vendor.internal"));
+    /// ```
+    pub fn localize(&self, error: &SupplierError, supplier: &str, locale: &str) -> UserMessage {
+        let code = error.code();
+        let message = self
+            .lookup(locale, supplier, code)
+            .or_else(|| self.lookup(&self.default_locale, supplier, code))
+            .map(str::to_string)
+            .unwrap_or_else(|| default_message(code).to_string());
+
+        UserMessage {
+            code: code.to_string(),
+            message,
+        }
+    }
+
+    /// Localizes every failure of a group result, keeping the supplier name alongside.
+    pub fn localize_failures(&self, result: &SupplierGroupResult, locale: &str) -> Vec<(String, UserMessage)> {
+        result
+            .failures
+            .iter()
+            .map(|(name, error)| (name.clone(), self.localize(error, name, locale)))
+            .collect()
+    }
+
+    /// Localizes every failure of a group result in the locale of the request context, or the
+    /// default locale if the context has none.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::context::RequestContext;
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::localization::MessageCatalog;
+    /// use supplier_kit::supplier_group::SupplierGroupResult;
+    ///
+    /// let catalog = MessageCatalog::new("en").with_message("id", "timeout", "Pemasok tidak merespons tepat waktu.");
+    /// let result = SupplierGroupResult {
+    ///     failures: vec![("vendor".to_string(), SupplierError::Timeout)],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let messages = catalog.localize_failures_for(&result, &RequestContext::new().with_locale("id-ID"));
+    /// assert_eq!(messages[0].1.message, "Pemasok tidak merespons tepat waktu.");
+    /// ```
+    pub fn localize_failures_for(&self, result: &SupplierGroupResult, context: &RequestContext) -> Vec<(String, UserMessage)> {
+        self.localize_failures(result, context.locale.as_deref().unwrap_or(&self.default_locale))
+    }
+
+    fn lookup(&self, locale: &str, supplier: &str, code: &str) -> Option<&str> {
+        let find = |locale: &str| {
+            let messages = self.messages.get(locale)?;
+            messages
+                .get(&format!("{}:{}", supplier, code))
+                .or_else(|| messages.get(code))
+                .map(String::as_str)
+        };
+        find(locale).or_else(|| language(locale).filter(|language| *language != locale).and_then(find))
+    }
+}
+
+/// Picks the locale of `supported` closest to `requested`, comparing BCP 47 tags
+/// case-insensitively and treating `_` like `-`.
+///
+/// An exact match wins, then a supported locale of the same language and region scope (`pt`
+/// for `pt-BR`), then the first supported locale of the same language (`pt-BR` for `pt` or
+/// `pt-PT`). Returns `None` if no supported locale shares the requested language.
+///
+/// # Example
+/// ```
+/// use supplier_kit::localization::negotiate;
+///
+/// let supported = vec!["en".to_string(), "pt-BR".to_string(), "zh-Hant-TW".to_string()];
+/// assert_eq!(negotiate("EN_us", &supported), Some("en"));
+/// assert_eq!(negotiate("pt-PT", &supported), Some("pt-BR"));
+/// assert_eq!(negotiate("zh-hant-tw", &supported), Some("zh-Hant-TW"));
+/// assert_eq!(negotiate("fr", &supported), None);
+/// ```
+pub fn negotiate<'a>(requested: &str, supported: &'a [String]) -> Option<&'a str> {
+    let requested = canonical(requested);
+    let exact = supported.iter().find(|locale| canonical(locale) == requested);
+    let wanted = language(&requested);
+    let prefix = || {
+        supported
+            .iter()
+            .filter(|locale| requested.starts_with(&format!("{}-", canonical(locale))))
+            .max_by_key(|locale| locale.len())
+    };
+    let same_language = || supported.iter().find(|locale| language(&canonical(locale)) == wanted);
+    exact.or_else(prefix).or_else(same_language).map(String::as_str)
+}
+
+/// Passes `supplier` the locale it declares that is closest to the one of `request`.
+pub(crate) fn select_locale(supplier: &dyn Supplier, request: &mut SupplierRequest) {
+    let Some(requested) = request.context.locale.as_deref() else {
+        return;
+    };
+    let descriptor = supplier.describe();
+    if let Some(selected) = descriptor.select_locale(Some(requested))
+        && selected != requested
+    {
+        request.context.locale = Some(selected.to_string());
+    }
+}
+
+fn canonical(locale: &str) -> String {
+    locale.trim().replace('_', "-").to_ascii_lowercase()
+}
+
+fn language(locale: &str) -> Option<&str> {
+    locale.split(['-', '_']).next().filter(|language| !language.is_empty())
+}
+
+/// Built-in English messages used when the catalog has no entry.
+fn default_message(code: &str) -> &'static str {
+    match code {
+        "timeout" => "The supplier did not respond in time.",
+        "unauthorized" => "Access to the supplier was denied.",
+        "not_found" => "The requested item could not be found.",
+        "invalid_input" => "The request was invalid.",
+        "unsupported_operation" => "This operation is not available.",
+        "upstream" => "The supplier is currently unavailable.",
+        "concurrency_limit_exceeded" => "The supplier is busy. Please try again shortly.",
+        "overloaded" => "We are handling too many requests right now. Please try again shortly.",
+        "rate_limited" => "Too many requests were made to the supplier. Please try again later.",
+        "throttled" => "The supplier is receiving too many requests. Please try again later.",
+        "insufficient_suppliers" => "The service is temporarily unavailable. Please try again shortly.",
+        "already_exists" => "This item already exists.",
+        "response_too_large" => "The supplier returned more data than can be processed.",
+        _ => "Something went wrong. Please try again later.",
+    }
+}
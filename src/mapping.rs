@@ -0,0 +1,486 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use crate::conversion::ValueConverter;
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::json_path::JsonPath;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+
+/// Converts the responses of a supplier into the shape the rest of the system expects.
+///
+/// Implemented by compiled field mappings ([`FieldMapping`]), by the `XmlNormalizer` (`xml`
+/// feature) and `CsvParser` payload parsers, and by closures; apply one to a supplier with
+/// [`NormalizingSupplier`].
+pub trait ResponseNormalizer: Send + Sync {
+    /// Returns the normalized response.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::Upstream` if the response does not have the expected shape.
+    fn normalize_response(&self, response: SupplierResponse) -> Result<SupplierResponse, SupplierError>;
+}
+
+impl<F> ResponseNormalizer for F
+where
+    F: Fn(SupplierResponse) -> Result<SupplierResponse, SupplierError> + Send + Sync,
+{
+    fn normalize_response(&self, response: SupplierResponse) -> Result<SupplierResponse, SupplierError> {
+        self(response)
+    }
+}
+
+/// The type a mapped field is coerced to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldType {
+    /// Strings are kept; numbers and booleans are formatted as strings.
+    String,
+    /// Integral numbers, and strings holding one.
+    Integer,
+    /// Numbers, and strings holding one.
+    Float,
+    /// Booleans, the strings `true` and `false` (case-insensitive), and the numbers `0` and `1`.
+    Boolean,
+}
+
+/// One field of a [`MappingSpec`]: where a value comes from, where it goes and how it is
+/// coerced.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldRule {
+    /// JSONPath or JSON pointer of the source value. A path with a wildcard or recursive
+    /// descent maps every match into an array.
+    pub from: String,
+
+    /// Dot-separated target path, e.g. `price.amount`; intermediate objects are created.
+    pub to: String,
+
+    /// The type the value is coerced to; values are copied unchanged when absent.
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub field_type: Option<FieldType>,
+
+    /// The value used when the source is missing or `null`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+
+    /// Whether a missing source without a default fails the response instead of leaving the
+    /// target out.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+
+    /// Name of the converter applied to the value after coercion, e.g. to bring prices into
+    /// one currency; converters are registered with `FieldMapping::with_converter`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub convert: Option<String>,
+}
+
+impl FieldRule {
+    /// Creates a rule copying the value at `from` to `to`.
+    pub fn new(from: &str, to: &str) -> Self {
+        Self {
+            from: from.to_string(),
+            to: to.to_string(),
+            field_type: None,
+            default: None,
+            required: false,
+            convert: None,
+        }
+    }
+
+    /// Coerces the value to `field_type`.
+    pub fn with_type(mut self, field_type: FieldType) -> Self {
+        self.field_type = Some(field_type);
+        self
+    }
+
+    /// Uses `default` when the source is missing or `null`.
+    pub fn with_default(mut self, default: Value) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Fails the response when the source is missing and there is no default.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Applies the converter registered under `name` to the value.
+    pub fn with_converter(mut self, name: &str) -> Self {
+        self.convert = Some(name.to_string());
+        self
+    }
+}
+
+/// A declarative mapping from a supplier's response data to the target shape, usually kept in
+/// configuration so supplier mappings can change without code changes.
+///
+/// The target document only contains the mapped fields. With `each`, the path selects the
+/// records of the response (e.g. `$.results[*]`), every record is mapped on its own with
+/// `from` paths relative to it, and the data becomes the array of mapped records.
+///
+/// In a manifest the mapping is declared next to the supplier (see `config::SupplierSpec`):
+/// ```json
+/// {
+///   "each": "$.Results[*]",
+///   "fields": [
+///     { "from": "$.ProductID", "to": "id", "type": "string", "required": true },
+///     { "from": "$.Pricing.Amount", "to": "price.amount", "type": "float" },
+///     { "from": "$.Pricing.Currency", "to": "price.currency", "default": "USD" },
+///     { "from": "$.Weight", "to": "weight", "convert": "kilograms" },
+///     { "from": "$.Tags[*].Name", "to": "tags" }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MappingSpec {
+    /// Path selecting the records to map one by one; the whole data is one record when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub each: Option<String>,
+
+    /// The fields of the target document, applied in order.
+    #[serde(default)]
+    pub fields: Vec<FieldRule>,
+}
+
+impl MappingSpec {
+    /// Parses a mapping from JSON.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the JSON does not describe a mapping.
+    pub fn from_json(json: &str) -> Result<Self, SupplierError> {
+        serde_json::from_str(json).map_err(|e| SupplierError::InvalidInput(format!("invalid mapping: {}", e)))
+    }
+
+    /// Reads a mapping from a JSON file.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the file cannot be read or parsed.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, SupplierError> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)
+            .map_err(|e| SupplierError::InvalidInput(format!("cannot read mapping '{}': {}", path.display(), e)))?;
+        Self::from_json(&json).map_err(|e| SupplierError::InvalidInput(format!("{} ({})", e, path.display())))
+    }
+
+    /// Validates the mapping and compiles it into a [`FieldMapping`].
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` for invalid source paths, empty or conflicting
+    /// targets, and defaults that do not match their field type.
+    pub fn compile(&self) -> Result<FieldMapping, SupplierError> {
+        let each = self.each.as_deref().map(JsonPath::parse).transpose()?;
+        let mut fields: Vec<CompiledField> = Vec::with_capacity(self.fields.len());
+        for rule in &self.fields {
+            let invalid = |reason: &str| SupplierError::InvalidInput(format!("mapping field '{}': {}", rule.to, reason));
+            let from = JsonPath::parse(&rule.from)?;
+            let target: Vec<String> = rule.to.strip_prefix("$.").unwrap_or(&rule.to).split('.').map(str::to_string).collect();
+            if target.iter().any(String::is_empty) {
+                return Err(invalid("invalid target path"));
+            }
+            if let Some(other) = fields.iter().find(|f| f.target.starts_with(&target) || target.starts_with(&f.target)) {
+                return Err(invalid(&format!("conflicts with target '{}'", other.rule.to)));
+            }
+            let default = match (&rule.default, rule.field_type) {
+                (Some(default), Some(field_type)) if !default.is_null() => {
+                    Some(coerce(default, field_type).map_err(|reason| invalid(&format!("default {}", reason)))?)
+                }
+                (default, _) => default.clone(),
+            };
+            fields.push(CompiledField {
+                rule: rule.clone(),
+                from,
+                target,
+                default,
+            });
+        }
+        Ok(FieldMapping {
+            each,
+            fields,
+            converters: HashMap::new(),
+        })
+    }
+}
+
+/// A compiled [`MappingSpec`], ready to be applied to responses.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::mapping::{FieldRule, FieldType, MappingSpec, ResponseNormalizer};
+/// use supplier_kit::models::SupplierResponse;
+///
+/// let mapping = MappingSpec {
+///     each: Some("$.Results[*]".to_string()),
+///     fields: vec![
+///         FieldRule::new("$.ProductID", "id").with_type(FieldType::String).required(),
+///         FieldRule::new("$.Pricing.Amount", "price.amount").with_type(FieldType::Float),
+///         FieldRule::new("$.Pricing.Currency", "price.currency").with_default(json!("USD")),
+///     ],
+/// }
+/// .compile()
+/// .unwrap();
+///
+/// let raw = SupplierResponse::new(json!({ "Results": [{ "ProductID": 7, "Pricing": { "Amount": "12.50" } }] }));
+/// let response = mapping.normalize_response(raw).unwrap();
+/// assert_eq!(response.data, json!([{ "id": "7", "price": { "amount": 12.5, "currency": "USD" } }]));
+/// ```
+#[derive(Clone)]
+pub struct FieldMapping {
+    each: Option<JsonPath>,
+    fields: Vec<CompiledField>,
+    converters: HashMap<String, Arc<dyn ValueConverter>>,
+}
+
+impl fmt::Debug for FieldMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldMapping")
+            .field("each", &self.each)
+            .field("fields", &self.fields)
+            .field("converters", &self.converters.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CompiledField {
+    rule: FieldRule,
+    from: JsonPath,
+    target: Vec<String>,
+    default: Option<Value>,
+}
+
+impl FieldMapping {
+    /// Registers `converter` under `name`, for the fields whose `convert` names it.
+    pub fn with_converter<C: ValueConverter + 'static>(self, name: &str, converter: C) -> Self {
+        self.with_shared_converter(name, Arc::new(converter))
+    }
+
+    pub(crate) fn with_shared_converter(mut self, name: &str, converter: Arc<dyn ValueConverter>) -> Self {
+        self.converters.insert(name.to_string(), converter);
+        self
+    }
+
+    /// Checks that every converter named by a field is registered.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` naming the first unknown converter.
+    pub fn validate(&self) -> Result<(), SupplierError> {
+        match self.fields.iter().find(|f| f.rule.convert.as_ref().is_some_and(|name| !self.converters.contains_key(name))) {
+            Some(field) => Err(SupplierError::InvalidInput(format!(
+                "mapping field '{}': unknown converter '{}'",
+                field.rule.to,
+                field.rule.convert.as_deref().unwrap_or_default()
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Maps response data to the target shape.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::Upstream` if a required field is missing or a value cannot be
+    /// coerced to its field type or converted, and `SupplierError::InvalidInput` if a field
+    /// names a converter that is not registered.
+    pub fn map(&self, data: &Value) -> Result<Value, SupplierError> {
+        match &self.each {
+            Some(each) => each
+                .find(data)
+                .into_iter()
+                .enumerate()
+                .map(|(index, record)| self.map_record(record, &format!("mapping record {} of '{}'", index, each)))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array),
+            None => self.map_record(data, "mapping response"),
+        }
+    }
+
+    fn map_record(&self, record: &Value, context: &str) -> Result<Value, SupplierError> {
+        let mut target = Map::new();
+        for field in &self.fields {
+            let value = if field.from.is_definite() {
+                match field.from.first(record).filter(|value| !value.is_null()) {
+                    Some(value) => Some(self.convert(field, value, context)?),
+                    None => field.default.clone(),
+                }
+            } else {
+                let values = field.from.find(record);
+                let values = values
+                    .into_iter()
+                    .map(|value| self.convert(field, value, context))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Some(Value::Array(values))
+            };
+            match value {
+                Some(value) => insert(&mut target, &field.target, value),
+                None if field.rule.required => {
+                    return Err(SupplierError::Upstream(format!(
+                        "{}: field '{}' is missing at '{}'",
+                        context, field.rule.to, field.rule.from
+                    )));
+                }
+                None => {}
+            }
+        }
+        Ok(Value::Object(target))
+    }
+
+    /// Coerces a source value and applies the field's converter.
+    fn convert(&self, field: &CompiledField, value: &Value, context: &str) -> Result<Value, SupplierError> {
+        let value = field
+            .coerce(value)
+            .map_err(|reason| SupplierError::Upstream(format!("{}: {}", context, reason)))?;
+        let Some(name) = &field.rule.convert else {
+            return Ok(value);
+        };
+        if value.is_null() {
+            return Ok(value);
+        }
+        let converter = self.converters.get(name).ok_or_else(|| {
+            SupplierError::InvalidInput(format!("mapping field '{}': unknown converter '{}'", field.rule.to, name))
+        })?;
+        converter.convert(&value).map_err(|e| match e {
+            SupplierError::Upstream(reason) => {
+                SupplierError::Upstream(format!("{}: field '{}': {}", context, field.rule.to, reason))
+            }
+            other => other,
+        })
+    }
+}
+
+impl CompiledField {
+    fn coerce(&self, value: &Value) -> Result<Value, String> {
+        match self.rule.field_type {
+            Some(_) if value.is_null() => Ok(Value::Null),
+            Some(field_type) => coerce(value, field_type).map_err(|reason| format!("field '{}': {}", self.rule.to, reason)),
+            None => Ok(value.clone()),
+        }
+    }
+}
+
+impl ResponseNormalizer for FieldMapping {
+    fn normalize_response(&self, mut response: SupplierResponse) -> Result<SupplierResponse, SupplierError> {
+        response.data = self.map(&response.data)?;
+        Ok(response)
+    }
+}
+
+fn coerce(value: &Value, field_type: FieldType) -> Result<Value, String> {
+    let coerced = match (field_type, value) {
+        (FieldType::String, Value::String(_)) => Some(value.clone()),
+        (FieldType::String, Value::Number(n)) => Some(Value::String(n.to_string())),
+        (FieldType::String, Value::Bool(b)) => Some(Value::String(b.to_string())),
+        (FieldType::Integer, Value::Number(n)) => n
+            .as_i64()
+            .map(Value::from)
+            .or_else(|| n.as_f64().filter(|f| f.fract() == 0.0 && f.abs() < i64::MAX as f64).map(|f| Value::from(f as i64))),
+        (FieldType::Integer, Value::String(s)) => s.trim().parse::<i64>().ok().map(Value::from),
+        (FieldType::Float, Value::Number(_)) => Some(value.clone()),
+        (FieldType::Float, Value::String(s)) => s
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number),
+        (FieldType::Boolean, Value::Bool(_)) => Some(value.clone()),
+        (FieldType::Boolean, Value::String(s)) => match s.trim().to_ascii_lowercase().as_str() {
+            "true" => Some(Value::Bool(true)),
+            "false" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        (FieldType::Boolean, Value::Number(n)) => match n.as_u64() {
+            Some(0) => Some(Value::Bool(false)),
+            Some(1) => Some(Value::Bool(true)),
+            _ => None,
+        },
+        _ => None,
+    };
+    coerced.ok_or_else(|| format!("expected {:?}, found {}", field_type, value))
+}
+
+fn insert(target: &mut Map<String, Value>, path: &[String], value: Value) {
+    let (last, parents) = path.split_last().expect("target paths are not empty");
+    let mut object = target;
+    for name in parents {
+        let entry = object.entry(name.clone()).or_insert_with(|| Value::Object(Map::new()));
+        object = entry.as_object_mut().expect("targets do not overlap");
+    }
+    object.insert(last.clone(), value);
+}
+
+/// A supplier decorator that applies a [`ResponseNormalizer`] to every successful response.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::mapping::{FieldRule, MappingSpec, NormalizingSupplier};
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let partner = MockSupplierBuilder::new("partner")
+///     .respond_default(json!({ "Product": { "Title": "Lamp" } }))
+///     .build();
+/// let mapping = MappingSpec::from_json(r#"{ "fields": [{ "from": "$.Product.Title", "to": "name" }] }"#)
+///     .unwrap()
+///     .compile()
+///     .unwrap();
+/// let supplier = NormalizingSupplier::new(partner, mapping);
+///
+/// let response = supplier.query(SupplierRequest::new(SupplierOperation::Search, json!({}))).unwrap();
+/// assert_eq!(response.data, json!({ "name": "Lamp" }));
+/// ```
+pub struct NormalizingSupplier<S, N> {
+    inner: S,
+    normalizer: N,
+}
+
+impl<S: Supplier, N: ResponseNormalizer> NormalizingSupplier<S, N> {
+    /// Wraps `inner`, normalizing its responses with `normalizer`.
+    pub fn new(inner: S, normalizer: N) -> Self {
+        Self { inner, normalizer }
+    }
+
+    /// Returns the normalizer applied to responses.
+    pub fn normalizer(&self) -> &N {
+        &self.normalizer
+    }
+}
+
+impl<S: Supplier, N: ResponseNormalizer> Supplier for NormalizingSupplier<S, N> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        self.normalizer.normalize_response(self.inner.query(request)?)
+    }
+
+    fn query_batch(&self, requests: Vec<SupplierRequest>) -> Vec<Result<SupplierResponse, SupplierError>> {
+        self.inner
+            .query_batch(requests)
+            .into_iter()
+            .map(|result| result.and_then(|response| self.normalizer.normalize_response(response)))
+            .collect()
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
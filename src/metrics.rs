@@ -0,0 +1,124 @@
+use std::sync::Arc;
+use std::time::Duration;
+use crate::errors::SupplierError;
+use crate::path::SupplierPath;
+
+/// Recorder rendering the standard metrics in the Prometheus text exposition format.
+pub mod prometheus;
+
+/// Recorder pricing supplier calls and reporting cost per group and per tenant.
+pub mod cost;
+
+/// OpenTelemetry recorder exporting supplier and group measurements as spans and instruments.
+#[cfg(feature = "otel")]
+pub mod otel;
+
+/// One supplier call made by a group.
+#[derive(Debug, Clone, Copy)]
+pub struct SupplierCall<'a> {
+    /// The name of the group that made the call.
+    pub group: &'a str,
+
+    /// The name of the supplier that was called.
+    pub supplier: &'a str,
+
+    /// The tenant of the request, if it carried one.
+    pub tenant: Option<&'a str>,
+
+    /// The requested operation.
+    pub operation: &'a str,
+
+    /// The error returned by the supplier, if the call failed.
+    pub error: Option<&'a SupplierError>,
+
+    /// How long the call took.
+    pub latency: Duration,
+}
+
+/// One completed group query.
+#[derive(Debug, Clone, Copy)]
+pub struct GroupQuery<'a> {
+    /// The name of the group.
+    pub group: &'a str,
+
+    /// The tenant of the request, if it carried one.
+    pub tenant: Option<&'a str>,
+
+    /// The number of suppliers the query was sent to.
+    pub fan_out: usize,
+
+    /// The number of successful supplier responses.
+    pub successes: usize,
+
+    /// The number of failed supplier responses.
+    pub failures: usize,
+
+    /// How long the whole group query took.
+    pub latency: Duration,
+}
+
+impl SupplierCall<'_> {
+    /// Returns the hierarchical name of the called supplier, `group/supplier`.
+    pub fn path(&self) -> SupplierPath {
+        SupplierPath::new(self.group).child(self.supplier)
+    }
+}
+
+impl GroupQuery<'_> {
+    /// Returns the share of failed responses, or `0.0` if the query reached no supplier.
+    pub fn failure_ratio(&self) -> f64 {
+        let total = self.successes + self.failures;
+        if total == 0 {
+            0.0
+        } else {
+            self.failures as f64 / total as f64
+        }
+    }
+}
+
+/// Receives measurements from the query paths of a `BasicSupplierGroup`.
+///
+/// Attach a recorder with `BasicSupplierGroup::with_metrics`. Supplier calls are reported for
+/// every query method; group queries are reported for `query`, `query_each` and once per request
+/// of `query_batch`, but not for `query_streamed`, whose consumer decides when it is complete.
+///
+/// Both methods do nothing by default, so a recorder only implements what it needs.
+///
+/// # Example
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use supplier_kit::metrics::{MetricsRecorder, SupplierCall};
+///
+/// #[derive(Default)]
+/// struct ErrorCounter(AtomicUsize);
+///
+/// impl MetricsRecorder for ErrorCounter {
+///     fn on_supplier_call(&self, call: &SupplierCall<'_>) {
+///         if call.error.is_some() {
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+/// ```
+pub trait MetricsRecorder: Send + Sync {
+    /// Called after every supplier call made by a group.
+    fn on_supplier_call(&self, _call: &SupplierCall<'_>) {}
+
+    /// Called after a group query completes.
+    fn on_group_query(&self, _query: &GroupQuery<'_>) {}
+}
+
+/// Forwards every measurement to each recorder in turn, so a group can feed several recorders.
+impl MetricsRecorder for Vec<Arc<dyn MetricsRecorder>> {
+    fn on_supplier_call(&self, call: &SupplierCall<'_>) {
+        for recorder in self {
+            recorder.on_supplier_call(call);
+        }
+    }
+
+    fn on_group_query(&self, query: &GroupQuery<'_>) {
+        for recorder in self {
+            recorder.on_group_query(query);
+        }
+    }
+}
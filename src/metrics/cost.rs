@@ -0,0 +1,217 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard};
+use serde::Serialize;
+use crate::metrics::{GroupQuery, MetricsRecorder, SupplierCall};
+use crate::models::SupplierOperation;
+
+/// What calling a supplier costs.
+///
+/// A call costs the price of its operation, or the per-call price for operations without one.
+/// Failed calls cost the same as successful ones unless [`CostModel::without_failure_charges`]
+/// is set, since most partners bill attempts.
+///
+/// # Example
+/// ```
+/// use supplier_kit::metrics::cost::CostModel;
+/// use supplier_kit::models::SupplierOperation;
+///
+/// let model = CostModel::per_call(0.002).with_operation_cost(SupplierOperation::Create, 0.05);
+/// assert_eq!(model.cost("search", false), 0.002);
+/// assert_eq!(model.cost("create", false), 0.05);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CostModel {
+    per_call: f64,
+    per_operation: HashMap<String, f64>,
+    charge_failures: bool,
+}
+
+impl CostModel {
+    /// Charges `cost` for every call.
+    pub fn per_call(cost: f64) -> Self {
+        Self {
+            per_call: cost,
+            per_operation: HashMap::new(),
+            charge_failures: true,
+        }
+    }
+
+    /// Charges `cost` for calls of `operation` instead of the per-call price.
+    pub fn with_operation_cost(mut self, operation: SupplierOperation, cost: f64) -> Self {
+        self.per_operation.insert(operation.as_str().to_string(), cost);
+        self
+    }
+
+    /// Makes failed calls free.
+    pub fn without_failure_charges(mut self) -> Self {
+        self.charge_failures = false;
+        self
+    }
+
+    /// Returns the cost of one call of `operation`.
+    pub fn cost(&self, operation: &str, failed: bool) -> f64 {
+        if failed && !self.charge_failures {
+            return 0.0;
+        }
+        self.per_operation.get(operation).copied().unwrap_or(self.per_call)
+    }
+}
+
+/// Accumulated calls and cost.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct CostLine {
+    /// Completed group queries.
+    pub queries: u64,
+    /// Supplier calls made.
+    pub calls: u64,
+    /// Total cost of the calls.
+    pub cost: f64,
+}
+
+impl CostLine {
+    /// Returns the average cost of one group query, or `0.0` without queries.
+    pub fn cost_per_query(&self) -> f64 {
+        if self.queries == 0 {
+            0.0
+        } else {
+            self.cost / self.queries as f64
+        }
+    }
+
+    fn add(&mut self, other: &CostLine) {
+        self.queries += other.queries;
+        self.calls += other.calls;
+        self.cost += other.cost;
+    }
+}
+
+/// Costs accumulated by a `CostRecorder`, broken down by group, tenant and supplier.
+///
+/// Requests without a tenant are reported under the empty tenant `""`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CostReport {
+    /// Cost per group.
+    pub groups: BTreeMap<String, CostLine>,
+    /// Cost per tenant.
+    pub tenants: BTreeMap<String, CostLine>,
+    /// Cost per group and tenant, keyed by group and then tenant.
+    pub group_tenants: BTreeMap<String, BTreeMap<String, CostLine>>,
+    /// Calls and cost per supplier; suppliers take no part in group query counts.
+    pub suppliers: BTreeMap<String, CostLine>,
+    /// The totals over everything.
+    pub total: CostLine,
+}
+
+type Key = (String, String);
+
+#[derive(Debug, Default)]
+struct Ledger {
+    // (group, tenant) → line
+    lines: BTreeMap<Key, CostLine>,
+    suppliers: BTreeMap<String, CostLine>,
+}
+
+/// A [`MetricsRecorder`] pricing every supplier call with the supplier's `CostModel` and
+/// accumulating cost per group and per tenant.
+///
+/// Suppliers without a model are counted but cost nothing. Attach it next to other recorders
+/// with a `Vec<Arc<dyn MetricsRecorder>>`.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use supplier_kit::context::RequestContext;
+/// use supplier_kit::metrics::cost::{CostModel, CostRecorder};
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let costs = Arc::new(
+///     CostRecorder::new()
+///         .with_model("shop_a", CostModel::per_call(0.01))
+///         .with_model("shop_b", CostModel::per_call(0.03)),
+/// );
+/// let mut group = BasicSupplierGroup::new("marketplaces").with_metrics(costs.clone());
+/// group.add_supplier(MockSupplierBuilder::new("shop_a").respond_default(serde_json::json!({})).build());
+/// group.add_supplier(MockSupplierBuilder::new("shop_b").respond_default(serde_json::json!({})).build());
+///
+/// let context = RequestContext::new().with_tenant("acme");
+/// group.query(SupplierRequest::new(SupplierOperation::Search, serde_json::json!({})).with_context(context));
+///
+/// let report = costs.report();
+/// assert!((report.groups["marketplaces"].cost_per_query() - 0.04).abs() < 1e-9);
+/// assert_eq!(report.tenants["acme"].calls, 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct CostRecorder {
+    models: HashMap<String, CostModel>,
+    ledger: Mutex<Ledger>,
+}
+
+impl CostRecorder {
+    /// Creates a recorder without cost models.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prices the calls of `supplier` with `model`.
+    pub fn with_model(mut self, supplier: &str, model: CostModel) -> Self {
+        self.models.insert(supplier.to_string(), model);
+        self
+    }
+
+    /// Returns the costs accumulated so far.
+    pub fn report(&self) -> CostReport {
+        let ledger = self.lock();
+        let mut report = CostReport {
+            suppliers: ledger.suppliers.clone(),
+            ..CostReport::default()
+        };
+        for ((group, tenant), line) in &ledger.lines {
+            report.groups.entry(group.clone()).or_default().add(line);
+            report.tenants.entry(tenant.clone()).or_default().add(line);
+            report
+                .group_tenants
+                .entry(group.clone())
+                .or_default()
+                .insert(tenant.clone(), *line);
+            report.total.add(line);
+        }
+        report
+    }
+
+    /// Returns the accumulated costs and starts over, e.g. at the end of a billing period.
+    pub fn take_report(&self) -> CostReport {
+        let report = self.report();
+        *self.lock() = Ledger::default();
+        report
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Ledger> {
+        self.ledger.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl MetricsRecorder for CostRecorder {
+    fn on_supplier_call(&self, call: &SupplierCall<'_>) {
+        let cost = self
+            .models
+            .get(call.supplier)
+            .map_or(0.0, |model| model.cost(call.operation, call.error.is_some()));
+        let charge = CostLine {
+            queries: 0,
+            calls: 1,
+            cost,
+        };
+
+        let mut ledger = self.lock();
+        let key = (call.group.to_string(), call.tenant.unwrap_or_default().to_string());
+        ledger.lines.entry(key).or_default().add(&charge);
+        ledger.suppliers.entry(call.supplier.to_string()).or_default().add(&charge);
+    }
+
+    fn on_group_query(&self, query: &GroupQuery<'_>) {
+        let key = (query.group.to_string(), query.tenant.unwrap_or_default().to_string());
+        self.lock().lines.entry(key).or_default().queries += 1;
+    }
+}
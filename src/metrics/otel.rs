@@ -0,0 +1,134 @@
+use std::time::SystemTime;
+use opentelemetry::metrics::{Counter, Histogram, Meter};
+use opentelemetry::trace::{Span, Status, Tracer};
+use opentelemetry::{global, KeyValue};
+use crate::metrics::{GroupQuery, MetricsRecorder, SupplierCall};
+
+/// A [`MetricsRecorder`] exporting measurements through OpenTelemetry.
+///
+/// Every supplier call produces a `supplier.query` span and updates these instruments,
+/// labelled with `group`, `supplier` and `operation` (plus `error.code` for errors):
+///
+/// - `supplier_kit.supplier.calls`: counter of supplier calls
+/// - `supplier_kit.supplier.errors`: counter of failed supplier calls
+/// - `supplier_kit.supplier.duration`: histogram of call latency in seconds
+///
+/// Every group query produces a `supplier_group.query` span and updates, labelled with `group`:
+///
+/// - `supplier_kit.group.fan_out`: histogram of the number of suppliers queried
+/// - `supplier_kit.group.failure_ratio`: histogram of the share of failed supplier responses
+/// - `supplier_kit.group.duration`: histogram of group query latency in seconds
+///
+/// Spans are created after the fact with their real start and end times.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use supplier_kit::metrics::otel::OtelRecorder;
+/// use supplier_kit::supplier_group::BasicSupplierGroup;
+///
+/// let group = BasicSupplierGroup::new("marketplace").with_metrics(Arc::new(OtelRecorder::global()));
+/// ```
+pub struct OtelRecorder {
+    tracer: global::BoxedTracer,
+    supplier_calls: Counter<u64>,
+    supplier_errors: Counter<u64>,
+    supplier_duration: Histogram<f64>,
+    group_fan_out: Histogram<u64>,
+    group_failure_ratio: Histogram<f64>,
+    group_duration: Histogram<f64>,
+}
+
+impl OtelRecorder {
+    /// Creates a recorder using the globally installed tracer and meter providers.
+    pub fn global() -> Self {
+        Self::new(global::tracer("supplier_kit"), &global::meter("supplier_kit"))
+    }
+
+    /// Creates a recorder using the given tracer and meter.
+    pub fn new(tracer: global::BoxedTracer, meter: &Meter) -> Self {
+        Self {
+            tracer,
+            supplier_calls: meter
+                .u64_counter("supplier_kit.supplier.calls")
+                .with_description("Supplier calls made by groups")
+                .build(),
+            supplier_errors: meter
+                .u64_counter("supplier_kit.supplier.errors")
+                .with_description("Supplier calls that failed")
+                .build(),
+            supplier_duration: meter
+                .f64_histogram("supplier_kit.supplier.duration")
+                .with_unit("s")
+                .with_description("Latency of supplier calls")
+                .build(),
+            group_fan_out: meter
+                .u64_histogram("supplier_kit.group.fan_out")
+                .with_description("Number of suppliers a group query was sent to")
+                .build(),
+            group_failure_ratio: meter
+                .f64_histogram("supplier_kit.group.failure_ratio")
+                .with_description("Share of failed supplier responses per group query")
+                .build(),
+            group_duration: meter
+                .f64_histogram("supplier_kit.group.duration")
+                .with_unit("s")
+                .with_description("Latency of group queries")
+                .build(),
+        }
+    }
+}
+
+impl MetricsRecorder for OtelRecorder {
+    fn on_supplier_call(&self, call: &SupplierCall<'_>) {
+        let mut attributes = vec![
+            KeyValue::new("group", call.group.to_string()),
+            KeyValue::new("supplier", call.supplier.to_string()),
+            KeyValue::new("operation", call.operation.to_string()),
+        ];
+        attributes.extend(call.tenant.map(|tenant| KeyValue::new("tenant", tenant.to_string())));
+
+        self.supplier_calls.add(1, &attributes);
+        self.supplier_duration.record(call.latency.as_secs_f64(), &attributes);
+        if let Some(error) = call.error {
+            attributes.push(KeyValue::new("error.code", error.code()));
+            self.supplier_errors.add(1, &attributes);
+        }
+
+        let end = SystemTime::now();
+        let mut span = self
+            .tracer
+            .span_builder("supplier.query")
+            .with_start_time(end - call.latency)
+            .with_attributes(attributes)
+            .start(&self.tracer);
+        if let Some(error) = call.error {
+            span.set_status(Status::error(error.to_string()));
+        }
+        span.end_with_timestamp(end);
+    }
+
+    fn on_group_query(&self, query: &GroupQuery<'_>) {
+        let mut attributes = vec![KeyValue::new("group", query.group.to_string())];
+        attributes.extend(query.tenant.map(|tenant| KeyValue::new("tenant", tenant.to_string())));
+        self.group_fan_out.record(query.fan_out as u64, &attributes);
+        self.group_failure_ratio.record(query.failure_ratio(), &attributes);
+        self.group_duration.record(query.latency.as_secs_f64(), &attributes);
+
+        let end = SystemTime::now();
+        let mut span = self
+            .tracer
+            .span_builder("supplier_group.query")
+            .with_start_time(end - query.latency)
+            .with_attributes(
+                attributes
+                    .into_iter()
+                    .chain([
+                        KeyValue::new("fan_out", query.fan_out as i64),
+                        KeyValue::new("failures", query.failures as i64),
+                    ]),
+            )
+            .start(&self.tracer);
+        span.end_with_timestamp(end);
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Mutex, MutexGuard};
+use crate::metrics::{GroupQuery, MetricsRecorder, SupplierCall};
+
+const LATENCY_BUCKETS: &[f64] = &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+const FAN_OUT_BUCKETS: &[f64] = &[1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0];
+const RATIO_BUCKETS: &[f64] = &[0.0, 0.1, 0.25, 0.5, 0.75, 1.0];
+
+#[derive(Debug, Clone)]
+struct Histogram {
+    buckets: &'static [f64],
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(buckets: &'static [f64]) -> Self {
+        Self {
+            buckets,
+            counts: vec![0; buckets.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        for (bound, count) in self.buckets.iter().zip(&mut self.counts) {
+            if value <= *bound {
+                *count += 1;
+            }
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+/// Rendered label sets, e.g. `group="a",supplier="b"`, mapped to their values.
+type Series<T> = BTreeMap<String, T>;
+
+#[derive(Debug, Default)]
+struct Registry {
+    supplier_calls: Series<u64>,
+    supplier_errors: Series<u64>,
+    supplier_duration: Series<Histogram>,
+    group_queries: Series<u64>,
+    group_partial_failures: Series<u64>,
+    group_fan_out: Series<Histogram>,
+    group_failure_ratio: Series<Histogram>,
+    group_duration: Series<Histogram>,
+}
+
+/// A [`MetricsRecorder`] keeping the standard supplier and group metrics in memory and
+/// rendering them in the Prometheus text exposition format.
+///
+/// Serve the output of [`PrometheusRecorder::render`] from a `/metrics` endpoint to make a
+/// service scrapeable. The exposed metrics are:
+///
+/// - `supplier_kit_supplier_calls_total{group,supplier,operation}`
+/// - `supplier_kit_supplier_errors_total{group,supplier,operation,code}`
+/// - `supplier_kit_supplier_duration_seconds{group,supplier,operation}` (histogram)
+/// - `supplier_kit_group_queries_total{group}`
+/// - `supplier_kit_group_partial_failures_total{group}`: queries with both successes and failures
+/// - `supplier_kit_group_fan_out{group}` (histogram)
+/// - `supplier_kit_group_failure_ratio{group}` (histogram)
+/// - `supplier_kit_group_duration_seconds{group}` (histogram)
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use serde_json::json;
+/// use supplier_kit::metrics::prometheus::PrometheusRecorder;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let recorder = Arc::new(PrometheusRecorder::new());
+/// let mut group = BasicSupplierGroup::new("shops").with_metrics(recorder.clone());
+/// group.add_supplier(MockSupplierBuilder::new("a").respond_default(json!({})).build());
+/// group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+///
+/// let text = recorder.render();
+/// assert!(text.contains(r#"supplier_kit_supplier_calls_total{group="shops",supplier="a",operation="search"} 1"#));
+/// ```
+#[derive(Debug, Default)]
+pub struct PrometheusRecorder {
+    registry: Mutex<Registry>,
+}
+
+impl PrometheusRecorder {
+    /// Creates a recorder without any recorded series.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renders every recorded series in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let registry = self.lock();
+        let mut out = String::new();
+        counter(&mut out, "supplier_kit_supplier_calls_total", "Supplier calls made by groups.", &registry.supplier_calls);
+        counter(&mut out, "supplier_kit_supplier_errors_total", "Supplier calls that failed.", &registry.supplier_errors);
+        histogram(&mut out, "supplier_kit_supplier_duration_seconds", "Latency of supplier calls.", &registry.supplier_duration);
+        counter(&mut out, "supplier_kit_group_queries_total", "Completed group queries.", &registry.group_queries);
+        counter(
+            &mut out,
+            "supplier_kit_group_partial_failures_total",
+            "Group queries where some but not all suppliers failed.",
+            &registry.group_partial_failures,
+        );
+        histogram(&mut out, "supplier_kit_group_fan_out", "Number of suppliers a group query was sent to.", &registry.group_fan_out);
+        histogram(
+            &mut out,
+            "supplier_kit_group_failure_ratio",
+            "Share of failed supplier responses per group query.",
+            &registry.group_failure_ratio,
+        );
+        histogram(&mut out, "supplier_kit_group_duration_seconds", "Latency of group queries.", &registry.group_duration);
+        out
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Registry> {
+        self.registry.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl MetricsRecorder for PrometheusRecorder {
+    fn on_supplier_call(&self, call: &SupplierCall<'_>) {
+        let mut pairs = vec![("group", call.group), ("supplier", call.supplier), ("operation", call.operation)];
+        pairs.extend(call.tenant.map(|tenant| ("tenant", tenant)));
+        let labels = labels(&pairs);
+        let mut registry = self.lock();
+        *registry.supplier_calls.entry(labels.clone()).or_default() += 1;
+        if let Some(error) = call.error {
+            let labels = format!("{},{}", labels, self::labels(&[("code", error.code())]));
+            *registry.supplier_errors.entry(labels).or_default() += 1;
+        }
+        registry
+            .supplier_duration
+            .entry(labels)
+            .or_insert_with(|| Histogram::new(LATENCY_BUCKETS))
+            .observe(call.latency.as_secs_f64());
+    }
+
+    fn on_group_query(&self, query: &GroupQuery<'_>) {
+        let mut pairs = vec![("group", query.group)];
+        pairs.extend(query.tenant.map(|tenant| ("tenant", tenant)));
+        let labels = labels(&pairs);
+        let mut registry = self.lock();
+        *registry.group_queries.entry(labels.clone()).or_default() += 1;
+        if query.successes > 0 && query.failures > 0 {
+            *registry.group_partial_failures.entry(labels.clone()).or_default() += 1;
+        }
+        let observe = |series: &mut Series<Histogram>, buckets, value| {
+            series
+                .entry(labels.clone())
+                .or_insert_with(|| Histogram::new(buckets))
+                .observe(value);
+        };
+        observe(&mut registry.group_fan_out, FAN_OUT_BUCKETS, query.fan_out as f64);
+        observe(&mut registry.group_failure_ratio, RATIO_BUCKETS, query.failure_ratio());
+        observe(&mut registry.group_duration, LATENCY_BUCKETS, query.latency.as_secs_f64());
+    }
+}
+
+fn labels(pairs: &[(&str, &str)]) -> String {
+    pairs
+        .iter()
+        .map(|(name, value)| {
+            let escaped = value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
+            format!("{name}=\"{escaped}\"")
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn counter(out: &mut String, name: &str, help: &str, series: &Series<u64>) {
+    let _ = writeln!(out, "# HELP {name} {help}\n# TYPE {name} counter");
+    for (labels, value) in series {
+        let _ = writeln!(out, "{name}{{{labels}}} {value}");
+    }
+}
+
+fn histogram(out: &mut String, name: &str, help: &str, series: &Series<Histogram>) {
+    let _ = writeln!(out, "# HELP {name} {help}\n# TYPE {name} histogram");
+    for (labels, histogram) in series {
+        for (bound, count) in histogram.buckets.iter().zip(&histogram.counts) {
+            let _ = writeln!(out, "{name}_bucket{{{labels},le=\"{bound}\"}} {count}");
+        }
+        let _ = writeln!(out, "{name}_bucket{{{labels},le=\"+Inf\"}} {}", histogram.count);
+        let _ = writeln!(out, "{name}_sum{{{labels}}} {}", histogram.sum);
+        let _ = writeln!(out, "{name}_count{{{labels}}} {}", histogram.count);
+    }
+}
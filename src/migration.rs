@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use serde_json::Value;
+use crate::errors::SupplierError;
+use crate::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+
+/// Rewrites a JSON payload from one version to another.
+pub type PayloadMigration = Arc<dyn Fn(Value) -> Result<Value, SupplierError> + Send + Sync>;
+
+/// The payload changes of one operation between version `from` and version `from + 1`.
+///
+/// Each direction is optional; an unset direction leaves the payload unchanged, which suits
+/// changes that only touch requests or only touch responses.
+#[derive(Clone)]
+pub struct Migration {
+    operation: SupplierOperation,
+    from: u32,
+    upgrade_params: Option<PayloadMigration>,
+    downgrade_params: Option<PayloadMigration>,
+    upgrade_data: Option<PayloadMigration>,
+    downgrade_data: Option<PayloadMigration>,
+}
+
+impl Migration {
+    /// Starts the migration of `operation` from version `from` to `from + 1`.
+    pub fn new(operation: SupplierOperation, from: u32) -> Self {
+        Self {
+            operation: operation.normalize(),
+            from,
+            upgrade_params: None,
+            downgrade_params: None,
+            upgrade_data: None,
+            downgrade_data: None,
+        }
+    }
+
+    /// Sets how request params of version `from` become params of version `from + 1`.
+    pub fn upgrade_params<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Value) -> Result<Value, SupplierError> + Send + Sync + 'static,
+    {
+        self.upgrade_params = Some(Arc::new(f));
+        self
+    }
+
+    /// Sets how request params of version `from + 1` become params of version `from`.
+    pub fn downgrade_params<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Value) -> Result<Value, SupplierError> + Send + Sync + 'static,
+    {
+        self.downgrade_params = Some(Arc::new(f));
+        self
+    }
+
+    /// Sets how response data of version `from` becomes data of version `from + 1`.
+    pub fn upgrade_data<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Value) -> Result<Value, SupplierError> + Send + Sync + 'static,
+    {
+        self.upgrade_data = Some(Arc::new(f));
+        self
+    }
+
+    /// Sets how response data of version `from + 1` becomes data of version `from`.
+    pub fn downgrade_data<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Value) -> Result<Value, SupplierError> + Send + Sync + 'static,
+    {
+        self.downgrade_data = Some(Arc::new(f));
+        self
+    }
+
+    /// Returns the operation the migration applies to.
+    pub fn operation(&self) -> &SupplierOperation {
+        &self.operation
+    }
+
+    /// Returns the version the migration upgrades from.
+    pub fn from_version(&self) -> u32 {
+        self.from
+    }
+}
+
+/// Which payload of an exchange is migrated.
+#[derive(Clone, Copy)]
+enum Payload {
+    Params,
+    Data,
+}
+
+/// Migrations between consecutive payload versions, per operation.
+///
+/// Long-lived integrations outlive their payload formats: callers written against version 1
+/// of an operation keep sending version 1 params after the supplier moved to version 3, and
+/// expect version 1 data back. The registry chains single-step migrations to move request
+/// params and response data across any number of versions, in either direction.
+/// `decorators::migration::MigratingSupplier` applies it around a supplier.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::migration::{Migration, MigrationRegistry};
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+///
+/// let registry = MigrationRegistry::new().with(
+///     Migration::new(SupplierOperation::Search, 1)
+///         .upgrade_params(|params| Ok(json!({ "query": params["q"] })))
+///         .downgrade_params(|params| Ok(json!({ "q": params["query"] }))),
+/// );
+///
+/// let v1 = SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" })).with_version(1);
+/// let v2 = registry.migrate_request(v1, 2).unwrap();
+/// assert_eq!(v2.params, json!({ "query": "lamp" }));
+/// assert_eq!(v2.version, Some(2));
+/// ```
+#[derive(Clone, Default)]
+pub struct MigrationRegistry {
+    steps: HashMap<(String, u32), Migration>,
+}
+
+impl MigrationRegistry {
+    /// Creates a registry without migrations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `migration`, replacing any migration of the same operation and version.
+    pub fn with(mut self, migration: Migration) -> Self {
+        self.register(migration);
+        self
+    }
+
+    /// Adds `migration`, replacing any migration of the same operation and version.
+    pub fn register(&mut self, migration: Migration) {
+        let key = (migration.operation.as_str().to_string(), migration.from);
+        self.steps.insert(key, migration);
+    }
+
+    /// Migrates the params of `request` to version `target` and marks the request with it.
+    ///
+    /// Requests without a version are assumed to be of version `target` already.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if a step between the two versions is missing, or
+    /// any error of a migration.
+    pub fn migrate_request(&self, mut request: SupplierRequest, target: u32) -> Result<SupplierRequest, SupplierError> {
+        let from = request.version.unwrap_or(target);
+        request.params = self.migrate(&request.operation, Payload::Params, request.params, from, target)?;
+        request.version = Some(target);
+        Ok(request)
+    }
+
+    /// Migrates the data of `response` to `operation` from version `from` to version `to`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if a step between the two versions is missing, or
+    /// any error of a migration.
+    pub fn migrate_response(
+        &self,
+        operation: &SupplierOperation,
+        mut response: SupplierResponse,
+        from: u32,
+        to: u32,
+    ) -> Result<SupplierResponse, SupplierError> {
+        response.data = self.migrate(operation, Payload::Data, response.data, from, to)?;
+        Ok(response)
+    }
+
+    fn migrate(&self, operation: &SupplierOperation, payload: Payload, mut value: Value, from: u32, to: u32) -> Result<Value, SupplierError> {
+        let operation = operation.clone().normalize();
+        let name = operation.as_str();
+        let step = |version: u32| {
+            self.steps.get(&(name.to_string(), version)).ok_or_else(|| {
+                SupplierError::InvalidInput(format!(
+                    "no migration of '{}' between versions {} and {}",
+                    name,
+                    version,
+                    version + 1
+                ))
+            })
+        };
+        if from < to {
+            for version in from..to {
+                let step = step(version)?;
+                let migrate = match payload {
+                    Payload::Params => &step.upgrade_params,
+                    Payload::Data => &step.upgrade_data,
+                };
+                if let Some(migrate) = migrate {
+                    value = migrate(value)?;
+                }
+            }
+        } else {
+            for version in (to..from).rev() {
+                let step = step(version)?;
+                let migrate = match payload {
+                    Payload::Params => &step.downgrade_params,
+                    Payload::Data => &step.downgrade_data,
+                };
+                if let Some(migrate) = migrate {
+                    value = migrate(value)?;
+                }
+            }
+        }
+        Ok(value)
+    }
+}
@@ -1,5 +1,9 @@
 use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
 use serde_json::Value;
+use crate::context::RequestContext;
+use crate::errors::SupplierError;
+use crate::json_path::JsonPath;
 
 /// Represents the type of operation requested from a supplier.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -9,6 +13,14 @@ pub enum SupplierOperation {
     Search,
     /// Retrieve detailed information for a specific item
     GetDetail,
+    /// Create a resource, such as placing an order
+    Create,
+    /// Modify an existing resource
+    Update,
+    /// Remove or cancel a resource
+    Delete,
+    /// Submit a prepared resource for processing, such as confirming a booking
+    Submit,
     /// A custom, non-standard operation
     Other(String),
 }
@@ -16,7 +28,7 @@ pub enum SupplierOperation {
 impl SupplierOperation {
     /// Normalizes the `Other(String)` variant into `snake_case` format.
     ///
-    /// This only affects the `Other` variant. `Search` and `GetDetail` are returned unchanged.
+    /// This only affects the `Other` variant; every other variant is returned unchanged.
     pub fn normalize(self) -> Self {
         match self {
             SupplierOperation::Other(s) => {
@@ -35,9 +47,57 @@ impl SupplierOperation {
         match self {
             SupplierOperation::Search => "search",
             SupplierOperation::GetDetail => "get_detail",
+            SupplierOperation::Create => "create",
+            SupplierOperation::Update => "update",
+            SupplierOperation::Delete => "delete",
+            SupplierOperation::Submit => "submit",
             SupplierOperation::Other(s) => s.as_str(),
         }
     }
+
+    /// Returns whether the operation changes state at the supplier (`Create`, `Update`, `Delete`
+    /// or `Submit`).
+    ///
+    /// Write operations are not safe to repeat blindly; pair them with an idempotency key
+    /// (see [`SupplierRequest::with_idempotency_key`]).
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::SupplierOperation;
+    /// assert!(SupplierOperation::Create.is_write());
+    /// assert!(!SupplierOperation::Search.is_write());
+    /// ```
+    pub fn is_write(&self) -> bool {
+        matches!(
+            self,
+            SupplierOperation::Create | SupplierOperation::Update | SupplierOperation::Delete | SupplierOperation::Submit
+        )
+    }
+}
+
+impl From<&str> for SupplierOperation {
+    /// Parses an operation name as produced by [`SupplierOperation::as_str`].
+    ///
+    /// The name is normalized first (see [`SupplierOperation::normalize`]); unknown names
+    /// become an `Other` operation.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::SupplierOperation;
+    /// assert_eq!(SupplierOperation::from("get_detail"), SupplierOperation::GetDetail);
+    /// assert_eq!(SupplierOperation::from("Track Order"), SupplierOperation::Other("track_order".into()));
+    /// ```
+    fn from(name: &str) -> Self {
+        match SupplierOperation::Other(name.to_string()).normalize() {
+            SupplierOperation::Other(name) if name == "search" => SupplierOperation::Search,
+            SupplierOperation::Other(name) if name == "get_detail" => SupplierOperation::GetDetail,
+            SupplierOperation::Other(name) if name == "create" => SupplierOperation::Create,
+            SupplierOperation::Other(name) if name == "update" => SupplierOperation::Update,
+            SupplierOperation::Other(name) if name == "delete" => SupplierOperation::Delete,
+            SupplierOperation::Other(name) if name == "submit" => SupplierOperation::Submit,
+            other => other,
+        }
+    }
 }
 
 
@@ -53,15 +113,352 @@ pub struct SupplierRequest {
     /// Free-form parameters required by the operation.
     /// This can be any valid JSON structure (object, array, etc.)
     pub params: Value,
+
+    /// Cross-cutting information about the request, such as its request ID.
+    #[serde(default)]
+    pub context: RequestContext,
+
+    /// Key identifying a logical write, so that retries of the same request are applied once.
+    ///
+    /// Suppliers and decorators such as `IdempotentSupplier` use it to recognise repeats.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+
+    /// The version of the operation's payloads the caller speaks; `None` means the version the
+    /// supplier implements. See `migration::MigrationRegistry`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+}
+
+impl SupplierRequest {
+    /// Creates a request for `operation` with the given parameters and an empty context.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({ "q": "laptop" }));
+    /// assert_eq!(request.params["q"], "laptop");
+    /// assert!(request.context.request_id.is_none());
+    /// ```
+    pub fn new(operation: SupplierOperation, params: Value) -> Self {
+        Self {
+            operation,
+            params,
+            context: RequestContext::default(),
+            idempotency_key: None,
+            version: None,
+        }
+    }
+
+    /// Returns the request with the given context.
+    pub fn with_context(mut self, context: RequestContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Returns the request with the given idempotency key.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// let request = SupplierRequest::new(SupplierOperation::Create, serde_json::json!({ "sku": "A1" }))
+    ///     .with_idempotency_key("order-7f3a");
+    /// assert_eq!(request.idempotency_key.as_deref(), Some("order-7f3a"));
+    /// ```
+    pub fn with_idempotency_key(mut self, key: &str) -> Self {
+        self.idempotency_key = Some(key.to_string());
+        self
+    }
+
+    /// Returns the request with the given operation version.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({})).with_version(2);
+    /// assert_eq!(request.version, Some(2));
+    /// ```
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+/// Describes where the data of a `SupplierResponse` came from.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseSource {
+    /// The response was produced by a live call to the supplier.
+    #[default]
+    Live,
+    /// The response was served from a cache.
+    Cache,
+    /// The response came from a fallback supplier or default value.
+    Fallback,
+    /// The response was replayed from a recording or fixture.
+    Replay,
+    /// The response was assembled from data the supplier pushed proactively.
+    Push,
+}
+
+/// Metadata attached to a `SupplierResponse`, describing how it was produced.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct ResponseMetadata {
+    /// Where the response data came from.
+    #[serde(default)]
+    pub source: ResponseSource,
+}
+
+/// A non-JSON payload carried by a `SupplierResponse`, such as an image, a PDF or a CSV export.
+///
+/// In JSON the bytes are encoded as standard base64, so responses with a body still travel
+/// through caches, recordings and the HTTP server unchanged; binary formats (see `wire`)
+/// store them as byte strings.
+///
+/// # Example
+/// ```
+/// use supplier_kit::models::ResponseBody;
+/// let body = ResponseBody::new("text/csv", b"sku,stock\nA1,4\n".to_vec());
+/// assert_eq!(body.as_text(), Some("sku,stock\nA1,4\n"));
+/// assert_eq!(serde_json::to_value(&body).unwrap()["bytes"], "c2t1LHN0b2NrCkExLDQK");
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ResponseBody {
+    /// The MIME type of the payload, e.g. `application/pdf`.
+    pub content_type: String,
+
+    /// The raw payload.
+    #[serde(with = "base64_bytes")]
+    pub bytes: Vec<u8>,
+}
+
+impl ResponseBody {
+    /// Creates a body of the given content type.
+    pub fn new(content_type: &str, bytes: Vec<u8>) -> Self {
+        Self {
+            content_type: content_type.to_string(),
+            bytes,
+        }
+    }
+
+    /// Returns the payload as text if it is valid UTF-8, e.g. for CSV or XML documents.
+    pub fn as_text(&self) -> Option<&str> {
+        std::str::from_utf8(&self.bytes).ok()
+    }
+
+    /// Returns the size of the payload in bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns whether the payload is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+/// Serializes byte payloads as standard base64 strings in human-readable formats such as JSON,
+/// and as native byte strings in binary formats such as MessagePack or CBOR.
+mod base64_bytes {
+    use std::fmt;
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use serde::de::{SeqAccess, Visitor};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&STANDARD.encode(bytes))
+        } else {
+            serializer.serialize_bytes(bytes)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        if deserializer.is_human_readable() {
+            let encoded = String::deserialize(deserializer)?;
+            STANDARD.decode(encoded).map_err(serde::de::Error::custom)
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor)
+        }
+    }
+
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a byte string")
+        }
+
+        fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Vec<u8>, E> {
+            Ok(bytes.to_vec())
+        }
+
+        fn visit_byte_buf<E: serde::de::Error>(self, bytes: Vec<u8>) -> Result<Vec<u8>, E> {
+            Ok(bytes)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            Ok(bytes)
+        }
+    }
 }
 
 /// Represents a response returned by a supplier.
 ///
-/// The response contains a single JSON value (`data`)
-/// that holds the result of the requested operation.
+/// The response contains a JSON value (`data`) that holds the result of the requested
+/// operation. Suppliers that produce documents, such as images, PDFs or CSV exports, return
+/// the raw bytes in `body`, either instead of the JSON data or alongside it (e.g. a
+/// generated invoice together with its number).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SupplierResponse {
     /// The raw data returned from the supplier.
-    /// This can be any valid JSON value.
+    /// This can be any valid JSON value; `Null` for responses that only carry a `body`.
     pub data: Value,
+
+    /// A non-JSON payload returned by the supplier, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<ResponseBody>,
+
+    /// Metadata describing how the response was produced (e.g. live call or cache).
+    #[serde(default)]
+    pub metadata: ResponseMetadata,
+}
+
+impl SupplierResponse {
+    /// Creates a response from a live supplier call.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::{ResponseSource, SupplierResponse};
+    /// let response = SupplierResponse::new(serde_json::json!({ "ok": true }));
+    /// assert_eq!(response.source(), ResponseSource::Live);
+    /// ```
+    pub fn new(data: Value) -> Self {
+        Self {
+            data,
+            body: None,
+            metadata: ResponseMetadata::default(),
+        }
+    }
+
+    /// Creates a response that only carries a non-JSON payload; `data` is `Null`.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::SupplierResponse;
+    /// let response = SupplierResponse::binary("application/pdf", b"%PDF-1.7".to_vec());
+    /// assert_eq!(response.content_type(), Some("application/pdf"));
+    /// assert_eq!(response.bytes(), Some(&b"%PDF-1.7"[..]));
+    /// assert!(response.data.is_null());
+    /// ```
+    pub fn binary(content_type: &str, bytes: Vec<u8>) -> Self {
+        Self::new(Value::Null).with_body(content_type, bytes)
+    }
+
+    /// Returns the response with a non-JSON payload attached alongside its data.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::SupplierResponse;
+    /// let response = SupplierResponse::new(serde_json::json!({ "invoice": "INV-7" }))
+    ///     .with_body("application/pdf", b"%PDF-1.7".to_vec());
+    /// assert_eq!(response.data["invoice"], "INV-7");
+    /// assert!(response.body.is_some());
+    /// ```
+    pub fn with_body(mut self, content_type: &str, bytes: Vec<u8>) -> Self {
+        self.body = Some(ResponseBody::new(content_type, bytes));
+        self
+    }
+
+    /// Returns the raw payload, if the response carries one.
+    pub fn bytes(&self) -> Option<&[u8]> {
+        self.body.as_ref().map(|body| body.bytes.as_slice())
+    }
+
+    /// Returns the content type of the raw payload, if the response carries one.
+    pub fn content_type(&self) -> Option<&str> {
+        self.body.as_ref().map(|body| body.content_type.as_str())
+    }
+
+    /// Returns the first value at `path` in the response data, or `None` if nothing is there
+    /// or the path is invalid.
+    ///
+    /// The path is a JSONPath expression or a JSON pointer; see
+    /// [`JsonPath`](crate::json_path::JsonPath) for the supported syntax.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::SupplierResponse;
+    /// let response = SupplierResponse::new(serde_json::json!({ "items": [{ "sku": "A1" }, { "sku": "B2" }] }));
+    /// assert_eq!(response.extract("$.items[-1].sku"), Some(&serde_json::json!("B2")));
+    /// assert_eq!(response.extract("/items/0/sku"), Some(&serde_json::json!("A1")));
+    /// assert_eq!(response.extract("$.total"), None);
+    /// ```
+    pub fn extract(&self, path: &str) -> Option<&Value> {
+        JsonPath::parse(path).ok()?.first(&self.data)
+    }
+
+    /// Returns every value at `path` in the response data, in document order; empty if the
+    /// path is invalid.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::SupplierResponse;
+    /// let response = SupplierResponse::new(serde_json::json!({ "items": [{ "sku": "A1" }, { "sku": "B2" }] }));
+    /// assert_eq!(response.extract_all("$.items[*].sku"), ["A1", "B2"]);
+    /// ```
+    pub fn extract_all(&self, path: &str) -> Vec<&Value> {
+        JsonPath::parse(path)
+            .map(|path| path.find(&self.data))
+            .unwrap_or_default()
+    }
+
+    /// Deserializes the first value at `path` in the response data.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the path is invalid, and
+    /// `SupplierError::Upstream` if nothing is at the path or the value does not match `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::SupplierResponse;
+    /// let response = SupplierResponse::new(serde_json::json!({ "page": { "total": 42 } }));
+    /// let total: u64 = response.extract_as("$.page.total").unwrap();
+    /// assert_eq!(total, 42);
+    /// ```
+    pub fn extract_as<T: DeserializeOwned>(&self, path: &str) -> Result<T, SupplierError> {
+        let value = JsonPath::parse(path)?
+            .first(&self.data)
+            .ok_or_else(|| SupplierError::Upstream(format!("response has nothing at '{}'", path)))?;
+        T::deserialize(value)
+            .map_err(|e| SupplierError::Upstream(format!("unexpected value at '{}': {}", path, e)))
+    }
+
+    /// Returns the response annotated with the given source.
+    ///
+    /// Decorators such as caches or replayers use this to mark responses
+    /// that did not come from a live upstream call.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::{ResponseSource, SupplierResponse};
+    /// let response = SupplierResponse::new(serde_json::json!([])).with_source(ResponseSource::Cache);
+    /// assert_eq!(response.source(), ResponseSource::Cache);
+    /// ```
+    pub fn with_source(mut self, source: ResponseSource) -> Self {
+        self.metadata.source = source;
+        self
+    }
+
+    /// Returns where the response data came from.
+    pub fn source(&self) -> ResponseSource {
+        self.metadata.source
+    }
 }
\ No newline at end of file
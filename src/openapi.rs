@@ -0,0 +1,230 @@
+use serde_json::{json, Map, Value};
+use crate::descriptor::{OperationDescriptor, SupplierDescriptor};
+use crate::supplier::SupplierRegistry;
+
+/// Generates an OpenAPI 3 document describing the suppliers of a registry.
+///
+/// Every operation a supplier declares through `Supplier::describe` becomes one
+/// `POST {prefix}/{supplier}/{operation}` path. The request body is the declared params schema
+/// (or a free-form object), the `200` response wraps the declared response schema in the
+/// `SupplierResponse` envelope, and failures are documented as the serialized `SupplierError`.
+///
+/// Suppliers that declare no operations produce no paths.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::descriptor::{OperationDescriptor, SupplierDescriptor};
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::openapi::OpenApiGenerator;
+/// use supplier_kit::supplier::{Supplier, SupplierRegistry};
+///
+/// struct Shop;
+///
+/// impl Supplier for Shop {
+///     fn name(&self) -> &str { "shop" }
+///     fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         Ok(SupplierResponse::new(json!([])))
+///     }
+///     fn describe(&self) -> SupplierDescriptor {
+///         SupplierDescriptor::new("shop").with_operation(
+///             OperationDescriptor::new(SupplierOperation::Search)
+///                 .with_params_schema(json!({ "type": "object", "required": ["q"] })),
+///         )
+///     }
+/// }
+///
+/// let mut registry = SupplierRegistry::new();
+/// registry.register("shop", Shop);
+///
+/// let spec = OpenApiGenerator::new("Marketplace", "1.0.0").generate(&registry);
+/// assert_eq!(spec["openapi"], "3.0.3");
+/// let body = &spec["paths"]["/suppliers/shop/search"]["post"]["requestBody"];
+/// assert_eq!(body["content"]["application/json"]["schema"]["required"][0], "q");
+/// ```
+#[derive(Debug, Clone)]
+pub struct OpenApiGenerator {
+    title: String,
+    version: String,
+    description: Option<String>,
+    servers: Vec<String>,
+    path_prefix: String,
+}
+
+impl OpenApiGenerator {
+    /// Creates a generator for an API with the given title and version.
+    ///
+    /// Paths are placed under `/suppliers` by default.
+    pub fn new(title: &str, version: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            version: version.to_string(),
+            description: None,
+            servers: Vec::new(),
+            path_prefix: "/suppliers".to_string(),
+        }
+    }
+
+    /// Sets the API description.
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Adds a server URL the API is reachable at.
+    pub fn with_server(mut self, url: &str) -> Self {
+        self.servers.push(url.to_string());
+        self
+    }
+
+    /// Sets the prefix of every supplier path, e.g. `/api/v1/suppliers`.
+    pub fn with_path_prefix(mut self, prefix: &str) -> Self {
+        self.path_prefix = prefix.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Generates the document for every supplier in `registry`, keyed by its registered name.
+    pub fn generate(&self, registry: &SupplierRegistry) -> Value {
+        self.generate_from(&registry.describe_all().into_iter().collect::<Vec<_>>())
+    }
+
+    /// Generates the document from `(registered name, descriptor)` pairs.
+    pub fn generate_from(&self, descriptors: &[(String, SupplierDescriptor)]) -> Value {
+        let mut paths = Map::new();
+        let mut tags = Vec::new();
+
+        for (name, descriptor) in descriptors {
+            if descriptor.operations.is_empty() {
+                continue;
+            }
+            tags.push(tag(name, descriptor));
+            for operation in &descriptor.operations {
+                let path = format!("{}/{}/{}", self.path_prefix, name, operation.operation.as_str());
+                paths.insert(path, json!({ "post": self.path_item(name, descriptor, operation) }));
+            }
+        }
+
+        let mut info = json!({ "title": self.title, "version": self.version });
+        if let Some(description) = &self.description {
+            info["description"] = json!(description);
+        }
+
+        let mut spec = json!({
+            "openapi": "3.0.3",
+            "info": info,
+            "tags": tags,
+            "paths": paths,
+            "components": { "schemas": components() },
+        });
+        if !self.servers.is_empty() {
+            spec["servers"] = self.servers.iter().map(|url| json!({ "url": url })).collect();
+        }
+        spec
+    }
+
+    fn path_item(&self, name: &str, descriptor: &SupplierDescriptor, operation: &OperationDescriptor) -> Value {
+        let params = operation.params_schema.clone().unwrap_or_else(|| json!({ "type": "object" }));
+        let data = operation.response_schema.clone().unwrap_or_else(|| json!({}));
+        let summary = operation
+            .summary
+            .clone()
+            .unwrap_or_else(|| format!("{} on {}", operation.operation.as_str(), name));
+        let error = json!({
+            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/SupplierError" } } }
+        });
+
+        let mut item = json!({
+            "operationId": format!("{}_{}", name, operation.operation.as_str()),
+            "summary": summary,
+            "tags": [name],
+            "requestBody": {
+                "required": true,
+                "content": { "application/json": { "schema": params } }
+            },
+            "responses": {
+                "200": {
+                    "description": "The supplier response.",
+                    "content": {
+                        "application/json": {
+                            "schema": {
+                                "type": "object",
+                                "required": ["data"],
+                                "properties": {
+                                    "data": data,
+                                    "body": { "$ref": "#/components/schemas/ResponseBody" },
+                                    "metadata": { "$ref": "#/components/schemas/ResponseMetadata" }
+                                }
+                            }
+                        }
+                    }
+                },
+                "default": {
+                    "description": "The supplier failed.",
+                    "content": error["content"].clone()
+                }
+            }
+        });
+        if let Some(limit) = descriptor.rate_limits.first() {
+            item["x-rate-limit"] = json!({ "max_requests": limit.max_requests, "window_ms": limit.window_ms });
+        }
+        item
+    }
+}
+
+fn tag(name: &str, descriptor: &SupplierDescriptor) -> Value {
+    let mut tag = json!({ "name": name });
+    let description = match (&descriptor.description, &descriptor.version) {
+        (Some(description), Some(version)) => Some(format!("{} (version {})", description, version)),
+        (Some(description), None) => Some(description.clone()),
+        (None, Some(version)) => Some(format!("version {}", version)),
+        (None, None) => None,
+    };
+    if let Some(description) = description {
+        tag["description"] = json!(description);
+    }
+    tag
+}
+
+fn components() -> Value {
+    json!({
+        "ResponseBody": {
+            "type": "object",
+            "required": ["content_type", "bytes"],
+            "properties": {
+                "content_type": { "type": "string" },
+                "bytes": { "type": "string", "format": "byte" }
+            }
+        },
+        "ResponseMetadata": {
+            "type": "object",
+            "properties": {
+                "source": { "type": "string", "enum": ["live", "cache", "fallback", "replay", "push"] }
+            }
+        },
+        "SupplierError": {
+            "type": "object",
+            "required": ["code"],
+            "properties": {
+                "code": {
+                    "type": "string",
+                    "enum": [
+                        "timeout", "unauthorized", "not_found", "internal", "upstream",
+                        "invalid_input", "unsupported_operation", "concurrency_limit_exceeded", "rate_limited",
+                        "overloaded", "already_exists", "response_too_large", "throttled",
+                        "insufficient_suppliers"
+                    ]
+                },
+                "message": {
+                    "oneOf": [
+                        { "type": "string" },
+                        {
+                            "type": "object",
+                            "properties": { "retry_after_ms": { "type": "integer", "minimum": 0 } }
+                        }
+                    ]
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+use crate::errors::SupplierError;
+use crate::id::{IdGenerator, UuidV7Generator};
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::{query_isolated, Supplier};
+
+/// Pipelines: DAGs of supplier calls feeding each other's requests.
+pub mod pipeline;
+
+/// Builds the request of a saga step from the responses of the steps completed before it.
+pub type StepAction = Arc<dyn Fn(&SagaContext) -> SupplierRequest + Send + Sync>;
+
+/// Builds the compensating request of a step from the saga context and the step's own response.
+pub type StepCompensation = Arc<dyn Fn(&SagaContext, &SupplierResponse) -> SupplierRequest + Send + Sync>;
+
+/// The responses of the saga steps completed so far, keyed by step name.
+#[derive(Debug, Clone, Default)]
+pub struct SagaContext {
+    /// The saga run identifier, also used as request ID and idempotency key prefix.
+    pub saga_id: String,
+    responses: HashMap<String, SupplierResponse>,
+}
+
+impl SagaContext {
+    /// Returns the response of the completed step `name`.
+    pub fn response(&self, name: &str) -> Option<&SupplierResponse> {
+        self.responses.get(name)
+    }
+}
+
+struct SagaStep {
+    name: String,
+    supplier: Arc<dyn Supplier>,
+    action: StepAction,
+    compensation: Option<StepCompensation>,
+}
+
+/// An ordered sequence of supplier operations with compensations, run with all-or-nothing intent.
+///
+/// Steps run one after another. When a step fails, the compensations of every completed step run
+/// in reverse order, each built from that step's response (e.g. cancelling the order it created).
+/// A step whose action panics fails with `SupplierError::Internal`, and a compensation that
+/// panics is reported in `SagaOutcome::compensation_failures`; the remaining compensations
+/// still run.
+///
+/// Every request gets the saga ID as request ID, and an idempotency key of
+/// `"{saga_id}:{step}"` (or `"{saga_id}:{step}:compensate"`) unless it already has one, so
+/// suppliers wrapped in `IdempotentSupplier` apply retried steps only once.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use serde_json::json;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::orchestration::Saga;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let hotel = Arc::new(
+///     MockSupplierBuilder::new("hotel")
+///         .respond(SupplierOperation::Create, json!({ "booking": "H-1" }))
+///         .respond(SupplierOperation::Delete, json!({ "cancelled": true }))
+///         .build(),
+/// );
+/// let flight = Arc::new(MockSupplierBuilder::new("flight").build()); // fails every operation
+///
+/// let outcome = Saga::new("trip")
+///     .step("hotel", hotel.clone(), SupplierRequest::new(SupplierOperation::Create, json!({ "nights": 2 })))?
+///     .compensate(|_, booked| SupplierRequest::new(SupplierOperation::Delete, booked.data.clone()))
+///     .step("flight", flight, SupplierRequest::new(SupplierOperation::Create, json!({ "to": "DPS" })))?
+///     .run();
+///
+/// assert!(!outcome.is_success());
+/// assert_eq!(outcome.failure.as_ref().unwrap().step, "flight");
+/// assert_eq!(outcome.compensated, ["hotel"]);
+/// assert_eq!(hotel.last_request().unwrap().params["booking"], "H-1");
+/// # Ok::<(), supplier_kit::errors::SupplierError>(())
+/// ```
+pub struct Saga {
+    name: String,
+    steps: Vec<SagaStep>,
+    compensation_retries: usize,
+    id_generator: Arc<dyn IdGenerator>,
+}
+
+/// The failed step of a saga run.
+#[derive(Debug, Clone)]
+pub struct SagaFailure {
+    /// The name of the step that failed.
+    pub step: String,
+
+    /// The error it failed with.
+    pub error: SupplierError,
+}
+
+/// The result of running a [`Saga`].
+#[derive(Debug, Clone, Default)]
+pub struct SagaOutcome {
+    /// The saga run identifier.
+    pub saga_id: String,
+
+    /// The steps that succeeded, in execution order, with their responses.
+    pub completed: Vec<(String, SupplierResponse)>,
+
+    /// The failed step, if the saga did not complete.
+    pub failure: Option<SagaFailure>,
+
+    /// The steps whose compensation succeeded, in compensation (reverse) order.
+    pub compensated: Vec<String>,
+
+    /// The steps whose compensation failed after all retries, with the last error.
+    pub compensation_failures: Vec<(String, SupplierError)>,
+}
+
+impl SagaOutcome {
+    /// Returns whether every step succeeded.
+    pub fn is_success(&self) -> bool {
+        self.failure.is_none()
+    }
+
+    /// Returns whether the saga failed and every completed step with a compensation was undone.
+    pub fn is_rolled_back(&self) -> bool {
+        self.failure.is_some() && self.compensation_failures.is_empty()
+    }
+
+    /// Returns the response of step `name`, if it completed.
+    pub fn response(&self, name: &str) -> Option<&SupplierResponse> {
+        self.completed.iter().find(|(step, _)| step == name).map(|(_, response)| response)
+    }
+}
+
+impl Saga {
+    /// Creates an empty saga.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            steps: Vec::new(),
+            compensation_retries: 0,
+            id_generator: Arc::new(UuidV7Generator),
+        }
+    }
+
+    /// Returns the saga name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Appends a step sending a fixed `request` to `supplier`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::AlreadyExists` if the saga already has a step named `name`.
+    pub fn step(self, name: &str, supplier: Arc<dyn Supplier>, request: SupplierRequest) -> Result<Self, SupplierError> {
+        self.step_with(name, supplier, move |_| request.clone())
+    }
+
+    /// Appends a step whose request is built from the responses of earlier steps.
+    ///
+    /// Step names identify responses in the `SagaContext` and idempotency keys, so they must be
+    /// unique.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::AlreadyExists` if the saga already has a step named `name`.
+    pub fn step_with<F>(mut self, name: &str, supplier: Arc<dyn Supplier>, action: F) -> Result<Self, SupplierError>
+    where
+        F: Fn(&SagaContext) -> SupplierRequest + Send + Sync + 'static,
+    {
+        if self.steps.iter().any(|step| step.name == name) {
+            return Err(SupplierError::AlreadyExists(name.to_string()));
+        }
+        self.steps.push(SagaStep {
+            name: name.to_string(),
+            supplier,
+            action: Arc::new(action),
+            compensation: None,
+        });
+        Ok(self)
+    }
+
+    /// Sets the compensation of the most recently added step.
+    ///
+    /// The compensation is sent to the same supplier and receives the step's response.
+    ///
+    /// # Panics
+    /// Panics if no step has been added yet.
+    pub fn compensate<F>(mut self, compensation: F) -> Self
+    where
+        F: Fn(&SagaContext, &SupplierResponse) -> SupplierRequest + Send + Sync + 'static,
+    {
+        let step = self.steps.last_mut().expect("compensate() must follow a step");
+        step.compensation = Some(Arc::new(compensation));
+        self
+    }
+
+    /// Retries each failing compensation up to `retries` more times.
+    pub fn with_compensation_retries(mut self, retries: usize) -> Self {
+        self.compensation_retries = retries;
+        self
+    }
+
+    /// Replaces the generator of saga IDs (UUIDv7 by default).
+    pub fn with_id_generator(mut self, generator: Arc<dyn IdGenerator>) -> Self {
+        self.id_generator = generator;
+        self
+    }
+
+    /// Runs the saga, compensating completed steps if a step fails.
+    pub fn run(&self) -> SagaOutcome {
+        let mut context = SagaContext {
+            saga_id: self.id_generator.generate(),
+            responses: HashMap::new(),
+        };
+        let mut outcome = SagaOutcome {
+            saga_id: context.saga_id.clone(),
+            ..SagaOutcome::default()
+        };
+
+        for (index, step) in self.steps.iter().enumerate() {
+            let result = catch_unwind(AssertUnwindSafe(|| (step.action)(&context)))
+                .map_err(|_| SupplierError::Internal(format!("saga step '{}' panicked", step.name)))
+                .and_then(|request| query_isolated(step.supplier.as_ref(), prepare(request, &context.saga_id, &step.name)));
+            match result {
+                Ok(response) => {
+                    context.responses.insert(step.name.clone(), response.clone());
+                    outcome.completed.push((step.name.clone(), response));
+                }
+                Err(error) => {
+                    outcome.failure = Some(SagaFailure {
+                        step: step.name.clone(),
+                        error,
+                    });
+                    self.compensate_until(index, &context, &mut outcome);
+                    break;
+                }
+            }
+        }
+        outcome
+    }
+
+    /// Compensates the steps before `failed`, in reverse order.
+    fn compensate_until(&self, failed: usize, context: &SagaContext, outcome: &mut SagaOutcome) {
+        for step in self.steps[..failed].iter().rev() {
+            let (Some(compensation), Some(response)) = (&step.compensation, context.response(&step.name)) else {
+                continue;
+            };
+            let Ok(request) = catch_unwind(AssertUnwindSafe(|| compensation(context, response))) else {
+                let error = SupplierError::Internal(format!("compensation of saga step '{}' panicked", step.name));
+                outcome.compensation_failures.push((step.name.clone(), error));
+                continue;
+            };
+            let key = format!("{}:compensate", step.name);
+            let request = prepare(request, &context.saga_id, &key);
+
+            let mut result = query_isolated(step.supplier.as_ref(), request.clone());
+            for _ in 0..self.compensation_retries {
+                if result.is_ok() {
+                    break;
+                }
+                result = query_isolated(step.supplier.as_ref(), request.clone());
+            }
+            match result {
+                Ok(_) => outcome.compensated.push(step.name.clone()),
+                Err(error) => outcome.compensation_failures.push((step.name.clone(), error)),
+            }
+        }
+    }
+}
+
+fn prepare(mut request: SupplierRequest, saga_id: &str, key: &str) -> SupplierRequest {
+    request.context.request_id.get_or_insert_with(|| saga_id.to_string());
+    request.idempotency_key.get_or_insert_with(|| format!("{}:{}", saga_id, key));
+    request
+}
@@ -0,0 +1,374 @@
+use std::collections::{BTreeMap, HashSet};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use serde_json::{json, Map, Value};
+use crate::errors::SupplierError;
+use crate::execution::QueryMemo;
+use crate::json_path::JsonPath;
+use crate::models::SupplierRequest;
+use crate::supplier::{query_isolated, Supplier, SupplierRegistry};
+use crate::supplier_group::SupplierGroup;
+
+/// Computes the output of a pipeline stage from the outputs of the stages it depends on.
+pub type StageRunner = Arc<dyn Fn(&PipelineContext) -> Result<Value, SupplierError> + Send + Sync>;
+
+/// The outputs of the pipeline stages completed so far.
+#[derive(Debug, Clone, Default)]
+pub struct PipelineContext {
+    outputs: BTreeMap<String, Value>,
+    // Shared by every stage of a run, so identical supplier calls of different stages run once.
+    memo: QueryMemo,
+}
+
+impl PipelineContext {
+    /// Returns `request` carrying the memo of the run, unless it already carries one.
+    fn memoized(&self, mut request: SupplierRequest) -> SupplierRequest {
+        request.context.memo.get_or_insert_with(|| self.memo.clone());
+        request
+    }
+
+    /// Returns the output of stage `name`.
+    pub fn output(&self, name: &str) -> Option<&Value> {
+        self.outputs.get(name)
+    }
+
+    /// Returns the value at JSON `pointer` (e.g. `/items/0/id`) in the output of stage `name`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the stage has no output or nothing is at `pointer`.
+    pub fn pointer(&self, name: &str, pointer: &str) -> Result<&Value, SupplierError> {
+        self.output(name)
+            .ok_or_else(|| SupplierError::InvalidInput(format!("stage '{}' has no output", name)))?
+            .pointer(pointer)
+            .ok_or_else(|| SupplierError::InvalidInput(format!("stage '{}' output has nothing at '{}'", name, pointer)))
+    }
+
+    /// Returns the first value at `path` in the output of stage `name`.
+    ///
+    /// The path is a JSONPath expression, such as `$.items[-1].id`, or a JSON pointer; see
+    /// [`JsonPath`](crate::json_path::JsonPath) for the supported syntax.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the path is invalid, the stage has no output or
+    /// nothing is at `path`.
+    pub fn extract(&self, name: &str, path: &str) -> Result<&Value, SupplierError> {
+        self.extract_all(name, path)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| SupplierError::InvalidInput(format!("stage '{}' output has nothing at '{}'", name, path)))
+    }
+
+    /// Returns every value at `path` in the output of stage `name`, e.g. all ids with
+    /// `$.items[*].id`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the path is invalid or the stage has no output.
+    pub fn extract_all(&self, name: &str, path: &str) -> Result<Vec<&Value>, SupplierError> {
+        let path = JsonPath::parse(path)?;
+        let output = self
+            .output(name)
+            .ok_or_else(|| SupplierError::InvalidInput(format!("stage '{}' has no output", name)))?;
+        Ok(path.find(output))
+    }
+}
+
+struct Stage {
+    name: String,
+    after: Vec<String>,
+    runner: StageRunner,
+}
+
+/// The outputs of a pipeline run.
+#[derive(Debug, Clone, Default)]
+pub struct PipelineResult {
+    /// The output of every stage that succeeded, keyed by stage name.
+    pub outputs: BTreeMap<String, Value>,
+
+    /// The error of every stage that failed.
+    pub failures: BTreeMap<String, SupplierError>,
+
+    /// Stages not run because a stage they depend on failed or was skipped, in stage order.
+    pub skipped: Vec<String>,
+}
+
+impl PipelineResult {
+    /// Returns whether every stage succeeded.
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty() && self.skipped.is_empty()
+    }
+
+    /// Returns the output of stage `name`.
+    pub fn output(&self, name: &str) -> Option<&Value> {
+        self.outputs.get(name)
+    }
+}
+
+/// A DAG of supplier calls where the outputs of earlier stages feed the requests of later ones.
+///
+/// Every stage produces a JSON value: a supplier stage outputs the response data, a group stage
+/// outputs `{ "successes": { supplier: data }, "failures": { supplier: error } }`, and a map
+/// stage outputs whatever its closure returns. Stages declare the stages they depend on with
+/// [`Pipeline::after`]; independent stages run concurrently, each starting as soon as its
+/// dependencies are done.
+///
+/// A failing stage does not stop independent branches; the stages depending on it are skipped.
+/// Supplier, registry and group stages reaching the same supplier instance with an identical
+/// request during one run call it only once, also through nested groups.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use serde_json::json;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::orchestration::pipeline::Pipeline;
+/// use supplier_kit::supplier::SupplierRegistry;
+/// use supplier_kit::supplier_group::BasicSupplierGroup;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let shop_a = MockSupplierBuilder::new("shop_a")
+///     .respond(SupplierOperation::Search, json!({ "price": 90, "id": "a-1" }))
+///     .respond(SupplierOperation::GetDetail, json!({ "id": "a-1", "stock": 3 }))
+///     .build();
+/// let shop_b = MockSupplierBuilder::new("shop_b")
+///     .respond(SupplierOperation::Search, json!({ "price": 120, "id": "b-7" }))
+///     .build();
+///
+/// let mut group = BasicSupplierGroup::new("marketplaces");
+/// group.add_supplier(shop_a.clone());
+/// group.add_supplier(shop_b.clone());
+/// let mut registry = SupplierRegistry::new();
+/// registry.register("shop_a", shop_a);
+/// registry.register("shop_b", shop_b);
+///
+/// let result = Pipeline::new()
+///     .query_group("search", Arc::new(group), |_| {
+///         Ok(SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" })))
+///     })
+///     .map("cheapest", |ctx| {
+///         let offers = ctx.pointer("search", "/successes")?.as_object().unwrap();
+///         let (supplier, offer) = offers.iter().min_by_key(|(_, o)| o["price"].as_u64()).unwrap();
+///         Ok(json!({ "supplier": supplier, "id": offer["id"] }))
+///     })
+///     .after(&["search"])
+///     .query_registry("detail", Arc::new(registry), |ctx| {
+///         let supplier = ctx.pointer("cheapest", "/supplier")?.as_str().unwrap_or_default().to_string();
+///         let id = ctx.pointer("cheapest", "/id")?.clone();
+///         Ok((supplier, SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": id }))))
+///     })
+///     .after(&["cheapest"])
+///     .run()
+///     .unwrap();
+///
+/// assert!(result.is_success());
+/// assert_eq!(result.output("detail").unwrap()["stock"], 3);
+/// ```
+#[derive(Default)]
+pub struct Pipeline {
+    stages: Vec<Stage>,
+    max_concurrency: Option<usize>,
+}
+
+impl Pipeline {
+    /// Creates an empty pipeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a stage computing its output with `runner`.
+    pub fn stage<F>(mut self, name: &str, runner: F) -> Self
+    where
+        F: Fn(&PipelineContext) -> Result<Value, SupplierError> + Send + Sync + 'static,
+    {
+        self.stages.push(Stage {
+            name: name.to_string(),
+            after: Vec::new(),
+            runner: Arc::new(runner),
+        });
+        self
+    }
+
+    /// Adds a stage transforming earlier outputs without calling a supplier.
+    pub fn map<F>(self, name: &str, transform: F) -> Self
+    where
+        F: Fn(&PipelineContext) -> Result<Value, SupplierError> + Send + Sync + 'static,
+    {
+        self.stage(name, transform)
+    }
+
+    /// Adds a stage querying `supplier` with the request built by `request`; outputs the response data.
+    pub fn query<F>(self, name: &str, supplier: Arc<dyn Supplier>, request: F) -> Self
+    where
+        F: Fn(&PipelineContext) -> Result<SupplierRequest, SupplierError> + Send + Sync + 'static,
+    {
+        self.stage(name, move |ctx| {
+            let request = ctx.memoized(request(ctx)?);
+            Ok(ctx.memo.query_with(&supplier, &request, || query_isolated(supplier.as_ref(), request.clone()))?.data)
+        })
+    }
+
+    /// Adds a stage querying the registry supplier named by `request`, e.g. the winner of a search.
+    pub fn query_registry<F>(self, name: &str, registry: Arc<SupplierRegistry>, request: F) -> Self
+    where
+        F: Fn(&PipelineContext) -> Result<(String, SupplierRequest), SupplierError> + Send + Sync + 'static,
+    {
+        self.stage(name, move |ctx| {
+            let (supplier, request) = request(ctx)?;
+            let request = ctx.memoized(request);
+            let instance = registry.lookup(&supplier).ok_or(SupplierError::NotFound)?;
+            Ok(ctx.memo.query_with(&instance, &request, || registry.query(&supplier, request.clone()))?.data)
+        })
+    }
+
+    /// Adds a stage querying `group`; fails only when every supplier of the group failed.
+    pub fn query_group<G, F>(self, name: &str, group: Arc<G>, request: F) -> Self
+    where
+        G: SupplierGroup + Send + Sync + ?Sized + 'static,
+        F: Fn(&PipelineContext) -> Result<SupplierRequest, SupplierError> + Send + Sync + 'static,
+    {
+        self.stage(name, move |ctx| {
+            let result = group.query(ctx.memoized(request(ctx)?));
+            if result.successes.is_empty()
+                && let Some((_, error)) = result.failures.first()
+            {
+                return Err(error.clone());
+            }
+            let successes: Map<String, Value> = result.successes.into_iter().map(|(n, r)| (n, r.data)).collect();
+            let failures: Map<String, Value> = result
+                .failures
+                .into_iter()
+                .map(|(n, e)| (n, serde_json::to_value(e).unwrap_or_default()))
+                .collect();
+            Ok(json!({ "successes": successes, "failures": failures }))
+        })
+    }
+
+    /// Makes the most recently added stage depend on the stages in `stages`.
+    ///
+    /// # Panics
+    /// Panics if no stage has been added yet.
+    pub fn after(mut self, stages: &[&str]) -> Self {
+        let stage = self.stages.last_mut().expect("after() must follow a stage");
+        stage.after.extend(stages.iter().map(|s| s.to_string()));
+        self
+    }
+
+    /// Limits how many stages run at the same time; unlimited by default.
+    pub fn with_max_concurrency(mut self, max: usize) -> Self {
+        self.max_concurrency = Some(max.max(1));
+        self
+    }
+
+    /// Returns the stages in topological order, grouped into layers.
+    ///
+    /// Every stage of a layer depends only on stages of earlier layers, so the stages of one
+    /// layer can run concurrently. Within a layer, stages keep the order they were added in.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if stage names are duplicated, a stage depends on an
+    /// unknown stage, or the dependencies form a cycle.
+    pub fn layers(&self) -> Result<Vec<Vec<String>>, SupplierError> {
+        let mut names = HashSet::new();
+        for stage in &self.stages {
+            if !names.insert(stage.name.as_str()) {
+                return Err(SupplierError::InvalidInput(format!("stage '{}' is declared twice", stage.name)));
+            }
+        }
+        for stage in &self.stages {
+            if let Some(unknown) = stage.after.iter().find(|d| !names.contains(d.as_str())) {
+                return Err(SupplierError::InvalidInput(format!(
+                    "stage '{}' depends on unknown stage '{}'",
+                    stage.name, unknown
+                )));
+            }
+        }
+
+        let mut layers: Vec<Vec<String>> = Vec::new();
+        let mut resolved: HashSet<&str> = HashSet::new();
+        while resolved.len() < self.stages.len() {
+            let layer: Vec<&str> = self
+                .stages
+                .iter()
+                .filter(|s| !resolved.contains(s.name.as_str()))
+                .filter(|s| s.after.iter().all(|d| resolved.contains(d.as_str())))
+                .map(|s| s.name.as_str())
+                .collect();
+            if layer.is_empty() {
+                let mut cyclic: Vec<&str> =
+                    self.stages.iter().map(|s| s.name.as_str()).filter(|n| !resolved.contains(n)).collect();
+                cyclic.sort_unstable();
+                return Err(SupplierError::InvalidInput(format!("stages {:?} form a dependency cycle", cyclic)));
+            }
+            resolved.extend(layer.iter().copied());
+            layers.push(layer.into_iter().map(String::from).collect());
+        }
+        Ok(layers)
+    }
+
+    /// Runs the pipeline.
+    ///
+    /// A stage starts as soon as every stage it depends on has succeeded, without waiting for
+    /// unrelated stages started alongside its dependencies. Ready stages start in the order they
+    /// were added, up to the concurrency limit set with [`Pipeline::with_max_concurrency`].
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the stage graph is invalid (see [`Pipeline::layers`]).
+    /// Stage failures are reported in the result.
+    pub fn run(&self) -> Result<PipelineResult, SupplierError> {
+        self.layers()?;
+
+        let mut context = PipelineContext::default();
+        let _scope = context.memo.scope();
+        let mut result = PipelineResult::default();
+        let mut pending: Vec<&Stage> = self.stages.iter().collect();
+        let limit = self.max_concurrency.unwrap_or(usize::MAX);
+        let (tx, rx) = mpsc::channel();
+
+        thread::scope(|scope| {
+            let mut running = 0;
+            loop {
+                let mut index = 0;
+                while index < pending.len() {
+                    let stage = pending[index];
+                    if stage.after.iter().any(|d| result.failures.contains_key(d) || result.skipped.contains(d)) {
+                        result.skipped.push(stage.name.clone());
+                        pending.remove(index);
+                        // A newly skipped stage may cause earlier pending stages to be skipped too.
+                        index = 0;
+                    } else if running < limit && stage.after.iter().all(|d| context.outputs.contains_key(d)) {
+                        let snapshot = context.clone();
+                        let tx = tx.clone();
+                        scope.spawn(move || {
+                            let outcome = catch_unwind(AssertUnwindSafe(|| (stage.runner)(&snapshot)))
+                                .unwrap_or_else(|_| Err(SupplierError::Internal("pipeline stage panicked".into())));
+                            let _ = tx.send((stage, outcome));
+                        });
+                        running += 1;
+                        pending.remove(index);
+                    } else {
+                        index += 1;
+                    }
+                }
+
+                if running == 0 {
+                    break;
+                }
+                let Ok((stage, outcome)) = rx.recv() else { break };
+                running -= 1;
+                match outcome {
+                    Ok(output) => {
+                        context.outputs.insert(stage.name.clone(), output.clone());
+                        result.outputs.insert(stage.name.clone(), output);
+                    }
+                    Err(error) => {
+                        result.failures.insert(stage.name.clone(), error);
+                    }
+                }
+            }
+        });
+
+        let order: Vec<&str> = self.stages.iter().map(|s| s.name.as_str()).collect();
+        result.skipped.sort_by_key(|name| order.iter().position(|n| n == name));
+        Ok(result)
+    }
+}
@@ -0,0 +1,305 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use crate::errors::SupplierError;
+
+#[derive(Debug, Default)]
+struct SupplierWindow {
+    outcomes: VecDeque<(bool, Duration)>,
+    ejected_until: Option<Instant>,
+    ejections: u64,
+}
+
+/// Detects misbehaving suppliers and temporarily ejects them from group fan-out.
+///
+/// The detector keeps a rolling window of the most recent outcomes of every supplier.
+/// Once a window holds at least `min_samples` outcomes and its error rate exceeds
+/// `max_error_rate` (or its average latency exceeds the optional `max_latency`), the
+/// supplier is ejected for the probation period. When the probation period ends, the
+/// supplier is re-admitted with an empty window.
+///
+/// Attach a detector to a group with `BasicSupplierGroup::with_outlier_detection`.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::outlier::OutlierDetector;
+///
+/// let detector = OutlierDetector::new(0.5, Duration::from_secs(30)).with_min_samples(2);
+/// detector.record("flaky", false, Duration::ZERO);
+/// assert!(!detector.is_ejected("flaky"));
+/// detector.record("flaky", false, Duration::ZERO);
+/// assert!(detector.is_ejected("flaky"));
+/// assert_eq!(detector.ejected(), vec!["flaky".to_string()]);
+/// ```
+#[derive(Debug)]
+pub struct OutlierDetector {
+    max_error_rate: f64,
+    max_latency: Option<Duration>,
+    probation: Duration,
+    window: usize,
+    min_samples: usize,
+    suppliers: Mutex<HashMap<String, SupplierWindow>>,
+}
+
+impl OutlierDetector {
+    /// Creates a detector that ejects suppliers whose error rate exceeds `max_error_rate`
+    /// for the duration of `probation`.
+    ///
+    /// By default, the rolling window holds 20 outcomes and at least 5 are required.
+    pub fn new(max_error_rate: f64, probation: Duration) -> Self {
+        Self {
+            max_error_rate,
+            max_latency: None,
+            probation,
+            window: 20,
+            min_samples: 5,
+            suppliers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Also ejects suppliers whose average latency over the window exceeds `max_latency`.
+    pub fn with_max_latency(mut self, max_latency: Duration) -> Self {
+        self.max_latency = Some(max_latency);
+        self
+    }
+
+    /// Sets how many recent outcomes are kept per supplier.
+    pub fn with_window(mut self, window: usize) -> Self {
+        self.window = window.max(1);
+        self
+    }
+
+    /// Sets how many outcomes must be observed before a supplier can be ejected.
+    pub fn with_min_samples(mut self, min_samples: usize) -> Self {
+        self.min_samples = min_samples.max(1);
+        self
+    }
+
+    /// Records the outcome of one query, ejecting the supplier if it became an outlier.
+    pub fn record(&self, supplier: &str, success: bool, latency: Duration) {
+        let mut suppliers = self.lock();
+        let entry = suppliers.entry(supplier.to_string()).or_default();
+        if entry.ejected_until.is_some() {
+            return;
+        }
+
+        entry.outcomes.push_back((success, latency));
+        while entry.outcomes.len() > self.window {
+            entry.outcomes.pop_front();
+        }
+        if entry.outcomes.len() < self.min_samples.min(self.window) {
+            return;
+        }
+
+        let samples = entry.outcomes.len() as f64;
+        let error_rate = entry.outcomes.iter().filter(|(ok, _)| !ok).count() as f64 / samples;
+        let average_latency = entry.outcomes.iter().map(|(_, l)| l.as_secs_f64()).sum::<f64>() / samples;
+        let too_slow = self
+            .max_latency
+            .is_some_and(|max| average_latency > max.as_secs_f64());
+
+        if error_rate > self.max_error_rate || too_slow {
+            entry.ejected_until = Some(Instant::now() + self.probation);
+            entry.ejections += 1;
+            entry.outcomes.clear();
+        }
+    }
+
+    /// Returns whether the supplier is currently ejected, re-admitting it once its probation has ended.
+    pub fn is_ejected(&self, supplier: &str) -> bool {
+        let mut suppliers = self.lock();
+        let Some(entry) = suppliers.get_mut(supplier) else {
+            return false;
+        };
+        match entry.ejected_until {
+            Some(until) if Instant::now() < until => true,
+            Some(_) => {
+                entry.ejected_until = None;
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the names of all currently ejected suppliers, sorted.
+    pub fn ejected(&self) -> Vec<String> {
+        let now = Instant::now();
+        let mut names: Vec<String> = self
+            .lock()
+            .iter()
+            .filter(|(_, w)| w.ejected_until.is_some_and(|until| now < until))
+            .map(|(name, _)| name.clone())
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Returns how many times the supplier has been ejected.
+    pub fn ejections(&self, supplier: &str) -> u64 {
+        self.lock().get(supplier).map_or(0, |w| w.ejections)
+    }
+
+    /// Re-admits a supplier immediately and forgets its recent outcomes.
+    pub fn readmit(&self, supplier: &str) {
+        if let Some(entry) = self.lock().get_mut(supplier) {
+            entry.ejected_until = None;
+            entry.outcomes.clear();
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, SupplierWindow>> {
+        self.suppliers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[derive(Debug, Default)]
+struct ErrorStreak {
+    code: Option<&'static str>,
+    count: u32,
+    level: u32,
+    ejected_until: Option<Instant>,
+    ejections: u64,
+}
+
+/// Ejects suppliers from group fan-out after errors that retrying cannot fix, for
+/// exponentially longer windows.
+///
+/// Unlike `OutlierDetector`, which looks at the share of failed calls, this policy looks at
+/// the kind of error: by default one `Unauthorized` or three consecutive `Throttled` errors
+/// eject a supplier. Other errors are left to the outlier detector and neither count nor
+/// reset a streak.
+///
+/// The first ejection lasts the base window; each further ejection without a successful
+/// call in between doubles it, up to the maximum. A throttled supplier is never re-admitted
+/// before the `retry_after` it asked for. A successful call resets the supplier's streak and
+/// window.
+///
+/// Attach the policy to a group with `BasicSupplierGroup::with_error_ejection`.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::outlier::ErrorEjector;
+///
+/// let ejector = ErrorEjector::new(Duration::from_secs(30), Duration::from_secs(3600));
+/// ejector.record("partner", Some(&SupplierError::Unauthorized));
+/// assert!(ejector.is_ejected("partner"));
+/// assert!(ejector.ejected_for("partner").unwrap() <= Duration::from_secs(30));
+///
+/// ejector.record("busy", Some(&SupplierError::Throttled { retry_after: None }));
+/// assert!(!ejector.is_ejected("busy"));
+/// ```
+#[derive(Debug)]
+pub struct ErrorEjector {
+    base: Duration,
+    max: Duration,
+    thresholds: HashMap<String, u32>,
+    suppliers: Mutex<HashMap<String, ErrorStreak>>,
+}
+
+impl ErrorEjector {
+    /// Creates a policy ejecting on the first `Unauthorized` and the third consecutive
+    /// `Throttled` error, first for `base`, doubling up to `max`.
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self {
+            base,
+            max: max.max(base),
+            thresholds: HashMap::from([("unauthorized".to_string(), 1), ("throttled".to_string(), 3)]),
+            suppliers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Ejects after `consecutive` errors with the given `SupplierError::code`; zero stops
+    /// ejecting on that error.
+    pub fn with_threshold(mut self, code: &str, consecutive: u32) -> Self {
+        if consecutive == 0 {
+            self.thresholds.remove(code);
+        } else {
+            self.thresholds.insert(code.to_string(), consecutive);
+        }
+        self
+    }
+
+    /// Records the outcome of one query: `None` for a success, or the error it failed with.
+    pub fn record(&self, supplier: &str, error: Option<&SupplierError>) {
+        let mut suppliers = self.lock();
+        let Some(error) = error else {
+            if let Some(entry) = suppliers.get_mut(supplier) {
+                entry.code = None;
+                entry.count = 0;
+                entry.level = 0;
+            }
+            return;
+        };
+        let code = error.code();
+        let Some(&threshold) = self.thresholds.get(code) else {
+            return;
+        };
+
+        let entry = suppliers.entry(supplier.to_string()).or_default();
+        if entry.ejected_until.is_some_and(|until| Instant::now() < until) {
+            return;
+        }
+        if entry.code == Some(code) {
+            entry.count += 1;
+        } else {
+            entry.code = Some(code);
+            entry.count = 1;
+        }
+        if entry.count < threshold {
+            return;
+        }
+
+        let window = self.base.saturating_mul(2u32.saturating_pow(entry.level)).min(self.max);
+        let window = window.max(error.retry_after().unwrap_or_default());
+        entry.ejected_until = Some(Instant::now() + window);
+        entry.ejections += 1;
+        entry.level = entry.level.saturating_add(1);
+        entry.code = None;
+        entry.count = 0;
+    }
+
+    /// Returns whether the supplier is currently ejected.
+    pub fn is_ejected(&self, supplier: &str) -> bool {
+        self.ejected_for(supplier).is_some()
+    }
+
+    /// Returns how much longer the supplier stays ejected, or `None` if it is admitted.
+    pub fn ejected_for(&self, supplier: &str) -> Option<Duration> {
+        let now = Instant::now();
+        let until = self.lock().get(supplier)?.ejected_until?;
+        (now < until).then(|| until - now)
+    }
+
+    /// Returns the names of all currently ejected suppliers, sorted.
+    pub fn ejected(&self) -> Vec<String> {
+        let now = Instant::now();
+        let mut names: Vec<String> = self
+            .lock()
+            .iter()
+            .filter(|(_, s)| s.ejected_until.is_some_and(|until| now < until))
+            .map(|(name, _)| name.clone())
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Returns how many times the supplier has been ejected.
+    pub fn ejections(&self, supplier: &str) -> u64 {
+        self.lock().get(supplier).map_or(0, |s| s.ejections)
+    }
+
+    /// Re-admits a supplier immediately and resets its streak and window, e.g. after its
+    /// credentials were rotated.
+    pub fn readmit(&self, supplier: &str) {
+        if let Some(entry) = self.lock().get_mut(supplier) {
+            *entry = ErrorStreak { ejections: entry.ejections, ..ErrorStreak::default() };
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, ErrorStreak>> {
+        self.suppliers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::{BTreeMap, HashMap};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::{query_isolated, Supplier};
+use crate::supplier_group::{SupplierGroup, SupplierGroupResult};
+
+/// The pagination position of a single supplier.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SupplierCursor {
+    /// The supplier has more data; the value is passed back to it to fetch the next page.
+    /// It can be an offset, a page token, or any other JSON value the supplier understands.
+    Next(Value),
+    /// The supplier has no more data.
+    Exhausted,
+}
+
+/// An opaque group-level cursor holding the individual cursor of every supplier.
+///
+/// Encoding the cursor as a string (see [`GroupCursor::encode`]) makes it safe to hand
+/// to API clients, who pass it back unchanged to fetch the next page.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct GroupCursor {
+    /// The cursor of each supplier, by supplier name.
+    pub positions: BTreeMap<String, SupplierCursor>,
+}
+
+impl GroupCursor {
+    /// Returns `true` when every supplier is exhausted.
+    pub fn is_exhausted(&self) -> bool {
+        self.positions.values().all(|c| *c == SupplierCursor::Exhausted)
+    }
+
+    /// Encodes the cursor as an opaque, URL-safe string.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::pagination::{GroupCursor, SupplierCursor};
+    /// let mut cursor = GroupCursor::default();
+    /// cursor.positions.insert("a".into(), SupplierCursor::Next(serde_json::json!(20)));
+    /// let token = cursor.encode();
+    /// assert_eq!(GroupCursor::decode(&token).unwrap(), cursor);
+    /// ```
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    /// Decodes a cursor produced by [`GroupCursor::encode`].
+    ///
+    /// # Returns
+    /// `Err(SupplierError::InvalidInput)` if the token is malformed.
+    pub fn decode(token: &str) -> Result<Self, SupplierError> {
+        let json = URL_SAFE_NO_PAD
+            .decode(token)
+            .map_err(|e| SupplierError::InvalidInput(format!("invalid cursor: {}", e)))?;
+        serde_json::from_slice(&json).map_err(|e| SupplierError::InvalidInput(format!("invalid cursor: {}", e)))
+    }
+}
+
+/// One page of a paginated group query.
+#[derive(Debug, Clone)]
+pub struct GroupPage {
+    /// The per-supplier outcomes for this page.
+    pub result: SupplierGroupResult,
+
+    /// The cursor for the next page, or `None` when every supplier is exhausted.
+    pub next: Option<GroupCursor>,
+}
+
+/// Runs paginated group queries, keeping a separate cursor for every supplier.
+///
+/// Each supplier receives its own cursor in the request parameter `cursor_param` and reports
+/// its next cursor at the JSON pointer `next_cursor_pointer` of its response data. A missing
+/// or `null` next cursor marks the supplier as exhausted. Suppliers that fail keep their
+/// previous cursor so the next page retries from the same position.
+///
+/// # Example
+/// ```
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::pagination::Paginator;
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::supplier_group::BasicSupplierGroup;
+///
+/// /// Serves items 0..5 in pages of two, using an offset cursor.
+/// struct Numbers;
+///
+/// impl Supplier for Numbers {
+///     fn name(&self) -> &str { "numbers" }
+///     fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         let offset = request.params["cursor"].as_u64().unwrap_or(0);
+///         let items: Vec<u64> = (offset..(offset + 2).min(5)).collect();
+///         let next = if offset + 2 < 5 { serde_json::json!(offset + 2) } else { serde_json::Value::Null };
+///         Ok(SupplierResponse::new(serde_json::json!({ "items": items, "next_cursor": next })))
+///     }
+/// }
+///
+/// let mut group = BasicSupplierGroup::new("g");
+/// group.add_supplier(Numbers);
+///
+/// let paginator = Paginator::default();
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}));
+///
+/// let mut pages = 0;
+/// let mut cursor = None;
+/// loop {
+///     let page = paginator.query_page(&group, request.clone(), cursor.as_ref());
+///     pages += 1;
+///     match page.next {
+///         Some(next) => cursor = Some(next),
+///         None => break,
+///     }
+/// }
+/// assert_eq!(pages, 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paginator {
+    cursor_param: String,
+    next_cursor_pointer: String,
+}
+
+impl Default for Paginator {
+    fn default() -> Self {
+        Self::new("cursor", "/next_cursor")
+    }
+}
+
+impl Paginator {
+    /// Creates a paginator that passes cursors in `cursor_param` and reads them from `next_cursor_pointer`.
+    pub fn new(cursor_param: &str, next_cursor_pointer: &str) -> Self {
+        Self {
+            cursor_param: cursor_param.to_string(),
+            next_cursor_pointer: next_cursor_pointer.to_string(),
+        }
+    }
+
+    /// Fetches one page from `group`.
+    ///
+    /// Without a cursor, the request is broadcast to every supplier. With a cursor, only
+    /// suppliers that are not exhausted are queried, each with its own cursor value.
+    pub fn query_page<G>(&self, group: &G, request: SupplierRequest, cursor: Option<&GroupCursor>) -> GroupPage
+    where
+        G: SupplierGroup + ?Sized,
+    {
+        let result = match cursor {
+            None => group.query(request),
+            Some(cursor) => {
+                let requests: HashMap<String, SupplierRequest> = cursor
+                    .positions
+                    .iter()
+                    .filter_map(|(name, position)| match position {
+                        SupplierCursor::Next(value) => Some((name.clone(), self.with_cursor(&request, value))),
+                        SupplierCursor::Exhausted => None,
+                    })
+                    .collect();
+                group.query_each(requests)
+            }
+        };
+
+        let mut next = cursor.cloned().unwrap_or_default();
+        for (name, response) in &result.successes {
+            let position = match response.data.pointer(&self.next_cursor_pointer) {
+                None | Some(Value::Null) => SupplierCursor::Exhausted,
+                Some(value) => SupplierCursor::Next(value.clone()),
+            };
+            next.positions.insert(name.clone(), position);
+        }
+        for (name, _) in &result.failures {
+            next.positions
+                .entry(name.clone())
+                .or_insert_with(|| SupplierCursor::Next(Value::Null));
+        }
+
+        GroupPage {
+            result,
+            next: (!next.is_exhausted()).then_some(next),
+        }
+    }
+
+    /// Fetches every page from a single supplier, following its next cursor until it is exhausted.
+    ///
+    /// # Errors
+    /// Returns the supplier's error if any page fails, or `SupplierError::Internal` if the
+    /// supplier still reports a next cursor after `max_pages` pages.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::pagination::Paginator;
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let supplier = MockSupplierBuilder::new("erp")
+    ///     .then_respond(serde_json::json!({ "items": [1, 2], "next_cursor": "p2" }))
+    ///     .then_respond(serde_json::json!({ "items": [3] }))
+    ///     .build();
+    /// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}));
+    ///
+    /// let pages = Paginator::default().fetch_all(&supplier, request, 10).unwrap();
+    /// assert_eq!(pages.len(), 2);
+    /// assert_eq!(supplier.last_request().unwrap().params["cursor"], "p2");
+    /// ```
+    pub fn fetch_all(
+        &self,
+        supplier: &dyn Supplier,
+        request: SupplierRequest,
+        max_pages: usize,
+    ) -> Result<Vec<SupplierResponse>, SupplierError> {
+        let mut pages = Vec::new();
+        let mut cursor = Value::Null;
+        loop {
+            if pages.len() == max_pages {
+                return Err(SupplierError::Internal(format!(
+                    "supplier '{}' has more than {} pages",
+                    supplier.name(),
+                    max_pages
+                )));
+            }
+            let response = query_isolated(supplier, self.with_cursor(&request, &cursor))?;
+            let next = response.data.pointer(&self.next_cursor_pointer).cloned();
+            pages.push(response);
+            match next {
+                None | Some(Value::Null) => return Ok(pages),
+                Some(next) => cursor = next,
+            }
+        }
+    }
+
+    fn with_cursor(&self, request: &SupplierRequest, cursor: &Value) -> SupplierRequest {
+        let mut request = request.clone();
+        if !cursor.is_null() {
+            if !request.params.is_object() {
+                request.params = Value::Object(Default::default());
+            }
+            request.params[&self.cursor_param] = cursor.clone();
+        }
+        request
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::errors::SupplierError;
+
+/// A hierarchical supplier name such as `eu/amazon_de/mobile`, one segment per level.
+///
+/// Paths render with `/` between segments. Segments may contain any character: `~` and `/`
+/// inside a segment are escaped as `~0` and `~1` (as in JSON pointers), so a supplier named
+/// `amazon/de` inside `eu` (`eu/amazon~1de`) never collides with the member `de` of a nested
+/// group `amazon` (`eu/amazon/de`). Paths serialize as their rendered string.
+///
+/// # Example
+/// ```
+/// use supplier_kit::path::SupplierPath;
+///
+/// let path = SupplierPath::new("eu").child("amazon/de");
+/// assert_eq!(path.to_string(), "eu/amazon~1de");
+/// assert_eq!(path.leaf(), "amazon/de");
+///
+/// let nested: SupplierPath = "eu/amazon/de".parse().unwrap();
+/// assert_eq!(nested.segments(), ["eu", "amazon", "de"]);
+/// assert_ne!(nested, path);
+/// assert!(nested.starts_with(&SupplierPath::new("eu")));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SupplierPath {
+    segments: Vec<String>,
+}
+
+impl SupplierPath {
+    /// Creates a single-segment path for the supplier or group `name`, taken verbatim.
+    pub fn new(name: &str) -> Self {
+        Self {
+            segments: vec![name.to_string()],
+        }
+    }
+
+    /// Parses a rendered path, unescaping `~0` and `~1` in each segment.
+    ///
+    /// Fails with `SupplierError::InvalidInput` for empty segments and unknown escapes.
+    pub fn parse(path: &str) -> Result<Self, SupplierError> {
+        let segments = path
+            .split('/')
+            .map(|segment| match segment {
+                "" => Err(SupplierError::InvalidInput(format!("supplier path '{}' has an empty segment", path))),
+                segment => unescape(segment).ok_or_else(|| {
+                    SupplierError::InvalidInput(format!("supplier path '{}' has an invalid '~' escape", path))
+                }),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { segments })
+    }
+
+    /// Returns the path with the segment `name` appended.
+    pub fn child(&self, name: &str) -> Self {
+        let mut path = self.clone();
+        path.segments.push(name.to_string());
+        path
+    }
+
+    /// Returns the path with every segment of `other` appended.
+    pub fn join(&self, other: &SupplierPath) -> Self {
+        let mut path = self.clone();
+        path.segments.extend(other.segments.iter().cloned());
+        path
+    }
+
+    /// Returns the unescaped segments, from the outermost level down.
+    pub fn segments(&self) -> &[String] {
+        &self.segments
+    }
+
+    /// Returns the first segment, e.g. the group member a flattened result entry belongs to.
+    pub fn root(&self) -> &str {
+        &self.segments[0]
+    }
+
+    /// Returns the last segment, usually the supplier's own name.
+    pub fn leaf(&self) -> &str {
+        &self.segments[self.segments.len() - 1]
+    }
+
+    /// Returns the path without its last segment, or `None` for single-segment paths.
+    pub fn parent(&self) -> Option<Self> {
+        (self.segments.len() > 1).then(|| Self {
+            segments: self.segments[..self.segments.len() - 1].to_vec(),
+        })
+    }
+
+    /// Returns whether the leading segments of the path are exactly those of `prefix`.
+    pub fn starts_with(&self, prefix: &SupplierPath) -> bool {
+        self.segments.starts_with(&prefix.segments)
+    }
+}
+
+impl fmt::Display for SupplierPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, segment) in self.segments.iter().enumerate() {
+            if index > 0 {
+                f.write_str("/")?;
+            }
+            f.write_str(&segment.replace('~', "~0").replace('/', "~1"))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SupplierPath {
+    type Err = SupplierError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        Self::parse(path)
+    }
+}
+
+impl Serialize for SupplierPath {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SupplierPath {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let path = String::deserialize(deserializer)?;
+        Self::parse(&path).map_err(serde::de::Error::custom)
+    }
+}
+
+fn unescape(segment: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(segment.len());
+    let mut chars = segment.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '~' => match chars.next()? {
+                '0' => unescaped.push('~'),
+                '1' => unescaped.push('/'),
+                _ => return None,
+            },
+            c => unescaped.push(c),
+        }
+    }
+    Some(unescaped)
+}
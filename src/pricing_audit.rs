@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+use std::thread;
+use std::time::{Duration, SystemTime};
+use serde::{Deserialize, Serialize};
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier_group::SupplierGroup;
+
+/// A normalized price and availability offered by one supplier.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct PriceQuote {
+    /// The price, in the audit's canonical currency.
+    pub price: f64,
+
+    /// Whether the item was reported as available.
+    pub available: bool,
+}
+
+/// A single supplier's answer in one audit round.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PriceObservation {
+    /// The index of the audit round that produced this observation.
+    pub round: usize,
+
+    /// When the round was run.
+    pub at: SystemTime,
+
+    /// The supplier name.
+    pub supplier: String,
+
+    /// The extracted quote, or `None` if the supplier failed or returned no price.
+    pub quote: Option<PriceQuote>,
+}
+
+/// Aggregated pricing statistics for one supplier across all audit rounds.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SupplierPriceStats {
+    /// Number of rounds in which the supplier returned a price.
+    pub quoted: usize,
+
+    /// Number of rounds in which the supplier failed or returned no price.
+    pub missing: usize,
+
+    /// Fraction of quotes reported as available.
+    pub availability_ratio: f64,
+
+    /// Lowest price observed.
+    pub min: f64,
+
+    /// Highest price observed.
+    pub max: f64,
+
+    /// Mean price observed.
+    pub mean: f64,
+
+    /// Population variance of the observed prices.
+    pub variance: f64,
+
+    /// Number of rounds in which this supplier had the cheapest available offer.
+    pub cheapest_wins: usize,
+}
+
+/// A comparison of suppliers across all recorded audit rounds.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PriceComparisonReport {
+    /// The number of rounds recorded.
+    pub rounds: usize,
+
+    /// The cheapest available supplier per round (`None` if nobody had an available offer).
+    pub cheapest_per_round: Vec<Option<String>>,
+
+    /// Statistics per supplier, ordered by supplier name.
+    pub suppliers: BTreeMap<String, SupplierPriceStats>,
+}
+
+impl PriceComparisonReport {
+    /// Returns the supplier that was cheapest in the most rounds, if any.
+    pub fn overall_cheapest(&self) -> Option<&str> {
+        self.suppliers
+            .iter()
+            .filter(|(_, stats)| stats.cheapest_wins > 0)
+            .max_by_key(|(_, stats)| stats.cheapest_wins)
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+type PriceExtractor = Box<dyn Fn(&SupplierResponse) -> Option<PriceQuote> + Send + Sync>;
+
+/// Runs the same request across all members of a group repeatedly and compares their prices.
+///
+/// Each call to [`PriceAudit::record`] runs one round: the group is queried, a `PriceQuote`
+/// is extracted from every successful response, and the observations are stored.
+/// [`PriceAudit::report`] then summarizes who was cheapest and how prices varied over time.
+///
+/// # Example
+/// ```
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::pricing_audit::PriceAudit;
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::supplier_group::BasicSupplierGroup;
+///
+/// struct FixedPrice(&'static str, f64);
+///
+/// impl Supplier for FixedPrice {
+///     fn name(&self) -> &str { self.0 }
+///     fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         Ok(SupplierResponse::new(serde_json::json!({ "price": self.1, "in_stock": true })))
+///     }
+/// }
+///
+/// let mut group = BasicSupplierGroup::new("marketplaces");
+/// group.add_supplier(FixedPrice("a", 10.0));
+/// group.add_supplier(FixedPrice("b", 8.5));
+///
+/// let request = SupplierRequest::new(
+///     SupplierOperation::GetDetail,
+///     serde_json::json!({ "sku": "X-1" }),
+/// );
+///
+/// let mut audit = PriceAudit::from_pointers("/price", "/in_stock");
+/// audit.record(&group, request);
+/// assert_eq!(audit.report().overall_cheapest(), Some("b"));
+/// ```
+pub struct PriceAudit {
+    extractor: PriceExtractor,
+    observations: Vec<PriceObservation>,
+    rounds: usize,
+}
+
+impl PriceAudit {
+    /// Creates an audit using a custom function to extract a quote from a response.
+    pub fn new<F>(extractor: F) -> Self
+    where
+        F: Fn(&SupplierResponse) -> Option<PriceQuote> + Send + Sync + 'static,
+    {
+        Self {
+            extractor: Box::new(extractor),
+            observations: Vec::new(),
+            rounds: 0,
+        }
+    }
+
+    /// Creates an audit that reads the price and availability from JSON pointers into `data`.
+    ///
+    /// A missing availability field is treated as available.
+    pub fn from_pointers(price: &str, available: &str) -> Self {
+        let price = price.to_string();
+        let available = available.to_string();
+        Self::new(move |response| {
+            let price = response.data.pointer(&price)?.as_f64()?;
+            let available = response.data.pointer(&available).and_then(|v| v.as_bool()).unwrap_or(true);
+            Some(PriceQuote { price, available })
+        })
+    }
+
+    /// Runs one audit round against `group` and stores its observations.
+    pub fn record<G: SupplierGroup + ?Sized>(&mut self, group: &G, request: SupplierRequest) {
+        let at = SystemTime::now();
+        let round = self.rounds;
+        let result = group.query(request);
+
+        let successes = result
+            .successes
+            .iter()
+            .map(|(name, response)| (name.clone(), (self.extractor)(response)));
+        let failures = result.failures.iter().map(|(name, _)| (name.clone(), None));
+
+        self.observations.extend(
+            successes
+                .chain(failures)
+                .map(|(supplier, quote)| PriceObservation { round, at, supplier, quote }),
+        );
+        self.rounds += 1;
+    }
+
+    /// Runs `rounds` audit rounds, sleeping `interval` between them.
+    ///
+    /// This blocks the current thread; run it on a background thread for long audits.
+    pub fn run_schedule<G: SupplierGroup + ?Sized>(
+        &mut self,
+        group: &G,
+        request: SupplierRequest,
+        interval: Duration,
+        rounds: usize,
+    ) {
+        for i in 0..rounds {
+            if i > 0 {
+                thread::sleep(interval);
+            }
+            self.record(group, request.clone());
+        }
+    }
+
+    /// Returns every stored observation, in recording order.
+    pub fn observations(&self) -> &[PriceObservation] {
+        &self.observations
+    }
+
+    /// Builds a comparison report over all recorded rounds.
+    pub fn report(&self) -> PriceComparisonReport {
+        let mut report = PriceComparisonReport {
+            rounds: self.rounds,
+            ..Default::default()
+        };
+
+        for round in 0..self.rounds {
+            let cheapest = self
+                .observations
+                .iter()
+                .filter(|o| o.round == round)
+                .filter_map(|o| o.quote.filter(|q| q.available).map(|q| (o, q.price)))
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(o, _)| o.supplier.clone());
+            report.cheapest_per_round.push(cheapest);
+        }
+
+        let mut by_supplier: BTreeMap<&str, Vec<&PriceObservation>> = BTreeMap::new();
+        for observation in &self.observations {
+            by_supplier.entry(&observation.supplier).or_default().push(observation);
+        }
+
+        for (name, observations) in by_supplier {
+            let quotes: Vec<PriceQuote> = observations.iter().filter_map(|o| o.quote).collect();
+            let prices: Vec<f64> = quotes.iter().map(|q| q.price).collect();
+            let count = prices.len() as f64;
+            let mean = if prices.is_empty() { 0.0 } else { prices.iter().sum::<f64>() / count };
+            let variance = if prices.is_empty() {
+                0.0
+            } else {
+                prices.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / count
+            };
+
+            let stats = SupplierPriceStats {
+                quoted: quotes.len(),
+                missing: observations.len() - quotes.len(),
+                availability_ratio: if quotes.is_empty() {
+                    0.0
+                } else {
+                    quotes.iter().filter(|q| q.available).count() as f64 / count
+                },
+                min: prices.iter().copied().reduce(f64::min).unwrap_or(0.0),
+                max: prices.iter().copied().reduce(f64::max).unwrap_or(0.0),
+                mean,
+                variance,
+                cheapest_wins: report
+                    .cheapest_per_round
+                    .iter()
+                    .filter(|c| c.as_deref() == Some(name))
+                    .count(),
+            };
+            report.suppliers.insert(name.to_string(), stats);
+        }
+
+        report
+    }
+}
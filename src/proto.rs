@@ -0,0 +1,291 @@
+use serde_json::Value;
+use crate::context;
+use crate::errors;
+use crate::models;
+
+/// How important a request is, used to decide what to drop under load.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum Priority {
+    Unspecified = 0,
+    Low = 1,
+    Normal = 2,
+    High = 3,
+    Critical = 4,
+}
+
+/// Cross-cutting information that travels with a request.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RequestContext {
+    #[prost(string, optional, tag = "1")]
+    pub request_id: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    pub caller: Option<String>,
+    #[prost(string, optional, tag = "3")]
+    pub tenant: Option<String>,
+    #[prost(enumeration = "Priority", tag = "4")]
+    pub priority: i32,
+    #[prost(string, optional, tag = "5")]
+    pub session_id: Option<String>,
+    #[prost(string, optional, tag = "6")]
+    pub locale: Option<String>,
+    #[prost(string, repeated, tag = "7")]
+    pub excluded_suppliers: Vec<String>,
+}
+
+/// A request to be processed by a supplier.
+///
+/// The types of this module are generated with prost from `proto/supplier_kit/v1/models.proto`,
+/// so services written in other languages, such as Go suppliers, can exchange requests and
+/// responses with a Rust gateway. Conversions from the core models are infallible; conversions
+/// back validate the embedded JSON documents.
+///
+/// # Example
+/// ```
+/// use prost::Message;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::proto;
+///
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({ "q": "lamp" }));
+/// let bytes = proto::SupplierRequest::from(request.clone()).encode_to_vec();
+///
+/// let decoded = proto::SupplierRequest::decode(bytes.as_slice()).unwrap();
+/// assert_eq!(decoded.operation, "search");
+/// assert_eq!(SupplierRequest::try_from(decoded).unwrap(), request);
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SupplierRequest {
+    /// The operation name, as returned by `SupplierOperation::as_str`.
+    #[prost(string, tag = "1")]
+    pub operation: String,
+    /// The operation parameters as a JSON document.
+    #[prost(bytes = "vec", tag = "2")]
+    pub params: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub context: Option<RequestContext>,
+    #[prost(string, optional, tag = "4")]
+    pub idempotency_key: Option<String>,
+    #[prost(uint32, optional, tag = "5")]
+    pub version: Option<u32>,
+}
+
+/// Where the data of a response came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum ResponseSource {
+    Live = 0,
+    Cache = 1,
+    Fallback = 2,
+    Replay = 3,
+    Push = 4,
+}
+
+/// A non-JSON payload, such as an image, a PDF or a CSV export.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ResponseBody {
+    #[prost(string, tag = "1")]
+    pub content_type: String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub bytes: Vec<u8>,
+}
+
+/// A response returned by a supplier.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SupplierResponse {
+    /// The response data as a JSON document.
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub body: Option<ResponseBody>,
+    #[prost(enumeration = "ResponseSource", tag = "3")]
+    pub source: i32,
+}
+
+/// A failed supplier call.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SupplierError {
+    /// The machine-readable error code, as returned by `SupplierError::code`.
+    #[prost(string, tag = "1")]
+    pub code: String,
+    #[prost(string, optional, tag = "2")]
+    pub message: Option<String>,
+}
+
+impl From<context::Priority> for Priority {
+    fn from(priority: context::Priority) -> Self {
+        match priority {
+            context::Priority::Low => Priority::Low,
+            context::Priority::Normal => Priority::Normal,
+            context::Priority::High => Priority::High,
+            context::Priority::Critical => Priority::Critical,
+        }
+    }
+}
+
+impl From<context::RequestContext> for RequestContext {
+    fn from(context: context::RequestContext) -> Self {
+        Self {
+            request_id: context.request_id,
+            caller: context.caller,
+            tenant: context.tenant,
+            priority: context.priority.map_or(Priority::Unspecified, Priority::from) as i32,
+            session_id: context.session_id,
+            locale: context.locale,
+            excluded_suppliers: context.excluded_suppliers.into_iter().collect(),
+        }
+    }
+}
+
+impl From<RequestContext> for context::RequestContext {
+    /// Unknown priorities are treated as unspecified.
+    fn from(context: RequestContext) -> Self {
+        let priority = match Priority::try_from(context.priority) {
+            Ok(Priority::Low) => Some(context::Priority::Low),
+            Ok(Priority::Normal) => Some(context::Priority::Normal),
+            Ok(Priority::High) => Some(context::Priority::High),
+            Ok(Priority::Critical) => Some(context::Priority::Critical),
+            Ok(Priority::Unspecified) | Err(_) => None,
+        };
+        Self {
+            request_id: context.request_id,
+            caller: context.caller,
+            tenant: context.tenant,
+            priority,
+            session_id: context.session_id,
+            locale: context.locale,
+            excluded_suppliers: context.excluded_suppliers.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<models::SupplierRequest> for SupplierRequest {
+    fn from(request: models::SupplierRequest) -> Self {
+        let context = (request.context != context::RequestContext::default()).then(|| request.context.into());
+        Self {
+            operation: request.operation.as_str().to_string(),
+            params: to_json(&request.params),
+            context,
+            idempotency_key: request.idempotency_key,
+            version: request.version,
+        }
+    }
+}
+
+impl TryFrom<SupplierRequest> for models::SupplierRequest {
+    type Error = errors::SupplierError;
+
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if `params` is not a valid JSON document.
+    fn try_from(request: SupplierRequest) -> Result<Self, Self::Error> {
+        let params = from_json(&request.params)
+            .map_err(|e| errors::SupplierError::InvalidInput(format!("invalid params JSON: {}", e)))?;
+        Ok(Self {
+            operation: models::SupplierOperation::from(request.operation.as_str()),
+            params,
+            context: request.context.map(Into::into).unwrap_or_default(),
+            idempotency_key: request.idempotency_key,
+            version: request.version,
+        })
+    }
+}
+
+impl From<models::ResponseSource> for ResponseSource {
+    fn from(source: models::ResponseSource) -> Self {
+        match source {
+            models::ResponseSource::Live => ResponseSource::Live,
+            models::ResponseSource::Cache => ResponseSource::Cache,
+            models::ResponseSource::Fallback => ResponseSource::Fallback,
+            models::ResponseSource::Replay => ResponseSource::Replay,
+            models::ResponseSource::Push => ResponseSource::Push,
+        }
+    }
+}
+
+impl From<models::SupplierResponse> for SupplierResponse {
+    fn from(response: models::SupplierResponse) -> Self {
+        Self {
+            data: to_json(&response.data),
+            body: response.body.map(|body| ResponseBody {
+                content_type: body.content_type,
+                bytes: body.bytes,
+            }),
+            source: ResponseSource::from(response.metadata.source) as i32,
+        }
+    }
+}
+
+impl TryFrom<SupplierResponse> for models::SupplierResponse {
+    type Error = errors::SupplierError;
+
+    /// Unknown sources are treated as live.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::Upstream` if `data` is not a valid JSON document.
+    fn try_from(response: SupplierResponse) -> Result<Self, Self::Error> {
+        let data = from_json(&response.data)
+            .map_err(|e| errors::SupplierError::Upstream(format!("invalid response data JSON: {}", e)))?;
+        let source = match ResponseSource::try_from(response.source) {
+            Ok(ResponseSource::Cache) => models::ResponseSource::Cache,
+            Ok(ResponseSource::Fallback) => models::ResponseSource::Fallback,
+            Ok(ResponseSource::Replay) => models::ResponseSource::Replay,
+            Ok(ResponseSource::Push) => models::ResponseSource::Push,
+            Ok(ResponseSource::Live) | Err(_) => models::ResponseSource::Live,
+        };
+        let mut converted = models::SupplierResponse::new(data).with_source(source);
+        converted.body = response
+            .body
+            .map(|body| models::ResponseBody::new(&body.content_type, body.bytes));
+        Ok(converted)
+    }
+}
+
+impl From<errors::SupplierError> for SupplierError {
+    fn from(error: errors::SupplierError) -> Self {
+        let value = serde_json::to_value(&error).unwrap_or_default();
+        let message = match &value["message"] {
+            Value::Null => None,
+            Value::String(message) => Some(message.clone()),
+            details => Some(details.to_string()),
+        };
+        Self {
+            code: error.code().to_string(),
+            message,
+        }
+    }
+}
+
+impl From<SupplierError> for errors::SupplierError {
+    /// Errors with a code unknown to this version of the kit become `SupplierError::Upstream`.
+    ///
+    /// Messages of errors with structured details, such as `SupplierError::Throttled`, carry
+    /// the details as JSON text.
+    fn from(error: SupplierError) -> Self {
+        let details = error.message.as_deref().and_then(|message| serde_json::from_str::<Value>(message).ok());
+        let decoded = serde_json::from_value(serde_json::json!({ "code": error.code, "message": error.message }))
+            .or_else(|e| match details {
+                Some(details) => serde_json::from_value(serde_json::json!({ "code": error.code, "message": details })),
+                None => Err(e),
+            });
+        decoded.unwrap_or_else(|_| {
+            errors::SupplierError::Upstream(match error.message {
+                Some(message) => format!("{}: {}", error.code, message),
+                None => error.code,
+            })
+        })
+    }
+}
+
+fn to_json(value: &Value) -> Vec<u8> {
+    if value.is_null() {
+        return Vec::new();
+    }
+    serde_json::to_vec(value).unwrap_or_default()
+}
+
+fn from_json(bytes: &[u8]) -> Result<Value, serde_json::Error> {
+    if bytes.is_empty() {
+        return Ok(Value::Null);
+    }
+    serde_json::from_slice(bytes)
+}
@@ -0,0 +1,255 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
+use serde_json::{json, Value};
+use crate::errors::SupplierError;
+use crate::models::{ResponseSource, SupplierOperation, SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+
+/// HTTP webhook receiver feeding pushed data into an [`IngestSink`].
+#[cfg(feature = "axum")]
+pub mod webhook;
+
+/// Data a supplier delivered proactively instead of answering a query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PushEvent {
+    /// The name of the supplier that pushed the data.
+    pub supplier: String,
+
+    /// What the push means: `Delete` removes records, any other operation upserts them.
+    pub operation: SupplierOperation,
+
+    /// A single record or an array of records.
+    pub data: Value,
+
+    /// The supplier's ID for this delivery, used to drop redelivered duplicates.
+    pub event_id: Option<String>,
+
+    /// When the event was received.
+    pub received_at: SystemTime,
+}
+
+impl PushEvent {
+    /// Creates an upsert event received now.
+    pub fn new(supplier: &str, data: Value) -> Self {
+        Self {
+            supplier: supplier.to_string(),
+            operation: SupplierOperation::Update,
+            data,
+            event_id: None,
+            received_at: SystemTime::now(),
+        }
+    }
+
+    /// Sets the operation of the event.
+    pub fn with_operation(mut self, operation: SupplierOperation) -> Self {
+        self.operation = operation;
+        self
+    }
+
+    /// Sets the supplier's delivery ID.
+    pub fn with_event_id(mut self, event_id: &str) -> Self {
+        self.event_id = Some(event_id.to_string());
+        self
+    }
+}
+
+/// Receives data pushed by suppliers.
+///
+/// Implemented for closures, for `mpsc::Sender<PushEvent>`, for [`PushStore`], and for lists
+/// of sinks, which forward every event to each sink in order and stop at the first error.
+pub trait IngestSink: Send + Sync {
+    /// Accepts one pushed event.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` for events the sink cannot interpret.
+    fn ingest(&self, event: &PushEvent) -> Result<(), SupplierError>;
+}
+
+impl<F> IngestSink for F
+where
+    F: Fn(&PushEvent) -> Result<(), SupplierError> + Send + Sync,
+{
+    fn ingest(&self, event: &PushEvent) -> Result<(), SupplierError> {
+        self(event)
+    }
+}
+
+impl IngestSink for Sender<PushEvent> {
+    fn ingest(&self, event: &PushEvent) -> Result<(), SupplierError> {
+        self.send(event.clone())
+            .map_err(|_| SupplierError::Internal("push event receiver was dropped".into()))
+    }
+}
+
+impl IngestSink for Vec<Arc<dyn IngestSink>> {
+    fn ingest(&self, event: &PushEvent) -> Result<(), SupplierError> {
+        self.iter().try_for_each(|sink| sink.ingest(event))
+    }
+}
+
+/// How many event IDs per supplier are remembered for dropping redeliveries.
+const REMEMBERED_EVENT_IDS: usize = 1024;
+
+#[derive(Default)]
+struct PushedRecords {
+    records: BTreeMap<String, Value>,
+    seen: HashSet<String>,
+    seen_order: VecDeque<String>,
+    last_push: Option<SystemTime>,
+}
+
+/// An ingest sink keeping the latest pushed version of every record, keyed per supplier.
+///
+/// Records are keyed by the value at `key_pointer`; string keys are used as is, other values as
+/// their JSON text. [`PushStore::supplier`] exposes the records of one supplier as a regular
+/// [`Supplier`], so push-only partners can sit in a group next to suppliers that are queried.
+/// Redelivered events with an already seen `event_id` are ignored.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use serde_json::json;
+/// use supplier_kit::models::{ResponseSource, SupplierOperation, SupplierRequest};
+/// use supplier_kit::push::{IngestSink, PushEvent, PushStore};
+/// use supplier_kit::supplier::Supplier;
+///
+/// let store = Arc::new(PushStore::new("/sku"));
+/// store.ingest(&PushEvent::new("partner", json!([{ "sku": "A1", "qty": 4 }]))).unwrap();
+///
+/// let partner = store.supplier("partner");
+/// let request = SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": "A1" }));
+/// let response = partner.query(request).unwrap();
+/// assert_eq!(response.data["qty"], 4);
+/// assert_eq!(response.source(), ResponseSource::Push);
+/// ```
+pub struct PushStore {
+    key_pointer: String,
+    suppliers: RwLock<HashMap<String, PushedRecords>>,
+}
+
+impl PushStore {
+    /// Creates an empty store keying records by the value at `key_pointer`.
+    pub fn new(key_pointer: &str) -> Self {
+        Self {
+            key_pointer: key_pointer.to_string(),
+            suppliers: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Returns a supplier named `name` serving the records `name` pushed.
+    pub fn supplier(self: &Arc<Self>, name: &str) -> PushedSupplier {
+        PushedSupplier {
+            name: name.to_string(),
+            store: self.clone(),
+        }
+    }
+
+    /// Returns the latest pushed version of record `key` from `supplier`.
+    pub fn get(&self, supplier: &str, key: &str) -> Option<Value> {
+        let suppliers = self.suppliers.read().unwrap_or_else(|e| e.into_inner());
+        suppliers.get(supplier)?.records.get(key).cloned()
+    }
+
+    /// Returns every record `supplier` pushed, sorted by key.
+    pub fn records(&self, supplier: &str) -> Vec<Value> {
+        let suppliers = self.suppliers.read().unwrap_or_else(|e| e.into_inner());
+        suppliers
+            .get(supplier)
+            .map(|s| s.records.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns when `supplier` last pushed an accepted event.
+    pub fn last_push(&self, supplier: &str) -> Option<SystemTime> {
+        let suppliers = self.suppliers.read().unwrap_or_else(|e| e.into_inner());
+        suppliers.get(supplier)?.last_push
+    }
+
+    fn key_of(&self, record: &Value) -> Result<String, SupplierError> {
+        match record.pointer(&self.key_pointer) {
+            None | Some(Value::Null) => Err(SupplierError::InvalidInput(format!(
+                "pushed record has no key at '{}'",
+                self.key_pointer
+            ))),
+            Some(Value::String(key)) => Ok(key.clone()),
+            Some(other) => Ok(other.to_string()),
+        }
+    }
+}
+
+impl IngestSink for PushStore {
+    fn ingest(&self, event: &PushEvent) -> Result<(), SupplierError> {
+        let records = match &event.data {
+            Value::Array(records) => records.iter().collect(),
+            record => vec![record],
+        };
+        // Validate every key first, so a bad record rejects the whole event.
+        let keyed = records
+            .into_iter()
+            .map(|record| self.key_of(record).map(|key| (key, record)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut suppliers = self.suppliers.write().unwrap_or_else(|e| e.into_inner());
+        let pushed = suppliers.entry(event.supplier.clone()).or_default();
+        if let Some(id) = &event.event_id {
+            if !pushed.seen.insert(id.clone()) {
+                return Ok(());
+            }
+            pushed.seen_order.push_back(id.clone());
+            if pushed.seen_order.len() > REMEMBERED_EVENT_IDS
+                && let Some(oldest) = pushed.seen_order.pop_front()
+            {
+                pushed.seen.remove(&oldest);
+            }
+        }
+        for (key, record) in keyed {
+            if event.operation == SupplierOperation::Delete {
+                pushed.records.remove(&key);
+            } else {
+                pushed.records.insert(key, record.clone());
+            }
+        }
+        pushed.last_push = Some(event.received_at);
+        Ok(())
+    }
+}
+
+/// A supplier answering from the records another supplier pushed into a [`PushStore`].
+///
+/// `Search` returns `{ "items": [...] }` with every record sorted by key. `GetDetail` returns
+/// the record whose key equals the `id` param, or `SupplierError::NotFound`. Other operations
+/// are unsupported. Responses are marked as `ResponseSource::Push`.
+pub struct PushedSupplier {
+    name: String,
+    store: Arc<PushStore>,
+}
+
+impl Supplier for PushedSupplier {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let data = match request.operation {
+            SupplierOperation::Search => json!({ "items": self.store.records(&self.name) }),
+            SupplierOperation::GetDetail => {
+                let key = match &request.params["id"] {
+                    Value::String(id) => id.clone(),
+                    Value::Null => return Err(SupplierError::InvalidInput("missing 'id' param".into())),
+                    other => other.to_string(),
+                };
+                self.store.get(&self.name, &key).ok_or(SupplierError::NotFound)?
+            }
+            other => {
+                return Err(SupplierError::UnsupportedOperation(format!(
+                    "pushed supplier '{}' does not support '{}'",
+                    self.name,
+                    other.as_str()
+                )))
+            }
+        };
+        Ok(SupplierResponse::new(data).with_source(ResponseSource::Push))
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+use serde_json::{json, Value};
+use crate::errors::SupplierError;
+use crate::models::SupplierOperation;
+use crate::push::{IngestSink, PushEvent};
+
+/// Builds an axum [`Router`] receiving supplier pushes over HTTP.
+///
+/// The router exposes `POST /push/{supplier}`, whose JSON body becomes the event data. The
+/// optional `X-Push-Operation` header sets the operation (e.g. `delete`) and `X-Event-Id` the
+/// delivery ID. Accepted events answer `202`; events the sink rejects as invalid answer `400`
+/// with the serialized `SupplierError`.
+///
+/// When tokens are configured with [`WebhookReceiver::with_token`], only those suppliers may
+/// push, and each must send `Authorization: Bearer <token>`; anything else answers `401`.
+/// Sinks are blocking, so they run on tokio's blocking thread pool.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use supplier_kit::push::PushStore;
+/// use supplier_kit::push::webhook::WebhookReceiver;
+///
+/// let store = Arc::new(PushStore::new("/sku"));
+/// let router: axum::Router = WebhookReceiver::new(store)
+///     .with_token("partner", "s3cret")
+///     .build();
+/// ```
+pub struct WebhookReceiver {
+    sink: Arc<dyn IngestSink>,
+    tokens: HashMap<String, String>,
+}
+
+impl WebhookReceiver {
+    /// Creates a receiver delivering every push to `sink`.
+    pub fn new(sink: Arc<dyn IngestSink>) -> Self {
+        Self {
+            sink,
+            tokens: HashMap::new(),
+        }
+    }
+
+    /// Allows `supplier` to push when it presents `token` as a bearer token.
+    pub fn with_token(mut self, supplier: &str, token: &str) -> Self {
+        self.tokens.insert(supplier.to_string(), token.to_string());
+        self
+    }
+
+    /// Builds the router.
+    pub fn build(self) -> Router {
+        Router::new()
+            .route("/push/{supplier}", post(receive))
+            .with_state(Arc::new(self))
+    }
+
+    fn authorize(&self, supplier: &str, headers: &HeaderMap) -> Result<(), SupplierError> {
+        if self.tokens.is_empty() {
+            return Ok(());
+        }
+        let presented = headers
+            .get("authorization")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "));
+        match (self.tokens.get(supplier), presented) {
+            (Some(expected), Some(presented)) if expected == presented => Ok(()),
+            _ => Err(SupplierError::Unauthorized),
+        }
+    }
+}
+
+async fn receive(
+    State(receiver): State<Arc<WebhookReceiver>>,
+    Path(supplier): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    if let Err(error) = receiver.authorize(&supplier, &headers) {
+        return error_response(StatusCode::UNAUTHORIZED, error);
+    }
+    let data: Value = match serde_json::from_slice(&body) {
+        Ok(data) => data,
+        Err(error) => {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                SupplierError::InvalidInput(format!("push body is not valid JSON: {}", error)),
+            )
+        }
+    };
+
+    let mut event = PushEvent::new(&supplier, data);
+    if let Some(operation) = header(&headers, "x-push-operation") {
+        event = event.with_operation(SupplierOperation::from(operation));
+    }
+    if let Some(id) = header(&headers, "x-event-id") {
+        event = event.with_event_id(id);
+    }
+
+    let sink = receiver.sink.clone();
+    match tokio::task::spawn_blocking(move || sink.ingest(&event)).await {
+        Ok(Ok(())) => (StatusCode::ACCEPTED, Json(json!({ "accepted": true }))).into_response(),
+        Ok(Err(error @ SupplierError::InvalidInput(_))) => error_response(StatusCode::BAD_REQUEST, error),
+        Ok(Err(error)) => error_response(StatusCode::INTERNAL_SERVER_ERROR, error),
+        Err(error) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            SupplierError::Internal(format!("push from '{}' failed: {}", supplier, error)),
+        ),
+    }
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+fn error_response(status: StatusCode, error: SupplierError) -> Response {
+    (status, Json(error)).into_response()
+}
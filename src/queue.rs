@@ -0,0 +1,248 @@
+use std::sync::Arc;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use crate::compression::{compress_stream, inflate, Compression};
+use crate::decorators::size_limit::too_large;
+use crate::errors::SupplierError;
+use crate::id::{IdGenerator, UuidV7Generator};
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+
+/// NATS request/reply transport (requires the `nats` feature).
+#[cfg(feature = "nats")]
+pub mod nats;
+
+/// A broker connection able to send a message and wait for its reply.
+///
+/// Implementations only move bytes; correlation and (de)serialization are handled by
+/// [`QueueSupplier`]. Brokers with built-in request/reply (NATS) map this directly, others
+/// (Kafka) publish to `subject` and wait on a reply topic for a message with the same correlation ID.
+pub trait RequestReplyTransport: Send + Sync {
+    /// Publishes `payload` to `subject` and returns the reply payload.
+    ///
+    /// Implementations must give up after `timeout` and return `SupplierError::Timeout`.
+    fn request(&self, subject: &str, payload: Vec<u8>, timeout: Duration) -> Result<Vec<u8>, SupplierError>;
+}
+
+/// The message published for each query.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QueueRequest {
+    /// Identifier the responder must copy into its reply.
+    pub correlation_id: String,
+
+    /// The supplier request.
+    pub request: SupplierRequest,
+}
+
+/// The reply a responder sends back for a [`QueueRequest`].
+///
+/// Exactly one of `response` and `error` is expected to be set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueueReply {
+    /// The correlation ID of the request being answered.
+    pub correlation_id: String,
+
+    /// The response, when the query succeeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<SupplierResponse>,
+
+    /// The error, when the query failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<SupplierError>,
+}
+
+impl QueueReply {
+    /// Builds the reply to the request with `correlation_id` from a query result.
+    pub fn from_result(correlation_id: &str, result: Result<SupplierResponse, SupplierError>) -> Self {
+        let (response, error) = match result {
+            Ok(response) => (Some(response), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self {
+            correlation_id: correlation_id.to_string(),
+            response,
+            error,
+        }
+    }
+
+    /// Converts the reply back into a query result.
+    pub fn into_result(self) -> Result<SupplierResponse, SupplierError> {
+        match (self.response, self.error) {
+            (_, Some(error)) => Err(error),
+            (Some(response), None) => Ok(response),
+            (None, None) => Err(SupplierError::Upstream("reply carries neither a response nor an error".into())),
+        }
+    }
+}
+
+/// Answers one serialized [`QueueRequest`] with `supplier`, for use on the responder side.
+///
+/// Returns the serialized [`QueueReply`], or an error if `payload` is not a valid request.
+///
+/// # Example
+/// ```
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::queue::{respond, QueueReply, QueueRequest};
+/// use supplier_kit::supplier::Supplier;
+///
+/// struct Echo;
+///
+/// impl Supplier for Echo {
+///     fn name(&self) -> &str { "echo" }
+///     fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         Ok(SupplierResponse::new(request.params))
+///     }
+/// }
+///
+/// let request = QueueRequest {
+///     correlation_id: "c-1".into(),
+///     request: SupplierRequest::new(SupplierOperation::Search, serde_json::json!({ "q": "lamp" })),
+/// };
+/// let reply = respond(&Echo, &serde_json::to_vec(&request).unwrap()).unwrap();
+/// let reply: QueueReply = serde_json::from_slice(&reply).unwrap();
+/// assert_eq!(reply.correlation_id, "c-1");
+/// assert_eq!(reply.into_result().unwrap().data["q"], "lamp");
+/// ```
+pub fn respond<S: Supplier + ?Sized>(supplier: &S, payload: &[u8]) -> Result<Vec<u8>, SupplierError> {
+    let request: QueueRequest = serde_json::from_slice(payload)
+        .map_err(|e| SupplierError::InvalidInput(format!("invalid queue request: {}", e)))?;
+    let result = crate::supplier::query_isolated(supplier, request.request);
+    let reply = QueueReply::from_result(&request.correlation_id, result);
+    serde_json::to_vec(&reply).map_err(|e| SupplierError::Internal(format!("failed to encode reply: {}", e)))
+}
+
+/// Like [`respond`], but compresses the reply as a standard stream (see
+/// [`compress_stream`]), for responders returning large payloads such as full catalogs.
+///
+/// `QueueSupplier` decompresses such replies transparently.
+pub fn respond_compressed<S: Supplier + ?Sized>(
+    supplier: &S,
+    payload: &[u8],
+    compression: Compression,
+) -> Result<Vec<u8>, SupplierError> {
+    compress_stream(&respond(supplier, payload)?, compression)
+}
+
+/// A supplier implemented by a remote service behind a message broker.
+///
+/// Each query is wrapped in a [`QueueRequest`] with a fresh correlation ID, serialized as JSON and
+/// published to the configured subject; the call then blocks until the correlated [`QueueReply`]
+/// arrives or the timeout expires. Replies carrying another correlation ID are rejected as
+/// `SupplierError::Upstream`, and errors returned by the remote supplier are passed through.
+///
+/// Replies compressed as gzip, zstd or LZ4 streams (see [`respond_compressed`]) are
+/// decompressed transparently when the matching feature is enabled.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::queue::{respond, QueueSupplier, RequestReplyTransport};
+/// use supplier_kit::supplier::Supplier;
+///
+/// struct Echo;
+///
+/// impl Supplier for Echo {
+///     fn name(&self) -> &str { "echo" }
+///     fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         Ok(SupplierResponse::new(request.params))
+///     }
+/// }
+///
+/// /// Answers in-process instead of going through a broker.
+/// struct Loopback;
+///
+/// impl RequestReplyTransport for Loopback {
+///     fn request(&self, _subject: &str, payload: Vec<u8>, _timeout: Duration) -> Result<Vec<u8>, SupplierError> {
+///         respond(&Echo, &payload)
+///     }
+/// }
+///
+/// let supplier = QueueSupplier::new("echo", Loopback, "suppliers.echo");
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({ "q": "lamp" }));
+/// assert_eq!(supplier.query(request).unwrap().data["q"], "lamp");
+/// ```
+pub struct QueueSupplier<T> {
+    name: String,
+    transport: T,
+    subject: String,
+    timeout: Duration,
+    id_generator: Arc<dyn IdGenerator>,
+    max_response_size: Option<usize>,
+}
+
+impl<T: RequestReplyTransport> QueueSupplier<T> {
+    /// Creates a supplier called `name` that publishes to `subject` with a 5 second reply timeout.
+    pub fn new(name: &str, transport: T, subject: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            transport,
+            subject: subject.to_string(),
+            timeout: Duration::from_secs(5),
+            id_generator: Arc::new(UuidV7Generator),
+            max_response_size: None,
+        }
+    }
+
+    /// Sets how long to wait for a reply.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Replaces the generator used for correlation IDs (UUIDv7 by default).
+    pub fn with_id_generator(mut self, generator: Arc<dyn IdGenerator>) -> Self {
+        self.id_generator = generator;
+        self
+    }
+
+    /// Fails replies larger than `bytes` with `SupplierError::ResponseTooLarge` before they are
+    /// decoded. The limit applies both to compressed replies and to their decompressed size.
+    pub fn with_max_response_size(mut self, bytes: usize) -> Self {
+        self.max_response_size = Some(bytes);
+        self
+    }
+
+    /// Returns the subject requests are published to.
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+}
+
+impl<T: RequestReplyTransport> Supplier for QueueSupplier<T> {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let correlation_id = self.id_generator.generate();
+        let message = QueueRequest {
+            correlation_id: correlation_id.clone(),
+            request,
+        };
+        let payload = serde_json::to_vec(&message)
+            .map_err(|e| SupplierError::Internal(format!("failed to encode queue request: {}", e)))?;
+
+        let reply = self.transport.request(&self.subject, payload, self.timeout)?;
+        if let Some(limit) = self.max_response_size
+            && reply.len() > limit
+        {
+            return Err(too_large(&self.name, limit));
+        }
+        let reply = inflate(&reply, self.max_response_size).map_err(|e| match (e, self.max_response_size) {
+            (SupplierError::ResponseTooLarge(_), Some(limit)) => too_large(&self.name, limit),
+            (e, _) => SupplierError::Upstream(format!("invalid reply on '{}': {}", self.subject, e)),
+        })?;
+        let reply: QueueReply = serde_json::from_slice(&reply)
+            .map_err(|e| SupplierError::Upstream(format!("invalid reply on '{}': {}", self.subject, e)))?;
+        if reply.correlation_id != correlation_id {
+            return Err(SupplierError::Upstream(format!(
+                "reply correlation ID '{}' does not match request '{}'",
+                reply.correlation_id, correlation_id
+            )));
+        }
+        reply.into_result()
+    }
+}
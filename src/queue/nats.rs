@@ -0,0 +1,64 @@
+use std::time::Duration;
+use async_nats::{Client, Request, RequestErrorKind};
+use tokio::runtime::{Builder, Runtime};
+use crate::errors::SupplierError;
+use crate::queue::RequestReplyTransport;
+
+/// A [`RequestReplyTransport`] using NATS request/reply.
+///
+/// NATS routes the reply to a private inbox, so every request gets exactly one correlated reply.
+/// Calls run on a private tokio runtime and block the calling thread.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use supplier_kit::queue::nats::NatsTransport;
+/// use supplier_kit::queue::QueueSupplier;
+///
+/// let transport = NatsTransport::connect("nats://127.0.0.1:4222").unwrap();
+/// let supplier = QueueSupplier::new("inventory", transport, "suppliers.inventory")
+///     .with_timeout(Duration::from_secs(2));
+/// ```
+pub struct NatsTransport {
+    client: Client,
+    runtime: Runtime,
+}
+
+impl NatsTransport {
+    /// Connects to the NATS server(s) at `addrs`, e.g. `nats://127.0.0.1:4222`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::Upstream` if the connection fails, or `SupplierError::Internal`
+    /// if the private runtime cannot be started.
+    pub fn connect(addrs: &str) -> Result<Self, SupplierError> {
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .map_err(|e| SupplierError::Internal(format!("failed to start NATS runtime: {}", e)))?;
+        let client = runtime
+            .block_on(async_nats::connect(addrs))
+            .map_err(|e| SupplierError::Upstream(format!("failed to connect to NATS at '{}': {}", addrs, e)))?;
+        Ok(Self { client, runtime })
+    }
+
+    /// Returns the underlying NATS client, e.g. to subscribe responders on the same connection.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+}
+
+impl RequestReplyTransport for NatsTransport {
+    fn request(&self, subject: &str, payload: Vec<u8>, timeout: Duration) -> Result<Vec<u8>, SupplierError> {
+        let request = Request::new().payload(payload.into()).timeout(Some(timeout));
+        let message = self
+            .runtime
+            .block_on(self.client.send_request(subject.to_string(), request))
+            .map_err(|e| match e.kind() {
+                RequestErrorKind::TimedOut => SupplierError::Timeout,
+                RequestErrorKind::NoResponders => SupplierError::Upstream(format!("no responders on '{}'", subject)),
+                _ => SupplierError::Upstream(format!("NATS request on '{}' failed: {}", subject, e)),
+            })?;
+        Ok(message.payload.to_vec())
+    }
+}
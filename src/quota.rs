@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+use std::time::SystemTime;
+use crate::errors::SupplierError;
+use crate::events::{EventBus, SupplierEvent};
+use crate::models::SupplierOperation;
+use crate::scheduler::Schedule;
+
+/// A call budget for one supplier, renewed every window.
+///
+/// Every call consumes the weight of its operation (`1.0` unless configured otherwise). Calls
+/// that would exceed the hard limit are rejected; reaching the soft limit publishes
+/// `SupplierEvent::QuotaSoftLimitReached` once per window.
+///
+/// # Example
+/// ```
+/// use supplier_kit::models::SupplierOperation;
+/// use supplier_kit::quota::Quota;
+///
+/// let quota = Quota::monthly()
+///     .with_hard_limit(100_000.0)
+///     .with_soft_limit(80_000.0)
+///     .with_weight(SupplierOperation::Create, 5.0);
+/// assert_eq!(quota.weight(&SupplierOperation::Search), 1.0);
+/// assert_eq!(quota.weight(&SupplierOperation::Create), 5.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Quota {
+    window: Schedule,
+    hard_limit: Option<f64>,
+    soft_limit: Option<f64>,
+    weights: HashMap<String, f64>,
+}
+
+impl Quota {
+    /// Creates an unlimited quota whose usage resets at every occurrence of `window`.
+    ///
+    /// With `Schedule::Every`, a window starts at the first call and lasts the interval; with
+    /// `Schedule::Cron`, windows end at the cron times, e.g. `0 0 1 * *` for calendar months.
+    pub fn new(window: Schedule) -> Self {
+        Self {
+            window,
+            hard_limit: None,
+            soft_limit: None,
+            weights: HashMap::new(),
+        }
+    }
+
+    /// Creates an unlimited quota resetting at midnight UTC on the first day of every month.
+    pub fn monthly() -> Self {
+        Self::new(Schedule::cron("0 0 1 * *").expect("valid cron expression"))
+    }
+
+    /// Rejects calls once the window's usage would exceed `limit`.
+    pub fn with_hard_limit(mut self, limit: f64) -> Self {
+        self.hard_limit = Some(limit);
+        self
+    }
+
+    /// Publishes an event once the window's usage reaches `limit`.
+    pub fn with_soft_limit(mut self, limit: f64) -> Self {
+        self.soft_limit = Some(limit);
+        self
+    }
+
+    /// Sets the cost weight of `operation`.
+    pub fn with_weight(mut self, operation: SupplierOperation, weight: f64) -> Self {
+        self.weights.insert(operation.as_str().to_string(), weight);
+        self
+    }
+
+    /// Returns the weight one call of `operation` consumes.
+    pub fn weight(&self, operation: &SupplierOperation) -> f64 {
+        self.weights.get(operation.as_str()).copied().unwrap_or(1.0)
+    }
+}
+
+/// A snapshot of a supplier's quota usage in the current window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuotaUsage {
+    /// The weight consumed in the current window.
+    pub used: f64,
+    /// When the current window started.
+    pub window_start: SystemTime,
+    /// When the current window ends, if the schedule has a next occurrence.
+    pub resets_at: Option<SystemTime>,
+    /// The hard limit, if any.
+    pub hard_limit: Option<f64>,
+    /// The soft limit, if any.
+    pub soft_limit: Option<f64>,
+}
+
+impl QuotaUsage {
+    /// Returns the weight left before the hard limit, or `None` without a hard limit.
+    pub fn remaining(&self) -> Option<f64> {
+        self.hard_limit.map(|limit| (limit - self.used).max(0.0))
+    }
+}
+
+struct QuotaState {
+    quota: Quota,
+    used: f64,
+    window_start: SystemTime,
+    resets_at: Option<SystemTime>,
+    soft_reported: bool,
+}
+
+impl QuotaState {
+    fn new(quota: Quota, now: SystemTime) -> Self {
+        let resets_at = quota.window.next_after(now);
+        Self {
+            quota,
+            used: 0.0,
+            window_start: now,
+            resets_at,
+            soft_reported: false,
+        }
+    }
+
+    /// Starts a new window if the current one has ended.
+    fn roll(&mut self, now: SystemTime) {
+        if self.resets_at.is_some_and(|end| now >= end) {
+            *self = QuotaState::new(self.quota.clone(), now);
+        }
+    }
+
+    fn usage(&self) -> QuotaUsage {
+        QuotaUsage {
+            used: self.used,
+            window_start: self.window_start,
+            resets_at: self.resets_at,
+            hard_limit: self.quota.hard_limit,
+            soft_limit: self.quota.soft_limit,
+        }
+    }
+}
+
+/// Tracks quota usage for many suppliers.
+///
+/// Share one tracker between the `QuotaSupplier`s of all suppliers with contracted volumes.
+/// Suppliers without a quota are never limited.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::SupplierOperation;
+/// use supplier_kit::quota::{Quota, QuotaTracker};
+/// use supplier_kit::scheduler::Schedule;
+///
+/// let tracker = QuotaTracker::new();
+/// tracker.set_quota("partner", Quota::new(Schedule::Every(Duration::from_secs(60))).with_hard_limit(2.0));
+///
+/// assert!(tracker.try_consume("partner", &SupplierOperation::Search).is_ok());
+/// assert!(tracker.try_consume("partner", &SupplierOperation::Search).is_ok());
+/// assert!(matches!(
+///     tracker.try_consume("partner", &SupplierOperation::Search),
+///     Err(SupplierError::RateLimited(_))
+/// ));
+/// assert_eq!(tracker.usage("partner").unwrap().remaining(), Some(0.0));
+/// ```
+#[derive(Default)]
+pub struct QuotaTracker {
+    states: Mutex<HashMap<String, QuotaState>>,
+    events: EventBus,
+}
+
+impl QuotaTracker {
+    /// Creates a tracker without quotas.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Publishes soft-limit events on `bus` instead of a private bus.
+    pub fn with_event_bus(mut self, bus: EventBus) -> Self {
+        self.events = bus;
+        self
+    }
+
+    /// Returns the bus soft-limit events are published on.
+    pub fn events(&self) -> &EventBus {
+        &self.events
+    }
+
+    /// Sets the quota of `supplier`, starting a new window with no usage.
+    pub fn set_quota(&self, supplier: &str, quota: Quota) {
+        self.lock()
+            .insert(supplier.to_string(), QuotaState::new(quota, SystemTime::now()));
+    }
+
+    /// Removes the quota of `supplier`, lifting its limits.
+    pub fn remove_quota(&self, supplier: &str) {
+        self.lock().remove(supplier);
+    }
+
+    /// Returns the usage of `supplier` in its current window, or `None` if it has no quota.
+    pub fn usage(&self, supplier: &str) -> Option<QuotaUsage> {
+        let mut states = self.lock();
+        let state = states.get_mut(supplier)?;
+        state.roll(SystemTime::now());
+        Some(state.usage())
+    }
+
+    /// Records one call of `operation` against the quota of `supplier`.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::RateLimited` without recording anything if the call would exceed
+    /// the hard limit of the current window.
+    pub fn try_consume(&self, supplier: &str, operation: &SupplierOperation) -> Result<(), SupplierError> {
+        let reached = {
+            let mut states = self.lock();
+            let Some(state) = states.get_mut(supplier) else {
+                return Ok(());
+            };
+            state.roll(SystemTime::now());
+
+            let used = state.used + state.quota.weight(operation);
+            if let Some(limit) = state.quota.hard_limit
+                && used > limit
+            {
+                return Err(SupplierError::RateLimited(format!(
+                    "quota of '{}' exhausted: {} of {} used in the current window",
+                    supplier, state.used, limit
+                )));
+            }
+            state.used = used;
+
+            match state.quota.soft_limit {
+                Some(limit) if used >= limit && !state.soft_reported => {
+                    state.soft_reported = true;
+                    Some((used, limit))
+                }
+                _ => None,
+            }
+        };
+
+        // Subscribers run outside the lock, so they may inspect the tracker.
+        if let Some((used, limit)) = reached {
+            self.events.publish(SupplierEvent::QuotaSoftLimitReached {
+                supplier: supplier.to_string(),
+                used,
+                limit,
+            });
+        }
+        Ok(())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, QuotaState>> {
+        self.states.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::supplier_group::SupplierGroupResult;
+
+/// An item taken from a supplier response, together with its ranking score.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RankedItem {
+    /// The supplier that returned the item.
+    pub supplier: String,
+
+    /// The item itself.
+    pub item: Value,
+
+    /// The score assigned by the ranker; higher scores rank first.
+    pub score: f64,
+}
+
+/// Scores items from aggregated supplier responses.
+///
+/// Any `Fn(&str, &Value) -> f64` closure taking the supplier name and the item is a `Ranker`.
+pub trait Ranker {
+    /// Returns the score of `item` returned by `supplier`. Higher is better.
+    fn score(&self, supplier: &str, item: &Value) -> f64;
+}
+
+impl<F> Ranker for F
+where
+    F: Fn(&str, &Value) -> f64,
+{
+    fn score(&self, supplier: &str, item: &Value) -> f64 {
+        self(supplier, item)
+    }
+}
+
+/// Ranks cheaper items first, reading the price from a JSON pointer into each item.
+///
+/// Items without a numeric price score `f64::NEG_INFINITY` and therefore rank last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriceRanker {
+    pointer: String,
+}
+
+impl PriceRanker {
+    /// Creates a ranker reading the price at `pointer`, e.g. `/price`.
+    pub fn new(pointer: &str) -> Self {
+        Self {
+            pointer: pointer.to_string(),
+        }
+    }
+}
+
+impl Ranker for PriceRanker {
+    fn score(&self, _supplier: &str, item: &Value) -> f64 {
+        item.pointer(&self.pointer)
+            .and_then(Value::as_f64)
+            .map_or(f64::NEG_INFINITY, |price| -price)
+    }
+}
+
+/// Scores items by a fixed weight of the supplier that returned them.
+///
+/// Suppliers without an explicit weight get the default weight of `1.0`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SupplierWeightRanker {
+    weights: HashMap<String, f64>,
+}
+
+impl SupplierWeightRanker {
+    /// Creates a ranker from supplier weights.
+    pub fn new(weights: HashMap<String, f64>) -> Self {
+        Self { weights }
+    }
+
+    /// Sets the weight of one supplier.
+    pub fn with_weight(mut self, supplier: &str, weight: f64) -> Self {
+        self.weights.insert(supplier.to_string(), weight);
+        self
+    }
+}
+
+impl Ranker for SupplierWeightRanker {
+    fn score(&self, supplier: &str, _item: &Value) -> f64 {
+        self.weights.get(supplier).copied().unwrap_or(1.0)
+    }
+}
+
+/// Combines several rankers into a weighted sum of their scores.
+#[derive(Default)]
+pub struct CompositeRanker {
+    rankers: Vec<(f64, Box<dyn Ranker + Send + Sync>)>,
+}
+
+impl CompositeRanker {
+    /// Creates an empty composite ranker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a ranker whose score is multiplied by `weight`.
+    pub fn with<R>(mut self, weight: f64, ranker: R) -> Self
+    where
+        R: Ranker + Send + Sync + 'static,
+    {
+        self.rankers.push((weight, Box::new(ranker)));
+        self
+    }
+}
+
+impl Ranker for CompositeRanker {
+    fn score(&self, supplier: &str, item: &Value) -> f64 {
+        self.rankers
+            .iter()
+            .map(|(weight, ranker)| weight * ranker.score(supplier, item))
+            .sum()
+    }
+}
+
+/// Merges the items of every successful response into a single list ordered by score.
+///
+/// Items are read from the JSON pointer `items_pointer` of each response's data. If it points
+/// to an array, each element is an item; any other value is treated as a single item.
+/// Ties keep the group's supplier order and each supplier's item order.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::models::SupplierResponse;
+/// use supplier_kit::ranking::{ranked_merge, PriceRanker};
+/// use supplier_kit::supplier_group::SupplierGroupResult;
+///
+/// let result = SupplierGroupResult {
+///     successes: vec![
+///         ("a".into(), SupplierResponse::new(json!({ "items": [{ "price": 12 }, { "price": 7 }] }))),
+///         ("b".into(), SupplierResponse::new(json!({ "items": [{ "price": 9 }] }))),
+///     ],
+///     failures: vec![],
+///     ..Default::default()
+/// };
+///
+/// let ranked = ranked_merge(&result, "/items", &PriceRanker::new("/price"));
+/// let prices: Vec<_> = ranked.iter().map(|r| r.item["price"].as_i64().unwrap()).collect();
+/// assert_eq!(prices, [7, 9, 12]);
+/// ```
+pub fn ranked_merge(result: &SupplierGroupResult, items_pointer: &str, ranker: &dyn Ranker) -> Vec<RankedItem> {
+    let mut ranked: Vec<RankedItem> = result
+        .successes
+        .iter()
+        .flat_map(|(supplier, response)| {
+            let items = match response.data.pointer(items_pointer) {
+                Some(Value::Array(items)) => items.clone(),
+                Some(item) => vec![item.clone()],
+                None => Vec::new(),
+            };
+            items.into_iter().map(move |item| RankedItem {
+                supplier: supplier.clone(),
+                score: ranker.score(supplier, &item),
+                item,
+            })
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+    ranked
+}
@@ -0,0 +1,304 @@
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::thread;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::consistency::{ConsistencyChecker, Divergence};
+use crate::errors::SupplierError;
+use crate::models::SupplierRequest;
+use crate::pagination::Paginator;
+use crate::supplier::Supplier;
+
+/// Statistics about the dataset pulled from one supplier.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatasetSummary {
+    /// The supplier name.
+    pub supplier: String,
+
+    /// The number of pages fetched.
+    pub pages: usize,
+
+    /// The number of records with a key, counting each duplicate key once.
+    pub records: usize,
+
+    /// Keys found on more than one record; only the first record of each key is compared.
+    pub duplicate_keys: Vec<String>,
+
+    /// The number of records from which no key could be extracted; they are not compared.
+    pub unkeyed: usize,
+}
+
+/// A record present on both sides whose contents differ.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordMismatch {
+    /// The record key.
+    pub key: String,
+
+    /// The differences, with the reference record on the left.
+    pub divergences: Vec<Divergence>,
+}
+
+/// How one supplier's dataset compares with the reference dataset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceComparison {
+    /// The supplier name.
+    pub supplier: String,
+
+    /// Keys present in the reference but not at this supplier, sorted.
+    pub missing: Vec<String>,
+
+    /// Keys present at this supplier but not in the reference, sorted.
+    pub extra: Vec<String>,
+
+    /// Records present on both sides with different contents, sorted by key.
+    pub mismatched: Vec<RecordMismatch>,
+}
+
+impl SourceComparison {
+    /// Returns whether the supplier holds exactly the reference records.
+    pub fn is_reconciled(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty() && self.mismatched.is_empty()
+    }
+}
+
+/// The outcome of a reconciliation run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconciliationReport {
+    /// The supplier holding the reference dataset (the system of record).
+    pub reference: String,
+
+    /// One summary per supplier whose dataset was fetched, the reference first.
+    pub datasets: Vec<DatasetSummary>,
+
+    /// One comparison per other supplier whose dataset was fetched, in source order.
+    pub comparisons: Vec<SourceComparison>,
+
+    /// The other suppliers whose dataset could not be fetched.
+    pub failures: Vec<(String, SupplierError)>,
+}
+
+impl ReconciliationReport {
+    /// Returns whether every source was fetched and matches the reference, without duplicate
+    /// or unkeyed records anywhere.
+    pub fn is_reconciled(&self) -> bool {
+        self.failures.is_empty()
+            && self.comparisons.iter().all(SourceComparison::is_reconciled)
+            && self.datasets.iter().all(|d| d.duplicate_keys.is_empty() && d.unkeyed == 0)
+    }
+
+    /// Returns the comparison for `supplier`.
+    pub fn comparison(&self, supplier: &str) -> Option<&SourceComparison> {
+        self.comparisons.iter().find(|c| c.supplier == supplier)
+    }
+}
+
+/// Pulls complete datasets from several suppliers, joins them by key and reports the records
+/// that are missing, extra or different compared with a reference supplier.
+///
+/// Every supplier is paged through with the configured [`Paginator`] and its records are read
+/// from the array at `items_pointer` of each page (default `/items`). A record's key is the
+/// value at `key_pointer` inside the record; string keys are used as is, other values as their
+/// JSON text. Records are compared with a [`ConsistencyChecker`], so tolerance rules such as
+/// ignoring `/updated_at` apply per record.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use serde_json::json;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::reconciliation::Reconciler;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let erp = MockSupplierBuilder::new("erp")
+///     .respond_default(json!({ "items": [{ "sku": "A", "qty": 3 }, { "sku": "B", "qty": 1 }] }))
+///     .build();
+/// let shop = MockSupplierBuilder::new("shop")
+///     .respond_default(json!({ "items": [{ "sku": "A", "qty": 2 }, { "sku": "C", "qty": 5 }] }))
+///     .build();
+///
+/// let request = SupplierRequest::new(SupplierOperation::Search, json!({}));
+/// let report = Reconciler::new("/sku").run(Arc::new(erp), &[Arc::new(shop)], request).unwrap();
+///
+/// let shop = report.comparison("shop").unwrap();
+/// assert_eq!(shop.missing, vec!["B"]);
+/// assert_eq!(shop.extra, vec!["C"]);
+/// assert_eq!(shop.mismatched[0].key, "A");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Reconciler {
+    key_pointer: String,
+    items_pointer: String,
+    paginator: Paginator,
+    checker: ConsistencyChecker,
+    max_pages: usize,
+}
+
+impl Reconciler {
+    /// Creates a reconciler keying records by the value at `key_pointer`, e.g. `/sku`.
+    pub fn new(key_pointer: &str) -> Self {
+        Self {
+            key_pointer: key_pointer.to_string(),
+            items_pointer: "/items".to_string(),
+            paginator: Paginator::default(),
+            checker: ConsistencyChecker::new(),
+            max_pages: 1000,
+        }
+    }
+
+    /// Sets where the record array is found in each page.
+    pub fn with_items_pointer(mut self, pointer: &str) -> Self {
+        self.items_pointer = pointer.to_string();
+        self
+    }
+
+    /// Sets how suppliers are paged through.
+    pub fn with_paginator(mut self, paginator: Paginator) -> Self {
+        self.paginator = paginator;
+        self
+    }
+
+    /// Sets the checker comparing records that share a key.
+    pub fn with_checker(mut self, checker: ConsistencyChecker) -> Self {
+        self.checker = checker;
+        self
+    }
+
+    /// Sets how many pages may be fetched from one supplier before the fetch fails (default 1000).
+    pub fn with_max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages;
+        self
+    }
+
+    /// Fetches the datasets of `reference` and `sources` concurrently and compares each source
+    /// with the reference.
+    ///
+    /// # Errors
+    /// Returns the reference supplier's error if its dataset cannot be fetched. Failures of
+    /// other sources are reported in [`ReconciliationReport::failures`].
+    pub fn run(
+        &self,
+        reference: Arc<dyn Supplier>,
+        sources: &[Arc<dyn Supplier>],
+        request: SupplierRequest,
+    ) -> Result<ReconciliationReport, SupplierError> {
+        let (reference_data, source_data) = thread::scope(|scope| {
+            let handles: Vec<_> = sources
+                .iter()
+                .map(|source| {
+                    let request = request.clone();
+                    scope.spawn(move || self.fetch(source.as_ref(), request))
+                })
+                .collect();
+            let reference_data = self.fetch(reference.as_ref(), request.clone());
+            let source_data: Vec<_> = handles
+                .into_iter()
+                .map(|h| h.join().unwrap_or_else(|_| Err(SupplierError::Internal("dataset fetch panicked".into()))))
+                .collect();
+            (reference_data, source_data)
+        });
+        let reference_data = reference_data?;
+
+        let mut report = ReconciliationReport {
+            reference: reference.name().to_string(),
+            datasets: vec![reference_data.summary.clone()],
+            comparisons: Vec::new(),
+            failures: Vec::new(),
+        };
+        for (source, data) in sources.iter().zip(source_data) {
+            match data {
+                Ok(data) => {
+                    report.comparisons.push(self.compare(&reference_data, &data));
+                    report.datasets.push(data.summary);
+                }
+                Err(error) => report.failures.push((source.name().to_string(), error)),
+            }
+        }
+        Ok(report)
+    }
+
+    fn fetch(&self, supplier: &dyn Supplier, request: SupplierRequest) -> Result<Dataset, SupplierError> {
+        let pages = self.paginator.fetch_all(supplier, request, self.max_pages)?;
+        let mut dataset = Dataset {
+            summary: DatasetSummary {
+                supplier: supplier.name().to_string(),
+                pages: pages.len(),
+                records: 0,
+                duplicate_keys: Vec::new(),
+                unkeyed: 0,
+            },
+            records: BTreeMap::new(),
+        };
+
+        for page in pages {
+            let items = match page.data.pointer(&self.items_pointer) {
+                Some(Value::Array(items)) => items.clone(),
+                Some(Value::Null) | None => Vec::new(),
+                Some(_) => {
+                    return Err(SupplierError::InvalidInput(format!(
+                        "'{}' in the response of supplier '{}' is not an array",
+                        self.items_pointer,
+                        supplier.name()
+                    )))
+                }
+            };
+            for item in items {
+                let key = match item.pointer(&self.key_pointer) {
+                    None | Some(Value::Null) => {
+                        dataset.summary.unkeyed += 1;
+                        continue;
+                    }
+                    Some(Value::String(key)) => key.clone(),
+                    Some(other) => other.to_string(),
+                };
+                match dataset.records.entry(key) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(item);
+                    }
+                    Entry::Occupied(entry) => {
+                        if !dataset.summary.duplicate_keys.contains(entry.key()) {
+                            dataset.summary.duplicate_keys.push(entry.key().clone());
+                        }
+                    }
+                }
+            }
+        }
+        dataset.summary.records = dataset.records.len();
+        dataset.summary.duplicate_keys.sort();
+        Ok(dataset)
+    }
+
+    fn compare(&self, reference: &Dataset, source: &Dataset) -> SourceComparison {
+        let mut comparison = SourceComparison {
+            supplier: source.summary.supplier.clone(),
+            missing: Vec::new(),
+            extra: source
+                .records
+                .keys()
+                .filter(|key| !reference.records.contains_key(*key))
+                .cloned()
+                .collect(),
+            mismatched: Vec::new(),
+        };
+        for (key, expected) in &reference.records {
+            match source.records.get(key) {
+                None => comparison.missing.push(key.clone()),
+                Some(actual) => {
+                    let divergences = self.checker.compare(expected, actual);
+                    if !divergences.is_empty() {
+                        comparison.mismatched.push(RecordMismatch {
+                            key: key.clone(),
+                            divergences,
+                        });
+                    }
+                }
+            }
+        }
+        comparison
+    }
+}
+
+struct Dataset {
+    summary: DatasetSummary,
+    records: BTreeMap<String, Value>,
+}
@@ -0,0 +1,122 @@
+use serde_json::Value;
+use crate::models::{SupplierRequest, SupplierResponse};
+
+/// Masks sensitive values in requests and responses before they are logged, audited or recorded.
+///
+/// Rules are dot-separated paths rooted at the serialized request or response, such as
+/// `params.credit_card` or `data.customer.email`. A `*` segment matches every key of an object
+/// or every element of an array, and a numeric segment also matches an array index, so
+/// `params.payments.*.card` masks the card of every payment. Matched values are replaced with
+/// the replacement value (`"[REDACTED]"` by default); paths that do not exist are ignored.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::redaction::Redactor;
+///
+/// let redactor = Redactor::new().with_rule("params.token").with_rule("params.cards.*.number");
+/// let request = SupplierRequest::new(
+///     SupplierOperation::Other("pay".into()),
+///     json!({ "token": "secret", "cards": [{ "number": "4111", "brand": "visa" }] }),
+/// );
+///
+/// let redacted = redactor.redact_request(&request);
+/// assert_eq!(redacted.params, json!({ "token": "[REDACTED]", "cards": [{ "number": "[REDACTED]", "brand": "visa" }] }));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Redactor {
+    rules: Vec<Vec<String>>,
+    replacement: Value,
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Self {
+            rules: Vec::new(),
+            replacement: Value::String("[REDACTED]".into()),
+        }
+    }
+}
+
+impl Redactor {
+    /// Creates a redactor without rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a path rule, e.g. `params.credit_card`.
+    pub fn with_rule(mut self, path: &str) -> Self {
+        self.rules.push(path.split('.').map(str::to_string).collect());
+        self
+    }
+
+    /// Sets the value matched fields are replaced with.
+    pub fn with_replacement(mut self, replacement: Value) -> Self {
+        self.replacement = replacement;
+        self
+    }
+
+    /// Returns whether the redactor has no rules.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Applies every rule to `value`, treating rule paths as rooted at `value` itself.
+    pub fn redact(&self, value: &Value) -> Value {
+        let mut value = value.clone();
+        for rule in &self.rules {
+            self.apply(&mut value, rule);
+        }
+        value
+    }
+
+    /// Applies the rules starting with `root` (e.g. `params`) to `value`, which holds that field.
+    pub fn redact_field(&self, root: &str, value: &Value) -> Value {
+        let mut value = value.clone();
+        for rule in &self.rules {
+            if let Some((first, rest)) = rule.split_first()
+                && (first == root || first == "*")
+            {
+                self.apply(&mut value, rest);
+            }
+        }
+        value
+    }
+
+    /// Returns a copy of the request with its parameters redacted (`params.*` rules).
+    pub fn redact_request(&self, request: &SupplierRequest) -> SupplierRequest {
+        let mut request = request.clone();
+        request.params = self.redact_field("params", &request.params);
+        request
+    }
+
+    /// Returns a copy of the response with its data redacted (`data.*` rules).
+    pub fn redact_response(&self, response: &SupplierResponse) -> SupplierResponse {
+        let mut response = response.clone();
+        response.data = self.redact_field("data", &response.data);
+        response
+    }
+
+    fn apply(&self, value: &mut Value, path: &[String]) {
+        let Some((segment, rest)) = path.split_first() else {
+            *value = self.replacement.clone();
+            return;
+        };
+        match value {
+            Value::Object(map) if segment == "*" => map.values_mut().for_each(|v| self.apply(v, rest)),
+            Value::Object(map) => {
+                if let Some(child) = map.get_mut(segment) {
+                    self.apply(child, rest);
+                }
+            }
+            Value::Array(items) if segment == "*" => items.iter_mut().for_each(|v| self.apply(v, rest)),
+            Value::Array(items) => {
+                if let Some(child) = segment.parse::<usize>().ok().and_then(|i| items.get_mut(i)) {
+                    self.apply(child, rest);
+                }
+            }
+            _ => {}
+        }
+    }
+}
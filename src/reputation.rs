@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use serde_json::Value;
+use crate::ranking::Ranker;
+
+/// The observed track record of a single supplier.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SupplierReputation {
+    /// Exponentially weighted success rate, between `0.0` and `1.0`.
+    pub success_rate: f64,
+
+    /// Exponentially weighted latency.
+    pub latency: Duration,
+
+    /// Number of outcomes recorded.
+    pub samples: u64,
+
+    /// The manually configured base weight.
+    pub base_weight: f64,
+}
+
+impl SupplierReputation {
+    fn new(base_weight: f64) -> Self {
+        Self {
+            success_rate: 1.0,
+            latency: Duration::ZERO,
+            samples: 0,
+            base_weight,
+        }
+    }
+}
+
+/// Tracks per-supplier weights that combine a manual base weight with observed behavior.
+///
+/// Every recorded outcome updates an exponentially weighted moving average of the supplier's
+/// success rate and latency. The effective weight is
+/// `base_weight × success_rate × latency_score`, where the latency score is `1.0` up to the
+/// reference latency and decreases proportionally above it.
+///
+/// A tracker can be shared between groups (see `BasicSupplierGroup::with_reputation`), whose
+/// `QueryStrategy::Adaptive` then prefers suppliers of higher weight. With
+/// [`with_ejection`](Self::with_ejection), suppliers whose weight drops too low are also
+/// ejected from the groups' fan-out for a probation period.
+/// The tracker is itself a [`Ranker`] scoring items by their supplier's current weight, and its
+/// weights can be snapshotted into a `ranking::SupplierWeightRanker`.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::reputation::ReputationTracker;
+///
+/// let tracker = ReputationTracker::new(Duration::from_millis(200));
+/// tracker.set_base_weight("premium", 2.0);
+/// tracker.record("premium", true, Duration::from_millis(50));
+/// tracker.record("flaky", false, Duration::from_millis(50));
+///
+/// assert_eq!(tracker.weight("premium"), 2.0);
+/// assert!(tracker.weight("flaky") < 1.0);
+/// assert_eq!(tracker.weight("unknown"), 1.0);
+/// ```
+#[derive(Debug)]
+pub struct ReputationTracker {
+    reference_latency: Duration,
+    smoothing: f64,
+    suppliers: Mutex<HashMap<String, SupplierReputation>>,
+    ejection: Option<Ejection>,
+    ejected_until: Mutex<HashMap<String, Instant>>,
+}
+
+/// When a `ReputationTracker` ejects suppliers.
+#[derive(Debug, Clone, Copy)]
+struct Ejection {
+    min_weight: f64,
+    min_samples: u64,
+    probation: Duration,
+}
+
+impl Default for ReputationTracker {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1))
+    }
+}
+
+impl ReputationTracker {
+    /// Creates a tracker where latencies up to `reference_latency` are not penalized.
+    pub fn new(reference_latency: Duration) -> Self {
+        Self {
+            reference_latency,
+            smoothing: 0.2,
+            suppliers: Mutex::new(HashMap::new()),
+            ejection: None,
+            ejected_until: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Ejects suppliers whose weight falls below `min_weight` after at least `min_samples`
+    /// outcomes, for the duration of `probation`. Re-admitted suppliers start over with a
+    /// fresh track record and their base weight.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use supplier_kit::reputation::ReputationTracker;
+    ///
+    /// let tracker = ReputationTracker::default().with_ejection(0.5, 2, Duration::from_secs(30));
+    /// tracker.record("flaky", false, Duration::ZERO);
+    /// assert!(!tracker.is_ejected("flaky"));
+    /// tracker.record("flaky", false, Duration::ZERO);
+    /// assert!(tracker.is_ejected("flaky"));
+    /// ```
+    pub fn with_ejection(mut self, min_weight: f64, min_samples: u64, probation: Duration) -> Self {
+        self.ejection = Some(Ejection {
+            min_weight,
+            min_samples: min_samples.max(1),
+            probation,
+        });
+        self
+    }
+
+    /// Sets how strongly new outcomes move the averages, between `0.0` (never) and `1.0` (only the latest counts).
+    pub fn with_smoothing(mut self, smoothing: f64) -> Self {
+        self.smoothing = smoothing.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the manual base weight of a supplier.
+    pub fn set_base_weight(&self, supplier: &str, weight: f64) {
+        self.lock()
+            .entry(supplier.to_string())
+            .or_insert_with(|| SupplierReputation::new(1.0))
+            .base_weight = weight.max(0.0);
+    }
+
+    /// Records the outcome of one query, ejecting the supplier if its weight fell too low.
+    ///
+    /// Outcomes of ejected suppliers are ignored until their probation ends.
+    pub fn record(&self, supplier: &str, success: bool, latency: Duration) {
+        if self.is_ejected(supplier) {
+            return;
+        }
+        let alpha = self.smoothing;
+        let mut suppliers = self.lock();
+        let entry = suppliers
+            .entry(supplier.to_string())
+            .or_insert_with(|| SupplierReputation::new(1.0));
+
+        let outcome = if success { 1.0 } else { 0.0 };
+        if entry.samples == 0 {
+            entry.success_rate = outcome;
+            entry.latency = latency;
+        } else {
+            entry.success_rate = alpha * outcome + (1.0 - alpha) * entry.success_rate;
+            entry.latency = latency.mul_f64(alpha) + entry.latency.mul_f64(1.0 - alpha);
+        }
+        entry.samples += 1;
+
+        if let Some(ejection) = self.ejection
+            && entry.samples >= ejection.min_samples
+            && self.weight_of(entry) < ejection.min_weight
+        {
+            *entry = SupplierReputation::new(entry.base_weight);
+            self.ejected_until
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(supplier.to_string(), Instant::now() + ejection.probation);
+        }
+    }
+
+    /// Returns whether the supplier is currently ejected, re-admitting it once its probation has ended.
+    pub fn is_ejected(&self, supplier: &str) -> bool {
+        let mut ejected = self.ejected_until.lock().unwrap_or_else(|e| e.into_inner());
+        match ejected.get(supplier) {
+            Some(until) if Instant::now() < *until => true,
+            Some(_) => {
+                ejected.remove(supplier);
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the names of all currently ejected suppliers, sorted.
+    pub fn ejected(&self) -> Vec<String> {
+        let now = Instant::now();
+        let mut names: Vec<String> = self
+            .ejected_until
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter(|(_, until)| now < **until)
+            .map(|(name, _)| name.clone())
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Returns the track record of a supplier, if anything was recorded or configured for it.
+    pub fn reputation(&self, supplier: &str) -> Option<SupplierReputation> {
+        self.lock().get(supplier).copied()
+    }
+
+    /// Returns the effective weight of a supplier. Unknown suppliers have a weight of `1.0`.
+    pub fn weight(&self, supplier: &str) -> f64 {
+        self.reputation(supplier).map_or(1.0, |r| self.weight_of(&r))
+    }
+
+    /// Returns the effective weight of every known supplier.
+    pub fn weights(&self) -> HashMap<String, f64> {
+        self.lock()
+            .iter()
+            .map(|(name, r)| (name.clone(), self.weight_of(r)))
+            .collect()
+    }
+
+    fn weight_of(&self, reputation: &SupplierReputation) -> f64 {
+        let latency_score = if reputation.latency <= self.reference_latency || reputation.latency.is_zero() {
+            1.0
+        } else {
+            self.reference_latency.as_secs_f64() / reputation.latency.as_secs_f64()
+        };
+        reputation.base_weight * reputation.success_rate * latency_score
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, SupplierReputation>> {
+        self.suppliers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Ranker for ReputationTracker {
+    fn score(&self, supplier: &str, _item: &Value) -> f64 {
+        self.weight(supplier)
+    }
+}
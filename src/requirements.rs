@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+use crate::errors::SupplierError;
+use crate::models::SupplierOperation;
+
+/// Requires that at least `min` of the named suppliers are available to a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupplierRequirement {
+    /// The suppliers that can satisfy the requirement.
+    pub suppliers: Vec<String>,
+
+    /// How many of them must be available.
+    pub min: usize,
+}
+
+impl SupplierRequirement {
+    /// Requires at least one of `suppliers`.
+    pub fn any_of(suppliers: &[&str]) -> Self {
+        Self::at_least(1, suppliers)
+    }
+
+    /// Requires every one of `suppliers`.
+    pub fn all_of(suppliers: &[&str]) -> Self {
+        Self::at_least(suppliers.len(), suppliers)
+    }
+
+    /// Requires at least `min` of `suppliers`.
+    pub fn at_least(min: usize, suppliers: &[&str]) -> Self {
+        Self {
+            suppliers: suppliers.iter().map(|s| s.to_string()).collect(),
+            min,
+        }
+    }
+}
+
+/// The suppliers a group must be able to reach, per operation, before it fans out a query.
+///
+/// A group whose members are ejected, unhealthy or cold still answers with an empty list of
+/// successes, which callers easily mistake for "no results". With requirements attached
+/// (see `BasicSupplierGroup::with_requirements`), a query that cannot reach enough of the
+/// required suppliers fails fast with `SupplierError::InsufficientSuppliers` instead, without
+/// querying any supplier. Operations are compared after normalization.
+///
+/// # Example
+/// ```
+/// use supplier_kit::models::SupplierOperation;
+/// use supplier_kit::requirements::{SupplierRequirement, SupplierRequirements};
+///
+/// let requirements = SupplierRequirements::new()
+///     .with_requirement(SupplierOperation::Search, SupplierRequirement::any_of(&["amazon", "ebay"]));
+///
+/// let ebay_ejected = |name: &str| (name == "ebay").then(|| "ejected as an outlier".to_string());
+/// assert!(requirements.check(&SupplierOperation::Search, ebay_ejected).is_ok());
+///
+/// let all_ejected = |_: &str| Some("ejected as an outlier".to_string());
+/// let error = requirements.check(&SupplierOperation::Search, all_ejected).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "insufficient suppliers: search requires 1 of amazon, ebay; amazon: ejected as an outlier, ebay: ejected as an outlier"
+/// );
+/// assert!(requirements.check(&SupplierOperation::Create, all_ejected).is_ok());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SupplierRequirements {
+    requirements: BTreeMap<String, Vec<SupplierRequirement>>,
+}
+
+impl SupplierRequirements {
+    /// Creates requirements that every query meets.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `requirement` for queries of `operation`; all requirements of an operation must be met.
+    pub fn with_requirement(mut self, operation: SupplierOperation, requirement: SupplierRequirement) -> Self {
+        self.requirements.entry(Self::key(operation)).or_default().push(requirement);
+        self
+    }
+
+    /// Returns the requirements of `operation`, in the order they were added.
+    pub fn requirements(&self, operation: &SupplierOperation) -> &[SupplierRequirement] {
+        self.requirements.get(&Self::key(operation.clone())).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the suppliers named by the requirements of `operation`, sorted and deduplicated.
+    pub fn required_suppliers(&self, operation: &SupplierOperation) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .requirements(operation)
+            .iter()
+            .flat_map(|requirement| requirement.suppliers.iter().cloned())
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Checks the requirements of `operation`, given why each unavailable supplier is unavailable.
+    ///
+    /// `unavailable` returns `None` for suppliers a query would reach.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InsufficientSuppliers` naming the first unmet requirement and why
+    /// each of its suppliers is unavailable.
+    pub fn check<F>(&self, operation: &SupplierOperation, unavailable: F) -> Result<(), SupplierError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        for requirement in self.requirements(operation) {
+            let reasons: Vec<(&str, Option<String>)> =
+                requirement.suppliers.iter().map(|name| (name.as_str(), unavailable(name))).collect();
+            let available = reasons.iter().filter(|(_, reason)| reason.is_none()).count();
+            if available < requirement.min {
+                let unavailable: Vec<String> = reasons
+                    .into_iter()
+                    .filter_map(|(name, reason)| reason.map(|reason| format!("{}: {}", name, reason)))
+                    .collect();
+                return Err(SupplierError::InsufficientSuppliers(format!(
+                    "{} requires {} of {}; {}",
+                    operation.as_str(),
+                    requirement.min,
+                    requirement.suppliers.join(", "),
+                    unavailable.join(", ")
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn key(operation: SupplierOperation) -> String {
+        operation.normalize().as_str().to_string()
+    }
+}
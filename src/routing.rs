@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::errors::SupplierError;
+use crate::json_path::JsonPath;
+use crate::models::{SupplierOperation, SupplierRequest};
+
+/// A test applied to the values found at a [`Condition`]'s path.
+///
+/// When the path selects several values, e.g. through a wildcard, `Equals`, `In` and
+/// `Exists(true)` hold if any of them satisfies the test, while `NotEquals` and
+/// `Exists(false)` hold if none does.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Test {
+    /// The value equals the given value.
+    Equals(Value),
+    /// The value is missing or differs from the given value.
+    NotEquals(Value),
+    /// The value equals one of the given values.
+    In(Vec<Value>),
+    /// The value is present (`true`) or missing (`false`); `null` counts as present.
+    Exists(bool),
+}
+
+/// A condition on the params of a request.
+///
+/// The path is either a JSONPath, such as `$.filters.category`, `$.items[0]['sku']` or
+/// `$.items[*].sku`, or a JSON pointer such as `/filters/category`; see
+/// [`JsonPath`](crate::json_path::JsonPath) for the supported syntax.
+///
+/// In a manifest the test is written next to the path:
+/// ```json
+/// { "path": "$.category", "in": ["books", "ebooks"] }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Condition {
+    /// Where in the params to look.
+    pub path: String,
+
+    /// What the value found there must satisfy.
+    #[serde(flatten)]
+    pub test: Test,
+}
+
+impl Condition {
+    /// Creates a condition applying `test` to the value at `path`.
+    pub fn new(path: &str, test: Test) -> Self {
+        Self {
+            path: path.to_string(),
+            test,
+        }
+    }
+
+    /// Returns whether `params` satisfy the condition; an invalid path never matches.
+    pub fn matches(&self, params: &Value) -> bool {
+        let Ok(path) = JsonPath::parse(&self.path) else {
+            return false;
+        };
+        let values = path.find(params);
+        match &self.test {
+            Test::Equals(expected) => values.contains(&expected),
+            Test::NotEquals(expected) => !values.contains(&expected),
+            Test::In(expected) => values.iter().any(|value| expected.contains(value)),
+            Test::Exists(exists) => values.is_empty() != *exists,
+        }
+    }
+}
+
+/// Sends the requests matching its operations and conditions to a subset of a group.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoutingRule {
+    /// The operations the rule applies to; empty applies it to every operation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub operations: Vec<SupplierOperation>,
+
+    /// Conditions on the params, all of which must hold.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<Condition>,
+
+    /// Names of the group members receiving the matching requests.
+    pub suppliers: Vec<String>,
+}
+
+impl RoutingRule {
+    /// Creates a rule routing every request to `suppliers`; narrow it with
+    /// [`RoutingRule::for_operation`] and [`RoutingRule::when`].
+    pub fn to(suppliers: &[&str]) -> Self {
+        Self {
+            operations: Vec::new(),
+            conditions: Vec::new(),
+            suppliers: suppliers.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Restricts the rule to `operation`, in addition to any operation added before.
+    pub fn for_operation(mut self, operation: SupplierOperation) -> Self {
+        self.operations.push(operation.normalize());
+        self
+    }
+
+    /// Adds a condition on the params.
+    pub fn when(mut self, path: &str, test: Test) -> Self {
+        self.conditions.push(Condition::new(path, test));
+        self
+    }
+
+    /// Returns whether the rule applies to `request`.
+    pub fn matches(&self, request: &SupplierRequest) -> bool {
+        let operation = request.operation.clone().normalize();
+        (self.operations.is_empty() || self.operations.iter().any(|o| o.clone().normalize() == operation))
+            && self.conditions.iter().all(|c| c.matches(&request.params))
+    }
+
+    /// Checks that every condition path parses.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` naming the first invalid path.
+    pub fn validate(&self) -> Result<(), SupplierError> {
+        self.conditions.iter().try_for_each(|c| JsonPath::parse(&c.path).map(|_| ()))
+    }
+}
+
+/// Content-based routing for a group: the first matching rule picks the suppliers that
+/// receive a request.
+///
+/// Requests no rule matches go to every supplier of the group, so rules only need to cover
+/// the traffic that must be narrowed. Rules are usually declared in the group's `routes` in a
+/// manifest (see `config::GroupSpec`) and installed with `BasicSupplierGroup::with_routing`.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::routing::{RoutingRule, RoutingRules, Test};
+///
+/// let rules = RoutingRules::new(vec![
+///     RoutingRule::to(&["book_depot"])
+///         .for_operation(SupplierOperation::Search)
+///         .when("$.category", Test::Equals(json!("books"))),
+/// ]);
+///
+/// let books = SupplierRequest::new(SupplierOperation::Search, json!({ "category": "books" }));
+/// assert!(rules.routes("book_depot", &books));
+/// assert!(!rules.routes("gadget_hub", &books));
+///
+/// let lamps = SupplierRequest::new(SupplierOperation::Search, json!({ "category": "lamps" }));
+/// assert!(rules.routes("gadget_hub", &lamps));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RoutingRules {
+    rules: Vec<RoutingRule>,
+}
+
+impl RoutingRules {
+    /// Creates routing from rules tried in order.
+    pub fn new(rules: Vec<RoutingRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Returns the rules, in the order they are tried.
+    pub fn rules(&self) -> &[RoutingRule] {
+        &self.rules
+    }
+
+    /// Returns the first rule matching `request`, if any.
+    pub fn select(&self, request: &SupplierRequest) -> Option<&RoutingRule> {
+        self.rules.iter().find(|rule| rule.matches(request))
+    }
+
+    /// Returns whether supplier `name` receives `request`.
+    pub fn routes(&self, name: &str, request: &SupplierRequest) -> bool {
+        self.select(request).is_none_or(|rule| rule.suppliers.iter().any(|s| s == name))
+    }
+}
@@ -0,0 +1,438 @@
+use std::collections::HashMap;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::{query_isolated, Supplier};
+use crate::supplier_group::{SupplierGroup, SupplierGroupResult};
+
+/// When a scheduled job runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Schedule {
+    /// Runs repeatedly with a fixed delay between the start of consecutive runs.
+    Every(Duration),
+    /// Runs at the times matching a cron expression, evaluated in UTC.
+    Cron(CronExpr),
+}
+
+impl Schedule {
+    /// Parses a five-field cron expression, see [`CronExpr::parse`].
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the expression is malformed.
+    pub fn cron(expression: &str) -> Result<Self, SupplierError> {
+        CronExpr::parse(expression).map(Schedule::Cron)
+    }
+
+    /// Returns the first run time strictly after `after`, or `None` if there is none.
+    pub fn next_after(&self, after: SystemTime) -> Option<SystemTime> {
+        match self {
+            Schedule::Every(interval) => after.checked_add(*interval),
+            Schedule::Cron(cron) => cron.next_after(after),
+        }
+    }
+}
+
+/// A standard five-field cron expression: minute, hour, day of month, month and day of week.
+///
+/// Every field accepts `*`, single values, ranges (`1-5`), lists (`1,15`) and steps (`*/10`,
+/// `0-30/5`). Days of week run from `0` (Sunday) to `6`, with `7` also meaning Sunday. As in
+/// classic cron, when both the day of month and the day of week are restricted, a day matching
+/// either one runs the job.
+///
+/// # Example
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use supplier_kit::scheduler::CronExpr;
+///
+/// let cron = CronExpr::parse("*/15 6 * * 1-5").unwrap();
+/// // 1970-01-01T00:00:00Z was a Thursday, so the first run is that day at 06:00.
+/// let next = cron.next_after(UNIX_EPOCH).unwrap();
+/// assert_eq!(next, UNIX_EPOCH + Duration::from_secs(6 * 3600));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronExpr {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl CronExpr {
+    /// Parses a cron expression.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InvalidInput` if the expression does not have five fields or a
+    /// field holds an unparseable or out-of-range value.
+    pub fn parse(expression: &str) -> Result<Self, SupplierError> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+            return Err(SupplierError::InvalidInput(format!(
+                "cron expression '{}' must have 5 fields, found {}",
+                expression,
+                fields.len()
+            )));
+        };
+
+        let mut days_of_week = parse_field(day_of_week, 0, 7, "day of week")?;
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+        Ok(Self {
+            minutes: parse_field(minute, 0, 59, "minute")?,
+            hours: parse_field(hour, 0, 23, "hour")?,
+            days_of_month: parse_field(day_of_month, 1, 31, "day of month")?,
+            months: parse_field(month, 1, 12, "month")?,
+            days_of_week,
+            day_of_month_restricted: day_of_month != "*",
+            day_of_week_restricted: day_of_week != "*",
+        })
+    }
+
+    /// Returns the first matching minute strictly after `after`, searching up to five years ahead.
+    pub fn next_after(&self, after: SystemTime) -> Option<SystemTime> {
+        let seconds = after.duration_since(UNIX_EPOCH).ok()?.as_secs();
+        let first_minute = seconds / 60 + 1;
+        let first_day = first_minute / 1440;
+
+        for day in first_day..first_day + 5 * 366 {
+            if !self.matches_day(day) {
+                continue;
+            }
+            let start = if day == first_day { first_minute % 1440 } else { 0 };
+            for minute_of_day in start..1440 {
+                let (hour, minute) = (minute_of_day / 60, minute_of_day % 60);
+                if self.hours & (1 << hour) != 0 && self.minutes & (1 << minute) != 0 {
+                    return Some(UNIX_EPOCH + Duration::from_secs((day * 1440 + minute_of_day) * 60));
+                }
+            }
+        }
+        None
+    }
+
+    fn matches_day(&self, days_since_epoch: u64) -> bool {
+        let (_, month, day) = civil_from_days(days_since_epoch);
+        if self.months & (1 << month) == 0 {
+            return false;
+        }
+        let weekday = (days_since_epoch + 4) % 7;
+        let by_month = self.days_of_month & (1 << day) != 0;
+        let by_week = self.days_of_week & (1 << weekday) != 0;
+        match (self.day_of_month_restricted, self.day_of_week_restricted) {
+            (true, true) => by_month || by_week,
+            _ => by_month && by_week,
+        }
+    }
+}
+
+/// Parses one cron field into a bit set of the allowed values.
+fn parse_field(field: &str, min: u64, max: u64, label: &str) -> Result<u64, SupplierError> {
+    let invalid = || SupplierError::InvalidInput(format!("invalid cron {} field '{}'", label, field));
+    let mut bits = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u64>().map_err(|_| invalid())?),
+            None => (part, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((a, b)) => (a.parse().map_err(|_| invalid())?, b.parse().map_err(|_| invalid())?),
+                None => {
+                    let value = range.parse().map_err(|_| invalid())?;
+                    (value, if part.contains('/') { max } else { value })
+                }
+            },
+        };
+        if step == 0 || start < min || end > max || start > end {
+            return Err(invalid());
+        }
+        for value in (start..=end).step_by(step as usize) {
+            bits |= 1 << value;
+        }
+    }
+    Ok(bits)
+}
+
+/// Converts days since 1970-01-01 to a (year, month, day) civil date.
+fn civil_from_days(days: u64) -> (i64, u64, u64) {
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u64;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u64;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// The outcome of one scheduled run.
+#[derive(Debug, Clone)]
+pub enum ScheduledOutcome {
+    /// The result of querying a single supplier.
+    Supplier(Result<SupplierResponse, SupplierError>),
+    /// The result of querying a group.
+    Group(SupplierGroupResult),
+}
+
+/// One scheduled run delivered to the job's sinks.
+#[derive(Debug, Clone)]
+pub struct ScheduledResult {
+    /// The job name.
+    pub job: String,
+
+    /// When the run started.
+    pub started_at: SystemTime,
+
+    /// What the supplier or group returned.
+    pub outcome: ScheduledOutcome,
+}
+
+/// Receives the results of scheduled runs.
+///
+/// Implemented for closures, for `mpsc::Sender<ScheduledResult>` and for [`LatestResults`].
+/// Sinks are called on the job's thread, so a slow sink delays the job's next run.
+pub trait ResultSink: Send + Sync {
+    /// Handles the result of one run.
+    fn deliver(&self, result: &ScheduledResult);
+}
+
+impl<F> ResultSink for F
+where
+    F: Fn(&ScheduledResult) + Send + Sync,
+{
+    fn deliver(&self, result: &ScheduledResult) {
+        self(result)
+    }
+}
+
+impl ResultSink for Sender<ScheduledResult> {
+    fn deliver(&self, result: &ScheduledResult) {
+        let _ = self.send(result.clone());
+    }
+}
+
+/// A sink caching the latest result of every job, e.g. for serving a refreshed catalog.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::scheduler::{LatestResults, Schedule, ScheduledJob, Scheduler};
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let catalog = MockSupplierBuilder::new("catalog")
+///     .respond_default(serde_json::json!({ "items": [1, 2, 3] }))
+///     .build();
+/// let latest = Arc::new(LatestResults::new());
+///
+/// let handle = Scheduler::new()
+///     .job(
+///         ScheduledJob::supplier(
+///             "refresh_catalog",
+///             Arc::new(catalog),
+///             SupplierRequest::new(SupplierOperation::Search, serde_json::json!({})),
+///             Schedule::Every(Duration::from_secs(3600)),
+///         )
+///         .run_on_start()
+///         .sink(latest.clone()),
+///     )
+///     .start();
+///
+/// while latest.get("refresh_catalog").is_none() {
+///     std::thread::sleep(Duration::from_millis(5));
+/// }
+/// handle.stop();
+/// ```
+#[derive(Debug, Default)]
+pub struct LatestResults {
+    results: RwLock<HashMap<String, ScheduledResult>>,
+}
+
+impl LatestResults {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the latest result of `job`.
+    pub fn get(&self, job: &str) -> Option<ScheduledResult> {
+        self.results.read().unwrap_or_else(|e| e.into_inner()).get(job).cloned()
+    }
+}
+
+impl ResultSink for LatestResults {
+    fn deliver(&self, result: &ScheduledResult) {
+        self.results
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(result.job.clone(), result.clone());
+    }
+}
+
+/// A shared group that scheduled jobs can query from their own thread.
+pub type SharedGroup = Arc<dyn SupplierGroup + Send + Sync>;
+
+enum Target {
+    Supplier(Arc<dyn Supplier>),
+    Group(SharedGroup),
+}
+
+/// A query run against a supplier or group on a schedule.
+pub struct ScheduledJob {
+    name: String,
+    target: Target,
+    request: SupplierRequest,
+    schedule: Schedule,
+    run_on_start: bool,
+    sinks: Vec<Arc<dyn ResultSink>>,
+}
+
+impl ScheduledJob {
+    /// Creates a job querying `supplier` with `request`.
+    pub fn supplier(name: &str, supplier: Arc<dyn Supplier>, request: SupplierRequest, schedule: Schedule) -> Self {
+        Self::new(name, Target::Supplier(supplier), request, schedule)
+    }
+
+    /// Creates a job querying `group` with `request`.
+    pub fn group(name: &str, group: SharedGroup, request: SupplierRequest, schedule: Schedule) -> Self {
+        Self::new(name, Target::Group(group), request, schedule)
+    }
+
+    fn new(name: &str, target: Target, request: SupplierRequest, schedule: Schedule) -> Self {
+        Self {
+            name: name.to_string(),
+            target,
+            request,
+            schedule,
+            run_on_start: false,
+            sinks: Vec::new(),
+        }
+    }
+
+    /// Also runs the job as soon as the scheduler starts, before its first scheduled time.
+    pub fn run_on_start(mut self) -> Self {
+        self.run_on_start = true;
+        self
+    }
+
+    /// Adds a sink receiving every result of the job.
+    pub fn sink(mut self, sink: Arc<dyn ResultSink>) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+
+    /// Returns the job name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&self) {
+        let started_at = SystemTime::now();
+        let outcome = match &self.target {
+            Target::Supplier(supplier) => ScheduledOutcome::Supplier(query_isolated(supplier.as_ref(), self.request.clone())),
+            Target::Group(group) => ScheduledOutcome::Group(group.query(self.request.clone())),
+        };
+        let result = ScheduledResult {
+            job: self.name.clone(),
+            started_at,
+            outcome,
+        };
+        for sink in &self.sinks {
+            sink.deliver(&result);
+        }
+    }
+}
+
+/// Runs scheduled jobs, each on its own background thread.
+///
+/// A run that takes longer than the gap to the next scheduled time delays that run instead
+/// of overlapping with it.
+#[derive(Default)]
+pub struct Scheduler {
+    jobs: Vec<ScheduledJob>,
+}
+
+impl Scheduler {
+    /// Creates a scheduler without jobs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a job.
+    pub fn job(mut self, job: ScheduledJob) -> Self {
+        self.jobs.push(job);
+        self
+    }
+
+    /// Starts every job and returns the handle that stops them.
+    pub fn start(self) -> SchedulerHandle {
+        let stop = Arc::new((Mutex::new(false), Condvar::new()));
+        let threads = self
+            .jobs
+            .into_iter()
+            .map(|job| {
+                let stop = stop.clone();
+                thread::spawn(move || run_job(job, &stop))
+            })
+            .collect();
+        SchedulerHandle { stop, threads }
+    }
+}
+
+fn run_job(job: ScheduledJob, stop: &(Mutex<bool>, Condvar)) {
+    let (stopped, wake) = stop;
+    if job.run_on_start {
+        job.run();
+    }
+    let mut last = SystemTime::now();
+    while let Some(next) = job.schedule.next_after(last) {
+        let mut guard = stopped.lock().unwrap_or_else(|e| e.into_inner());
+        loop {
+            if *guard {
+                return;
+            }
+            let Ok(remaining) = next.duration_since(SystemTime::now()) else { break };
+            guard = wake.wait_timeout(guard, remaining).unwrap_or_else(|e| e.into_inner()).0;
+        }
+        drop(guard);
+        last = next.max(SystemTime::now());
+        job.run();
+    }
+}
+
+/// Stops the jobs of a started [`Scheduler`]; dropping the handle stops them too.
+pub struct SchedulerHandle {
+    stop: Arc<(Mutex<bool>, Condvar)>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl SchedulerHandle {
+    /// Stops every job and waits for runs in progress to finish.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        let (stopped, wake) = &*self.stop;
+        *stopped.lock().unwrap_or_else(|e| e.into_inner()) = true;
+        wake.notify_all();
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for SchedulerHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
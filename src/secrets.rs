@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::Arc;
+use serde_json::{Map, Value};
+use crate::errors::SupplierError;
+
+/// Looks up secrets such as API keys by name.
+///
+/// Implemented for [`EnvSecrets`], [`FileSecrets`], [`ChainedSecrets`], `HashMap<String, String>`
+/// and closures. Error messages must never include secret values.
+pub trait SecretsProvider: Send + Sync {
+    /// Returns the secret called `name`, or `None` if this provider does not define it.
+    ///
+    /// # Errors
+    /// Returns an error if the provider defines the secret but cannot read it.
+    fn secret(&self, name: &str) -> Result<Option<String>, SupplierError>;
+}
+
+impl<F> SecretsProvider for F
+where
+    F: Fn(&str) -> Result<Option<String>, SupplierError> + Send + Sync,
+{
+    fn secret(&self, name: &str) -> Result<Option<String>, SupplierError> {
+        self(name)
+    }
+}
+
+impl SecretsProvider for HashMap<String, String> {
+    fn secret(&self, name: &str) -> Result<Option<String>, SupplierError> {
+        Ok(self.get(name).cloned())
+    }
+}
+
+/// Reads secrets from environment variables.
+///
+/// The variable name is the optional prefix followed by the secret name, upper-cased with every
+/// character other than ASCII letters and digits replaced by `_`: with prefix `APP_`, the secret
+/// `shop-a.api_key` is read from `APP_SHOP_A_API_KEY`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvSecrets {
+    prefix: String,
+}
+
+impl EnvSecrets {
+    /// Reads secrets from variables named after the secret.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads secrets from variables named after the secret, behind `prefix`.
+    pub fn with_prefix(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+        }
+    }
+
+    /// Returns the variable holding secret `name`.
+    pub fn variable(&self, name: &str) -> String {
+        let name: String = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+            .collect();
+        format!("{}{}", self.prefix, name)
+    }
+}
+
+impl SecretsProvider for EnvSecrets {
+    fn secret(&self, name: &str) -> Result<Option<String>, SupplierError> {
+        let variable = self.variable(name);
+        match env::var(&variable) {
+            Ok(value) => Ok(Some(value)),
+            Err(env::VarError::NotPresent) => Ok(None),
+            Err(env::VarError::NotUnicode(_)) => Err(SupplierError::InvalidInput(format!(
+                "secret '{}' in variable '{}' is not valid UTF-8",
+                name, variable
+            ))),
+        }
+    }
+}
+
+/// Reads every secret from its own file in a directory, as mounted by Docker or Kubernetes.
+///
+/// The secret `shop_a_api_key` is read from `<dir>/shop_a_api_key`; trailing line breaks are
+/// removed. Names containing path separators or `..` are rejected.
+#[derive(Debug, Clone)]
+pub struct FileSecrets {
+    dir: PathBuf,
+}
+
+impl FileSecrets {
+    /// Reads secrets from files in `dir`.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl SecretsProvider for FileSecrets {
+    fn secret(&self, name: &str) -> Result<Option<String>, SupplierError> {
+        if name.is_empty() || name.contains(['/', '\\']) || name == "." || name == ".." {
+            return Err(SupplierError::InvalidInput(format!("invalid secret name '{}'", name)));
+        }
+        let path = self.dir.join(name);
+        match fs::read_to_string(&path) {
+            Ok(value) => Ok(Some(value.trim_end_matches(['\r', '\n']).to_string())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(SupplierError::InvalidInput(format!(
+                "cannot read secret '{}' from '{}': {}",
+                name,
+                path.display(),
+                e
+            ))),
+        }
+    }
+}
+
+/// Asks several providers in order and returns the first secret found.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use std::sync::Arc;
+/// use supplier_kit::secrets::{ChainedSecrets, EnvSecrets, SecretsProvider};
+///
+/// let defaults: HashMap<String, String> = [("region".to_string(), "eu".to_string())].into();
+/// let secrets = ChainedSecrets::new()
+///     .with(Arc::new(EnvSecrets::with_prefix("SUPPLIER_KIT_DOC_")))
+///     .with(Arc::new(defaults));
+///
+/// assert_eq!(secrets.secret("region").unwrap().as_deref(), Some("eu"));
+/// assert_eq!(secrets.secret("missing").unwrap(), None);
+/// ```
+#[derive(Clone, Default)]
+pub struct ChainedSecrets {
+    providers: Vec<Arc<dyn SecretsProvider>>,
+}
+
+impl ChainedSecrets {
+    /// Creates a chain without providers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a provider, asked after the ones added before it.
+    pub fn with(mut self, provider: Arc<dyn SecretsProvider>) -> Self {
+        self.providers.push(provider);
+        self
+    }
+}
+
+impl SecretsProvider for ChainedSecrets {
+    fn secret(&self, name: &str) -> Result<Option<String>, SupplierError> {
+        for provider in &self.providers {
+            if let Some(secret) = provider.secret(name)? {
+                return Ok(Some(secret));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Replaces every `{ "$secret": "<name>" }` object inside `value` with the named secret.
+///
+/// # Errors
+/// Returns `SupplierError::InvalidInput` if a referenced secret is not defined, or the
+/// provider's error if it cannot be read.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use serde_json::json;
+/// use supplier_kit::secrets::resolve_secrets;
+///
+/// let secrets: HashMap<String, String> = [("shop_a_key".to_string(), "k-123".to_string())].into();
+/// let config = json!({ "url": "https://shop-a.test", "auth": { "api_key": { "$secret": "shop_a_key" } } });
+///
+/// let resolved = resolve_secrets(&config, &secrets).unwrap();
+/// assert_eq!(resolved["auth"]["api_key"], "k-123");
+/// ```
+pub fn resolve_secrets(value: &Value, provider: &dyn SecretsProvider) -> Result<Value, SupplierError> {
+    match value {
+        Value::Object(object) => {
+            if let Some(name) = secret_reference(object) {
+                return provider
+                    .secret(name)?
+                    .map(Value::String)
+                    .ok_or_else(|| SupplierError::InvalidInput(format!("secret '{}' is not defined", name)));
+            }
+            object
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), resolve_secrets(value, provider)?)))
+                .collect::<Result<Map<_, _>, _>>()
+                .map(Value::Object)
+        }
+        Value::Array(items) => items
+            .iter()
+            .map(|item| resolve_secrets(item, provider))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        other => Ok(other.clone()),
+    }
+}
+
+/// Returns whether `value` contains any `{ "$secret": ... }` reference.
+pub fn references_secrets(value: &Value) -> bool {
+    match value {
+        Value::Object(object) => secret_reference(object).is_some() || object.values().any(references_secrets),
+        Value::Array(items) => items.iter().any(references_secrets),
+        _ => false,
+    }
+}
+
+fn secret_reference(object: &Map<String, Value>) -> Option<&str> {
+    match (object.len(), object.get("$secret")) {
+        (1, Some(Value::String(name))) => Some(name),
+        _ => None,
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use axum::extract::{Path, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde_json::{json, Map, Value};
+use crate::errors::SupplierError;
+use crate::health::ProbeReport;
+use crate::models::SupplierRequest;
+use crate::supplier::SupplierRegistry;
+use crate::supplier_group::SupplierGroup;
+
+/// A supplier group that can be shared with the HTTP handlers.
+pub type SharedGroup = Arc<dyn SupplierGroup + Send + Sync>;
+
+/// Builds an axum [`Router`] that serves supplier groups over HTTP.
+///
+/// The router exposes:
+/// - `POST /groups/{name}/query`: accepts a JSON `SupplierRequest` and returns the serialized
+///   `SupplierGroupResult`. Unknown groups answer `404` with a serialized `SupplierError::NotFound`.
+///   Requests without a locale in their context take the preferred one of `Accept-Language`.
+///   The caller, tenant and priority of the body's context are never trusted: they are taken
+///   from the headers configured with `with_caller_header`, `with_tenant_header` and
+///   `with_priority_header`, and cleared otherwise.
+/// - `GET /health`: lists the mounted groups and, for every registry supplier, whether it is
+///   ready (see `SupplierRegistry::is_healthy`). `status` is `"degraded"` exactly when
+///   `GET /ready` fails.
+/// - `GET /ready` and `GET /live`: return `SupplierRegistry::readiness` and
+///   `SupplierRegistry::liveness` with `200`, or `503` when the probe fails, for Kubernetes
+///   readiness and liveness probes. Both pass when no registry is attached.
+/// - `GET /describe`: returns `SupplierRegistry::describe_all` for the attached registry.
+///
+/// Group queries are blocking, so they run on tokio's blocking thread pool.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use supplier_kit::server::GroupRouter;
+/// use supplier_kit::supplier::SupplierRegistry;
+/// use supplier_kit::supplier_group::BasicSupplierGroup;
+///
+/// let router: axum::Router = GroupRouter::new()
+///     .group(BasicSupplierGroup::new("marketplace"))
+///     .with_registry(Arc::new(SupplierRegistry::new()))
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct GroupRouter {
+    groups: HashMap<String, SharedGroup>,
+    registry: Option<Arc<SupplierRegistry>>,
+    caller_header: Option<String>,
+    tenant_header: Option<String>,
+    priority_header: Option<String>,
+}
+
+impl GroupRouter {
+    /// Creates a router builder without groups.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mounts `group` under its group name, replacing any group with the same name.
+    pub fn group<G>(self, group: G) -> Self
+    where
+        G: SupplierGroup + Send + Sync + 'static,
+    {
+        self.group_arc(Arc::new(group))
+    }
+
+    /// Mounts an already shared group under its group name.
+    pub fn group_arc(mut self, group: SharedGroup) -> Self {
+        self.groups.insert(group.group_name().to_string(), group);
+        self
+    }
+
+    /// Attaches the registry reported by the health and describe endpoints.
+    pub fn with_registry(mut self, registry: Arc<SupplierRegistry>) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Takes the caller checked by access policies from `header`, set by an authenticating
+    /// proxy in front of the router.
+    ///
+    /// Callers found in the request body are always ignored; without this header, or when a
+    /// request lacks it, requests are handled as anonymous.
+    pub fn with_caller_header(mut self, header: &str) -> Self {
+        self.caller_header = Some(header.to_ascii_lowercase());
+        self
+    }
+
+    /// Takes the tenant of requests from `header`, set by an authenticating proxy.
+    ///
+    /// Tenants found in the request body are always ignored; without this header, requests
+    /// carry no tenant.
+    pub fn with_tenant_header(mut self, header: &str) -> Self {
+        self.tenant_header = Some(header.to_ascii_lowercase());
+        self
+    }
+
+    /// Takes the priority of requests from `header` (`low`, `normal`, `high` or `critical`),
+    /// set by a trusted proxy.
+    ///
+    /// Priorities found in the request body are always ignored, so clients cannot exempt
+    /// themselves from load shedding; without this header, or with an unknown value, requests
+    /// have the normal priority.
+    pub fn with_priority_header(mut self, header: &str) -> Self {
+        self.priority_header = Some(header.to_ascii_lowercase());
+        self
+    }
+
+    /// Builds the router.
+    pub fn build(self) -> Router {
+        Router::new()
+            .route("/groups/{name}/query", post(query_group))
+            .route("/health", get(health))
+            .route("/ready", get(ready))
+            .route("/live", get(live))
+            .route("/describe", get(describe))
+            .with_state(Arc::new(self))
+    }
+}
+
+type AppState = State<Arc<GroupRouter>>;
+
+async fn query_group(
+    State(state): AppState,
+    Path(name): Path<String>,
+    headers: HeaderMap,
+    Json(mut request): Json<SupplierRequest>,
+) -> Response {
+    let Some(group) = state.groups.get(&name).cloned() else {
+        return error_response(StatusCode::NOT_FOUND, SupplierError::NotFound);
+    };
+    // Identity and priority come from trusted headers only, never from the body.
+    let trusted = |header: &Option<String>| {
+        header
+            .as_ref()
+            .and_then(|header| headers.get(header))
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    request.context.caller = trusted(&state.caller_header);
+    request.context.tenant = trusted(&state.tenant_header);
+    request.context.priority = trusted(&state.priority_header).and_then(|p| serde_json::from_value(Value::String(p)).ok());
+    if request.context.locale.is_none() {
+        request.context.locale = headers
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(preferred_language);
+    }
+    match tokio::task::spawn_blocking(move || group.query(request)).await {
+        Ok(result) => Json(result).into_response(),
+        Err(error) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            SupplierError::Internal(format!("group '{}' query failed: {}", name, error)),
+        ),
+    }
+}
+
+/// Returns the language range of an `Accept-Language` value with the highest quality, the
+/// first one on ties; `*` and ranges with quality 0 are ignored.
+fn preferred_language(accept: &str) -> Option<String> {
+    let mut best: Option<(&str, f32)> = None;
+    for range in accept.split(',') {
+        let mut parts = range.split(';');
+        let tag = parts.next().unwrap_or_default().trim();
+        let quality = parts
+            .filter_map(|param| param.trim().strip_prefix("q="))
+            .find_map(|q| q.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+        if tag.is_empty() || tag == "*" || quality <= 0.0 {
+            continue;
+        }
+        if best.is_none_or(|(_, q)| quality > q) {
+            best = Some((tag, quality));
+        }
+    }
+    best.map(|(tag, _)| tag.to_string())
+}
+
+async fn health(State(state): AppState) -> Json<Value> {
+    let mut groups: Vec<&str> = state.groups.keys().map(String::as_str).collect();
+    groups.sort_unstable();
+
+    let mut suppliers = Map::new();
+    let mut ok = true;
+    if let Some(registry) = &state.registry {
+        let mut names = registry.all_names();
+        names.sort();
+        for name in names {
+            let ready = registry.is_healthy(&name);
+            suppliers.insert(name, json!({ "ready": ready }));
+        }
+        ok = registry.readiness().ok;
+    }
+
+    Json(json!({
+        "status": if ok { "ok" } else { "degraded" },
+        "groups": groups,
+        "suppliers": suppliers,
+    }))
+}
+
+async fn ready(State(state): AppState) -> Response {
+    probe_response(state.registry.as_ref().map(|registry| registry.readiness()))
+}
+
+async fn live(State(state): AppState) -> Response {
+    probe_response(state.registry.as_ref().map(|registry| registry.liveness()))
+}
+
+fn probe_response(report: Option<ProbeReport>) -> Response {
+    let report = report.unwrap_or_else(|| ProbeReport::new(0, Vec::new(), Vec::new(), |_| String::new()));
+    let status = if report.ok { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+    (status, Json(report)).into_response()
+}
+
+async fn describe(State(state): AppState) -> Json<Value> {
+    let descriptors = state
+        .registry
+        .as_ref()
+        .map(|registry| json!(registry.describe_all()))
+        .unwrap_or_else(|| json!({}));
+    Json(descriptors)
+}
+
+fn error_response(status: StatusCode, error: SupplierError) -> Response {
+    (status, Json(error)).into_response()
+}
@@ -0,0 +1,173 @@
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::runtime::{Builder, Runtime};
+use tower::{BoxError, Service, ServiceExt};
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::{query_isolated, Supplier};
+
+/// Exposes any `tower::Service<SupplierRequest>` as a [`Supplier`].
+///
+/// Each query clones the service, waits for it to become ready and calls it on a private
+/// current-thread tokio runtime, so tower middleware such as timeouts, rate and concurrency limits
+/// can be stacked in front of a supplier implementation.
+///
+/// Service errors are mapped as follows: a boxed `SupplierError` is returned unchanged,
+/// `tower::timeout::error::Elapsed` becomes `SupplierError::Timeout`, and anything else becomes
+/// `SupplierError::Upstream` with the error message.
+///
+/// `query` blocks the calling thread; call it from a blocking context (e.g. a plain thread,
+/// a supplier group or `tokio::task::spawn_blocking`), not from inside an async task.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::service::TowerSupplier;
+/// use supplier_kit::supplier::Supplier;
+/// use tower::ServiceBuilder;
+///
+/// let service = ServiceBuilder::new()
+///     .timeout(Duration::from_secs(1))
+///     .service_fn(|request: SupplierRequest| async move {
+///         Ok::<_, SupplierError>(SupplierResponse::new(request.params))
+///     });
+///
+/// let supplier = TowerSupplier::new("echo", service).unwrap();
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({ "q": "lamp" }));
+/// assert_eq!(supplier.query(request).unwrap().data["q"], "lamp");
+/// ```
+pub struct TowerSupplier<S> {
+    name: String,
+    service: S,
+    runtime: Runtime,
+}
+
+impl<S> TowerSupplier<S> {
+    /// Wraps `service` as a supplier called `name`.
+    ///
+    /// # Errors
+    /// Returns an error if the private tokio runtime cannot be created.
+    pub fn new(name: &str, service: S) -> io::Result<Self> {
+        let runtime = Builder::new_current_thread().enable_time().build()?;
+        Ok(Self {
+            name: name.to_string(),
+            service,
+            runtime,
+        })
+    }
+}
+
+impl<S> Supplier for TowerSupplier<S>
+where
+    S: Service<SupplierRequest, Response = SupplierResponse> + Clone + Send + Sync,
+    S::Error: Into<BoxError>,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let service = self.service.clone();
+        self.runtime
+            .block_on(service.oneshot(request))
+            .map_err(|error| to_supplier_error(error.into()))
+    }
+}
+
+fn to_supplier_error(error: BoxError) -> SupplierError {
+    match error.downcast::<SupplierError>() {
+        Ok(error) => *error,
+        Err(error) if error.is::<tower::timeout::error::Elapsed>() => SupplierError::Timeout,
+        Err(error) => SupplierError::Upstream(error.to_string()),
+    }
+}
+
+/// Exposes a [`Supplier`] as a `tower::Service<SupplierRequest>`.
+///
+/// The service is always ready; each call runs the supplier on tokio's blocking thread pool
+/// with panic isolation (see [`query_isolated`]), so it must be called from within a tokio runtime.
+/// Cloning the service is cheap and shares the supplier.
+///
+/// # Example
+/// ```
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::service::SupplierService;
+/// use supplier_kit::supplier::Supplier;
+/// use tower::ServiceExt;
+///
+/// struct Echo;
+///
+/// impl Supplier for Echo {
+///     fn name(&self) -> &str { "echo" }
+///     fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         Ok(SupplierResponse::new(request.params))
+///     }
+/// }
+///
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({ "q": "lamp" }));
+/// let response = SupplierService::new(Echo).oneshot(request).await.unwrap();
+/// assert_eq!(response.data["q"], "lamp");
+/// # }
+/// ```
+pub struct SupplierService<S: ?Sized> {
+    supplier: Arc<S>,
+}
+
+impl<S: Supplier + 'static> SupplierService<S> {
+    /// Wraps `supplier` as a tower service.
+    pub fn new(supplier: S) -> Self {
+        Self::from_arc(Arc::new(supplier))
+    }
+}
+
+impl<S: Supplier + ?Sized + 'static> SupplierService<S> {
+    /// Wraps an already shared supplier, e.g. one taken from a registry.
+    pub fn from_arc(supplier: Arc<S>) -> Self {
+        Self { supplier }
+    }
+
+    /// Returns the descriptor of the wrapped supplier.
+    pub fn describe(&self) -> SupplierDescriptor {
+        self.supplier.describe()
+    }
+}
+
+impl<S: ?Sized> Clone for SupplierService<S> {
+    fn clone(&self) -> Self {
+        Self {
+            supplier: self.supplier.clone(),
+        }
+    }
+}
+
+/// The future returned by [`SupplierService`].
+pub type SupplierFuture = Pin<Box<dyn Future<Output = Result<SupplierResponse, SupplierError>> + Send>>;
+
+impl<S: Supplier + ?Sized + 'static> Service<SupplierRequest> for SupplierService<S> {
+    type Response = SupplierResponse;
+    type Error = SupplierError;
+    type Future = SupplierFuture;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: SupplierRequest) -> Self::Future {
+        let supplier = self.supplier.clone();
+        Box::pin(async move {
+            let name = supplier.name().to_string();
+            tokio::task::spawn_blocking(move || query_isolated(supplier.as_ref(), request))
+                .await
+                .unwrap_or_else(|error| Err(SupplierError::Internal(format!("supplier '{}' task failed: {}", name, error))))
+        })
+    }
+}
@@ -0,0 +1,150 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use crate::context::Priority;
+use crate::errors::SupplierError;
+
+type DepthProbe = Box<dyn Fn() -> usize + Send + Sync>;
+
+/// Rejects low-priority group queries while the system is under pressure.
+///
+/// Pressure is measured as the number of group queries in flight through the shedder and,
+/// optionally, the depth of an external queue reported by a probe. Each threshold sheds the
+/// requests of a priority and every lower priority once the measure reaches it; requests of
+/// higher priorities are still admitted. Shed requests fail with `SupplierError::Overloaded`
+/// before any supplier is queried.
+///
+/// Attach a shedder to a group with `BasicSupplierGroup::with_load_shedding`; share one shedder
+/// between groups to protect the whole aggregator.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use supplier_kit::context::Priority;
+/// use supplier_kit::shedding::LoadShedder;
+///
+/// let shedder = Arc::new(
+///     LoadShedder::new()
+///         .shed_at_in_flight(Priority::Low, 1)
+///         .shed_at_in_flight(Priority::Normal, 2),
+/// );
+///
+/// let first = shedder.try_admit(Priority::Normal).unwrap();
+/// assert!(shedder.try_admit(Priority::Low).is_err());
+/// let second = shedder.try_admit(Priority::Normal).unwrap();
+/// assert!(shedder.try_admit(Priority::Normal).is_err());
+/// assert!(shedder.try_admit(Priority::High).is_ok());
+///
+/// drop((first, second));
+/// assert!(shedder.try_admit(Priority::Low).is_ok());
+/// assert_eq!(shedder.shed_count(), 2);
+/// ```
+#[derive(Default)]
+pub struct LoadShedder {
+    in_flight: AtomicUsize,
+    shed: AtomicU64,
+    in_flight_thresholds: Vec<(Priority, usize)>,
+    queue_thresholds: Vec<(Priority, usize)>,
+    queue_depth: Option<DepthProbe>,
+}
+
+impl LoadShedder {
+    /// Creates a shedder that admits everything until thresholds are added.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sheds requests of `priority` and below while at least `in_flight` queries are in flight.
+    pub fn shed_at_in_flight(mut self, priority: Priority, in_flight: usize) -> Self {
+        self.in_flight_thresholds.push((priority, in_flight));
+        self
+    }
+
+    /// Sheds requests of `priority` and below while the queue probe reports at least `depth`.
+    pub fn shed_at_queue_depth(mut self, priority: Priority, depth: usize) -> Self {
+        self.queue_thresholds.push((priority, depth));
+        self
+    }
+
+    /// Reports the depth of an external queue, such as a job queue feeding the groups.
+    ///
+    /// Without a probe, queue-depth thresholds never shed.
+    pub fn with_queue_depth<F>(mut self, probe: F) -> Self
+    where
+        F: Fn() -> usize + Send + Sync + 'static,
+    {
+        self.queue_depth = Some(Box::new(probe));
+        self
+    }
+
+    /// Returns the number of admitted queries still in flight.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Returns how many requests have been shed.
+    pub fn shed_count(&self) -> u64 {
+        self.shed.load(Ordering::SeqCst)
+    }
+
+    /// Admits a request of `priority`, or sheds it.
+    ///
+    /// The returned permit counts as in flight until it is dropped.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::Overloaded` if a threshold covering `priority` is reached.
+    pub fn try_admit(self: &Arc<Self>, priority: Priority) -> Result<LoadPermit, SupplierError> {
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst);
+        let permit = LoadPermit { shedder: self.clone() };
+
+        if let Some(max) = threshold(&self.in_flight_thresholds, priority)
+            && in_flight >= max
+        {
+            return Err(self.reject(format!("{} queries in flight, {:?} requests are shed at {}", in_flight, priority, max)));
+        }
+        if let (Some(max), Some(probe)) = (threshold(&self.queue_thresholds, priority), &self.queue_depth) {
+            let depth = probe();
+            if depth >= max {
+                return Err(self.reject(format!("queue depth {}, {:?} requests are shed at {}", depth, priority, max)));
+            }
+        }
+        Ok(permit)
+    }
+
+    fn reject(&self, reason: String) -> SupplierError {
+        self.shed.fetch_add(1, Ordering::SeqCst);
+        SupplierError::Overloaded(reason)
+    }
+}
+
+impl fmt::Debug for LoadShedder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoadShedder")
+            .field("in_flight", &self.in_flight())
+            .field("shed", &self.shed_count())
+            .field("in_flight_thresholds", &self.in_flight_thresholds)
+            .field("queue_thresholds", &self.queue_thresholds)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The lowest threshold covering `priority`, i.e. set for `priority` or a higher priority.
+fn threshold(thresholds: &[(Priority, usize)], priority: Priority) -> Option<usize> {
+    thresholds
+        .iter()
+        .filter(|(covered, _)| priority <= *covered)
+        .map(|(_, max)| *max)
+        .min()
+}
+
+/// An admitted query; it stops counting as in flight when dropped.
+#[derive(Debug)]
+pub struct LoadPermit {
+    shedder: Arc<LoadShedder>,
+}
+
+impl Drop for LoadPermit {
+    fn drop(&mut self) {
+        self.shedder.in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
+}
@@ -1,11 +1,23 @@
-use std::sync::Arc;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use crate::auth::{AuthProvider, RotatingAuth};
+use crate::deprecation::{Deprecation, DeprecationMilestone, DeprecationNotice};
+use crate::descriptor::SupplierDescriptor;
 use crate::errors::SupplierError;
+use crate::events::{EventBus, SupplierEvent};
+use crate::health::{HealthMap, ProbeReport};
 use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier_group::SupplierGroup;
 
 /// A trait that represents a supplier, which is a provider of data or services.
 /// A supplier can be queried with a `SupplierRequest` and will return a `SupplierResponse`.
 /// It is implemented by different types that provide the actual supplier logic.
-pub trait Supplier {
+///
+/// Suppliers must be `Send + Sync` so that groups can query them from multiple threads.
+pub trait Supplier: Send + Sync {
     /// Returns the name of the supplier.
     ///
     /// # Example
@@ -36,12 +48,10 @@ pub trait Supplier {
     ///         if self.should_fail {
     ///             Err(SupplierError::Internal(format!("{} failed", self.name)))
     ///         } else {
-    ///             Ok(SupplierResponse {
-    ///                 data: json!({
-    ///                     "supplier": self.name,
-    ///                     "params": request.params
-    ///                 }),
-    ///             })
+    ///             Ok(SupplierResponse::new(json!({
+    ///                 "supplier": self.name,
+    ///                 "params": request.params
+    ///             })))
     ///         }
     ///     }
     /// }    
@@ -87,36 +97,557 @@ pub trait Supplier {
     ///         if self.should_fail {
     ///             Err(SupplierError::Internal(format!("{} failed", self.name)))
     ///         } else {
-    ///             Ok(SupplierResponse {
-    ///                 data: json!({
-    ///                     "supplier": self.name,
-    ///                     "params": request.params
-    ///                 }),
-    ///             })
+    ///             Ok(SupplierResponse::new(json!({
+    ///                 "supplier": self.name,
+    ///                 "params": request.params
+    ///             })))
     ///         }
     ///     }
     /// }
     ///
     /// let supplier = MySupplier {name: "my_supplier".to_string(),should_fail: false};
-    /// let request = SupplierRequest {
-    ///     operation: SupplierOperation::Search,
-    ///     params: json!({"query": "item"}),
-    /// };
+    /// let request = SupplierRequest::new(SupplierOperation::Search, json!({"query": "item"}));
     /// let response = supplier.query(request);
     /// ```
     fn query(
         &self,
         request: SupplierRequest,
     ) -> Result<SupplierResponse, SupplierError>;
+
+    /// Queries the supplier with several requests at once.
+    ///
+    /// The default implementation calls [`Supplier::query`] for each request in turn.
+    /// Suppliers backed by a bulk endpoint should override it to issue a single upstream call.
+    ///
+    /// # Returns
+    /// One result per request, in the same order as `requests`.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+    /// use supplier_kit::supplier::Supplier;
+    ///
+    /// struct EchoSupplier;
+    ///
+    /// impl Supplier for EchoSupplier {
+    ///     fn name(&self) -> &str { "echo" }
+    ///     fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+    ///         Ok(SupplierResponse::new(request.params))
+    ///     }
+    /// }
+    ///
+    /// let requests = (1..=3)
+    ///     .map(|id| SupplierRequest::new(SupplierOperation::GetDetail, serde_json::json!({ "id": id })))
+    ///     .collect();
+    /// let results = EchoSupplier.query_batch(requests);
+    /// assert_eq!(results.len(), 3);
+    /// assert_eq!(results[2].as_ref().unwrap().data["id"], 3);
+    /// ```
+    fn query_batch(
+        &self,
+        requests: Vec<SupplierRequest>,
+    ) -> Vec<Result<SupplierResponse, SupplierError>> {
+        requests.into_iter().map(|request| self.query(request)).collect()
+    }
+
+    /// Prepares the supplier to serve traffic, e.g. by filling caches or acquiring tokens.
+    ///
+    /// `BasicSupplierGroup` calls this once when the supplier is added, so the cost is not paid
+    /// by the first user-facing request. A supplier whose warm-up fails is left out of group
+    /// queries until a later `BasicSupplierGroup::warm_up` succeeds. The default does nothing.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::models::{SupplierRequest, SupplierResponse};
+    /// use supplier_kit::supplier::Supplier;
+    ///
+    /// struct TokenSupplier {
+    ///     token: AtomicBool,
+    /// }
+    ///
+    /// impl Supplier for TokenSupplier {
+    ///     fn name(&self) -> &str { "token" }
+    ///     fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+    ///         Ok(SupplierResponse::new(serde_json::json!({})))
+    ///     }
+    ///     fn warm_up(&self) -> Result<(), SupplierError> {
+    ///         self.token.store(true, Ordering::SeqCst);
+    ///         Ok(())
+    ///     }
+    ///     fn is_ready(&self) -> bool {
+    ///         self.token.load(Ordering::SeqCst)
+    ///     }
+    /// }
+    ///
+    /// let supplier = TokenSupplier { token: AtomicBool::new(false) };
+    /// assert!(!supplier.is_ready());
+    /// supplier.warm_up().unwrap();
+    /// assert!(supplier.is_ready());
+    /// ```
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        Ok(())
+    }
+
+    /// Reports whether the supplier can currently serve queries.
+    ///
+    /// Groups skip suppliers that are not ready. The default always returns `true`.
+    fn is_ready(&self) -> bool {
+        true
+    }
+
+    /// Actively checks that the supplier can serve queries, e.g. by calling a ping endpoint.
+    ///
+    /// `HealthMonitor` calls this periodically, so outages are noticed before user traffic
+    /// fails. Keep it cheap. The default reports `SupplierError::Upstream` when the supplier
+    /// is not ready and succeeds otherwise.
+    fn health_check(&self) -> Result<(), SupplierError> {
+        if self.is_ready() {
+            Ok(())
+        } else {
+            Err(SupplierError::Upstream(format!("supplier '{}' is not ready", self.name())))
+        }
+    }
+
+    /// Describes the supplier for admin tools and documentation generators.
+    ///
+    /// The default describes the supplier by name only. Override it to declare the version,
+    /// supported operations with their schemas, and rate limits.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::descriptor::{OperationDescriptor, SupplierDescriptor};
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+    /// use supplier_kit::supplier::Supplier;
+    ///
+    /// struct Catalog;
+    ///
+    /// impl Supplier for Catalog {
+    ///     fn name(&self) -> &str { "catalog" }
+    ///     fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+    ///         Ok(SupplierResponse::new(serde_json::json!([])))
+    ///     }
+    ///     fn describe(&self) -> SupplierDescriptor {
+    ///         SupplierDescriptor::new(self.name())
+    ///             .with_version("1.0.0")
+    ///             .with_operation(OperationDescriptor::new(SupplierOperation::Search))
+    ///     }
+    /// }
+    ///
+    /// assert!(Catalog.describe().supports(&SupplierOperation::Search));
+    /// ```
+    fn describe(&self) -> SupplierDescriptor {
+        SupplierDescriptor::new(self.name())
+    }
+
+    /// Returns the supplier group this supplier answers from, if it wraps one.
+    ///
+    /// `CompositeSupplier` returns its nested group, which lets groups built with
+    /// `with_flattened_results` list the nested members. The default returns `None`.
+    fn as_group(&self) -> Option<&(dyn SupplierGroup + Send + Sync)> {
+        None
+    }
+}
+
+impl<S: Supplier + ?Sized> Supplier for Arc<S> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        (**self).query(request)
+    }
+
+    fn query_batch(&self, requests: Vec<SupplierRequest>) -> Vec<Result<SupplierResponse, SupplierError>> {
+        (**self).query_batch(requests)
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        (**self).warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        (**self).is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        (**self).health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        (**self).describe()
+    }
+
+    fn as_group(&self) -> Option<&(dyn SupplierGroup + Send + Sync)> {
+        (**self).as_group()
+    }
+}
+
+/// Queries a supplier while isolating the caller from panics inside the supplier implementation.
+///
+/// If the supplier panics, the panic is caught and converted into `SupplierError::Internal`
+/// carrying the panic message, so one buggy supplier cannot take down a whole group query.
+///
+/// # Example
+/// ```
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::supplier::{query_isolated, Supplier};
+///
+/// struct PanickingSupplier;
+///
+/// impl Supplier for PanickingSupplier {
+///     fn name(&self) -> &str { "panicky" }
+///     fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         panic!("boom")
+///     }
+/// }
+///
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}));
+/// let result = query_isolated(&PanickingSupplier, request);
+/// assert!(matches!(result, Err(SupplierError::Internal(msg)) if msg.contains("boom")));
+/// ```
+pub fn query_isolated<S>(supplier: &S, request: SupplierRequest) -> Result<SupplierResponse, SupplierError>
+where
+    S: Supplier + ?Sized,
+{
+    panic::catch_unwind(AssertUnwindSafe(|| supplier.query(request)))
+        .unwrap_or_else(|payload| Err(panic_error(supplier.name(), payload)))
+}
+
+/// Runs `Supplier::warm_up` like [`query_isolated`] runs queries: a panic fails the warm-up
+/// with `SupplierError::Internal`.
+pub(crate) fn warm_up_isolated<S>(supplier: &S) -> Result<(), SupplierError>
+where
+    S: Supplier + ?Sized,
+{
+    panic::catch_unwind(AssertUnwindSafe(|| supplier.warm_up()))
+        .unwrap_or_else(|payload| Err(panic_error(supplier.name(), payload)))
+}
+
+/// Batch counterpart of [`query_isolated`].
+///
+/// A panic fails every request of the batch with `SupplierError::Internal`. If the supplier
+/// returns fewer results than requests, the missing ones are reported as internal errors too,
+/// so the output always has exactly one result per request.
+pub fn query_batch_isolated<S>(
+    supplier: &S,
+    requests: Vec<SupplierRequest>,
+) -> Vec<Result<SupplierResponse, SupplierError>>
+where
+    S: Supplier + ?Sized,
+{
+    let expected = requests.len();
+    let mut results = match panic::catch_unwind(AssertUnwindSafe(|| supplier.query_batch(requests))) {
+        Ok(results) => results,
+        Err(payload) => {
+            let error = panic_error(supplier.name(), payload);
+            return vec![Err(error); expected];
+        }
+    };
+
+    results.truncate(expected);
+    while results.len() < expected {
+        results.push(Err(SupplierError::Internal(format!(
+            "supplier '{}' returned {} batch results for {} requests",
+            supplier.name(),
+            results.len(),
+            expected
+        ))));
+    }
+    results
+}
+
+/// Normalizes `name` and follows it to the supplier name it is an alias of, if any.
+fn resolve_name<'a>(normalization: &NameNormalization, aliases: &'a HashMap<String, String>, name: &'a str) -> Cow<'a, str> {
+    let name = normalization.apply(name);
+    match aliases.get(&*name) {
+        Some(target) => Cow::Borrowed(target),
+        None => name,
+    }
+}
+
+/// Folds a character onto its plain form, or drops it if it is a combining mark.
+fn fold_char(c: char) -> Option<char> {
+    let folded = match c {
+        '\u{0300}'..='\u{036F}' => return None,
+        '\u{3000}' => ' ',
+        // Fullwidth ASCII variants sit at a fixed offset from ASCII.
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        _ => {
+            let lower = c.to_lowercase().next().unwrap_or(c);
+            let base = match lower {
+                'à'..='å' | 'ā' | 'ă' | 'ą' => 'a',
+                'ç' | 'ć' | 'č' => 'c',
+                'ď' | 'đ' => 'd',
+                'è'..='ë' | 'ē' | 'ė' | 'ę' | 'ě' => 'e',
+                'ğ' => 'g',
+                'ì'..='ï' | 'ī' | 'į' | 'ı' => 'i',
+                'ł' | 'ľ' => 'l',
+                'ñ' | 'ń' | 'ň' => 'n',
+                'ò'..='ö' | 'ø' | 'ō' | 'ő' => 'o',
+                'ř' => 'r',
+                'ś' | 'š' | 'ş' => 's',
+                'ť' | 'ţ' => 't',
+                'ù'..='ü' | 'ū' | 'ů' | 'ű' | 'ų' => 'u',
+                'ý' | 'ÿ' => 'y',
+                'ź' | 'ż' | 'ž' => 'z',
+                _ => return Some(c),
+            };
+            if c.is_uppercase() { base.to_ascii_uppercase() } else { base }
+        }
+    };
+    Some(folded)
+}
+
+/// Orders dotted versions such as `2.10.1` numerically; pre-release suffixes are ignored and
+/// a missing version sorts first.
+fn version_key(version: Option<&str>) -> Option<Vec<u64>> {
+    let mut key: Vec<u64> = version?
+        .split(['-', '+'])
+        .next()
+        .unwrap_or_default()
+        .split('.')
+        .map(|part| part.trim_start_matches('v').parse().unwrap_or(0))
+        .collect();
+    while key.last() == Some(&0) {
+        key.pop();
+    }
+    Some(key)
+}
+
+fn panic_error(supplier: &str, payload: Box<dyn std::any::Any + Send>) -> SupplierError {
+    SupplierError::Internal(format!("supplier '{}' panicked: {}", supplier, panic_reason(payload.as_ref())))
+}
+
+/// Returns the message a panic was raised with.
+pub(crate) fn panic_reason(payload: &(dyn std::any::Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
 }
 
 /// A registry for managing suppliers by name. It allows suppliers to be registered, retrieved by name, 
 /// and provides a list of all registered suppliers.
 #[derive(Default)]
 pub struct SupplierRegistry {
-    suppliers: std::collections::HashMap<String, Arc<dyn Supplier>>,
+    suppliers: HashMap<String, Arc<dyn Supplier>>,
+    deprecations: HashMap<String, Deprecation>,
+    reached_milestones: Mutex<HashMap<String, DeprecationMilestone>>,
+    deprecation_listener: Option<DeprecationListener>,
+    events: EventBus,
+    auth: HashMap<String, Arc<RotatingAuth>>,
+    // Alternative names, each pointing at a registered supplier name.
+    aliases: HashMap<String, String>,
+    duplicate_policy: DuplicatePolicy,
+    normalization: NameNormalization,
+    critical: HashSet<String>,
+    min_ready: Option<usize>,
+    health: Option<Arc<HealthMap>>,
+    max_check_age: Option<Duration>,
+}
+
+/// How a `SupplierRegistry` normalizes supplier names and aliases before storing or looking
+/// them up, so that names from configuration files or user input match despite stray
+/// whitespace or different spelling.
+///
+/// All steps are off by default, which keeps names exactly as given.
+///
+/// # Example
+/// ```
+/// use supplier_kit::supplier::NameNormalization;
+///
+/// let normalization = NameNormalization::all();
+/// assert_eq!(normalization.apply(" Amazon "), "amazon");
+/// assert_eq!(normalization.apply("Ｃafé"), "cafe");
+/// assert_eq!(NameNormalization::default().apply(" Amazon "), " Amazon ");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NameNormalization {
+    /// Strips leading and trailing whitespace.
+    pub trim: bool,
+    /// Lowercases the name, including non-ASCII letters.
+    pub lowercase: bool,
+    /// Folds Unicode variants onto plain characters: fullwidth forms become ASCII, accented
+    /// Latin letters lose their accents and combining marks are dropped.
+    pub fold_unicode: bool,
+}
+
+impl NameNormalization {
+    /// Enables every normalization step.
+    pub fn all() -> Self {
+        Self {
+            trim: true,
+            lowercase: true,
+            fold_unicode: true,
+        }
+    }
+
+    /// Returns the normalized form of `name`, borrowing it when nothing changes.
+    pub fn apply<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        let mut name = Cow::Borrowed(if self.trim { name.trim() } else { name });
+        if self.fold_unicode && !name.is_ascii() {
+            name = Cow::Owned(name.chars().filter_map(fold_char).collect());
+        }
+        if self.lowercase && name.chars().any(char::is_uppercase) {
+            name = Cow::Owned(name.to_lowercase());
+        }
+        name
+    }
+}
+
+/// What `SupplierRegistry::register` does when a supplier is already registered under the name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicatePolicy {
+    /// Replace the registered supplier.
+    #[default]
+    Overwrite,
+    /// Keep the registered supplier and reject the new one.
+    Reject,
+    /// Replace the registered supplier only if the new one describes a newer version (see
+    /// `SupplierDescriptor::version`); a supplier without a version is older than any version.
+    Version,
+}
+
+/// The outcome of registering a single supplier through `SupplierRegistry::register` or
+/// `SupplierRegistry::register_all`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistrationOutcome {
+    /// The name was free and the supplier was added.
+    Added,
+    /// The name was already taken and the previous supplier was replaced.
+    Replaced,
+    /// The supplier was not registered, for the given reason.
+    Rejected(String),
+}
+
+/// A per-name report of a bulk registration.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegistrationReport {
+    /// The outcome for each entry, in registration order.
+    pub outcomes: Vec<(String, RegistrationOutcome)>,
+}
+
+impl RegistrationReport {
+    /// Returns the names that were newly added.
+    pub fn added(&self) -> Vec<&str> {
+        self.names_where(|o| matches!(o, RegistrationOutcome::Added))
+    }
+
+    /// Returns the names whose previous supplier was replaced.
+    pub fn replaced(&self) -> Vec<&str> {
+        self.names_where(|o| matches!(o, RegistrationOutcome::Replaced))
+    }
+
+    /// Returns the rejected names together with the rejection reason.
+    pub fn rejected(&self) -> Vec<(&str, &str)> {
+        self.outcomes
+            .iter()
+            .filter_map(|(name, outcome)| match outcome {
+                RegistrationOutcome::Rejected(reason) => Some((name.as_str(), reason.as_str())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns `true` if every entry was added without replacing or rejecting anything.
+    pub fn is_clean(&self) -> bool {
+        self.outcomes.iter().all(|(_, o)| *o == RegistrationOutcome::Added)
+    }
+
+    fn names_where(&self, predicate: impl Fn(&RegistrationOutcome) -> bool) -> Vec<&str> {
+        self.outcomes
+            .iter()
+            .filter(|(_, outcome)| predicate(outcome))
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+}
+
+/// An immutable view of a `SupplierRegistry`, taken with `SupplierRegistry::snapshot`.
+///
+/// Clones share the same state. Lookups resolve aliases and normalize names like the
+/// registry did when the snapshot was taken, and queries publish the same events on the
+/// registry's bus. Deprecation notices are not sent for lookups through a snapshot.
+#[derive(Clone)]
+pub struct RegistrySnapshot {
+    state: Arc<SnapshotState>,
+}
+
+struct SnapshotState {
+    suppliers: HashMap<String, Arc<dyn Supplier>>,
+    aliases: HashMap<String, String>,
+    normalization: NameNormalization,
+    events: EventBus,
+}
+
+impl RegistrySnapshot {
+    /// Retrieves a supplier by its name or one of its aliases.
+    pub fn get(&self, name: &str) -> Option<Arc<dyn Supplier>> {
+        self.state.suppliers.get(&*self.resolve(name)).cloned()
+    }
+
+    /// Returns whether a supplier is registered under `name` or has `name` as an alias.
+    pub fn contains(&self, name: &str) -> bool {
+        self.state.suppliers.contains_key(&*self.resolve(name))
+    }
+
+    /// Returns the registered name behind `name`, see [`SupplierRegistry::resolve`].
+    pub fn resolve<'a>(&'a self, name: &'a str) -> Cow<'a, str> {
+        resolve_name(&self.state.normalization, &self.state.aliases, name)
+    }
+
+    /// Retrieves all the names of the suppliers in the snapshot, without aliases.
+    pub fn all_names(&self) -> Vec<String> {
+        self.state.suppliers.keys().cloned().collect()
+    }
+
+    /// Returns the number of suppliers in the snapshot.
+    pub fn len(&self) -> usize {
+        self.state.suppliers.len()
+    }
+
+    /// Returns `true` if the snapshot holds no suppliers.
+    pub fn is_empty(&self) -> bool {
+        self.state.suppliers.is_empty()
+    }
+
+    /// Describes every supplier in the snapshot, keyed by its registered name.
+    pub fn describe_all(&self) -> BTreeMap<String, SupplierDescriptor> {
+        self.state
+            .suppliers
+            .iter()
+            .map(|(name, supplier)| (name.clone(), supplier.describe()))
+            .collect()
+    }
+
+    /// Queries a supplier by name, like [`SupplierRegistry::query`].
+    ///
+    /// # Errors
+    /// Returns `SupplierError::NotFound` if no supplier is registered under `name`, or any
+    /// error returned by the supplier itself.
+    pub fn query(&self, name: &str, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let name = self.resolve(name);
+        let supplier = self.state.suppliers.get(&*name).ok_or(SupplierError::NotFound)?;
+        let operation = request.operation.as_str().to_string();
+        self.state.events.query_started(None, &name, &operation);
+        let started = Instant::now();
+        let result = query_isolated(supplier.as_ref(), request);
+        self.state
+            .events
+            .query_finished(None, &name, &operation, &result, started.elapsed());
+        result
+    }
 }
 
+/// A callback receiving deprecation notices from a `SupplierRegistry`.
+pub type DeprecationListener = Arc<dyn Fn(&DeprecationNotice) + Send + Sync>;
+
 impl SupplierRegistry {
     /// Creates a new, empty supplier registry.
     ///
@@ -129,13 +660,16 @@ impl SupplierRegistry {
     /// let registry = SupplierRegistry::new();
     /// ```
     pub fn new() -> Self {
-        Self {
-            suppliers: std::collections::HashMap::new(),
-        }
+        Self::default()
     }
 
     /// Registers a new supplier with the given name.
     ///
+    /// If the name is taken, the registry's [`DuplicatePolicy`] decides whether the new supplier
+    /// replaces the registered one; the returned outcome tells which happened. A name that is an
+    /// alias is always taken over by the new supplier. The name is stored in the form given by
+    /// the registry's [`NameNormalization`].
+    ///
     /// # Parameters
     /// - `name`: The name of the supplier to register.
     /// - `supplier`: The supplier instance to register.
@@ -167,12 +701,10 @@ impl SupplierRegistry {
     ///         if self.should_fail {
     ///             Err(SupplierError::Internal(format!("{} failed", self.name)))
     ///         } else {
-    ///             Ok(SupplierResponse {
-    ///                 data: json!({
-    ///                     "supplier": self.name,
-    ///                     "params": request.params
-    ///                 }),
-    ///             })
+    ///             Ok(SupplierResponse::new(json!({
+    ///                 "supplier": self.name,
+    ///                 "params": request.params
+    ///             })))
     ///         }
     ///     }
     /// }
@@ -180,14 +712,177 @@ impl SupplierRegistry {
     /// registry.register("my_supplier", MySupplier {name: "my_supplier".to_string(), should_fail: false});
     ///
     /// ```
-    pub fn register<S>(&mut self, name: &str, supplier: S)
+    pub fn register<S>(&mut self, name: &str, supplier: S) -> RegistrationOutcome
     where
         S: Supplier + 'static,
     {
-        self.suppliers.insert(name.to_string(), Arc::new(supplier));
+        self.insert(name, Arc::new(supplier))
+    }
+
+    /// Registers a supplier only if `name` is free, whatever the duplicate policy.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::AlreadyExists` if a supplier or alias already uses `name`.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::supplier::SupplierRegistry;
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut registry = SupplierRegistry::new();
+    /// registry.try_register("shop", MockSupplierBuilder::new("shop").build()).unwrap();
+    /// let again = registry.try_register("shop", MockSupplierBuilder::new("shop").build());
+    /// assert!(matches!(again, Err(SupplierError::AlreadyExists(name)) if name == "shop"));
+    /// ```
+    pub fn try_register<S>(&mut self, name: &str, supplier: S) -> Result<(), SupplierError>
+    where
+        S: Supplier + 'static,
+    {
+        if self.contains(name) {
+            return Err(SupplierError::AlreadyExists(name.to_string()));
+        }
+        self.insert(name, Arc::new(supplier));
+        Ok(())
+    }
+
+    /// Sets how [`SupplierRegistry::register`] and [`SupplierRegistry::register_all`] treat
+    /// names that are already taken.
+    pub fn set_duplicate_policy(&mut self, policy: DuplicatePolicy) {
+        self.duplicate_policy = policy;
+    }
+
+    /// Returns the policy for names that are already taken.
+    pub fn duplicate_policy(&self) -> DuplicatePolicy {
+        self.duplicate_policy
+    }
+
+    /// Sets how names are normalized on registration and lookup.
+    ///
+    /// Names and aliases that are already registered are normalized too, so the setting can be
+    /// changed at any time.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::AlreadyExists` if two registered names or aliases would become
+    /// the same name, in which case nothing changes.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::supplier::{NameNormalization, SupplierRegistry};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut registry = SupplierRegistry::new();
+    /// registry.register("Amazon", MockSupplierBuilder::new("amazon").build());
+    /// registry.set_name_normalization(NameNormalization::all()).unwrap();
+    ///
+    /// assert!(registry.get("amazon ").is_some());
+    /// assert_eq!(registry.all_names(), ["amazon"]);
+    /// ```
+    pub fn set_name_normalization(&mut self, normalization: NameNormalization) -> Result<(), SupplierError> {
+        let key = |name: &String| normalization.apply(name).into_owned();
+        let mut seen = HashSet::new();
+        if let Some(name) = self.suppliers.keys().chain(self.aliases.keys()).find(|name| !seen.insert(key(name))) {
+            return Err(SupplierError::AlreadyExists(key(name)));
+        }
+
+        self.normalization = normalization;
+        self.suppliers = self.suppliers.drain().map(|(name, supplier)| (key(&name), supplier)).collect();
+        self.aliases = self.aliases.drain().map(|(alias, target)| (key(&alias), key(&target))).collect();
+        self.deprecations = self.deprecations.drain().map(|(name, d)| (key(&name), d)).collect();
+        self.auth = self.auth.drain().map(|(name, auth)| (key(&name), auth)).collect();
+        let reached = self.reached_milestones.get_mut().unwrap_or_else(|e| e.into_inner());
+        *reached = reached.drain().map(|(name, milestone)| (key(&name), milestone)).collect();
+        Ok(())
+    }
+
+    /// Returns how names are normalized on registration and lookup.
+    pub fn name_normalization(&self) -> NameNormalization {
+        self.normalization
+    }
+
+    /// Registers many already shared suppliers at once and reports what happened to each name.
+    ///
+    /// Entries are processed in iteration order. Names that are empty or consist only of
+    /// whitespace are rejected; a name that is already taken (including earlier in the same
+    /// batch) is handled by the registry's [`DuplicatePolicy`].
+    ///
+    /// # Returns
+    /// A `RegistrationReport` with one outcome per entry, in iteration order.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::models::{SupplierRequest, SupplierResponse};
+    /// use supplier_kit::supplier::{RegistrationOutcome, Supplier, SupplierRegistry};
+    ///
+    /// struct DummySupplier;
+    ///
+    /// impl Supplier for DummySupplier {
+    ///     fn name(&self) -> &str { "dummy" }
+    ///     fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+    ///         Err(SupplierError::Timeout)
+    ///     }
+    /// }
+    ///
+    /// let mut registry = SupplierRegistry::new();
+    /// let report = registry.register_all([
+    ///     ("a".to_string(), Arc::new(DummySupplier) as Arc<dyn Supplier>),
+    ///     ("a".to_string(), Arc::new(DummySupplier) as Arc<dyn Supplier>),
+    ///     (" ".to_string(), Arc::new(DummySupplier) as Arc<dyn Supplier>),
+    /// ]);
+    ///
+    /// assert_eq!(report.added(), vec!["a"]);
+    /// assert_eq!(report.replaced(), vec!["a"]);
+    /// assert_eq!(report.rejected().len(), 1);
+    /// ```
+    pub fn register_all<I>(&mut self, suppliers: I) -> RegistrationReport
+    where
+        I: IntoIterator<Item = (String, Arc<dyn Supplier>)>,
+    {
+        let outcomes = suppliers
+            .into_iter()
+            .map(|(name, supplier)| {
+                let outcome = if name.trim().is_empty() {
+                    RegistrationOutcome::Rejected("supplier name must not be empty".into())
+                } else {
+                    self.insert(&name, supplier)
+                };
+                (name, outcome)
+            })
+            .collect();
+
+        RegistrationReport { outcomes }
+    }
+
+    /// Removes a supplier, its aliases and its deprecation schedule, returning the supplier if it
+    /// was registered. `name` may be an alias.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::supplier::SupplierRegistry;
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut registry = SupplierRegistry::new();
+    /// registry.register("shop", MockSupplierBuilder::new("shop").build());
+    /// assert!(registry.remove("shop").is_some());
+    /// assert!(registry.get("shop").is_none());
+    /// ```
+    pub fn remove(&mut self, name: &str) -> Option<Arc<dyn Supplier>> {
+        let name = &self.resolve(name).into_owned();
+        let supplier = self.suppliers.remove(name)?;
+        self.aliases.retain(|_, target| target != name);
+        self.deprecations.remove(name);
+        self.auth.remove(name);
+        self.critical.remove(name);
+        self.reached_milestones.lock().unwrap_or_else(|e| e.into_inner()).remove(name);
+        self.events.publish(SupplierEvent::Removed {
+            supplier: name.to_string(),
+        });
+        Some(supplier)
     }
 
-    /// Retrieves a supplier by its name.
+    /// Retrieves a supplier by its name or one of its aliases.
     ///
     /// # Parameters
     /// - `name`: The name of the supplier to retrieve.
@@ -203,11 +898,94 @@ impl SupplierRegistry {
     /// let supplier = registry.get("my_supplier");
     /// ```
     pub fn get(&self, name: &str) -> Option<Arc<dyn Supplier>> {
-        self.suppliers.get(name).cloned()
+        let name = self.resolve(name);
+        let supplier = self.suppliers.get(&*name).cloned()?;
+        self.notify_deprecated_use(&name);
+        Some(supplier)
+    }
+
+    /// Retrieves a supplier like [`SupplierRegistry::get`], without counting as using a
+    /// deprecated supplier.
+    pub(crate) fn lookup(&self, name: &str) -> Option<Arc<dyn Supplier>> {
+        self.suppliers.get(&*self.resolve(name)).cloned()
+    }
+
+    /// Returns whether a supplier is registered under `name` or has `name` as an alias.
+    ///
+    /// Unlike [`SupplierRegistry::get`], this does not count as using a deprecated supplier.
+    pub fn contains(&self, name: &str) -> bool {
+        self.suppliers.contains_key(&*self.resolve(name))
+    }
+
+    /// Makes `alias` another name of the supplier registered as `target`.
+    ///
+    /// Lookups, queries and removals accept the alias wherever they accept a supplier name,
+    /// while events and [`SupplierRegistry::all_names`] keep using the registered name. `target`
+    /// may itself be an alias; the new alias then points at the supplier it names. Pointing an
+    /// existing alias elsewhere is allowed, and registering a supplier under an alias's name
+    /// replaces the alias.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::NotFound` if `target` names no supplier, and
+    /// `SupplierError::InvalidInput` if `alias` is empty or is the name of a registered supplier.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::supplier::SupplierRegistry;
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut registry = SupplierRegistry::new();
+    /// registry.register("amazon", MockSupplierBuilder::new("amazon").build());
+    /// registry.alias("amzn", "amazon").unwrap();
+    ///
+    /// assert_eq!(registry.get("amzn").unwrap().name(), "amazon");
+    /// assert_eq!(registry.all_names(), ["amazon"]);
+    /// assert_eq!(registry.aliases_of("amazon"), ["amzn"]);
+    /// ```
+    pub fn alias(&mut self, alias: &str, target: &str) -> Result<(), SupplierError> {
+        let alias = self.normalization.apply(alias).into_owned();
+        if alias.trim().is_empty() {
+            return Err(SupplierError::InvalidInput("supplier alias must not be empty".into()));
+        }
+        if self.suppliers.contains_key(&alias) {
+            return Err(SupplierError::InvalidInput(format!("'{}' is a registered supplier, not an alias", alias)));
+        }
+        let target = self.resolve(target).into_owned();
+        if !self.suppliers.contains_key(&target) {
+            return Err(SupplierError::NotFound);
+        }
+        self.aliases.insert(alias, target);
+        Ok(())
+    }
+
+    /// Removes `alias`, returning the name of the supplier it pointed at.
+    pub fn unalias(&mut self, alias: &str) -> Option<String> {
+        self.aliases.remove(&*self.normalization.apply(alias))
+    }
+
+    /// Returns the aliases of supplier `name`, sorted.
+    pub fn aliases_of(&self, name: &str) -> Vec<String> {
+        let name = self.resolve(name);
+        let mut aliases: Vec<String> = self
+            .aliases
+            .iter()
+            .filter(|(_, target)| **target == name)
+            .map(|(alias, _)| alias.clone())
+            .collect();
+        aliases.sort();
+        aliases
+    }
+
+    /// Returns the registered name behind `name`: the alias target, or `name` itself, after
+    /// applying the registry's [`NameNormalization`].
+    pub fn resolve<'a>(&'a self, name: &'a str) -> Cow<'a, str> {
+        resolve_name(&self.normalization, &self.aliases, name)
     }
 
     /// Retrieves all the names of the registered suppliers.
     ///
+    /// Aliases are not listed; see [`SupplierRegistry::aliases_of`].
+    ///
     /// # Returns
     /// A vector of all the supplier names in the registry.
     ///
@@ -238,12 +1016,10 @@ impl SupplierRegistry {
     ///         if self.should_fail {
     ///             Err(SupplierError::Internal(format!("{} failed", self.name)))
     ///         } else {
-    ///             Ok(SupplierResponse {
-    ///                 data: json!({
-    ///                     "supplier": self.name,
-    ///                     "params": request.params
-    ///                 }),
-    ///             })
+    ///             Ok(SupplierResponse::new(json!({
+    ///                 "supplier": self.name,
+    ///                 "params": request.params
+    ///             })))
     ///         }
     ///     }
     /// }
@@ -256,4 +1032,395 @@ impl SupplierRegistry {
     pub fn all_names(&self) -> Vec<String> {
         self.suppliers.keys().cloned().collect()
     }
+
+    /// Describes every registered supplier, keyed by its registered name.
+    ///
+    /// Each entry is the supplier's own [`Supplier::describe`] output; the map is ordered by name
+    /// so the result is stable for admin endpoints and generated documentation.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::models::{SupplierRequest, SupplierResponse};
+    /// use supplier_kit::supplier::{Supplier, SupplierRegistry};
+    ///
+    /// struct Shop;
+    ///
+    /// impl Supplier for Shop {
+    ///     fn name(&self) -> &str { "shop" }
+    ///     fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+    ///         Ok(SupplierResponse::new(serde_json::json!([])))
+    ///     }
+    /// }
+    ///
+    /// let mut registry = SupplierRegistry::new();
+    /// registry.register("shop_b", Shop);
+    /// registry.register("shop_a", Shop);
+    ///
+    /// let descriptors = registry.describe_all();
+    /// assert_eq!(descriptors.keys().collect::<Vec<_>>(), ["shop_a", "shop_b"]);
+    /// assert_eq!(descriptors["shop_a"].name, "shop");
+    /// ```
+    pub fn describe_all(&self) -> BTreeMap<String, SupplierDescriptor> {
+        self.suppliers
+            .iter()
+            .map(|(name, supplier)| (name.clone(), supplier.describe()))
+            .collect()
+    }
+
+    /// Takes an immutable view of the registered suppliers and aliases.
+    ///
+    /// The snapshot is unaffected by later changes to the registry and is cheap to clone, so
+    /// hot query paths can share it between threads without locking the live registry, which
+    /// can then sit behind a `RwLock` and be swapped or mutated freely.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_json::json;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier::SupplierRegistry;
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut registry = SupplierRegistry::new();
+    /// registry.register("shop", MockSupplierBuilder::new("shop").respond_default(json!({ "ok": true })).build());
+    /// let snapshot = registry.snapshot();
+    /// registry.remove("shop");
+    ///
+    /// let request = SupplierRequest::new(SupplierOperation::Search, json!({}));
+    /// assert_eq!(snapshot.query("shop", request).unwrap().data["ok"], true);
+    /// assert!(registry.get("shop").is_none());
+    /// ```
+    pub fn snapshot(&self) -> RegistrySnapshot {
+        RegistrySnapshot {
+            state: Arc::new(SnapshotState {
+                suppliers: self.suppliers.clone(),
+                aliases: self.aliases.clone(),
+                normalization: self.normalization,
+                events: self.events.clone(),
+            }),
+        }
+    }
+
+    /// Queries a registered supplier by name.
+    ///
+    /// Panics raised by the supplier are caught and reported as `SupplierError::Internal`
+    /// (see [`query_isolated`]).
+    ///
+    /// # Returns
+    /// - `Ok(SupplierResponse)`: The supplier's response.
+    /// - `Err(SupplierError::NotFound)`: If no supplier is registered under `name`.
+    /// - `Err(SupplierError)`: Any error returned by the supplier itself.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier::SupplierRegistry;
+    /// let registry = SupplierRegistry::new();
+    /// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}));
+    /// assert!(matches!(registry.query("missing", request), Err(SupplierError::NotFound)));
+    /// ```
+    pub fn query(&self, name: &str, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let name = self.resolve(name);
+        let supplier = self.get(&name).ok_or(SupplierError::NotFound)?;
+        let operation = request.operation.as_str().to_string();
+        self.events.query_started(None, &name, &operation);
+        let started = Instant::now();
+        let result = query_isolated(supplier.as_ref(), request);
+        self.events
+            .query_finished(None, &name, &operation, &result, started.elapsed());
+        result
+    }
+
+    /// Records the rotating auth shared with supplier `name`, so its credentials can be
+    /// rotated through the registry.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::NotFound` if no supplier is registered under `name`.
+    pub fn register_auth(&mut self, name: &str, auth: Arc<RotatingAuth>) -> Result<(), SupplierError> {
+        let name = self.resolve(name).into_owned();
+        if !self.suppliers.contains_key(&name) {
+            return Err(SupplierError::NotFound);
+        }
+        self.auth.insert(name, auth);
+        Ok(())
+    }
+
+    /// Returns the rotating auth registered for supplier `name`.
+    pub fn auth(&self, name: &str) -> Option<Arc<RotatingAuth>> {
+        self.auth.get(&*self.resolve(name)).cloned()
+    }
+
+    /// Swaps the auth provider of a live supplier and publishes `SupplierEvent::CredentialsRotated`.
+    ///
+    /// Calls in flight finish with the credential they already took; no supplier is rebuilt.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::NotFound` if no auth is registered for `name`, or the new
+    /// provider's error if it cannot produce a credential, in which case nothing changes.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use supplier_kit::auth::{AuthenticatedSupplier, Credential, RotatingAuth};
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+    /// use supplier_kit::supplier::SupplierRegistry;
+    ///
+    /// let auth = Arc::new(RotatingAuth::new(Arc::new(Credential::Bearer("old".into()))));
+    /// let partner = AuthenticatedSupplier::new("partner", auth.clone(), |credential, _| {
+    ///     Ok(SupplierResponse::new(serde_json::json!({ "auth": credential.header().1 })))
+    /// });
+    ///
+    /// let mut registry = SupplierRegistry::new();
+    /// registry.register("partner", partner);
+    /// registry.register_auth("partner", auth).unwrap();
+    /// registry.rotate_auth("partner", Arc::new(Credential::Bearer("new".into()))).unwrap();
+    ///
+    /// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}));
+    /// assert_eq!(registry.query("partner", request).unwrap().data["auth"], "Bearer new");
+    /// ```
+    pub fn rotate_auth(&self, name: &str, provider: Arc<dyn AuthProvider>) -> Result<u64, SupplierError> {
+        let name = self.resolve(name);
+        let auth = self.auth.get(&*name).ok_or(SupplierError::NotFound)?;
+        let generation = auth.rotate(provider)?;
+        self.events.publish(SupplierEvent::CredentialsRotated {
+            supplier: name.to_string(),
+            generation,
+        });
+        Ok(generation)
+    }
+
+    /// Publishes registry events, and the query events of `SupplierRegistry::query`, on `bus`
+    /// instead of the registry's own bus.
+    pub fn set_event_bus(&mut self, bus: EventBus) {
+        self.events = bus;
+    }
+
+    /// Returns the bus the registry publishes on, for subscribing.
+    pub fn events(&self) -> &EventBus {
+        &self.events
+    }
+
+    /// Marks a registered supplier as critical for `SupplierRegistry::readiness`, or unmarks it.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::NotFound` if no supplier is registered under `name`.
+    pub fn set_critical(&mut self, name: &str, critical: bool) -> Result<(), SupplierError> {
+        let name = self.resolve(name).into_owned();
+        if !self.suppliers.contains_key(&name) {
+            return Err(SupplierError::NotFound);
+        }
+        if critical {
+            self.critical.insert(name);
+        } else {
+            self.critical.remove(&name);
+        }
+        Ok(())
+    }
+
+    /// Returns the names of the suppliers marked critical, sorted.
+    pub fn critical_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.critical.iter().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Makes `SupplierRegistry::readiness` pass once `count` critical suppliers are healthy,
+    /// instead of requiring all of them.
+    pub fn set_min_ready(&mut self, count: usize) {
+        self.min_ready = Some(count);
+    }
+
+    /// Consults `health`, usually kept up to date by a `HealthMonitor`, in the readiness and
+    /// liveness probes.
+    ///
+    /// With `max_check_age`, liveness fails once a supplier's latest check is older than
+    /// that, which means the monitor has stopped.
+    pub fn set_health_map(&mut self, health: Arc<HealthMap>, max_check_age: Option<Duration>) {
+        self.health = Some(health);
+        self.max_check_age = max_check_age;
+    }
+
+    /// Returns whether the supplier registered under `name`, or with `name` as an alias, counts
+    /// as healthy in [`SupplierRegistry::readiness`]: it is ready and the attached health map,
+    /// if any, does not report it unhealthy.
+    ///
+    /// Unlike [`SupplierRegistry::get`], this does not count as using a deprecated supplier.
+    pub fn is_healthy(&self, name: &str) -> bool {
+        let name = self.resolve(name);
+        self.suppliers.get(&*name).is_some_and(|supplier| supplier.is_ready())
+            && self.health.as_ref().is_none_or(|health| health.is_healthy(&name))
+    }
+
+    /// Reports whether the registry can serve traffic, for readiness probes.
+    ///
+    /// The critical suppliers (every supplier, when none is marked with
+    /// `SupplierRegistry::set_critical`) are healthy when they are ready and the attached
+    /// health map, if any, does not report them unhealthy. The probe passes when at least
+    /// `SupplierRegistry::set_min_ready` of them, by default all, are healthy.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::supplier::SupplierRegistry;
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut registry = SupplierRegistry::new();
+    /// registry.register("shop", MockSupplierBuilder::new("shop").build());
+    /// registry.set_critical("shop", true).unwrap();
+    ///
+    /// let readiness = registry.readiness();
+    /// assert!(readiness.ok);
+    /// assert_eq!(readiness.passing, vec!["shop".to_string()]);
+    /// ```
+    pub fn readiness(&self) -> ProbeReport {
+        let considered: Vec<&String> = match self.critical.is_empty() {
+            true => self.suppliers.keys().collect(),
+            false => self.critical.iter().collect(),
+        };
+        let (passing, failing): (Vec<String>, Vec<String>) =
+            considered.iter().map(|name| name.to_string()).partition(|name| self.is_healthy(name));
+        let required = self.min_ready.unwrap_or(considered.len());
+        ProbeReport::new(required, passing, failing, |healthy| {
+            format!("{} of {} required critical suppliers are healthy", healthy, required)
+        })
+    }
+
+    /// Reports whether the process is alive, for liveness probes.
+    ///
+    /// Supplier outages never fail liveness, since restarting the process does not fix them.
+    /// It only fails when the attached health map has a check older than its maximum age,
+    /// i.e. the health monitor stopped; suppliers never checked are not considered.
+    pub fn liveness(&self) -> ProbeReport {
+        let (Some(health), Some(max_age)) = (&self.health, self.max_check_age) else {
+            return ProbeReport::new(0, Vec::new(), Vec::new(), |_| String::new());
+        };
+        let now_ms = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or_default();
+        let checked: Vec<(String, u64)> = self
+            .suppliers
+            .keys()
+            .filter_map(|name| health.get(name).map(|h| (name.clone(), h.checked_at_ms)))
+            .collect();
+        let required = checked.len();
+        let (passing, failing): (Vec<_>, Vec<_>) =
+            checked.into_iter().partition(|(_, at)| now_ms.saturating_sub(*at) <= max_age.as_millis() as u64);
+        ProbeReport::new(
+            required,
+            passing.into_iter().map(|(name, _)| name).collect(),
+            failing.into_iter().map(|(name, _)| name).collect(),
+            |fresh| format!("{} of {} health checks are older than {:?}", required - fresh, required, max_age),
+        )
+    }
+
+    /// Marks a registered supplier as deprecated with the given sunset schedule.
+    ///
+    /// From then on, every lookup of the supplier emits a `DeprecationNotice::Used` to the
+    /// registered listener, and a `DeprecationNotice::MilestoneReached` the first time each
+    /// lifecycle stage is observed.
+    ///
+    /// # Returns
+    /// `Err(SupplierError::NotFound)` if no supplier is registered under `name`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use supplier_kit::deprecation::Deprecation;
+    /// use supplier_kit::supplier::SupplierRegistry;
+    ///
+    /// let mut registry = SupplierRegistry::new();
+    /// let deprecation = Deprecation::new(SystemTime::now(), Duration::from_secs(60));
+    /// assert!(registry.deprecate("missing", deprecation).is_err());
+    /// ```
+    pub fn deprecate(&mut self, name: &str, deprecation: Deprecation) -> Result<(), SupplierError> {
+        let name = self.resolve(name).into_owned();
+        if !self.suppliers.contains_key(&name) {
+            return Err(SupplierError::NotFound);
+        }
+        self.deprecations.insert(name, deprecation);
+        Ok(())
+    }
+
+    /// Returns the deprecation schedule of a supplier, if it is deprecated.
+    pub fn deprecation(&self, name: &str) -> Option<&Deprecation> {
+        self.deprecations.get(&*self.resolve(name))
+    }
+
+    /// Returns the routing weight multiplier of a supplier at the current time.
+    ///
+    /// Suppliers that are not deprecated always have a weight of `1.0`;
+    /// deprecated ones ramp down to `0.0` at their sunset date.
+    pub fn deprecation_weight(&self, name: &str) -> f64 {
+        self.deprecations
+            .get(&*self.resolve(name))
+            .map_or(1.0, |d| d.weight_at(SystemTime::now()))
+    }
+
+    /// Sets the listener that receives deprecation notices.
+    pub fn on_deprecation<F>(&mut self, listener: F)
+    where
+        F: Fn(&DeprecationNotice) + Send + Sync + 'static,
+    {
+        self.deprecation_listener = Some(Arc::new(listener));
+    }
+
+    /// Registers `supplier` under `name` as the duplicate policy allows.
+    fn insert(&mut self, name: &str, supplier: Arc<dyn Supplier>) -> RegistrationOutcome {
+        let name = self.normalization.apply(name).into_owned();
+        let name = name.as_str();
+        let outcome = match self.suppliers.get(name) {
+            // The name now refers to the new supplier instead of the alias target.
+            None if self.aliases.remove(name).is_some() => RegistrationOutcome::Replaced,
+            None => RegistrationOutcome::Added,
+            Some(_) if self.duplicate_policy == DuplicatePolicy::Overwrite => RegistrationOutcome::Replaced,
+            Some(_) if self.duplicate_policy == DuplicatePolicy::Reject => {
+                RegistrationOutcome::Rejected(format!("'{}' is already registered", name))
+            }
+            Some(registered) => {
+                let (current, candidate) = (registered.describe().version, supplier.describe().version);
+                if version_key(candidate.as_deref()) > version_key(current.as_deref()) {
+                    RegistrationOutcome::Replaced
+                } else {
+                    RegistrationOutcome::Rejected(format!(
+                        "version {} is not newer than the registered version {}",
+                        candidate.as_deref().unwrap_or("(none)"),
+                        current.as_deref().unwrap_or("(none)")
+                    ))
+                }
+            }
+        };
+        if !matches!(outcome, RegistrationOutcome::Rejected(_)) {
+            self.suppliers.insert(name.to_string(), supplier);
+            self.publish_registered(name);
+        }
+        outcome
+    }
+
+    fn publish_registered(&self, name: &str) {
+        self.events.publish(SupplierEvent::Registered {
+            supplier: name.to_string(),
+        });
+    }
+
+    fn notify_deprecated_use(&self, name: &str) {
+        let (Some(deprecation), Some(listener)) = (self.deprecations.get(name), &self.deprecation_listener) else {
+            return;
+        };
+
+        let milestone = deprecation.milestone_at(SystemTime::now());
+        let is_new_milestone = {
+            let mut reached = self.reached_milestones.lock().unwrap_or_else(|e| e.into_inner());
+            let previous = reached.insert(name.to_string(), milestone);
+            previous.is_none_or(|p| p < milestone)
+        };
+
+        if is_new_milestone {
+            listener(&DeprecationNotice::MilestoneReached {
+                supplier: name.to_string(),
+                milestone,
+            });
+        }
+        listener(&DeprecationNotice::Used {
+            supplier: name.to_string(),
+            deprecation: deprecation.clone(),
+        });
+    }
 }
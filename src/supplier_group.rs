@@ -1,18 +1,539 @@
-use std::sync::Arc;
-use crate::errors::SupplierError;
-use crate::models::{SupplierRequest, SupplierResponse};
-use crate::supplier::Supplier;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock, RwLockWriteGuard};
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use crate::access::AccessPolicy;
+use crate::aliases::OperationAliases;
+use crate::audit::AuditLog;
+use crate::balancing::{LoadTracker, SessionAffinity};
+use crate::composite::{CapturingSupplier, Captured};
+use crate::requirements::SupplierRequirements;
+use crate::routing::RoutingRules;
+use crate::context::Priority;
+use crate::descriptor::GroupDescriptor;
+use crate::errors::{SupplierError, SupplierGroupError};
+use crate::events::EventBus;
+use crate::id::{IdGenerator, UuidV7Generator};
+use crate::models::{ResponseSource, SupplierRequest, SupplierResponse};
+use crate::executor::{Executor, ThreadExecutor};
+use crate::execution::{dedupe_jobs, parallel_map, spawn_jobs, Call, Job, QueryHooks, QueryMemo};
+use crate::metrics::MetricsRecorder;
+use crate::health::HealthMap;
+use crate::outlier::{ErrorEjector, OutlierDetector};
+use crate::path::SupplierPath;
+use crate::reputation::ReputationTracker;
+use crate::shedding::{LoadPermit, LoadShedder};
+use crate::supplier::{panic_reason, warm_up_isolated, Supplier, SupplierRegistry};
+
+/// The least share of adaptive traffic a supplier's reputation can bring it down to.
+const MIN_REPUTATION_WEIGHT: f64 = 0.01;
 
 /// Represents the result of querying a group of suppliers.
 /// Contains both successful and failed responses for each supplier in the group.
+///
+/// `BasicSupplierGroup` lists the entries in supplier order, even under parallel strategies,
+/// unless configured otherwise with `BasicSupplierGroup::with_result_order`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SupplierGroupResult {
     /// A list of successful supplier queries, with each success containing the supplier's name and its response.
     pub successes: Vec<(String, SupplierResponse)>,
 
     /// A list of failed supplier queries, with each failure containing the supplier's name and the error encountered.
     pub failures: Vec<(String, SupplierError)>,
+
+    /// How each supplier call went, in the same order as the successes and failures.
+    ///
+    /// Filled by `BasicSupplierGroup` for every supplier it called or answered from an
+    /// earlier identical call; failures that never reached a supplier, such as shed queries
+    /// or unknown supplier names, have no outcome.
+    #[serde(default)]
+    pub outcomes: Vec<SupplierOutcome>,
+
+    /// Wall-clock time of the group query, in microseconds, including any retries made
+    /// through `SupplierGroupResult::retry_failures`. Zero when the group did not measure it.
+    #[serde(default)]
+    pub duration_us: u64,
+
+    /// The failed suppliers marked `SupplierSeverity::Critical` in the group, in failure order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub critical_failures: Vec<String>,
+
+    /// Why the query failed as a whole, set when the group's `PartialFailurePolicy` rejects
+    /// the failures or the query exceeded the group's deadline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<SupplierGroupError>,
+}
+
+/// Summary statistics of a `SupplierGroupResult`, see `SupplierGroupResult::report`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupQueryReport {
+    /// Wall-clock time of the group query, in microseconds.
+    pub duration_us: u64,
+
+    /// Number of suppliers that succeeded.
+    pub successes: usize,
+
+    /// Number of suppliers that failed.
+    pub failures: usize,
+
+    /// Share of suppliers that succeeded, from `0.0` to `1.0`; `0.0` when no supplier answered.
+    pub success_ratio: f64,
+
+    /// The supplier whose live call took the least time; reused and unmeasured entries are
+    /// not considered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fastest: Option<SupplierTiming>,
+
+    /// The supplier whose live call took the most time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slowest: Option<SupplierTiming>,
+
+    /// Number of failures per error code (see `SupplierError::code`).
+    #[serde(default)]
+    pub errors: BTreeMap<String, usize>,
+}
+
+impl GroupQueryReport {
+    /// Returns the wall-clock time of the group query.
+    pub fn duration(&self) -> Duration {
+        Duration::from_micros(self.duration_us)
+    }
+}
+
+/// Failures of a group query bucketed by error kind and message pattern, see
+/// `SupplierGroupResult::error_summary`.
+///
+/// Displays as one line, such as `7× timeout, 2× upstream error: HTTP #`, for logs and alerts
+/// that would otherwise repeat near-identical errors once per supplier.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorSummary {
+    /// Number of failures summarized.
+    pub total: usize,
+
+    /// The buckets, most frequent first; ties are ordered by code and pattern.
+    pub buckets: Vec<ErrorBucket>,
+}
+
+/// Failures sharing an error code and message pattern.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorBucket {
+    /// The error code (see `SupplierError::code`).
+    pub code: String,
+
+    /// The error message with numbers replaced by `#` and quoted text by `'*'`.
+    pub pattern: String,
+
+    /// Number of failures in the bucket.
+    pub count: usize,
+
+    /// The failed suppliers, in failure order.
+    pub suppliers: Vec<String>,
+}
+
+impl ErrorSummary {
+    /// Buckets `failures` by error code and message pattern.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::supplier_group::ErrorSummary;
+    ///
+    /// let failures = vec![
+    ///     ("a".to_string(), SupplierError::Upstream("HTTP 502".into())),
+    ///     ("b".to_string(), SupplierError::Timeout),
+    ///     ("c".to_string(), SupplierError::Upstream("HTTP 503".into())),
+    /// ];
+    /// let summary = ErrorSummary::from_failures(&failures);
+    /// assert_eq!(summary.to_string(), "2× upstream error: HTTP #, 1× timeout");
+    /// assert_eq!(summary.buckets[0].suppliers, vec!["a".to_string(), "c".to_string()]);
+    /// ```
+    pub fn from_failures(failures: &[(String, SupplierError)]) -> Self {
+        let mut buckets: Vec<ErrorBucket> = Vec::new();
+        for (supplier, error) in failures {
+            let pattern = error_pattern(&error.to_string());
+            match buckets.iter_mut().find(|b| b.code == error.code() && b.pattern == pattern) {
+                Some(bucket) => {
+                    bucket.count += 1;
+                    bucket.suppliers.push(supplier.clone());
+                }
+                None => buckets.push(ErrorBucket {
+                    code: error.code().to_string(),
+                    pattern,
+                    count: 1,
+                    suppliers: vec![supplier.clone()],
+                }),
+            }
+        }
+        buckets.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.code.cmp(&b.code)).then_with(|| a.pattern.cmp(&b.pattern)));
+        Self { total: failures.len(), buckets }
+    }
+
+    /// Returns whether there were no failures.
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+}
+
+impl fmt::Display for ErrorSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.buckets.is_empty() {
+            return write!(f, "no errors");
+        }
+        for (index, bucket) in self.buckets.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}× {}", bucket.count, bucket.pattern)?;
+        }
+        Ok(())
+    }
+}
+
+/// Replaces the parts of an error message that vary between occurrences: runs of digits
+/// become `#` and quoted text becomes `'*'`.
+fn error_pattern(message: &str) -> String {
+    let mut pattern = String::with_capacity(message.len());
+    let mut chars = message.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '0'..='9' => {
+                while chars.next_if(char::is_ascii_digit).is_some() {}
+                pattern.push('#');
+            }
+            '\'' | '"' if chars.clone().any(|next| next == c) => {
+                while chars.next().is_some_and(|next| next != c) {}
+                pattern.push_str("'*'");
+            }
+            _ => pattern.push(c),
+        }
+    }
+    pattern
+}
+
+/// A supplier and the time spent in its calls, in microseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SupplierTiming {
+    /// The supplier name.
+    pub supplier: String,
+
+    /// Time spent in the supplier's calls, in microseconds.
+    pub duration_us: u64,
+}
+
+/// Timing and provenance of one supplier's entry in a `SupplierGroupResult`.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let mut group = BasicSupplierGroup::new("marketplaces");
+/// group.add_supplier(MockSupplierBuilder::new("shop").respond_default(json!([])).build());
+///
+/// let result = group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+/// let outcome = result.outcome("shop").unwrap();
+/// assert!(outcome.succeeded);
+/// assert_eq!(outcome.attempts, 1);
+/// assert!(!outcome.from_cache);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SupplierOutcome {
+    /// The supplier name.
+    pub supplier: String,
+
+    /// Whether the supplier's latest attempt succeeded.
+    pub succeeded: bool,
+
+    /// How many times the group called the supplier for this result, including retries made
+    /// through `SupplierGroupResult::retry_failures`. Zero when the result was reused.
+    pub attempts: u32,
+
+    /// Time spent in the supplier's calls, in microseconds.
+    pub duration_us: u64,
+
+    /// Whether the response was served without a live call: reused from an identical call of
+    /// the same query, or marked as `ResponseSource::Cache` by a caching decorator.
+    pub from_cache: bool,
+}
+
+impl SupplierOutcome {
+    fn new(supplier: &str, call: &Call) -> Self {
+        let cached = matches!(&call.result, Ok(response) if response.source() == ResponseSource::Cache);
+        Self {
+            supplier: supplier.to_string(),
+            succeeded: call.result.is_ok(),
+            attempts: if call.reused { 0 } else { 1 },
+            duration_us: call.elapsed.as_micros() as u64,
+            from_cache: call.reused || cached,
+        }
+    }
+
+    /// Returns the time spent in the supplier's calls.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_micros(self.duration_us)
+    }
+}
+
+impl SupplierGroupResult {
+    /// Re-queries only the suppliers that failed and merges the new outcomes into this result.
+    ///
+    /// Suppliers that succeed on retry move from `failures` to `successes`; suppliers that fail
+    /// again keep a single failure entry carrying the latest error. Successful entries are never
+    /// re-queried, so calling this repeatedly is safe.
+    ///
+    /// Suppliers that failed with `SupplierError::Throttled` are not re-queried: retrying them
+    /// immediately ignores the wait they asked for. Their failures stay in place, and
+    /// `SupplierError::retry_after` tells when they may be queried again.
+    ///
+    /// Write operations (see `SupplierOperation::is_write`) are never re-queried, since a
+    /// failed write may still have reached the supplier; their failures stay in place.
+    ///
+    /// # Returns
+    /// The number of suppliers that recovered on this retry.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// let group = BasicSupplierGroup::new("group1");
+    /// let request = SupplierRequest::new(
+    ///     SupplierOperation::Search,
+    ///     serde_json::json!({"query": "item"}),
+    /// );
+    /// let mut result = group.query(request.clone());
+    /// assert_eq!(result.retry_failures(&group, request), 0);
+    /// ```
+    pub fn retry_failures(&mut self, group: &BasicSupplierGroup, request: SupplierRequest) -> usize {
+        if self.failures.is_empty() || request.operation.is_write() {
+            return 0;
+        }
+
+        let failed: Vec<String> = self
+            .failures
+            .iter()
+            .filter(|(_, error)| !matches!(error, SupplierError::Throttled { .. }))
+            .map(|(name, _)| name.clone())
+            .collect();
+        if failed.is_empty() {
+            return 0;
+        }
+        // Flattened failures are retried through the nested group wrapping them.
+        let retried_by = |supplier: &Arc<dyn Supplier>| {
+            failed
+                .iter()
+                .any(|name| name == supplier.name() || member_of(name).is_some_and(|member| member == supplier.name()))
+        };
+        let mut retried = group.query_where(request, retried_by);
+        self.duration_us += retried.duration_us;
+        // Retrying a nested group queries its members that succeeded again; keep their answers.
+        let succeeded = |name: &str| self.successes.iter().any(|(n, _)| n == name);
+        retried.successes.retain(|(name, _)| !succeeded(name));
+        retried.failures.retain(|(name, _)| !succeeded(name));
+
+        self.failures.retain(|(name, _)| !retried.contains(name));
+        let recovered = retried.successes.len();
+        self.successes.extend(retried.successes);
+        self.failures.extend(retried.failures);
+        for outcome in retried.outcomes {
+            match self.outcomes.iter_mut().find(|o| o.supplier == outcome.supplier) {
+                Some(previous) => {
+                    previous.succeeded = outcome.succeeded;
+                    previous.attempts += outcome.attempts;
+                    previous.duration_us += outcome.duration_us;
+                    previous.from_cache = outcome.from_cache;
+                }
+                None => self.outcomes.push(outcome),
+            }
+        }
+        if group.result_order == ResultOrder::Supplier {
+            group.sort_in_supplier_order(self);
+        }
+        group.assess(self);
+        recovered
+    }
+
+    /// Summarizes the result for dashboards and logs.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_json::json;
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut group = BasicSupplierGroup::new("marketplaces");
+    /// group.add_supplier(MockSupplierBuilder::new("a").respond_default(json!([])).build());
+    /// group.add_supplier(MockSupplierBuilder::new("b").then_fail(SupplierError::Timeout).build());
+    ///
+    /// let report = group.query(SupplierRequest::new(SupplierOperation::Search, json!({}))).report();
+    /// assert_eq!(report.success_ratio, 0.5);
+    /// assert_eq!(report.errors["timeout"], 1);
+    /// ```
+    pub fn report(&self) -> GroupQueryReport {
+        let live = || self.outcomes.iter().filter(|o| o.attempts > 0);
+        let timing = |o: &SupplierOutcome| SupplierTiming {
+            supplier: o.supplier.clone(),
+            duration_us: o.duration_us,
+        };
+        let total = self.successes.len() + self.failures.len();
+        let mut errors = BTreeMap::new();
+        for (_, error) in &self.failures {
+            *errors.entry(error.code().to_string()).or_insert(0) += 1;
+        }
+        GroupQueryReport {
+            duration_us: self.duration_us,
+            successes: self.successes.len(),
+            failures: self.failures.len(),
+            success_ratio: if total == 0 { 0.0 } else { self.successes.len() as f64 / total as f64 },
+            fastest: live().min_by_key(|o| o.duration_us).map(timing),
+            slowest: live().max_by_key(|o| o.duration_us).map(timing),
+            errors,
+        }
+    }
+
+    /// Returns whether a supplier marked `SupplierSeverity::Critical` failed.
+    pub fn has_critical_failure(&self) -> bool {
+        !self.critical_failures.is_empty()
+    }
+
+    /// Returns the result, or why it failed as a whole if the group's `PartialFailurePolicy`
+    /// or deadline rejected it.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_json::json;
+    /// use supplier_kit::errors::{SupplierError, SupplierGroupError};
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, PartialFailurePolicy, SupplierGroup, SupplierSeverity};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut group = BasicSupplierGroup::new("checkout").with_failure_policy(PartialFailurePolicy::FailOnCritical);
+    /// group.add_supplier(MockSupplierBuilder::new("inventory").then_fail(SupplierError::Timeout).build());
+    /// group.add_supplier(MockSupplierBuilder::new("reviews").then_fail(SupplierError::Timeout).build());
+    /// group.set_severity("inventory", SupplierSeverity::Critical).unwrap();
+    ///
+    /// let result = group.query(SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": 7 })));
+    /// assert_eq!(result.critical_failures, vec!["inventory".to_string()]);
+    /// let error = result.into_result().unwrap_err();
+    /// assert!(matches!(&error, SupplierGroupError::CriticalFailed { suppliers, .. } if suppliers == &["inventory"]));
+    /// assert!(matches!(error.supplier_error("inventory"), Some(SupplierError::Timeout)));
+    /// ```
+    pub fn into_result(self) -> Result<Self, SupplierGroupError> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self),
+        }
+    }
+
+    /// Buckets the failures by error kind and message pattern, e.g. `7× timeout, 2× unauthorized`.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_json::json;
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut group = BasicSupplierGroup::new("marketplaces");
+    /// for name in ["a", "b", "c"] {
+    ///     group.add_supplier(MockSupplierBuilder::new(name).then_fail(SupplierError::Timeout).build());
+    /// }
+    /// group.add_supplier(MockSupplierBuilder::new("d").then_fail(SupplierError::Unauthorized).build());
+    ///
+    /// let result = group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+    /// assert_eq!(result.error_summary().to_string(), "3× timeout, 1× unauthorized");
+    /// ```
+    pub fn error_summary(&self) -> ErrorSummary {
+        ErrorSummary::from_failures(&self.failures)
+    }
+
+    /// Returns the outcome recorded for supplier `name`, if it was called.
+    pub fn outcome(&self, name: &str) -> Option<&SupplierOutcome> {
+        self.outcomes.iter().find(|o| o.supplier == name)
+    }
+
+    /// Records the result of a call to supplier `name`.
+    fn record(&mut self, name: &str, call: Call) {
+        self.outcomes.push(SupplierOutcome::new(name, &call));
+        match call.result {
+            Ok(response) => self.successes.push((name.to_string(), response)),
+            Err(e) => self.failures.push((name.to_string(), e)),
+        }
+    }
+
+    /// Records the result of a call to a nested group `name` under the paths of its members,
+    /// or the call itself when the nested group reached no member.
+    fn record_nested(&mut self, name: &str, Captured { result: nested, flattened }: Captured, call: Call) {
+        let group = SupplierPath::new(name);
+        if nested.successes.is_empty() && nested.failures.is_empty() {
+            return self.record(&group.to_string(), call);
+        }
+        let leaf = |member: String| {
+            let member = match SupplierPath::parse(&member) {
+                Ok(path) if flattened => path,
+                _ => SupplierPath::new(&member),
+            };
+            group.join(&member).to_string()
+        };
+        self.outcomes.extend(nested.outcomes.into_iter().map(|outcome| SupplierOutcome {
+            supplier: leaf(outcome.supplier),
+            ..outcome
+        }));
+        self.successes.extend(nested.successes.into_iter().map(|(member, response)| (leaf(member), response)));
+        self.failures.extend(nested.failures.into_iter().map(|(member, error)| (leaf(member), error)));
+    }
+
+    /// Returns `true` if `name`, or a flattened entry under the member `name`, appears in
+    /// either the successes or the failures.
+    fn contains(&self, name: &str) -> bool {
+        let matches = |n: &str| n == name || member_of(n).is_some_and(|member| member == name);
+        self.successes.iter().any(|(n, _)| matches(n)) || self.failures.iter().any(|(n, _)| matches(n))
+    }
+}
+
+/// The result of a scatter-gather query whose successful responses were transformed inside
+/// the fan-out, as returned by `BasicSupplierGroup::query_transformed`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransformedGroupResult<T> {
+    /// The transformed output of every supplier that succeeded, with the supplier's name.
+    pub successes: Vec<(String, T)>,
+
+    /// The suppliers whose query or transform failed, with the error encountered.
+    pub failures: Vec<(String, SupplierError)>,
+
+    /// The failed suppliers marked `SupplierSeverity::Critical` in the group, in failure order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub critical_failures: Vec<String>,
+
+    /// Why the query failed as a whole, as in `SupplierGroupResult::error`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<SupplierGroupError>,
 }
 
+impl<T> TransformedGroupResult<T> {
+    /// Returns the result, or why it failed as a whole, like `SupplierGroupResult::into_result`.
+    pub fn into_result(self) -> Result<Self, SupplierGroupError> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self),
+        }
+    }
+}
+
+/// The transformed output of one result entry, or why it failed, with the entry's name.
+type TransformedOutput<T> = (String, Result<T, SupplierError>);
+
+/// An iterator over per-supplier outcomes, yielded as suppliers complete.
+pub type GroupResultStream<'a> = Box<dyn Iterator<Item = (String, Result<SupplierResponse, SupplierError>)> + Send + 'a>;
+
 /// A trait representing a group of suppliers. 
 /// A `SupplierGroup` can query all its suppliers and return their responses.
 pub trait SupplierGroup {
@@ -20,66 +541,1025 @@ pub trait SupplierGroup {
     ///
     /// # Example
     /// ```
-    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
-    /// let group = BasicSupplierGroup::new("group1");
-    /// assert_eq!(group.group_name(), "group1");
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// let group = BasicSupplierGroup::new("group1");
+    /// assert_eq!(group.group_name(), "group1");
+    /// ```
+    fn group_name(&self) -> &str;
+
+    /// Returns machine-readable metadata about the group.
+    ///
+    /// The default implementation describes the group by name only.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut group = BasicSupplierGroup::new("payments").with_strategy(QueryStrategy::Failover);
+    /// group.add_supplier(MockSupplierBuilder::new("stripe").build());
+    ///
+    /// let descriptor = group.describe();
+    /// assert_eq!(descriptor.strategy, Some(QueryStrategy::Failover));
+    /// assert_eq!(descriptor.suppliers, ["stripe"]);
+    /// ```
+    fn describe(&self) -> GroupDescriptor {
+        GroupDescriptor::new(self.group_name())
+    }
+
+    /// Returns whether results name their entries by `SupplierPath`, listing the members of
+    /// nested groups under hierarchical names. The default returns `false`.
+    fn flattens_results(&self) -> bool {
+        false
+    }
+
+    /// Queries all suppliers in the group with the provided request and returns the result of the query.
+    ///
+    /// # Parameters
+    /// - `request`: A `SupplierRequest` containing the operation and parameters for the query.
+    ///
+    /// # Returns
+    /// A `SupplierGroupResult` containing the successes and failures from querying the suppliers in the group.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// let group = BasicSupplierGroup::new("group1");
+    /// let request = SupplierRequest::new(
+    ///     SupplierOperation::Search,
+    ///     serde_json::json!({"query": "item"}),
+    /// );
+    /// let result = group.query(request);
+    /// ```
+    fn query(&self, request: SupplierRequest) -> SupplierGroupResult;
+
+    /// Queries all suppliers in the group and yields each supplier's outcome as soon as it is available.
+    ///
+    /// Unlike [`SupplierGroup::query`], callers do not have to wait for the slowest supplier
+    /// before rendering the first results. The default implementation runs [`SupplierGroup::query`]
+    /// and yields its successes followed by its failures.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// let group = BasicSupplierGroup::new("group1");
+    /// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({"query": "item"}));
+    /// for (name, result) in group.query_streamed(request) {
+    ///     println!("{}: {:?}", name, result.is_ok());
+    /// }
+    /// ```
+    fn query_streamed(&self, request: SupplierRequest) -> GroupResultStream<'_> {
+        let result = self.query(request);
+        let successes = result.successes.into_iter().map(|(name, r)| (name, Ok(r)));
+        let failures = result.failures.into_iter().map(|(name, e)| (name, Err(e)));
+        Box::new(successes.chain(failures))
+    }
+
+    /// Queries all suppliers in the group and hands each outcome to `callback` as it completes.
+    ///
+    /// Nothing is buffered, which makes this suitable for pushing partial results to
+    /// websockets or channels. Outcomes are delivered in the same order as
+    /// [`SupplierGroup::query_streamed`], on the calling thread.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// let group = BasicSupplierGroup::new("group1");
+    /// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({"query": "item"}));
+    /// let mut delivered = 0;
+    /// group.query_with_callback(request, &mut |_name, _result| delivered += 1);
+    /// assert_eq!(delivered, 0);
+    /// ```
+    fn query_with_callback(
+        &self,
+        request: SupplierRequest,
+        callback: &mut dyn FnMut(&str, &Result<SupplierResponse, SupplierError>),
+    ) {
+        for (name, result) in self.query_streamed(request) {
+            callback(&name, &result);
+        }
+    }
+
+    /// Queries all suppliers in the group with several requests at once.
+    ///
+    /// The default implementation calls [`SupplierGroup::query`] once per request.
+    /// Implementations should prefer [`Supplier::query_batch`] so that suppliers with
+    /// bulk endpoints receive the whole batch in a single call.
+    ///
+    /// # Returns
+    /// One `SupplierGroupResult` per request, in the same order as `requests`.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// let group = BasicSupplierGroup::new("group1");
+    /// let requests = vec![
+    ///     SupplierRequest::new(SupplierOperation::GetDetail, serde_json::json!({"id": 1})),
+    ///     SupplierRequest::new(SupplierOperation::GetDetail, serde_json::json!({"id": 2})),
+    /// ];
+    /// let results = group.query_batch(requests);
+    /// assert_eq!(results.len(), 2);
+    /// ```
+    fn query_batch(&self, requests: Vec<SupplierRequest>) -> Vec<SupplierGroupResult> {
+        requests.into_iter().map(|request| self.query(request)).collect()
+    }
+
+    /// Sends a distinct request to each named supplier in one call.
+    ///
+    /// Suppliers of the group that have no entry in `requests` are not queried.
+    /// Names that do not match any supplier in the group are reported as
+    /// `SupplierError::NotFound` failures.
+    ///
+    /// The default implementation cannot address individual suppliers and reports every
+    /// entry as `SupplierError::UnsupportedOperation`; `BasicSupplierGroup` overrides it.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// let group = BasicSupplierGroup::new("group1");
+    /// let mut requests = HashMap::new();
+    /// requests.insert("unknown".to_string(), SupplierRequest::new(
+    ///     SupplierOperation::Search,
+    ///     serde_json::json!({"query": "item"}),
+    /// ));
+    /// let result = group.query_each(requests);
+    /// assert!(matches!(result.failures[0].1, SupplierError::NotFound));
+    /// ```
+    fn query_each(&self, requests: HashMap<String, SupplierRequest>) -> SupplierGroupResult {
+        let mut names: Vec<String> = requests.into_keys().collect();
+        names.sort();
+        SupplierGroupResult {
+            successes: Vec::new(),
+            outcomes: Vec::new(),
+            duration_us: 0,
+            critical_failures: Vec::new(),
+            error: None,
+            failures: names
+                .into_iter()
+                .map(|name| {
+                    let error = SupplierError::UnsupportedOperation(format!(
+                        "group '{}' does not support per-supplier requests",
+                        self.group_name()
+                    ));
+                    (name, error)
+                })
+                .collect(),
+        }
+    }
+
+    /// Queries only the named suppliers of the group with `request`, e.g. for a user-facing
+    /// "only search these stores" filter, without building a group per request.
+    ///
+    /// Names that do not match any supplier in the group are reported as
+    /// `SupplierError::NotFound` failures. The default implementation sends `request` to each
+    /// named supplier through [`SupplierGroup::query_each`].
+    ///
+    /// # Example
+    /// ```
+    /// use serde_json::json;
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut group = BasicSupplierGroup::new("marketplaces");
+    /// for name in ["s1", "s2", "s3"] {
+    ///     group.add_supplier(MockSupplierBuilder::new(name).respond_default(json!([])).build());
+    /// }
+    ///
+    /// let request = SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }));
+    /// let result = group.query_subset(request, &["s1", "s3", "s9"]);
+    /// assert_eq!(result.successes.len(), 2);
+    /// assert!(matches!(&result.failures[..], [(name, SupplierError::NotFound)] if name == "s9"));
+    /// ```
+    fn query_subset(&self, request: SupplierRequest, suppliers: &[&str]) -> SupplierGroupResult {
+        self.query_each(suppliers.iter().map(|name| (name.to_string(), request.clone())).collect())
+    }
+}
+
+/// How one supplier of a group would handle a request, as reported by `BasicSupplierGroup::plan`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlannedSupplier {
+    /// The supplier name.
+    pub name: String,
+
+    /// Why fan-out queries would skip the supplier; `None` if it would be queried.
+    pub skip_reason: Option<String>,
+
+    /// Whether the supplier's descriptor declares the operation; `None` if it declares no operations.
+    pub declares_operation: Option<bool>,
+
+    /// Violations of the declared params schema of the operation.
+    pub param_errors: Vec<String>,
+}
+
+impl PlannedSupplier {
+    /// Plans `request` for a standalone supplier, which is only skipped when it is not ready.
+    pub fn new(supplier: &dyn Supplier, request: &SupplierRequest) -> Self {
+        let descriptor = supplier.describe();
+        let operation = descriptor.operations.iter().find(|o| o.operation == request.operation);
+        Self {
+            name: supplier.name().to_string(),
+            skip_reason: (!supplier.is_ready()).then(|| "not ready".to_string()),
+            declares_operation: (!descriptor.operations.is_empty()).then_some(operation.is_some()),
+            param_errors: operation.map(|o| o.validate_params(&request.params)).unwrap_or_default(),
+        }
+    }
+
+    /// Returns whether fan-out queries would call the supplier.
+    pub fn would_query(&self) -> bool {
+        self.skip_reason.is_none()
+    }
+}
+
+/// Determines how a `BasicSupplierGroup` executes the queries of its suppliers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryStrategy {
+    /// Query suppliers one after another, in insertion order.
+    #[default]
+    Sequential,
+    /// Query suppliers concurrently on scoped threads, bounded by the group's `max_concurrency`.
+    Parallel,
+    /// Query suppliers concurrently and keep only the first successful response.
+    ///
+    /// Failures that arrive before the first success are reported; slower suppliers are
+    /// abandoned and finish in the background.
+    Race,
+    /// Query suppliers one after another, in insertion order, until one succeeds.
+    ///
+    /// The failures of the suppliers tried before it are reported; later suppliers are not queried.
+    Failover,
+    /// Query a single supplier, picked by the power of two choices.
+    ///
+    /// Two suppliers are sampled at random and the one with the lower recent latency and fewer
+    /// calls in flight is queried (see `balancing::LoadTracker`). Meant for groups of
+    /// interchangeable replicas, where any one answer will do.
+    Adaptive,
+}
+
+/// The order in which a `BasicSupplierGroup` lists the entries of its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResultOrder {
+    /// Supplier order: descending priority, then insertion order, whatever the strategy and
+    /// however fast each supplier answered. Results of identical queries list their entries
+    /// identically, which keeps diffs and snapshot tests stable.
+    #[default]
+    Supplier,
+    /// The order in which the suppliers' calls finished.
+    Completion,
+}
+
+/// How much the failure of a supplier matters to the callers of a group.
+///
+/// Set per supplier with `BasicSupplierGroup::set_severity`; failures of critical suppliers
+/// are listed in `SupplierGroupResult::critical_failures`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SupplierSeverity {
+    /// The result is unusable without the supplier, e.g. inventory on a checkout page.
+    Critical,
+    /// The result degrades gracefully without the supplier, e.g. reviews on a product page.
+    #[default]
+    Optional,
+}
+
+/// Decides whether a group result with failed suppliers is reported as a
+/// `SupplierGroupError` in `SupplierGroupResult::error`.
+///
+/// Every policy but `Tolerate` fails with `SupplierGroupError::NoSuppliers` when no supplier
+/// was queried. `FailOnAny` and `Quorum` report `SupplierGroupError::AllFailed` rather than
+/// `SupplierGroupError::QuorumNotMet` when no supplier succeeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartialFailurePolicy {
+    /// Never set an overall error; callers inspect the failures themselves.
+    #[default]
+    Tolerate,
+    /// Fail with `SupplierGroupError::CriticalFailed` when a critical supplier failed.
+    FailOnCritical,
+    /// Fail when any queried supplier failed.
+    FailOnAny,
+    /// Fail with `SupplierGroupError::QuorumNotMet` when fewer suppliers succeeded than given.
+    Quorum(usize),
+}
+
+/// A basic implementation of a `SupplierGroup`, which can hold a list of suppliers 
+/// and perform queries against all of them.
+pub struct BasicSupplierGroup {
+    name: String,
+    suppliers: Vec<Arc<dyn Supplier>>,
+    strategy: QueryStrategy,
+    max_concurrency: Option<usize>,
+    executor: Arc<dyn Executor>,
+    id_generator: Arc<dyn IdGenerator>,
+    hooks: QueryHooks,
+    background_warm_up: bool,
+    shedder: Option<Arc<LoadShedder>>,
+    affinity: Option<Arc<SessionAffinity>>,
+    routing: Option<RoutingRules>,
+    aliases: Option<OperationAliases>,
+    // Priorities by supplier name; suppliers without one have priority 0.
+    priorities: RwLock<HashMap<String, i32>>,
+    severities: RwLock<HashMap<String, SupplierSeverity>>,
+    failure_policy: PartialFailurePolicy,
+    deadline: Option<Duration>,
+    result_order: ResultOrder,
+    flatten: bool,
+    // Suppliers (by `Arc` address) whose warm-up has not succeeded yet.
+    cold: Arc<Mutex<Vec<usize>>>,
+    health: Option<Arc<HealthMap>>,
+    deprecations: Option<Arc<SupplierRegistry>>,
+    requirements: Option<SupplierRequirements>,
+}
+
+impl BasicSupplierGroup {
+    /// Creates a new supplier group with the specified name.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the group.
+    ///
+    /// # Returns
+    /// A new `BasicSupplierGroup` instance.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// let group = BasicSupplierGroup::new("group1");
+    /// assert_eq!(group.group_name(), "group1");
+    /// ```
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            suppliers: vec![],
+            strategy: QueryStrategy::default(),
+            max_concurrency: None,
+            executor: Arc::new(ThreadExecutor),
+            id_generator: Arc::new(UuidV7Generator),
+            hooks: QueryHooks {
+                group: name.into(),
+                ..QueryHooks::default()
+            },
+            background_warm_up: false,
+            shedder: None,
+            affinity: None,
+            routing: None,
+            aliases: None,
+            priorities: RwLock::new(HashMap::new()),
+            severities: RwLock::new(HashMap::new()),
+            failure_policy: PartialFailurePolicy::default(),
+            deadline: None,
+            result_order: ResultOrder::default(),
+            flatten: false,
+            cold: Arc::new(Mutex::new(Vec::new())),
+            health: None,
+            deprecations: None,
+            requirements: None,
+        }
+    }
+
+    /// Sets the generator used to assign request IDs to requests that do not carry one.
+    ///
+    /// By default, groups assign UUIDv7 identifiers.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::id::SnowflakeGenerator;
+    /// use supplier_kit::supplier_group::BasicSupplierGroup;
+    /// let group = BasicSupplierGroup::new("group1").with_id_generator(SnowflakeGenerator::new(1));
+    /// ```
+    pub fn with_id_generator<G>(mut self, generator: G) -> Self
+    where
+        G: IdGenerator + 'static,
+    {
+        self.id_generator = Arc::new(generator);
+        self
+    }
+
+    /// Reports the outcome and latency of every supplier call to `tracker`, and routes by its
+    /// weights.
+    ///
+    /// `QueryStrategy::Adaptive` weighs its choice with each supplier's
+    /// `ReputationTracker::weight` (see `LoadTracker::choose_weighted`), and fan-out queries skip
+    /// suppliers the tracker ejects (see `ReputationTracker::with_ejection`); targeted
+    /// `query_each` requests still reach them. The tracker can be shared with other groups and
+    /// consulted for ranking.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use supplier_kit::reputation::ReputationTracker;
+    /// use supplier_kit::supplier_group::BasicSupplierGroup;
+    /// let tracker = Arc::new(ReputationTracker::default());
+    /// let group = BasicSupplierGroup::new("group1").with_reputation(tracker.clone());
+    /// ```
+    pub fn with_reputation(mut self, tracker: Arc<ReputationTracker>) -> Self {
+        self.hooks.reputation = Some(tracker);
+        self
+    }
+
+    /// Skips suppliers ejected by `detector` in fan-out queries and reports every supplier call to it.
+    ///
+    /// Ejected suppliers are left out of `query`, `query_streamed` and `query_batch` results
+    /// until their probation ends. Targeted `query_each` requests still reach them.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use supplier_kit::outlier::OutlierDetector;
+    /// use supplier_kit::supplier_group::BasicSupplierGroup;
+    /// let detector = Arc::new(OutlierDetector::new(0.5, Duration::from_secs(30)));
+    /// let group = BasicSupplierGroup::new("group1").with_outlier_detection(detector.clone());
+    /// ```
+    pub fn with_outlier_detection(mut self, detector: Arc<OutlierDetector>) -> Self {
+        self.hooks.outliers = Some(detector);
+        self
+    }
+
+    /// Skips suppliers ejected by `ejector` in fan-out queries and reports every supplier call to it.
+    ///
+    /// Use it next to `with_outlier_detection`: suppliers failing with errors that retries
+    /// cannot fix, such as `Unauthorized`, are ejected for growing windows instead of being
+    /// called again on every query. Targeted `query_each` requests still reach them.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use supplier_kit::outlier::ErrorEjector;
+    /// use supplier_kit::supplier_group::BasicSupplierGroup;
+    /// let ejector = Arc::new(ErrorEjector::new(Duration::from_secs(30), Duration::from_secs(3600)));
+    /// let group = BasicSupplierGroup::new("group1").with_error_ejection(ejector.clone());
+    /// ```
+    pub fn with_error_ejection(mut self, ejector: Arc<ErrorEjector>) -> Self {
+        self.hooks.error_ejection = Some(ejector);
+        self
+    }
+
+    /// Skips suppliers that `health` reports as unhealthy in fan-out queries.
+    ///
+    /// The map is usually kept up to date by a `HealthMonitor`; suppliers it has never
+    /// checked are queried. Targeted `query_each` requests still reach unhealthy suppliers.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use supplier_kit::health::HealthMap;
+    /// use supplier_kit::supplier_group::BasicSupplierGroup;
+    /// let health = Arc::new(HealthMap::new());
+    /// let group = BasicSupplierGroup::new("group1").with_health(health.clone());
+    /// ```
+    pub fn with_health(mut self, health: Arc<HealthMap>) -> Self {
+        self.health = Some(health);
+        self
+    }
+
+    /// Routes traffic away from suppliers that `registry` deprecates, following their
+    /// sunset schedules (see `SupplierRegistry::deprecate`).
+    ///
+    /// `QueryStrategy::Adaptive` weighs its choice with each supplier's
+    /// `SupplierRegistry::deprecation_weight` (see `LoadTracker::choose_weighted`), so deprecated
+    /// suppliers receive less and less traffic as their sunset nears.
+    /// Once a supplier's sunset date has passed, every fan-out query skips it; targeted
+    /// `query_each` requests still reach it. Suppliers are looked up by their own name.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use std::time::{Duration, SystemTime};
+    /// use serde_json::json;
+    /// use supplier_kit::deprecation::Deprecation;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier::SupplierRegistry;
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let legacy = MockSupplierBuilder::new("legacy").respond_default(json!([])).build();
+    /// let mut registry = SupplierRegistry::new();
+    /// registry.register("legacy", legacy.clone());
+    /// registry.deprecate("legacy", Deprecation::new(SystemTime::now(), Duration::from_secs(60))).unwrap();
+    ///
+    /// let mut group = BasicSupplierGroup::new("shops").with_deprecations(Arc::new(registry));
+    /// group.add_supplier(legacy.clone());
+    /// group.add_supplier(MockSupplierBuilder::new("current").respond_default(json!([])).build());
+    ///
+    /// let result = group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+    /// assert_eq!(result.successes.len(), 1);
+    /// assert_eq!(legacy.calls(), 0);
+    /// ```
+    pub fn with_deprecations(mut self, registry: Arc<SupplierRegistry>) -> Self {
+        self.deprecations = Some(registry);
+        self
+    }
+
+    /// Makes fan-out queries fail fast when they cannot reach the suppliers `requirements`
+    /// demand for their operation.
+    ///
+    /// A supplier counts as reachable when it is a member of the group that fan-out would
+    /// query: not cold, ready, healthy, not ejected and selected by the routing rules. An unmet
+    /// query queries no supplier and reports `SupplierError::InsufficientSuppliers` for every
+    /// unreachable required supplier, so an outage of the suppliers that matter never looks
+    /// like a successful, empty aggregation. `query_each` requests, which name their suppliers,
+    /// are not checked.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use serde_json::json;
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::health::HealthMap;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::requirements::{SupplierRequirement, SupplierRequirements};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let requirements = SupplierRequirements::new()
+    ///     .with_requirement(SupplierOperation::Search, SupplierRequirement::any_of(&["amazon", "ebay"]));
+    /// let health = Arc::new(HealthMap::new());
+    /// let mut group = BasicSupplierGroup::new("marketplaces")
+    ///     .with_health(health.clone())
+    ///     .with_requirements(requirements);
+    /// group.add_supplier(MockSupplierBuilder::new("amazon").respond_default(json!([])).build());
+    /// group.add_supplier(MockSupplierBuilder::new("ebay").respond_default(json!([])).build());
+    /// group.add_supplier(MockSupplierBuilder::new("etsy").respond_default(json!([])).build());
+    ///
+    /// let search = SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }));
+    /// health.record("amazon", Err(SupplierError::Timeout), Default::default(), 1);
+    /// assert_eq!(group.query(search.clone()).successes.len(), 2);
+    ///
+    /// health.record("ebay", Err(SupplierError::Timeout), Default::default(), 1);
+    /// let result = group.query(search);
+    /// assert!(result.successes.is_empty());
+    /// assert_eq!(result.failures.len(), 2);
+    /// assert!(matches!(result.failures[0].1, SupplierError::InsufficientSuppliers(_)));
+    /// ```
+    pub fn with_requirements(mut self, requirements: SupplierRequirements) -> Self {
+        self.requirements = Some(requirements);
+        self
+    }
+
+    /// Returns the supplier requirements of the group, if any.
+    pub fn requirements(&self) -> Option<&SupplierRequirements> {
+        self.requirements.as_ref()
+    }
+
+    /// Checks whether fan-out queries of `request` can currently reach the suppliers the
+    /// group's requirements demand, without querying any supplier.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::InsufficientSuppliers` explaining why each unreachable required
+    /// supplier is skipped.
+    pub fn check_requirements(&self, request: &SupplierRequest) -> Result<(), SupplierError> {
+        self.unmet_requirements(&self.resolved(request.clone())).map_or(Ok(()), |(_, error)| Err(error))
+    }
+
+    /// Returns the unreachable required suppliers of `request` and the error to report for
+    /// them, if its requirements are unmet.
+    fn unmet_requirements(&self, request: &SupplierRequest) -> Option<(Vec<String>, SupplierError)> {
+        let requirements = self.requirements.as_ref()?;
+        let members = self.ordered();
+        let unreachable = |name: &str| {
+            let reasons: Vec<Option<&str>> = members
+                .iter()
+                .filter(|s| s.name() == name)
+                .map(|s| self.exclusion_reason(s, request))
+                .collect();
+            match reasons.iter().any(Option::is_none) && !reasons.is_empty() {
+                true => None,
+                false => Some(reasons.into_iter().flatten().next().unwrap_or("not a member of the group").to_string()),
+            }
+        };
+        let error = requirements.check(&request.operation, unreachable).err()?;
+        let names = requirements
+            .required_suppliers(&request.operation)
+            .into_iter()
+            .filter(|name| unreachable(name).is_some())
+            .collect();
+        Some((names, error))
+    }
+
+    /// Reports every supplier call and group query to `recorder`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use supplier_kit::metrics::MetricsRecorder;
+    /// use supplier_kit::supplier_group::BasicSupplierGroup;
+    ///
+    /// struct Noop;
+    /// impl MetricsRecorder for Noop {}
+    ///
+    /// let group = BasicSupplierGroup::new("group1").with_metrics(Arc::new(Noop));
+    /// ```
+    pub fn with_metrics(mut self, recorder: Arc<dyn MetricsRecorder>) -> Self {
+        self.hooks.metrics = Some(recorder);
+        self
+    }
+
+    /// Records every supplier call of the group to `log`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use supplier_kit::audit::{AuditLog, JsonLinesSink};
+    /// use supplier_kit::supplier_group::BasicSupplierGroup;
+    /// let log = Arc::new(AuditLog::new(JsonLinesSink::stdout()));
+    /// let group = BasicSupplierGroup::new("group1").with_audit(log);
+    /// ```
+    pub fn with_audit(mut self, log: Arc<AuditLog>) -> Self {
+        self.hooks.audit = Some(log);
+        self
+    }
+
+    /// Publishes the group's query and ejection events on `bus` instead of the group's own bus,
+    /// e.g. to share one bus with a registry and other groups.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::events::EventBus;
+    /// use supplier_kit::supplier_group::BasicSupplierGroup;
+    /// let bus = EventBus::new();
+    /// let group = BasicSupplierGroup::new("group1").with_event_bus(bus.clone());
+    /// ```
+    pub fn with_event_bus(mut self, bus: EventBus) -> Self {
+        self.hooks.events = bus;
+        self
+    }
+
+    /// Restricts the group to the callers and operations granted by `policy`.
+    ///
+    /// Denied calls fail with `SupplierError::Unauthorized` for every supplier, without
+    /// reaching them or affecting their reputation, metrics or audit trail. To restrict single
+    /// suppliers, wrap them in `AccessControlledSupplier` instead.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::access::AccessPolicy;
+    /// use supplier_kit::context::RequestContext;
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut group = BasicSupplierGroup::new("team_b").with_access_policy(AccessPolicy::new().allow_reads("team-a"));
+    /// group.add_supplier(MockSupplierBuilder::new("orders").respond_default(serde_json::json!({})).build());
+    ///
+    /// let context = RequestContext::new().with_caller("team-a");
+    /// let search = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({})).with_context(context.clone());
+    /// let delete = SupplierRequest::new(SupplierOperation::Delete, serde_json::json!({})).with_context(context);
+    /// assert_eq!(group.query(search).successes.len(), 1);
+    /// assert!(matches!(group.query(delete).failures[0].1, SupplierError::Unauthorized));
+    /// ```
+    pub fn with_access_policy(mut self, policy: AccessPolicy) -> Self {
+        self.hooks.access = Some(Arc::new(policy));
+        self
+    }
+
+    /// Returns the access policy of the group, if any.
+    pub fn access_policy(&self) -> Option<&AccessPolicy> {
+        self.hooks.access.as_deref()
+    }
+
+    /// Sheds low-priority queries through `shedder` before they fan out.
+    ///
+    /// A shed query reports `SupplierError::Overloaded` for every supplier it would have
+    /// reached, without calling any. Batches and multiplexed queries are admitted or shed as a
+    /// whole, at the highest priority among their requests.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use supplier_kit::context::{Priority, RequestContext};
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::shedding::LoadShedder;
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let shedder = Arc::new(LoadShedder::new().with_queue_depth(|| 500).shed_at_queue_depth(Priority::Low, 100));
+    /// let mut group = BasicSupplierGroup::new("marketplaces").with_load_shedding(shedder);
+    /// group.add_supplier(MockSupplierBuilder::new("shop_a").respond_default(serde_json::json!({})).build());
+    ///
+    /// let prefetch = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}))
+    ///     .with_context(RequestContext::new().with_priority(Priority::Low));
+    /// assert!(matches!(group.query(prefetch).failures[0].1, SupplierError::Overloaded(_)));
+    /// ```
+    pub fn with_load_shedding(mut self, shedder: Arc<LoadShedder>) -> Self {
+        self.shedder = Some(shedder);
+        self
+    }
+
+    /// Returns the load shedder of the group, if any.
+    pub fn load_shedder(&self) -> Option<&Arc<LoadShedder>> {
+        self.shedder.as_ref()
+    }
+
+    /// Routes adaptive queries through `tracker` instead of the group's own tracker, e.g. to
+    /// share load observations between groups using the same suppliers.
+    pub fn with_load_tracker(mut self, tracker: Arc<LoadTracker>) -> Self {
+        self.hooks.load = tracker;
+        self
+    }
+
+    /// Returns the tracker holding the recent load of the group's suppliers.
+    pub fn load_tracker(&self) -> &Arc<LoadTracker> {
+        &self.hooks.load
+    }
+
+    /// Keeps adaptive queries of the same session on the same supplier, see `SessionAffinity`.
+    ///
+    /// Only applies to `QueryStrategy::Adaptive`; the other strategies query several suppliers.
+    pub fn with_session_affinity(mut self, affinity: Arc<SessionAffinity>) -> Self {
+        self.affinity = Some(affinity);
+        self
+    }
+
+    /// Returns the session affinity table, if the group has one.
+    pub fn session_affinity(&self) -> Option<&Arc<SessionAffinity>> {
+        self.affinity.as_ref()
+    }
+
+    /// Sends each request only to the suppliers selected by the first matching rule of
+    /// `routing`; requests no rule matches still go to every supplier.
+    ///
+    /// Routing applies to every query except `query_each`, whose requests already name their
+    /// supplier.
+    ///
+    /// # Example
+    /// ```
+    /// use serde_json::json;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::routing::{RoutingRule, RoutingRules, Test};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let rules = RoutingRules::new(vec![
+    ///     RoutingRule::to(&["book_depot"]).when("$.category", Test::Equals(json!("books"))),
+    /// ]);
+    /// let mut group = BasicSupplierGroup::new("marketplaces").with_routing(rules);
+    /// group.add_supplier(MockSupplierBuilder::new("book_depot").respond_default(json!([])).build());
+    /// group.add_supplier(MockSupplierBuilder::new("gadget_hub").respond_default(json!([])).build());
+    ///
+    /// let books = SupplierRequest::new(SupplierOperation::Search, json!({ "category": "books" }));
+    /// assert_eq!(group.query(books).successes.len(), 1);
+    /// let lamps = SupplierRequest::new(SupplierOperation::Search, json!({ "category": "lamps" }));
+    /// assert_eq!(group.query(lamps).successes.len(), 2);
+    /// ```
+    pub fn with_routing(mut self, routing: RoutingRules) -> Self {
+        self.routing = Some(routing);
+        self
+    }
+
+    /// Returns the content-based routing rules of the group, if any.
+    pub fn routing(&self) -> Option<&RoutingRules> {
+        self.routing.as_ref()
+    }
+
+    /// Resolves operation aliases of incoming requests through `aliases` before routing and
+    /// dispatch, so callers using legacy operation names reach the canonical operation.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::aliases::OperationAliases;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let aliases = OperationAliases::new().with_alias("find", SupplierOperation::Search);
+    /// let mut group = BasicSupplierGroup::new("marketplaces").with_operation_aliases(aliases);
+    /// let shop = MockSupplierBuilder::new("shop_a").respond(SupplierOperation::Search, serde_json::json!([])).build();
+    /// group.add_supplier(shop.clone());
+    ///
+    /// let request = SupplierRequest::new(SupplierOperation::from("find"), serde_json::json!({}));
+    /// assert_eq!(group.query(request).successes.len(), 1);
+    /// assert_eq!(shop.last_request().unwrap().operation, SupplierOperation::Search);
+    /// ```
+    pub fn with_operation_aliases(mut self, aliases: OperationAliases) -> Self {
+        self.aliases = Some(aliases);
+        self
+    }
+
+    /// Returns the operation aliases of the group, if any.
+    pub fn operation_aliases(&self) -> Option<&OperationAliases> {
+        self.aliases.as_ref()
+    }
+
+    /// Assigns a request ID and resolves operation aliases before a request is dispatched.
+    fn prepare(&self, request: &mut SupplierRequest) {
+        request.context.ensure_request_id(self.id_generator.as_ref());
+        if let Some(aliases) = &self.aliases {
+            request.operation = aliases.resolve(request.operation.clone());
+        }
+    }
+
+    /// Returns `request` with its operation aliases resolved.
+    fn resolved(&self, mut request: SupplierRequest) -> SupplierRequest {
+        if let Some(aliases) = &self.aliases {
+            request.operation = aliases.resolve(request.operation);
+        }
+        request
+    }
+
+    /// Returns whether the routing rules send `request` to `supplier` and its context does not
+    /// exclude it.
+    fn routes(&self, supplier: &Arc<dyn Supplier>, request: &SupplierRequest) -> bool {
+        !request.context.excludes(supplier.name())
+            && self.routing.as_ref().is_none_or(|routing| routing.routes(supplier.name(), request))
+    }
+
+    /// Returns the admitted suppliers the routing rules send `request` to, in supplier order.
+    fn candidates(&self, request: &SupplierRequest) -> Vec<Arc<dyn Supplier>> {
+        self.ordered().into_iter().filter(|s| self.admits(s) && self.routes(s, request)).collect()
+    }
+
+    /// Picks the candidate an adaptive query of `session` goes to: the session's pinned supplier
+    /// if it is still a candidate, otherwise the load tracker's choice, which becomes the pin.
+    fn pick(&self, session: Option<&str>, candidates: &[Arc<dyn Supplier>]) -> Option<usize> {
+        let sticky = self.affinity.as_ref().zip(session);
+        if let Some((affinity, session)) = sticky
+            && let Some(pinned) = affinity.pinned(session)
+            && let Some(index) = candidates.iter().position(|s| s.name() == pinned)
+        {
+            return Some(index);
+        }
+        let weights: Vec<f64> = candidates.iter().map(|s| self.routing_weight(s.as_ref())).collect();
+        let index = self.hooks.load.choose_weighted(candidates, &weights)?;
+        if let Some((affinity, session)) = sticky {
+            affinity.pin(session, candidates[index].name());
+        }
+        Some(index)
+    }
+
+    /// Returns the share of adaptive traffic `supplier` receives relative to its peers.
+    fn routing_weight(&self, supplier: &dyn Supplier) -> f64 {
+        let name = supplier.name();
+        let deprecation = self.deprecations.as_ref().map_or(1.0, |registry| registry.deprecation_weight(name));
+        let reputation = self.hooks.reputation.as_ref().map_or(1.0, |tracker| match tracker.reputation(name) {
+            Some(reputation) if reputation.base_weight <= 0.0 => 0.0,
+            // The floor keeps failing suppliers sampled now and then, so they can earn their weight back.
+            _ => tracker.weight(name).max(MIN_REPUTATION_WEIGHT),
+        });
+        deprecation * reputation
+    }
+
+    /// Narrows `suppliers` to the one picked by the adaptive strategy; other strategies keep all.
+    fn route(&self, session: Option<&str>, suppliers: Vec<Arc<dyn Supplier>>) -> Vec<Arc<dyn Supplier>> {
+        if self.strategy != QueryStrategy::Adaptive {
+            return suppliers;
+        }
+        self.pick(session, &suppliers).map(|index| suppliers[index].clone()).into_iter().collect()
+    }
+
+    /// Narrows `jobs` to those of the supplier picked by the adaptive strategy.
+    fn route_jobs(&self, jobs: Vec<Job>) -> Vec<Job> {
+        if self.strategy != QueryStrategy::Adaptive {
+            return jobs;
+        }
+        let (unique, _) = dedupe_jobs(&jobs);
+        let candidates: Vec<Arc<dyn Supplier>> = unique.iter().map(|(s, _)| s.clone()).collect();
+        let session = jobs.iter().find_map(|(_, request)| request.context.session_id.clone());
+        let Some(chosen) = self.pick(session.as_deref(), &candidates).map(|index| supplier_key(&candidates[index])) else {
+            return Vec::new();
+        };
+        jobs.into_iter().filter(|(s, _)| supplier_key(s) == chosen).collect()
+    }
+
+    /// Admits a query of `priority` through the load shedder, if the group has one.
+    fn admit(&self, priority: Priority) -> Result<Option<LoadPermit>, SupplierError> {
+        self.shedder.as_ref().map(|shedder| shedder.try_admit(priority)).transpose()
+    }
+
+    /// Returns the bus the group publishes on, for subscribing.
+    pub fn events(&self) -> &EventBus {
+        &self.hooks.events
+    }
+
+    /// Warms up suppliers added from now on in the background, on the group's executor, instead
+    /// of blocking `add_supplier`. Suppliers are left out of queries until their warm-up succeeds;
+    /// a panicking warm-up counts as failed.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::supplier_group::BasicSupplierGroup;
+    /// let group = BasicSupplierGroup::new("group1").with_background_warm_up();
+    /// ```
+    pub fn with_background_warm_up(mut self) -> Self {
+        self.background_warm_up = true;
+        self
+    }
+
+    /// Retries the warm-up of every supplier whose previous warm-up failed.
+    ///
+    /// # Returns
+    /// The suppliers that are still cold, with the error of their latest warm-up.
+    pub fn warm_up(&self) -> Vec<(String, SupplierError)> {
+        let cold: Vec<Arc<dyn Supplier>> = self
+            .suppliers
+            .iter()
+            .filter(|s| self.is_cold(s))
+            .cloned()
+            .collect();
+
+        let mut failures = Vec::new();
+        for supplier in cold {
+            match warm_up_isolated(supplier.as_ref()) {
+                Ok(()) => mark_warm(&self.cold, &supplier),
+                Err(e) => failures.push((supplier.name().to_string(), e)),
+            }
+        }
+        failures
+    }
+
+    /// Returns the names of the suppliers whose warm-up has not succeeded yet, in supplier order.
+    pub fn cold_suppliers(&self) -> Vec<String> {
+        self.suppliers
+            .iter()
+            .filter(|s| self.is_cold(s))
+            .map(|s| s.name().to_string())
+            .collect()
+    }
+
+    /// Sets the strategy used to execute supplier queries.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy};
+    /// let group = BasicSupplierGroup::new("group1").with_strategy(QueryStrategy::Parallel);
+    /// assert_eq!(group.strategy(), QueryStrategy::Parallel);
     /// ```
-    fn group_name(&self) -> &str;
+    pub fn with_strategy(mut self, strategy: QueryStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
 
-    /// Queries all suppliers in the group with the provided request and returns the result of the query.
-    ///
-    /// # Parameters
-    /// - `request`: A `SupplierRequest` containing the operation and parameters for the query.
+    /// Returns the strategy used to execute supplier queries.
+    pub fn strategy(&self) -> QueryStrategy {
+        self.strategy
+    }
+
+    /// Bounds the number of supplier queries the group runs at the same time.
     ///
-    /// # Returns
-    /// A `SupplierGroupResult` containing the successes and failures from querying the suppliers in the group.
+    /// Only relevant for the concurrent strategies (`Parallel` and `Race`); without a limit, every supplier
+    /// is queried on its own worker. A limit of zero is treated as one. See `with_executor` to
+    /// bound the threads the workers run on.
     ///
     /// # Example
     /// ```
-    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
-    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
-    /// let group = BasicSupplierGroup::new("group1");
-    /// let request = SupplierRequest {
-    ///     operation: SupplierOperation::Search,
-    ///     params: serde_json::json!({"query": "item"}),
-    /// };
-    /// let result = group.query(request);
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy};
+    /// let group = BasicSupplierGroup::new("group1")
+    ///     .with_strategy(QueryStrategy::Parallel)
+    ///     .with_max_concurrency(4);
+    /// assert_eq!(group.max_concurrency(), Some(4));
     /// ```
-    fn query(&self, request: SupplierRequest) -> SupplierGroupResult;
-}
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = Some(max_concurrency.max(1));
+        self
+    }
 
-/// A basic implementation of a `SupplierGroup`, which can hold a list of suppliers 
-/// and perform queries against all of them.
-pub struct BasicSupplierGroup {
-    name: String,
-    suppliers: Vec<Arc<dyn Supplier>>,
-}
+    /// Returns the configured concurrency limit, if any.
+    pub fn max_concurrency(&self) -> Option<usize> {
+        self.max_concurrency
+    }
 
-impl BasicSupplierGroup {
-    /// Creates a new supplier group with the specified name.
-    ///
-    /// # Parameters
-    /// - `name`: The name of the group.
+    /// Runs the group's parallel work on `executor` instead of spawning a thread per worker.
     ///
-    /// # Returns
-    /// A new `BasicSupplierGroup` instance.
+    /// Covers the `Parallel` and `Race` strategies of every query method and background
+    /// warm-ups; see `executor::BoundedExecutor` to cap the number of threads.
     ///
     /// # Example
     /// ```
-    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
-    /// let group = BasicSupplierGroup::new("group1");
-    /// assert_eq!(group.group_name(), "group1");
+    /// use std::sync::Arc;
+    /// use supplier_kit::executor::BoundedExecutor;
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy};
+    /// let group = BasicSupplierGroup::new("group1")
+    ///     .with_strategy(QueryStrategy::Parallel)
+    ///     .with_executor(Arc::new(BoundedExecutor::new(8)));
     /// ```
-    pub fn new(name: &str) -> Self {
-        Self {
-            name: name.into(),
-            suppliers: vec![],
-        }
+    pub fn with_executor(mut self, executor: Arc<dyn Executor>) -> Self {
+        self.executor = executor;
+        self
+    }
+
+    /// Returns the executor running the group's parallel work.
+    pub fn executor(&self) -> &Arc<dyn Executor> {
+        &self.executor
     }
 
     /// Adds a supplier to the group.
     /// This function takes ownership of the supplier and wraps it in an `Arc` for shared ownership.
     ///
+    /// The supplier's [`Supplier::warm_up`] hook runs before it joins. If it fails, the supplier
+    /// is left out of queries until [`BasicSupplierGroup::warm_up`] succeeds for it.
+    ///
     /// # Parameters
     /// - `supplier`: A supplier instance to add to the group.
     ///
@@ -114,12 +1594,10 @@ impl BasicSupplierGroup {
     ///         if self.should_fail {
     ///             Err(SupplierError::Internal(format!("{} failed", self.name)))
     ///         } else {
-    ///             Ok(SupplierResponse {
-    ///                 data: json!({
-    ///                     "supplier": self.name,
-    ///                     "params": request.params
-    ///                 }),
-    ///             })
+    ///             Ok(SupplierResponse::new(json!({
+    ///                 "supplier": self.name,
+    ///                 "params": request.params
+    ///             })))
     ///         }
     ///     }
     /// }
@@ -131,11 +1609,266 @@ impl BasicSupplierGroup {
     where
         S: Supplier + 'static,
     {
-        self.suppliers.push(Arc::new(supplier));
+        self.add_supplier_arc(Arc::new(supplier));
+    }
+
+    /// Adds a supplier with the given priority; see [`BasicSupplierGroup::set_priority`].
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut group = BasicSupplierGroup::new("payments").with_strategy(QueryStrategy::Failover);
+    /// group.add_supplier_with_priority(MockSupplierBuilder::new("backup").respond_default(serde_json::json!({})).build(), 0);
+    /// group.add_supplier_with_priority(MockSupplierBuilder::new("primary").respond_default(serde_json::json!({})).build(), 10);
+    ///
+    /// let result = group.query(SupplierRequest::new(SupplierOperation::Create, serde_json::json!({})));
+    /// assert_eq!(result.successes[0].0, "primary");
+    /// ```
+    pub fn add_supplier_with_priority<S>(&mut self, supplier: S, priority: i32)
+    where
+        S: Supplier + 'static,
+    {
+        self.write_priorities().insert(supplier.name().to_string(), priority);
+        self.add_supplier(supplier);
+    }
+
+    /// Changes the priority of supplier `name` while the group is in use.
+    ///
+    /// Suppliers are queried, listed and planned in descending priority; suppliers of equal
+    /// priority keep the order they were added in. Suppliers added without a priority have
+    /// priority `0`. Sequential and failover queries therefore try higher priorities first.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::NotFound` if the group has no supplier named `name`.
+    pub fn set_priority(&self, name: &str, priority: i32) -> Result<(), SupplierError> {
+        if !self.suppliers.iter().any(|s| s.name() == name) {
+            return Err(SupplierError::NotFound);
+        }
+        self.write_priorities().insert(name.to_string(), priority);
+        Ok(())
+    }
+
+    /// Returns the priority of supplier `name`, `0` unless one was set.
+    pub fn priority(&self, name: &str) -> i32 {
+        self.priorities.read().unwrap_or_else(|e| e.into_inner()).get(name).copied().unwrap_or_default()
+    }
+
+    /// Marks supplier `name` as critical or optional while the group is in use.
+    ///
+    /// Suppliers are optional unless marked otherwise.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::NotFound` if the group has no supplier named `name`.
+    pub fn set_severity(&self, name: &str, severity: SupplierSeverity) -> Result<(), SupplierError> {
+        if !self.suppliers.iter().any(|s| s.name() == name) {
+            return Err(SupplierError::NotFound);
+        }
+        self.severities.write().unwrap_or_else(|e| e.into_inner()).insert(name.to_string(), severity);
+        Ok(())
+    }
+
+    /// Returns the severity of supplier `name`, `SupplierSeverity::Optional` unless one was set.
+    pub fn severity(&self, name: &str) -> SupplierSeverity {
+        self.severities.read().unwrap_or_else(|e| e.into_inner()).get(name).copied().unwrap_or_default()
+    }
+
+    /// Sets when results with failed suppliers carry an overall error, see
+    /// `SupplierGroupResult::into_result`.
+    ///
+    /// Applies to `query`, `query_each`, `query_batch` and `SupplierGroupResult::retry_failures`.
+    pub fn with_failure_policy(mut self, policy: PartialFailurePolicy) -> Self {
+        self.failure_policy = policy;
+        self
+    }
+
+    /// Returns the partial failure policy of the group.
+    pub fn failure_policy(&self) -> PartialFailurePolicy {
+        self.failure_policy
+    }
+
+    /// Fails queries taking longer than `deadline` with `SupplierGroupError::DeadlineExceeded`,
+    /// whatever the partial failure policy.
+    ///
+    /// The deadline is checked once the query finished; it does not cancel supplier calls, so
+    /// bound slow suppliers with timeouts of their own.
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Returns the deadline of the group's queries, if any.
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    /// Sets the order in which results list their successes, failures and outcomes.
+    ///
+    /// Applies to `query`, `query_each`, `query_batch`, `query_transformed` and
+    /// `SupplierGroupResult::retry_failures`; `query_streamed` always yields entries as they
+    /// complete. Failures of suppliers that were never called, such as unknown `query_each`
+    /// names, come last.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use serde_json::json;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, ResultOrder, SupplierGroup};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut group = BasicSupplierGroup::new("marketplaces")
+    ///     .with_strategy(QueryStrategy::Parallel)
+    ///     .with_result_order(ResultOrder::Completion);
+    /// group.add_supplier(MockSupplierBuilder::new("slow").with_delay(Duration::from_millis(50)).respond_default(json!([])).build());
+    /// group.add_supplier(MockSupplierBuilder::new("fast").respond_default(json!([])).build());
+    ///
+    /// let result = group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+    /// assert_eq!(result.successes[0].0, "fast");
+    /// ```
+    pub fn with_result_order(mut self, order: ResultOrder) -> Self {
+        self.result_order = order;
+        self
+    }
+
+    /// Returns the order in which results list their entries.
+    pub fn result_order(&self) -> ResultOrder {
+        self.result_order
+    }
+
+    /// Lists the members of nested groups instead of the `CompositeSupplier` wrapping them.
+    ///
+    /// Results then name every entry by its `SupplierPath`, attributing successes, failures
+    /// and outcomes to the nested group's members under hierarchical names such as
+    /// `eu/amazon_de`; nested groups that flatten too add further levels. Names containing `/`
+    /// or `~` are escaped, so they never collide with nested members. A nested group that
+    /// reached no member is still listed under the composite's name. Applies to `query`, `query_each`, `query_subset` and
+    /// `SupplierGroupResult::retry_failures`; composites wrapped in decorators are not flattened.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use serde_json::json;
+    /// use supplier_kit::composite::CompositeSupplier;
+    /// use supplier_kit::errors::SupplierError;
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut eu = BasicSupplierGroup::new("eu");
+    /// eu.add_supplier(MockSupplierBuilder::new("amazon_de").respond_default(json!(["de"])).build());
+    /// eu.add_supplier(MockSupplierBuilder::new("amazon_fr").then_fail(SupplierError::Timeout).build());
+    ///
+    /// let mut global = BasicSupplierGroup::new("global").with_flattened_results();
+    /// global.add_supplier(CompositeSupplier::new(Arc::new(eu)));
+    /// global.add_supplier(MockSupplierBuilder::new("walmart").respond_default(json!(["us"])).build());
+    ///
+    /// let result = global.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+    /// let succeeded: Vec<&str> = result.successes.iter().map(|(name, _)| name.as_str()).collect();
+    /// assert_eq!(succeeded, ["eu/amazon_de", "walmart"]);
+    /// assert_eq!(result.failures[0].0, "eu/amazon_fr");
+    /// ```
+    pub fn with_flattened_results(mut self) -> Self {
+        self.flatten = true;
+        self
+    }
+
+    /// Reorders the entries of `result` by the current supplier order; entries of unknown
+    /// suppliers come last.
+    fn sort_in_supplier_order(&self, result: &mut SupplierGroupResult) {
+        let order: Vec<String> = self.ordered().iter().map(|s| s.name().to_string()).collect();
+        // Flattened entries of nested groups sort by the member wrapping them.
+        let position = |name: &str| {
+            order
+                .iter()
+                .position(|n| n == name)
+                .or_else(|| member_of(name).and_then(|member| order.iter().position(|n| *n == member)))
+                .unwrap_or(order.len())
+        };
+        result.successes.sort_by_key(|(name, _)| position(name));
+        result.failures.sort_by_key(|(name, _)| position(name));
+        result.outcomes.sort_by_key(|outcome| position(&outcome.supplier));
+    }
+
+    /// Lists the critical failures of `result` and applies the deadline and the partial
+    /// failure policy.
+    fn assess(&self, result: &mut SupplierGroupResult) {
+        result.critical_failures = self.critical_failures(&result.failures);
+        let elapsed = Duration::from_micros(result.duration_us);
+        result.error = self.group_error(result.successes.len(), &result.failures, &result.critical_failures, elapsed);
+    }
+
+    /// Lists the suppliers marked `SupplierSeverity::Critical` among `failures`.
+    fn critical_failures(&self, failures: &[(String, SupplierError)]) -> Vec<String> {
+        failures
+            .iter()
+            .filter(|(name, _)| self.severity(name) == SupplierSeverity::Critical)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Returns why a query with `succeeded` successes and `failures` fails as a whole, if it does.
+    fn group_error(
+        &self,
+        succeeded: usize,
+        failures: &[(String, SupplierError)],
+        critical_failures: &[String],
+        elapsed: Duration,
+    ) -> Option<SupplierGroupError> {
+        let group = self.name.clone();
+        let all_failures = || failures.to_vec();
+        if let Some(deadline) = self.deadline
+            && elapsed > deadline
+        {
+            return Some(SupplierGroupError::DeadlineExceeded {
+                group,
+                deadline_ms: deadline.as_millis() as u64,
+                elapsed_ms: elapsed.as_millis() as u64,
+                failures: all_failures(),
+            });
+        }
+
+        let required = match self.failure_policy {
+            PartialFailurePolicy::Tolerate => return None,
+            _ if succeeded + failures.len() == 0 => return Some(SupplierGroupError::NoSuppliers { group }),
+            PartialFailurePolicy::FailOnCritical if !critical_failures.is_empty() => {
+                return Some(SupplierGroupError::CriticalFailed {
+                    group,
+                    suppliers: critical_failures.to_vec(),
+                    failures: all_failures(),
+                });
+            }
+            PartialFailurePolicy::FailOnCritical => return None,
+            PartialFailurePolicy::FailOnAny => succeeded + failures.len(),
+            PartialFailurePolicy::Quorum(required) => required,
+        };
+        match succeeded {
+            _ if succeeded >= required => None,
+            0 => Some(SupplierGroupError::AllFailed { group, failures: all_failures() }),
+            _ => Some(SupplierGroupError::QuorumNotMet { group, required, succeeded, failures: all_failures() }),
+        }
+    }
+
+    /// Returns the suppliers in descending priority, keeping insertion order between equals.
+    fn ordered(&self) -> Vec<Arc<dyn Supplier>> {
+        let mut suppliers = self.suppliers.clone();
+        let priorities = self.priorities.read().unwrap_or_else(|e| e.into_inner());
+        if !priorities.is_empty() {
+            suppliers.sort_by_key(|s| Reverse(priorities.get(s.name()).copied().unwrap_or_default()));
+        }
+        suppliers
+    }
+
+    fn write_priorities(&self) -> RwLockWriteGuard<'_, HashMap<String, i32>> {
+        self.priorities.write().unwrap_or_else(|e| e.into_inner())
     }
 
     /// Adds a supplier to the group using an already wrapped `Arc<dyn Supplier>`.
     ///
+    /// Warm-up behaves as described for [`BasicSupplierGroup::add_supplier`].
+    ///
     /// # Parameters
     /// - `supplier`: An `Arc` containing a `dyn Supplier` to add to the group.
     ///
@@ -171,12 +1904,10 @@ impl BasicSupplierGroup {
     ///         if self.should_fail {
     ///             Err(SupplierError::Internal(format!("{} failed", self.name)))
     ///         } else {
-    ///             Ok(SupplierResponse {
-    ///                 data: json!({
-    ///                     "supplier": self.name,
-    ///                     "params": request.params
-    ///                 }),
-    ///             })
+    ///             Ok(SupplierResponse::new(json!({
+    ///                 "supplier": self.name,
+    ///                 "params": request.params
+    ///             })))
     ///         }
     ///     }
     /// }
@@ -186,8 +1917,422 @@ impl BasicSupplierGroup {
     /// group.add_supplier_arc(supplier);
     /// ```
     pub fn add_supplier_arc(&mut self, supplier: Arc<dyn Supplier>) {
+        if self.background_warm_up {
+            self.cold.lock().unwrap_or_else(|e| e.into_inner()).push(supplier_key(&supplier));
+            let cold = self.cold.clone();
+            let supplier = supplier.clone();
+            self.executor.spawn(Box::new(move || {
+                if warm_up_isolated(supplier.as_ref()).is_ok() {
+                    mark_warm(&cold, &supplier);
+                }
+            }));
+        } else if warm_up_isolated(supplier.as_ref()).is_err() {
+            self.cold.lock().unwrap_or_else(|e| e.into_inner()).push(supplier_key(&supplier));
+        }
         self.suppliers.push(supplier);
     }
+
+    /// Explains how `request` would be handled without querying any supplier (a dry run).
+    ///
+    /// Every supplier of the group is listed in order with the reason fan-out queries would skip
+    /// it (cold, not ready, ejected, excluded), whether its descriptor declares the operation, and the
+    /// violations of the declared params schema (see [`crate::descriptor::validate_schema`]).
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::BasicSupplierGroup;
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut group = BasicSupplierGroup::new("marketplaces");
+    /// group.add_supplier(MockSupplierBuilder::new("shop_a").build());
+    ///
+    /// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}));
+    /// let plan = group.plan(&request);
+    /// assert_eq!(plan[0].name, "shop_a");
+    /// assert!(plan[0].would_query());
+    /// assert_eq!(plan[0].declares_operation, None);
+    /// ```
+    pub fn plan(&self, request: &SupplierRequest) -> Vec<PlannedSupplier> {
+        let request = &self.resolved(request.clone());
+        self.ordered()
+            .iter()
+            .map(|supplier| PlannedSupplier {
+                skip_reason: self.exclusion_reason(supplier, request).map(str::to_string),
+                ..PlannedSupplier::new(supplier.as_ref(), request)
+            })
+            .collect()
+    }
+
+    /// Queries every supplier like [`SupplierGroup::query`] and applies `transform` to each
+    /// successful response before aggregation.
+    ///
+    /// The transform receives the supplier name and takes ownership of the response, so
+    /// normalization happens in a single pass without cloning payloads. With the sequential,
+    /// failover and parallel strategies it runs on the worker that made the call; with the race
+    /// strategy it runs on the calling thread as responses arrive. A transform error, or a panic
+    /// of the transform, is reported as that supplier's failure, and does not end a race or
+    /// failover. Flattened nested groups, critical failures and the partial failure policy are
+    /// handled as by `query`, transforming each member's response of a flattened group.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+    /// use supplier_kit::supplier_group::BasicSupplierGroup;
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut group = BasicSupplierGroup::new("marketplaces");
+    /// group.add_supplier(
+    ///     MockSupplierBuilder::new("shop_a")
+    ///         .respond_default(serde_json::json!({ "price_cents": 1250 }))
+    ///         .build(),
+    /// );
+    /// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({ "q": "lamp" }));
+    /// let result = group.query_transformed(request, |_name, response| {
+    ///     Ok(response.data["price_cents"].as_u64().unwrap_or_default() as f64 / 100.0)
+    /// });
+    /// assert_eq!(result.successes, vec![("shop_a".to_string(), 12.5)]);
+    /// ```
+    pub fn query_transformed<T, F>(&self, mut request: SupplierRequest, transform: F) -> TransformedGroupResult<T>
+    where
+        T: Send,
+        F: Fn(&str, SupplierResponse) -> Result<T, SupplierError> + Sync,
+    {
+        self.prepare(&mut request);
+        if let Some((names, error)) = self.unmet_requirements(&request) {
+            let failures = names.into_iter().map(|name| (name, error.clone())).collect();
+            return self.assess_transformed(Vec::new(), failures, Duration::ZERO);
+        }
+        let suppliers = self.candidates(&request);
+        let _permit = match self.admit(request.context.priority()) {
+            Ok(permit) => permit,
+            Err(error) => {
+                let failures = suppliers.iter().map(|s| (s.name().to_string(), error.clone())).collect();
+                return self.assess_transformed(Vec::new(), failures, Duration::ZERO);
+            }
+        };
+        let suppliers = self.route(request.context.session_id.as_deref(), suppliers);
+        let limit = self.max_concurrency.unwrap_or(suppliers.len());
+        let started = Instant::now();
+        // Nested groups to flatten are queried through a wrapper keeping their results.
+        let captures: Vec<Option<Arc<CapturingSupplier>>> = suppliers
+            .iter()
+            .map(|s| (self.flatten && s.as_group().is_some()).then(|| Arc::new(CapturingSupplier::new(s.clone()))))
+            .collect();
+        let callees: Vec<Arc<dyn Supplier>> = suppliers
+            .iter()
+            .zip(&captures)
+            .map(|(supplier, capture)| match capture {
+                Some(capture) => capture.clone() as Arc<dyn Supplier>,
+                None => supplier.clone(),
+            })
+            .collect();
+        let finish = |index: usize, call: Call| {
+            let finished = call.finished;
+            (index, finished, self.transformed(suppliers[index].name(), captures[index].as_deref(), call, &transform))
+        };
+        let call = |index: usize| finish(index, self.hooks.call(callees[index].as_ref(), request.clone()));
+        let succeeded = |outputs: &[TransformedOutput<T>]| outputs.iter().any(|(_, output)| output.is_ok());
+
+        // The index of the supplier, when its outcome was ready, and its transformed outputs.
+        let mut outcomes: Vec<(usize, Instant, Vec<TransformedOutput<T>>)> = match self.strategy {
+            QueryStrategy::Sequential | QueryStrategy::Adaptive => (0..callees.len()).map(call).collect(),
+            QueryStrategy::Failover => {
+                let mut outcomes = Vec::new();
+                for index in 0..callees.len() {
+                    let outcome = call(index);
+                    let won = succeeded(&outcome.2);
+                    outcomes.push(outcome);
+                    if won {
+                        break;
+                    }
+                }
+                outcomes
+            }
+            QueryStrategy::Parallel => {
+                let indices: Vec<usize> = (0..callees.len()).collect();
+                parallel_map(self.executor.as_ref(), &indices, limit, |index| call(*index))
+            }
+            QueryStrategy::Race => {
+                let jobs: Vec<Job> = callees.iter().map(|s| (s.clone(), request.clone())).collect();
+                let mut outcomes = Vec::new();
+                for (index, call) in spawn_jobs(self.executor.as_ref(), jobs, limit, &self.hooks) {
+                    let outcome = finish(index, call);
+                    let won = succeeded(&outcome.2);
+                    outcomes.push(outcome);
+                    if won {
+                        break;
+                    }
+                }
+                outcomes
+            }
+        };
+        match self.result_order {
+            ResultOrder::Supplier => outcomes.sort_by_key(|(index, _, _)| *index),
+            ResultOrder::Completion => outcomes.sort_by_key(|(_, finished, _)| *finished),
+        }
+
+        let (mut successes, mut failures) = (Vec::new(), Vec::new());
+        for (name, output) in outcomes.into_iter().flat_map(|(_, _, outputs)| outputs) {
+            match output {
+                Ok(output) => successes.push((name, output)),
+                Err(e) => failures.push((name, e)),
+            }
+        }
+        let elapsed = started.elapsed();
+        self.hooks
+            .observe_group(request.context.tenant.as_deref(), suppliers.len(), successes.len(), failures.len(), elapsed);
+        self.assess_transformed(successes, failures, elapsed)
+    }
+
+    /// Records `call` to supplier `name` as `query` would, then transforms every success,
+    /// turning a panicking transform into that entry's failure.
+    fn transformed<T, F>(&self, name: &str, capture: Option<&CapturingSupplier>, call: Call, transform: &F) -> Vec<TransformedOutput<T>>
+    where
+        F: Fn(&str, SupplierResponse) -> Result<T, SupplierError>,
+    {
+        let mut recorded = SupplierGroupResult::default();
+        match capture.and_then(CapturingSupplier::take) {
+            Some(nested) => recorded.record_nested(name, nested, call),
+            None if self.flatten => recorded.record(&SupplierPath::new(name).to_string(), call),
+            None => recorded.record(name, call),
+        }
+        let failures = recorded.failures.into_iter().map(|(name, e)| (name, Err(e)));
+        recorded
+            .successes
+            .into_iter()
+            .map(|(name, response)| {
+                let output = panic::catch_unwind(AssertUnwindSafe(|| transform(&name, response))).unwrap_or_else(|payload| {
+                    Err(SupplierError::Internal(format!("transform of '{}' panicked: {}", name, panic_reason(payload.as_ref()))))
+                });
+                (name, output)
+            })
+            .chain(failures)
+            .collect()
+    }
+
+    /// Builds a transformed result, applying the critical failures and the partial failure
+    /// policy like [`BasicSupplierGroup::assess`].
+    fn assess_transformed<T>(&self, successes: Vec<(String, T)>, failures: Vec<(String, SupplierError)>, elapsed: Duration) -> TransformedGroupResult<T> {
+        let critical_failures = self.critical_failures(&failures);
+        let error = self.group_error(successes.len(), &failures, &critical_failures, elapsed);
+        TransformedGroupResult { successes, failures, critical_failures, error }
+    }
+
+    /// Queries only the suppliers accepted by `filter`, in supplier order.
+    fn query_where<F>(&self, mut request: SupplierRequest, filter: F) -> SupplierGroupResult
+    where
+        F: Fn(&Arc<dyn Supplier>) -> bool,
+    {
+        self.prepare(&mut request);
+        let (_, _scope) = QueryMemo::open(&mut request);
+        let jobs: Vec<(Arc<dyn Supplier>, SupplierRequest)> = self
+            .ordered()
+            .into_iter()
+            .filter(|s| self.admits(s) && self.routes(s, &request) && filter(s))
+            .map(|s| (s, request.clone()))
+            .collect();
+        match self.admit(request.context.priority()) {
+            Ok(_permit) => self.run_jobs(self.route_jobs(jobs)),
+            Err(error) => shed_result(jobs.iter().map(|(s, _)| s.name()), error),
+        }
+    }
+
+    /// Returns the suppliers taking part in fan-out queries, in supplier order.
+    fn admitted_suppliers(&self) -> Vec<Arc<dyn Supplier>> {
+        self.ordered().into_iter().filter(|s| self.admits(s)).collect()
+    }
+
+    /// Returns whether a supplier is warm, ready and not ejected.
+    fn admits(&self, supplier: &Arc<dyn Supplier>) -> bool {
+        self.skip_reason(supplier).is_none()
+    }
+
+    /// Explains why fan-out queries currently skip `supplier`, if they do.
+    fn skip_reason(&self, supplier: &Arc<dyn Supplier>) -> Option<&'static str> {
+        if self.is_cold(supplier) {
+            Some("cold: warm-up has not succeeded")
+        } else if !supplier.is_ready() {
+            Some("not ready")
+        } else if self.health.as_ref().is_some_and(|h| !h.is_healthy(supplier.name())) {
+            Some("unhealthy: failed its latest health checks")
+        } else if self.deprecations.as_ref().is_some_and(|r| r.deprecation_weight(supplier.name()) <= 0.0) {
+            Some("deprecated: its sunset date has passed")
+        } else {
+            self.hooks.ejection_reason(supplier.as_ref())
+        }
+    }
+
+    /// Explains why a fan-out query of `request` skips `supplier`, including routing, if it does.
+    fn exclusion_reason(&self, supplier: &Arc<dyn Supplier>, request: &SupplierRequest) -> Option<&'static str> {
+        self.skip_reason(supplier)
+            .or_else(|| request.context.excludes(supplier.name()).then_some("excluded by the request context"))
+            .or_else(|| (!self.routes(supplier, request)).then_some("not selected by routing rules"))
+    }
+
+    fn is_cold(&self, supplier: &Arc<dyn Supplier>) -> bool {
+        let key = supplier_key(supplier);
+        self.cold.lock().unwrap_or_else(|e| e.into_inner()).contains(&key)
+    }
+
+    /// Runs every `(supplier, request)` job and collects the outcomes in job order.
+    fn run_jobs(&self, jobs: Vec<(Arc<dyn Supplier>, SupplierRequest)>) -> SupplierGroupResult {
+        let mut result = SupplierGroupResult::default();
+        let started = Instant::now();
+        // Nested groups to flatten are queried through a wrapper keeping their results.
+        let captures: Vec<Option<Arc<CapturingSupplier>>> = jobs
+            .iter()
+            .map(|(supplier, _)| {
+                (self.flatten && supplier.as_group().is_some()).then(|| Arc::new(CapturingSupplier::new(supplier.clone())))
+            })
+            .collect();
+        let jobs: Vec<Job> = jobs
+            .into_iter()
+            .zip(&captures)
+            .map(|((supplier, request), capture)| match capture {
+                Some(capture) => (capture.clone() as Arc<dyn Supplier>, request),
+                None => (supplier, request),
+            })
+            .collect();
+        let mut calls: Vec<(&str, Option<Captured>, Call)> = jobs
+            .iter()
+            .zip(&captures)
+            .zip(self.execute(&jobs))
+            .filter_map(|(((supplier, _), capture), call)| {
+                call.map(|call| (supplier.name(), capture.as_ref().and_then(|c| c.take()), call))
+            })
+            .collect();
+        if self.result_order == ResultOrder::Completion {
+            calls.sort_by_key(|(_, _, call)| call.finished);
+        }
+        for (name, nested, call) in calls {
+            match nested {
+                Some(nested) => result.record_nested(name, nested, call),
+                None if self.flatten => result.record(&SupplierPath::new(name).to_string(), call),
+                None => result.record(name, call),
+            }
+        }
+
+        let elapsed = started.elapsed();
+        result.duration_us = elapsed.as_micros() as u64;
+        let tenant = jobs.first().and_then(|(_, request)| request.context.tenant.as_deref());
+        self.hooks.observe_group(tenant, jobs.len(), result.successes.len(), result.failures.len(), elapsed);
+        result
+    }
+
+    /// Runs every `(supplier, request)` job and returns the results in job order.
+    ///
+    /// Jobs the strategy did not query (or abandoned) have no result.
+    /// Identical jobs (same supplier instance and equal request) are executed only once, also
+    /// across the nested groups sharing the memo their requests carry.
+    fn execute(&self, jobs: &[Job]) -> Vec<Option<Call>> {
+        let local = QueryMemo::default();
+        let memo = |request: &SupplierRequest| request.context.memo.clone().unwrap_or_else(|| local.clone());
+        match self.strategy {
+            // Adaptive jobs are already narrowed to one supplier.
+            QueryStrategy::Sequential | QueryStrategy::Adaptive => jobs
+                .iter()
+                .map(|(supplier, request)| Some(memo(request).call(supplier, request, &self.hooks)))
+                .collect(),
+            QueryStrategy::Failover => {
+                let mut succeeded = false;
+                jobs.iter()
+                    .map(|(supplier, request)| {
+                        if succeeded {
+                            return None;
+                        }
+                        let call = memo(request).call(supplier, request, &self.hooks);
+                        succeeded = call.result.is_ok();
+                        Some(call)
+                    })
+                    .collect()
+            }
+            QueryStrategy::Parallel => {
+                // Query each distinct job only once, then fan the results back out.
+                let (unique, slots) = dedupe_jobs(jobs);
+                let limit = self.max_concurrency.unwrap_or(unique.len());
+                let calls = parallel_map(self.executor.as_ref(), &unique, limit, |(supplier, request)| {
+                    memo(request).call(supplier, request, &self.hooks)
+                });
+
+                let mut fanned_out = vec![false; calls.len()];
+                slots
+                    .into_iter()
+                    .map(|slot| {
+                        let call = if fanned_out[slot] { calls[slot].reused() } else { calls[slot].clone() };
+                        fanned_out[slot] = true;
+                        Some(call)
+                    })
+                    .collect()
+            }
+            QueryStrategy::Race => {
+                let (unique, slots) = dedupe_jobs(jobs);
+                let limit = self.max_concurrency.unwrap_or(unique.len());
+                let mut results = vec![None; jobs.len()];
+
+                for (index, call) in spawn_jobs(self.executor.as_ref(), unique, limit, &self.hooks) {
+                    let won = call.result.is_ok();
+                    let mut fanned_out = false;
+                    for (slot, outcome) in slots.iter().zip(results.iter_mut()) {
+                        if *slot == index {
+                            *outcome = Some(if fanned_out { call.reused() } else { call.clone() });
+                            fanned_out = true;
+                        }
+                    }
+                    if won {
+                        break;
+                    }
+                }
+                results
+            }
+        }
+    }
+}
+
+/// The result of a shed query: every supplier it would have reached fails with `error`.
+fn shed_result<'a>(names: impl Iterator<Item = &'a str>, error: SupplierError) -> SupplierGroupResult {
+    SupplierGroupResult {
+        successes: Vec::new(),
+        failures: names.map(|name| (name.to_string(), error.clone())).collect(),
+        outcomes: Vec::new(),
+        duration_us: 0,
+        critical_failures: Vec::new(),
+        error: None,
+    }
+}
+
+/// A result stream that keeps its load permit until the stream is dropped.
+struct Permitted<I> {
+    _permit: Option<LoadPermit>,
+    inner: I,
+}
+
+impl<I> Permitted<I> {
+    fn new(permit: Option<LoadPermit>, inner: I) -> Self {
+        Self { _permit: permit, inner }
+    }
+}
+
+impl<I: Iterator> Iterator for Permitted<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.inner.next()
+    }
+}
+
+/// Returns the group member a flattened result entry belongs to, the first segment of its path.
+fn member_of(name: &str) -> Option<String> {
+    SupplierPath::parse(name).ok().map(|path| path.root().to_string())
+}
+
+fn supplier_key(supplier: &Arc<dyn Supplier>) -> usize {
+    Arc::as_ptr(supplier) as *const () as usize
+}
+
+fn mark_warm(cold: &Mutex<Vec<usize>>, supplier: &Arc<dyn Supplier>) {
+    let key = supplier_key(supplier);
+    cold.lock().unwrap_or_else(|e| e.into_inner()).retain(|k| *k != key);
 }
 
 impl SupplierGroup for BasicSupplierGroup {
@@ -195,17 +2340,276 @@ impl SupplierGroup for BasicSupplierGroup {
         &self.name
     }
 
+    fn describe(&self) -> GroupDescriptor {
+        self.ordered()
+            .iter()
+            .fold(GroupDescriptor::new(&self.name).with_strategy(self.strategy), |descriptor, supplier| {
+                descriptor.with_supplier(supplier.name())
+            })
+    }
+
+    fn flattens_results(&self) -> bool {
+        self.flatten
+    }
+
     fn query(&self, request: SupplierRequest) -> SupplierGroupResult {
-        let mut successes = Vec::new();
-        let mut failures = Vec::new();
+        let mut result = match self.unmet_requirements(&self.resolved(request.clone())) {
+            Some((names, error)) => shed_result(names.iter().map(String::as_str), error),
+            None => self.query_where(request, |_| true),
+        };
+        self.assess(&mut result);
+        result
+    }
 
-        for supplier in &self.suppliers {
-            match supplier.query(request.clone()) {
-                Ok(response) => successes.push((supplier.name().to_string(), response)),
-                Err(e) => failures.push((supplier.name().to_string(), e)),
+    fn query_streamed(&self, mut request: SupplierRequest) -> GroupResultStream<'_> {
+        self.prepare(&mut request);
+        // The memo stays open for as long as the stream is read.
+        let (memo, scope) = QueryMemo::open(&mut request);
+        if let Some((names, error)) = self.unmet_requirements(&request) {
+            return Box::new(names.into_iter().map(move |name| (name, Err(error.clone()))));
+        }
+        let permit = match self.admit(request.context.priority()) {
+            Ok(permit) => permit,
+            Err(error) => {
+                let failures = shed_result(self.candidates(&request).iter().map(|s| s.name()), error).failures;
+                return Box::new(failures.into_iter().map(|(name, error)| (name, Err(error))));
+            }
+        };
+
+        let stop_after_success = matches!(self.strategy, QueryStrategy::Race | QueryStrategy::Failover);
+        let mut succeeded = false;
+
+        match self.strategy {
+            QueryStrategy::Sequential | QueryStrategy::Failover | QueryStrategy::Adaptive => {
+                let hooks = self.hooks.clone();
+                let suppliers = self.route(request.context.session_id.as_deref(), self.candidates(&request));
+                Box::new(Permitted::new(permit, suppliers.into_iter().map_while(move |supplier| {
+                    let _ = &scope;
+                    if succeeded {
+                        return None;
+                    }
+                    let result = memo.query(&supplier, &request, &hooks);
+                    succeeded = stop_after_success && result.is_ok();
+                    Some((supplier.name().to_string(), result))
+                })))
+            }
+            QueryStrategy::Parallel | QueryStrategy::Race => {
+                // Each distinct supplier instance is queried once; its result is yielded
+                // for every position it occupies in the group.
+                let jobs: Vec<Job> = self
+                    .candidates(&request)
+                    .into_iter()
+                    .map(|supplier| (supplier, request.clone()))
+                    .collect();
+                let (unique, slots) = dedupe_jobs(&jobs);
+                let names: Vec<String> = unique.iter().map(|(s, _)| s.name().to_string()).collect();
+                let limit = self.max_concurrency.unwrap_or(unique.len());
+
+                let results = spawn_jobs(self.executor.as_ref(), unique, limit, &self.hooks).flat_map(move |(index, call)| {
+                    let count = slots.iter().filter(|slot| **slot == index).count();
+                    std::iter::repeat_n((names[index].clone(), call.result), count)
+                });
+                Box::new(Permitted::new(permit, results.map_while(move |(name, result)| {
+                    let _ = &scope;
+                    if succeeded {
+                        return None;
+                    }
+                    succeeded = stop_after_success && result.is_ok();
+                    Some((name, result))
+                })))
+            }
+        }
+    }
+
+    /// Fans out like [`SupplierGroup::query`], restricted to the named suppliers: skipped
+    /// suppliers (cold, unhealthy, ejected, routed away) are still skipped, but the group's
+    /// supplier requirements are not checked, since the caller chose the subset.
+    fn query_subset(&self, request: SupplierRequest, suppliers: &[&str]) -> SupplierGroupResult {
+        let mut result = self.query_where(request, |supplier| suppliers.contains(&supplier.name()));
+        let mut unknown: Vec<&str> = suppliers
+            .iter()
+            .copied()
+            .filter(|name| !self.suppliers.iter().any(|s| s.name() == *name))
+            .collect();
+        unknown.sort_unstable();
+        unknown.dedup();
+        result
+            .failures
+            .extend(unknown.into_iter().map(|name| (name.to_string(), SupplierError::NotFound)));
+        self.assess(&mut result);
+        result
+    }
+
+    fn query_each(&self, mut requests: HashMap<String, SupplierRequest>) -> SupplierGroupResult {
+        // All requests of one multiplexed call share a request ID unless they bring their own.
+        let request_id = self.id_generator.generate();
+        let mut scope = None;
+        for request in requests.values_mut() {
+            request.context.request_id.get_or_insert_with(|| request_id.clone());
+            self.prepare(request);
+            // They share one memo too, so nested groups reached by several of them call once.
+            match &scope {
+                Some((memo, _)) => _ = request.context.memo.get_or_insert_with(|| QueryMemo::clone(memo)),
+                None => scope = Some(QueryMemo::open(request)),
+            }
+        }
+
+        let jobs: Vec<(Arc<dyn Supplier>, SupplierRequest)> = self
+            .ordered()
+            .into_iter()
+            .filter_map(|s| requests.get(s.name()).cloned().map(|r| (s, r)))
+            .collect();
+        let priority = requests.values().map(|r| r.context.priority()).max().unwrap_or_default();
+        let _permit = match self.admit(priority) {
+            Ok(permit) => permit,
+            Err(error) => {
+                let mut result = shed_result(jobs.iter().map(|(s, _)| s.name()), error);
+                self.assess(&mut result);
+                return result;
+            }
+        };
+        let mut result = self.run_jobs(self.route_jobs(jobs));
+
+        requests.retain(|name, _| !result.contains(name));
+        let mut unknown: Vec<String> = requests.into_keys().collect();
+        unknown.sort();
+        result
+            .failures
+            .extend(unknown.into_iter().map(|name| (name, SupplierError::NotFound)));
+        self.assess(&mut result);
+        result
+    }
+
+    fn query_batch(&self, mut requests: Vec<SupplierRequest>) -> Vec<SupplierGroupResult> {
+        for request in &mut requests {
+            self.prepare(request);
+        }
+        let suppliers = self.admitted_suppliers();
+        let priority = requests.iter().map(|r| r.context.priority()).max().unwrap_or_default();
+        let _permit = match self.admit(priority) {
+            Ok(permit) => permit,
+            Err(error) => {
+                return requests
+                    .iter()
+                    .map(|_| {
+                        let mut result = shed_result(suppliers.iter().map(|s| s.name()), error.clone());
+                        self.assess(&mut result);
+                        result
+                    })
+                    .collect();
+            }
+        };
+        // Requests with unmet requirements are answered without reaching any supplier.
+        let mut unmet: Vec<Option<SupplierGroupResult>> = requests
+            .iter()
+            .map(|request| {
+                self.unmet_requirements(request)
+                    .map(|(names, error)| shed_result(names.iter().map(String::as_str), error))
+            })
+            .collect();
+        let session = requests.iter().find_map(|r| r.context.session_id.clone());
+        let suppliers = self.route(session.as_deref(), suppliers);
+        // The indexes of the requests each supplier receives under the routing rules; suppliers
+        // receiving none are left out.
+        let (suppliers, routed): (Vec<_>, Vec<Vec<usize>>) = suppliers
+            .into_iter()
+            .map(|supplier| {
+                let routed = (0..requests.len())
+                    .filter(|i| unmet[*i].is_none() && self.routes(&supplier, &requests[*i]))
+                    .collect();
+                (supplier, routed)
+            })
+            .filter(|(_, routed): &(_, Vec<usize>)| !routed.is_empty())
+            .unzip();
+        let batch_of = |index: usize| routed[index].iter().map(|i| requests[*i].clone()).collect::<Vec<_>>();
+        let mut results: Vec<SupplierGroupResult> = requests.iter().map(|_| SupplierGroupResult::default()).collect();
+        let started = Instant::now();
+
+        // Supplier batches in merge order: supplier order, or completion order for races.
+        let batches: Vec<(usize, Vec<Call>)> = match self.strategy {
+            QueryStrategy::Sequential | QueryStrategy::Adaptive => suppliers
+                .iter()
+                .enumerate()
+                .map(|(index, supplier)| (index, self.hooks.invoke_batch(supplier.as_ref(), batch_of(index))))
+                .collect(),
+            QueryStrategy::Parallel => {
+                let indexes: Vec<usize> = (0..suppliers.len()).collect();
+                let limit = self.max_concurrency.unwrap_or(suppliers.len());
+                parallel_map(self.executor.as_ref(), &indexes, limit, |index| {
+                    (*index, self.hooks.invoke_batch(suppliers[*index].as_ref(), batch_of(*index)))
+                })
+            }
+            QueryStrategy::Race => {
+                // Batches cannot be abandoned midway, so a race waits for every batch and then
+                // keeps, per request, the success of the earliest finishing supplier.
+                let finished = AtomicUsize::new(0);
+                let indexes: Vec<usize> = (0..suppliers.len()).collect();
+                let limit = self.max_concurrency.unwrap_or(suppliers.len());
+                let mut batches: Vec<(usize, usize, _)> = parallel_map(self.executor.as_ref(), &indexes, limit, |index| {
+                    let batch = self.hooks.invoke_batch(suppliers[*index].as_ref(), batch_of(*index));
+                    (finished.fetch_add(1, Ordering::SeqCst), batch)
+                })
+                .into_iter()
+                .enumerate()
+                .map(|(index, (order, batch))| (order, index, batch))
+                .collect();
+                batches.sort_by_key(|(order, _, _)| *order);
+                batches.into_iter().map(|(_, index, batch)| (index, batch)).collect()
+            }
+            QueryStrategy::Failover => {
+                // Each supplier only receives the requests no earlier supplier answered.
+                for (supplier, routed) in suppliers.iter().zip(&routed) {
+                    let pending: Vec<usize> = routed.iter().copied().filter(|i| results[*i].successes.is_empty()).collect();
+                    if pending.is_empty() {
+                        break;
+                    }
+                    let batch = self
+                        .hooks
+                        .invoke_batch(supplier.as_ref(), pending.iter().map(|i| requests[*i].clone()).collect());
+                    for (index, call) in pending.into_iter().zip(batch) {
+                        results[index].record(supplier.name(), call);
+                    }
+                }
+                // Outcomes are already merged.
+                Vec::new()
+            }
+        };
+
+        // The calls kept per request, as (supplier index, call); races keep the first success.
+        let first_success_only = self.strategy == QueryStrategy::Race;
+        let mut kept: Vec<Vec<(usize, Call)>> = requests.iter().map(|_| Vec::new()).collect();
+        for (index, batch) in batches {
+            for (request, call) in routed[index].iter().zip(batch) {
+                let calls = &mut kept[*request];
+                if first_success_only && calls.iter().any(|(_, call)| call.result.is_ok()) {
+                    continue;
+                }
+                calls.push((index, call));
+            }
+        }
+        for (result, mut calls) in results.iter_mut().zip(kept) {
+            match self.result_order {
+                ResultOrder::Supplier => calls.sort_by_key(|(index, _)| *index),
+                ResultOrder::Completion => calls.sort_by_key(|(_, call)| call.finished),
+            }
+            for (index, call) in calls {
+                result.record(suppliers[index].name(), call);
             }
         }
 
-        SupplierGroupResult { successes, failures }
+        let elapsed = started.elapsed();
+        for (index, (request, result)) in requests.iter().zip(&mut results).enumerate() {
+            if let Some(unmet) = unmet[index].take() {
+                *result = unmet;
+                self.assess(result);
+                continue;
+            }
+            result.duration_us = elapsed.as_micros() as u64;
+            let tenant = request.context.tenant.as_deref();
+            let fan_out = routed.iter().filter(|routed| routed.contains(&index)).count();
+            self.hooks.observe_group(tenant, fan_out, result.successes.len(), result.failures.len(), elapsed);
+            self.assess(result);
+        }
+        results
     }
 }
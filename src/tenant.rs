@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::path::SupplierPath;
+use crate::supplier::{Supplier, SupplierRegistry};
+
+/// Maps tenant IDs to isolated `SupplierRegistry` instances.
+///
+/// Every tenant sees its own suppliers first and then those of the optional shared base
+/// registry; a tenant supplier shadows a base supplier with the same name. Tenants never see
+/// each other's suppliers, and unknown tenants see nothing, not even the base.
+///
+/// Queries stamp the tenant into `RequestContext::tenant`, so suppliers can read it and group
+/// metrics report it.
+///
+/// Tenants can be added and removed while the registry is shared between threads.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier::SupplierRegistry;
+/// use supplier_kit::tenant::TenantRegistry;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let mut base = SupplierRegistry::new();
+/// base.register("weather", MockSupplierBuilder::new("weather").respond_default(serde_json::json!({ "shared": true })).build());
+///
+/// let mut acme = SupplierRegistry::new();
+/// acme.register("erp", MockSupplierBuilder::new("erp").respond_default(serde_json::json!({ "tenant": "acme" })).build());
+///
+/// let tenants = TenantRegistry::new().with_base(Arc::new(base));
+/// tenants.insert("acme", acme);
+/// tenants.insert("globex", SupplierRegistry::new());
+///
+/// let search = || SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}));
+/// assert_eq!(tenants.query("acme", "erp", search()).unwrap().data["tenant"], "acme");
+/// assert_eq!(tenants.query("globex", "weather", search()).unwrap().data["shared"], true);
+/// assert!(tenants.query("globex", "erp", search()).is_err());
+/// ```
+#[derive(Default)]
+pub struct TenantRegistry {
+    base: Option<Arc<SupplierRegistry>>,
+    tenants: RwLock<HashMap<String, Arc<SupplierRegistry>>>,
+}
+
+impl TenantRegistry {
+    /// Creates a registry without tenants or shared suppliers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shares the suppliers of `base` with every tenant.
+    pub fn with_base(mut self, base: Arc<SupplierRegistry>) -> Self {
+        self.base = Some(base);
+        self
+    }
+
+    /// Returns the shared base registry, if any.
+    pub fn base(&self) -> Option<&Arc<SupplierRegistry>> {
+        self.base.as_ref()
+    }
+
+    /// Adds or replaces the registry of `tenant`, returning the replaced one.
+    pub fn insert(&self, tenant: &str, registry: SupplierRegistry) -> Option<Arc<SupplierRegistry>> {
+        self.insert_arc(tenant, Arc::new(registry))
+    }
+
+    /// Adds or replaces the already shared registry of `tenant`, returning the replaced one.
+    pub fn insert_arc(&self, tenant: &str, registry: Arc<SupplierRegistry>) -> Option<Arc<SupplierRegistry>> {
+        self.write().insert(tenant.to_string(), registry)
+    }
+
+    /// Removes `tenant`, returning its registry.
+    ///
+    /// Calls already holding the registry finish normally.
+    pub fn remove(&self, tenant: &str) -> Option<Arc<SupplierRegistry>> {
+        self.write().remove(tenant)
+    }
+
+    /// Returns the registry of `tenant`, without the base suppliers.
+    pub fn registry(&self, tenant: &str) -> Option<Arc<SupplierRegistry>> {
+        self.read().get(tenant).cloned()
+    }
+
+    /// Returns the IDs of all tenants, sorted.
+    pub fn tenants(&self) -> Vec<String> {
+        let mut tenants: Vec<String> = self.read().keys().cloned().collect();
+        tenants.sort();
+        tenants
+    }
+
+    /// Returns the supplier `name` as seen by `tenant`: the tenant's own supplier, or else the
+    /// base supplier.
+    pub fn get(&self, tenant: &str, name: &str) -> Option<Arc<dyn Supplier>> {
+        self.resolve(tenant, name).ok().and_then(|registry| registry.get(name))
+    }
+
+    /// Returns the supplier at `path`, a two-segment `tenant/name` path, as seen by the tenant.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::path::SupplierPath;
+    /// use supplier_kit::supplier::SupplierRegistry;
+    /// use supplier_kit::tenant::TenantRegistry;
+    /// use supplier_kit::testing::mock::MockSupplierBuilder;
+    ///
+    /// let mut acme = SupplierRegistry::new();
+    /// acme.register("erp/v2", MockSupplierBuilder::new("erp/v2").build());
+    /// let tenants = TenantRegistry::new();
+    /// tenants.insert("acme", acme);
+    ///
+    /// let path = SupplierPath::new("acme").child("erp/v2");
+    /// assert_eq!(path.to_string(), "acme/erp~1v2");
+    /// assert!(tenants.get_path(&path).is_some());
+    /// assert!(tenants.get_path(&"acme/erp/v2".parse().unwrap()).is_none());
+    /// ```
+    pub fn get_path(&self, path: &SupplierPath) -> Option<Arc<dyn Supplier>> {
+        match path.segments() {
+            [tenant, name] => self.get(tenant, name),
+            _ => None,
+        }
+    }
+
+    /// Returns the sorted names of every supplier visible to `tenant`, or `None` for unknown tenants.
+    pub fn supplier_names(&self, tenant: &str) -> Option<Vec<String>> {
+        let registry = self.registry(tenant)?;
+        let mut names = registry.all_names();
+        if let Some(base) = &self.base {
+            names.extend(base.all_names().into_iter().filter(|name| !registry.contains(name)));
+        }
+        names.sort();
+        Some(names)
+    }
+
+    /// Queries supplier `name` on behalf of `tenant`.
+    ///
+    /// The request's context tenant is set to `tenant`, replacing any tenant it carried.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::NotFound` if the tenant is unknown or no supplier `name` is
+    /// visible to it, or any error returned by the supplier.
+    pub fn query(&self, tenant: &str, name: &str, mut request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let registry = self.resolve(tenant, name)?;
+        request.context.tenant = Some(tenant.to_string());
+        registry.query(name, request)
+    }
+
+    /// Returns the registry serving supplier `name` for `tenant`.
+    fn resolve(&self, tenant: &str, name: &str) -> Result<Arc<SupplierRegistry>, SupplierError> {
+        let registry = self.registry(tenant).ok_or(SupplierError::NotFound)?;
+        if registry.contains(name) {
+            return Ok(registry);
+        }
+        match &self.base {
+            Some(base) if base.contains(name) => Ok(base.clone()),
+            _ => Err(SupplierError::NotFound),
+        }
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, HashMap<String, Arc<SupplierRegistry>>> {
+        self.tenants.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, HashMap<String, Arc<SupplierRegistry>>> {
+        self.tenants.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
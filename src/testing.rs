@@ -0,0 +1,14 @@
+/// Chaos supplier that injects latency, errors and malformed responses into another supplier.
+pub mod chaos;
+
+/// Configurable mock supplier with canned responses, scripted outcomes and request capture.
+pub mod mock;
+
+/// Assertion helpers for `SupplierGroupResult`.
+pub mod assertions;
+
+/// Suppliers that record live exchanges to NDJSON files and replay them offline.
+pub mod replay;
+
+/// Loader building registries of replay suppliers from a directory of fixture files.
+pub mod fixtures;
@@ -0,0 +1,87 @@
+use crate::supplier_group::SupplierGroupResult;
+
+/// Asserts that exactly the given suppliers succeeded, in the given order.
+///
+/// # Panics
+/// Panics with the actual successes and failures if they differ.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+/// use supplier_kit::testing::assertions::{assert_failed_with, assert_succeeded};
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let mut group = BasicSupplierGroup::new("shops");
+/// group.add_supplier(MockSupplierBuilder::new("a").respond_default(json!({})).build());
+/// group.add_supplier(MockSupplierBuilder::new("b").then_fail(SupplierError::Timeout).build());
+///
+/// let result = group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+/// assert_succeeded(&result, &["a"]);
+/// assert_failed_with(&result, "b", "timeout");
+/// ```
+#[track_caller]
+pub fn assert_succeeded(result: &SupplierGroupResult, suppliers: &[&str]) {
+    let actual: Vec<&str> = result.successes.iter().map(|(name, _)| name.as_str()).collect();
+    assert!(
+        actual == suppliers,
+        "expected successes from {suppliers:?}, got {actual:?} (failures: {:?})",
+        failure_summary(result)
+    );
+}
+
+/// Asserts that exactly the given suppliers failed, in the given order.
+///
+/// # Panics
+/// Panics with the actual failures if they differ.
+#[track_caller]
+pub fn assert_failed(result: &SupplierGroupResult, suppliers: &[&str]) {
+    let actual: Vec<&str> = result.failures.iter().map(|(name, _)| name.as_str()).collect();
+    assert!(
+        actual == suppliers,
+        "expected failures from {suppliers:?}, got {:?}",
+        failure_summary(result)
+    );
+}
+
+/// Asserts that `supplier` failed with an error whose [`code`](crate::errors::SupplierError::code) is `code`.
+///
+/// # Panics
+/// Panics if the supplier did not fail or failed with another code.
+#[track_caller]
+pub fn assert_failed_with(result: &SupplierGroupResult, supplier: &str, code: &str) {
+    match result.failures.iter().find(|(name, _)| name == supplier) {
+        Some((_, error)) => assert!(
+            error.code() == code,
+            "expected {supplier} to fail with `{code}`, got `{}`: {error}",
+            error.code()
+        ),
+        None => panic!(
+            "expected {supplier} to fail with `{code}`, but it did not fail (failures: {:?})",
+            failure_summary(result)
+        ),
+    }
+}
+
+/// Asserts that no supplier failed.
+///
+/// # Panics
+/// Panics with the failures if there are any.
+#[track_caller]
+pub fn assert_all_succeeded(result: &SupplierGroupResult) {
+    assert!(
+        result.failures.is_empty(),
+        "expected no failures, got {:?}",
+        failure_summary(result)
+    );
+}
+
+fn failure_summary(result: &SupplierGroupResult) -> Vec<String> {
+    result
+        .failures
+        .iter()
+        .map(|(name, error)| format!("{name}: {error}"))
+        .collect()
+}
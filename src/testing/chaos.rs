@@ -0,0 +1,165 @@
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use serde_json::{Map, Value};
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+use crate::utils::{unit, SplitMix64};
+
+/// A wrapper that injects faults into an inner supplier to exercise resilience policies.
+///
+/// Every query may be delayed by a latency drawn from the configured range, may fail with the
+/// configured error, and (if it succeeded) may have its response data replaced by malformed
+/// content. All random decisions come from a generator seeded at construction, so the same
+/// seed and the same sequence of queries always produce the same faults.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::testing::chaos::ChaosSupplier;
+///
+/// struct Healthy;
+///
+/// impl Supplier for Healthy {
+///     fn name(&self) -> &str { "healthy" }
+///     fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         Ok(SupplierResponse::new(serde_json::json!({ "ok": true })))
+///     }
+/// }
+///
+/// let chaos = ChaosSupplier::new(Healthy, 42)
+///     .with_error_rate(0.3)
+///     .with_error(SupplierError::Timeout)
+///     .with_latency(Duration::ZERO, Duration::from_millis(2));
+///
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({}));
+/// let failures = (0..100).filter(|_| chaos.query(request.clone()).is_err()).count();
+/// assert!(failures > 10 && failures < 50);
+/// ```
+pub struct ChaosSupplier<S> {
+    inner: S,
+    rng: Mutex<SplitMix64>,
+    latency: Option<(Duration, Duration)>,
+    error_rate: f64,
+    error: SupplierError,
+    malformed_rate: f64,
+}
+
+impl<S: Supplier> ChaosSupplier<S> {
+    /// Wraps `inner` without injecting anything yet; `seed` makes the injected faults reproducible.
+    pub fn new(inner: S, seed: u64) -> Self {
+        Self {
+            inner,
+            rng: Mutex::new(SplitMix64(seed)),
+            latency: None,
+            error_rate: 0.0,
+            error: SupplierError::Internal("chaos: injected failure".into()),
+            malformed_rate: 0.0,
+        }
+    }
+
+    /// Delays every query by a latency drawn uniformly from `min..=max`.
+    pub fn with_latency(mut self, min: Duration, max: Duration) -> Self {
+        self.latency = Some((min.min(max), max.max(min)));
+        self
+    }
+
+    /// Fails the given share of queries (between `0.0` and `1.0`) without calling the inner supplier.
+    pub fn with_error_rate(mut self, rate: f64) -> Self {
+        self.error_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the error returned by injected failures.
+    pub fn with_error(mut self, error: SupplierError) -> Self {
+        self.error = error;
+        self
+    }
+
+    /// Corrupts the data of the given share of successful responses (between `0.0` and `1.0`).
+    ///
+    /// Corrupted data is either `null`, a non-JSON-looking string, an empty array, or the
+    /// original object with one field removed.
+    pub fn with_malformed_rate(mut self, rate: f64) -> Self {
+        self.malformed_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the wrapped supplier.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    fn next(&self) -> u64 {
+        self.rng.lock().unwrap_or_else(|e| e.into_inner()).next()
+    }
+
+    /// Returns `true` with the given probability.
+    fn chance(&self, probability: f64) -> bool {
+        probability > 0.0 && unit(self.next()) < probability
+    }
+
+    fn malform(&self, data: Value) -> Value {
+        match self.next() % 4 {
+            0 => Value::Null,
+            1 => Value::String("<html>502 Bad Gateway</html>".into()),
+            2 => Value::Array(Vec::new()),
+            _ => match data {
+                Value::Object(map) if !map.is_empty() => {
+                    let skip = (self.next() % map.len() as u64) as usize;
+                    Value::Object(
+                        map.into_iter()
+                            .enumerate()
+                            .filter(|(i, _)| *i != skip)
+                            .map(|(_, entry)| entry)
+                            .collect::<Map<String, Value>>(),
+                    )
+                }
+                _ => Value::Null,
+            },
+        }
+    }
+}
+
+impl<S: Supplier> Supplier for ChaosSupplier<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        if let Some((min, max)) = self.latency {
+            let spread = (max - min).as_secs_f64();
+            thread::sleep(min + Duration::from_secs_f64(spread * unit(self.next())));
+        }
+        if self.chance(self.error_rate) {
+            return Err(self.error.clone());
+        }
+
+        let mut response = self.inner.query(request)?;
+        if self.chance(self.malformed_rate) {
+            response.data = self.malform(response.data);
+        }
+        Ok(response)
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use crate::supplier::{Supplier, SupplierRegistry};
+use crate::testing::replay::{read_exchanges, read_fixture, RecordedExchange, ReplaySupplier};
+
+/// Builds replay suppliers from a directory of fixture files.
+///
+/// Every `*.ndjson` file holds one [`RecordedExchange`] per line, as written by
+/// `RecordingSupplier`. Every `*.json` file holds a JSON array of exchanges, which is
+/// easier to write by hand. Other files are ignored.
+///
+/// Both kinds may be compressed, with an extra `.gz`, `.zst` or `.lz4` extension
+/// (`shop_a.ndjson.zst`); they are decompressed while loading when the matching feature is
+/// enabled.
+///
+/// Exchanges are grouped by their `supplier` field; exchanges without one belong to the
+/// supplier named after the file stem, so `fixtures/shop_a.json` defines `shop_a`.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::testing::fixtures::FixtureLoader;
+///
+/// let dir = std::env::temp_dir().join(format!("supplier_kit_fixture_doc_{}", std::process::id()));
+/// std::fs::create_dir_all(&dir).unwrap();
+/// std::fs::write(
+///     dir.join("shop_a.json"),
+///     r#"[{ "operation": "search", "params": { "q": "tv" }, "response": { "items": [1] }, "latency_ms": 40 }]"#,
+/// ).unwrap();
+///
+/// let registry = FixtureLoader::new(&dir).with_latency_scale(0.0).load_registry().unwrap();
+/// let request = SupplierRequest::new(SupplierOperation::Search, json!({ "q": "tv" }));
+/// assert_eq!(registry.query("shop_a", request).unwrap().data["items"][0], 1);
+/// # std::fs::remove_dir_all(dir).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixtureLoader {
+    dir: PathBuf,
+    latency_scale: Option<f64>,
+}
+
+impl FixtureLoader {
+    /// Creates a loader reading the fixture files in `dir`.
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+            latency_scale: None,
+        }
+    }
+
+    /// Makes the loaded suppliers simulate the recorded latency multiplied by `scale`.
+    pub fn with_latency_scale(mut self, scale: f64) -> Self {
+        self.latency_scale = Some(scale);
+        self
+    }
+
+    /// Loads every fixture file and returns the replay suppliers sorted by name.
+    ///
+    /// # Errors
+    /// Returns an error if the directory or a fixture file cannot be read or parsed.
+    pub fn load(&self) -> io::Result<Vec<ReplaySupplier>> {
+        let mut paths: Vec<PathBuf> = fs::read_dir(&self.dir)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<io::Result<_>>()?;
+        paths.sort();
+
+        let mut by_supplier: BTreeMap<String, Vec<RecordedExchange>> = BTreeMap::new();
+        for path in paths {
+            let Some((stem, kind)) = fixture_name(&path) else {
+                continue;
+            };
+            let exchanges = match kind {
+                "ndjson" => read_exchanges(&path)?,
+                _ => read_json_array(&path)?,
+            };
+            for mut exchange in exchanges {
+                if exchange.supplier.is_empty() {
+                    exchange.supplier = stem.to_string();
+                }
+                by_supplier.entry(exchange.supplier.clone()).or_default().push(exchange);
+            }
+        }
+
+        Ok(by_supplier
+            .into_iter()
+            .map(|(name, exchanges)| {
+                let supplier = ReplaySupplier::new(&name, exchanges);
+                match self.latency_scale {
+                    Some(scale) => supplier.with_simulated_latency(scale),
+                    None => supplier,
+                }
+            })
+            .collect())
+    }
+
+    /// Loads every fixture file into a registry holding one replay supplier per name.
+    ///
+    /// # Errors
+    /// Returns an error if the directory or a fixture file cannot be read or parsed.
+    pub fn load_registry(&self) -> io::Result<SupplierRegistry> {
+        let mut registry = SupplierRegistry::new();
+        for supplier in self.load()? {
+            let name = supplier.name().to_string();
+            registry.register(&name, supplier);
+        }
+        Ok(registry)
+    }
+}
+
+/// Splits a fixture file name into its supplier stem and kind (`ndjson` or `json`), ignoring a
+/// compression extension.
+fn fixture_name(path: &Path) -> Option<(&str, &str)> {
+    let name = path.file_name()?.to_str()?;
+    let name = [".gz", ".zst", ".lz4"]
+        .iter()
+        .find_map(|suffix| name.strip_suffix(suffix))
+        .unwrap_or(name);
+    let (stem, kind) = name.rsplit_once('.')?;
+    matches!(kind, "ndjson" | "json").then_some((stem, kind))
+}
+
+fn read_json_array(path: &Path) -> io::Result<Vec<RecordedExchange>> {
+    let content = read_fixture(path)?;
+    serde_json::from_str(&content)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e)))
+}
@@ -0,0 +1,213 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::Duration;
+use serde_json::Value;
+use crate::descriptor::{OperationDescriptor, SupplierDescriptor};
+use crate::errors::SupplierError;
+use crate::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+
+/// Builds a [`MockSupplier`] with canned responses and scripted outcomes.
+///
+/// Each query first consumes the next scripted outcome, if any. Once the script is exhausted,
+/// the supplier answers with the canned response for the request's operation, falling back to
+/// the default response, and finally to `SupplierError::UnsupportedOperation`.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::testing::mock::MockSupplierBuilder;
+///
+/// let mock = MockSupplierBuilder::new("shop")
+///     .respond(SupplierOperation::Search, json!({ "items": [] }))
+///     .then_fail(SupplierError::Timeout)
+///     .build();
+///
+/// let request = SupplierRequest::new(SupplierOperation::Search, json!({ "q": "tv" }));
+/// assert!(mock.query(request.clone()).is_err());
+/// assert_eq!(mock.query(request).unwrap().data, json!({ "items": [] }));
+/// assert_eq!(mock.calls(), 2);
+/// assert_eq!(mock.requests()[0].params["q"], "tv");
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockSupplierBuilder {
+    name: String,
+    responses: HashMap<String, Value>,
+    default_response: Option<Value>,
+    script: VecDeque<Result<Value, SupplierError>>,
+    delay: Duration,
+    operations: Vec<OperationDescriptor>,
+    version: Option<String>,
+    locales: Vec<String>,
+}
+
+impl MockSupplierBuilder {
+    /// Starts building a mock supplier with the given name.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            responses: HashMap::new(),
+            default_response: None,
+            script: VecDeque::new(),
+            delay: Duration::ZERO,
+            operations: Vec::new(),
+            version: None,
+            locales: Vec::new(),
+        }
+    }
+
+    /// Answers every request for `operation` with `data`.
+    pub fn respond(mut self, operation: SupplierOperation, data: Value) -> Self {
+        self.responses.insert(operation.as_str().to_string(), data);
+        self
+    }
+
+    /// Answers requests for operations without a canned response with `data`.
+    pub fn respond_default(mut self, data: Value) -> Self {
+        self.default_response = Some(data);
+        self
+    }
+
+    /// Appends a scripted failure, consumed by the next query that reaches the script.
+    pub fn then_fail(mut self, error: SupplierError) -> Self {
+        self.script.push_back(Err(error));
+        self
+    }
+
+    /// Appends a scripted success, consumed by the next query that reaches the script.
+    pub fn then_respond(mut self, data: Value) -> Self {
+        self.script.push_back(Ok(data));
+        self
+    }
+
+    /// Delays every query by `delay`.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Declares `operation` in the mock's descriptor, e.g. to exercise params schemas.
+    pub fn describe_operation(mut self, operation: OperationDescriptor) -> Self {
+        self.operations.push(operation);
+        self
+    }
+
+    /// Sets the integration version reported in the mock's descriptor.
+    pub fn with_version(mut self, version: &str) -> Self {
+        self.version = Some(version.to_string());
+        self
+    }
+
+    /// Declares `locale` in the mock's descriptor; the first one declared is its default.
+    pub fn with_locale(mut self, locale: &str) -> Self {
+        self.locales.push(locale.to_string());
+        self
+    }
+
+    /// Builds the mock supplier.
+    pub fn build(self) -> MockSupplier {
+        MockSupplier {
+            name: self.name,
+            delay: self.delay,
+            operations: Arc::new(self.operations),
+            version: self.version,
+            locales: Arc::new(self.locales),
+            responses: Arc::new(self.responses),
+            default_response: Arc::new(self.default_response),
+            state: Arc::new(Mutex::new(MockState {
+                script: self.script,
+                requests: Vec::new(),
+            })),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MockState {
+    script: VecDeque<Result<Value, SupplierError>>,
+    requests: Vec<SupplierRequest>,
+}
+
+/// A supplier answering from a script and canned responses while recording every request.
+///
+/// Clones share their script and recorded requests, so a test can add one clone to a group
+/// and inspect another afterwards. Created with [`MockSupplierBuilder`].
+#[derive(Debug, Clone)]
+pub struct MockSupplier {
+    name: String,
+    delay: Duration,
+    operations: Arc<Vec<OperationDescriptor>>,
+    version: Option<String>,
+    locales: Arc<Vec<String>>,
+    responses: Arc<HashMap<String, Value>>,
+    default_response: Arc<Option<Value>>,
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockSupplier {
+    /// Returns how many times the supplier was queried.
+    pub fn calls(&self) -> usize {
+        self.state().requests.len()
+    }
+
+    /// Returns every request received so far, in arrival order.
+    pub fn requests(&self) -> Vec<SupplierRequest> {
+        self.state().requests.clone()
+    }
+
+    /// Returns the most recent request, if any.
+    pub fn last_request(&self) -> Option<SupplierRequest> {
+        self.state().requests.last().cloned()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Supplier for MockSupplier {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        if !self.delay.is_zero() {
+            thread::sleep(self.delay);
+        }
+
+        let operation = request.operation.as_str().to_string();
+        let scripted = {
+            let mut state = self.state();
+            state.requests.push(request);
+            state.script.pop_front()
+        };
+
+        let data = match scripted {
+            Some(outcome) => outcome?,
+            None => self
+                .responses
+                .get(&operation)
+                .or(self.default_response.as_ref().as_ref())
+                .cloned()
+                .ok_or(SupplierError::UnsupportedOperation(operation))?,
+        };
+        Ok(SupplierResponse::new(data))
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        let descriptor = self
+            .operations
+            .iter()
+            .cloned()
+            .fold(SupplierDescriptor::new(&self.name), SupplierDescriptor::with_operation);
+        let descriptor = self.locales.iter().fold(descriptor, |descriptor, locale| descriptor.with_locale(locale));
+        match &self.version {
+            Some(version) => descriptor.with_version(version),
+            None => descriptor,
+        }
+    }
+}
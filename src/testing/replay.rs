@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::compression::{compress_stream, inflate, Compression};
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::models::{ResponseSource, SupplierOperation, SupplierRequest, SupplierResponse};
+use crate::redaction::Redactor;
+use crate::supplier::Supplier;
+
+/// One recorded request/outcome pair, stored as a single NDJSON line.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedExchange {
+    /// The name of the supplier that answered.
+    ///
+    /// Hand-written fixtures may leave it out; the fixture loader then uses the file name.
+    #[serde(default)]
+    pub supplier: String,
+
+    /// The operation of the request.
+    pub operation: SupplierOperation,
+
+    /// The parameters of the request.
+    pub params: Value,
+
+    /// The response data, if the query succeeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<Value>,
+
+    /// The error, if the query failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<SupplierError>,
+
+    /// How long the live query took, in milliseconds.
+    #[serde(default)]
+    pub latency_ms: u64,
+}
+
+impl RecordedExchange {
+    /// Returns the recorded outcome as a query result.
+    pub fn outcome(&self) -> Result<SupplierResponse, SupplierError> {
+        match (&self.error, &self.response) {
+            (Some(error), _) => Err(error.clone()),
+            (None, data) => Ok(SupplierResponse::new(data.clone().unwrap_or(Value::Null))),
+        }
+    }
+
+    fn key(&self) -> String {
+        replay_key(&self.operation, &self.params)
+    }
+}
+
+/// Reads recorded exchanges from an NDJSON file. Blank lines are skipped.
+///
+/// Files compressed as gzip, zstd or LZ4 streams, such as those written by a
+/// [`RecordingSupplier`] with compression, are decompressed transparently.
+///
+/// # Errors
+/// Returns an `io::ErrorKind::InvalidData` error naming the line that is not a valid exchange,
+/// or if the file is not valid (decompressed) UTF-8.
+pub fn read_exchanges<P: AsRef<Path>>(path: P) -> io::Result<Vec<RecordedExchange>> {
+    let content = read_fixture(path.as_ref())?;
+    let mut exchanges = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let exchange = serde_json::from_str(line)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {}", index + 1, e)))?;
+        exchanges.push(exchange);
+    }
+    Ok(exchanges)
+}
+
+/// Reads a fixture file as text, decompressing it if it is a compressed stream.
+pub(crate) fn read_fixture(path: &Path) -> io::Result<String> {
+    let bytes = fs::read(path)?;
+    let invalid = |e: String| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e));
+    let bytes = inflate(&bytes, None).map_err(|e| invalid(e.to_string()))?;
+    String::from_utf8(bytes.into_owned()).map_err(|e| invalid(e.to_string()))
+}
+
+/// A wrapper that forwards queries to a live supplier and appends every exchange to an NDJSON file.
+///
+/// The file is opened in append mode, so several recording suppliers may share it;
+/// a [`ReplaySupplier`] later serves the exchanges back. A failed write never fails the query;
+/// the error is kept and can be inspected with [`RecordingSupplier::take_error`].
+///
+/// Recordings of large responses can be compressed with
+/// [`with_compression`](RecordingSupplier::with_compression).
+///
+/// # Example
+/// ```no_run
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::testing::replay::{RecordingSupplier, ReplaySupplier};
+///
+/// struct LiveApi;
+///
+/// impl Supplier for LiveApi {
+///     fn name(&self) -> &str { "live_api" }
+///     fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         Ok(SupplierResponse::new(request.params))
+///     }
+/// }
+///
+/// let recorder = RecordingSupplier::new(LiveApi, "fixtures/live_api.ndjson").unwrap();
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({ "q": "tv" }));
+/// recorder.query(request.clone()).unwrap();
+///
+/// let replay = ReplaySupplier::from_file("live_api", "fixtures/live_api.ndjson").unwrap();
+/// assert_eq!(replay.query(request).unwrap().data["q"], "tv");
+/// ```
+pub struct RecordingSupplier<S> {
+    inner: S,
+    file: Mutex<File>,
+    error: Mutex<Option<io::Error>>,
+    redactor: Option<Redactor>,
+    compression: Compression,
+}
+
+impl<S: Supplier> RecordingSupplier<S> {
+    /// Wraps `inner`, appending its exchanges to the file at `path` (created if missing).
+    pub fn new<P: AsRef<Path>>(inner: S, path: P) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            inner,
+            file: Mutex::new(file),
+            error: Mutex::new(None),
+            redactor: None,
+            compression: Compression::None,
+        })
+    }
+
+    /// Compresses every recorded line as a standard stream (see `compression::compress_stream`).
+    ///
+    /// The streams are appended one after another, so the file stays readable with `zcat`,
+    /// `zstdcat` or `lz4cat` and by [`read_exchanges`]. Name it with the matching extension
+    /// (`.ndjson.gz`, `.ndjson.zst` or `.ndjson.lz4`) so that `FixtureLoader` picks it up, and
+    /// do not append compressed and plain lines to the same file.
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Redacts recorded parameters (`params.*` rules) and response data (`data.*` rules).
+    ///
+    /// Replay the recording with a [`ReplaySupplier`] using the same redactor so that live
+    /// requests still match their redacted recordings.
+    pub fn with_redactor(mut self, redactor: Redactor) -> Self {
+        self.redactor = Some(redactor);
+        self
+    }
+
+    /// Returns and clears the most recent write error, if any.
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.error.lock().unwrap_or_else(|e| e.into_inner()).take()
+    }
+
+    fn append(&self, exchange: &RecordedExchange) -> io::Result<()> {
+        let mut line = serde_json::to_vec(exchange)?;
+        line.push(b'\n');
+        let line = compress_stream(&line, self.compression).map_err(io::Error::other)?;
+        self.file.lock().unwrap_or_else(|e| e.into_inner()).write_all(&line)
+    }
+}
+
+impl<S: Supplier> Supplier for RecordingSupplier<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let operation = request.operation.clone();
+        let params = request.params.clone();
+        let started = Instant::now();
+        let result = self.inner.query(request);
+
+        let mut exchange = RecordedExchange {
+            supplier: self.inner.name().to_string(),
+            operation,
+            params,
+            response: result.as_ref().ok().map(|r| r.data.clone()),
+            error: result.as_ref().err().cloned(),
+            latency_ms: started.elapsed().as_millis() as u64,
+        };
+        if let Some(redactor) = &self.redactor {
+            exchange.params = redactor.redact_field("params", &exchange.params);
+            exchange.response = exchange.response.map(|data| redactor.redact_field("data", &data));
+        }
+        if let Err(e) = self.append(&exchange) {
+            *self.error.lock().unwrap_or_else(|e| e.into_inner()) = Some(e);
+        }
+        result
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
+
+/// A supplier that answers from recorded exchanges instead of a live API.
+///
+/// Requests are matched by operation and parameters; the request context is ignored.
+/// When several exchanges match, they are served in recording order and the last one repeats.
+/// Responses are marked with `ResponseSource::Replay`. Requests without a recording fail with
+/// `SupplierError::Internal`.
+pub struct ReplaySupplier {
+    name: String,
+    exchanges: HashMap<String, Vec<RecordedExchange>>,
+    served: Mutex<HashMap<String, usize>>,
+    latency_scale: Option<f64>,
+    redactor: Option<Redactor>,
+}
+
+impl ReplaySupplier {
+    /// Creates a replay supplier serving the given exchanges under `name`.
+    pub fn new<I>(name: &str, exchanges: I) -> Self
+    where
+        I: IntoIterator<Item = RecordedExchange>,
+    {
+        let mut grouped: HashMap<String, Vec<RecordedExchange>> = HashMap::new();
+        for exchange in exchanges {
+            grouped.entry(exchange.key()).or_default().push(exchange);
+        }
+        Self {
+            name: name.to_string(),
+            exchanges: grouped,
+            served: Mutex::new(HashMap::new()),
+            latency_scale: None,
+            redactor: None,
+        }
+    }
+
+    /// Redacts incoming request parameters before matching, for recordings made with a redactor.
+    pub fn with_redactor(mut self, redactor: Redactor) -> Self {
+        self.redactor = Some(redactor);
+        self
+    }
+
+    /// Delays every answer by its recorded latency multiplied by `scale`.
+    ///
+    /// A scale of `1.0` reproduces the live timings; `0.1` replays ten times faster.
+    pub fn with_simulated_latency(mut self, scale: f64) -> Self {
+        self.latency_scale = Some(scale.max(0.0));
+        self
+    }
+
+    /// Loads the exchanges recorded for the supplier `name` from an NDJSON file.
+    pub fn from_file<P: AsRef<Path>>(name: &str, path: P) -> io::Result<Self> {
+        let exchanges = read_exchanges(path)?.into_iter().filter(|e| e.supplier == name);
+        Ok(Self::new(name, exchanges))
+    }
+
+    /// Returns the number of recorded exchanges.
+    pub fn len(&self) -> usize {
+        self.exchanges.values().map(Vec::len).sum()
+    }
+
+    /// Returns whether no exchange was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.exchanges.is_empty()
+    }
+
+    /// Returns the exchange answering `request`, advancing through repeated recordings.
+    fn next_exchange(&self, request: &SupplierRequest) -> Result<&RecordedExchange, SupplierError> {
+        let key = match &self.redactor {
+            Some(redactor) => replay_key(&request.operation, &redactor.redact_field("params", &request.params)),
+            None => replay_key(&request.operation, &request.params),
+        };
+        let Some(recorded) = self.exchanges.get(&key) else {
+            return Err(SupplierError::Internal(format!(
+                "replay: no recorded exchange for {} {}",
+                request.operation.as_str(),
+                request.params
+            )));
+        };
+
+        let mut served = self.served.lock().unwrap_or_else(|e| e.into_inner());
+        let count = served.entry(key).or_insert(0);
+        let exchange = &recorded[(*count).min(recorded.len() - 1)];
+        *count += 1;
+        Ok(exchange)
+    }
+}
+
+impl Supplier for ReplaySupplier {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let exchange = self.next_exchange(&request)?;
+        if let Some(scale) = self.latency_scale {
+            thread::sleep(Duration::from_millis(exchange.latency_ms).mul_f64(scale));
+        }
+        exchange
+            .outcome()
+            .map(|response| response.with_source(ResponseSource::Replay))
+    }
+}
+
+fn replay_key(operation: &SupplierOperation, params: &Value) -> String {
+    format!("{}\n{}", operation.as_str(), params)
+}
@@ -0,0 +1,127 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::errors::SupplierError;
+
+/// Reset values above this many seconds are read as Unix timestamps rather than delays.
+const EPOCH_THRESHOLD: u64 = 1_000_000_000;
+
+/// Parses an HTTP `Retry-After` value, either delay-seconds or an IMF-fixdate such as
+/// `Wed, 21 Oct 2015 07:28:00 GMT`.
+///
+/// Dates in the past yield a zero wait; unparseable values, including dates too far in the
+/// future to represent, yield `None`.
+///
+/// # Example
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use supplier_kit::throttling::parse_retry_after;
+///
+/// let now = UNIX_EPOCH + Duration::from_secs(1_445_412_470);
+/// assert_eq!(parse_retry_after("120", now), Some(Duration::from_secs(120)));
+/// assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT", now), Some(Duration::from_secs(10)));
+/// assert_eq!(parse_retry_after("soon", now), None);
+/// ```
+pub fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = parse_http_date(value)?;
+    Some(at.duration_since(now).unwrap_or_default())
+}
+
+/// Returns the wait requested by throttling headers of an HTTP response.
+///
+/// `Retry-After` takes precedence. Otherwise, once `RateLimit-Remaining` or
+/// `X-RateLimit-Remaining` reaches zero, `RateLimit-Reset` or `X-RateLimit-Reset` gives the
+/// wait, read as seconds or, for large values, as a Unix timestamp. Header names are matched
+/// case-insensitively.
+///
+/// # Example
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use supplier_kit::throttling::retry_after_from_headers;
+///
+/// let now = SystemTime::now();
+/// let headers = [("x-ratelimit-remaining", "0"), ("X-RateLimit-Reset", "30")];
+/// assert_eq!(retry_after_from_headers(headers, now), Some(Duration::from_secs(30)));
+/// assert_eq!(retry_after_from_headers([("X-RateLimit-Remaining", "12"), ("X-RateLimit-Reset", "30")], now), None);
+/// ```
+pub fn retry_after_from_headers<'a>(headers: impl IntoIterator<Item = (&'a str, &'a str)>, now: SystemTime) -> Option<Duration> {
+    let (mut retry_after, mut remaining, mut reset) = (None, None, None);
+    for (name, value) in headers {
+        match name.to_ascii_lowercase().as_str() {
+            "retry-after" => retry_after = parse_retry_after(value, now),
+            "ratelimit-remaining" | "x-ratelimit-remaining" => remaining = value.trim().parse::<f64>().ok(),
+            "ratelimit-reset" | "x-ratelimit-reset" => reset = value.trim().parse::<u64>().ok(),
+            _ => {}
+        }
+    }
+    retry_after.or_else(|| {
+        let reset = reset.filter(|_| remaining.is_some_and(|remaining| remaining <= 0.0))?;
+        if reset < EPOCH_THRESHOLD {
+            return Some(Duration::from_secs(reset));
+        }
+        let at = UNIX_EPOCH.checked_add(Duration::from_secs(reset))?;
+        Some(at.duration_since(now).unwrap_or_default())
+    })
+}
+
+/// Turns an HTTP response status and its headers into `SupplierError::Throttled`, for
+/// suppliers that call HTTP APIs.
+///
+/// `429 Too Many Requests` is always a throttle; `503 Service Unavailable` is one only when
+/// the response says when to retry. Other responses return `None`.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::throttling::throttled_from_http;
+///
+/// let error = throttled_from_http(429, [("Retry-After", "5")]).unwrap();
+/// assert_eq!(error.retry_after(), Some(Duration::from_secs(5)));
+/// assert!(matches!(throttled_from_http(429, []), Some(SupplierError::Throttled { retry_after: None })));
+/// assert!(throttled_from_http(503, []).is_none());
+/// assert!(throttled_from_http(200, [("Retry-After", "5")]).is_none());
+/// ```
+pub fn throttled_from_http<'a>(status: u16, headers: impl IntoIterator<Item = (&'a str, &'a str)>) -> Option<SupplierError> {
+    let retry_after = match status {
+        429 | 503 => retry_after_from_headers(headers, SystemTime::now()),
+        _ => return None,
+    };
+    (status == 429 || retry_after.is_some()).then_some(SupplierError::Throttled { retry_after })
+}
+
+/// Parses an IMF-fixdate, the preferred HTTP date format.
+fn parse_http_date(value: &str) -> Option<SystemTime> {
+    let (_, date) = value.split_once(", ")?;
+    let parts: Vec<&str> = date.split(' ').collect();
+    let [day, month, year, time, "GMT"] = parts[..] else {
+        return None;
+    };
+    let month = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"]
+        .iter()
+        .position(|name| *name == month)? as u64
+        + 1;
+    let (day, year) = (day.parse::<u64>().ok()?, year.parse::<i64>().ok()?);
+    let mut clock = time.split(':').map(|part| part.parse::<u64>().ok());
+    let (hour, minute, second) = (clock.next()??, clock.next()??, clock.next()??);
+    if !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 || clock.next().is_some() {
+        return None;
+    }
+    let days = u64::try_from(days_from_civil(year, month, day)?).ok()?;
+    let seconds = days.checked_mul(86_400)?.checked_add(hour * 3_600 + minute * 60 + second)?;
+    UNIX_EPOCH.checked_add(Duration::from_secs(seconds))
+}
+
+/// Converts a (year, month, day) civil date to days since 1970-01-01, or `None` if the year
+/// is too large to count in days.
+fn days_from_civil(year: i64, month: u64, day: u64) -> Option<i64> {
+    let year = if month <= 2 { year.checked_sub(1)? } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 } as i64;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era.checked_mul(146_097)?.checked_add(doe - 719_468)
+}
@@ -72,9 +72,7 @@ pub fn add_supplier_from_registry(
 ///     fn name(&self) -> &str { "dummy" }
 ///     fn query(&self, _request: supplier_kit::models::SupplierRequest)
 ///         -> Result<supplier_kit::models::SupplierResponse, SupplierError> {
-///         Ok(supplier_kit::models::SupplierResponse {
-///             data: serde_json::json!({ "ok": true }),
-///         })
+///         Ok(supplier_kit::models::SupplierResponse::new(serde_json::json!({ "ok": true })))
 ///     }
 /// }
 ///
@@ -103,3 +101,21 @@ pub fn add_suppliers_from_registry(
 
     failures
 }
+
+/// Maps a random integer to `0.0..1.0`.
+pub(crate) fn unit(value: u64) -> f64 {
+    (value >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// The SplitMix64 generator: tiny, fast, and good enough for fault injection and load balancing.
+pub(crate) struct SplitMix64(pub(crate) u64);
+
+impl SplitMix64 {
+    pub(crate) fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
@@ -0,0 +1,105 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use crate::errors::SupplierError;
+
+/// A serialization format for `SupplierRequest`, `SupplierResponse`, `SupplierGroupResult` and
+/// the other serializable types of the kit.
+///
+/// JSON is always available. MessagePack (`msgpack` feature) and CBOR (`cbor` feature) encode
+/// the same data model in a fraction of the size and are meant for high-volume traffic between
+/// services that both embed the kit. Structs are encoded as maps keyed by field name, so
+/// optional fields can be added or omitted exactly as in JSON, and response bodies are stored
+/// as raw bytes instead of base64.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WireFormat {
+    /// JSON, as used by the HTTP server and manifests.
+    #[default]
+    Json,
+    /// MessagePack (requires the `msgpack` feature).
+    #[cfg(feature = "msgpack")]
+    MessagePack,
+    /// CBOR, RFC 8949 (requires the `cbor` feature).
+    #[cfg(feature = "cbor")]
+    Cbor,
+}
+
+impl WireFormat {
+    /// Returns the media type of the format, e.g. for a `Content-Type` header.
+    pub fn content_type(self) -> &'static str {
+        match self {
+            WireFormat::Json => "application/json",
+            #[cfg(feature = "msgpack")]
+            WireFormat::MessagePack => "application/msgpack",
+            #[cfg(feature = "cbor")]
+            WireFormat::Cbor => "application/cbor",
+        }
+    }
+
+    /// Returns the format for a media type, ignoring parameters such as `charset`.
+    ///
+    /// Returns `None` for unknown media types and for formats whose feature is not enabled in
+    /// this build.
+    ///
+    /// # Example
+    /// ```
+    /// use supplier_kit::wire::WireFormat;
+    /// assert_eq!(WireFormat::from_content_type("application/json; charset=utf-8"), Some(WireFormat::Json));
+    /// assert_eq!(WireFormat::from_content_type("text/html"), None);
+    /// ```
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let essence = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+        match essence.as_str() {
+            "application/json" => Some(WireFormat::Json),
+            #[cfg(feature = "msgpack")]
+            "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack" => Some(WireFormat::MessagePack),
+            #[cfg(feature = "cbor")]
+            "application/cbor" => Some(WireFormat::Cbor),
+            _ => None,
+        }
+    }
+}
+
+/// Serializes `value` in the given format.
+///
+/// # Example
+/// ```
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest};
+/// use supplier_kit::wire::{decode, encode, WireFormat};
+///
+/// let request = SupplierRequest::new(SupplierOperation::Search, serde_json::json!({ "q": "lamp" }));
+/// let bytes = encode(&request, WireFormat::Json).unwrap();
+/// assert_eq!(decode::<SupplierRequest>(&bytes, WireFormat::Json).unwrap(), request);
+/// ```
+pub fn encode<T: Serialize>(value: &T, format: WireFormat) -> Result<Vec<u8>, SupplierError> {
+    match format {
+        WireFormat::Json => serde_json::to_vec(value)
+            .map_err(|e| SupplierError::Internal(format!("JSON encoding failed: {}", e))),
+        #[cfg(feature = "msgpack")]
+        WireFormat::MessagePack => rmp_serde::to_vec_named(value)
+            .map_err(|e| SupplierError::Internal(format!("MessagePack encoding failed: {}", e))),
+        #[cfg(feature = "cbor")]
+        WireFormat::Cbor => {
+            let mut bytes = Vec::new();
+            ciborium::into_writer(value, &mut bytes)
+                .map_err(|e| SupplierError::Internal(format!("CBOR encoding failed: {}", e)))?;
+            Ok(bytes)
+        }
+    }
+}
+
+/// Deserializes a value encoded by [`encode`] in the given format.
+///
+/// # Returns
+/// `Err(SupplierError::InvalidInput)` if the payload is corrupt or does not match `T`.
+pub fn decode<T: DeserializeOwned>(bytes: &[u8], format: WireFormat) -> Result<T, SupplierError> {
+    match format {
+        WireFormat::Json => serde_json::from_slice(bytes)
+            .map_err(|e| SupplierError::InvalidInput(format!("invalid JSON payload: {}", e))),
+        #[cfg(feature = "msgpack")]
+        WireFormat::MessagePack => rmp_serde::from_slice(bytes)
+            .map_err(|e| SupplierError::InvalidInput(format!("invalid MessagePack payload: {}", e))),
+        #[cfg(feature = "cbor")]
+        WireFormat::Cbor => ciborium::from_reader(bytes)
+            .map_err(|e| SupplierError::InvalidInput(format!("invalid CBOR payload: {}", e))),
+    }
+}
@@ -0,0 +1,403 @@
+use std::collections::HashSet;
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, XmlVersion};
+use serde_json::{Map, Value};
+use crate::descriptor::SupplierDescriptor;
+use crate::errors::SupplierError;
+use crate::mapping::ResponseNormalizer;
+use crate::models::{SupplierRequest, SupplierResponse};
+use crate::supplier::Supplier;
+
+/// Converts XML documents into JSON values, so XML and SOAP suppliers can return their results
+/// as ordinary `SupplierResponse::data`.
+///
+/// The mapping follows the common XML-to-JSON convention:
+///
+/// - every element becomes a key named after the element;
+/// - an element with neither attributes nor child elements becomes its text (`null` if empty);
+/// - other elements become objects, with attributes under `@name` and any text under `#text`;
+/// - repeated sibling elements become an array, and elements registered with
+///   [`with_array`](Self::with_array) are always arrays, even with a single occurrence.
+///
+/// Namespace prefixes and `xmlns` declarations are dropped unless
+/// [`with_namespaces`](Self::with_namespaces) is set, and text stays a string unless
+/// [`with_type_inference`](Self::with_type_inference) is set. [`with_root`](Self::with_root)
+/// selects the element whose value becomes the result, e.g. the payload inside a SOAP envelope.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::xml::XmlNormalizer;
+///
+/// let normalizer = XmlNormalizer::new()
+///     .with_root("Envelope/Body/StockResponse")
+///     .with_array("item")
+///     .with_type_inference(true);
+///
+/// let data = normalizer
+///     .normalize(
+///         r#"<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+///              <soap:Body>
+///                <StockResponse warehouse="north">
+///                  <item sku="A1"><stock>4</stock></item>
+///                </StockResponse>
+///              </soap:Body>
+///            </soap:Envelope>"#,
+///     )
+///     .unwrap();
+/// assert_eq!(data, json!({ "@warehouse": "north", "item": [{ "@sku": "A1", "stock": 4 }] }));
+/// ```
+#[derive(Debug, Clone)]
+pub struct XmlNormalizer {
+    attribute_prefix: String,
+    text_key: String,
+    keep_namespaces: bool,
+    infer_types: bool,
+    arrays: HashSet<String>,
+    root: Vec<String>,
+}
+
+impl Default for XmlNormalizer {
+    fn default() -> Self {
+        Self {
+            attribute_prefix: "@".to_string(),
+            text_key: "#text".to_string(),
+            keep_namespaces: false,
+            infer_types: false,
+            arrays: HashSet::new(),
+            root: Vec::new(),
+        }
+    }
+}
+
+impl XmlNormalizer {
+    /// Creates a normalizer with the default mapping.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the prefix of keys holding attributes (default `@`).
+    pub fn with_attribute_prefix(mut self, prefix: &str) -> Self {
+        self.attribute_prefix = prefix.to_string();
+        self
+    }
+
+    /// Sets the key holding the text of elements that also have attributes or children
+    /// (default `#text`).
+    pub fn with_text_key(mut self, key: &str) -> Self {
+        self.text_key = key.to_string();
+        self
+    }
+
+    /// Sets whether element and attribute names keep their namespace prefix (`soap:Body`) and
+    /// `xmlns` declarations are kept as attributes. Off by default.
+    pub fn with_namespaces(mut self, keep: bool) -> Self {
+        self.keep_namespaces = keep;
+        self
+    }
+
+    /// Sets whether text that looks like a boolean or a number is converted to one. Off by
+    /// default; numbers with leading zeros, such as `007`, always stay strings.
+    pub fn with_type_inference(mut self, infer: bool) -> Self {
+        self.infer_types = infer;
+        self
+    }
+
+    /// Always maps elements named `element` to arrays, so a single occurrence has the same
+    /// shape as several.
+    pub fn with_array(mut self, element: &str) -> Self {
+        self.arrays.insert(element.to_string());
+        self
+    }
+
+    /// Selects the element whose value becomes the result, as a `/`-separated path of element
+    /// names starting at the document element, e.g. `Envelope/Body/GetStockResponse`.
+    ///
+    /// Without a root, the result is an object with the document element as its only key.
+    pub fn with_root(mut self, path: &str) -> Self {
+        self.root = path.split('/').filter(|s| !s.is_empty()).map(str::to_string).collect();
+        self
+    }
+
+    /// Converts an XML document into a JSON value.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::Upstream` if the document is not well-formed XML or has no
+    /// element at the configured root path.
+    pub fn normalize(&self, xml: &str) -> Result<Value, SupplierError> {
+        let document = self.parse(xml).map_err(|e| SupplierError::Upstream(format!("invalid XML response: {}", e)))?;
+        self.select(document)
+    }
+
+    /// Converts the XML body of `response` into its `data`, removing the body.
+    ///
+    /// Responses without a body, or with a body whose content type is not XML (such as
+    /// `application/xml`, `text/xml` or `application/soap+xml`), are returned unchanged.
+    ///
+    /// # Errors
+    /// Returns `SupplierError::Upstream` if the body is not valid UTF-8 or cannot be normalized.
+    pub fn normalize_response(&self, mut response: SupplierResponse) -> Result<SupplierResponse, SupplierError> {
+        let Some(body) = response.body.take_if(|body| is_xml(&body.content_type)) else {
+            return Ok(response);
+        };
+        let xml = body
+            .as_text()
+            .ok_or_else(|| SupplierError::Upstream("XML response is not valid UTF-8".to_string()))?;
+        response.data = self.normalize(xml)?;
+        Ok(response)
+    }
+
+    fn parse(&self, xml: &str) -> Result<Value, String> {
+        let mut reader = Reader::from_str(xml);
+        let mut stack: Vec<Element> = Vec::new();
+        let mut document = Map::new();
+        loop {
+            match reader.read_event().map_err(|e| e.to_string())? {
+                Event::Start(start) => stack.push(self.element(&start)?),
+                Event::Empty(start) => {
+                    let element = self.element(&start)?;
+                    self.close(element, &mut stack, &mut document)?;
+                }
+                Event::End(_) => {
+                    let element = stack.pop().ok_or("unexpected closing tag")?;
+                    self.close(element, &mut stack, &mut document)?;
+                }
+                Event::Text(text) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&text.xml10_content());
+                    }
+                }
+                Event::CData(data) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&data.xml10_content());
+                    }
+                }
+                Event::GeneralRef(reference) => {
+                    let resolved = match reference.resolve_char_ref().map_err(|e| e.to_string())? {
+                        Some(c) => c.to_string(),
+                        None => resolve_predefined_entity(&reference)
+                            .ok_or_else(|| format!("unknown entity '&{};'", &*reference))?
+                            .to_string(),
+                    };
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&resolved);
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        if !stack.is_empty() {
+            return Err("unexpected end of document".to_string());
+        }
+        if document.is_empty() {
+            return Err("no document element".to_string());
+        }
+        Ok(Value::Object(document))
+    }
+
+    fn element(&self, start: &BytesStart) -> Result<Element, String> {
+        let mut fields = Map::new();
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(|e| e.to_string())?;
+            let key: &str = attribute.key.as_ref();
+            if !self.keep_namespaces && (key == "xmlns" || key.starts_with("xmlns:")) {
+                continue;
+            }
+            let value = attribute
+                .normalized_value(XmlVersion::Implicit1_0)
+                .map_err(|e| e.to_string())?;
+            fields.insert(format!("{}{}", self.attribute_prefix, self.name(key)), self.scalar(&value));
+        }
+        Ok(Element {
+            name: self.name(start.name().as_ref()).to_string(),
+            fields,
+            text: String::new(),
+        })
+    }
+
+    fn close(&self, element: Element, stack: &mut [Element], document: &mut Map<String, Value>) -> Result<(), String> {
+        let Element { name, mut fields, text } = element;
+        let text = text.trim();
+        let value = if fields.is_empty() {
+            if text.is_empty() { Value::Null } else { self.scalar(text) }
+        } else {
+            if !text.is_empty() {
+                fields.insert(self.text_key.clone(), self.scalar(text));
+            }
+            Value::Object(fields)
+        };
+        match stack.last_mut() {
+            Some(parent) => {
+                self.insert(&mut parent.fields, name, value);
+                Ok(())
+            }
+            None if document.is_empty() => {
+                self.insert(document, name, value);
+                Ok(())
+            }
+            None => Err("more than one document element".to_string()),
+        }
+    }
+
+    fn insert(&self, fields: &mut Map<String, Value>, name: String, value: Value) {
+        let forced = self.arrays.contains(&name);
+        match fields.get_mut(&name) {
+            Some(Value::Array(items)) => items.push(value),
+            Some(existing) => *existing = Value::Array(vec![existing.take(), value]),
+            None if forced => {
+                fields.insert(name, Value::Array(vec![value]));
+            }
+            None => {
+                fields.insert(name, value);
+            }
+        }
+    }
+
+    fn select(&self, document: Value) -> Result<Value, SupplierError> {
+        let mut value = document;
+        for segment in &self.root {
+            value = match value {
+                Value::Object(mut fields) => fields.remove(segment),
+                _ => None,
+            }
+            .ok_or_else(|| {
+                SupplierError::Upstream(format!("XML response has no element at '{}'", self.root.join("/")))
+            })?;
+        }
+        Ok(value)
+    }
+
+    fn name<'a>(&self, name: &'a str) -> &'a str {
+        match name.split_once(':') {
+            Some((_, local)) if !self.keep_namespaces => local,
+            _ => name,
+        }
+    }
+
+    fn scalar(&self, text: &str) -> Value {
+        if !self.infer_types {
+            return Value::String(text.to_string());
+        }
+        match text {
+            "true" => return Value::Bool(true),
+            "false" => return Value::Bool(false),
+            _ => {}
+        }
+        let digits = text.strip_prefix('-').unwrap_or(text);
+        let leading_zero = digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.");
+        if !leading_zero && digits.starts_with(|c: char| c.is_ascii_digit()) {
+            if let Ok(n) = text.parse::<i64>() {
+                return Value::from(n);
+            }
+            if let Some(n) = text.parse::<f64>().ok().and_then(serde_json::Number::from_f64) {
+                return Value::Number(n);
+            }
+        }
+        Value::String(text.to_string())
+    }
+}
+
+/// An element whose closing tag has not been read yet.
+struct Element {
+    name: String,
+    fields: Map<String, Value>,
+    text: String,
+}
+
+fn is_xml(content_type: &str) -> bool {
+    let essence = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+    essence == "application/xml" || essence == "text/xml" || essence.ends_with("+xml")
+}
+
+impl ResponseNormalizer for XmlNormalizer {
+    fn normalize_response(&self, response: SupplierResponse) -> Result<SupplierResponse, SupplierError> {
+        XmlNormalizer::normalize_response(self, response)
+    }
+}
+
+/// A supplier decorator that converts XML response bodies into JSON `data` using an
+/// `XmlNormalizer`.
+///
+/// Wrap suppliers that return their documents as XML bodies (see
+/// `SupplierResponse::binary`), such as legacy B2B or SOAP services; callers then see the same
+/// JSON responses as from any other supplier. Responses without an XML body pass through
+/// unchanged.
+///
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use supplier_kit::errors::SupplierError;
+/// use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+/// use supplier_kit::supplier::Supplier;
+/// use supplier_kit::xml::{XmlNormalizer, XmlSupplier};
+///
+/// struct LegacyCatalog;
+///
+/// impl Supplier for LegacyCatalog {
+///     fn name(&self) -> &str {
+///         "legacy"
+///     }
+///
+///     fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+///         let xml = "<catalog><product sku=\"A1\">Lamp</product></catalog>";
+///         Ok(SupplierResponse::binary("application/xml", xml.as_bytes().to_vec()))
+///     }
+/// }
+///
+/// let supplier = XmlSupplier::new(LegacyCatalog, XmlNormalizer::new().with_root("catalog"));
+/// let response = supplier.query(SupplierRequest::new(SupplierOperation::Search, json!({}))).unwrap();
+/// assert_eq!(response.data, json!({ "product": { "@sku": "A1", "#text": "Lamp" } }));
+/// assert!(response.body.is_none());
+/// ```
+pub struct XmlSupplier<S> {
+    inner: S,
+    normalizer: XmlNormalizer,
+}
+
+impl<S: Supplier> XmlSupplier<S> {
+    /// Wraps `inner`, normalizing its XML responses with `normalizer`.
+    pub fn new(inner: S, normalizer: XmlNormalizer) -> Self {
+        Self { inner, normalizer }
+    }
+
+    /// Returns the normalizer applied to responses.
+    pub fn normalizer(&self) -> &XmlNormalizer {
+        &self.normalizer
+    }
+}
+
+impl<S: Supplier> Supplier for XmlSupplier<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        self.normalizer.normalize_response(self.inner.query(request)?)
+    }
+
+    fn query_batch(&self, requests: Vec<SupplierRequest>) -> Vec<Result<SupplierResponse, SupplierError>> {
+        self.inner
+            .query_batch(requests)
+            .into_iter()
+            .map(|result| result.and_then(|response| self.normalizer.normalize_response(response)))
+            .collect()
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        self.inner.warm_up()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.inner.health_check()
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        self.inner.describe()
+    }
+}
@@ -0,0 +1,114 @@
+use serde_json::json;
+use supplier_kit::access::{AccessPolicy, OperationGrant, ANY_CALLER};
+use supplier_kit::context::RequestContext;
+use supplier_kit::decorators::access::AccessControlledSupplier;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::{Supplier, SupplierRegistry};
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn request(caller: Option<&str>, operation: SupplierOperation) -> SupplierRequest {
+    let mut context = RequestContext::new();
+    context.caller = caller.map(str::to_string);
+    SupplierRequest::new(operation, json!({})).with_context(context)
+}
+
+fn team_b_policy() -> AccessPolicy {
+    AccessPolicy::new()
+        .allow_all("team-b")
+        .allow_reads("team-a")
+        .allow(ANY_CALLER, &[SupplierOperation::Search])
+}
+
+#[test]
+fn policies_deny_everything_not_granted() {
+    let policy = team_b_policy();
+
+    assert!(policy.permits(Some("team-b"), &SupplierOperation::Delete));
+    assert!(policy.permits(Some("team-a"), &SupplierOperation::GetDetail));
+    assert!(!policy.permits(Some("team-a"), &SupplierOperation::Submit));
+    assert!(policy.permits(None, &SupplierOperation::Search));
+    assert!(!policy.permits(None, &SupplierOperation::GetDetail));
+    assert!(!policy.permits(Some("team-c"), &SupplierOperation::Other("export".into())));
+    assert!(!AccessPolicy::new().permits(Some("team-b"), &SupplierOperation::Search));
+}
+
+#[test]
+fn custom_operations_can_be_granted_explicitly() {
+    let policy = AccessPolicy::new().grant("team-a", OperationGrant::Only(vec![SupplierOperation::from("Track Order")]));
+
+    assert!(policy.check(&request(Some("team-a"), SupplierOperation::Other("track_order".into()))).is_ok());
+    assert!(matches!(
+        policy.check(&request(Some("team-a"), SupplierOperation::Search)),
+        Err(SupplierError::Unauthorized)
+    ));
+}
+
+#[test]
+fn denied_calls_never_reach_the_supplier() {
+    let mock = MockSupplierBuilder::new("orders").respond_default(json!({ "ok": true })).build();
+    let supplier = AccessControlledSupplier::new(mock.clone(), team_b_policy());
+
+    assert!(matches!(
+        supplier.query(request(Some("team-a"), SupplierOperation::Create)),
+        Err(SupplierError::Unauthorized)
+    ));
+    assert_eq!(mock.calls(), 0);
+
+    supplier.query(request(Some("team-b"), SupplierOperation::Create)).unwrap();
+    assert_eq!(mock.calls(), 1);
+}
+
+#[test]
+fn registry_suppliers_enforce_their_policy() {
+    let mut registry = SupplierRegistry::new();
+    let mock = MockSupplierBuilder::new("orders").respond_default(json!({})).build();
+    registry.register("orders", AccessControlledSupplier::new(mock, team_b_policy()));
+
+    assert!(registry.query("orders", request(Some("team-a"), SupplierOperation::Search)).is_ok());
+    assert!(matches!(
+        registry.query("orders", request(Some("team-a"), SupplierOperation::Update)),
+        Err(SupplierError::Unauthorized)
+    ));
+}
+
+#[test]
+fn group_policies_apply_to_every_strategy() {
+    for strategy in [QueryStrategy::Sequential, QueryStrategy::Parallel, QueryStrategy::Race, QueryStrategy::Failover] {
+        let a = MockSupplierBuilder::new("a").respond_default(json!({})).build();
+        let b = MockSupplierBuilder::new("b").respond_default(json!({})).build();
+        let mut group = BasicSupplierGroup::new("team_b")
+            .with_strategy(strategy)
+            .with_access_policy(team_b_policy());
+        group.add_supplier(a.clone());
+        group.add_supplier(b.clone());
+
+        let denied = group.query(request(Some("team-a"), SupplierOperation::Delete));
+        assert!(denied.successes.is_empty(), "{:?}", strategy);
+        assert!(denied.failures.iter().all(|(_, e)| matches!(e, SupplierError::Unauthorized)));
+        assert_eq!(a.calls() + b.calls(), 0);
+
+        let allowed = group.query(request(Some("team-b"), SupplierOperation::Delete));
+        assert!(!allowed.successes.is_empty(), "{:?}", strategy);
+    }
+}
+
+#[test]
+fn group_batches_deny_requests_individually() {
+    let mock = MockSupplierBuilder::new("orders").respond_default(json!({})).build();
+    let mut group = BasicSupplierGroup::new("team_b").with_access_policy(team_b_policy());
+    group.add_supplier(mock.clone());
+
+    let results = group.query_batch(vec![
+        request(Some("team-a"), SupplierOperation::Search),
+        request(Some("team-a"), SupplierOperation::Create),
+        request(Some("team-b"), SupplierOperation::Create),
+    ]);
+
+    assert_eq!(results[0].successes.len(), 1);
+    assert!(matches!(results[1].failures[0].1, SupplierError::Unauthorized));
+    assert_eq!(results[2].successes.len(), 1);
+    assert_eq!(mock.calls(), 2);
+    assert_eq!(group.access_policy(), Some(&team_b_policy()));
+}
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::balancing::LoadTracker;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+fn shop(name: &str, delay: Duration) -> MockSupplier {
+    MockSupplierBuilder::new(name).respond_default(json!({ "shop": name })).with_delay(delay).build()
+}
+
+fn adaptive_group(suppliers: &[&MockSupplier]) -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new("replicas")
+        .with_strategy(QueryStrategy::Adaptive)
+        .with_load_tracker(Arc::new(LoadTracker::new().with_seed(42)));
+    for supplier in suppliers {
+        group.add_supplier((*supplier).clone());
+    }
+    group
+}
+
+#[test]
+fn each_query_goes_to_a_single_supplier() {
+    let a = shop("a", Duration::ZERO);
+    let b = shop("b", Duration::ZERO);
+    let c = shop("c", Duration::ZERO);
+    let group = adaptive_group(&[&a, &b, &c]);
+
+    for _ in 0..9 {
+        let result = group.query(request());
+        assert_eq!(result.successes.len(), 1);
+        assert!(result.failures.is_empty());
+    }
+    assert_eq!(a.calls() + b.calls() + c.calls(), 9);
+}
+
+#[test]
+fn traffic_prefers_the_faster_supplier() {
+    let fast = shop("fast", Duration::from_millis(1));
+    let slow = shop("slow", Duration::from_millis(40));
+    let group = adaptive_group(&[&fast, &slow]);
+
+    for _ in 0..30 {
+        group.query(request());
+    }
+    assert!(fast.calls() > slow.calls() * 3, "fast {} vs slow {}", fast.calls(), slow.calls());
+    assert!(group.load_tracker().load("slow").latency > group.load_tracker().load("fast").latency);
+}
+
+#[test]
+fn calls_in_flight_steer_traffic_away() {
+    let busy = shop("busy", Duration::from_millis(300));
+    let idle = shop("idle", Duration::from_millis(300));
+    let tracker = Arc::new(LoadTracker::new().with_seed(1));
+    tracker.record("busy", Duration::from_millis(10), true);
+    tracker.record("idle", Duration::from_millis(10), true);
+    tracker.begin("busy");
+    tracker.begin("busy");
+
+    let candidates: Vec<Arc<dyn Supplier>> = vec![Arc::new(busy.clone()), Arc::new(idle.clone())];
+    assert_eq!(tracker.choose(&candidates), Some(1));
+
+    let mut group = BasicSupplierGroup::new("replicas")
+        .with_strategy(QueryStrategy::Adaptive)
+        .with_load_tracker(tracker.clone());
+    group.add_supplier(busy.clone());
+    group.add_supplier(idle.clone());
+    let group = Arc::new(group);
+
+    let pending = {
+        let group = group.clone();
+        thread::spawn(move || group.query(request()))
+    };
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(tracker.load("idle").in_flight, 1);
+
+    assert_eq!(pending.join().unwrap().successes[0].0, "idle");
+    assert_eq!(tracker.load("idle").in_flight, 0);
+    assert_eq!(tracker.load("idle").samples, 2);
+}
+
+#[test]
+fn failures_count_as_the_failure_penalty() {
+    let broken = MockSupplierBuilder::new("broken")
+        .then_fail(SupplierError::Upstream("down".into()))
+        .respond_default(json!({}))
+        .build();
+    let healthy = shop("healthy", Duration::from_millis(5));
+    let tracker = Arc::new(LoadTracker::new().with_seed(3).with_failure_penalty(Duration::from_millis(500)));
+    let mut group = BasicSupplierGroup::new("replicas")
+        .with_strategy(QueryStrategy::Adaptive)
+        .with_load_tracker(tracker.clone());
+    group.add_supplier(broken.clone());
+    group.add_supplier(healthy.clone());
+
+    for _ in 0..10 {
+        group.query(request());
+    }
+    if broken.calls() > 0 {
+        assert!(tracker.load("broken").latency >= Duration::from_millis(150));
+    }
+    assert!(healthy.calls() >= 9, "healthy {} vs broken {}", healthy.calls(), broken.calls());
+}
+
+#[test]
+fn empty_groups_query_nothing() {
+    let group = adaptive_group(&[]);
+    let result = group.query(request());
+    assert!(result.successes.is_empty() && result.failures.is_empty());
+}
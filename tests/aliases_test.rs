@@ -0,0 +1,77 @@
+use serde_json::json;
+use supplier_kit::aliases::OperationAliases;
+use supplier_kit::descriptor::{OperationDescriptor, SupplierDescriptor};
+use supplier_kit::dispatcher::Dispatcher;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::{Supplier, SupplierRegistry};
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn request(operation: &str) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::from(operation), json!({}))
+}
+
+#[test]
+fn aliases_resolve_to_canonical_operations() {
+    let aliases = OperationAliases::new()
+        .with_alias("find", SupplierOperation::Search)
+        .with_alias("Track Shipment", SupplierOperation::Other("track_order".into()));
+
+    assert_eq!(aliases.resolve(SupplierOperation::from("FIND")), SupplierOperation::Search);
+    assert_eq!(aliases.resolve(SupplierOperation::from("track-shipment")), SupplierOperation::Other("track_order".into()));
+    assert_eq!(aliases.resolve(SupplierOperation::from("lookup")), SupplierOperation::Other("lookup".into()));
+    assert_eq!(aliases.canonical("Find"), Some(&SupplierOperation::Search));
+    assert_eq!(aliases.iter().map(|(alias, _)| alias).collect::<Vec<_>>(), ["find", "track_shipment"]);
+}
+
+#[test]
+fn groups_dispatch_aliased_requests_under_the_canonical_operation() {
+    let shop = MockSupplierBuilder::new("shop_a").respond(SupplierOperation::Search, json!({ "items": [] })).build();
+    let aliases = OperationAliases::new().with_alias("find", SupplierOperation::Search);
+    let mut group = BasicSupplierGroup::new("marketplaces").with_operation_aliases(aliases);
+    group.add_supplier(shop.clone());
+
+    let result = group.query(request("find"));
+    assert_eq!(result.successes[0].1.data, json!({ "items": [] }));
+    let batch = group.query_batch(vec![request("find"), request("search")]);
+    assert!(batch.iter().all(|result| result.successes.len() == 1));
+    assert!(shop.requests().iter().all(|r| r.operation == SupplierOperation::Search));
+}
+
+#[test]
+fn descriptors_declare_legacy_names() {
+    let legacy = MockSupplierBuilder::new("orders")
+        .describe_operation(OperationDescriptor::new(SupplierOperation::Create).with_alias("place_order").with_alias("Order"))
+        .respond_default(json!({ "order": 1 }))
+        .build();
+    let descriptor = legacy.describe();
+    assert!(descriptor.supports(&SupplierOperation::from("place-order")));
+    assert!(!descriptor.supports(&SupplierOperation::from("cancel_order")));
+    assert_eq!(serde_json::to_value(&descriptor.operations[0]).unwrap()["aliases"], json!(["place_order", "Order"]));
+
+    let mut registry = SupplierRegistry::new();
+    registry.register("orders", legacy);
+    let dispatcher = Dispatcher::from_registry(&registry).unwrap();
+    assert_eq!(dispatcher.owner(&SupplierOperation::from("order")), Some("orders"));
+    assert_eq!(dispatcher.query(request("place_order")).unwrap().data, json!({ "order": 1 }));
+}
+
+#[test]
+fn conflicting_aliases_are_rejected() {
+    let descriptors = [
+        SupplierDescriptor::new("catalog").with_operation(OperationDescriptor::new(SupplierOperation::Search).with_alias("find")),
+        SupplierDescriptor::new("orders").with_operation(OperationDescriptor::new(SupplierOperation::GetDetail).with_alias("Find")),
+    ];
+    let error = OperationAliases::from_descriptors(&descriptors).unwrap_err();
+    assert!(matches!(error, SupplierError::InvalidInput(message) if message.contains("'find'") && message.contains("'orders'")));
+
+    let shadowing = [SupplierDescriptor::new("catalog").with_operation(OperationDescriptor::new(SupplierOperation::Search).with_alias("create"))];
+    assert!(OperationAliases::from_descriptors(&shadowing).is_err());
+
+    let agreeing = [
+        SupplierDescriptor::new("a").with_operation(OperationDescriptor::new(SupplierOperation::Search).with_alias("find")),
+        SupplierDescriptor::new("b").with_operation(OperationDescriptor::new(SupplierOperation::Search).with_alias("find")),
+    ];
+    assert_eq!(OperationAliases::from_descriptors(&agreeing).unwrap().canonical("find"), Some(&SupplierOperation::Search));
+}
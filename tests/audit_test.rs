@@ -0,0 +1,186 @@
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use serde_json::json;
+use supplier_kit::audit::{verify_chain, verify_chain_from, AuditLog, AuditOutcome, AuditRecord, AuditSink, AuditedSupplier, ChannelSink, JsonLinesSink};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn search(params: serde_json::Value) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, params)
+}
+
+#[test]
+fn group_calls_are_audited_with_correlation_ids() {
+    let (sink, receiver) = ChannelSink::new();
+    let mut group = BasicSupplierGroup::new("partners").with_audit(Arc::new(AuditLog::new(sink)));
+    group.add_supplier(MockSupplierBuilder::new("a").respond_default(json!({})).build());
+    group.add_supplier(MockSupplierBuilder::new("b").then_fail(SupplierError::Timeout).build());
+
+    let mut request = search(json!({ "q": "tv" }));
+    request.context.request_id = Some("req-1".into());
+    group.query(request);
+
+    let records: Vec<AuditRecord> = receiver.try_iter().collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].group.as_deref(), Some("partners"));
+    assert_eq!(records[0].supplier, "a");
+    assert_eq!(records[0].operation, "search");
+    assert_eq!(records[0].outcome, AuditOutcome::Success);
+    assert_eq!(records[0].correlation_id.as_deref(), Some("req-1"));
+    assert_eq!(
+        records[1].outcome,
+        AuditOutcome::Failure { code: "timeout".into(), message: "timeout".into() }
+    );
+    assert_eq!(records[1].sequence, 2);
+    assert!(verify_chain(&records).is_ok());
+}
+
+#[test]
+fn tampering_breaks_the_chain() {
+    let (sink, receiver) = ChannelSink::new();
+    let log = Arc::new(AuditLog::new(sink));
+    let supplier = AuditedSupplier::new(MockSupplierBuilder::new("a").respond_default(json!({})).build(), log);
+    for id in 0..3 {
+        supplier.query(search(json!({ "id": id }))).unwrap();
+    }
+    let records: Vec<AuditRecord> = receiver.try_iter().collect();
+    assert!(verify_chain(&records).is_ok());
+
+    let mut edited = records.clone();
+    edited[1].params = json!({ "id": 99 });
+    assert_eq!(verify_chain(&edited), Err(1));
+
+    let removed = vec![records[0].clone(), records[2].clone()];
+    assert_eq!(verify_chain(&removed), Err(1));
+}
+
+#[test]
+fn params_are_filtered_before_recording() {
+    let (sink, receiver) = ChannelSink::new();
+    let log = AuditLog::new(sink).with_param_filter(|_| json!("[redacted]"));
+    let supplier = AuditedSupplier::new(MockSupplierBuilder::new("a").respond_default(json!({})).build(), Arc::new(log));
+
+    supplier.query(search(json!({ "card": "4111" }))).unwrap();
+    assert_eq!(receiver.recv().unwrap().params, json!("[redacted]"));
+}
+
+#[test]
+fn params_and_error_texts_are_redacted_by_default() {
+    let (sink, receiver) = ChannelSink::new();
+    let partner = MockSupplierBuilder::new("a").then_fail(SupplierError::Upstream("card 4111 declined".into())).build();
+    let supplier = AuditedSupplier::new(partner, Arc::new(AuditLog::new(sink)));
+
+    let _ = supplier.query(search(json!({ "card": "4111", "items": [{ "sku": 7 }], "note": null })));
+    let record = receiver.recv().unwrap();
+    assert_eq!(record.params, json!({ "card": "[REDACTED]", "items": [{ "sku": "[REDACTED]" }], "note": null }));
+    assert_eq!(record.outcome, AuditOutcome::Failure { code: "upstream".into(), message: "upstream error: [REDACTED]".into() });
+
+    let (sink, receiver) = ChannelSink::new();
+    let partner = MockSupplierBuilder::new("a").then_fail(SupplierError::Upstream("HTTP 502".into())).build();
+    let log = AuditLog::new(sink).with_param_filter(|params| params.clone()).with_error_messages(true);
+    let _ = AuditedSupplier::new(partner, Arc::new(log)).query(search(json!({ "q": "tv" })));
+    let record = receiver.recv().unwrap();
+    assert_eq!(record.params, json!({ "q": "tv" }));
+    assert_eq!(record.outcome, AuditOutcome::Failure { code: "upstream".into(), message: "upstream error: HTTP 502".into() });
+}
+
+/// Stores records, failing the writes made while `failing` is set.
+#[derive(Clone, Default)]
+struct FlakySink {
+    failing: Arc<AtomicBool>,
+    records: Arc<Mutex<Vec<AuditRecord>>>,
+}
+
+impl AuditSink for FlakySink {
+    fn write(&self, record: &AuditRecord) -> io::Result<()> {
+        if self.failing.load(Ordering::SeqCst) {
+            return Err(io::Error::other("disk full"));
+        }
+        self.records.lock().unwrap().push(record.clone());
+        Ok(())
+    }
+}
+
+#[test]
+fn failed_writes_do_not_break_the_stored_chain() {
+    let sink = FlakySink::default();
+    let log = Arc::new(AuditLog::new(sink.clone()));
+    let supplier = AuditedSupplier::new(MockSupplierBuilder::new("a").respond_default(json!({})).build(), log.clone());
+
+    supplier.query(search(json!({ "id": 1 }))).unwrap();
+    sink.failing.store(true, Ordering::SeqCst);
+    supplier.query(search(json!({ "id": 2 }))).unwrap();
+    sink.failing.store(false, Ordering::SeqCst);
+    supplier.query(search(json!({ "id": 3 }))).unwrap();
+
+    let records = sink.records.lock().unwrap();
+    assert_eq!(log.write_failures(), 1);
+    assert_eq!(records.iter().map(|record| record.sequence).collect::<Vec<_>>(), [1, 2]);
+    assert!(verify_chain(&records).is_ok());
+}
+
+#[test]
+fn json_lines_sink_writes_one_record_per_line() {
+    let path = std::env::temp_dir().join(format!("supplier_kit_audit_{}.ndjson", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let log = Arc::new(AuditLog::new(JsonLinesSink::file(&path).unwrap()));
+    let supplier = AuditedSupplier::new(MockSupplierBuilder::new("a").build(), log.clone());
+    let _ = supplier.query(search(json!({})));
+    let _ = supplier.query(search(json!({})));
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    let records: Vec<AuditRecord> = content.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+    assert_eq!(records.len(), 2);
+    assert!(verify_chain(&records).is_ok());
+    assert_eq!(log.write_failures(), 0);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn failed_writes_are_counted() {
+    let (sink, receiver) = ChannelSink::new();
+    drop(receiver);
+    let log = Arc::new(AuditLog::new(sink));
+    let supplier = AuditedSupplier::new(MockSupplierBuilder::new("a").build(), log.clone());
+    let _ = supplier.query(search(json!({})));
+    assert_eq!(log.write_failures(), 1);
+}
+
+#[test]
+fn resumed_logs_continue_the_chain() {
+    let supplier = || MockSupplierBuilder::new("a").respond_default(json!({})).build();
+    let (sink, receiver) = ChannelSink::new();
+    let before = AuditedSupplier::new(supplier(), Arc::new(AuditLog::new(sink)));
+    before.query(search(json!({ "id": 1 }))).unwrap();
+    before.query(search(json!({ "id": 2 }))).unwrap();
+    let mut records: Vec<AuditRecord> = receiver.try_iter().collect();
+
+    let (sink, receiver) = ChannelSink::new();
+    let after = AuditedSupplier::new(supplier(), Arc::new(AuditLog::resume(sink, &records[1])));
+    after.query(search(json!({ "id": 3 }))).unwrap();
+    let resumed: Vec<AuditRecord> = receiver.try_iter().collect();
+    assert_eq!(resumed[0].sequence, 3);
+    assert!(verify_chain_from(&resumed, &records[1].hash).is_ok());
+
+    records.extend(resumed);
+    assert!(verify_chain(&records).is_ok());
+}
+
+#[test]
+fn chains_must_start_at_their_anchor() {
+    let (sink, receiver) = ChannelSink::new();
+    let supplier = AuditedSupplier::new(MockSupplierBuilder::new("a").respond_default(json!({})).build(), Arc::new(AuditLog::new(sink)));
+    for id in 0..3 {
+        supplier.query(search(json!({ "id": id }))).unwrap();
+    }
+    let records: Vec<AuditRecord> = receiver.try_iter().collect();
+
+    // Dropping the first records leaves a consistent tail, but not one starting at the genesis.
+    assert_eq!(verify_chain(&records[1..]), Err(0));
+    assert!(verify_chain_from(&records[1..], &records[0].hash).is_ok());
+    assert_eq!(verify_chain_from(&records[2..], &records[0].hash), Err(0));
+}
@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::auth::{AuthProvider, AuthenticatedSupplier, Credential, RotatingAuth, SecretAuth};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::events::SupplierEvent;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::secrets::SecretsProvider;
+use supplier_kit::supplier::{Supplier, SupplierRegistry};
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+fn bearer(token: &str) -> Arc<dyn AuthProvider> {
+    Arc::new(Credential::Bearer(token.into()))
+}
+
+/// A partner that echoes the credential it was called with, after an optional pause.
+fn partner(auth: Arc<RotatingAuth>, pause: Option<mpsc::Receiver<()>>) -> AuthenticatedSupplier {
+    let pause = Mutex::new(pause);
+    AuthenticatedSupplier::new("partner", auth, move |credential, _| {
+        let release = pause.lock().unwrap().take();
+        if let Some(release) = release {
+            release.recv().unwrap();
+        }
+        Ok(SupplierResponse::new(json!({ "auth": credential.header().1 })))
+    })
+}
+
+#[test]
+fn in_flight_calls_finish_on_the_old_credential() {
+    let auth = Arc::new(RotatingAuth::new(bearer("old")));
+    let (release, paused) = mpsc::channel();
+    let mut registry = SupplierRegistry::new();
+    registry.register("partner", partner(auth.clone(), Some(paused)));
+    registry.register_auth("partner", auth).unwrap();
+    let registry = Arc::new(registry);
+
+    let in_flight = {
+        let registry = registry.clone();
+        thread::spawn(move || registry.query("partner", search()))
+    };
+    thread::sleep(Duration::from_millis(50));
+
+    assert_eq!(registry.rotate_auth("partner", bearer("new")).unwrap(), 1);
+    assert_eq!(registry.query("partner", search()).unwrap().data["auth"], "Bearer new");
+
+    release.send(()).unwrap();
+    assert_eq!(in_flight.join().unwrap().unwrap().data["auth"], "Bearer old");
+}
+
+#[test]
+fn rotation_publishes_an_event() {
+    let auth = Arc::new(RotatingAuth::new(bearer("old")));
+    let mut registry = SupplierRegistry::new();
+    registry.register("partner", partner(auth.clone(), None));
+    registry.register_auth("partner", auth).unwrap();
+    let events = registry.events().subscribe_channel();
+
+    registry.rotate_auth("partner", bearer("new")).unwrap();
+    registry.rotate_auth("partner", bearer("newer")).unwrap();
+
+    let rotations: Vec<_> = events
+        .try_iter()
+        .filter_map(|event| match event {
+            SupplierEvent::CredentialsRotated { supplier, generation } => Some((supplier, generation)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(rotations, vec![("partner".to_string(), 1), ("partner".to_string(), 2)]);
+}
+
+#[test]
+fn failed_rotation_keeps_the_current_credential() {
+    let auth = Arc::new(RotatingAuth::new(bearer("old")));
+    let mut registry = SupplierRegistry::new();
+    registry.register("partner", partner(auth.clone(), None));
+    registry.register_auth("partner", auth.clone()).unwrap();
+
+    let missing: Arc<dyn SecretsProvider> = Arc::new(HashMap::<String, String>::new());
+    let broken = Arc::new(SecretAuth::bearer(missing, "partner_token"));
+    assert!(matches!(registry.rotate_auth("partner", broken), Err(SupplierError::InvalidInput(_))));
+
+    assert_eq!(auth.generation(), 0);
+    assert_eq!(registry.query("partner", search()).unwrap().data["auth"], "Bearer old");
+}
+
+#[test]
+fn unknown_suppliers_cannot_be_rotated() {
+    let auth = Arc::new(RotatingAuth::new(bearer("old")));
+    let mut registry = SupplierRegistry::new();
+    assert!(matches!(registry.register_auth("partner", auth.clone()), Err(SupplierError::NotFound)));
+    assert!(matches!(registry.rotate_auth("partner", bearer("new")), Err(SupplierError::NotFound)));
+
+    registry.register("partner", partner(auth.clone(), None));
+    registry.register_auth("partner", auth).unwrap();
+    registry.remove("partner");
+    assert!(registry.auth("partner").is_none());
+}
+
+#[test]
+fn secret_auth_reads_the_secret_on_every_call() {
+    let secrets = Arc::new(Mutex::new(HashMap::from([("partner_key".to_string(), "k-1".to_string())])));
+    let source = secrets.clone();
+    let provider: Arc<dyn SecretsProvider> = Arc::new(move |name: &str| Ok(source.lock().unwrap().get(name).cloned()));
+    let supplier = AuthenticatedSupplier::new("partner", Arc::new(SecretAuth::api_key(provider, "partner_key", "X-Api-Key")), |credential, _| {
+        let (header, value) = credential.header();
+        Ok(SupplierResponse::new(json!({ header: value })))
+    });
+
+    assert_eq!(supplier.query(search()).unwrap().data["X-Api-Key"], "k-1");
+    secrets.lock().unwrap().insert("partner_key".into(), "k-2".into());
+    assert_eq!(supplier.query(search()).unwrap().data["X-Api-Key"], "k-2");
+}
+
+#[test]
+fn credential_debug_output_is_redacted() {
+    let basic = Credential::Basic {
+        username: "shop".into(),
+        password: "hunter2".into(),
+    };
+    assert_eq!(basic.header().1, "Basic c2hvcDpodW50ZXIy");
+    assert!(!format!("{:?}", basic).contains("hunter2"));
+    assert!(!format!("{:?}", Credential::Bearer("t-1".into())).contains("t-1"));
+}
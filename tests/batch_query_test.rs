@@ -0,0 +1,96 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+
+/// Serves batches through a single "bulk endpoint" call.
+struct BulkSupplier {
+    bulk_calls: Arc<AtomicUsize>,
+}
+
+impl Supplier for BulkSupplier {
+    fn name(&self) -> &str {
+        "bulk"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        panic!("single queries should not be used for batches")
+    }
+
+    fn query_batch(&self, requests: Vec<SupplierRequest>) -> Vec<Result<SupplierResponse, SupplierError>> {
+        self.bulk_calls.fetch_add(1, Ordering::SeqCst);
+        requests
+            .into_iter()
+            .map(|r| Ok(SupplierResponse::new(json!({ "id": r.params["id"], "bulk": true }))))
+            .collect()
+    }
+}
+
+/// Uses the default batch implementation and rejects odd ids.
+struct PickySupplier;
+
+impl Supplier for PickySupplier {
+    fn name(&self) -> &str {
+        "picky"
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        match request.params["id"].as_u64() {
+            Some(id) if id % 2 == 0 => Ok(SupplierResponse::new(json!({ "id": id }))),
+            _ => Err(SupplierError::NotFound),
+        }
+    }
+}
+
+/// Returns too few results for a batch.
+struct ShortSupplier;
+
+impl Supplier for ShortSupplier {
+    fn name(&self) -> &str {
+        "short"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!({})))
+    }
+
+    fn query_batch(&self, _requests: Vec<SupplierRequest>) -> Vec<Result<SupplierResponse, SupplierError>> {
+        vec![Ok(SupplierResponse::new(json!({})))]
+    }
+}
+
+fn details(ids: &[u64]) -> Vec<SupplierRequest> {
+    ids.iter()
+        .map(|id| SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": id })))
+        .collect()
+}
+
+#[test]
+fn test_group_batch_uses_bulk_endpoint_and_keeps_request_order() {
+    let bulk_calls = Arc::new(AtomicUsize::new(0));
+    let mut group = BasicSupplierGroup::new("batch");
+    group.add_supplier(BulkSupplier { bulk_calls: bulk_calls.clone() });
+    group.add_supplier(PickySupplier);
+
+    let results = group.query_batch(details(&[1, 2, 3]));
+    assert_eq!(bulk_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(results.len(), 3);
+
+    assert_eq!(results[0].successes.len(), 1);
+    assert_eq!(results[0].failures[0].0, "picky");
+    assert_eq!(results[1].successes.len(), 2);
+    assert_eq!(results[2].successes[0].1.data["id"], 3);
+}
+
+#[test]
+fn test_short_batch_is_padded_with_errors() {
+    let mut group = BasicSupplierGroup::new("batch");
+    group.add_supplier(ShortSupplier);
+
+    let results = group.query_batch(details(&[1, 2, 3]));
+    assert_eq!(results[0].successes.len(), 1);
+    assert!(matches!(results[2].failures[0].1, SupplierError::Internal(_)));
+}
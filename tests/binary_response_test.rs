@@ -0,0 +1,84 @@
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::decorators::cache::CachingSupplier;
+use supplier_kit::decorators::size_limit::SizeLimitedSupplier;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{ResponseBody, ResponseSource, SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+
+const PDF: &[u8] = b"%PDF-1.7\n\x00\xff\x10binary";
+
+struct InvoiceSupplier;
+
+impl Supplier for InvoiceSupplier {
+    fn name(&self) -> &str {
+        "invoices"
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        match request.operation {
+            SupplierOperation::GetDetail => {
+                Ok(SupplierResponse::new(json!({ "invoice": "INV-7" })).with_body("application/pdf", PDF.to_vec()))
+            }
+            SupplierOperation::Search => Ok(SupplierResponse::binary("text/csv", b"invoice,total\nINV-7,12.50\n".to_vec())),
+            other => Err(SupplierError::UnsupportedOperation(other.as_str().to_string())),
+        }
+    }
+}
+
+fn request(operation: SupplierOperation) -> SupplierRequest {
+    SupplierRequest::new(operation, json!({}))
+}
+
+#[test]
+fn suppliers_return_documents_with_a_content_type() {
+    let detail = InvoiceSupplier.query(request(SupplierOperation::GetDetail)).unwrap();
+    assert_eq!(detail.data["invoice"], "INV-7");
+    assert_eq!(detail.content_type(), Some("application/pdf"));
+    assert_eq!(detail.bytes(), Some(PDF));
+
+    let export = InvoiceSupplier.query(request(SupplierOperation::Search)).unwrap();
+    assert!(export.data.is_null());
+    assert_eq!(export.body.unwrap().as_text(), Some("invoice,total\nINV-7,12.50\n"));
+    assert!(SupplierResponse::new(json!([])).bytes().is_none());
+}
+
+#[test]
+fn bodies_round_trip_through_json_as_base64() {
+    let response = InvoiceSupplier.query(request(SupplierOperation::GetDetail)).unwrap();
+    let json = serde_json::to_value(&response).unwrap();
+    assert!(json["body"]["bytes"].is_string());
+    assert_eq!(serde_json::from_value::<SupplierResponse>(json).unwrap(), response);
+
+    let plain = serde_json::to_value(SupplierResponse::new(json!(1))).unwrap();
+    assert!(plain.get("body").is_none());
+    let legacy: SupplierResponse = serde_json::from_value(json!({ "data": 1 })).unwrap();
+    assert!(legacy.body.is_none());
+
+    let invalid = serde_json::from_value::<ResponseBody>(json!({ "content_type": "image/png", "bytes": "not base64!" }));
+    assert!(invalid.is_err());
+}
+
+#[test]
+fn decorators_and_groups_carry_bodies() {
+    let cached = CachingSupplier::new(InvoiceSupplier, Duration::from_secs(60));
+    cached.query(request(SupplierOperation::GetDetail)).unwrap();
+    let again = cached.query(request(SupplierOperation::GetDetail)).unwrap();
+    assert_eq!(again.source(), ResponseSource::Cache);
+    assert_eq!(again.bytes(), Some(PDF));
+
+    let mut group = BasicSupplierGroup::new("documents");
+    group.add_supplier(InvoiceSupplier);
+    let result = group.query(request(SupplierOperation::Search));
+    assert_eq!(result.successes[0].1.content_type(), Some("text/csv"));
+}
+
+#[test]
+fn bodies_count_towards_size_limits() {
+    let limited = SizeLimitedSupplier::new(InvoiceSupplier, PDF.len());
+    assert!(matches!(limited.query(request(SupplierOperation::GetDetail)), Err(SupplierError::ResponseTooLarge(_))));
+
+    let roomy = SizeLimitedSupplier::new(InvoiceSupplier, PDF.len() + 64);
+    assert!(roomy.query(request(SupplierOperation::GetDetail)).is_ok());
+}
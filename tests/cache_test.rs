@@ -0,0 +1,199 @@
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use serde_json::json;
+use supplier_kit::context::RequestContext;
+use supplier_kit::decorators::cache::CachingSupplier;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::executor::BoundedExecutor;
+use supplier_kit::models::{ResponseSource, SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn search(q: &str) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": q }))
+}
+
+fn wait_until(condition: impl Fn() -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while !condition() {
+        assert!(Instant::now() < deadline, "condition not met in time");
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn fresh_entries_are_served_from_cache_per_request() {
+    let supplier = MockSupplierBuilder::new("catalog").respond_default(json!({ "v": 1 })).build();
+    let cached = CachingSupplier::new(supplier.clone(), Duration::from_secs(60));
+
+    cached.query(search("lamp")).unwrap();
+    assert_eq!(cached.query(search("lamp")).unwrap().source(), ResponseSource::Cache);
+    cached.query(search("desk")).unwrap();
+
+    assert_eq!(supplier.calls(), 2);
+    assert_eq!(cached.len(), 2);
+}
+
+#[test]
+fn stale_entries_are_served_immediately_and_refreshed_once() {
+    let supplier = MockSupplierBuilder::new("catalog")
+        .then_respond(json!({ "v": 1 }))
+        .then_respond(json!({ "v": 2 }))
+        .with_delay(Duration::from_millis(100))
+        .build();
+    let cached = CachingSupplier::new(supplier.clone(), Duration::from_millis(20))
+        .with_stale_while_revalidate(Duration::from_secs(60));
+
+    assert_eq!(cached.query(search("lamp")).unwrap().data["v"], 1);
+    thread::sleep(Duration::from_millis(30));
+
+    let started = Instant::now();
+    for _ in 0..3 {
+        let stale = cached.query(search("lamp")).unwrap();
+        assert_eq!(stale.data["v"], 1);
+        assert_eq!(stale.source(), ResponseSource::Cache);
+    }
+    assert!(started.elapsed() < Duration::from_millis(80));
+
+    wait_until(|| supplier.calls() == 2);
+    wait_until(|| cached.query(search("lamp")).unwrap().data["v"] == 2);
+    assert_eq!(supplier.calls(), 2);
+}
+
+#[test]
+fn failed_refresh_keeps_serving_the_stale_entry() {
+    let supplier = MockSupplierBuilder::new("catalog")
+        .then_respond(json!({ "v": 1 }))
+        .then_fail(SupplierError::Timeout)
+        .then_respond(json!({ "v": 3 }))
+        .build();
+    let cached = CachingSupplier::new(supplier.clone(), Duration::from_millis(10))
+        .with_stale_while_revalidate(Duration::from_secs(60));
+
+    cached.query(search("lamp")).unwrap();
+    thread::sleep(Duration::from_millis(20));
+    assert_eq!(cached.query(search("lamp")).unwrap().data["v"], 1);
+    wait_until(|| supplier.calls() == 2);
+
+    // The failed refresh is retried by the next stale hit.
+    thread::sleep(Duration::from_millis(10));
+    assert_eq!(cached.query(search("lamp")).unwrap().data["v"], 1);
+    wait_until(|| cached.query(search("lamp")).unwrap().data["v"] == 3);
+}
+
+#[test]
+fn expired_entries_and_errors_go_upstream() {
+    let supplier = MockSupplierBuilder::new("catalog")
+        .then_fail(SupplierError::Timeout)
+        .then_respond(json!({ "v": 1 }))
+        .then_respond(json!({ "v": 2 }))
+        .build();
+    let cached = CachingSupplier::new(supplier.clone(), Duration::from_millis(10));
+
+    assert!(cached.query(search("lamp")).is_err());
+    assert!(cached.is_empty());
+    assert_eq!(cached.query(search("lamp")).unwrap().data["v"], 1);
+
+    thread::sleep(Duration::from_millis(20));
+    let refreshed = cached.query(search("lamp")).unwrap();
+    assert_eq!(refreshed.data["v"], 2);
+    assert_eq!(refreshed.source(), ResponseSource::Live);
+}
+
+#[test]
+fn writes_bypass_and_invalidation_drops_entries() {
+    let supplier = MockSupplierBuilder::new("orders").respond_default(json!({ "ok": true })).build();
+    let cached = CachingSupplier::new(supplier.clone(), Duration::from_secs(60));
+    let create = SupplierRequest::new(SupplierOperation::Create, json!({ "sku": "A" }));
+
+    cached.query(create.clone()).unwrap();
+    cached.query(create).unwrap();
+    assert_eq!(supplier.calls(), 2);
+    assert!(cached.is_empty());
+
+    cached.query(search("lamp")).unwrap();
+    cached.invalidate(&search("lamp"));
+    cached.query(search("lamp")).unwrap();
+    assert_eq!(supplier.calls(), 4);
+
+    cached.clear();
+    assert!(cached.is_empty());
+}
+
+#[test]
+fn tenants_never_share_cached_responses() {
+    let supplier = MockSupplierBuilder::new("catalog").respond_default(json!({ "v": 1 })).build();
+    let cached = CachingSupplier::new(supplier.clone(), Duration::from_secs(60));
+    let for_tenant = |tenant: &str| search("lamp").with_context(RequestContext::new().with_tenant(tenant));
+
+    cached.query(for_tenant("acme")).unwrap();
+    assert_eq!(cached.query(for_tenant("globex")).unwrap().source(), ResponseSource::Live);
+    assert_eq!(cached.query(for_tenant("acme")).unwrap().source(), ResponseSource::Cache);
+    assert_eq!(supplier.calls(), 2);
+}
+
+#[test]
+fn operation_versions_never_share_cached_responses() {
+    let supplier = MockSupplierBuilder::new("catalog").respond_default(json!({ "v": 1 })).build();
+    let cached = CachingSupplier::new(supplier.clone(), Duration::from_secs(60));
+
+    cached.query(search("lamp")).unwrap();
+    assert_eq!(cached.query(search("lamp").with_version(2)).unwrap().source(), ResponseSource::Live);
+    assert_eq!(cached.query(search("lamp").with_version(2)).unwrap().source(), ResponseSource::Cache);
+    assert_eq!(cached.query(search("lamp")).unwrap().source(), ResponseSource::Cache);
+    assert_eq!(supplier.calls(), 2);
+}
+
+#[test]
+fn locales_never_share_cached_responses() {
+    let supplier = MockSupplierBuilder::new("catalog").respond_default(json!({ "v": 1 })).build();
+    let cached = CachingSupplier::new(supplier.clone(), Duration::from_secs(60));
+    let in_locale = |locale: &str| search("lamp").with_context(RequestContext::new().with_locale(locale));
+
+    cached.query(in_locale("de-DE")).unwrap();
+    assert_eq!(cached.query(in_locale("fr-FR")).unwrap().source(), ResponseSource::Live);
+    assert_eq!(cached.query(in_locale("de-DE")).unwrap().source(), ResponseSource::Cache);
+    assert_eq!(supplier.calls(), 2);
+}
+
+#[test]
+fn refreshes_run_on_the_executor() {
+    let supplier = MockSupplierBuilder::new("catalog")
+        .then_respond(json!({ "v": 1 }))
+        .then_respond(json!({ "v": 2 }))
+        .build();
+    let cached = CachingSupplier::new(supplier.clone(), Duration::from_millis(20))
+        .with_stale_while_revalidate(Duration::from_secs(60))
+        .with_executor(Arc::new(BoundedExecutor::new(0)));
+
+    cached.query(search("lamp")).unwrap();
+    thread::sleep(Duration::from_millis(30));
+    // Without threads, the refresh runs on the caller before the stale response is returned.
+    assert_eq!(cached.query(search("lamp")).unwrap().data["v"], 1);
+    assert_eq!(supplier.calls(), 2);
+    assert_eq!(cached.query(search("lamp")).unwrap().data["v"], 2);
+}
+
+#[test]
+fn full_caches_evict_expired_then_oldest_entries() {
+    let supplier = MockSupplierBuilder::new("catalog").respond_default(json!([])).build();
+    let cached = CachingSupplier::new(supplier.clone(), Duration::from_millis(100)).with_max_entries(3);
+
+    cached.query(search("lamp")).unwrap();
+    thread::sleep(Duration::from_millis(120));
+    cached.query(search("desk")).unwrap();
+    cached.query(search("chair")).unwrap();
+    // Storing a fourth response drops the expired one only.
+    cached.query(search("sofa")).unwrap();
+    assert_eq!(cached.len(), 3);
+
+    // Storing a fifth one drops the oldest live one.
+    cached.query(search("rug")).unwrap();
+    assert_eq!(cached.len(), 3);
+    let calls = supplier.calls();
+    cached.query(search("chair")).unwrap();
+    assert_eq!(supplier.calls(), calls);
+    cached.query(search("desk")).unwrap();
+    assert_eq!(supplier.calls(), calls + 1);
+}
@@ -0,0 +1,78 @@
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::decorators::canary::CanarySupplier;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+
+struct Connector {
+    name: &'static str,
+    fail: bool,
+}
+
+impl Supplier for Connector {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        std::thread::sleep(Duration::from_millis(1));
+        if self.fail {
+            return Err(SupplierError::Upstream(format!("{} failed", self.name)));
+        }
+        Ok(SupplierResponse::new(json!({ "from": self.name })))
+    }
+}
+
+fn canary(percentage: f64, canary_fails: bool) -> CanarySupplier {
+    CanarySupplier::new(
+        Connector { name: "stable", fail: false },
+        Connector { name: "canary", fail: canary_fails },
+        percentage,
+    )
+}
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+#[test]
+fn traffic_is_split_by_percentage() {
+    let supplier = canary(10.0, false);
+    let from: Vec<String> = (0..100)
+        .map(|_| supplier.query(request()).unwrap().data["from"].as_str().unwrap().to_string())
+        .collect();
+
+    assert_eq!(from.iter().filter(|f| *f == "canary").count(), 10);
+    // Canary requests are spread out rather than bunched together.
+    assert_eq!(from[..10].iter().filter(|f| *f == "canary").count(), 1);
+    assert_eq!(supplier.name(), "stable");
+}
+
+#[test]
+fn stats_track_each_arm() {
+    let supplier = canary(50.0, true);
+    let failures = (0..10).filter(|_| supplier.query(request()).is_err()).count();
+
+    let stats = supplier.stats();
+    assert_eq!(failures, 5);
+    assert_eq!(stats.canary.requests, 5);
+    assert_eq!(stats.canary.error_rate(), 1.0);
+    assert_eq!(stats.stable.error_rate(), 0.0);
+    assert!(stats.stable.average_latency() >= Duration::from_millis(1));
+}
+
+#[test]
+fn percentage_can_change_during_rollout() {
+    let supplier = canary(0.0, false);
+    (0..10).for_each(|_| drop(supplier.query(request())));
+    assert_eq!(supplier.stats().canary.requests, 0);
+
+    supplier.set_percentage(100.0);
+    assert_eq!(supplier.percentage(), 100.0);
+    (0..10).for_each(|_| drop(supplier.query(request())));
+    assert_eq!(supplier.stats().canary.requests, 10);
+
+    supplier.set_percentage(250.0);
+    assert_eq!(supplier.percentage(), 100.0);
+}
@@ -0,0 +1,73 @@
+use std::time::{Duration, Instant};
+use serde_json::{json, Value};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::testing::chaos::ChaosSupplier;
+
+struct Healthy;
+
+impl Supplier for Healthy {
+    fn name(&self) -> &str {
+        "healthy"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!({ "id": 1, "price": 9.5 })))
+    }
+}
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": 1 }))
+}
+
+fn outcomes(chaos: &ChaosSupplier<Healthy>) -> Vec<Result<Value, String>> {
+    (0..50)
+        .map(|_| chaos.query(request()).map(|r| r.data).map_err(|e| e.code().to_string()))
+        .collect()
+}
+
+#[test]
+fn same_seed_reproduces_the_same_faults() {
+    let build = |seed| {
+        ChaosSupplier::new(Healthy, seed)
+            .with_error_rate(0.3)
+            .with_malformed_rate(0.3)
+    };
+    assert_eq!(outcomes(&build(7)), outcomes(&build(7)));
+    assert_ne!(outcomes(&build(7)), outcomes(&build(8)));
+}
+
+#[test]
+fn no_faults_by_default() {
+    let chaos = ChaosSupplier::new(Healthy, 1);
+    assert!(outcomes(&chaos).iter().all(|o| o == &Ok(json!({ "id": 1, "price": 9.5 }))));
+    assert_eq!(chaos.name(), "healthy");
+}
+
+#[test]
+fn configured_error_is_injected() {
+    let chaos = ChaosSupplier::new(Healthy, 3)
+        .with_error_rate(1.0)
+        .with_error(SupplierError::Timeout);
+    assert!(matches!(chaos.query(request()), Err(SupplierError::Timeout)));
+}
+
+#[test]
+fn malformed_responses_differ_from_the_original() {
+    let chaos = ChaosSupplier::new(Healthy, 5).with_malformed_rate(1.0);
+    for outcome in outcomes(&chaos) {
+        assert_ne!(outcome.unwrap(), json!({ "id": 1, "price": 9.5 }));
+    }
+}
+
+#[test]
+fn latency_is_injected_within_range() {
+    let chaos = ChaosSupplier::new(Healthy, 9).with_latency(Duration::from_millis(5), Duration::from_millis(10));
+    let started = Instant::now();
+    for _ in 0..5 {
+        chaos.query(request()).unwrap();
+    }
+    let elapsed = started.elapsed();
+    assert!(elapsed >= Duration::from_millis(25));
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use serde_json::Value;
+
+fn supplier_kit(args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_supplier-kit"))
+        .args(["--manifest", "tests/fixtures/cli/manifest.json"])
+        .args(args)
+        .output()
+        .unwrap();
+    let text = String::from_utf8_lossy(&output.stdout).to_string() + &String::from_utf8_lossy(&output.stderr);
+    (output.status.success(), text)
+}
+
+#[test]
+fn list_shows_suppliers_and_groups() {
+    let (ok, out) = supplier_kit(&["list"]);
+    assert!(ok);
+    assert!(out.contains("shop_b") && out.contains("replay"));
+    assert!(out.contains("marketplaces") && out.contains("shop_a, shop_b, flaky"));
+}
+
+#[test]
+fn group_query_prints_successes_and_failures() {
+    let (ok, out) = supplier_kit(&["query", "--group", "marketplaces", "--op", "search", "--params", r#"{"q":"laptop"}"#]);
+    assert!(ok, "{}", out);
+    assert!(out.contains("2 succeeded, 1 failed"));
+    assert!(out.contains("OK   shop_a") && out.contains("laptop b"));
+    assert!(out.contains("FAIL flaky: [upstream]"));
+}
+
+#[test]
+fn supplier_query_can_print_json() {
+    let (ok, out) = supplier_kit(&["query", "--supplier", "shop_a", "--op", "search", "--json"]);
+    assert!(ok, "{}", out);
+    let result: Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(result["successes"][0][0], "shop_a");
+}
+
+#[test]
+fn errors_are_reported() {
+    let (ok, out) = supplier_kit(&["query", "--group", "nope", "--op", "search"]);
+    assert!(!ok);
+    assert!(out.contains("unknown group 'nope'"));
+
+    let (ok, out) = supplier_kit(&["query", "--supplier", "flaky", "--op", "search", "--params", r#"{"q":"laptop"}"#]);
+    assert!(!ok, "a query without successes exits with failure");
+    assert!(out.contains("0 succeeded, 1 failed"));
+}
+
+#[test]
+fn dry_run_validates_params_without_querying() {
+    let (ok, out) = supplier_kit(&["query", "--group", "marketplaces", "--op", "search", "--params", r#"{"q":""}"#, "--dry-run"]);
+    assert!(!ok, "invalid params fail the dry run");
+    assert!(out.contains("no supplier was called"));
+    assert!(out.contains("QUERY shop_a") && out.contains("QUERY flaky"));
+    assert!(out.contains("invalid params: /q: must be at least 1 characters"));
+    assert!(out.contains("3 of 3 suppliers would be queried, 1 with invalid params"));
+    assert!(!out.contains("laptop b"), "replayed data must not be fetched");
+
+    let (ok, out) = supplier_kit(&["query", "--supplier", "shop_a", "--op", "get_detail", "--dry-run", "--json"]);
+    assert!(ok, "{}", out);
+    let plan: Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(plan[0]["declares_operation"], false);
+}
+
+#[test]
+fn repl_runs_commands_from_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_supplier-kit"))
+        .args(["--manifest", "tests/fixtures/cli/manifest.json", "repl"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"list\nquery --supplier shop_a --op search --params '{\"q\": \"laptop x\"}'\nquery --group nope --op search\nquery --bogus\nexit\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("marketplaces"));
+    assert!(stdout.contains("OK   shop_a"));
+    assert!(stderr.contains("unknown group 'nope'"));
+    assert!(stderr.contains("--bogus"));
+}
@@ -0,0 +1,132 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier};
+use std::thread;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::context::RequestContext;
+use supplier_kit::decorators::coalescing::CoalescingSupplier;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+
+struct SlowCountingSupplier {
+    calls: Arc<AtomicUsize>,
+}
+
+impl Supplier for SlowCountingSupplier {
+    fn name(&self) -> &str {
+        "slow"
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(100));
+        Ok(SupplierResponse::new(request.params))
+    }
+}
+
+fn detail(id: u32) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": id }))
+}
+
+#[test]
+fn test_concurrent_identical_requests_share_one_call() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let supplier = Arc::new(CoalescingSupplier::new(SlowCountingSupplier { calls: calls.clone() }));
+    let barrier = Arc::new(Barrier::new(8));
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let supplier = supplier.clone();
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                supplier.query(detail(1))
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        assert_eq!(handle.join().unwrap().unwrap().data["id"], 1);
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(supplier.in_flight(), 0);
+}
+
+#[test]
+fn test_different_requests_are_not_coalesced() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let supplier = Arc::new(CoalescingSupplier::new(SlowCountingSupplier { calls: calls.clone() }));
+
+    let handles: Vec<_> = (0..3)
+        .map(|id| {
+            let supplier = supplier.clone();
+            thread::spawn(move || supplier.query(detail(id)))
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap().unwrap();
+    }
+
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_requests_of_different_tenants_are_not_coalesced() {
+    assert_eq!(concurrent_calls([RequestContext::new().with_tenant("acme"), RequestContext::new().with_tenant("globex")]), 2);
+}
+
+#[test]
+fn test_requests_in_different_locales_are_not_coalesced() {
+    assert_eq!(concurrent_calls([RequestContext::new().with_locale("fr-FR"), RequestContext::new().with_locale("de-DE")]), 2);
+}
+
+/// Queries `detail(1)` concurrently once per context and returns the upstream call count.
+fn concurrent_calls(contexts: [RequestContext; 2]) -> usize {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let supplier = Arc::new(CoalescingSupplier::new(SlowCountingSupplier { calls: calls.clone() }));
+    let barrier = Arc::new(Barrier::new(2));
+
+    let handles: Vec<_> = contexts
+        .into_iter()
+        .map(|context| {
+            let supplier = supplier.clone();
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                supplier.query(detail(1).with_context(context))
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap().unwrap();
+    }
+    calls.load(Ordering::SeqCst)
+}
+
+#[test]
+fn test_writes_are_coalesced_only_with_an_idempotency_key() {
+    let concurrent = |request: SupplierRequest| {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let supplier = Arc::new(CoalescingSupplier::new(SlowCountingSupplier { calls: calls.clone() }));
+        let barrier = Arc::new(Barrier::new(2));
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let (supplier, barrier, request) = (supplier.clone(), barrier.clone(), request.clone());
+                thread::spawn(move || {
+                    barrier.wait();
+                    supplier.query(request)
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap().unwrap();
+        }
+        calls.load(Ordering::SeqCst)
+    };
+
+    let create = SupplierRequest::new(SupplierOperation::Create, json!({ "sku": "lamp" }));
+    assert_eq!(concurrent(create.clone()), 2);
+    assert_eq!(concurrent(create.with_idempotency_key("order-1")), 1);
+}
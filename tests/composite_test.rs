@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use serde_json::json;
+use supplier_kit::composite::CompositeSupplier;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }))
+}
+
+fn names<T>(entries: &[(String, T)]) -> Vec<&str> {
+    entries.iter().map(|(name, _)| name.as_str()).collect()
+}
+
+/// `amazon_de` answers, `amazon_fr` fails once before answering.
+fn eu() -> BasicSupplierGroup {
+    let mut eu = BasicSupplierGroup::new("eu");
+    eu.add_supplier(MockSupplierBuilder::new("amazon_de").respond_default(json!(["de"])).build());
+    eu.add_supplier(
+        MockSupplierBuilder::new("amazon_fr")
+            .then_fail(SupplierError::Timeout)
+            .respond_default(json!(["fr"]))
+            .build(),
+    );
+    eu
+}
+
+fn global(flatten: bool) -> BasicSupplierGroup {
+    let mut global = BasicSupplierGroup::new("global").with_strategy(QueryStrategy::Parallel);
+    if flatten {
+        global = global.with_flattened_results();
+    }
+    global.add_supplier(CompositeSupplier::new(Arc::new(eu())));
+    global.add_supplier(MockSupplierBuilder::new("walmart").respond_default(json!(["us"])).build());
+    global
+}
+
+#[test]
+fn composite_merges_the_successes_of_its_group() {
+    let composite = CompositeSupplier::new(Arc::new(eu())).with_name("europe");
+    assert_eq!(composite.name(), "europe");
+    assert_eq!(composite.group().group_name(), "eu");
+    assert!(composite.as_group().is_some());
+
+    let response = composite.query(search()).unwrap();
+    assert_eq!(response.data, json!({ "amazon_de": ["de"] }));
+}
+
+#[test]
+fn composite_fails_when_its_group_fails_as_a_whole() {
+    let mut down = BasicSupplierGroup::new("down");
+    down.add_supplier(MockSupplierBuilder::new("a").then_fail(SupplierError::Timeout).build());
+    let composite = CompositeSupplier::new(Arc::new(down));
+
+    let error = composite.query(search()).unwrap_err();
+    assert_eq!(error.to_string(), "upstream error: all 1 queried suppliers of group 'down' failed");
+}
+
+#[test]
+fn nested_groups_are_one_supplier_by_default() {
+    let group = global(false);
+    assert!(!group.flattens_results());
+
+    let result = group.query(search());
+    assert_eq!(names(&result.successes), ["eu", "walmart"]);
+    assert!(result.failures.is_empty());
+}
+
+#[test]
+fn flattened_results_name_the_members_of_nested_groups() {
+    let group = global(true);
+    assert!(group.flattens_results());
+
+    let result = group.query(search());
+    assert_eq!(names(&result.successes), ["eu/amazon_de", "walmart"]);
+    assert_eq!(result.successes[0].1.data, json!(["de"]));
+    assert_eq!(names(&result.failures), ["eu/amazon_fr"]);
+    assert!(matches!(result.failures[0].1, SupplierError::Timeout));
+    let outcomes: Vec<&str> = result.outcomes.iter().map(|o| o.supplier.as_str()).collect();
+    assert_eq!(outcomes, ["eu/amazon_de", "eu/amazon_fr", "walmart"]);
+}
+
+#[test]
+fn flattening_nests_through_several_levels() {
+    let mut emea = BasicSupplierGroup::new("emea").with_flattened_results();
+    emea.add_supplier(CompositeSupplier::new(Arc::new(eu())));
+    let mut world = BasicSupplierGroup::new("world").with_flattened_results();
+    world.add_supplier(CompositeSupplier::new(Arc::new(emea)));
+
+    let result = world.query(search());
+    assert_eq!(names(&result.successes), ["emea/eu/amazon_de"]);
+    assert_eq!(names(&result.failures), ["emea/eu/amazon_fr"]);
+}
+
+#[test]
+fn nested_groups_without_members_keep_the_composite_name() {
+    let mut global = BasicSupplierGroup::new("global").with_flattened_results();
+    global.add_supplier(CompositeSupplier::new(Arc::new(BasicSupplierGroup::new("empty"))));
+
+    let result = global.query(search());
+    assert_eq!(names(&result.failures), ["empty"]);
+    assert!(matches!(result.failures[0].1, SupplierError::Upstream(_)));
+}
+
+#[test]
+fn query_each_flattens_the_nested_group_request() {
+    let group = global(true);
+    let requests = HashMap::from([("eu".to_string(), search())]);
+
+    let result = group.query_each(requests);
+    assert_eq!(names(&result.successes), ["eu/amazon_de"]);
+    assert_eq!(names(&result.failures), ["eu/amazon_fr"]);
+}
+
+#[test]
+fn retrying_a_flattened_failure_requeries_its_nested_group() {
+    let group = global(true);
+    let mut result = group.query(search());
+
+    assert_eq!(result.retry_failures(&group, search()), 1);
+    assert_eq!(names(&result.successes), ["eu/amazon_de", "eu/amazon_fr", "walmart"]);
+    assert!(result.failures.is_empty());
+}
@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::compression::{
+    compress, compress_stream, decompress, from_compressed_json, inflate, to_compressed_json, Compression,
+};
+use supplier_kit::decorators::cache::CachingSupplier;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{ResponseSource, SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::testing::fixtures::FixtureLoader;
+use supplier_kit::testing::mock::MockSupplierBuilder;
+use supplier_kit::testing::replay::{read_exchanges, RecordingSupplier};
+
+fn catalog() -> SupplierResponse {
+    let items: Vec<_> = (0..200).map(|i| json!({ "sku": format!("SKU-{}", i), "currency": "IDR" })).collect();
+    SupplierResponse::new(json!({ "items": items }))
+}
+
+fn codecs() -> Vec<Compression> {
+    #[allow(unused_mut)]
+    let mut codecs = vec![Compression::None];
+    #[cfg(feature = "zstd")]
+    codecs.push(Compression::Zstd(3));
+    #[cfg(feature = "lz4")]
+    codecs.push(Compression::Lz4);
+    #[cfg(feature = "gzip")]
+    codecs.push(Compression::Gzip(6));
+    codecs
+}
+
+fn extension(codec: Compression) -> &'static str {
+    match codec {
+        Compression::None => "",
+        #[cfg(feature = "zstd")]
+        Compression::Zstd(_) => ".zst",
+        #[cfg(feature = "lz4")]
+        Compression::Lz4 => ".lz4",
+        #[cfg(feature = "gzip")]
+        Compression::Gzip(_) => ".gz",
+    }
+}
+
+#[test]
+fn test_round_trip_for_every_enabled_codec() {
+    for codec in codecs() {
+        let packed = to_compressed_json(&catalog(), codec).unwrap();
+        let unpacked: SupplierResponse = from_compressed_json(&packed).unwrap();
+        assert_eq!(unpacked, catalog(), "codec {:?}", codec);
+    }
+}
+
+#[test]
+fn test_rejects_empty_and_unknown_payloads() {
+    assert!(matches!(decompress(&[]), Err(SupplierError::InvalidInput(_))));
+    assert!(matches!(decompress(&[42, 1, 2]), Err(SupplierError::InvalidInput(_))));
+    assert_eq!(decompress(&compress(b"", Compression::None).unwrap()).unwrap(), b"");
+}
+
+#[test]
+fn test_streams_inflate_transparently_and_concatenate() {
+    let json = serde_json::to_vec(&catalog()).unwrap();
+    assert!(matches!(inflate(&json, None).unwrap(), Cow::Borrowed(_)));
+
+    for codec in codecs() {
+        let mut stream = compress_stream(&json, codec).unwrap();
+        if codec != Compression::None {
+            assert!(stream.len() < json.len() / 2, "codec {:?}", codec);
+        }
+        stream.extend(compress_stream(b"tail", codec).unwrap());
+        let inflated = inflate(&stream, None).unwrap();
+        assert_eq!(&inflated[..json.len()], json.as_slice(), "codec {:?}", codec);
+        assert_eq!(&inflated[json.len()..], b"tail", "codec {:?}", codec);
+    }
+}
+
+#[test]
+fn test_inflate_bounds_the_decompressed_size() {
+    let bomb = vec![b' '; 1 << 20];
+    for codec in codecs().into_iter().skip(1) {
+        let stream = compress_stream(&bomb, codec).unwrap();
+        assert!(matches!(inflate(&stream, Some(4096)), Err(SupplierError::ResponseTooLarge(_))), "codec {:?}", codec);
+        assert_eq!(inflate(&stream, Some(bomb.len())).unwrap().len(), bomb.len());
+        assert!(matches!(inflate(&stream[..stream.len() / 2], None), Err(SupplierError::InvalidInput(_))));
+    }
+}
+
+#[test]
+fn test_cached_entries_can_be_compressed() {
+    for codec in codecs() {
+        let catalog_supplier = MockSupplierBuilder::new("catalog").respond_default(catalog().data).build();
+        let cached = CachingSupplier::new(catalog_supplier.clone(), Duration::from_secs(60)).with_compression(codec);
+        let request = SupplierRequest::new(SupplierOperation::Search, json!({ "q": "all" }));
+
+        assert_eq!(cached.query(request.clone()).unwrap().source(), ResponseSource::Live);
+        let hit = cached.query(request).unwrap();
+        assert_eq!(hit.source(), ResponseSource::Cache);
+        assert_eq!(hit.data, catalog().data, "codec {:?}", codec);
+        assert_eq!(catalog_supplier.calls(), 1);
+    }
+}
+
+#[test]
+fn test_recorded_fixtures_can_be_compressed() {
+    let dir = std::env::temp_dir().join(format!("supplier_kit_compressed_fixtures_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    for (index, codec) in codecs().into_iter().enumerate() {
+        let name = format!("catalog_{}", index);
+        let path = dir.join(format!("{}.ndjson{}", name, extension(codec)));
+        let live = MockSupplierBuilder::new(&name).respond_default(catalog().data).build();
+        let recorder = RecordingSupplier::new(live, &path).unwrap().with_compression(codec);
+        for q in ["a", "b"] {
+            recorder.query(SupplierRequest::new(SupplierOperation::Search, json!({ "q": q }))).unwrap();
+        }
+        assert!(recorder.take_error().is_none());
+        assert_eq!(read_exchanges(&path).unwrap().len(), 2, "codec {:?}", codec);
+    }
+
+    let registry = FixtureLoader::new(&dir).load_registry().unwrap();
+    assert_eq!(registry.all_names().len(), codecs().len());
+    for index in 0..codecs().len() {
+        let request = SupplierRequest::new(SupplierOperation::Search, json!({ "q": "b" }));
+        assert_eq!(registry.query(&format!("catalog_{}", index), request).unwrap().data, catalog().data);
+    }
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,110 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::decorators::bulkhead::{Bulkhead, BulkheadMode};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+
+/// Sleeps during each query and records the peak number of concurrent callers.
+struct SlowSupplier {
+    name: String,
+    current: Arc<AtomicUsize>,
+    peak: Arc<AtomicUsize>,
+}
+
+impl SlowSupplier {
+    fn new(name: &str, current: &Arc<AtomicUsize>, peak: &Arc<AtomicUsize>) -> Self {
+        Self {
+            name: name.to_string(),
+            current: current.clone(),
+            peak: peak.clone(),
+        }
+    }
+}
+
+impl Supplier for SlowSupplier {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let now = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(now, Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(50));
+        self.current.fetch_sub(1, Ordering::SeqCst);
+        Ok(SupplierResponse::new(json!({ "supplier": self.name })))
+    }
+}
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+#[test]
+fn test_bulkhead_rejects_excess_queries() {
+    let current = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let supplier = Arc::new(Bulkhead::new(SlowSupplier::new("slow", &current, &peak), 1, BulkheadMode::Reject));
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let supplier = supplier.clone();
+            thread::spawn(move || supplier.query(request()))
+        })
+        .collect();
+    let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    assert_eq!(peak.load(Ordering::SeqCst), 1);
+    assert!(results.iter().any(|r| r.is_ok()));
+    assert!(results
+        .iter()
+        .any(|r| matches!(r, Err(SupplierError::ConcurrencyLimitExceeded(_)))));
+    assert_eq!(supplier.in_flight(), 0);
+}
+
+#[test]
+fn test_bulkhead_queue_waits_for_permit() {
+    let current = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let supplier = Arc::new(Bulkhead::new(
+        SlowSupplier::new("slow", &current, &peak),
+        1,
+        BulkheadMode::Queue(Duration::from_secs(5)),
+    ));
+
+    let handles: Vec<_> = (0..3)
+        .map(|_| {
+            let supplier = supplier.clone();
+            thread::spawn(move || supplier.query(request()))
+        })
+        .collect();
+
+    for handle in handles {
+        assert!(handle.join().unwrap().is_ok());
+    }
+    assert_eq!(peak.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_parallel_group_honors_max_concurrency() {
+    let current = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+
+    let mut group = BasicSupplierGroup::new("parallel")
+        .with_strategy(QueryStrategy::Parallel)
+        .with_max_concurrency(2);
+    for i in 0..6 {
+        group.add_supplier(SlowSupplier::new(&format!("s{}", i), &current, &peak));
+    }
+
+    let result = group.query(request());
+    assert_eq!(result.successes.len(), 6);
+    assert!(peak.load(Ordering::SeqCst) <= 2);
+
+    let names: Vec<_> = result.successes.iter().map(|(n, _)| n.as_str()).collect();
+    assert_eq!(names, ["s0", "s1", "s2", "s3", "s4", "s5"]);
+}
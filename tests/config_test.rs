@@ -0,0 +1,115 @@
+use std::sync::Arc;
+use serde_json::json;
+use supplier_kit::config::{Manifest, SupplierFactory};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{QueryStrategy, SupplierGroup};
+
+fn search(q: &str) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": q }))
+}
+
+#[test]
+fn manifest_file_builds_registry_and_groups() {
+    let manifest = Manifest::from_file("tests/fixtures/cli/manifest.json").unwrap();
+    let loaded = SupplierFactory::new().load(&manifest).unwrap();
+
+    let mut names = loaded.registry.all_names();
+    names.sort();
+    assert_eq!(names, ["flaky", "shop_a", "shop_b"]);
+
+    let group = &loaded.groups["marketplaces"];
+    assert_eq!(group.strategy(), QueryStrategy::Parallel);
+    assert_eq!(group.max_concurrency(), Some(2));
+
+    let result = group.query(search("laptop"));
+    let mut succeeded: Vec<_> = result.successes.iter().map(|(name, _)| name.as_str()).collect();
+    succeeded.sort();
+    assert_eq!(succeeded, ["shop_a", "shop_b"]);
+    assert!(matches!(&result.failures[..], [(name, SupplierError::Upstream(_))] if name == "flaky"));
+}
+
+#[test]
+fn mock_type_answers_per_operation() {
+    let manifest = Manifest::from_json(
+        r#"{ "suppliers": [{ "name": "m", "type": "mock", "config": { "responses": { "Get Detail": { "id": 1 } } } }] }"#,
+    )
+    .unwrap();
+    let loaded = SupplierFactory::new().load(&manifest).unwrap();
+
+    let detail = SupplierRequest::new(SupplierOperation::GetDetail, json!({}));
+    assert_eq!(loaded.registry.query("m", detail).unwrap().data["id"], 1);
+    assert!(matches!(loaded.registry.query("m", search("x")), Err(SupplierError::UnsupportedOperation(_))));
+}
+
+struct Fixed(String);
+
+impl Supplier for Fixed {
+    fn name(&self) -> &str {
+        &self.0
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!({ "from": self.0 })))
+    }
+}
+
+#[test]
+fn custom_types_can_be_registered() {
+    let factory = SupplierFactory::new().register("fixed", |spec, _context| {
+        let label = spec.config["label"].as_str().unwrap_or(&spec.name).to_string();
+        Ok(Arc::new(Fixed(label)) as Arc<dyn Supplier>)
+    });
+    assert_eq!(factory.kinds(), ["fixed", "mock", "replay"]);
+
+    let manifest = Manifest::from_json(
+        r#"{ "suppliers": [{ "name": "x", "type": "fixed", "config": { "label": "custom" } }],
+             "groups": [{ "name": "g", "suppliers": ["x"] }] }"#,
+    )
+    .unwrap();
+    let loaded = factory.load(&manifest).unwrap();
+    assert_eq!(loaded.groups["g"].query(search("a")).successes[0].1.data["from"], "custom");
+}
+
+#[test]
+fn invalid_manifests_are_rejected() {
+    let factory = SupplierFactory::new();
+    let load = |json: &str| factory.load(&Manifest::from_json(json).unwrap()).map(|_| ());
+
+    assert!(matches!(Manifest::from_json("{ \"suppliers\": 3 }"), Err(SupplierError::InvalidInput(_))));
+    assert!(matches!(Manifest::from_file("tests/fixtures/cli/missing.json"), Err(SupplierError::InvalidInput(_))));
+    assert!(matches!(
+        load(r#"{ "suppliers": [{ "name": "a", "type": "ftp" }] }"#),
+        Err(SupplierError::InvalidInput(m)) if m.contains("unknown type 'ftp'")
+    ));
+    assert!(matches!(
+        load(r#"{ "suppliers": [{ "name": "a", "type": "mock" }, { "name": "a", "type": "mock" }] }"#),
+        Err(SupplierError::InvalidInput(m)) if m.contains("declared twice")
+    ));
+    assert!(matches!(
+        load(r#"{ "groups": [{ "name": "g", "suppliers": ["ghost"] }] }"#),
+        Err(SupplierError::InvalidInput(m)) if m.contains("unknown supplier 'ghost'")
+    ));
+    assert!(matches!(
+        load(r#"{ "suppliers": [{ "name": "r", "type": "replay", "config": {} }] }"#),
+        Err(SupplierError::InvalidInput(m)) if m.contains("'path' is required")
+    ));
+}
+
+#[test]
+fn group_plan_reports_skips_and_param_errors() {
+    let manifest = Manifest::from_file("tests/fixtures/cli/manifest.json").unwrap();
+    let loaded = SupplierFactory::new().load(&manifest).unwrap();
+    let group = &loaded.groups["marketplaces"];
+
+    let plan = group.plan(&SupplierRequest::new(SupplierOperation::Search, json!({ "q": 3 })));
+    assert_eq!(plan.len(), 3);
+    assert!(plan.iter().all(|p| p.would_query()));
+    assert_eq!(plan[0].declares_operation, Some(true));
+    assert_eq!(plan[0].param_errors, ["/q: expected string, found number"]);
+    assert_eq!(plan[1].declares_operation, None);
+
+    let valid = group.plan(&search("laptop"));
+    assert!(valid.iter().all(|p| p.param_errors.is_empty()));
+}
@@ -0,0 +1,82 @@
+use serde_json::json;
+use supplier_kit::consistency::{ConsistencyChecker, Divergence};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+#[test]
+fn reports_value_type_and_shape_differences() {
+    let a = json!({ "name": "Lamp", "stock": 3, "tags": ["a"], "only_left": true });
+    let b = json!({ "name": "Lamp", "stock": "3", "tags": ["a", "b"], "only_right": 1 });
+
+    let divergences = ConsistencyChecker::new().compare(&a, &b);
+    let paths: Vec<&str> = divergences.iter().map(|d| d.path.as_str()).collect();
+    assert_eq!(paths, vec!["/only_left", "/only_right", "/stock", "/tags"]);
+    assert_eq!(
+        divergences[0],
+        Divergence { path: "/only_left".into(), left: Some(json!(true)), right: None }
+    );
+}
+
+#[test]
+fn tolerance_rules_absorb_expected_differences() {
+    let checker = ConsistencyChecker::new()
+        .ignore("/meta")
+        .relative_tolerance("/offers/*/price", 0.02)
+        .absolute_tolerance("/rating", 0.1)
+        .unordered("/offers");
+
+    let a = json!({
+        "meta": { "trace": "x" },
+        "rating": 4.5,
+        "offers": [{ "id": 1, "price": 100.0 }, { "id": 2, "price": 50 }],
+    });
+    let b = json!({
+        "meta": { "trace": "y", "extra": 1 },
+        "rating": 4.55,
+        "offers": [{ "id": 2, "price": 50.5 }, { "id": 1, "price": 101 }],
+    });
+    assert!(checker.agrees(&a, &b));
+
+    let c = json!({
+        "rating": 4.8,
+        "offers": [{ "id": 2, "price": 60 }, { "id": 1, "price": 100 }],
+    });
+    let paths: Vec<String> = checker.compare(&a, &c).into_iter().map(|d| d.path).collect();
+    assert_eq!(paths, vec!["/offers", "/rating"]);
+}
+
+#[test]
+fn pointers_escape_special_characters() {
+    let divergences = ConsistencyChecker::new().compare(&json!({ "a/b": 1 }), &json!({ "a/b": 2 }));
+    assert_eq!(divergences[0].path, "/a~1b");
+    assert!(ConsistencyChecker::new().ignore("/a~1b").agrees(&json!({ "a/b": 1 }), &json!({ "a/b": 2 })));
+}
+
+#[test]
+fn group_check_picks_the_majority_as_reference() {
+    let mut group = BasicSupplierGroup::new("mirrors").with_strategy(QueryStrategy::Parallel);
+    group.add_supplier(MockSupplierBuilder::new("stale").respond_default(json!({ "price": 12 })).build());
+    group.add_supplier(MockSupplierBuilder::new("primary").respond_default(json!({ "price": 10 })).build());
+    group.add_supplier(MockSupplierBuilder::new("replica").respond_default(json!({ "price": 10 })).build());
+    group.add_supplier(MockSupplierBuilder::new("down").then_fail(SupplierError::Timeout).build());
+
+    let result = group.query(SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": 1 })));
+    let report = ConsistencyChecker::new().check(&result);
+
+    assert!(!report.is_consistent());
+    assert_eq!(report.reference.as_deref(), Some("primary"));
+    assert_eq!(report.agreeing, vec!["primary", "replica"]);
+    assert_eq!(report.divergent.len(), 1);
+    assert_eq!(report.divergent[0].supplier, "stale");
+    assert_eq!(report.divergent[0].divergences[0].right, Some(json!(12)));
+    assert_eq!(report.failed, vec!["down"]);
+}
+
+#[test]
+fn empty_result_has_no_reference() {
+    let report = ConsistencyChecker::new().check(&Default::default());
+    assert!(report.is_consistent());
+    assert_eq!(report.reference, None);
+}
@@ -0,0 +1,198 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use serde_json::{json, Value};
+use supplier_kit::config::{Manifest, SupplierFactory};
+use supplier_kit::conversion::{Conversions, CurrencyConverter, Dimension, FixedRates, RateSource, UnitConverter, ValueConverter};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::json_path::JsonPath;
+use supplier_kit::mapping::{FieldRule, FieldType, MappingSpec, ResponseNormalizer};
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier_group::BasicSupplierGroup;
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn rates() -> FixedRates {
+    FixedRates::new("USD").with_rate("EUR", 1.1).with_rate("GBP", 1.25)
+}
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }))
+}
+
+struct CountingRates(AtomicUsize);
+
+impl RateSource for CountingRates {
+    fn rate(&self, from: &str, to: &str) -> Result<f64, SupplierError> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        match (from, to) {
+            ("JPY", "USD") => Ok(0.0067),
+            _ => Err(SupplierError::Internal(format!("no rate {} -> {}", from, to))),
+        }
+    }
+}
+
+#[test]
+fn currency_converter_uses_the_rate_source() {
+    let usd = CurrencyConverter::new("usd", rates());
+    assert_eq!(usd.target(), "USD");
+    assert_eq!(usd.convert(&json!({ "amount": 10, "currency": "GBP" })).unwrap(), json!({ "amount": 12.5, "currency": "USD" }));
+    assert_eq!(usd.convert(&json!({ "amount": 3.333, "currency": "USD" })).unwrap()["amount"], 3.33);
+
+    let eur = CurrencyConverter::new("EUR", rates()).with_precision(None);
+    let converted = eur.convert(&json!({ "amount": 11, "currency": "USD" })).unwrap();
+    assert!((converted["amount"].as_f64().unwrap() - 10.0).abs() < 1e-9);
+
+    let custom = CurrencyConverter::new("USD", rates()).with_keys("value", "ccy");
+    assert_eq!(custom.convert(&json!({ "value": "2", "ccy": "EUR" })).unwrap(), json!({ "value": 2.2, "ccy": "USD" }));
+
+    let counting = Arc::new(CountingRates(AtomicUsize::new(0)));
+    let live = CurrencyConverter::with_rate_source("USD", counting.clone());
+    assert_eq!(live.convert(&json!({ "amount": 1000, "currency": "JPY" })).unwrap()["amount"], 6.7);
+    assert_eq!(live.convert(&json!({ "amount": 1, "currency": "USD" })).unwrap()["amount"], 1.0);
+    assert_eq!(counting.0.load(Ordering::SeqCst), 1);
+    assert!(matches!(live.convert(&json!({ "amount": 1, "currency": "CHF" })), Err(SupplierError::Internal(_))));
+}
+
+#[test]
+fn malformed_prices_are_upstream_errors() {
+    let usd = CurrencyConverter::new("USD", rates());
+    for price in [json!(12), json!({ "amount": "ten", "currency": "EUR" }), json!({ "amount": 1 }), json!({ "amount": 1, "currency": " " })] {
+        assert!(matches!(usd.convert(&price), Err(SupplierError::Upstream(_))), "{}", price);
+    }
+}
+
+#[test]
+fn unit_converter_handles_weights_and_dimensions() {
+    let grams = UnitConverter::weight("g").unwrap();
+    assert_eq!(grams.convert(&json!({ "value": "1.5", "unit": "KG" })).unwrap(), json!({ "value": 1500.0, "unit": "g" }));
+    assert_eq!(grams.convert(&json!("16 oz")).unwrap(), json!("453.592 g"));
+    assert_eq!(grams.convert(&json!("2lbs")).unwrap(), json!("907.185 g"));
+
+    let inches = UnitConverter::new(Dimension::Length, "in").unwrap().with_precision(Some(1));
+    assert_eq!(
+        inches.convert(&json!({ "length": 100, "width": "50.8", "label": "box", "unit": "cm" })).unwrap(),
+        json!({ "length": 39.4, "width": 20.0, "label": "box", "unit": "in" })
+    );
+
+    let metres = UnitConverter::length("m").unwrap().with_unit_key("uom");
+    assert_eq!(metres.convert(&json!({ "height": 3, "uom": "ft" })).unwrap(), json!({ "height": 0.914, "uom": "m" }));
+}
+
+#[test]
+fn unknown_units_are_rejected() {
+    assert!(matches!(UnitConverter::weight("cm"), Err(SupplierError::InvalidInput(_))));
+    assert!(matches!(UnitConverter::length("stone"), Err(SupplierError::InvalidInput(_))));
+
+    let kilograms = UnitConverter::weight("kg").unwrap();
+    for quantity in [json!("3 stone"), json!("heavy"), json!({ "value": 1 }), json!({ "value": 1, "unit": "m" }), json!(true)] {
+        assert!(matches!(kilograms.convert(&quantity), Err(SupplierError::Upstream(_))), "{}", quantity);
+    }
+}
+
+#[test]
+fn conversions_normalize_every_selected_value() {
+    let conversions = Conversions::new()
+        .with_conversion(JsonPath::parse("$..price").unwrap(), CurrencyConverter::new("USD", rates()))
+        .with_conversion(JsonPath::parse("$.items[*].size").unwrap(), UnitConverter::length("cm").unwrap())
+        .with_conversion(JsonPath::parse("$.note").unwrap(), |value: &Value| Ok(json!(value.to_string().len())));
+
+    let mut data = json!({
+        "items": [
+            { "price": { "amount": 10, "currency": "EUR" }, "size": { "length": 1, "unit": "m" } },
+            { "price": null, "size": "2 in", "bundle": { "price": { "amount": 4, "currency": "GBP" } } }
+        ],
+        "note": "hi"
+    });
+    conversions.apply(&mut data).unwrap();
+    assert_eq!(
+        data,
+        json!({
+            "items": [
+                { "price": { "amount": 11.0, "currency": "USD" }, "size": { "length": 100.0, "unit": "cm" } },
+                { "price": null, "size": "5.08 cm", "bundle": { "price": { "amount": 5.0, "currency": "USD" } } }
+            ],
+            "note": 4
+        })
+    );
+
+    let broken = SupplierResponse::new(json!({ "items": [{ "price": { "amount": 1, "currency": "XYZ" } }] }));
+    assert!(conversions.normalize_response(broken).is_err());
+}
+
+#[test]
+fn groups_compare_prices_in_one_currency_after_conversion() {
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.add_supplier(MockSupplierBuilder::new("eu_shop").respond_default(json!({ "price": { "amount": 100, "currency": "EUR" } })).build());
+    group.add_supplier(MockSupplierBuilder::new("uk_shop").respond_default(json!({ "price": { "amount": 90, "currency": "GBP" } })).build());
+    group.add_supplier(MockSupplierBuilder::new("us_shop").respond_default(json!({ "price": { "amount": 111, "currency": "USD" } })).build());
+
+    let conversions = Conversions::new().with_conversion(JsonPath::parse("$.price").unwrap(), CurrencyConverter::new("USD", rates()));
+    let result = group.query_transformed(search(), |_name, response| conversions.normalize_response(response));
+
+    let cheapest = result
+        .successes
+        .iter()
+        .min_by(|(_, a), (_, b)| a.data["price"]["amount"].as_f64().partial_cmp(&b.data["price"]["amount"].as_f64()).unwrap())
+        .map(|(name, _)| name.as_str());
+    assert_eq!(cheapest, Some("eu_shop"));
+}
+
+#[test]
+fn mappings_apply_named_converters() {
+    let spec = MappingSpec {
+        each: Some("$.Results[*]".to_string()),
+        fields: vec![
+            FieldRule::new("$.Price", "price").with_converter("usd"),
+            FieldRule::new("$.Weights[*]", "weights").with_type(FieldType::String).with_converter("kg"),
+        ],
+    };
+    let mapping = spec
+        .compile()
+        .unwrap()
+        .with_converter("usd", CurrencyConverter::new("USD", rates()))
+        .with_converter("kg", UnitConverter::weight("kg").unwrap());
+    mapping.validate().unwrap();
+
+    let data = json!({ "Results": [{ "Price": { "amount": 2, "currency": "EUR" }, "Weights": ["500 g", "1 lb"] }] });
+    assert_eq!(
+        mapping.map(&data).unwrap(),
+        json!([{ "price": { "amount": 2.2, "currency": "USD" }, "weights": ["0.5 kg", "0.454 kg"] }])
+    );
+
+    let bad = json!({ "Results": [{ "Price": { "amount": 2 } }] });
+    assert!(matches!(mapping.map(&bad), Err(SupplierError::Upstream(m)) if m.contains("record 0") && m.contains("'price'")));
+
+    let unregistered = spec.compile().unwrap();
+    assert!(matches!(unregistered.validate(), Err(SupplierError::InvalidInput(m)) if m.contains("'usd'")));
+    assert!(matches!(unregistered.map(&data), Err(SupplierError::InvalidInput(_))));
+}
+
+#[test]
+fn factories_provide_converters_to_manifest_mappings() {
+    let manifest = Manifest::from_json(
+        r#"{
+            "suppliers": [{
+                "name": "eu_shop",
+                "type": "mock",
+                "config": { "default": { "Offer": { "Price": "10", "Currency": "EUR" } } },
+                "mapping": { "fields": [
+                    { "from": "$.Offer.Price", "to": "price.amount", "type": "float" },
+                    { "from": "$.Offer.Currency", "to": "price.currency" }
+                ] }
+            }, {
+                "name": "uk_shop",
+                "type": "mock",
+                "config": { "default": { "price": { "amount": 8, "currency": "GBP" } } },
+                "mapping": { "fields": [{ "from": "$.price", "to": "price", "convert": "usd" }] }
+            }]
+        }"#,
+    )
+    .unwrap();
+
+    let factory = SupplierFactory::new().with_converter("usd", CurrencyConverter::new("USD", rates()));
+    let loaded = factory.load(&manifest).unwrap();
+    assert_eq!(loaded.registry.query("uk_shop", search()).unwrap().data, json!({ "price": { "amount": 10.0, "currency": "USD" } }));
+    assert_eq!(loaded.registry.query("eu_shop", search()).unwrap().data["price"]["amount"], 10.0);
+
+    assert!(matches!(SupplierFactory::new().load(&manifest), Err(SupplierError::InvalidInput(m))
+        if m.contains("uk_shop") && m.contains("unknown converter 'usd'")));
+}
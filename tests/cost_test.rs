@@ -0,0 +1,102 @@
+use std::sync::Arc;
+use serde_json::json;
+use supplier_kit::context::RequestContext;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::metrics::cost::{CostModel, CostRecorder};
+use supplier_kit::metrics::prometheus::PrometheusRecorder;
+use supplier_kit::metrics::MetricsRecorder;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn costs() -> Arc<CostRecorder> {
+    Arc::new(
+        CostRecorder::new()
+            .with_model("shop_a", CostModel::per_call(0.25).with_operation_cost(SupplierOperation::Create, 1.0))
+            .with_model("shop_b", CostModel::per_call(0.5).without_failure_charges()),
+    )
+}
+
+fn group(name: &str, recorder: Arc<dyn MetricsRecorder>) -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new(name).with_metrics(recorder);
+    group.add_supplier(MockSupplierBuilder::new("shop_a").respond_default(json!({})).build());
+    group.add_supplier(
+        MockSupplierBuilder::new("shop_b")
+            .respond(SupplierOperation::Search, json!({}))
+            .build(),
+    );
+    group.add_supplier(MockSupplierBuilder::new("free").respond_default(json!({})).build());
+    group
+}
+
+fn request(operation: SupplierOperation, tenant: Option<&str>) -> SupplierRequest {
+    let mut context = RequestContext::new();
+    context.tenant = tenant.map(str::to_string);
+    SupplierRequest::new(operation, json!({})).with_context(context)
+}
+
+#[test]
+fn calls_are_priced_per_operation() {
+    let costs = costs();
+    let marketplaces = group("marketplaces", costs.clone());
+
+    marketplaces.query(request(SupplierOperation::Search, Some("acme")));
+    let created = marketplaces.query(request(SupplierOperation::Create, Some("acme")));
+    assert!(matches!(created.failures[0].1, SupplierError::UnsupportedOperation(_) | SupplierError::NotFound));
+
+    let report = costs.report();
+    // search: 0.25 + 0.5 + 0; create: 1.0 + (failed, free) + 0
+    assert_eq!(report.total.cost, 1.75);
+    assert_eq!(report.total.calls, 6);
+    assert_eq!(report.suppliers["shop_a"].cost, 1.25);
+    assert_eq!(report.suppliers["shop_b"].cost, 0.5);
+    assert_eq!(report.suppliers["free"].calls, 2);
+    assert_eq!(report.suppliers["free"].cost, 0.0);
+}
+
+#[test]
+fn costs_are_broken_down_by_group_and_tenant() {
+    let costs = costs();
+    let marketplaces = group("marketplaces", costs.clone());
+    let mirrors = group("mirrors", costs.clone());
+
+    marketplaces.query(request(SupplierOperation::Search, Some("acme")));
+    marketplaces.query(request(SupplierOperation::Search, Some("globex")));
+    marketplaces.query(request(SupplierOperation::Search, Some("globex")));
+    mirrors.query(request(SupplierOperation::Search, None));
+
+    let report = costs.report();
+    assert_eq!(report.groups["marketplaces"].queries, 3);
+    assert_eq!(report.groups["marketplaces"].cost_per_query(), 0.75);
+    assert_eq!(report.tenants["globex"].cost, 1.5);
+    assert_eq!(report.tenants[""].queries, 1);
+    assert_eq!(report.group_tenants["marketplaces"]["acme"].cost, 0.75);
+    assert!(!report.group_tenants["mirrors"].contains_key("acme"));
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["tenants"]["acme"]["calls"], 3);
+}
+
+#[test]
+fn taking_a_report_starts_a_new_period() {
+    let costs = costs();
+    let marketplaces = group("marketplaces", costs.clone());
+    marketplaces.query(request(SupplierOperation::Search, None));
+
+    assert_eq!(costs.take_report().total.queries, 1);
+    assert_eq!(costs.report().total.queries, 0);
+    assert_eq!(costs.report().total.cost_per_query(), 0.0);
+}
+
+#[test]
+fn cost_recording_combines_with_other_recorders() {
+    let costs = costs();
+    let prometheus = Arc::new(PrometheusRecorder::new());
+    let recorders: Vec<Arc<dyn MetricsRecorder>> = vec![costs.clone(), prometheus.clone()];
+    let marketplaces = group("marketplaces", Arc::new(recorders));
+
+    marketplaces.query(request(SupplierOperation::Search, None));
+
+    assert_eq!(costs.report().total.calls, 3);
+    assert!(prometheus.render().contains(r#"supplier_kit_group_queries_total{group="marketplaces"} 1"#));
+}
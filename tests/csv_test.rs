@@ -0,0 +1,83 @@
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::formats::csv::{ColumnType, CsvHeader, CsvParser};
+use supplier_kit::models::SupplierResponse;
+
+const PRICE_LIST: &str = "\u{feff}sku,description,price,stock,active\r\n\
+    007,\"Lamp, brass\",12.50,4,true\r\n\
+    \r\n\
+    108,\"Shade \"\"XL\"\"\nlinen\",-3,,FALSE\r\n";
+
+#[test]
+fn price_lists_become_arrays_of_objects() {
+    let rows = CsvParser::new().parse(PRICE_LIST).unwrap();
+    assert_eq!(
+        rows,
+        json!([
+            { "sku": "007", "description": "Lamp, brass", "price": 12.5, "stock": 4, "active": true },
+            { "sku": 108, "description": "Shade \"XL\"\nlinen", "price": -3, "stock": null, "active": false }
+        ])
+    );
+
+    let typed = CsvParser::new()
+        .with_column_type("sku", ColumnType::String)
+        .with_column_type("price", ColumnType::Float)
+        .parse(PRICE_LIST)
+        .unwrap();
+    assert_eq!(typed[1]["sku"], "108");
+    assert_eq!(typed[1]["price"], -3.0);
+
+    let raw = CsvParser::new().with_type_coercion(false).parse(PRICE_LIST).unwrap();
+    assert_eq!(raw[0]["stock"], "4");
+    assert_eq!(raw[1]["stock"], "");
+}
+
+#[test]
+fn headers_can_be_inferred_or_supplied() {
+    let tsv = "A1\t4\nB2\t0\n";
+    let named = CsvParser::tsv()
+        .with_header(CsvHeader::Names(vec!["sku".into(), "stock".into()]))
+        .parse(tsv)
+        .unwrap();
+    assert_eq!(named, json!([{ "sku": "A1", "stock": 4 }, { "sku": "B2", "stock": 0 }]));
+
+    let inferred = CsvParser::tsv().with_header(CsvHeader::Infer);
+    assert_eq!(inferred.parse(tsv).unwrap()[0], json!({ "column_1": "A1", "column_2": 4 }));
+    assert_eq!(inferred.parse("sku\tstock\nA1\t4\n").unwrap(), json!([{ "sku": "A1", "stock": 4 }]));
+
+    let messy = CsvParser::new().with_delimiter(';').parse("price;;price\n1;2;3\n").unwrap();
+    assert_eq!(messy, json!([{ "price": 1, "column_2": 2, "price_2": 3 }]));
+}
+
+#[test]
+fn malformed_payloads_report_the_line() {
+    let cases = [
+        ("a,b\n1,2,3\n", "line 2"),
+        ("a\n\"open\n", "unterminated"),
+        ("qty\nmany\n", "expects Integer"),
+    ];
+    let parser = CsvParser::new().with_column_type("qty", ColumnType::Integer);
+    for (text, expected) in cases {
+        let error = parser.parse(text).unwrap_err();
+        assert!(matches!(&error, SupplierError::Upstream(m) if m.contains(expected)), "{error}");
+    }
+    assert_eq!(parser.parse("a,b,c\n1\n").unwrap(), json!([{ "a": 1, "b": null, "c": null }]));
+    assert_eq!(parser.parse("").unwrap(), json!([]));
+}
+
+#[test]
+fn csv_bodies_are_parsed_into_data() {
+    let response = SupplierResponse::binary("text/csv; charset=utf-8", b"sku,stock\nA1,4\n".to_vec());
+    let parsed = CsvParser::new().parse_response(response).unwrap();
+    assert_eq!(parsed.data, json!([{ "sku": "A1", "stock": 4 }]));
+    assert!(parsed.body.is_none());
+
+    let tsv = SupplierResponse::binary("text/tab-separated-values", b"sku\tstock\nA1\t4\n".to_vec());
+    assert_eq!(CsvParser::new().parse_response(tsv).unwrap().data[0]["stock"], 4);
+
+    let pdf = SupplierResponse::binary("application/pdf", b"%PDF".to_vec());
+    assert!(CsvParser::new().parse_response(pdf).unwrap().body.is_some());
+
+    let invalid = SupplierResponse::binary("text/csv", vec![0xff, 0xfe]);
+    assert!(matches!(CsvParser::new().parse_response(invalid), Err(SupplierError::Upstream(_))));
+}
@@ -0,0 +1,101 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use serde_json::json;
+use supplier_kit::deprecation::{Deprecation, DeprecationMilestone, DeprecationNotice};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::{Supplier, SupplierRegistry};
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+struct DummySupplier;
+
+impl Supplier for DummySupplier {
+    fn name(&self) -> &str {
+        "legacy"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!({})))
+    }
+}
+
+#[test]
+fn test_weight_ramps_down_toward_sunset() {
+    let now = SystemTime::now();
+    let deprecation = Deprecation::new(now + Duration::from_secs(100), Duration::from_secs(200));
+
+    let weight = deprecation.weight_at(now);
+    assert!((weight - 0.5).abs() < 0.01, "weight was {}", weight);
+    assert_eq!(deprecation.milestone_at(now), DeprecationMilestone::RampingDown);
+    assert_eq!(deprecation.weight_at(now - Duration::from_secs(200)), 1.0);
+    assert_eq!(deprecation.milestone_at(now + Duration::from_secs(101)), DeprecationMilestone::SunsetReached);
+}
+
+#[test]
+fn test_registry_notifies_on_use_and_milestones_once() {
+    let notices = Arc::new(Mutex::new(Vec::new()));
+    let sink = notices.clone();
+
+    let mut registry = SupplierRegistry::new();
+    registry.register("legacy", DummySupplier);
+    registry.on_deprecation(move |notice| sink.lock().unwrap().push(notice.clone()));
+    registry
+        .deprecate("legacy", Deprecation::new(SystemTime::now() - Duration::from_secs(1), Duration::ZERO))
+        .unwrap();
+
+    assert!(registry.get("legacy").is_some());
+    assert!(registry.get("legacy").is_some());
+    assert_eq!(registry.deprecation_weight("legacy"), 0.0);
+
+    let notices = notices.lock().unwrap();
+    let milestones: Vec<_> = notices
+        .iter()
+        .filter_map(|n| match n {
+            DeprecationNotice::MilestoneReached { milestone, .. } => Some(*milestone),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(milestones, [DeprecationMilestone::SunsetReached]);
+    assert_eq!(notices.iter().filter(|n| matches!(n, DeprecationNotice::Used { .. })).count(), 2);
+}
+
+#[test]
+fn test_adaptive_groups_ramp_deprecated_suppliers_down() {
+    let replica = |name| MockSupplierBuilder::new(name).respond_default(json!([])).with_delay(Duration::from_millis(2)).build();
+    let (legacy, current) = (replica("legacy"), replica("current"));
+    let mut registry = SupplierRegistry::new();
+    registry.register("legacy", legacy.clone());
+    // 5% of the ramp is left, so the legacy supplier has a twentieth of the weight.
+    let sunset = SystemTime::now() + Duration::from_secs(50);
+    registry.deprecate("legacy", Deprecation::new(sunset, Duration::from_secs(1000))).unwrap();
+
+    let mut group = BasicSupplierGroup::new("shops")
+        .with_strategy(QueryStrategy::Adaptive)
+        .with_deprecations(Arc::new(registry));
+    group.add_supplier(legacy.clone());
+    group.add_supplier(current.clone());
+    for _ in 0..100 {
+        group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+    }
+
+    assert_eq!(legacy.calls() + current.calls(), 100);
+    assert!(legacy.calls() < 20, "legacy got {} calls", legacy.calls());
+}
+
+#[test]
+fn test_groups_skip_suppliers_past_their_sunset() {
+    let legacy = MockSupplierBuilder::new("legacy").respond_default(json!([])).build();
+    let mut registry = SupplierRegistry::new();
+    registry.register("legacy", legacy.clone());
+    registry.deprecate("legacy", Deprecation::new(SystemTime::now(), Duration::ZERO)).unwrap();
+
+    let mut group = BasicSupplierGroup::new("shops").with_deprecations(Arc::new(registry));
+    group.add_supplier(legacy.clone());
+    group.add_supplier(MockSupplierBuilder::new("current").respond_default(json!([])).build());
+
+    let result = group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+    assert_eq!(result.successes.len(), 1);
+    assert_eq!(result.successes[0].0, "current");
+    assert_eq!(legacy.calls(), 0);
+}
@@ -0,0 +1,113 @@
+use serde_json::json;
+use supplier_kit::decorators::coalescing::CoalescingSupplier;
+use supplier_kit::descriptor::{validate_schema, OperationDescriptor, RateLimit, SupplierDescriptor};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::{Supplier, SupplierRegistry};
+
+struct Plain;
+
+impl Supplier for Plain {
+    fn name(&self) -> &str {
+        "plain"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!([])))
+    }
+}
+
+struct Described;
+
+impl Supplier for Described {
+    fn name(&self) -> &str {
+        "described"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!([])))
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        SupplierDescriptor::new(self.name())
+            .with_version("3.2.1")
+            .with_operation(
+                OperationDescriptor::new(SupplierOperation::Search)
+                    .with_summary("Full-text product search")
+                    .with_params_schema(json!({ "type": "object", "required": ["q"] })),
+            )
+            .with_operation(OperationDescriptor::new(SupplierOperation::GetDetail))
+            .with_rate_limit(RateLimit { max_requests: 50, window_ms: 1000 })
+    }
+}
+
+#[test]
+fn default_descriptor_only_has_the_name() {
+    let descriptor = Plain.describe();
+    assert_eq!(descriptor, SupplierDescriptor::new("plain"));
+    assert!(descriptor.operations.is_empty());
+    assert!(!descriptor.supports(&SupplierOperation::Search));
+}
+
+#[test]
+fn descriptor_serializes_without_empty_optionals() {
+    let value = serde_json::to_value(Described.describe()).unwrap();
+    assert_eq!(value["version"], "3.2.1");
+    assert_eq!(value["operations"][0]["operation"], "search");
+    assert_eq!(value["operations"][0]["params_schema"]["required"][0], "q");
+    assert!(value["operations"][1].get("params_schema").is_none());
+    assert_eq!(value["rate_limits"][0]["max_requests"], 50);
+
+    let back: SupplierDescriptor = serde_json::from_value(value).unwrap();
+    assert_eq!(back, Described.describe());
+}
+
+#[test]
+fn decorators_forward_the_inner_descriptor() {
+    let coalescing = CoalescingSupplier::new(Described);
+    assert_eq!(coalescing.describe(), Described.describe());
+}
+
+#[test]
+fn registry_describes_all_suppliers_by_registered_name() {
+    let mut registry = SupplierRegistry::new();
+    registry.register("zeta", Plain);
+    registry.register("alpha", Described);
+
+    let descriptors = registry.describe_all();
+    assert_eq!(descriptors.keys().collect::<Vec<_>>(), ["alpha", "zeta"]);
+    assert_eq!(descriptors["alpha"].version.as_deref(), Some("3.2.1"));
+    assert!(descriptors["alpha"].supports(&SupplierOperation::GetDetail));
+    assert_eq!(descriptors["zeta"].name, "plain");
+}
+
+#[test]
+fn params_are_validated_against_the_declared_schema() {
+    let schema = json!({
+        "type": "object",
+        "required": ["q"],
+        "additionalProperties": false,
+        "properties": {
+            "q": { "type": "string" },
+            "sort": { "enum": ["price", "rating"] },
+            "page": { "type": "integer", "minimum": 1 },
+            "tags": { "type": "array", "maxItems": 2, "items": { "type": "string" } }
+        }
+    });
+    assert!(validate_schema(&schema, &json!({ "q": "lamp", "sort": "price", "page": 2, "tags": ["a"] })).is_empty());
+    assert_eq!(
+        validate_schema(&schema, &json!({ "sort": "name", "page": 0.5, "tags": ["a", 1, "c"], "colour": "red" })),
+        [
+            "missing required property 'q'",
+            "/colour: unexpected property",
+            "/page: expected integer, found number",
+            "/sort: must be one of [\"price\",\"rating\"]",
+            "/tags: must have at most 2 items",
+            "/tags/1: expected string, found number",
+        ]
+    );
+
+    let operation = OperationDescriptor::new(SupplierOperation::Search).with_params_schema(schema);
+    assert_eq!(operation.validate_params(&json!("lamp")), ["expected object, found string"]);
+    assert!(OperationDescriptor::new(SupplierOperation::Search).validate_params(&json!(null)).is_empty());
+}
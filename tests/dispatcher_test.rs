@@ -0,0 +1,90 @@
+use std::sync::Arc;
+use serde_json::json;
+use supplier_kit::descriptor::OperationDescriptor;
+use supplier_kit::dispatcher::Dispatcher;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::SupplierRegistry;
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn service(name: &str, operations: &[SupplierOperation]) -> MockSupplier {
+    operations
+        .iter()
+        .fold(MockSupplierBuilder::new(name), |builder, operation| {
+            builder.describe_operation(OperationDescriptor::new(operation.clone()))
+        })
+        .respond_default(json!({ "service": name }))
+        .build()
+}
+
+fn request(operation: SupplierOperation) -> SupplierRequest {
+    SupplierRequest::new(operation, json!({}))
+}
+
+#[test]
+fn each_operation_reaches_only_its_owner() {
+    let catalog = service("catalog", &[SupplierOperation::Search, SupplierOperation::GetDetail]);
+    let orders = service("orders", &[SupplierOperation::Create, SupplierOperation::Other("track_order".into())]);
+    let mut registry = SupplierRegistry::new();
+    registry.register("catalog", catalog.clone());
+    registry.register("orders", orders.clone());
+    registry.register("undeclared", service("undeclared", &[]));
+
+    let dispatcher = Dispatcher::from_registry(&registry).unwrap();
+    let response = dispatcher.query(request(SupplierOperation::Other("Track Order".into()))).unwrap();
+    assert_eq!(response.data["service"], "orders");
+    let response = dispatcher.query(request(SupplierOperation::Search)).unwrap();
+    assert_eq!(response.data["service"], "catalog");
+
+    assert_eq!(catalog.calls(), 1);
+    assert_eq!(orders.calls(), 1);
+    assert_eq!(dispatcher.owner(&SupplierOperation::GetDetail), Some("catalog"));
+    assert_eq!(dispatcher.owner(&SupplierOperation::from("track-order")), Some("orders"));
+    assert_eq!(
+        dispatcher.operations(),
+        vec![
+            SupplierOperation::Create,
+            SupplierOperation::GetDetail,
+            SupplierOperation::Search,
+            SupplierOperation::Other("track_order".into()),
+        ]
+    );
+}
+
+#[test]
+fn operations_without_an_owner_are_unsupported() {
+    let dispatcher = Dispatcher::new();
+    let result = dispatcher.query(request(SupplierOperation::Delete));
+    assert!(matches!(result, Err(SupplierError::UnsupportedOperation(message)) if message.contains("delete")));
+}
+
+#[test]
+fn two_owners_for_one_operation_are_rejected() {
+    let mut registry = SupplierRegistry::new();
+    registry.register("orders", service("orders", &[SupplierOperation::Create]));
+    registry.register("returns", service("returns", &[SupplierOperation::Create]));
+
+    let error = Dispatcher::from_registry(&registry).err().unwrap();
+    assert!(matches!(&error, SupplierError::InvalidInput(message)
+        if message.contains("'create'") && message.contains("'orders'") && message.contains("'returns'")));
+}
+
+#[test]
+fn reassigning_the_same_owner_replaces_the_supplier() {
+    let old = service("orders", &[]);
+    let new = service("orders-v2", &[]);
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.assign(SupplierOperation::Create, "orders", Arc::new(old.clone())).unwrap();
+    dispatcher.assign(SupplierOperation::Create, "orders", Arc::new(new.clone())).unwrap();
+
+    dispatcher.query(request(SupplierOperation::Create)).unwrap();
+    assert_eq!((old.calls(), new.calls()), (0, 1));
+}
+
+#[test]
+fn owner_errors_are_returned_unchanged() {
+    let failing = MockSupplierBuilder::new("orders").then_fail(SupplierError::Timeout).build();
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.assign(SupplierOperation::Create, "orders", Arc::new(failing)).unwrap();
+    assert!(matches!(dispatcher.query(request(SupplierOperation::Create)), Err(SupplierError::Timeout)));
+}
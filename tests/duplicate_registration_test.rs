@@ -0,0 +1,66 @@
+use std::sync::Arc;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::supplier::{DuplicatePolicy, RegistrationOutcome, Supplier, SupplierRegistry};
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn shop(tag: &str) -> MockSupplier {
+    MockSupplierBuilder::new("shop").respond_default(json!({ "tag": tag })).build()
+}
+
+fn versioned(version: &str) -> MockSupplier {
+    MockSupplierBuilder::new("shop").with_version(version).build()
+}
+
+fn registered_version(registry: &SupplierRegistry) -> Option<String> {
+    registry.get("shop").unwrap().describe().version
+}
+
+#[test]
+fn register_reports_overwrites_by_default() {
+    let mut registry = SupplierRegistry::new();
+    assert_eq!(registry.duplicate_policy(), DuplicatePolicy::Overwrite);
+    assert_eq!(registry.register("shop", shop("first")), RegistrationOutcome::Added);
+    assert_eq!(registry.register("shop", shop("second")), RegistrationOutcome::Replaced);
+    assert_eq!(registry.all_names(), ["shop"]);
+}
+
+#[test]
+fn reject_policy_keeps_the_first_supplier() {
+    let mut registry = SupplierRegistry::new();
+    registry.set_duplicate_policy(DuplicatePolicy::Reject);
+    let events = registry.events().subscribe_channel();
+    registry.register("shop", versioned("1.0"));
+
+    let outcome = registry.register("shop", versioned("2.0"));
+    assert!(matches!(outcome, RegistrationOutcome::Rejected(reason) if reason.contains("'shop'")));
+    assert_eq!(registered_version(&registry).as_deref(), Some("1.0"));
+    assert_eq!(events.try_iter().count(), 1);
+
+    let report = registry.register_all(vec![("shop".to_string(), Arc::new(shop("again")) as Arc<dyn Supplier>)]);
+    assert_eq!(report.rejected().len(), 1);
+}
+
+#[test]
+fn version_policy_only_accepts_newer_versions() {
+    let mut registry = SupplierRegistry::new();
+    registry.set_duplicate_policy(DuplicatePolicy::Version);
+    registry.register("shop", versioned("1.9"));
+
+    assert_eq!(registry.register("shop", versioned("1.10")), RegistrationOutcome::Replaced);
+    let outcome = registry.register("shop", versioned("1.10.0"));
+    assert!(matches!(outcome, RegistrationOutcome::Rejected(reason) if reason.contains("1.10.0") && reason.contains("1.10")));
+    assert!(matches!(registry.register("shop", shop("unversioned")), RegistrationOutcome::Rejected(_)));
+    assert_eq!(registered_version(&registry).as_deref(), Some("1.10"));
+}
+
+#[test]
+fn try_register_fails_for_taken_names_and_aliases() {
+    let mut registry = SupplierRegistry::new();
+    registry.try_register("shop", shop("first")).unwrap();
+    registry.alias("store", "shop").unwrap();
+
+    assert!(matches!(registry.try_register("shop", shop("second")), Err(SupplierError::AlreadyExists(name)) if name == "shop"));
+    assert!(matches!(registry.try_register("store", shop("second")), Err(SupplierError::AlreadyExists(_))));
+    assert_eq!(SupplierError::AlreadyExists("shop".into()).code(), "already_exists");
+}
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+use std::thread::sleep;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::events::{EventBus, SupplierEvent};
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::outlier::{ErrorEjector, OutlierDetector};
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }))
+}
+
+fn throttled() -> SupplierError {
+    SupplierError::Throttled { retry_after: None }
+}
+
+fn failing(name: &str, error: SupplierError, times: usize) -> MockSupplier {
+    (0..times)
+        .fold(MockSupplierBuilder::new(name), |builder, _| builder.then_fail(error.clone()))
+        .respond_default(json!([]))
+        .build()
+}
+
+#[test]
+fn unauthorized_ejects_at_once_and_throttling_after_a_streak() {
+    let ejector = ErrorEjector::new(Duration::from_secs(10), Duration::from_secs(60));
+
+    ejector.record("locked", Some(&SupplierError::Unauthorized));
+    assert!(ejector.is_ejected("locked"));
+
+    for _ in 0..2 {
+        ejector.record("busy", Some(&throttled()));
+    }
+    assert!(!ejector.is_ejected("busy"));
+    ejector.record("busy", Some(&throttled()));
+    assert!(ejector.is_ejected("busy"));
+    assert_eq!(ejector.ejected(), ["busy", "locked"]);
+
+    // Generic failures are left to the outlier detector; successes break a streak.
+    for _ in 0..5 {
+        ejector.record("flaky", Some(&SupplierError::Timeout));
+    }
+    ejector.record("calm", Some(&throttled()));
+    ejector.record("calm", Some(&throttled()));
+    ejector.record("calm", None);
+    ejector.record("calm", Some(&throttled()));
+    assert!(!ejector.is_ejected("flaky"));
+    assert!(!ejector.is_ejected("calm"));
+    assert_eq!(ejector.ejections("flaky"), 0);
+}
+
+#[test]
+fn windows_double_until_a_success_resets_them() {
+    let ejector = ErrorEjector::new(Duration::from_millis(40), Duration::from_millis(100));
+    let eject = || {
+        ejector.record("locked", Some(&SupplierError::Unauthorized));
+        ejector.ejected_for("locked").unwrap()
+    };
+
+    assert!(eject() <= Duration::from_millis(40));
+    // Errors reported while ejected, e.g. by targeted calls, do not extend the window.
+    ejector.record("locked", Some(&SupplierError::Unauthorized));
+    assert_eq!(ejector.ejections("locked"), 1);
+
+    sleep(Duration::from_millis(50));
+    assert!(!ejector.is_ejected("locked"));
+    assert!(eject() > Duration::from_millis(40));
+
+    sleep(Duration::from_millis(90));
+    let capped = eject();
+    assert!(capped > Duration::from_millis(80) && capped <= Duration::from_millis(100), "{:?}", capped);
+    assert_eq!(ejector.ejections("locked"), 3);
+
+    ejector.readmit("locked");
+    ejector.record("locked", None);
+    assert!(eject() <= Duration::from_millis(40));
+    assert_eq!(ejector.ejections("locked"), 4);
+}
+
+#[test]
+fn throttled_suppliers_stay_out_for_their_retry_after() {
+    let ejector = ErrorEjector::new(Duration::from_secs(1), Duration::from_secs(5)).with_threshold("throttled", 1);
+    ejector.record("busy", Some(&SupplierError::Throttled { retry_after: Some(Duration::from_secs(120)) }));
+    assert!(ejector.ejected_for("busy").unwrap() > Duration::from_secs(100));
+}
+
+#[test]
+fn thresholds_are_configurable_per_error_code() {
+    let ejector = ErrorEjector::new(Duration::from_secs(10), Duration::from_secs(60))
+        .with_threshold("unauthorized", 0)
+        .with_threshold("rate_limited", 2);
+
+    ejector.record("locked", Some(&SupplierError::Unauthorized));
+    assert!(!ejector.is_ejected("locked"));
+
+    ejector.record("quota", Some(&SupplierError::RateLimited("monthly".into())));
+    ejector.record("quota", Some(&SupplierError::RateLimited("monthly".into())));
+    assert!(ejector.is_ejected("quota"));
+}
+
+#[test]
+fn groups_skip_ejected_suppliers_and_publish_ejections() {
+    for strategy in [QueryStrategy::Sequential, QueryStrategy::Parallel] {
+        let ejector = Arc::new(ErrorEjector::new(Duration::from_secs(60), Duration::from_secs(600)));
+        let bus = EventBus::new();
+        let events = bus.subscribe_channel();
+        let locked = failing("locked", SupplierError::Unauthorized, 10);
+        let busy = failing("busy", throttled(), 10);
+        let mut group = BasicSupplierGroup::new("marketplaces")
+            .with_strategy(strategy)
+            .with_error_ejection(ejector.clone())
+            .with_outlier_detection(Arc::new(OutlierDetector::new(0.99, Duration::from_secs(60))))
+            .with_event_bus(bus);
+        group.add_supplier(MockSupplierBuilder::new("steady").respond_default(json!([])).build());
+        group.add_supplier(locked.clone());
+        group.add_supplier(busy.clone());
+
+        for _ in 0..5 {
+            group.query(search());
+        }
+        assert_eq!((locked.calls(), busy.calls()), (1, 3));
+        assert_eq!(ejector.ejected(), ["busy", "locked"]);
+
+        let result = group.query(search());
+        assert_eq!(result.successes.len(), 1);
+        assert!(result.failures.is_empty());
+
+        let plan = group.plan(&search());
+        let reason = plan.iter().find(|entry| entry.name == "locked").and_then(|entry| entry.skip_reason.clone());
+        assert_eq!(reason.as_deref(), Some("ejected after unauthorized or throttled calls"));
+
+        let ejected: Vec<String> = events
+            .try_iter()
+            .filter_map(|event| match event {
+                SupplierEvent::Ejected { supplier, ejections: 1, .. } => Some(supplier),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(ejected, ["locked", "busy"]);
+    }
+}
@@ -0,0 +1,63 @@
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier_group::{BasicSupplierGroup, ErrorSummary, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn failures(errors: Vec<SupplierError>) -> Vec<(String, SupplierError)> {
+    errors.into_iter().enumerate().map(|(index, error)| (format!("shop_{}", index), error)).collect()
+}
+
+#[test]
+fn failures_are_bucketed_by_code_and_pattern() {
+    let summary = ErrorSummary::from_failures(&failures(vec![
+        SupplierError::Timeout,
+        SupplierError::Upstream("HTTP 502 after 1200ms".into()),
+        SupplierError::Timeout,
+        SupplierError::Upstream("HTTP 503 after 87ms".into()),
+        SupplierError::Internal("supplier 'shop_4' panicked".into()),
+        SupplierError::Internal("supplier \"shop_5\" panicked".into()),
+        SupplierError::Upstream("connection refused".into()),
+        SupplierError::Throttled { retry_after: Some(Duration::from_secs(3)) },
+    ]));
+
+    assert_eq!(summary.total, 8);
+    assert_eq!(
+        summary.to_string(),
+        "2× internal error: supplier '*' panicked, 2× timeout, 2× upstream error: HTTP # after #ms, \
+         1× throttled: retry after #s, 1× upstream error: connection refused"
+    );
+    assert_eq!(summary.buckets[2].code, "upstream");
+    assert_eq!(summary.buckets[2].suppliers, ["shop_1", "shop_3"]);
+}
+
+#[test]
+fn quotes_span_to_the_next_matching_quote() {
+    let summary = ErrorSummary::from_failures(&failures(vec![SupplierError::InvalidInput("can't parse field 'q".into())]));
+    assert_eq!(summary.to_string(), "1× invalid input: can'*'q");
+
+    let summary = ErrorSummary::from_failures(&failures(vec![SupplierError::InvalidInput("it's 12".into())]));
+    assert_eq!(summary.buckets[0].pattern, "invalid input: it's #");
+}
+
+#[test]
+fn group_results_summarize_their_failures() {
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    for name in ["a", "b"] {
+        group.add_supplier(MockSupplierBuilder::new(name).then_fail(SupplierError::Unauthorized).build());
+    }
+    group.add_supplier(MockSupplierBuilder::new("c").respond_default(json!([])).build());
+
+    let result = group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+    let summary = result.error_summary();
+    assert_eq!(summary.to_string(), "2× unauthorized");
+    assert_eq!(
+        serde_json::to_value(&summary).unwrap(),
+        json!({ "total": 2, "buckets": [{ "code": "unauthorized", "pattern": "unauthorized", "count": 2, "suppliers": ["a", "b"] }] })
+    );
+
+    let empty = ErrorSummary::from_failures(&[]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.to_string(), "no errors");
+}
@@ -0,0 +1,126 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::events::{EventBus, SupplierEvent};
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::outlier::OutlierDetector;
+use supplier_kit::supplier::{Supplier, SupplierRegistry};
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }))
+}
+
+fn names(events: &[SupplierEvent]) -> Vec<String> {
+    events
+        .iter()
+        .map(|event| match event {
+            SupplierEvent::Registered { supplier } => format!("registered {supplier}"),
+            SupplierEvent::Removed { supplier } => format!("removed {supplier}"),
+            SupplierEvent::QueryStarted { supplier, .. } => format!("started {supplier}"),
+            SupplierEvent::QuerySucceeded { supplier, .. } => format!("succeeded {supplier}"),
+            SupplierEvent::QueryFailed { supplier, .. } => format!("failed {supplier}"),
+            SupplierEvent::CredentialsRotated { supplier, .. } => format!("rotated {supplier}"),
+            SupplierEvent::QuotaSoftLimitReached { supplier, .. } => format!("quota {supplier}"),
+            SupplierEvent::CircuitOpened { supplier, .. } => format!("circuit {supplier}"),
+            SupplierEvent::Ejected { supplier, .. } => format!("ejected {supplier}"),
+            SupplierEvent::HealthChanged { supplier, healthy, .. } => format!("health {supplier} {healthy}"),
+        })
+        .collect()
+}
+
+#[test]
+fn registry_publishes_topology_changes_and_queries() {
+    let bus = EventBus::new();
+    let events = bus.subscribe_channel();
+    let mut registry = SupplierRegistry::new();
+    registry.set_event_bus(bus);
+
+    registry.register("shop", MockSupplierBuilder::new("shop").respond_default(json!({})).build());
+    let report = registry.register_all(vec![
+        ("".to_string(), Arc::new(MockSupplierBuilder::new("x").build()) as Arc<dyn Supplier>),
+        ("shop".to_string(), Arc::new(MockSupplierBuilder::new("shop").build()) as Arc<dyn Supplier>),
+    ]);
+    assert_eq!(report.replaced(), vec!["shop"]);
+    registry.query("shop", search()).unwrap_err();
+    assert!(registry.remove("shop").is_some());
+    assert!(registry.remove("shop").is_none());
+
+    let received: Vec<SupplierEvent> = events.try_iter().collect();
+    assert_eq!(
+        names(&received),
+        vec!["registered shop", "registered shop", "started shop", "failed shop", "removed shop"]
+    );
+    assert!(matches!(&received[3], SupplierEvent::QueryFailed { group: None, operation, .. } if operation == "search"));
+}
+
+#[test]
+fn groups_publish_query_outcomes_with_their_name() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let recorded = seen.clone();
+
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.events().subscribe(move |event| recorded.lock().unwrap().push(event.clone()));
+    group.add_supplier(MockSupplierBuilder::new("ok").respond_default(json!({})).build());
+    group.add_supplier(MockSupplierBuilder::new("down").then_fail(SupplierError::Timeout).build());
+    group.query(search());
+
+    let events = seen.lock().unwrap();
+    assert_eq!(names(&events), vec!["started ok", "succeeded ok", "started down", "failed down"]);
+    assert!(events.iter().all(|event| match event {
+        SupplierEvent::QueryStarted { group, .. }
+        | SupplierEvent::QuerySucceeded { group, .. }
+        | SupplierEvent::QueryFailed { group, .. } => group.as_deref() == Some("marketplaces"),
+        _ => false,
+    }));
+}
+
+#[test]
+fn outlier_ejections_are_published_once() {
+    let detector = Arc::new(OutlierDetector::new(0.5, Duration::from_secs(60)).with_min_samples(2));
+    let bus = EventBus::new();
+    let events = bus.subscribe_channel();
+
+    let mut group = BasicSupplierGroup::new("g")
+        .with_outlier_detection(detector)
+        .with_event_bus(bus);
+    let mut flaky = MockSupplierBuilder::new("flaky");
+    for _ in 0..5 {
+        flaky = flaky.then_fail(SupplierError::Timeout);
+    }
+    group.add_supplier(flaky.build());
+    for _ in 0..5 {
+        group.query(search());
+    }
+
+    let ejections: Vec<SupplierEvent> = events
+        .try_iter()
+        .filter(|e| matches!(e, SupplierEvent::Ejected { .. }))
+        .collect();
+    assert_eq!(ejections.len(), 1);
+    assert!(matches!(
+        &ejections[0],
+        SupplierEvent::Ejected { group, supplier, ejections: 1 } if group == "g" && supplier == "flaky"
+    ));
+}
+
+#[test]
+fn subscriptions_can_end() {
+    let bus = EventBus::new();
+    let count = Arc::new(Mutex::new(0));
+    let counter = count.clone();
+    let id = bus.subscribe(move |_| *counter.lock().unwrap() += 1);
+    let receiver = bus.subscribe_channel();
+
+    let circuit = || SupplierEvent::CircuitOpened { supplier: "s".into(), reason: "5 failures".into() };
+    bus.publish(circuit());
+    assert!(bus.unsubscribe(id));
+    assert!(!bus.unsubscribe(id));
+    drop(receiver);
+    bus.publish(circuit());
+
+    assert_eq!(*count.lock().unwrap(), 1);
+    assert!(!bus.has_subscribers());
+}
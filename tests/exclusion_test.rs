@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use serde_json::json;
+use supplier_kit::context::RequestContext;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::requirements::{SupplierRequirement, SupplierRequirements};
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn search(excluded: &[&str]) -> SupplierRequest {
+    let context = excluded.iter().fold(RequestContext::new(), |context, name| context.with_excluded_supplier(name));
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" })).with_context(context)
+}
+
+fn marketplace(strategy: QueryStrategy) -> (BasicSupplierGroup, Vec<MockSupplier>) {
+    let mut group = BasicSupplierGroup::new("marketplace").with_strategy(strategy);
+    let stores: Vec<MockSupplier> = ["amazon", "ebay", "etsy"]
+        .into_iter()
+        .map(|name| MockSupplierBuilder::new(name).respond_default(json!([])).build())
+        .collect();
+    for store in &stores {
+        group.add_supplier(store.clone());
+    }
+    (group, stores)
+}
+
+fn names<T>(entries: &[(String, T)]) -> Vec<&str> {
+    entries.iter().map(|(name, _)| name.as_str()).collect()
+}
+
+#[test]
+fn contexts_serialize_their_exclusions() {
+    let context = RequestContext::new().with_excluded_supplier("etsy").with_excluded_supplier("amazon");
+    assert_eq!(serde_json::to_value(&context).unwrap(), json!({ "excluded_suppliers": ["amazon", "etsy"] }));
+    assert_eq!(serde_json::to_value(RequestContext::new()).unwrap(), json!({}));
+
+    let decoded: RequestContext = serde_json::from_value(json!({ "excluded_suppliers": ["ebay"] })).unwrap();
+    assert!(decoded.excludes("ebay"));
+}
+
+#[test]
+fn fan_out_skips_excluded_suppliers() {
+    for strategy in [QueryStrategy::Sequential, QueryStrategy::Parallel, QueryStrategy::Race, QueryStrategy::Failover] {
+        let (group, stores) = marketplace(strategy);
+        let result = group.query(search(&["amazon"]));
+        assert!(!names(&result.successes).contains(&"amazon"), "{:?}", strategy);
+        assert_eq!(stores[0].calls(), 0);
+
+        let streamed: Vec<String> = group.query_streamed(search(&["amazon", "ebay"])).map(|(name, _)| name).collect();
+        assert_eq!(streamed, ["etsy"]);
+    }
+
+    let (group, _) = marketplace(QueryStrategy::Parallel);
+    let plan = group.plan(&search(&["ebay"]));
+    assert_eq!(plan[1].skip_reason.as_deref(), Some("excluded by the request context"));
+
+    let results = group.query_batch(vec![search(&["ebay"]), search(&[])]);
+    assert_eq!(names(&results[0].successes), ["amazon", "etsy"]);
+    assert_eq!(results[1].successes.len(), 3);
+
+    let subset = group.query_subset(search(&["ebay"]), &["ebay", "etsy"]);
+    assert_eq!(names(&subset.successes), ["etsy"]);
+
+    let transformed = group.query_transformed(search(&["etsy"]), |_, response| Ok(response.data));
+    assert_eq!(names(&transformed.successes), ["amazon", "ebay"]);
+}
+
+#[test]
+fn exclusions_count_against_requirements_but_not_targeted_requests() {
+    let (group, _) = marketplace(QueryStrategy::Sequential);
+    let group = group.with_requirements(
+        SupplierRequirements::new().with_requirement(SupplierOperation::Search, SupplierRequirement::any_of(&["amazon", "ebay"])),
+    );
+    assert!(group.check_requirements(&search(&["amazon"])).is_ok());
+    let error = group.check_requirements(&search(&["amazon", "ebay"])).unwrap_err();
+    assert!(error.to_string().contains("amazon: excluded by the request context"), "{}", error);
+
+    let each = group.query_each(HashMap::from([("amazon".to_string(), search(&["amazon"]))]));
+    assert_eq!(names(&each.successes), ["amazon"]);
+}
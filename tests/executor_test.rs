@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ThreadId};
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::executor::{BoundedExecutor, Executor, Task, ThreadExecutor};
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+/// Tracks how many calls run at once and on which threads.
+#[derive(Default)]
+struct Tracker {
+    calls: AtomicUsize,
+    running: AtomicUsize,
+    peak: AtomicUsize,
+    threads: Mutex<HashSet<ThreadId>>,
+}
+
+struct Slow {
+    name: String,
+    tracker: Arc<Tracker>,
+}
+
+impl Supplier for Slow {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        self.tracker.calls.fetch_add(1, Ordering::SeqCst);
+        let running = self.tracker.running.fetch_add(1, Ordering::SeqCst) + 1;
+        self.tracker.peak.fetch_max(running, Ordering::SeqCst);
+        self.tracker.threads.lock().unwrap().insert(thread::current().id());
+        thread::sleep(Duration::from_millis(20));
+        self.tracker.running.fetch_sub(1, Ordering::SeqCst);
+        Ok(SupplierResponse::new(json!([])))
+    }
+}
+
+fn group(strategy: QueryStrategy, executor: Arc<dyn Executor>, tracker: &Arc<Tracker>) -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new("marketplaces").with_strategy(strategy).with_executor(executor);
+    for index in 0..8 {
+        group.add_supplier(Slow {
+            name: format!("s{}", index),
+            tracker: tracker.clone(),
+        });
+    }
+    group
+}
+
+#[test]
+fn bounded_executor_caps_the_threads_of_a_parallel_query() {
+    let tracker = Arc::new(Tracker::default());
+    let executor = Arc::new(BoundedExecutor::new(2));
+    let group = group(QueryStrategy::Parallel, executor.clone(), &tracker);
+
+    let result = group.query(search());
+    assert_eq!(result.successes.len(), 8);
+    // Two executor threads plus the calling thread, which runs work when both are busy.
+    assert!(tracker.peak.load(Ordering::SeqCst) <= 3);
+    assert!(tracker.threads.lock().unwrap().len() <= 3);
+    assert_eq!(executor.active_threads(), 0);
+    assert_eq!(executor.max_threads(), 2);
+}
+
+#[test]
+fn executor_without_threads_runs_everything_on_the_caller() {
+    let tracker = Arc::new(Tracker::default());
+    let group = group(QueryStrategy::Race, Arc::new(BoundedExecutor::new(0)), &tracker);
+
+    let result = group.query(search());
+    assert_eq!(result.successes.len(), 1);
+    // The first call wins the race, so no other supplier is called.
+    assert_eq!(tracker.calls.load(Ordering::SeqCst), 1);
+    assert_eq!(tracker.peak.load(Ordering::SeqCst), 1);
+    assert_eq!(*tracker.threads.lock().unwrap(), HashSet::from([thread::current().id()]));
+}
+
+#[test]
+fn streamed_and_batched_queries_use_the_executor() {
+    let tracker = Arc::new(Tracker::default());
+    let group = group(QueryStrategy::Parallel, Arc::new(BoundedExecutor::new(0)), &tracker);
+
+    let mut stream = group.query_streamed(search());
+    assert!(stream.next().is_some());
+    assert_eq!(tracker.calls.load(Ordering::SeqCst), 1);
+    assert_eq!(stream.count(), 7);
+    assert_eq!(tracker.calls.load(Ordering::SeqCst), 8);
+    let results = group.query_batch(vec![search(), search()]);
+    assert!(results.iter().all(|result| result.successes.len() == 8));
+    assert_eq!(*tracker.threads.lock().unwrap(), HashSet::from([thread::current().id()]));
+}
+
+#[test]
+fn thread_executor_queries_suppliers_concurrently() {
+    let tracker = Arc::new(Tracker::default());
+    let group = group(QueryStrategy::Parallel, Arc::new(ThreadExecutor), &tracker);
+
+    assert_eq!(group.query(search()).successes.len(), 8);
+    assert!(tracker.peak.load(Ordering::SeqCst) > 1);
+}
+
+#[test]
+fn bounded_executor_spawns_while_threads_are_free() {
+    let executor = BoundedExecutor::new(1);
+    let (tx, rx) = std::sync::mpsc::channel();
+    let release = Arc::new(Mutex::new(()));
+    let held = release.lock().unwrap();
+
+    let blocker = release.clone();
+    let first = tx.clone();
+    executor.spawn(Box::new(move || {
+        drop(blocker.lock());
+        first.send(thread::current().id()).unwrap();
+    }));
+    assert_eq!(executor.active_threads(), 1);
+
+    // The only thread is busy, so the second task runs right here.
+    let task: Task<'static> = Box::new(move || tx.send(thread::current().id()).unwrap());
+    executor.spawn(task);
+    assert_eq!(rx.recv().unwrap(), thread::current().id());
+
+    drop(held);
+    assert_ne!(rx.recv().unwrap(), thread::current().id());
+}
@@ -0,0 +1,52 @@
+use std::time::{Duration, Instant};
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+use supplier_kit::testing::assertions::{assert_failed_with, assert_succeeded};
+use supplier_kit::testing::fixtures::FixtureLoader;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/marketplace");
+
+fn laptop_search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "laptop" }))
+}
+
+#[test]
+fn fixtures_load_into_a_registry() {
+    let registry = FixtureLoader::new(FIXTURES).load_registry().unwrap();
+    let mut names = registry.all_names();
+    names.sort();
+    assert_eq!(names, vec!["shop_a", "shop_b", "shop_c"]);
+
+    let detail = SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": 404 }));
+    assert!(matches!(registry.query("shop_a", detail), Err(SupplierError::NotFound)));
+}
+
+#[test]
+fn fixtures_drive_end_to_end_aggregation() {
+    let mut group = BasicSupplierGroup::new("marketplace");
+    for supplier in FixtureLoader::new(FIXTURES).load().unwrap() {
+        group.add_supplier(supplier);
+    }
+
+    let result = group.query(laptop_search());
+    assert_succeeded(&result, &["shop_a", "shop_b"]);
+    assert_failed_with(&result, "shop_c", "upstream");
+}
+
+#[test]
+fn recorded_latency_is_simulated() {
+    let suppliers = FixtureLoader::new(FIXTURES).with_latency_scale(1.0).load().unwrap();
+    let shop_a = suppliers.iter().find(|s| s.name() == "shop_a").unwrap();
+
+    let started = Instant::now();
+    shop_a.query(laptop_search()).unwrap();
+    assert!(started.elapsed() >= Duration::from_millis(30));
+}
+
+#[test]
+fn missing_directory_is_an_error() {
+    assert!(FixtureLoader::new("does/not/exist").load().is_err());
+}
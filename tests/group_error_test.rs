@@ -0,0 +1,83 @@
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::errors::{SupplierError, SupplierGroupError};
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::routing::{RoutingRule, RoutingRules, Test};
+use supplier_kit::supplier_group::{BasicSupplierGroup, PartialFailurePolicy, SupplierGroup, SupplierGroupResult};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }))
+}
+
+fn group(policy: PartialFailurePolicy, failing: usize, succeeding: usize) -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new("marketplaces").with_failure_policy(policy);
+    for index in 0..failing {
+        let name = format!("down_{}", index);
+        group.add_supplier(MockSupplierBuilder::new(&name).then_fail(SupplierError::Timeout).build());
+    }
+    for index in 0..succeeding {
+        let name = format!("up_{}", index);
+        group.add_supplier(MockSupplierBuilder::new(&name).respond_default(json!([])).build());
+    }
+    group
+}
+
+#[test]
+fn empty_fan_outs_report_no_suppliers() {
+    let error = group(PartialFailurePolicy::FailOnAny, 0, 0).query(search()).into_result().unwrap_err();
+    assert!(matches!(&error, SupplierGroupError::NoSuppliers { group } if group == "marketplaces"));
+    assert!(error.failures().is_empty());
+
+    let rules = RoutingRules::new(vec![RoutingRule::to(&["elsewhere"]).when("$.q", Test::Equals(json!("lamp")))]);
+    let routed_away = group(PartialFailurePolicy::Quorum(1), 0, 2).with_routing(rules);
+    assert_eq!(routed_away.query(search()).error.unwrap().code(), "no_suppliers");
+
+    assert!(group(PartialFailurePolicy::Tolerate, 0, 0).query(search()).into_result().is_ok());
+}
+
+#[test]
+fn policies_report_all_failed_or_quorum_not_met() {
+    let error = group(PartialFailurePolicy::Quorum(1), 2, 0).query(search()).error.unwrap();
+    assert!(matches!(&error, SupplierGroupError::AllFailed { failures, .. } if failures.len() == 2));
+    assert!(matches!(error.supplier_error("down_1"), Some(SupplierError::Timeout)));
+    assert_eq!(error.group(), "marketplaces");
+
+    let error = group(PartialFailurePolicy::Quorum(2), 2, 1).query(search()).error.unwrap();
+    assert!(matches!(error, SupplierGroupError::QuorumNotMet { required: 2, succeeded: 1, .. }));
+    assert_eq!(error.to_string(), "group 'marketplaces' needed 2 successful suppliers but got 1");
+
+    assert!(group(PartialFailurePolicy::Quorum(2), 1, 2).query(search()).error.is_none());
+    assert!(group(PartialFailurePolicy::FailOnCritical, 2, 0).query(search()).error.is_none());
+}
+
+#[test]
+fn slow_queries_exceed_the_deadline_under_any_policy() {
+    let mut slow = BasicSupplierGroup::new("marketplaces").with_deadline(Duration::from_millis(20));
+    slow.add_supplier(
+        MockSupplierBuilder::new("sluggish")
+            .with_delay(Duration::from_millis(40))
+            .respond_default(json!([]))
+            .build(),
+    );
+    assert_eq!(slow.deadline(), Some(Duration::from_millis(20)));
+
+    let result = slow.query(search());
+    assert_eq!(result.successes.len(), 1);
+    let error = result.into_result().unwrap_err();
+    assert!(matches!(error, SupplierGroupError::DeadlineExceeded { deadline_ms: 20, elapsed_ms, .. } if elapsed_ms >= 40));
+    assert!(error.to_string().ends_with("exceeding its 20ms deadline"));
+}
+
+#[test]
+fn group_errors_serialize_with_their_failures() {
+    let result = group(PartialFailurePolicy::FailOnAny, 1, 0).query(search());
+    let value = serde_json::to_value(&result).unwrap();
+    assert_eq!(
+        value["error"],
+        json!({ "code": "all_failed", "group": "marketplaces", "failures": [["down_0", { "code": "timeout" }]] })
+    );
+
+    let decoded: SupplierGroupResult = serde_json::from_value(value).unwrap();
+    assert_eq!(decoded.error.unwrap().code(), "all_failed");
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+use std::thread;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::group_registry::GroupRegistry;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::RegistrationOutcome;
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+fn group(name: &str, suppliers: &[&str]) -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new(name);
+    for supplier in suppliers {
+        group.add_supplier(MockSupplierBuilder::new(supplier).respond_default(json!({ "shop": supplier })).build());
+    }
+    group
+}
+
+#[test]
+fn groups_are_found_and_queried_by_name() {
+    let mut groups = GroupRegistry::new();
+    assert_eq!(groups.register("marketplaces", group("marketplaces", &["a", "b"])), RegistrationOutcome::Added);
+    groups.register("payments", group("payments", &["stripe"]));
+
+    assert_eq!(groups.all_names(), ["marketplaces", "payments"]);
+    assert_eq!(groups.get("payments").unwrap().group_name(), "payments");
+    assert_eq!(groups.query("marketplaces", request()).unwrap().successes.len(), 2);
+    assert!(matches!(groups.query("missing", request()), Err(SupplierError::NotFound)));
+}
+
+#[test]
+fn duplicate_names_are_reported() {
+    let mut groups = GroupRegistry::new();
+    groups.register("payments", group("payments", &["stripe"]));
+    assert_eq!(groups.register("payments", group("payments", &["adyen"])), RegistrationOutcome::Replaced);
+
+    let again = groups.try_register("payments", group("payments", &[]));
+    assert!(matches!(again, Err(SupplierError::AlreadyExists(name)) if name == "payments"));
+    assert!(groups.remove("payments").is_some());
+    assert!(!groups.contains("payments"));
+}
+
+#[test]
+fn groups_describe_their_members_in_query_order() {
+    let mut payments = group("payments", &["adyen"]).with_strategy(QueryStrategy::Failover);
+    payments.add_supplier_with_priority(MockSupplierBuilder::new("stripe").build(), 1);
+    let mut groups = GroupRegistry::new();
+    groups.register("payments_v2", payments);
+
+    let described = groups.describe_all();
+    let descriptor = &described["payments_v2"];
+    assert_eq!(descriptor.name, "payments");
+    assert_eq!(descriptor.strategy, Some(QueryStrategy::Failover));
+    assert_eq!(descriptor.suppliers, ["stripe", "adyen"]);
+    assert_eq!(serde_json::to_value(descriptor).unwrap()["strategy"], "failover");
+}
+
+#[test]
+fn shared_groups_serve_concurrent_lookups() {
+    let mut groups = GroupRegistry::new();
+    groups.register("marketplaces", group("marketplaces", &["a"]));
+    let groups = Arc::new(groups);
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let groups = groups.clone();
+            thread::spawn(move || groups.query("marketplaces", request()).unwrap().successes.len())
+        })
+        .collect();
+    assert!(handles.into_iter().all(|handle| handle.join().unwrap() == 1));
+}
@@ -0,0 +1,58 @@
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier_group::{BasicSupplierGroup, GroupQueryReport, QueryStrategy, SupplierGroup, SupplierGroupResult};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+fn marketplaces() -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new("marketplaces").with_strategy(QueryStrategy::Parallel);
+    group.add_supplier(MockSupplierBuilder::new("fast").respond_default(json!([])).build());
+    group.add_supplier(MockSupplierBuilder::new("slow").with_delay(Duration::from_millis(40)).respond_default(json!([])).build());
+    group.add_supplier(MockSupplierBuilder::new("down").then_fail(SupplierError::Upstream("502".into())).build());
+    group.add_supplier(MockSupplierBuilder::new("late").then_fail(SupplierError::Timeout).build());
+    group.add_supplier(MockSupplierBuilder::new("gone").then_fail(SupplierError::Upstream("503".into())).build());
+    group
+}
+
+#[test]
+fn reports_summarize_a_fan_out() {
+    let result = marketplaces().query(request());
+    let report = result.report();
+
+    assert_eq!((report.successes, report.failures), (2, 3));
+    assert_eq!(report.success_ratio, 0.4);
+    assert_eq!(report.slowest.as_ref().unwrap().supplier, "slow");
+    assert_ne!(report.fastest.as_ref().unwrap().supplier, "slow");
+    assert_eq!(report.errors.get("upstream"), Some(&2));
+    assert_eq!(report.errors.get("timeout"), Some(&1));
+    assert!(report.duration() >= Duration::from_millis(40));
+    assert_eq!(report.duration_us, result.duration_us);
+}
+
+#[test]
+fn retries_extend_the_reported_duration() {
+    let group = marketplaces();
+    let mut result = group.query(request());
+    let before = result.duration_us;
+    assert_eq!(result.retry_failures(&group, request()), 0);
+    assert!(result.duration_us >= before);
+    // The failing mocks have no response left, so the retry reports their latest error.
+    assert_eq!(result.report().errors.into_iter().collect::<Vec<_>>(), [("unsupported_operation".to_string(), 3)]);
+}
+
+#[test]
+fn empty_results_have_an_empty_report() {
+    let report = SupplierGroupResult::default().report();
+    assert_eq!(report.success_ratio, 0.0);
+    assert!(report.fastest.is_none() && report.slowest.is_none());
+    assert!(report.errors.is_empty());
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert!(json.get("fastest").is_none());
+    assert_eq!(serde_json::from_value::<GroupQueryReport>(json).unwrap(), report);
+}
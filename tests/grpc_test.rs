@@ -0,0 +1,203 @@
+#![cfg(feature = "grpc")]
+
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::mpsc;
+use std::task::{Context, Poll};
+use std::thread;
+use std::time::Duration;
+use prost_reflect::{DescriptorPool, DynamicMessage, Value as ProtoValue};
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::grpc::{DynamicCodec, GrpcSupplier};
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::Supplier;
+use tonic::body::Body;
+use tonic::codegen::http;
+use tonic::server::NamedService;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::Server;
+use tonic::Status;
+use tower::Service;
+
+fn compiler() -> protox::Compiler {
+    let mut compiler = protox::Compiler::new(["tests/fixtures/grpc"]).unwrap();
+    compiler.include_imports(true).open_file("catalog.proto").unwrap();
+    compiler
+}
+
+/// A `shop.v1.Catalog` server implemented with dynamic messages.
+#[derive(Clone)]
+struct Catalog {
+    pool: DescriptorPool,
+}
+
+impl NamedService for Catalog {
+    const NAME: &'static str = "shop.v1.Catalog";
+}
+
+type ResponseFuture = Pin<Box<dyn Future<Output = Result<http::Response<Body>, Infallible>> + Send>>;
+
+impl Service<http::Request<Body>> for Catalog {
+    type Response = http::Response<Body>;
+    type Error = Infallible;
+    type Future = ResponseFuture;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+        let service = self.pool.get_service_by_name(Self::NAME).unwrap();
+        let name = request.uri().path().rsplit('/').next().unwrap_or_default().to_string();
+        let method = service.methods().find(|m| m.name() == name).unwrap();
+        let output = method.output();
+
+        Box::pin(async move {
+            let handler = tower::service_fn(move |request: tonic::Request<DynamicMessage>| {
+                let output = output.clone();
+                let name = name.clone();
+                async move {
+                    let input = request.into_inner();
+                    let field = |name: &str| match input.get_field_by_name(name).as_deref() {
+                        Some(ProtoValue::String(value)) => value.clone(),
+                        _ => String::new(),
+                    };
+                    let body = match (name.as_str(), field("query").as_str(), field("sku").as_str()) {
+                        ("Search", "secret", _) => return Err(Status::permission_denied("not allowed")),
+                        ("Search", "busy", _) => {
+                            let mut status = Status::resource_exhausted("slow down");
+                            status.metadata_mut().insert("retry-after", "7".parse().unwrap());
+                            return Err(status);
+                        }
+                        ("Search", "full", _) => return Err(Status::resource_exhausted("quota exceeded")),
+                        ("Search", "slow", _) => {
+                            tokio::time::sleep(Duration::from_millis(500)).await;
+                            json!({ "items": [] })
+                        }
+                        ("Search", query, _) => json!({ "items": [
+                            { "sku": "A1", "title": query, "priceCents": 129900, "inStock": true },
+                            { "sku": "B2", "title": "Shade" },
+                        ] }),
+                        ("GetItem", _, "missing") => return Err(Status::not_found("no such item")),
+                        ("GetItem", _, sku) => json!({ "sku": sku, "title": "Lamp" }),
+                        _ => return Err(Status::unimplemented(name)),
+                    };
+                    Ok::<_, Status>(tonic::Response::new(DynamicMessage::deserialize(output, body).unwrap()))
+                }
+            });
+            let mut grpc = tonic::server::Grpc::new(DynamicCodec::new(method.input()));
+            Ok(grpc.unary(handler, request).await)
+        })
+    }
+}
+
+fn start_server() -> String {
+    let pool = compiler().descriptor_pool();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async move {
+            let incoming = TcpIncoming::bind("127.0.0.1:0".parse().unwrap()).unwrap();
+            tx.send(incoming.local_addr().unwrap()).unwrap();
+            Server::builder()
+                .add_service(Catalog { pool })
+                .serve_with_incoming(incoming)
+                .await
+                .unwrap();
+        });
+    });
+    format!("http://{}", rx.recv().unwrap())
+}
+
+fn supplier(endpoint: &str) -> GrpcSupplier {
+    GrpcSupplier::new("catalog", endpoint, &compiler().encode_file_descriptor_set())
+        .unwrap()
+        .with_method(SupplierOperation::Search, "shop.v1.Catalog/Search")
+        .unwrap()
+        .with_method(SupplierOperation::GetDetail, "shop.v1.Catalog.GetItem")
+        .unwrap()
+        .with_timeout(Duration::from_millis(200))
+}
+
+fn search(query: &str) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "query": query, "limit": 10 }))
+}
+
+#[test]
+fn json_params_round_trip_through_protobuf() {
+    let supplier = supplier(&start_server());
+
+    let response = supplier.query(search("lamp")).unwrap();
+    let items = response.data["items"].as_array().unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0], json!({ "sku": "A1", "title": "lamp", "price_cents": 129900, "in_stock": true }));
+    assert_eq!(items[1]["in_stock"], false);
+
+    let detail = SupplierRequest::new(SupplierOperation::GetDetail, json!({ "sku": "A1" }));
+    assert_eq!(supplier.query(detail).unwrap().data["title"], "Lamp");
+}
+
+#[test]
+fn grpc_statuses_map_to_supplier_errors() {
+    let supplier = supplier(&start_server());
+
+    assert!(matches!(supplier.query(search("secret")), Err(SupplierError::Unauthorized)));
+    assert!(matches!(supplier.query(search("slow")), Err(SupplierError::Timeout)));
+    assert!(matches!(supplier.query(search("busy")), Err(SupplierError::Throttled { retry_after: Some(wait) }) if wait.as_secs() == 7));
+    assert!(matches!(supplier.query(search("full")), Err(SupplierError::Throttled { retry_after: None })));
+
+    let missing = SupplierRequest::new(SupplierOperation::GetDetail, json!({ "sku": "missing" }));
+    assert!(matches!(supplier.query(missing), Err(SupplierError::NotFound)));
+}
+
+#[test]
+fn oversized_responses_are_rejected() {
+    let endpoint = start_server();
+    let limited = supplier(&endpoint).with_max_response_size(16);
+    assert!(matches!(limited.query(search("lamp")), Err(SupplierError::ResponseTooLarge(m)) if m.contains("16 bytes")));
+
+    let roomy = supplier(&endpoint).with_max_response_size(1024);
+    assert!(roomy.query(search("lamp")).is_ok());
+}
+
+#[test]
+fn requests_are_validated_before_calling_the_service() {
+    // Nothing listens on this endpoint; both requests fail before a connection is attempted.
+    let supplier = supplier("http://127.0.0.1:1");
+
+    let unknown_field = SupplierRequest::new(SupplierOperation::Search, json!({ "colour": "red" }));
+    assert!(matches!(supplier.query(unknown_field), Err(SupplierError::InvalidInput(_))));
+
+    let unmapped = SupplierRequest::new(SupplierOperation::Other("watch".into()), json!({}));
+    assert!(matches!(supplier.query(unmapped), Err(SupplierError::UnsupportedOperation(op)) if op == "watch"));
+
+    assert!(matches!(supplier.query(search("lamp")), Err(SupplierError::Upstream(_))));
+}
+
+#[test]
+fn configuration_errors_are_reported() {
+    let set = compiler().encode_file_descriptor_set();
+
+    assert!(matches!(GrpcSupplier::new("x", "http://localhost:1", b"not a descriptor"), Err(SupplierError::InvalidInput(_))));
+    assert!(matches!(GrpcSupplier::new("x", "not a uri", &set), Err(SupplierError::InvalidInput(_))));
+
+    let supplier = GrpcSupplier::new("x", "http://localhost:1", &set).unwrap();
+    let supplier = match supplier.with_method(SupplierOperation::Search, "shop.v1.Catalog/Nope") {
+        Err(SupplierError::InvalidInput(_)) => GrpcSupplier::new("x", "http://localhost:1", &set).unwrap(),
+        _ => panic!("unknown methods must be rejected"),
+    };
+    assert!(matches!(
+        supplier.with_method(SupplierOperation::Search, "shop.v1.Catalog/Watch"),
+        Err(SupplierError::UnsupportedOperation(_))
+    ));
+}
+
+#[test]
+fn descriptor_lists_mapped_methods() {
+    let descriptor = supplier("http://127.0.0.1:1").describe();
+    assert_eq!(descriptor.operations.len(), 2);
+    assert!(descriptor.supports(&SupplierOperation::GetDetail));
+    assert_eq!(descriptor.operations[1].summary.as_deref(), Some("shop.v1.Catalog/Search"));
+}
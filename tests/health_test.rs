@@ -0,0 +1,284 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+use std::time::{Duration, Instant, SystemTime};
+use serde_json::json;
+use supplier_kit::decorators::size_limit::SizeLimitedSupplier;
+use supplier_kit::deprecation::{Deprecation, DeprecationNotice};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::events::{EventBus, SupplierEvent};
+use supplier_kit::executor::BoundedExecutor;
+use supplier_kit::health::{HealthMap, HealthMonitor};
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::{Supplier, SupplierRegistry};
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+
+/// A supplier whose ping endpoint can be switched off.
+struct Pinged {
+    name: &'static str,
+    up: Arc<AtomicBool>,
+    pings: Arc<AtomicUsize>,
+}
+
+impl Pinged {
+    fn new(name: &'static str) -> (Self, Arc<AtomicBool>, Arc<AtomicUsize>) {
+        let up = Arc::new(AtomicBool::new(true));
+        let pings = Arc::new(AtomicUsize::new(0));
+        (Self { name, up: up.clone(), pings: pings.clone() }, up, pings)
+    }
+}
+
+impl Supplier for Pinged {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!({ "supplier": self.name })))
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.pings.fetch_add(1, Ordering::SeqCst);
+        if self.name == "panicky" {
+            panic!("ping crashed");
+        }
+        match self.up.load(Ordering::SeqCst) {
+            true => Ok(()),
+            false => Err(SupplierError::Upstream("ping failed".into())),
+        }
+    }
+}
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }))
+}
+
+#[test]
+fn health_maps_count_consecutive_failures() {
+    let health = HealthMap::new();
+    let fail = || Err(SupplierError::Timeout);
+
+    assert!(health.record("partner", fail(), Duration::from_millis(3), 2).healthy);
+    let down = health.record("partner", fail(), Duration::from_millis(3), 2);
+    assert!(!down.healthy);
+    assert_eq!(down.consecutive_failures, 2);
+    assert!(matches!(down.error, Some(SupplierError::Timeout)));
+    assert_eq!(down.latency_us, 3000);
+
+    let up = health.record("partner", Ok(()), Duration::ZERO, 2);
+    assert!(up.healthy && up.error.is_none() && up.consecutive_failures == 0);
+    assert_eq!(serde_json::to_value(health.snapshot()).unwrap()["partner"]["healthy"], true);
+
+    health.remove("partner");
+    assert!(health.get("partner").is_none());
+}
+
+#[test]
+fn monitors_check_every_registered_supplier() {
+    let (steady, _, _) = Pinged::new("steady");
+    let (flaky, flaky_up, flaky_pings) = Pinged::new("flaky");
+    let (panicky, _, _) = Pinged::new("panicky");
+    let mut registry = SupplierRegistry::new();
+    registry.register("steady", steady);
+    registry.register("flaky", SizeLimitedSupplier::new(flaky, 1024));
+    registry.register("panicky", panicky);
+
+    let bus = EventBus::new();
+    let events = bus.subscribe_channel();
+    let monitor = HealthMonitor::new(Arc::new(registry), Duration::from_secs(60))
+        .with_failure_threshold(2)
+        .with_event_bus(bus);
+    let health = monitor.health();
+
+    monitor.check_now();
+    assert_eq!(flaky_pings.load(Ordering::SeqCst), 1);
+    assert!(health.is_healthy("flaky"));
+    assert!(health.is_healthy("panicky"));
+
+    flaky_up.store(false, Ordering::SeqCst);
+    monitor.check_now();
+    assert!(health.is_healthy("flaky"));
+    monitor.check_now();
+    assert_eq!(health.unhealthy(), ["flaky", "panicky"]);
+    assert!(matches!(health.get("panicky").unwrap().error, Some(SupplierError::Internal(m)) if m.contains("panicked")));
+
+    flaky_up.store(true, Ordering::SeqCst);
+    monitor.check_now();
+    assert_eq!(health.unhealthy(), ["panicky"]);
+
+    let changes: Vec<(String, bool)> = events
+        .try_iter()
+        .filter_map(|event| match event {
+            SupplierEvent::HealthChanged { supplier, healthy, .. } => Some((supplier, healthy)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(changes, [("panicky".to_string(), false), ("flaky".to_string(), false), ("flaky".to_string(), true)]);
+}
+
+#[test]
+fn groups_skip_unhealthy_suppliers_before_fan_out() {
+    let (steady, _, _) = Pinged::new("steady");
+    let (flaky, flaky_up, _) = Pinged::new("flaky");
+    let steady = Arc::new(steady);
+    let flaky = Arc::new(flaky);
+    let mut registry = SupplierRegistry::new();
+    registry.register("steady", steady.clone());
+    registry.register("flaky", flaky.clone());
+
+    let monitor = HealthMonitor::new(Arc::new(registry), Duration::from_secs(60));
+    let mut group = BasicSupplierGroup::new("shops").with_health(monitor.health());
+    group.add_supplier(steady);
+    group.add_supplier(flaky);
+
+    flaky_up.store(false, Ordering::SeqCst);
+    assert_eq!(group.query(search()).successes.len(), 2);
+
+    monitor.check_now();
+    let result = group.query(search());
+    assert_eq!(result.successes.len(), 1);
+    assert!(result.failures.is_empty());
+    let plan = group.plan(&search());
+    assert_eq!(plan[1].skip_reason.as_deref(), Some("unhealthy: failed its latest health checks"));
+
+    flaky_up.store(true, Ordering::SeqCst);
+    monitor.check_now();
+    assert_eq!(group.query(search()).successes.len(), 2);
+}
+
+#[test]
+fn started_monitors_check_in_the_background_until_stopped() {
+    let (flaky, flaky_up, pings) = Pinged::new("flaky");
+    let mut registry = SupplierRegistry::new();
+    registry.register("flaky", flaky);
+    let health = Arc::new(HealthMap::new());
+
+    flaky_up.store(false, Ordering::SeqCst);
+    let handle = HealthMonitor::new(Arc::new(registry), Duration::from_millis(10))
+        .with_health_map(health.clone())
+        .start();
+
+    let started = Instant::now();
+    while pings.load(Ordering::SeqCst) < 3 && started.elapsed() < Duration::from_secs(5) {
+        sleep(Duration::from_millis(5));
+    }
+    assert!(!health.is_healthy("flaky"));
+
+    flaky_up.store(true, Ordering::SeqCst);
+    while !health.is_healthy("flaky") && started.elapsed() < Duration::from_secs(5) {
+        sleep(Duration::from_millis(5));
+    }
+    assert!(health.is_healthy("flaky"));
+
+    handle.stop();
+    let stopped = pings.load(Ordering::SeqCst);
+    sleep(Duration::from_millis(40));
+    assert_eq!(pings.load(Ordering::SeqCst), stopped);
+}
+
+#[test]
+fn checks_run_on_the_executor() {
+    let (steady, _, pings) = Pinged::new("steady");
+    let mut registry = SupplierRegistry::new();
+    registry.register("steady", steady);
+    let executor = Arc::new(BoundedExecutor::new(1));
+    let monitor = HealthMonitor::new(Arc::new(registry), Duration::from_secs(60)).with_executor(executor.clone());
+
+    monitor.check_now();
+    assert_eq!(pings.load(Ordering::SeqCst), 1);
+    assert!(monitor.health().is_healthy("steady"));
+    assert_eq!(executor.active_threads(), 0);
+}
+
+/// A supplier whose health check hangs.
+struct Hung;
+
+impl Supplier for Hung {
+    fn name(&self) -> &str {
+        "hung"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!({})))
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        sleep(Duration::from_secs(1));
+        Ok(())
+    }
+}
+
+/// A supplier whose health check takes a while, counting how often it started.
+struct Slow {
+    pings: Arc<AtomicUsize>,
+}
+
+impl Supplier for Slow {
+    fn name(&self) -> &str {
+        "slow"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!({})))
+    }
+
+    fn health_check(&self) -> Result<(), SupplierError> {
+        self.pings.fetch_add(1, Ordering::SeqCst);
+        sleep(Duration::from_millis(400));
+        Ok(())
+    }
+}
+
+#[test]
+fn hung_checks_time_out() {
+    let (steady, _, _) = Pinged::new("steady");
+    let mut registry = SupplierRegistry::new();
+    registry.register("hung", Hung);
+    registry.register("steady", steady);
+    let monitor = HealthMonitor::new(Arc::new(registry), Duration::from_secs(60)).with_check_timeout(Duration::from_millis(50));
+
+    let started = Instant::now();
+    monitor.check_now();
+    assert!(started.elapsed() < Duration::from_millis(500));
+    assert!(matches!(monitor.health().get("hung").unwrap().error, Some(SupplierError::Timeout)));
+    assert!(monitor.health().is_healthy("steady"));
+}
+
+#[test]
+fn checks_are_recorded_under_the_registered_name_only() {
+    let (flaky, flaky_up, _) = Pinged::new("flaky");
+    let flaky = Arc::new(flaky);
+    let notices = Arc::new(Mutex::new(Vec::new()));
+    let sink = notices.clone();
+    let mut registry = SupplierRegistry::new();
+    registry.register("flaky_eu", flaky.clone());
+    registry.on_deprecation(move |notice: &DeprecationNotice| sink.lock().unwrap().push(notice.clone()));
+    registry.deprecate("flaky_eu", Deprecation::new(SystemTime::now() + Duration::from_secs(3600), Duration::ZERO)).unwrap();
+    let monitor = HealthMonitor::new(Arc::new(registry), Duration::from_secs(60));
+
+    flaky_up.store(false, Ordering::SeqCst);
+    monitor.check_now();
+    assert!(!monitor.health().is_healthy("flaky_eu"));
+    assert!(monitor.health().get("flaky").is_none());
+    assert!(notices.lock().unwrap().is_empty());
+}
+
+#[test]
+fn hung_checks_are_not_started_again() {
+    let (steady, _, steady_pings) = Pinged::new("steady");
+    let pings = Arc::new(AtomicUsize::new(0));
+    let mut registry = SupplierRegistry::new();
+    registry.register("hung", Slow { pings: pings.clone() });
+    registry.register("steady", steady);
+    let monitor = HealthMonitor::new(Arc::new(registry), Duration::from_secs(60)).with_check_timeout(Duration::from_millis(50));
+
+    monitor.check_now();
+    monitor.check_now();
+    assert_eq!(pings.load(Ordering::SeqCst), 1);
+    assert_eq!(steady_pings.load(Ordering::SeqCst), 2);
+    assert!(matches!(monitor.health().get("hung").unwrap().error, Some(SupplierError::Timeout)));
+
+    sleep(Duration::from_millis(600));
+    monitor.check_now();
+    assert_eq!(pings.load(Ordering::SeqCst), 2);
+}
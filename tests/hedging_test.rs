@@ -0,0 +1,88 @@
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use serde_json::json;
+use supplier_kit::decorators::hedging::HedgingSupplier;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::executor::BoundedExecutor;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+
+struct DelayedSupplier {
+    name: &'static str,
+    delay: Duration,
+    fail: bool,
+}
+
+impl Supplier for DelayedSupplier {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        thread::sleep(self.delay);
+        if self.fail {
+            Err(SupplierError::Upstream(format!("{} failed", self.name)))
+        } else {
+            Ok(SupplierResponse::new(json!({ "from": self.name })))
+        }
+    }
+}
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": 1 }))
+}
+
+fn delayed(name: &'static str, millis: u64, fail: bool) -> DelayedSupplier {
+    DelayedSupplier {
+        name,
+        delay: Duration::from_millis(millis),
+        fail,
+    }
+}
+
+#[test]
+fn test_hedge_wins_when_primary_is_slow() {
+    let hedged = HedgingSupplier::new(delayed("primary", 2000, false), delayed("secondary", 10, false), Duration::from_millis(30));
+
+    let started = Instant::now();
+    let response = hedged.query(request()).unwrap();
+    assert_eq!(response.data["from"], "secondary");
+    assert!(started.elapsed() < Duration::from_millis(1000));
+}
+
+#[test]
+fn test_fast_primary_is_not_hedged() {
+    let hedged = HedgingSupplier::new(delayed("primary", 0, false), delayed("secondary", 0, false), Duration::from_millis(500));
+    assert_eq!(hedged.query(request()).unwrap().data["from"], "primary");
+}
+
+#[test]
+fn test_failed_primary_falls_through_to_secondary() {
+    let hedged = HedgingSupplier::new(delayed("primary", 0, true), delayed("secondary", 0, false), Duration::from_millis(500));
+    assert_eq!(hedged.query(request()).unwrap().data["from"], "secondary");
+
+    let both_fail = HedgingSupplier::new(delayed("primary", 0, true), delayed("secondary", 0, true), Duration::from_millis(500));
+    assert!(matches!(both_fail.query(request()), Err(SupplierError::Upstream(_))));
+}
+
+#[test]
+fn test_hedging_runs_both_calls_on_the_executor() {
+    // Without threads, the primary runs on the caller and fails before the hedge is sent.
+    let hedged = HedgingSupplier::new(delayed("primary", 0, true), delayed("secondary", 0, false), Duration::from_millis(500))
+        .with_executor(Arc::new(BoundedExecutor::new(0)));
+    let started = Instant::now();
+    assert_eq!(hedged.query(request()).unwrap().data["from"], "secondary");
+    assert!(started.elapsed() < Duration::from_millis(500));
+}
+
+#[test]
+fn test_writes_are_never_hedged() {
+    let hedged = HedgingSupplier::new(delayed("primary", 100, false), delayed("secondary", 0, false), Duration::from_millis(10));
+    let create = SupplierRequest::new(SupplierOperation::Create, json!({ "sku": "lamp" }));
+    assert_eq!(hedged.query(create).unwrap().data["from"], "primary");
+
+    let failing = HedgingSupplier::new(delayed("primary", 0, true), delayed("secondary", 0, false), Duration::from_millis(500));
+    let delete = SupplierRequest::new(SupplierOperation::Delete, json!({ "id": 1 }));
+    assert!(matches!(failing.query(delete), Err(SupplierError::Upstream(_))));
+}
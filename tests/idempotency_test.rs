@@ -0,0 +1,150 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::context::RequestContext;
+use supplier_kit::decorators::idempotency::IdempotentSupplier;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{ResponseSource, SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+
+#[derive(Clone, Default)]
+struct Orders {
+    placed: Arc<AtomicUsize>,
+    failures_left: Arc<AtomicUsize>,
+}
+
+impl Supplier for Orders {
+    fn name(&self) -> &str {
+        "orders"
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        thread::sleep(Duration::from_millis(20));
+        if self.failures_left.load(Ordering::SeqCst) > 0 {
+            self.failures_left.fetch_sub(1, Ordering::SeqCst);
+            return Err(SupplierError::Upstream("payment gateway down".into()));
+        }
+        let number = self.placed.fetch_add(1, Ordering::SeqCst) + 1;
+        Ok(SupplierResponse::new(json!({ "order": number, "sku": request.params["sku"] })))
+    }
+}
+
+fn place(sku: &str, key: &str) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Create, json!({ "sku": sku })).with_idempotency_key(key)
+}
+
+#[test]
+fn repeated_keys_are_applied_once() {
+    let orders = Orders::default();
+    let supplier = IdempotentSupplier::new(orders.clone(), Duration::from_secs(60));
+
+    let first = supplier.query(place("A1", "k1")).unwrap();
+    let again = supplier.query(place("A1", "k1")).unwrap();
+    assert_eq!(first.data, again.data);
+    assert_eq!(again.source(), ResponseSource::Cache);
+
+    supplier.query(place("A1", "k2")).unwrap();
+    assert_eq!(orders.placed.load(Ordering::SeqCst), 2);
+    assert_eq!(supplier.len(), 2);
+}
+
+#[test]
+fn concurrent_duplicates_wait_for_the_first_call() {
+    let orders = Orders::default();
+    let supplier = Arc::new(IdempotentSupplier::new(orders.clone(), Duration::from_secs(60)));
+
+    let handles: Vec<_> = (0..5)
+        .map(|_| {
+            let supplier = supplier.clone();
+            thread::spawn(move || supplier.query(place("A1", "same")).unwrap())
+        })
+        .collect();
+    let numbers: Vec<_> = handles.into_iter().map(|h| h.join().unwrap().data["order"].clone()).collect();
+
+    assert_eq!(orders.placed.load(Ordering::SeqCst), 1);
+    assert!(numbers.iter().all(|n| *n == json!(1)));
+}
+
+#[test]
+fn reusing_a_key_for_another_request_is_rejected() {
+    let supplier = IdempotentSupplier::new(Orders::default(), Duration::from_secs(60));
+    supplier.query(place("A1", "k")).unwrap();
+
+    let reused = supplier.query(place("B2", "k"));
+    assert!(matches!(reused, Err(SupplierError::InvalidInput(m)) if m.contains("'k'")));
+    assert!(matches!(supplier.query(place("A1", "k").with_version(2)), Err(SupplierError::InvalidInput(_))));
+}
+
+#[test]
+fn failures_are_not_remembered() {
+    let orders = Orders::default();
+    orders.failures_left.store(1, Ordering::SeqCst);
+    let supplier = IdempotentSupplier::new(orders.clone(), Duration::from_secs(60));
+
+    assert!(supplier.query(place("A1", "k")).is_err());
+    assert!(supplier.is_empty());
+    assert_eq!(supplier.query(place("A1", "k")).unwrap().source(), ResponseSource::Live);
+}
+
+#[test]
+fn keys_expire_and_can_be_forgotten() {
+    let orders = Orders::default();
+    let supplier = IdempotentSupplier::new(orders.clone(), Duration::from_millis(50));
+
+    supplier.query(place("A1", "k")).unwrap();
+    thread::sleep(Duration::from_millis(80));
+    assert_eq!(supplier.query(place("A1", "k")).unwrap().source(), ResponseSource::Live);
+
+    supplier.forget("k");
+    supplier.query(place("A1", "k")).unwrap();
+    assert_eq!(orders.placed.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn requests_without_a_key_pass_through() {
+    let orders = Orders::default();
+    let supplier = IdempotentSupplier::new(orders.clone(), Duration::from_secs(60));
+    let request = SupplierRequest::new(SupplierOperation::Create, json!({ "sku": "A1" }));
+
+    supplier.query(request.clone()).unwrap();
+    supplier.query(request).unwrap();
+    assert_eq!(orders.placed.load(Ordering::SeqCst), 2);
+    assert!(supplier.is_empty());
+}
+
+#[test]
+fn write_operations_and_keys_serialize() {
+    let request = place("A1", "k1");
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(value["operation"], "create");
+    assert_eq!(value["idempotency_key"], "k1");
+    assert_eq!(serde_json::from_value::<SupplierRequest>(value).unwrap(), request);
+
+    let read = SupplierRequest::new(SupplierOperation::Search, json!({}));
+    assert!(serde_json::to_value(&read).unwrap().get("idempotency_key").is_none());
+
+    for name in ["create", "update", "delete", "submit"] {
+        let operation = SupplierOperation::from(name);
+        assert!(operation.is_write());
+        assert_eq!(operation.as_str(), name);
+    }
+}
+
+#[test]
+fn keys_are_scoped_by_tenant() {
+    let orders = Orders::default();
+    let supplier = IdempotentSupplier::new(orders.clone(), Duration::from_secs(60));
+    let for_tenant = |tenant: &str| place("A1", "k1").with_context(RequestContext::new().with_tenant(tenant));
+
+    let acme = supplier.query(for_tenant("acme")).unwrap();
+    let globex = supplier.query(for_tenant("globex")).unwrap();
+    assert_eq!(globex.source(), ResponseSource::Live);
+    assert_ne!(acme.data["order"], globex.data["order"]);
+    assert_eq!(supplier.query(for_tenant("acme")).unwrap().data, acme.data);
+    assert_eq!(orders.placed.load(Ordering::SeqCst), 2);
+
+    supplier.forget("k1");
+    assert!(supplier.is_empty());
+}
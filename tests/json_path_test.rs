@@ -0,0 +1,146 @@
+use serde_json::{json, Value};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::json_path::JsonPath;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::orchestration::pipeline::Pipeline;
+use supplier_kit::routing::{Condition, RoutingRule, RoutingRules, Test};
+
+fn catalog() -> Value {
+    json!({
+        "store": {
+            "name": "Lamp Depot",
+            "items": [
+                { "sku": "A1", "price": 12.5, "tags": ["brass"] },
+                { "sku": "B2", "price": 7, "tags": [] },
+                { "sku": "C3", "price": 30, "variant": { "sku": "C3-XL", "price": 35 } }
+            ],
+            "a/b": { "~c": true }
+        }
+    })
+}
+
+fn find(path: &str) -> Vec<Value> {
+    JsonPath::parse(path).unwrap().find(&catalog()).into_iter().cloned().collect()
+}
+
+#[test]
+fn names_indexes_and_brackets_select_single_values() {
+    assert_eq!(find("$.store.name"), [json!("Lamp Depot")]);
+    assert_eq!(find("$['store'][\"name\"]"), [json!("Lamp Depot")]);
+    assert_eq!(find("$.store.items[1].sku"), [json!("B2")]);
+    assert_eq!(find("$.store.items[-1].sku"), [json!("C3")]);
+    assert_eq!(find("$"), [catalog()]);
+    assert!(find("$.store.items[3]").is_empty());
+    assert!(find("$.store.items[-4]").is_empty());
+    assert!(find("$.store.name.first").is_empty());
+    assert!(find("$.store[0]").is_empty());
+}
+
+#[test]
+fn json_pointers_are_accepted() {
+    assert_eq!(find("/store/items/0/sku"), [json!("A1")]);
+    assert_eq!(find("/store/a~1b/~0c"), [json!(true)]);
+    assert_eq!(find(""), [catalog()]);
+    assert!(find("/store/items/x").is_empty());
+}
+
+#[test]
+fn wildcards_select_every_member_or_element() {
+    assert_eq!(find("$.store.items[*].sku"), [json!("A1"), json!("B2"), json!("C3")]);
+    assert_eq!(find("$.store.items.*.price"), [json!(12.5), json!(7), json!(30)]);
+    assert_eq!(find("$.store.items[*].tags[*]"), [json!("brass")]);
+}
+
+#[test]
+fn recursive_descent_selects_matches_at_any_depth() {
+    assert_eq!(find("$..sku"), [json!("A1"), json!("B2"), json!("C3"), json!("C3-XL")]);
+    assert_eq!(find("$.store..variant.price"), [json!(35)]);
+    assert_eq!(find("$..items[0].sku"), [json!("A1")]);
+    assert_eq!(find("$..*").len(), 20);
+}
+
+#[test]
+fn unsupported_paths_are_rejected_with_the_path_in_the_message() {
+    for path in ["store.name", "$.", "$..", "$.items[first]", "$['name'", "$[1:2]", "$store"] {
+        let error = JsonPath::parse(path).unwrap_err();
+        assert!(
+            matches!(&error, SupplierError::InvalidInput(message) if message.contains(path)),
+            "{}: {:?}",
+            path,
+            error
+        );
+    }
+}
+
+#[test]
+fn definite_paths_select_at_most_one_value() {
+    assert!(JsonPath::parse("$.store.items[0]").unwrap().is_definite());
+    assert!(JsonPath::parse("/store/items").unwrap().is_definite());
+    assert!(!JsonPath::parse("$.store.items[*]").unwrap().is_definite());
+    assert!(!JsonPath::parse("$..sku").unwrap().is_definite());
+
+    let path: JsonPath = "$.store.name".parse().unwrap();
+    assert_eq!(path.to_string(), "$.store.name");
+    assert_eq!(path.as_str(), "$.store.name");
+}
+
+#[test]
+fn responses_extract_values_from_their_data() {
+    let response = SupplierResponse::new(catalog());
+
+    assert_eq!(response.extract("$.store.items[0].price"), Some(&json!(12.5)));
+    assert_eq!(response.extract("$..sku"), Some(&json!("A1")));
+    assert_eq!(response.extract("$.store.missing"), None);
+    assert_eq!(response.extract("store"), None);
+    assert_eq!(response.extract_all("$.store.items[*].sku"), ["A1", "B2", "C3"]);
+    assert!(response.extract_all("$[").is_empty());
+
+    let tags: Vec<String> = response.extract_as("$.store.items[*].tags").unwrap();
+    assert_eq!(tags, ["brass"]);
+    let price: f64 = response.extract_as("/store/items/2/variant/price").unwrap();
+    assert_eq!(price, 35.0);
+    assert!(matches!(response.extract_as::<u32>("$.store.name"), Err(SupplierError::Upstream(_))));
+    assert!(matches!(response.extract_as::<u32>("$.store.total"), Err(SupplierError::Upstream(_))));
+    assert!(matches!(response.extract_as::<u32>("store.total"), Err(SupplierError::InvalidInput(_))));
+}
+
+#[test]
+fn routing_conditions_match_any_value_selected_by_wildcards() {
+    let params = json!({ "items": [{ "sku": "A-1", "kind": "book" }, { "sku": "B-2", "kind": "lamp" }] });
+
+    assert!(Condition::new("$.items[*].kind", Test::Equals(json!("lamp"))).matches(&params));
+    assert!(Condition::new("$..sku", Test::In(vec![json!("B-2"), json!("Z-9")])).matches(&params));
+    assert!(!Condition::new("$.items[*].kind", Test::NotEquals(json!("book"))).matches(&params));
+    assert!(Condition::new("$.items[*].kind", Test::NotEquals(json!("toy"))).matches(&params));
+    assert!(Condition::new("$.items[*].price", Test::Exists(false)).matches(&params));
+    assert!(Condition::new("$.items[-1].sku", Test::Equals(json!("B-2"))).matches(&params));
+
+    let rules = RoutingRules::new(vec![
+        RoutingRule::to(&["lamp_shop"]).when("$.items[*].kind", Test::Equals(json!("lamp"))),
+    ]);
+    let request = SupplierRequest::new(SupplierOperation::Search, params);
+    assert!(rules.routes("lamp_shop", &request));
+    assert!(!rules.routes("book_shop", &request));
+}
+
+#[test]
+fn pipeline_stages_extract_from_earlier_outputs() {
+    let result = Pipeline::new()
+        .stage("search", |_| Ok(catalog()))
+        .map("skus", |ctx| {
+            let skus = ctx.extract_all("search", "$.store.items[*].sku")?;
+            let cheapest = ctx.extract("search", "$.store.items[1].sku")?;
+            Ok(json!({ "skus": skus, "cheapest": cheapest }))
+        })
+        .after(&["search"])
+        .map("missing", |ctx| ctx.extract("search", "$.store.total").cloned())
+        .after(&["search"])
+        .map("invalid", |ctx| ctx.extract("search", "store").cloned())
+        .after(&["search"])
+        .run()
+        .unwrap();
+
+    assert_eq!(result.output("skus").unwrap(), &json!({ "skus": ["A1", "B2", "C3"], "cheapest": "B2" }));
+    assert!(matches!(result.failures.get("missing"), Some(SupplierError::InvalidInput(message)) if message.contains("$.store.total")));
+    assert!(matches!(result.failures.get("invalid"), Some(SupplierError::InvalidInput(_))));
+}
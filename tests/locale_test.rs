@@ -0,0 +1,134 @@
+use serde_json::json;
+use supplier_kit::config::{Manifest, SupplierFactory};
+use supplier_kit::context::RequestContext;
+use supplier_kit::descriptor::SupplierDescriptor;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::localization::{negotiate, MessageCatalog};
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn search(locale: Option<&str>) -> SupplierRequest {
+    let context = locale.map_or_else(RequestContext::new, |locale| RequestContext::new().with_locale(locale));
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" })).with_context(context)
+}
+
+fn shop(name: &str, locales: &[&str]) -> MockSupplier {
+    locales
+        .iter()
+        .fold(MockSupplierBuilder::new(name).respond_default(json!([])), |builder, locale| builder.with_locale(locale))
+        .build()
+}
+
+fn locale_of(shop: &MockSupplier) -> Option<String> {
+    shop.last_request().unwrap().context.locale
+}
+
+#[test]
+fn locales_travel_in_the_request_context() {
+    let request = search(Some("pt-BR"));
+    let json = serde_json::to_value(&request).unwrap();
+    assert_eq!(json["context"]["locale"], "pt-BR");
+    assert_eq!(serde_json::from_value::<SupplierRequest>(json).unwrap(), request);
+    assert!(serde_json::to_value(search(None)).unwrap()["context"].get("locale").is_none());
+}
+
+#[test]
+fn negotiation_prefers_exact_then_broader_then_sibling_locales() {
+    let supported: Vec<String> = ["en-US", "en", "pt-BR", "pt-PT", "zh-Hant"].iter().map(|s| s.to_string()).collect();
+    assert_eq!(negotiate("en-us", &supported), Some("en-US"));
+    assert_eq!(negotiate("en-GB", &supported), Some("en"));
+    assert_eq!(negotiate("pt_PT", &supported), Some("pt-PT"));
+    assert_eq!(negotiate("pt", &supported), Some("pt-BR"));
+    assert_eq!(negotiate("zh-Hant-HK", &supported), Some("zh-Hant"));
+    assert_eq!(negotiate("de-DE", &supported), None);
+    assert_eq!(negotiate("en", &[]), None);
+}
+
+#[test]
+fn descriptors_declare_locales_and_fall_back_to_their_default() {
+    let descriptor = SupplierDescriptor::new("shop").with_locale("id").with_locale("en");
+    assert_eq!(descriptor.select_locale(Some("en-AU")), Some("en"));
+    assert_eq!(descriptor.select_locale(Some("fr")), Some("id"));
+    assert_eq!(descriptor.select_locale(None), None);
+
+    let any = SupplierDescriptor::new("global");
+    assert_eq!(any.select_locale(Some("fr-CA")), Some("fr-CA"));
+    assert!(serde_json::to_value(&any).unwrap().get("locales").is_none());
+    assert_eq!(serde_json::to_value(&descriptor).unwrap()["locales"], json!(["id", "en"]));
+}
+
+#[test]
+fn groups_send_every_member_its_closest_locale() {
+    for strategy in [QueryStrategy::Sequential, QueryStrategy::Parallel] {
+        let local = shop("local", &["id", "en"]);
+        let brazil = shop("brazil", &["pt-BR"]);
+        let global = shop("global", &[]);
+        let mut group = BasicSupplierGroup::new("marketplaces").with_strategy(strategy);
+        for member in [&local, &brazil, &global] {
+            group.add_supplier(member.clone());
+        }
+
+        let result = group.query(search(Some("id-ID")));
+        assert_eq!(result.successes.len(), 3);
+        assert_eq!(locale_of(&local).as_deref(), Some("id"));
+        assert_eq!(locale_of(&brazil).as_deref(), Some("pt-BR"));
+        assert_eq!(locale_of(&global).as_deref(), Some("id-ID"));
+
+        group.query(search(None));
+        assert_eq!(locale_of(&local), None);
+        assert_eq!(locale_of(&brazil), None);
+    }
+}
+
+#[test]
+fn batches_and_direct_calls_are_localized_per_member() {
+    let local = shop("local", &["en", "id"]);
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.add_supplier(local.clone());
+
+    let results = group.query_batch(vec![search(Some("id-ID")), search(Some("ja"))]);
+    assert!(results.iter().all(|result| result.failures.is_empty()));
+    let locales: Vec<Option<String>> = local.requests().into_iter().map(|r| r.context.locale).collect();
+    assert_eq!(locales, [Some("id".to_string()), Some("en".to_string())]);
+
+    // Suppliers queried directly receive the request unchanged.
+    local.query(search(Some("ja"))).unwrap();
+    assert_eq!(locale_of(&local).as_deref(), Some("ja"));
+}
+
+#[test]
+fn failures_are_translated_in_the_request_locale() {
+    let catalog = MessageCatalog::new("en")
+        .with_message("en", "timeout", "The shop is slow.")
+        .with_message("pt", "timeout", "A loja está lenta.")
+        .with_supplier_message("pt-BR", "brazil", "timeout", "A loja brasileira está lenta.");
+
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.add_supplier(MockSupplierBuilder::new("brazil").then_fail(SupplierError::Timeout).then_fail(SupplierError::Timeout).build());
+    group.add_supplier(MockSupplierBuilder::new("lisbon").then_fail(SupplierError::Timeout).then_fail(SupplierError::Timeout).build());
+
+    let request = search(Some("pt-BR"));
+    let result = group.query(request.clone());
+    let messages = catalog.localize_failures_for(&result, &request.context);
+    assert_eq!(messages[0].1.message, "A loja brasileira está lenta.");
+    assert_eq!(messages[1].1.message, "A loja está lenta.");
+
+    let request = search(None);
+    let result = group.query(request.clone());
+    assert_eq!(catalog.localize_failures_for(&result, &request.context)[0].1.message, "The shop is slow.");
+}
+
+#[test]
+fn manifests_declare_mock_locales() {
+    let manifest = Manifest::from_json(
+        r#"{ "suppliers": [{ "name": "local", "type": "mock", "config": { "default": [], "locales": ["id", "en"] } }] }"#,
+    )
+    .unwrap();
+    let loaded = SupplierFactory::new().load(&manifest).unwrap();
+    assert_eq!(loaded.registry.get("local").unwrap().describe().locales, ["id", "en"]);
+
+    let invalid = Manifest::from_json(r#"{ "suppliers": [{ "name": "local", "type": "mock", "config": { "locales": "id" } }] }"#).unwrap();
+    assert!(matches!(SupplierFactory::new().load(&invalid), Err(SupplierError::InvalidInput(_))));
+}
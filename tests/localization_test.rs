@@ -0,0 +1,57 @@
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::localization::MessageCatalog;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+
+struct LeakySupplier;
+
+impl Supplier for LeakySupplier {
+    fn name(&self) -> &str {
+        "leaky"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Err(SupplierError::Upstream("db password=hunter2 rejected".into()))
+    }
+}
+
+#[test]
+fn test_supplier_specific_message_takes_precedence() {
+    let catalog = MessageCatalog::new("en")
+        .with_message("en", "timeout", "Too slow.")
+        .with_supplier_message("en", "acme", "timeout", "Acme is too slow.");
+
+    assert_eq!(catalog.localize(&SupplierError::Timeout, "acme", "en").message, "Acme is too slow.");
+    assert_eq!(catalog.localize(&SupplierError::Timeout, "other", "en").message, "Too slow.");
+}
+
+#[test]
+fn test_falls_back_to_default_locale() {
+    let catalog = MessageCatalog::new("en").with_message("en", "not_found", "Nothing here.");
+    let msg = catalog.localize(&SupplierError::NotFound, "acme", "de");
+    assert_eq!(msg.code, "not_found");
+    assert_eq!(msg.message, "Nothing here.");
+}
+
+#[test]
+fn test_catalog_from_json_and_group_failures() {
+    let catalog: MessageCatalog = serde_json::from_value(json!({
+        "default_locale": "en",
+        "messages": { "id": { "upstream": "Layanan mitra sedang bermasalah." } }
+    }))
+    .unwrap();
+
+    let mut group = BasicSupplierGroup::new("g");
+    group.add_supplier(LeakySupplier);
+    let result = group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+
+    let localized = catalog.localize_failures(&result, "id");
+    assert_eq!(localized.len(), 1);
+    assert_eq!(localized[0].0, "leaky");
+    assert_eq!(localized[0].1.message, "Layanan mitra sedang bermasalah.");
+
+    let english = catalog.localize_failures(&result, "en");
+    assert!(!english[0].1.message.contains("hunter2"));
+}
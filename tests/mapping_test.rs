@@ -0,0 +1,155 @@
+use serde_json::json;
+use supplier_kit::config::{Manifest, SupplierFactory};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::formats::csv::CsvParser;
+use supplier_kit::mapping::{FieldRule, FieldType, MappingSpec, NormalizingSupplier, ResponseNormalizer};
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }))
+}
+
+fn offers_mapping() -> MappingSpec {
+    MappingSpec::from_json(
+        r#"{
+            "each": "$.Results[*]",
+            "fields": [
+                { "from": "$.ProductID", "to": "id", "type": "string", "required": true },
+                { "from": "$.Pricing.Amount", "to": "price.amount", "type": "float" },
+                { "from": "$.Pricing.Currency", "to": "price.currency", "default": "USD" },
+                { "from": "$.Stock", "to": "stock", "type": "integer", "default": 0 },
+                { "from": "$.Active", "to": "active", "type": "boolean" },
+                { "from": "$.Tags[*].Name", "to": "tags" }
+            ]
+        }"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn mappings_rename_nest_coerce_and_default_fields() {
+    let mapping = offers_mapping().compile().unwrap();
+    let data = json!({
+        "Results": [
+            { "ProductID": 17, "Pricing": { "Amount": "12.50", "Currency": "EUR" }, "Stock": "3", "Active": "TRUE",
+              "Tags": [{ "Name": "brass" }, { "Name": "desk" }] },
+            { "ProductID": "B-2", "Pricing": { "Amount": 7 }, "Stock": null, "Active": 0, "Extra": "dropped" }
+        ]
+    });
+
+    assert_eq!(
+        mapping.map(&data).unwrap(),
+        json!([
+            { "id": "17", "price": { "amount": 12.5, "currency": "EUR" }, "stock": 3, "active": true, "tags": ["brass", "desk"] },
+            { "id": "B-2", "price": { "amount": 7, "currency": "USD" }, "stock": 0, "active": false, "tags": [] }
+        ])
+    );
+    assert_eq!(mapping.map(&json!({ "Results": [] })).unwrap(), json!([]));
+}
+
+#[test]
+fn mappings_without_each_map_the_whole_document() {
+    let mapping = MappingSpec {
+        each: None,
+        fields: vec![
+            FieldRule::new("/data/total", "total").with_type(FieldType::Integer),
+            FieldRule::new("$.data.next", "page.next"),
+            FieldRule::new("$.data.items[0].id", "first"),
+        ],
+    }
+    .compile()
+    .unwrap();
+
+    let data = json!({ "data": { "total": 42.0, "items": [{ "id": "a" }] } });
+    assert_eq!(mapping.map(&data).unwrap(), json!({ "total": 42, "first": "a" }));
+}
+
+#[test]
+fn bad_values_and_missing_required_fields_fail_as_upstream_errors() {
+    let mapping = offers_mapping().compile().unwrap();
+
+    let missing = json!({ "Results": [{ "ProductID": "A" }, { "Pricing": {} }] });
+    assert!(matches!(mapping.map(&missing), Err(SupplierError::Upstream(message))
+        if message.contains("record 1") && message.contains("'id'")));
+
+    let bad_price = json!({ "Results": [{ "ProductID": "A", "Pricing": { "Amount": "cheap" } }] });
+    assert!(matches!(mapping.map(&bad_price), Err(SupplierError::Upstream(message))
+        if message.contains("price.amount") && message.contains("cheap")));
+
+    let bad_flag = json!({ "Results": [{ "ProductID": "A", "Active": 2 }] });
+    assert!(matches!(mapping.map(&bad_flag), Err(SupplierError::Upstream(_))));
+}
+
+#[test]
+fn invalid_mappings_are_rejected_when_compiled() {
+    let compile = |fields: Vec<FieldRule>| MappingSpec { each: None, fields }.compile();
+
+    assert!(matches!(compile(vec![FieldRule::new("Product.ID", "id")]), Err(SupplierError::InvalidInput(m)) if m.contains("Product.ID")));
+    assert!(matches!(compile(vec![FieldRule::new("$.id", "a..b")]), Err(SupplierError::InvalidInput(_))));
+    assert!(matches!(
+        compile(vec![FieldRule::new("$.a", "price"), FieldRule::new("$.b", "price.amount")]),
+        Err(SupplierError::InvalidInput(m)) if m.contains("conflicts")
+    ));
+    assert!(matches!(
+        compile(vec![FieldRule::new("$.n", "n").with_type(FieldType::Integer).with_default(json!("many"))]),
+        Err(SupplierError::InvalidInput(m)) if m.contains("default")
+    ));
+    assert!(MappingSpec::from_json(r#"{ "fields": [{ "from": "$.a", "to": "a", "type": "date" }] }"#).is_err());
+}
+
+#[test]
+fn specs_round_trip_through_json() {
+    let spec = offers_mapping();
+    let json = serde_json::to_string(&spec).unwrap();
+    assert_eq!(MappingSpec::from_json(&json).unwrap(), spec);
+    assert!(!json.contains("\"required\":false"));
+}
+
+#[test]
+fn normalizing_suppliers_apply_mappings_closures_and_parsers() {
+    let partner = MockSupplierBuilder::new("partner")
+        .respond_default(json!({ "Results": [{ "ProductID": 1, "Pricing": { "Amount": 3 } }] }))
+        .build();
+    let supplier = NormalizingSupplier::new(partner.clone(), offers_mapping().compile().unwrap());
+    assert_eq!(supplier.name(), "partner");
+    assert_eq!(supplier.query(search()).unwrap().data[0]["price"], json!({ "amount": 3, "currency": "USD" }));
+    assert!(supplier.query_batch(vec![search(), search()]).iter().all(Result::is_ok));
+
+    let tagged = NormalizingSupplier::new(partner, |mut response: SupplierResponse| {
+        response.data["normalized"] = json!(true);
+        Ok(response)
+    });
+    assert_eq!(tagged.query(search()).unwrap().data["normalized"], true);
+
+    let csv = SupplierResponse::binary("text/csv", b"sku,qty\nA1,2\n".to_vec());
+    assert_eq!(CsvParser::new().normalize_response(csv).unwrap().data, json!([{ "sku": "A1", "qty": 2 }]));
+}
+
+#[test]
+fn manifests_declare_mappings_per_supplier() {
+    let manifest = Manifest::from_json(
+        r#"{
+            "suppliers": [{
+                "name": "legacy",
+                "type": "mock",
+                "config": { "default": { "Product": { "Title": "Lamp", "Price": "9.90" } } },
+                "mapping": { "fields": [
+                    { "from": "$.Product.Title", "to": "name" },
+                    { "from": "$.Product.Price", "to": "price", "type": "float" }
+                ] }
+            }]
+        }"#,
+    )
+    .unwrap();
+    let loaded = SupplierFactory::new().load(&manifest).unwrap();
+    assert_eq!(loaded.registry.query("legacy", search()).unwrap().data, json!({ "name": "Lamp", "price": 9.9 }));
+
+    let invalid = Manifest::from_json(
+        r#"{ "suppliers": [{ "name": "legacy", "type": "mock", "mapping": { "fields": [{ "from": "Title", "to": "name" }] } }] }"#,
+    )
+    .unwrap();
+    assert!(matches!(SupplierFactory::new().load(&invalid), Err(SupplierError::InvalidInput(m))
+        if m.contains("supplier 'legacy'") && m.contains("Title")));
+}
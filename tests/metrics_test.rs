@@ -0,0 +1,119 @@
+use std::sync::{Arc, Mutex};
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::metrics::{GroupQuery, MetricsRecorder, SupplierCall};
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+/// `(group, supplier, operation, error code)`
+type RecordedCall = (String, String, String, Option<&'static str>);
+
+#[derive(Default)]
+struct Collector {
+    calls: Mutex<Vec<RecordedCall>>,
+    groups: Mutex<Vec<(String, usize, usize, f64)>>,
+}
+
+impl MetricsRecorder for Collector {
+    fn on_supplier_call(&self, call: &SupplierCall<'_>) {
+        self.calls.lock().unwrap().push((
+            call.group.to_string(),
+            call.supplier.to_string(),
+            call.operation.to_string(),
+            call.error.map(SupplierError::code),
+        ));
+    }
+
+    fn on_group_query(&self, query: &GroupQuery<'_>) {
+        self.groups
+            .lock()
+            .unwrap()
+            .push((query.group.to_string(), query.fan_out, query.failures, query.failure_ratio()));
+    }
+}
+
+fn group(strategy: QueryStrategy, collector: Arc<Collector>) -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new("shops")
+        .with_strategy(strategy)
+        .with_metrics(collector);
+    group.add_supplier(MockSupplierBuilder::new("a").respond_default(json!({})).build());
+    group.add_supplier(MockSupplierBuilder::new("b").build());
+    group
+}
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+#[test]
+fn supplier_calls_and_group_queries_are_recorded() {
+    for strategy in [QueryStrategy::Sequential, QueryStrategy::Parallel] {
+        let collector = Arc::new(Collector::default());
+        group(strategy, collector.clone()).query(search());
+
+        let mut calls = collector.calls.lock().unwrap().clone();
+        calls.sort();
+        assert_eq!(
+            calls,
+            vec![
+                ("shops".into(), "a".into(), "search".into(), None),
+                ("shops".into(), "b".into(), "search".into(), Some("unsupported_operation")),
+            ]
+        );
+        assert_eq!(*collector.groups.lock().unwrap(), vec![("shops".into(), 2, 1, 0.5)]);
+    }
+}
+
+#[test]
+fn batch_queries_report_each_request() {
+    let collector = Arc::new(Collector::default());
+    group(QueryStrategy::Sequential, collector.clone()).query_batch(vec![search(), search()]);
+
+    assert_eq!(collector.calls.lock().unwrap().len(), 4);
+    assert_eq!(collector.groups.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn streamed_queries_report_only_supplier_calls() {
+    let collector = Arc::new(Collector::default());
+    group(QueryStrategy::Sequential, collector.clone()).query_streamed(search()).for_each(drop);
+
+    assert_eq!(collector.calls.lock().unwrap().len(), 2);
+    assert!(collector.groups.lock().unwrap().is_empty());
+}
+
+#[cfg(feature = "otel")]
+#[test]
+fn otel_recorder_accepts_measurements() {
+    use supplier_kit::metrics::otel::OtelRecorder;
+
+    let mut group = BasicSupplierGroup::new("shops").with_metrics(Arc::new(OtelRecorder::global()));
+    group.add_supplier(MockSupplierBuilder::new("b").build());
+    assert_eq!(group.query(search()).failures.len(), 1);
+}
+
+#[test]
+fn prometheus_recorder_renders_text_format() {
+    use supplier_kit::metrics::prometheus::PrometheusRecorder;
+
+    let recorder = Arc::new(PrometheusRecorder::new());
+    let mut group = BasicSupplierGroup::new("shops").with_metrics(recorder.clone());
+    group.add_supplier(MockSupplierBuilder::new("a").respond_default(json!({})).build());
+    group.add_supplier(MockSupplierBuilder::new("b\"x").build());
+    group.query(search());
+    group.query(search());
+
+    let text = recorder.render();
+    assert!(text.contains("# TYPE supplier_kit_supplier_calls_total counter\n"));
+    assert!(text.contains(r#"supplier_kit_supplier_calls_total{group="shops",supplier="a",operation="search"} 2"#));
+    assert!(text.contains(
+        r#"supplier_kit_supplier_errors_total{group="shops",supplier="b\"x",operation="search",code="unsupported_operation"} 2"#
+    ));
+    assert!(text.contains(r#"supplier_kit_supplier_duration_seconds_count{group="shops",supplier="a",operation="search"} 2"#));
+    assert!(text.contains(r#"supplier_kit_group_partial_failures_total{group="shops"} 2"#));
+    assert!(text.contains(r#"supplier_kit_group_fan_out_bucket{group="shops",le="1"} 0"#));
+    assert!(text.contains(r#"supplier_kit_group_fan_out_bucket{group="shops",le="2"} 2"#));
+    assert!(text.contains(r#"supplier_kit_group_failure_ratio_sum{group="shops"} 1"#));
+    assert!(text.contains(r#"supplier_kit_group_duration_seconds_bucket{group="shops",le="+Inf"} 2"#));
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+use serde_json::{json, Value};
+use supplier_kit::decorators::migration::MigratingSupplier;
+use supplier_kit::descriptor::OperationDescriptor;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::migration::{Migration, MigrationRegistry};
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+/// Search v1 takes `q`, v2 renames it to `query`, v3 nests it under `filter`. Results went
+/// from a bare list (v1) to `{ "items": [...] }` (v2); v3 did not change them.
+fn search_migrations() -> MigrationRegistry {
+    MigrationRegistry::new()
+        .with(
+            Migration::new(SupplierOperation::Search, 1)
+                .upgrade_params(|params| Ok(json!({ "query": params["q"] })))
+                .downgrade_params(|params| Ok(json!({ "q": params["query"] })))
+                .upgrade_data(|data| Ok(json!({ "items": data })))
+                .downgrade_data(|data| Ok(data["items"].clone())),
+        )
+        .with(
+            Migration::new(SupplierOperation::Search, 2)
+                .upgrade_params(|params| Ok(json!({ "filter": { "text": params["query"] } })))
+                .downgrade_params(|params| Ok(json!({ "query": params["filter"]["text"] }))),
+        )
+}
+
+fn partner(version: u32) -> MockSupplier {
+    MockSupplierBuilder::new("partner")
+        .describe_operation(OperationDescriptor::new(SupplierOperation::Search).with_version(version))
+        .respond_default(json!({ "items": [{ "sku": "A-1" }] }))
+        .build()
+}
+
+fn search(params: Value) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, params)
+}
+
+#[test]
+fn old_callers_reach_a_newer_supplier() {
+    let partner = partner(3);
+    let supplier = MigratingSupplier::new(partner.clone(), Arc::new(search_migrations()));
+
+    let response = supplier.query(search(json!({ "q": "lamp" })).with_version(1)).unwrap();
+    assert_eq!(response.data, json!([{ "sku": "A-1" }]));
+
+    let sent = partner.last_request().unwrap();
+    assert_eq!(sent.params, json!({ "filter": { "text": "lamp" } }));
+    assert_eq!(sent.version, Some(3));
+}
+
+#[test]
+fn requests_can_be_downgraded_for_older_suppliers() {
+    let registry = search_migrations();
+    let v3 = search(json!({ "filter": { "text": "lamp" } })).with_version(3);
+    let v1 = registry.migrate_request(v3, 1).unwrap();
+    assert_eq!(v1.params, json!({ "q": "lamp" }));
+
+    let response = registry
+        .migrate_response(&SupplierOperation::Search, SupplierResponse::new(json!([1, 2])), 1, 3)
+        .unwrap();
+    assert_eq!(response.data, json!({ "items": [1, 2] }));
+}
+
+#[test]
+fn unversioned_requests_and_operations_pass_through() {
+    let partner = partner(3);
+    let supplier = MigratingSupplier::new(partner.clone(), Arc::new(search_migrations()));
+
+    supplier.query(search(json!({ "filter": { "text": "lamp" } }))).unwrap();
+    assert_eq!(partner.last_request().unwrap().version, None);
+
+    let detail = SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": 7 })).with_version(1);
+    supplier.query(detail).unwrap();
+    assert_eq!(partner.last_request().unwrap().params, json!({ "id": 7 }));
+}
+
+#[test]
+fn missing_steps_fail_without_calling_the_supplier() {
+    let partner = partner(4);
+    let supplier = MigratingSupplier::new(partner.clone(), Arc::new(search_migrations()));
+
+    let result = supplier.query(search(json!({ "q": "lamp" })).with_version(1));
+    assert!(matches!(result, Err(SupplierError::InvalidInput(message)) if message.contains("versions 3 and 4")));
+    assert_eq!(partner.calls(), 0);
+}
+
+#[test]
+fn versions_round_trip_through_json() {
+    let request = search(json!({})).with_version(2);
+    let json = serde_json::to_value(&request).unwrap();
+    assert_eq!(json["version"], 2);
+    assert_eq!(serde_json::from_value::<SupplierRequest>(json).unwrap(), request);
+    assert!(serde_json::to_value(search(json!({}))).unwrap().get("version").is_none());
+}
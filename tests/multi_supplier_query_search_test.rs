@@ -29,9 +29,7 @@ mod tests {
             if self.should_fail {
                 Err(SupplierError::Internal(format!("{} failed", self.name)))
             } else {
-                Ok(SupplierResponse {
-                    data: json!({"echo": request.params}),
-                })
+                Ok(SupplierResponse::new(json!({"echo": request.params})))
             }
         }
     }
@@ -43,10 +41,7 @@ mod tests {
         group.add_supplier(MockSupplier::new("mock2", true));  // fail
         group.add_supplier(MockSupplier::new("mock3", false)); // success
 
-        let request = SupplierRequest {
-            operation: SupplierOperation::Search,
-            params: json!({"query": "partial"}),
-        };
+        let request = SupplierRequest::new(SupplierOperation::Search, json!({"query": "partial"}));
 
         let result = group.query(request);
         assert_eq!(result.successes.len(), 2);
@@ -66,10 +61,7 @@ mod tests {
         group.add_supplier(MockSupplier::new("mock1", true));
         group.add_supplier(MockSupplier::new("mock2", true));
 
-        let request = SupplierRequest {
-            operation: SupplierOperation::Search,
-            params: json!({"query": "fail-all"}),
-        };
+        let request = SupplierRequest::new(SupplierOperation::Search, json!({"query": "fail-all"}));
 
         let result = group.query(request);
         assert_eq!(result.successes.len(), 0);
@@ -90,10 +82,7 @@ mod tests {
         group.add_supplier(MockSupplier::new("mock1", false));
         group.add_supplier(MockSupplier::new("mock2", false));
 
-        let request = SupplierRequest {
-            operation: SupplierOperation::Search,
-            params: json!({"query": "test"}),
-        };
+        let request = SupplierRequest::new(SupplierOperation::Search, json!({"query": "test"}));
 
         let result = group.query(request);
         assert_eq!(result.successes.len(), 2);
@@ -0,0 +1,66 @@
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::{NameNormalization, SupplierRegistry};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn registry() -> SupplierRegistry {
+    let mut registry = SupplierRegistry::new();
+    registry.set_name_normalization(NameNormalization::all()).unwrap();
+    registry.register("Amazon", MockSupplierBuilder::new("amazon").respond_default(json!({ "shop": "amazon" })).build());
+    registry
+}
+
+#[test]
+fn names_from_user_input_find_the_supplier() {
+    let registry = registry();
+    assert_eq!(registry.all_names(), ["amazon"]);
+    assert!(registry.get("Amazon ").is_some());
+    assert!(registry.contains("\u{3000}ＡＭＡＺＯＮ"));
+
+    let request = SupplierRequest::new(SupplierOperation::Search, json!({}));
+    assert_eq!(registry.query(" AMAZON", request).unwrap().data["shop"], "amazon");
+}
+
+#[test]
+fn folding_removes_accents_in_composed_and_decomposed_form() {
+    let normalization = NameNormalization { fold_unicode: true, ..Default::default() };
+    assert_eq!(normalization.apply("Café"), "Cafe");
+    assert_eq!(normalization.apply("Cafe\u{0301}"), "Cafe");
+    assert_eq!(normalization.apply("Zürich"), "Zurich");
+
+    let lowercase = NameNormalization { lowercase: true, ..Default::default() };
+    assert_eq!(lowercase.apply(" ÉTÉ "), " été ");
+}
+
+#[test]
+fn aliases_and_registry_features_are_normalized() {
+    let mut registry = registry();
+    registry.alias(" AMZN ", "AMAZON").unwrap();
+    assert_eq!(registry.aliases_of("Amazon"), ["amzn"]);
+    assert_eq!(registry.resolve("Amzn"), "amazon");
+    assert!(matches!(registry.alias("Amazon", "amazon"), Err(SupplierError::InvalidInput(_))));
+
+    assert_eq!(registry.unalias("amzn ").as_deref(), Some("amazon"));
+    assert!(registry.remove(" AMAZON ").is_some());
+    assert!(registry.all_names().is_empty());
+}
+
+#[test]
+fn normalization_can_be_enabled_on_a_populated_registry() {
+    let mut registry = SupplierRegistry::new();
+    registry.register("Ebay", MockSupplierBuilder::new("ebay").build());
+    registry.alias("Bay", "Ebay").unwrap();
+    assert!(registry.get("ebay").is_none());
+
+    registry.set_name_normalization(NameNormalization::all()).unwrap();
+    assert_eq!(registry.resolve("BAY"), "ebay");
+
+    let mut clashing = SupplierRegistry::new();
+    clashing.register("Shop", MockSupplierBuilder::new("a").build());
+    clashing.register("shop", MockSupplierBuilder::new("b").build());
+    let error = clashing.set_name_normalization(NameNormalization::all()).unwrap_err();
+    assert!(matches!(error, SupplierError::AlreadyExists(name) if name == "shop"));
+    assert_eq!(clashing.name_normalization(), NameNormalization::default());
+    assert_eq!(clashing.all_names().len(), 2);
+}
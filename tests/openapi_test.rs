@@ -0,0 +1,110 @@
+#![cfg(feature = "openapi")]
+
+use serde_json::json;
+use supplier_kit::descriptor::{OperationDescriptor, RateLimit, SupplierDescriptor};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::openapi::OpenApiGenerator;
+use supplier_kit::supplier::{Supplier, SupplierRegistry};
+
+struct Shop;
+
+impl Supplier for Shop {
+    fn name(&self) -> &str {
+        "shop"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!([])))
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        SupplierDescriptor::new("shop")
+            .with_version("2.0.0")
+            .with_operation(
+                OperationDescriptor::new(SupplierOperation::Search)
+                    .with_summary("Search products")
+                    .with_params_schema(json!({ "type": "object", "required": ["q"] }))
+                    .with_response_schema(json!({ "type": "array" })),
+            )
+            .with_operation(OperationDescriptor::new(SupplierOperation::Other("track_order".into())))
+            .with_rate_limit(RateLimit { max_requests: 5, window_ms: 1000 })
+    }
+}
+
+struct Undescribed;
+
+impl Supplier for Undescribed {
+    fn name(&self) -> &str {
+        "undescribed"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!([])))
+    }
+}
+
+fn registry() -> SupplierRegistry {
+    let mut registry = SupplierRegistry::new();
+    registry.register("shop_a", Shop);
+    registry.register("legacy", Undescribed);
+    registry
+}
+
+#[test]
+fn one_path_per_supplier_and_declared_operation() {
+    let spec = OpenApiGenerator::new("Marketplace", "1.0.0")
+        .with_server("https://api.example.com")
+        .with_path_prefix("/api/suppliers/")
+        .generate(&registry());
+
+    assert_eq!(spec["info"]["title"], "Marketplace");
+    assert_eq!(spec["servers"][0]["url"], "https://api.example.com");
+
+    let paths = spec["paths"].as_object().unwrap();
+    let mut keys: Vec<_> = paths.keys().cloned().collect();
+    keys.sort();
+    assert_eq!(keys, ["/api/suppliers/shop_a/search", "/api/suppliers/shop_a/track_order"]);
+    assert_eq!(spec["tags"][0]["description"], "version 2.0.0");
+}
+
+#[test]
+fn declared_schemas_are_used_for_bodies_and_responses() {
+    let spec = OpenApiGenerator::new("Marketplace", "1.0.0").generate(&registry());
+    let search = &spec["paths"]["/suppliers/shop_a/search"]["post"];
+
+    assert_eq!(search["operationId"], "shop_a_search");
+    assert_eq!(search["summary"], "Search products");
+    assert_eq!(search["requestBody"]["content"]["application/json"]["schema"]["required"][0], "q");
+    let ok = &search["responses"]["200"]["content"]["application/json"]["schema"];
+    assert_eq!(ok["properties"]["data"]["type"], "array");
+    assert_eq!(search["x-rate-limit"]["max_requests"], 5);
+
+    let track = &spec["paths"]["/suppliers/shop_a/track_order"]["post"];
+    assert_eq!(track["requestBody"]["content"]["application/json"]["schema"]["type"], "object");
+}
+
+#[test]
+fn error_schema_lists_every_error_code() {
+    let spec = OpenApiGenerator::new("Marketplace", "1.0.0").generate(&registry());
+    let codes = &spec["components"]["schemas"]["SupplierError"]["properties"]["code"]["enum"];
+
+    for error in [
+        SupplierError::Timeout,
+        SupplierError::Unauthorized,
+        SupplierError::NotFound,
+        SupplierError::Internal(String::new()),
+        SupplierError::Upstream(String::new()),
+        SupplierError::InvalidInput(String::new()),
+        SupplierError::UnsupportedOperation(String::new()),
+        SupplierError::ConcurrencyLimitExceeded(String::new()),
+        SupplierError::RateLimited(String::new()),
+        SupplierError::Overloaded(String::new()),
+        SupplierError::AlreadyExists(String::new()),
+        SupplierError::ResponseTooLarge(String::new()),
+        SupplierError::Throttled { retry_after: None },
+        SupplierError::InsufficientSuppliers(String::new()),
+    ] {
+        assert!(codes.as_array().unwrap().contains(&json!(error.code())), "{}", error.code());
+    }
+}
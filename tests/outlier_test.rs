@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread::sleep;
+use std::time::Duration;
+use serde_json::{json, Value};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::outlier::OutlierDetector;
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+
+struct Shop {
+    name: &'static str,
+    fail: bool,
+    calls: Arc<AtomicUsize>,
+}
+
+impl Supplier for Shop {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if self.fail {
+            return Err(SupplierError::Internal("down".into()));
+        }
+        Ok(SupplierResponse::new(json!({ "supplier": self.name })))
+    }
+}
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, Value::Null)
+}
+
+fn group(strategy: QueryStrategy, detector: Arc<OutlierDetector>) -> (BasicSupplierGroup, Arc<AtomicUsize>) {
+    let broken_calls = Arc::new(AtomicUsize::new(0));
+    let mut group = BasicSupplierGroup::new("shops")
+        .with_strategy(strategy)
+        .with_outlier_detection(detector);
+    group.add_supplier(Shop { name: "steady", fail: false, calls: Arc::new(AtomicUsize::new(0)) });
+    group.add_supplier(Shop { name: "broken", fail: true, calls: broken_calls.clone() });
+    (group, broken_calls)
+}
+
+#[test]
+fn failing_supplier_is_ejected_from_fan_out() {
+    for strategy in [QueryStrategy::Sequential, QueryStrategy::Parallel] {
+        let detector = Arc::new(OutlierDetector::new(0.5, Duration::from_secs(60)).with_min_samples(2));
+        let (group, broken_calls) = group(strategy, detector.clone());
+
+        assert_eq!(group.query(search()).failures.len(), 1);
+        assert_eq!(group.query(search()).failures.len(), 1);
+        assert_eq!(detector.ejected(), vec!["broken".to_string()]);
+
+        let result = group.query(search());
+        assert_eq!(result.successes.len(), 1);
+        assert!(result.failures.is_empty());
+        assert_eq!(group.query_streamed(search()).count(), 1);
+        assert_eq!(group.query_batch(vec![search()])[0].failures.len(), 0);
+        assert_eq!(broken_calls.load(Ordering::SeqCst), 2);
+    }
+}
+
+#[test]
+fn targeted_queries_still_reach_ejected_suppliers() {
+    let detector = Arc::new(OutlierDetector::new(0.0, Duration::from_secs(60)).with_min_samples(1));
+    let (group, broken_calls) = group(QueryStrategy::Sequential, detector.clone());
+    group.query(search());
+    assert!(detector.is_ejected("broken"));
+
+    let result = group.query_each(HashMap::from([("broken".to_string(), search())]));
+    assert!(matches!(result.failures[0].1, SupplierError::Internal(_)));
+    assert_eq!(broken_calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn supplier_is_readmitted_after_probation() {
+    let detector = OutlierDetector::new(0.5, Duration::from_millis(30)).with_min_samples(1);
+    detector.record("a", false, Duration::ZERO);
+    assert!(detector.is_ejected("a"));
+    assert_eq!(detector.ejections("a"), 1);
+
+    sleep(Duration::from_millis(50));
+    assert!(!detector.is_ejected("a"));
+    assert!(detector.ejected().is_empty());
+}
+
+#[test]
+fn slow_supplier_is_ejected() {
+    let detector = OutlierDetector::new(1.0, Duration::from_secs(60))
+        .with_min_samples(2)
+        .with_max_latency(Duration::from_millis(100));
+    detector.record("slow", true, Duration::from_millis(150));
+    assert!(!detector.is_ejected("slow"));
+    detector.record("slow", true, Duration::from_millis(100));
+    assert!(detector.is_ejected("slow"));
+
+    detector.readmit("slow");
+    assert!(!detector.is_ejected("slow"));
+}
+
+#[test]
+fn old_outcomes_leave_the_window() {
+    let detector = OutlierDetector::new(0.5, Duration::from_secs(60))
+        .with_window(2)
+        .with_min_samples(2);
+    detector.record("a", false, Duration::ZERO);
+    detector.record("a", true, Duration::ZERO);
+    detector.record("a", true, Duration::ZERO);
+    detector.record("a", false, Duration::ZERO);
+    assert!(!detector.is_ejected("a"));
+}
@@ -0,0 +1,86 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use serde_json::{json, Value};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::pagination::{GroupCursor, Paginator};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::BasicSupplierGroup;
+
+/// Serves `total` items in pages of `page_size`, using an offset cursor.
+struct OffsetSupplier {
+    name: &'static str,
+    total: u64,
+    page_size: u64,
+}
+
+impl Supplier for OffsetSupplier {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let offset = request.params["cursor"].as_u64().unwrap_or(0);
+        let end = (offset + self.page_size).min(self.total);
+        let items: Vec<String> = (offset..end).map(|i| format!("{}-{}", self.name, i)).collect();
+        let next = if end < self.total { json!(end) } else { Value::Null };
+        Ok(SupplierResponse::new(json!({ "items": items, "next_cursor": next })))
+    }
+}
+
+/// Uses string page tokens and fails on its second call.
+struct TokenSupplier {
+    calls: AtomicUsize,
+}
+
+impl Supplier for TokenSupplier {
+    fn name(&self) -> &str {
+        "token"
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        if self.calls.fetch_add(1, Ordering::SeqCst) == 1 {
+            return Err(SupplierError::Timeout);
+        }
+        match request.params["cursor"].as_str() {
+            None => Ok(SupplierResponse::new(json!({ "items": ["token-0"], "next_cursor": "p2" }))),
+            Some("p2") => Ok(SupplierResponse::new(json!({ "items": ["token-1"] }))),
+            Some(other) => Err(SupplierError::InvalidInput(other.to_string())),
+        }
+    }
+}
+
+#[test]
+fn test_each_supplier_resumes_from_its_own_cursor() {
+    let mut group = BasicSupplierGroup::new("catalog");
+    group.add_supplier(OffsetSupplier { name: "big", total: 5, page_size: 2 });
+    group.add_supplier(OffsetSupplier { name: "small", total: 1, page_size: 2 });
+    group.add_supplier(TokenSupplier { calls: AtomicUsize::new(0) });
+
+    let paginator = Paginator::default();
+    let request = SupplierRequest::new(SupplierOperation::Search, json!({ "q": "shoes" }));
+
+    let mut items = Vec::new();
+    let mut token: Option<String> = None;
+    for _ in 0..10 {
+        let cursor = token.as_deref().map(|t| GroupCursor::decode(t).unwrap());
+        let page = paginator.query_page(&group, request.clone(), cursor.as_ref());
+        for (_, response) in &page.result.successes {
+            items.extend(response.data["items"].as_array().unwrap().iter().map(|v| v.as_str().unwrap().to_string()));
+        }
+        match page.next {
+            Some(next) => token = Some(next.encode()),
+            None => break,
+        }
+    }
+
+    items.sort();
+    assert_eq!(
+        items,
+        ["big-0", "big-1", "big-2", "big-3", "big-4", "small-0", "token-0", "token-1"]
+    );
+}
+
+#[test]
+fn test_malformed_cursor_is_rejected() {
+    assert!(matches!(GroupCursor::decode("%%%"), Err(SupplierError::InvalidInput(_))));
+}
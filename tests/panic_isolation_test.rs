@@ -0,0 +1,60 @@
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::{Supplier, SupplierRegistry};
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+
+struct PanickingSupplier;
+
+impl Supplier for PanickingSupplier {
+    fn name(&self) -> &str {
+        "panicky"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        panic!("index out of bounds in vendor parser")
+    }
+}
+
+struct HealthySupplier;
+
+impl Supplier for HealthySupplier {
+    fn name(&self) -> &str {
+        "healthy"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!({ "ok": true })))
+    }
+}
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+#[test]
+fn test_group_query_survives_panicking_supplier() {
+    let mut group = BasicSupplierGroup::new("g");
+    group.add_supplier(PanickingSupplier);
+    group.add_supplier(HealthySupplier);
+
+    let result = group.query(request());
+    assert_eq!(result.successes.len(), 1);
+    assert_eq!(result.failures.len(), 1);
+
+    let (name, err) = &result.failures[0];
+    assert_eq!(name, "panicky");
+    match err {
+        SupplierError::Internal(msg) => assert!(msg.contains("vendor parser")),
+        other => panic!("Unexpected error variant: {:?}", other),
+    }
+}
+
+#[test]
+fn test_registry_query_converts_panic() {
+    let mut registry = SupplierRegistry::new();
+    registry.register("panicky", PanickingSupplier);
+
+    let result = registry.query("panicky", request());
+    assert!(matches!(result, Err(SupplierError::Internal(_))));
+}
@@ -0,0 +1,92 @@
+use std::sync::{Arc, Mutex};
+use serde_json::json;
+use supplier_kit::audit::{AuditLog, AuditRecord, ChannelSink};
+use supplier_kit::composite::CompositeSupplier;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::metrics::{MetricsRecorder, SupplierCall};
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::path::SupplierPath;
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+#[test]
+fn paths_round_trip_through_their_rendered_form() {
+    let path = SupplierPath::new("eu").child("amazon~de").child("mobile/app");
+    assert_eq!(path.to_string(), "eu/amazon~0de/mobile~1app");
+    assert_eq!(path.to_string().parse::<SupplierPath>().unwrap(), path);
+    assert_eq!(path.root(), "eu");
+    assert_eq!(path.leaf(), "mobile/app");
+    assert_eq!(path.parent().unwrap().to_string(), "eu/amazon~0de");
+    assert_eq!(SupplierPath::new("eu").parent(), None);
+}
+
+#[test]
+fn joined_paths_keep_every_segment() {
+    let region = SupplierPath::new("eu");
+    let member: SupplierPath = "amazon/de".parse().unwrap();
+    let path = region.join(&member);
+    assert_eq!(path.segments(), ["eu", "amazon", "de"]);
+    assert!(path.starts_with(&region));
+    assert!(!region.starts_with(&path));
+}
+
+#[test]
+fn malformed_paths_are_rejected() {
+    for path in ["", "eu//de", "eu/", "eu/~2", "eu/de~"] {
+        assert!(matches!(SupplierPath::parse(path), Err(SupplierError::InvalidInput(_))), "{path}");
+    }
+}
+
+#[test]
+fn paths_serialize_as_strings() {
+    let path = SupplierPath::new("eu").child("amazon/de");
+    assert_eq!(serde_json::to_value(&path).unwrap(), json!("eu/amazon~1de"));
+    assert_eq!(serde_json::from_value::<SupplierPath>(json!("eu/amazon~1de")).unwrap(), path);
+    assert!(serde_json::from_value::<SupplierPath>(json!("eu//de")).is_err());
+}
+
+#[test]
+fn flattened_names_never_collide() {
+    let mut amazon = BasicSupplierGroup::new("amazon");
+    amazon.add_supplier(MockSupplierBuilder::new("de").respond_default(json!("nested")).build());
+    let mut eu = BasicSupplierGroup::new("eu").with_flattened_results();
+    eu.add_supplier(CompositeSupplier::new(Arc::new(amazon)));
+    eu.add_supplier(MockSupplierBuilder::new("amazon/de").respond_default(json!("plain")).build());
+    let mut global = BasicSupplierGroup::new("global").with_flattened_results();
+    global.add_supplier(CompositeSupplier::new(Arc::new(eu)));
+
+    let result = global.query(search());
+    let names: Vec<&str> = result.successes.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["eu/amazon/de", "eu/amazon~1de"]);
+    let paths: Vec<SupplierPath> = names.iter().map(|name| name.parse().unwrap()).collect();
+    assert_eq!(paths[0].segments(), ["eu", "amazon", "de"]);
+    assert_eq!(paths[1].segments(), ["eu", "amazon/de"]);
+}
+
+#[derive(Default)]
+struct PathRecorder(Mutex<Vec<SupplierPath>>);
+
+impl MetricsRecorder for PathRecorder {
+    fn on_supplier_call(&self, call: &SupplierCall<'_>) {
+        self.0.lock().unwrap().push(call.path());
+    }
+}
+
+#[test]
+fn metrics_and_audit_records_name_suppliers_by_path() {
+    let recorder = Arc::new(PathRecorder::default());
+    let (sink, receiver) = ChannelSink::new();
+    let mut group = BasicSupplierGroup::new("eu")
+        .with_metrics(recorder.clone())
+        .with_audit(Arc::new(AuditLog::new(sink)));
+    group.add_supplier(MockSupplierBuilder::new("amazon/de").respond_default(json!([])).build());
+    group.query(search());
+
+    assert_eq!(recorder.0.lock().unwrap()[0].to_string(), "eu/amazon~1de");
+    let records: Vec<AuditRecord> = receiver.try_iter().collect();
+    assert_eq!(records[0].path().segments(), ["eu", "amazon/de"]);
+}
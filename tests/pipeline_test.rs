@@ -0,0 +1,222 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::orchestration::pipeline::Pipeline;
+use supplier_kit::supplier::SupplierRegistry;
+use supplier_kit::supplier_group::BasicSupplierGroup;
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn search(q: &str) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": q }))
+}
+
+#[test]
+fn group_search_feeds_detail_query_on_the_winning_supplier() {
+    let shop_a = MockSupplierBuilder::new("shop_a")
+        .respond(SupplierOperation::Search, json!({ "id": "a-1", "price": 120 }))
+        .respond(SupplierOperation::GetDetail, json!({ "from": "shop_a" }))
+        .build();
+    let shop_b = MockSupplierBuilder::new("shop_b")
+        .respond(SupplierOperation::Search, json!({ "id": "b-9", "price": 80 }))
+        .respond(SupplierOperation::GetDetail, json!({ "from": "shop_b" }))
+        .build();
+    let broken = MockSupplierBuilder::new("broken")
+        .then_fail(SupplierError::Timeout)
+        .build();
+
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.add_supplier(shop_a.clone());
+    group.add_supplier(shop_b.clone());
+    group.add_supplier(broken);
+    let mut registry = SupplierRegistry::new();
+    registry.register("shop_a", shop_a.clone());
+    registry.register("shop_b", shop_b.clone());
+
+    let result = Pipeline::new()
+        .query_group("search", Arc::new(group), |_| Ok(search("lamp")))
+        .map("cheapest", |ctx| {
+            let offers = ctx.pointer("search", "/successes")?.as_object().unwrap();
+            let (supplier, offer) = offers.iter().min_by_key(|(_, o)| o["price"].as_u64()).unwrap();
+            Ok(json!({ "supplier": supplier, "id": offer["id"] }))
+        })
+        .after(&["search"])
+        .query_registry("detail", Arc::new(registry), |ctx| {
+            let supplier = ctx.pointer("cheapest", "/supplier")?.as_str().unwrap().to_string();
+            let id = ctx.pointer("cheapest", "/id")?.clone();
+            Ok((supplier, SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": id }))))
+        })
+        .after(&["cheapest"])
+        .run()
+        .unwrap();
+
+    assert!(result.is_success());
+    assert_eq!(result.output("search").unwrap()["failures"]["broken"]["code"], "timeout");
+    assert_eq!(result.output("detail").unwrap()["from"], "shop_b");
+    assert_eq!(shop_b.last_request().unwrap().params["id"], "b-9");
+    assert_eq!(shop_a.calls(), 1);
+}
+
+#[test]
+fn independent_branches_run_in_parallel_and_join() {
+    let slow = |name: &str| {
+        Arc::new(
+            MockSupplierBuilder::new(name)
+                .respond_default(json!({ "from": name }))
+                .with_delay(Duration::from_millis(150))
+                .build(),
+        )
+    };
+
+    let started = Instant::now();
+    let result = Pipeline::new()
+        .query("flights", slow("flights"), |_| Ok(search("CGK")))
+        .query("hotels", slow("hotels"), |_| Ok(search("Bali")))
+        .map("trip", |ctx| {
+            Ok(json!({
+                "flight": ctx.pointer("flights", "/from")?,
+                "hotel": ctx.pointer("hotels", "/from")?,
+            }))
+        })
+        .after(&["flights", "hotels"])
+        .run()
+        .unwrap();
+
+    assert!(started.elapsed() < Duration::from_millis(290));
+    assert_eq!(result.output("trip").unwrap(), &json!({ "flight": "flights", "hotel": "hotels" }));
+}
+
+#[test]
+fn failed_stage_skips_dependents_but_not_other_branches() {
+    let failing = Arc::new(MockSupplierBuilder::new("failing").then_fail(SupplierError::Timeout).build());
+    let healthy = Arc::new(MockSupplierBuilder::new("healthy").respond_default(json!({ "ok": true })).build());
+
+    let result = Pipeline::new()
+        .query("a", failing, |_| Ok(search("x")))
+        .map("b", |ctx| Ok(ctx.output("a").cloned().unwrap_or_default()))
+        .after(&["a"])
+        .map("c", |ctx| Ok(ctx.output("b").cloned().unwrap_or_default()))
+        .after(&["b"])
+        .query("d", healthy, |_| Ok(search("y")))
+        .run()
+        .unwrap();
+
+    assert!(!result.is_success());
+    assert!(matches!(result.failures["a"], SupplierError::Timeout));
+    assert_eq!(result.skipped, vec!["b", "c"]);
+    assert_eq!(result.output("d").unwrap()["ok"], true);
+}
+
+#[test]
+fn mapping_errors_fail_the_stage() {
+    let result = Pipeline::new()
+        .map("source", |_| Ok(json!({ "items": [] })))
+        .map("first", |ctx| ctx.pointer("source", "/items/0").cloned())
+        .after(&["source"])
+        .run()
+        .unwrap();
+
+    assert!(matches!(result.failures["first"], SupplierError::InvalidInput(_)));
+}
+
+#[test]
+fn invalid_graphs_are_rejected_before_running() {
+    let unknown = Pipeline::new().map("a", |_| Ok(json!(1))).after(&["missing"]).run();
+    assert!(matches!(unknown, Err(SupplierError::InvalidInput(msg)) if msg.contains("unknown stage 'missing'")));
+
+    let duplicate = Pipeline::new().map("a", |_| Ok(json!(1))).map("a", |_| Ok(json!(2))).run();
+    assert!(matches!(duplicate, Err(SupplierError::InvalidInput(msg)) if msg.contains("declared twice")));
+
+    let cycle = Pipeline::new()
+        .map("a", |_| Ok(json!(1)))
+        .after(&["b"])
+        .map("b", |_| Ok(json!(2)))
+        .after(&["a"])
+        .run();
+    assert!(matches!(cycle, Err(SupplierError::InvalidInput(msg)) if msg.contains("cycle")));
+}
+
+#[test]
+fn layers_group_stages_in_topological_order() {
+    let pipeline = Pipeline::new()
+        .map("quote", |ctx| Ok(ctx.output("pricing").cloned().unwrap_or_default()))
+        .after(&["pricing", "stock", "shipping"])
+        .map("pricing", |_| Ok(json!(1)))
+        .map("stock", |_| Ok(json!(2)))
+        .map("shipping", |_| Ok(json!(3)))
+        .after(&["stock"]);
+
+    assert_eq!(
+        pipeline.layers().unwrap(),
+        vec![vec!["pricing", "stock"], vec!["shipping"], vec!["quote"]]
+    );
+}
+
+#[test]
+fn stages_start_as_soon_as_their_own_dependencies_finish() {
+    let slow = Arc::new(
+        MockSupplierBuilder::new("slow")
+            .respond_default(json!({ "done": true }))
+            .with_delay(Duration::from_millis(300))
+            .build(),
+    );
+    let fast = Arc::new(
+        MockSupplierBuilder::new("fast")
+            .respond_default(json!({ "done": true }))
+            .with_delay(Duration::from_millis(20))
+            .build(),
+    );
+
+    let started = Instant::now();
+    let result = Pipeline::new()
+        .query("shipping", slow, |_| Ok(search("x")))
+        .query("pricing", fast.clone(), |_| Ok(search("x")))
+        .map("discount", move |_| Ok(json!(started.elapsed().as_millis() as u64)))
+        .after(&["pricing"])
+        .run()
+        .unwrap();
+
+    assert!(result.is_success());
+    assert!(result.output("discount").unwrap().as_u64().unwrap() < 250);
+}
+
+#[test]
+fn max_concurrency_bounds_parallel_stages() {
+    let stage = |name: &str| {
+        Arc::new(
+            MockSupplierBuilder::new(name)
+                .respond_default(json!({}))
+                .with_delay(Duration::from_millis(100))
+                .build(),
+        )
+    };
+
+    let started = Instant::now();
+    let result = Pipeline::new()
+        .with_max_concurrency(2)
+        .query("pricing", stage("pricing"), |_| Ok(search("x")))
+        .query("stock", stage("stock"), |_| Ok(search("x")))
+        .query("shipping", stage("shipping"), |_| Ok(search("x")))
+        .query("reviews", stage("reviews"), |_| Ok(search("x")))
+        .run()
+        .unwrap();
+
+    assert!(result.is_success());
+    let elapsed = started.elapsed();
+    assert!(elapsed >= Duration::from_millis(200), "{elapsed:?}");
+    assert!(elapsed < Duration::from_millis(390), "{elapsed:?}");
+}
+
+#[test]
+fn panicking_stage_is_reported_as_a_failure() {
+    let result = Pipeline::new()
+        .map("boom", |_| panic!("bad mapping"))
+        .map("next", |_| Ok(json!(1)))
+        .after(&["boom"])
+        .run()
+        .unwrap();
+
+    assert!(matches!(result.failures["boom"], SupplierError::Internal(_)));
+    assert_eq!(result.skipped, vec!["next"]);
+}
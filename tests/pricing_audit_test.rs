@@ -0,0 +1,67 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::pricing_audit::PriceAudit;
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::BasicSupplierGroup;
+
+/// Returns the next price from a fixed list on every call.
+struct ScriptedPrices {
+    name: &'static str,
+    prices: Vec<Option<f64>>,
+    call: AtomicUsize,
+}
+
+impl ScriptedPrices {
+    fn new(name: &'static str, prices: Vec<Option<f64>>) -> Self {
+        Self { name, prices, call: AtomicUsize::new(0) }
+    }
+}
+
+impl Supplier for ScriptedPrices {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let call = self.call.fetch_add(1, Ordering::SeqCst);
+        match self.prices[call % self.prices.len()] {
+            Some(price) => Ok(SupplierResponse::new(json!({ "price": price, "in_stock": true }))),
+            None => Err(SupplierError::Timeout),
+        }
+    }
+}
+
+#[test]
+fn test_report_tracks_cheapest_and_variance() {
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.add_supplier(ScriptedPrices::new("a", vec![Some(10.0), Some(12.0), Some(8.0)]));
+    group.add_supplier(ScriptedPrices::new("b", vec![Some(9.0), None, Some(9.0)]));
+
+    let request = SupplierRequest::new(SupplierOperation::GetDetail, json!({ "sku": "X-1" }));
+
+    let mut audit = PriceAudit::from_pointers("/price", "/in_stock");
+    audit.run_schedule(&group, request, Duration::ZERO, 3);
+
+    let report = audit.report();
+    assert_eq!(report.rounds, 3);
+    assert_eq!(
+        report.cheapest_per_round,
+        [Some("b".to_string()), Some("a".to_string()), Some("a".to_string())]
+    );
+    assert_eq!(report.overall_cheapest(), Some("a"));
+
+    let a = &report.suppliers["a"];
+    assert_eq!(a.quoted, 3);
+    assert_eq!(a.min, 8.0);
+    assert_eq!(a.max, 12.0);
+    assert!((a.mean - 10.0).abs() < 1e-9);
+    assert!((a.variance - 8.0 / 3.0).abs() < 1e-9);
+
+    let b = &report.suppliers["b"];
+    assert_eq!(b.quoted, 2);
+    assert_eq!(b.missing, 1);
+    assert_eq!(b.variance, 0.0);
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+use std::thread;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+fn shop(name: &str) -> MockSupplier {
+    MockSupplierBuilder::new(name).respond_default(json!({ "shop": name })).build()
+}
+
+fn order(result: &supplier_kit::supplier_group::SupplierGroupResult) -> Vec<&str> {
+    result.successes.iter().map(|(name, _)| name.as_str()).collect()
+}
+
+#[test]
+fn sequential_queries_follow_descending_priority() {
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.add_supplier(shop("plain"));
+    group.add_supplier_with_priority(shop("low"), -5);
+    group.add_supplier_with_priority(shop("high"), 10);
+    group.add_supplier_with_priority(shop("also_high"), 10);
+
+    assert_eq!(order(&group.query(request())), ["high", "also_high", "plain", "low"]);
+    assert_eq!(group.priority("plain"), 0);
+    assert_eq!(group.priority("low"), -5);
+
+    let plan: Vec<String> = group.plan(&request()).into_iter().map(|p| p.name).collect();
+    assert_eq!(plan, ["high", "also_high", "plain", "low"]);
+}
+
+#[test]
+fn failover_tries_the_highest_priority_first() {
+    let primary = shop("primary");
+    let backup = shop("backup");
+    let mut group = BasicSupplierGroup::new("payments").with_strategy(QueryStrategy::Failover);
+    group.add_supplier(backup.clone());
+    group.add_supplier_with_priority(primary.clone(), 1);
+
+    assert_eq!(order(&group.query(request())), ["primary"]);
+    assert_eq!((primary.calls(), backup.calls()), (1, 0));
+}
+
+#[test]
+fn priorities_can_be_tuned_while_the_group_is_shared() {
+    let primary = shop("primary");
+    let backup = shop("backup");
+    let mut group = BasicSupplierGroup::new("payments").with_strategy(QueryStrategy::Failover);
+    group.add_supplier(primary.clone());
+    group.add_supplier(backup.clone());
+    let group = Arc::new(group);
+
+    let tuner = {
+        let group = group.clone();
+        thread::spawn(move || group.set_priority("backup", 5))
+    };
+    tuner.join().unwrap().unwrap();
+
+    assert_eq!(order(&group.query(request())), ["backup"]);
+    let streamed: Vec<String> = group.query_streamed(request()).map(|(name, _)| name).collect();
+    assert_eq!(streamed, ["backup"]);
+    assert!(matches!(group.set_priority("unknown", 1), Err(SupplierError::NotFound)));
+}
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::health::HealthMap;
+use supplier_kit::models::{SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::{Supplier, SupplierRegistry};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+/// A supplier still warming up.
+struct Warming;
+
+impl Supplier for Warming {
+    fn name(&self) -> &str {
+        "warming"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Err(SupplierError::Timeout)
+    }
+
+    fn is_ready(&self) -> bool {
+        false
+    }
+}
+
+fn registry() -> SupplierRegistry {
+    let mut registry = SupplierRegistry::new();
+    for name in ["shop_a", "shop_b", "reviews"] {
+        registry.register(name, MockSupplierBuilder::new(name).respond_default(json!([])).build());
+    }
+    registry
+}
+
+#[test]
+fn readiness_requires_every_supplier_when_none_is_critical() {
+    let mut registry = registry();
+    let health = Arc::new(HealthMap::new());
+    registry.set_health_map(health.clone(), None);
+    assert!(registry.readiness().ok);
+
+    health.record("reviews", Err(SupplierError::Timeout), Duration::ZERO, 1);
+    let report = registry.readiness();
+    assert!(!report.ok);
+    assert_eq!(report.required, 3);
+    assert_eq!(report.passing, ["shop_a", "shop_b"]);
+    assert_eq!(report.failing, ["reviews"]);
+    assert_eq!(report.reason.as_deref(), Some("2 of 3 required critical suppliers are healthy"));
+}
+
+#[test]
+fn readiness_counts_healthy_critical_suppliers() {
+    let mut registry = registry();
+    let health = Arc::new(HealthMap::new());
+    registry.set_health_map(health.clone(), None);
+    registry.set_critical("shop_a", true).unwrap();
+    registry.set_critical("shop_b", true).unwrap();
+    registry.set_min_ready(1);
+    assert!(matches!(registry.set_critical("missing", true), Err(SupplierError::NotFound)));
+    assert_eq!(registry.critical_names(), ["shop_a", "shop_b"]);
+
+    health.record("reviews", Err(SupplierError::Timeout), Duration::ZERO, 1);
+    health.record("shop_a", Err(SupplierError::Timeout), Duration::ZERO, 1);
+    let report = registry.readiness();
+    assert!(report.ok && report.reason.is_none());
+    assert_eq!((report.passing, report.failing), (vec!["shop_b".to_string()], vec!["shop_a".to_string()]));
+
+    health.record("shop_b", Err(SupplierError::Timeout), Duration::ZERO, 1);
+    assert!(!registry.readiness().ok);
+
+    registry.remove("shop_b");
+    registry.set_critical("shop_a", false).unwrap();
+    assert!(registry.critical_names().is_empty());
+    assert_eq!(registry.readiness().required, 1);
+}
+
+#[test]
+fn readiness_reports_unready_suppliers_without_a_health_map() {
+    let mut registry = registry();
+    registry.register("warming", Warming);
+    registry.set_critical("warming", true).unwrap();
+
+    let report = registry.readiness();
+    assert!(!report.ok);
+    assert_eq!(report.failing, ["warming"]);
+    assert_eq!(
+        serde_json::to_value(&report).unwrap(),
+        json!({ "ok": false, "required": 1, "passing": [], "failing": ["warming"], "reason": "0 of 1 required critical suppliers are healthy" })
+    );
+}
+
+#[test]
+fn liveness_ignores_outages_but_fails_on_stale_checks() {
+    let mut registry = registry();
+    assert!(registry.liveness().ok);
+
+    let health = Arc::new(HealthMap::new());
+    registry.set_health_map(health.clone(), Some(Duration::from_millis(50)));
+    health.record("shop_a", Err(SupplierError::Timeout), Duration::ZERO, 1);
+    let report = registry.liveness();
+    assert!(report.ok);
+    assert_eq!(report.passing, ["shop_a"]);
+    assert!(!registry.readiness().ok);
+
+    std::thread::sleep(Duration::from_millis(80));
+    health.record("shop_b", Ok(()), Duration::ZERO, 1);
+    let report = registry.liveness();
+    assert!(!report.ok);
+    assert_eq!(report.failing, ["shop_a"]);
+    assert!(report.reason.unwrap().starts_with("1 of 2 health checks are older than"));
+}
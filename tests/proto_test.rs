@@ -0,0 +1,112 @@
+#![cfg(feature = "proto")]
+
+use std::time::Duration;
+use prost::Message;
+use serde_json::json;
+use supplier_kit::context::{Priority, RequestContext};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{ResponseSource, SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::proto;
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Other("track_order".into()), json!({ "id": 7, "weight": 1.5 }))
+        .with_context(
+            RequestContext::new()
+                .with_request_id("req-1")
+                .with_caller("gateway")
+                .with_priority(Priority::High)
+                .with_locale("id-ID")
+                .with_excluded_supplier("shop_b"),
+        )
+        .with_idempotency_key("order-7")
+        .with_version(2)
+}
+
+#[test]
+fn requests_round_trip_through_protobuf() {
+    let bytes = proto::SupplierRequest::from(request()).encode_to_vec();
+    let decoded = proto::SupplierRequest::decode(bytes.as_slice()).unwrap();
+    assert_eq!(decoded.operation, "track_order");
+    assert_eq!(decoded.context.as_ref().unwrap().priority, proto::Priority::High as i32);
+    assert_eq!(decoded.context.as_ref().unwrap().locale.as_deref(), Some("id-ID"));
+    assert_eq!(decoded.context.as_ref().unwrap().excluded_suppliers, ["shop_b"]);
+    assert_eq!(SupplierRequest::try_from(decoded).unwrap(), request());
+
+    let plain = SupplierRequest::new(SupplierOperation::GetDetail, json!(null));
+    let encoded = proto::SupplierRequest::from(plain.clone());
+    assert!(encoded.context.is_none() && encoded.params.is_empty());
+    assert_eq!(SupplierRequest::try_from(encoded).unwrap(), plain);
+}
+
+#[test]
+fn responses_round_trip_through_protobuf() {
+    let response = SupplierResponse::new(json!({ "total": u64::MAX, "items": [] }))
+        .with_body("application/pdf", b"%PDF".to_vec())
+        .with_source(ResponseSource::Replay);
+    let bytes = proto::SupplierResponse::from(response.clone()).encode_to_vec();
+    let decoded = proto::SupplierResponse::decode(bytes.as_slice()).unwrap();
+    assert_eq!(SupplierResponse::try_from(decoded).unwrap(), response);
+}
+
+#[test]
+fn invalid_json_documents_are_rejected() {
+    let request = proto::SupplierRequest {
+        operation: "search".into(),
+        params: b"{not json".to_vec(),
+        ..Default::default()
+    };
+    assert!(matches!(SupplierRequest::try_from(request), Err(SupplierError::InvalidInput(_))));
+
+    let response = proto::SupplierResponse {
+        data: b"[1,".to_vec(),
+        source: 42,
+        ..Default::default()
+    };
+    assert!(matches!(SupplierResponse::try_from(response), Err(SupplierError::Upstream(_))));
+}
+
+#[test]
+fn errors_keep_their_code_and_message() {
+    for error in [
+        SupplierError::Timeout,
+        SupplierError::RateLimited("quota".into()),
+        SupplierError::Throttled { retry_after: Some(Duration::from_millis(1500)) },
+        SupplierError::Throttled { retry_after: None },
+    ] {
+        let encoded = proto::SupplierError::from(error.clone());
+        assert_eq!(encoded.code, error.code());
+        let decoded = SupplierError::from(proto::SupplierError::decode(encoded.encode_to_vec().as_slice()).unwrap());
+        assert_eq!((decoded.code(), decoded.to_string()), (error.code(), error.to_string()));
+    }
+
+    let unknown = SupplierError::from(proto::SupplierError { code: "payment_required".into(), message: Some("card declined".into()) });
+    assert!(matches!(unknown, SupplierError::Upstream(m) if m == "payment_required: card declined"));
+}
+
+/// The hand-maintained prost types must match the published `.proto` file field for field.
+#[cfg(feature = "grpc")]
+#[test]
+fn messages_match_the_proto_definitions() {
+    use prost_reflect::{DescriptorPool, DynamicMessage};
+
+    let mut compiler = protox::Compiler::new(["proto"]).unwrap();
+    compiler.open_file("supplier_kit/v1/models.proto").unwrap();
+    let pool = DescriptorPool::from_file_descriptor_set(compiler.file_descriptor_set()).unwrap();
+
+    let encoded = proto::SupplierRequest::from(request()).encode_to_vec();
+    let descriptor = pool.get_message_by_name("supplier_kit.v1.SupplierRequest").unwrap();
+    let dynamic = DynamicMessage::decode(descriptor, encoded.as_slice()).unwrap();
+    let json = serde_json::to_value(&dynamic).unwrap();
+    assert_eq!(json["operation"], "track_order");
+    assert_eq!(json["idempotencyKey"], "order-7");
+    assert_eq!(json["context"]["priority"], "PRIORITY_HIGH");
+    assert_eq!(json["version"], 2);
+    assert_eq!(dynamic.encode_to_vec(), encoded);
+
+    let response = SupplierResponse::binary("text/csv", b"a,b".to_vec()).with_source(ResponseSource::Cache);
+    let encoded = proto::SupplierResponse::from(response).encode_to_vec();
+    let descriptor = pool.get_message_by_name("supplier_kit.v1.SupplierResponse").unwrap();
+    let json = serde_json::to_value(DynamicMessage::decode(descriptor, encoded.as_slice()).unwrap()).unwrap();
+    assert_eq!(json["body"]["contentType"], "text/csv");
+    assert_eq!(json["source"], "RESPONSE_SOURCE_CACHE");
+}
@@ -0,0 +1,103 @@
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{ResponseSource, SupplierOperation, SupplierRequest};
+use supplier_kit::push::{IngestSink, PushEvent, PushStore};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+fn detail(id: serde_json::Value) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": id }))
+}
+
+#[test]
+fn store_upserts_and_deletes_records_per_supplier() {
+    let store = Arc::new(PushStore::new("/sku"));
+    store.ingest(&PushEvent::new("partner", json!([{ "sku": "A", "qty": 1 }, { "sku": "B", "qty": 2 }]))).unwrap();
+    store.ingest(&PushEvent::new("partner", json!({ "sku": "A", "qty": 5 }))).unwrap();
+    store.ingest(&PushEvent::new("other", json!({ "sku": "A", "qty": 9 }))).unwrap();
+    store
+        .ingest(&PushEvent::new("partner", json!({ "sku": "B" })).with_operation(SupplierOperation::Delete))
+        .unwrap();
+
+    assert_eq!(store.records("partner"), vec![json!({ "sku": "A", "qty": 5 })]);
+    assert_eq!(store.get("other", "A").unwrap()["qty"], 9);
+    assert!(store.last_push("partner").is_some());
+    assert!(store.last_push("nobody").is_none());
+}
+
+#[test]
+fn events_without_keys_are_rejected_whole() {
+    let store = PushStore::new("/id");
+    let result = store.ingest(&PushEvent::new("partner", json!([{ "id": 1 }, { "name": "no key" }])));
+
+    assert!(matches!(result, Err(SupplierError::InvalidInput(_))));
+    assert!(store.records("partner").is_empty());
+}
+
+#[test]
+fn redelivered_events_are_ignored() {
+    let store = PushStore::new("/sku");
+    store.ingest(&PushEvent::new("partner", json!({ "sku": "A", "qty": 1 })).with_event_id("evt-1")).unwrap();
+    store.ingest(&PushEvent::new("partner", json!({ "sku": "A", "qty": 2 })).with_event_id("evt-2")).unwrap();
+    store.ingest(&PushEvent::new("partner", json!({ "sku": "A", "qty": 1 })).with_event_id("evt-1")).unwrap();
+
+    assert_eq!(store.get("partner", "A").unwrap()["qty"], 2);
+}
+
+#[test]
+fn pushed_suppliers_sit_in_groups_next_to_pulled_ones() {
+    let store = Arc::new(PushStore::new("/sku"));
+    store.ingest(&PushEvent::new("partner", json!([{ "sku": 7, "qty": 3 }]))).unwrap();
+
+    let partner = store.supplier("partner");
+    assert_eq!(partner.query(detail(json!(7))).unwrap().data["qty"], 3);
+    assert!(matches!(partner.query(detail(json!(8))), Err(SupplierError::NotFound)));
+    assert!(matches!(
+        partner.query(SupplierRequest::new(SupplierOperation::Create, json!({}))),
+        Err(SupplierError::UnsupportedOperation(_))
+    ));
+
+    let mut group = BasicSupplierGroup::new("inventory");
+    group.add_supplier(MockSupplierBuilder::new("pulled").respond_default(json!({ "items": [] })).build());
+    group.add_supplier(partner);
+    let result = group.query(search());
+
+    assert_eq!(result.successes.len(), 2);
+    let (name, response) = &result.successes[1];
+    assert_eq!(name, "partner");
+    assert_eq!(response.source(), ResponseSource::Push);
+    assert_eq!(response.data["items"][0]["sku"], 7);
+}
+
+#[test]
+fn sinks_forward_to_channels_closures_and_lists() {
+    let (tx, rx) = mpsc::channel();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let recorded = seen.clone();
+    let store = Arc::new(PushStore::new("/sku"));
+
+    let sinks: Vec<Arc<dyn IngestSink>> = vec![
+        store.clone(),
+        Arc::new(tx),
+        Arc::new(move |event: &PushEvent| {
+            recorded.lock().unwrap().push(event.supplier.clone());
+            Ok(())
+        }),
+    ];
+    sinks.ingest(&PushEvent::new("partner", json!({ "sku": "A" }))).unwrap();
+
+    assert_eq!(rx.recv().unwrap().data["sku"], "A");
+    assert_eq!(*seen.lock().unwrap(), vec!["partner"]);
+    assert!(store.get("partner", "A").is_some());
+
+    // The list stops at the first failing sink.
+    assert!(sinks.ingest(&PushEvent::new("partner", json!({ "qty": 1 }))).is_err());
+    assert!(rx.try_recv().is_err());
+}
@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+
+struct EchoSupplier(&'static str);
+
+impl Supplier for EchoSupplier {
+    fn name(&self) -> &str {
+        self.0
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!({ "supplier": self.0, "params": request.params })))
+    }
+}
+
+fn order_for(sku: &str) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Other("place_order".into()), json!({ "sku": sku }))
+}
+
+fn group(strategy: QueryStrategy) -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new("orders").with_strategy(strategy);
+    group.add_supplier(EchoSupplier("a"));
+    group.add_supplier(EchoSupplier("b"));
+    group.add_supplier(EchoSupplier("c"));
+    group
+}
+
+#[test]
+fn test_each_supplier_receives_its_own_request() {
+    for strategy in [QueryStrategy::Sequential, QueryStrategy::Parallel] {
+        let mut requests = HashMap::new();
+        requests.insert("a".to_string(), order_for("A-1"));
+        requests.insert("c".to_string(), order_for("C-9"));
+        requests.insert("zzz".to_string(), order_for("Z"));
+
+        let result = group(strategy).query_each(requests);
+
+        let successes: Vec<_> = result
+            .successes
+            .iter()
+            .map(|(name, r)| (name.as_str(), r.data["params"]["sku"].as_str().unwrap()))
+            .collect();
+        assert_eq!(successes, [("a", "A-1"), ("c", "C-9")]);
+
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.failures[0].0, "zzz");
+        assert!(matches!(result.failures[0].1, SupplierError::NotFound));
+    }
+}
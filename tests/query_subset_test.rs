@@ -0,0 +1,89 @@
+use std::sync::Arc;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::descriptor::GroupDescriptor;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::health::HealthMap;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::requirements::{SupplierRequirement, SupplierRequirements};
+use supplier_kit::supplier_group::{BasicSupplierGroup, PartialFailurePolicy, QueryStrategy, SupplierGroup, SupplierGroupResult};
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }))
+}
+
+fn stores() -> (BasicSupplierGroup, Vec<MockSupplier>) {
+    let mut group = BasicSupplierGroup::new("stores").with_strategy(QueryStrategy::Parallel);
+    let stores: Vec<MockSupplier> = ["s1", "s2", "s3"]
+        .into_iter()
+        .map(|name| MockSupplierBuilder::new(name).respond_default(json!([name])).build())
+        .collect();
+    for store in &stores {
+        group.add_supplier(store.clone());
+    }
+    (group, stores)
+}
+
+fn names<T>(entries: &[(String, T)]) -> Vec<&str> {
+    entries.iter().map(|(name, _)| name.as_str()).collect()
+}
+
+#[test]
+fn subsets_only_reach_the_named_suppliers() {
+    let (group, stores) = stores();
+    let result = group.query_subset(search(), &["s3", "s1"]);
+    assert_eq!(names(&result.successes), ["s1", "s3"]);
+    assert!(result.failures.is_empty());
+    let calls: Vec<usize> = stores.iter().map(MockSupplier::calls).collect();
+    assert_eq!(calls, [1, 0, 1]);
+
+    let result = group.query_subset(search(), &["s2", "nope", "nope"]);
+    assert_eq!(names(&result.successes), ["s2"]);
+    assert!(matches!(&result.failures[..], [(name, SupplierError::NotFound)] if name == "nope"));
+
+    assert!(group.query_subset(search(), &[]).successes.is_empty());
+}
+
+#[test]
+fn subsets_still_skip_unhealthy_suppliers_but_ignore_requirements() {
+    let health = Arc::new(HealthMap::new());
+    let (group, _) = stores();
+    let group = group
+        .with_health(health.clone())
+        .with_failure_policy(PartialFailurePolicy::Quorum(1))
+        .with_requirements(SupplierRequirements::new().with_requirement(SupplierOperation::Search, SupplierRequirement::all_of(&["s1", "s2"])));
+    health.record("s1", Err(SupplierError::Timeout), Duration::ZERO, 1);
+
+    assert!(group.query(search()).error.is_some());
+    let result = group.query_subset(search(), &["s1", "s3"]);
+    assert_eq!(names(&result.successes), ["s3"]);
+    assert!(result.error.is_none());
+
+    let result = group.query_subset(search(), &["s1"]);
+    assert_eq!(result.error.unwrap().code(), "no_suppliers");
+}
+
+/// A group that only implements the required methods.
+struct Minimal;
+
+impl SupplierGroup for Minimal {
+    fn group_name(&self) -> &str {
+        "minimal"
+    }
+
+    fn describe(&self) -> GroupDescriptor {
+        GroupDescriptor::new("minimal")
+    }
+
+    fn query(&self, _request: SupplierRequest) -> SupplierGroupResult {
+        SupplierGroupResult::default()
+    }
+}
+
+#[test]
+fn default_subsets_go_through_query_each() {
+    let result = Minimal.query_subset(search(), &["b", "a"]);
+    assert_eq!(names(&result.failures), ["a", "b"]);
+    assert!(matches!(result.failures[0].1, SupplierError::UnsupportedOperation(_)));
+}
@@ -0,0 +1,168 @@
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::compression::Compression;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::queue::{respond, respond_compressed, QueueReply, QueueRequest, QueueSupplier, RequestReplyTransport};
+use supplier_kit::supplier::Supplier;
+
+struct Inventory;
+
+impl Supplier for Inventory {
+    fn name(&self) -> &str {
+        "inventory"
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        match request.params["sku"].as_str() {
+            Some("slow") => {
+                thread::sleep(Duration::from_millis(300));
+                Ok(SupplierResponse::new(json!({ "stock": 0 })))
+            }
+            Some("gone") => Err(SupplierError::NotFound),
+            Some(sku) => Ok(SupplierResponse::new(json!({ "sku": sku, "stock": 4 }))),
+            None => Err(SupplierError::InvalidInput("sku is required".into())),
+        }
+    }
+}
+
+type Envelope = (String, Vec<u8>, Sender<Vec<u8>>);
+
+/// An in-process broker: a responder thread answers every message published to it.
+struct ChannelBroker {
+    outbox: Mutex<Sender<Envelope>>,
+}
+
+impl ChannelBroker {
+    fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&str, &[u8]) -> Vec<u8> + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel::<Envelope>();
+        thread::spawn(move || {
+            for (subject, payload, reply) in rx {
+                let _ = reply.send(handler(&subject, &payload));
+            }
+        });
+        Self { outbox: Mutex::new(tx) }
+    }
+}
+
+impl RequestReplyTransport for ChannelBroker {
+    fn request(&self, subject: &str, payload: Vec<u8>, timeout: Duration) -> Result<Vec<u8>, SupplierError> {
+        let (reply_tx, reply_rx) = mpsc::channel();
+        self.outbox
+            .lock()
+            .unwrap()
+            .send((subject.to_string(), payload, reply_tx))
+            .map_err(|_| SupplierError::Upstream("broker is down".into()))?;
+        reply_rx.recv_timeout(timeout).map_err(|_| SupplierError::Timeout)
+    }
+}
+
+fn lookup(sku: &str) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::GetDetail, json!({ "sku": sku }))
+}
+
+#[test]
+fn request_is_answered_by_the_remote_supplier() {
+    let subjects = Arc::new(Mutex::new(Vec::new()));
+    let seen = subjects.clone();
+    let broker = ChannelBroker::start(move |subject, payload| {
+        seen.lock().unwrap().push(subject.to_string());
+        respond(&Inventory, payload).unwrap()
+    });
+    let supplier = QueueSupplier::new("inventory", broker, "suppliers.inventory");
+
+    let response = supplier.query(lookup("A1")).unwrap();
+    assert_eq!(response.data, json!({ "sku": "A1", "stock": 4 }));
+    assert!(matches!(supplier.query(lookup("gone")), Err(SupplierError::NotFound)));
+    assert_eq!(*subjects.lock().unwrap(), ["suppliers.inventory", "suppliers.inventory"]);
+}
+
+#[test]
+fn requests_carry_generated_correlation_ids() {
+    let ids = Arc::new(Mutex::new(Vec::new()));
+    let seen = ids.clone();
+    let broker = ChannelBroker::start(move |_, payload| {
+        let request: QueueRequest = serde_json::from_slice(payload).unwrap();
+        seen.lock().unwrap().push(request.correlation_id.clone());
+        respond(&Inventory, payload).unwrap()
+    });
+    let counter = Arc::new(Mutex::new(0));
+    let supplier = QueueSupplier::new("inventory", broker, "suppliers.inventory").with_id_generator(Arc::new(move || {
+        let mut counter = counter.lock().unwrap();
+        *counter += 1;
+        format!("corr-{}", counter)
+    }));
+
+    supplier.query(lookup("A1")).unwrap();
+    supplier.query(lookup("B2")).unwrap();
+    assert_eq!(*ids.lock().unwrap(), ["corr-1", "corr-2"]);
+}
+
+#[test]
+fn slow_replies_time_out() {
+    let broker = ChannelBroker::start(|_, payload| respond(&Inventory, payload).unwrap());
+    let supplier = QueueSupplier::new("inventory", broker, "suppliers.inventory").with_timeout(Duration::from_millis(50));
+
+    assert!(matches!(supplier.query(lookup("slow")), Err(SupplierError::Timeout)));
+}
+
+#[test]
+fn uncorrelated_or_malformed_replies_are_rejected() {
+    let stranger = ChannelBroker::start(|_, _| {
+        let reply = QueueReply::from_result("someone-else", Ok(SupplierResponse::new(json!({}))));
+        serde_json::to_vec(&reply).unwrap()
+    });
+    let supplier = QueueSupplier::new("inventory", stranger, "suppliers.inventory");
+    assert!(matches!(supplier.query(lookup("A1")), Err(SupplierError::Upstream(m)) if m.contains("someone-else")));
+
+    let garbage = ChannelBroker::start(|_, _| b"not json".to_vec());
+    let supplier = QueueSupplier::new("inventory", garbage, "suppliers.inventory");
+    assert!(matches!(supplier.query(lookup("A1")), Err(SupplierError::Upstream(_))));
+}
+
+#[test]
+fn responder_rejects_invalid_requests() {
+    assert!(matches!(respond(&Inventory, b"{}"), Err(SupplierError::InvalidInput(_))));
+
+    let reply = QueueReply {
+        correlation_id: "c".into(),
+        response: None,
+        error: None,
+    };
+    assert!(matches!(reply.into_result(), Err(SupplierError::Upstream(_))));
+}
+
+#[test]
+fn oversized_replies_are_rejected_before_decoding() {
+    let broker = ChannelBroker::start(|_, payload| respond(&Inventory, payload).unwrap());
+    let supplier = QueueSupplier::new("inventory", broker, "suppliers.inventory").with_max_response_size(16);
+    assert!(matches!(supplier.query(lookup("A1")), Err(SupplierError::ResponseTooLarge(m)) if m.contains("'inventory'")));
+
+    let broker = ChannelBroker::start(|_, payload| respond(&Inventory, payload).unwrap());
+    let supplier = QueueSupplier::new("inventory", broker, "suppliers.inventory").with_max_response_size(4096);
+    assert!(supplier.query(lookup("A1")).is_ok());
+}
+
+#[test]
+fn compressed_replies_are_inflated_transparently() {
+    let broker = ChannelBroker::start(|_, payload| respond_compressed(&Inventory, payload, Compression::None).unwrap());
+    let supplier = QueueSupplier::new("inventory", broker, "suppliers.inventory");
+    assert!(supplier.query(lookup("A1")).is_ok());
+
+    #[cfg(feature = "gzip")]
+    {
+        let broker = ChannelBroker::start(|_, payload| respond_compressed(&Inventory, payload, Compression::Gzip(6)).unwrap());
+        let supplier = QueueSupplier::new("inventory", broker, "suppliers.inventory");
+        assert!(supplier.query(lookup("A1")).is_ok());
+
+        let broker = ChannelBroker::start(|_, payload| respond_compressed(&Inventory, payload, Compression::Gzip(6)).unwrap());
+        let limited = QueueSupplier::new("inventory", broker, "suppliers.inventory").with_max_response_size(24);
+        assert!(matches!(limited.query(lookup("A1")), Err(SupplierError::ResponseTooLarge(m)) if m.contains("'inventory'")));
+    }
+}
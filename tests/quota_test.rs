@@ -0,0 +1,122 @@
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::decorators::quota::QuotaSupplier;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::events::{EventBus, SupplierEvent};
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::quota::{Quota, QuotaTracker};
+use supplier_kit::scheduler::Schedule;
+use supplier_kit::supplier::Supplier;
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn request(operation: SupplierOperation) -> SupplierRequest {
+    SupplierRequest::new(operation, json!({}))
+}
+
+#[test]
+fn hard_limits_short_circuit_before_the_supplier() {
+    let tracker = Arc::new(QuotaTracker::new());
+    tracker.set_quota("partner", Quota::monthly().with_hard_limit(3.0));
+    let mock = MockSupplierBuilder::new("partner").respond_default(json!({})).build();
+    let supplier = QuotaSupplier::new(mock.clone(), tracker.clone());
+
+    for _ in 0..3 {
+        supplier.query(request(SupplierOperation::Search)).unwrap();
+    }
+    let error = supplier.query(request(SupplierOperation::Search)).unwrap_err();
+
+    assert!(matches!(error, SupplierError::RateLimited(_)));
+    assert_eq!(error.code(), "rate_limited");
+    assert_eq!(mock.calls(), 3);
+    assert_eq!(tracker.usage("partner").unwrap().used, 3.0);
+}
+
+#[test]
+fn operations_consume_their_weight() {
+    let tracker = QuotaTracker::new();
+    tracker.set_quota(
+        "partner",
+        Quota::monthly().with_hard_limit(10.0).with_weight(SupplierOperation::Create, 4.0),
+    );
+
+    tracker.try_consume("partner", &SupplierOperation::Create).unwrap();
+    tracker.try_consume("partner", &SupplierOperation::Create).unwrap();
+    tracker.try_consume("partner", &SupplierOperation::Search).unwrap();
+    // 9 used: another create would need 13.
+    assert!(matches!(
+        tracker.try_consume("partner", &SupplierOperation::Create),
+        Err(SupplierError::RateLimited(_))
+    ));
+    tracker.try_consume("partner", &SupplierOperation::Search).unwrap();
+    assert_eq!(tracker.usage("partner").unwrap().remaining(), Some(0.0));
+}
+
+#[test]
+fn soft_limits_publish_one_event_per_window() {
+    let bus = EventBus::new();
+    let events = bus.subscribe_channel();
+    let tracker = QuotaTracker::new().with_event_bus(bus);
+    tracker.set_quota(
+        "partner",
+        Quota::new(Schedule::Every(Duration::from_millis(100))).with_soft_limit(2.0),
+    );
+
+    for _ in 0..5 {
+        tracker.try_consume("partner", &SupplierOperation::Search).unwrap();
+    }
+    thread::sleep(Duration::from_millis(150));
+    for _ in 0..2 {
+        tracker.try_consume("partner", &SupplierOperation::Search).unwrap();
+    }
+
+    let reached: Vec<(String, f64, f64)> = events
+        .try_iter()
+        .filter_map(|event| match event {
+            SupplierEvent::QuotaSoftLimitReached { supplier, used, limit } => Some((supplier, used, limit)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(reached, vec![("partner".to_string(), 2.0, 2.0), ("partner".to_string(), 2.0, 2.0)]);
+}
+
+#[test]
+fn windows_renew_the_budget() {
+    let tracker = QuotaTracker::new();
+    tracker.set_quota("partner", Quota::new(Schedule::Every(Duration::from_millis(100))).with_hard_limit(1.0));
+
+    tracker.try_consume("partner", &SupplierOperation::Search).unwrap();
+    assert!(tracker.try_consume("partner", &SupplierOperation::Search).is_err());
+    let first_window = tracker.usage("partner").unwrap().window_start;
+
+    thread::sleep(Duration::from_millis(150));
+    assert_eq!(tracker.usage("partner").unwrap().used, 0.0);
+    tracker.try_consume("partner", &SupplierOperation::Search).unwrap();
+    assert!(tracker.usage("partner").unwrap().window_start > first_window);
+}
+
+#[test]
+fn suppliers_without_a_quota_are_unlimited() {
+    let tracker = QuotaTracker::new();
+    for _ in 0..100 {
+        tracker.try_consume("partner", &SupplierOperation::Delete).unwrap();
+    }
+    assert!(tracker.usage("partner").is_none());
+
+    tracker.set_quota("partner", Quota::monthly().with_hard_limit(0.0));
+    assert!(tracker.try_consume("partner", &SupplierOperation::Search).is_err());
+    tracker.remove_quota("partner");
+    assert!(tracker.try_consume("partner", &SupplierOperation::Search).is_ok());
+}
+
+#[test]
+fn monthly_windows_end_at_the_next_month() {
+    let tracker = QuotaTracker::new();
+    tracker.set_quota("partner", Quota::monthly());
+
+    let usage = tracker.usage("partner").unwrap();
+    let window = usage.resets_at.unwrap().duration_since(usage.window_start).unwrap();
+    assert!(window <= Duration::from_secs(31 * 24 * 3600));
+    assert_eq!(usage.remaining(), None);
+}
@@ -0,0 +1,62 @@
+use serde_json::{json, Value};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::ranking::{ranked_merge, CompositeRanker, PriceRanker, SupplierWeightRanker};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+
+struct Marketplace {
+    name: &'static str,
+    items: Value,
+}
+
+impl Supplier for Marketplace {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!({ "items": self.items })))
+    }
+}
+
+fn search_result() -> supplier_kit::supplier_group::SupplierGroupResult {
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.add_supplier(Marketplace {
+        name: "trusted",
+        items: json!([{ "title": "laptop a", "price": 100.0 }, { "title": "laptop b", "price": 90.0 }]),
+    });
+    group.add_supplier(Marketplace {
+        name: "sketchy",
+        items: json!([{ "title": "laptop c", "price": 85.0 }, { "title": "no price" }]),
+    });
+    group.query(SupplierRequest::new(SupplierOperation::Search, json!({ "q": "laptop" })))
+}
+
+fn titles(ranked: &[supplier_kit::ranking::RankedItem]) -> Vec<&str> {
+    ranked.iter().map(|r| r.item["title"].as_str().unwrap()).collect()
+}
+
+#[test]
+fn test_price_ranking_puts_cheapest_first_and_unpriced_last() {
+    let ranked = ranked_merge(&search_result(), "/items", &PriceRanker::new("/price"));
+    assert_eq!(titles(&ranked), ["laptop c", "laptop b", "laptop a", "no price"]);
+}
+
+#[test]
+fn test_trust_weight_can_outweigh_price() {
+    let ranker = CompositeRanker::new()
+        .with(1.0, PriceRanker::new("/price"))
+        .with(50.0, SupplierWeightRanker::default().with_weight("sketchy", 0.0));
+
+    let ranked = ranked_merge(&search_result(), "/items", &ranker);
+    assert_eq!(&titles(&ranked)[..2], ["laptop b", "laptop a"]);
+    assert_eq!(ranked[0].supplier, "trusted");
+}
+
+#[test]
+fn test_relevance_closure() {
+    let relevance = |_: &str, item: &Value| if item["title"] == "laptop a" { 1.0 } else { 0.0 };
+    let ranked = ranked_merge(&search_result(), "/items", &relevance);
+    assert_eq!(titles(&ranked)[0], "laptop a");
+}
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+use serde_json::json;
+use supplier_kit::consistency::ConsistencyChecker;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::pagination::Paginator;
+use supplier_kit::reconciliation::Reconciler;
+use supplier_kit::supplier::Supplier;
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "warehouse": "main" }))
+}
+
+fn erp() -> Arc<dyn Supplier> {
+    Arc::new(
+        MockSupplierBuilder::new("erp")
+            .then_respond(json!({
+                "items": [{ "sku": "A", "qty": 3, "synced": 1 }, { "sku": "B", "qty": 1, "synced": 1 }],
+                "next_cursor": 2,
+            }))
+            .then_respond(json!({ "items": [{ "sku": "C", "qty": 0, "synced": 1 }] }))
+            .build(),
+    )
+}
+
+#[test]
+fn pages_through_sources_and_reports_missing_extra_and_mismatched_records() {
+    let marketplace = MockSupplierBuilder::new("marketplace")
+        .then_respond(json!({ "items": [{ "sku": "A", "qty": 3, "synced": 9 }], "next_cursor": 1 }))
+        .then_respond(json!({ "items": [{ "sku": "C", "qty": 4, "synced": 9 }, { "sku": "Z", "qty": 1 }] }))
+        .build();
+    let mirror = MockSupplierBuilder::new("mirror")
+        .respond_default(json!({ "items": [
+            { "sku": "A", "qty": 3, "synced": 2 },
+            { "sku": "B", "qty": 1, "synced": 2 },
+            { "sku": "C", "qty": 0, "synced": 2 },
+        ] }))
+        .build();
+
+    let report = Reconciler::new("/sku")
+        .with_checker(ConsistencyChecker::new().ignore("/synced"))
+        .run(erp(), &[Arc::new(marketplace.clone()), Arc::new(mirror)], request())
+        .unwrap();
+
+    assert_eq!(report.reference, "erp");
+    assert_eq!(report.datasets[0].pages, 2);
+    assert_eq!(report.datasets[0].records, 3);
+
+    let market = report.comparison("marketplace").unwrap();
+    assert_eq!(market.missing, vec!["B"]);
+    assert_eq!(market.extra, vec!["Z"]);
+    assert_eq!(market.mismatched.len(), 1);
+    assert_eq!(market.mismatched[0].key, "C");
+    assert_eq!(market.mismatched[0].divergences[0].path, "/qty");
+    assert_eq!(marketplace.last_request().unwrap().params, json!({ "warehouse": "main", "cursor": 1 }));
+
+    assert!(report.comparison("mirror").unwrap().is_reconciled());
+    assert!(!report.is_reconciled());
+}
+
+#[test]
+fn duplicate_and_unkeyed_records_are_reported() {
+    let messy = MockSupplierBuilder::new("messy")
+        .respond_default(json!({ "data": { "rows": [
+            { "id": 7, "qty": 1 }, { "id": 7, "qty": 2 }, { "qty": 5 },
+        ] } }))
+        .build();
+    let clean = MockSupplierBuilder::new("clean")
+        .respond_default(json!({ "data": { "rows": [{ "id": 7, "qty": 1 }] } }))
+        .build();
+
+    let report = Reconciler::new("/id")
+        .with_items_pointer("/data/rows")
+        .with_paginator(Paginator::new("page", "/data/next"))
+        .run(Arc::new(messy), &[Arc::new(clean)], request())
+        .unwrap();
+
+    assert_eq!(report.datasets[0].duplicate_keys, vec!["7"]);
+    assert_eq!(report.datasets[0].unkeyed, 1);
+    assert!(report.comparison("clean").unwrap().is_reconciled());
+    assert!(!report.is_reconciled());
+}
+
+#[test]
+fn source_failures_are_reported_and_reference_failures_abort() {
+    let down = || -> Arc<dyn Supplier> {
+        Arc::new(MockSupplierBuilder::new("down").then_fail(SupplierError::Timeout).build())
+    };
+
+    let report = Reconciler::new("/sku").run(erp(), &[down()], request()).unwrap();
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(report.failures[0].0, "down");
+    assert!(report.comparisons.is_empty());
+    assert!(!report.is_reconciled());
+
+    assert!(matches!(
+        Reconciler::new("/sku").run(down(), &[erp()], request()),
+        Err(SupplierError::Timeout)
+    ));
+}
+
+#[test]
+fn endless_pagination_is_capped() {
+    let endless = MockSupplierBuilder::new("endless")
+        .respond_default(json!({ "items": [], "next_cursor": "again" }))
+        .build();
+
+    let result = Reconciler::new("/sku").with_max_pages(3).run(Arc::new(endless.clone()), &[], request());
+    assert!(matches!(result, Err(SupplierError::Internal(msg)) if msg.contains("more than 3 pages")));
+    assert_eq!(endless.calls(), 3);
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+use serde_json::json;
+use supplier_kit::audit::{AuditLog, AuditedSupplier, ChannelSink};
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::redaction::Redactor;
+use supplier_kit::supplier::Supplier;
+use supplier_kit::testing::mock::MockSupplierBuilder;
+use supplier_kit::testing::replay::{read_exchanges, RecordingSupplier, ReplaySupplier};
+
+fn payment() -> SupplierRequest {
+    SupplierRequest::new(
+        SupplierOperation::Other("pay".into()),
+        json!({ "amount": 10, "credit_card": "4111", "token": "abc" }),
+    )
+}
+
+#[test]
+fn rules_match_keys_wildcards_and_indexes() {
+    let redactor = Redactor::new()
+        .with_rule("a.b")
+        .with_rule("list.*.secret")
+        .with_rule("pairs.1")
+        .with_rule("missing.path")
+        .with_replacement(json!(null));
+
+    let value = json!({
+        "a": { "b": 1, "c": 2 },
+        "list": [{ "secret": 1, "x": 1 }, { "secret": 2 }],
+        "pairs": ["keep", "drop"]
+    });
+    assert_eq!(
+        redactor.redact(&value),
+        json!({
+            "a": { "b": null, "c": 2 },
+            "list": [{ "secret": null, "x": 1 }, { "secret": null }],
+            "pairs": ["keep", null]
+        })
+    );
+}
+
+#[test]
+fn request_and_response_rules_are_rooted_separately() {
+    let redactor = Redactor::new().with_rule("params.token").with_rule("data.email");
+
+    let request = redactor.redact_request(&payment());
+    assert_eq!(request.params["token"], "[REDACTED]");
+    assert_eq!(request.params["amount"], 10);
+
+    let response = redactor.redact_response(&SupplierResponse::new(json!({ "email": "a@b.c", "token": "t" })));
+    assert_eq!(response.data, json!({ "email": "[REDACTED]", "token": "t" }));
+}
+
+#[test]
+fn audit_log_records_redacted_params() {
+    let (sink, records) = ChannelSink::new();
+    let log = AuditLog::new(sink).with_redactor(Redactor::new().with_rule("params.credit_card"));
+    let supplier = AuditedSupplier::new(
+        MockSupplierBuilder::new("psp").respond_default(json!({})).build(),
+        Arc::new(log),
+    );
+    supplier.query(payment()).unwrap();
+
+    let record = records.recv().unwrap();
+    assert_eq!(record.params["credit_card"], "[REDACTED]");
+    assert_eq!(record.params["token"], "abc");
+}
+
+#[test]
+fn recordings_are_redacted_and_still_replay() {
+    let path = std::env::temp_dir().join(format!("supplier_kit_redacted_{}.ndjson", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let redactor = Redactor::new().with_rule("params.token").with_rule("data.session");
+
+    let live = MockSupplierBuilder::new("psp").respond_default(json!({ "ok": true, "session": "s3cr3t" })).build();
+    let recorder = RecordingSupplier::new(live, &path).unwrap().with_redactor(redactor.clone());
+    recorder.query(payment()).unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(!content.contains("abc") && !content.contains("s3cr3t"));
+    assert_eq!(read_exchanges(&path).unwrap()[0].params["token"], "[REDACTED]");
+
+    let replay = ReplaySupplier::from_file("psp", &path).unwrap().with_redactor(redactor);
+    assert_eq!(replay.query(payment()).unwrap().data["ok"], true);
+    std::fs::remove_file(path).unwrap();
+}
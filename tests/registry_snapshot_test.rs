@@ -0,0 +1,79 @@
+use std::sync::{Arc, RwLock};
+use std::thread;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::events::SupplierEvent;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::{NameNormalization, SupplierRegistry};
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+fn shop(tag: &str) -> MockSupplier {
+    MockSupplierBuilder::new("shop").respond_default(json!({ "tag": tag })).build()
+}
+
+#[test]
+fn snapshots_are_isolated_from_later_changes() {
+    let mut registry = SupplierRegistry::new();
+    registry.register("shop", shop("v1"));
+    let snapshot = registry.snapshot();
+
+    registry.register("shop", shop("v2"));
+    registry.register("other", shop("other"));
+
+    assert_eq!(snapshot.query("shop", request()).unwrap().data["tag"], "v1");
+    assert_eq!(registry.query("shop", request()).unwrap().data["tag"], "v2");
+    assert_eq!(snapshot.all_names(), ["shop"]);
+    assert_eq!(snapshot.len(), 1);
+    assert!(matches!(snapshot.query("other", request()), Err(SupplierError::NotFound)));
+}
+
+#[test]
+fn snapshots_keep_aliases_and_normalization() {
+    let mut registry = SupplierRegistry::new();
+    registry.set_name_normalization(NameNormalization::all()).unwrap();
+    registry.register("shop", shop("v1"));
+    registry.alias("store", "shop").unwrap();
+    let snapshot = registry.snapshot();
+
+    assert!(snapshot.contains(" STORE "));
+    assert_eq!(snapshot.resolve("Store"), "shop");
+    assert_eq!(snapshot.get("store").unwrap().name(), "shop");
+    assert!(snapshot.describe_all().contains_key("shop"));
+}
+
+#[test]
+fn snapshot_queries_publish_on_the_registry_bus() {
+    let mut registry = SupplierRegistry::new();
+    registry.register("shop", shop("v1"));
+    let events = registry.events().subscribe_channel();
+
+    registry.snapshot().query("shop", request()).unwrap();
+    let succeeded = events
+        .try_iter()
+        .any(|event| matches!(event, SupplierEvent::QuerySucceeded { supplier, .. } if supplier == "shop"));
+    assert!(succeeded);
+}
+
+#[test]
+fn readers_use_snapshots_while_the_registry_is_mutated() {
+    let live = Arc::new(RwLock::new(SupplierRegistry::new()));
+    live.write().unwrap().register("shop", shop("v1"));
+    let snapshot = live.read().unwrap().snapshot();
+
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let snapshot = snapshot.clone();
+            thread::spawn(move || (0..50).all(|_| snapshot.query("shop", request()).unwrap().data["tag"] == "v1"))
+        })
+        .collect();
+    for version in 2..20 {
+        live.write().unwrap().register("shop", shop(&format!("v{}", version)));
+    }
+
+    assert!(readers.into_iter().all(|reader| reader.join().unwrap()));
+    assert_eq!(live.read().unwrap().snapshot().query("shop", request()).unwrap().data["tag"], "v19");
+}
@@ -0,0 +1,99 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{ResponseSource, SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::testing::replay::{read_exchanges, RecordingSupplier, ReplaySupplier};
+
+struct CountingApi {
+    calls: AtomicUsize,
+}
+
+impl Supplier for CountingApi {
+    fn name(&self) -> &str {
+        "api"
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst);
+        if request.params["id"] == 404 {
+            return Err(SupplierError::NotFound);
+        }
+        Ok(SupplierResponse::new(json!({ "id": request.params["id"], "call": call })))
+    }
+}
+
+fn temp_file(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("supplier_kit_{}_{}.ndjson", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn detail(id: u64) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": id }))
+}
+
+#[test]
+fn recorded_exchanges_are_replayed() {
+    let path = temp_file("record");
+    let recorder = RecordingSupplier::new(CountingApi { calls: AtomicUsize::new(0) }, &path).unwrap();
+    recorder.query(detail(1)).unwrap();
+    assert!(recorder.query(detail(404)).is_err());
+    assert!(recorder.take_error().is_none());
+
+    let exchanges = read_exchanges(&path).unwrap();
+    assert_eq!(exchanges.len(), 2);
+    assert_eq!(exchanges[0].supplier, "api");
+
+    let replay = ReplaySupplier::from_file("api", &path).unwrap();
+    assert_eq!(replay.len(), 2);
+    let response = replay.query(detail(1)).unwrap();
+    assert_eq!(response.data, json!({ "id": 1, "call": 0 }));
+    assert_eq!(response.source(), ResponseSource::Replay);
+    assert!(matches!(replay.query(detail(404)), Err(SupplierError::NotFound)));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn repeated_requests_replay_in_order_then_repeat_the_last() {
+    let path = temp_file("repeat");
+    let recorder = RecordingSupplier::new(CountingApi { calls: AtomicUsize::new(0) }, &path).unwrap();
+    recorder.query(detail(1)).unwrap();
+    recorder.query(detail(1)).unwrap();
+
+    let replay = ReplaySupplier::from_file("api", &path).unwrap();
+    let calls: Vec<_> = (0..3).map(|_| replay.query(detail(1)).unwrap().data["call"].clone()).collect();
+    assert_eq!(calls, vec![json!(0), json!(1), json!(1)]);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn context_is_ignored_and_unknown_requests_fail() {
+    let path = temp_file("context");
+    let recorder = RecordingSupplier::new(CountingApi { calls: AtomicUsize::new(0) }, &path).unwrap();
+    recorder.query(detail(1)).unwrap();
+
+    let replay = ReplaySupplier::from_file("api", &path).unwrap();
+    let mut request = detail(1);
+    request.context.request_id = Some("abc".into());
+    assert!(replay.query(request).is_ok());
+    assert!(matches!(replay.query(detail(2)), Err(SupplierError::Internal(_))));
+    assert!(ReplaySupplier::from_file("other", &path).unwrap().is_empty());
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn invalid_lines_are_reported() {
+    let path = temp_file("invalid");
+    std::fs::write(&path, "\n{\"supplier\":\"a\",\"operation\":\"search\",\"params\":{}}\nnot json\n").unwrap();
+
+    let error = read_exchanges(&path).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(error.to_string().starts_with("line 3"));
+
+    std::fs::remove_file(path).unwrap();
+}
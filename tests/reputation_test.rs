@@ -0,0 +1,130 @@
+use std::sync::Arc;
+use std::time::Duration;
+use serde_json::{json, Value};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::ranking::ranked_merge;
+use supplier_kit::reputation::ReputationTracker;
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+
+struct Shop {
+    name: &'static str,
+    fail: bool,
+    delay: Duration,
+}
+
+impl Supplier for Shop {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        std::thread::sleep(self.delay);
+        if self.fail {
+            return Err(SupplierError::Internal("down".into()));
+        }
+        Ok(SupplierResponse::new(json!({ "items": [{ "title": self.name }] })))
+    }
+}
+
+fn group(strategy: QueryStrategy, tracker: Arc<ReputationTracker>) -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new("shops")
+        .with_strategy(strategy)
+        .with_reputation(tracker);
+    group.add_supplier(Shop { name: "steady", fail: false, delay: Duration::ZERO });
+    group.add_supplier(Shop { name: "broken", fail: true, delay: Duration::ZERO });
+    group.add_supplier(Shop { name: "slow", fail: false, delay: Duration::from_millis(40) });
+    group
+}
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, Value::Null)
+}
+
+#[test]
+fn group_records_success_and_latency() {
+    for strategy in [QueryStrategy::Sequential, QueryStrategy::Parallel] {
+        let tracker = Arc::new(ReputationTracker::new(Duration::from_millis(10)));
+        let group = group(strategy, tracker.clone());
+        group.query(search());
+        group.query_streamed(search()).for_each(drop);
+
+        assert_eq!(tracker.reputation("steady").unwrap().samples, 2);
+        assert_eq!(tracker.weight("steady"), 1.0);
+        assert_eq!(tracker.weight("broken"), 0.0);
+        let slow = tracker.weight("slow");
+        assert!(slow > 0.0 && slow < 0.5, "slow weight was {slow}");
+    }
+}
+
+#[test]
+fn batch_queries_are_recorded_per_request() {
+    let tracker = Arc::new(ReputationTracker::default());
+    let group = group(QueryStrategy::Sequential, tracker.clone());
+    group.query_batch(vec![search(), search(), search()]);
+
+    assert_eq!(tracker.reputation("broken").unwrap().samples, 3);
+}
+
+#[test]
+fn success_rate_recovers_gradually() {
+    let tracker = ReputationTracker::default().with_smoothing(0.5);
+    tracker.record("a", false, Duration::ZERO);
+    tracker.record("a", true, Duration::ZERO);
+    assert_eq!(tracker.weight("a"), 0.5);
+    tracker.record("a", true, Duration::ZERO);
+    assert_eq!(tracker.weight("a"), 0.75);
+}
+
+#[test]
+fn base_weight_scales_observed_weight() {
+    let tracker = ReputationTracker::default().with_smoothing(0.5);
+    tracker.set_base_weight("a", 4.0);
+    assert_eq!(tracker.weight("a"), 4.0);
+    tracker.record("a", false, Duration::ZERO);
+    tracker.record("a", true, Duration::ZERO);
+    assert_eq!(tracker.weight("a"), 2.0);
+    assert_eq!(tracker.weights().len(), 1);
+}
+
+#[test]
+fn tracker_ranks_items_by_supplier_weight() {
+    let tracker = Arc::new(ReputationTracker::new(Duration::from_millis(10)));
+    let group = group(QueryStrategy::Sequential, tracker.clone());
+    let result = group.query(search());
+
+    let ranked = ranked_merge(&result, "/items", tracker.as_ref());
+    let suppliers: Vec<&str> = ranked.iter().map(|r| r.supplier.as_str()).collect();
+    assert_eq!(suppliers, vec!["steady", "slow"]);
+}
+
+#[test]
+fn adaptive_groups_prefer_suppliers_of_higher_weight() {
+    let tracker = Arc::new(ReputationTracker::default());
+    tracker.set_base_weight("broken", 0.0);
+    let mut group = BasicSupplierGroup::new("shops")
+        .with_strategy(QueryStrategy::Adaptive)
+        .with_reputation(tracker.clone());
+    group.add_supplier(Shop { name: "steady", fail: false, delay: Duration::ZERO });
+    group.add_supplier(Shop { name: "broken", fail: true, delay: Duration::ZERO });
+
+    for _ in 0..50 {
+        assert_eq!(group.query(search()).successes.len(), 1);
+    }
+    assert_eq!(tracker.reputation("broken").unwrap().samples, 0);
+}
+
+#[test]
+fn groups_skip_suppliers_the_tracker_ejects() {
+    let tracker = Arc::new(ReputationTracker::default().with_ejection(0.5, 2, Duration::from_secs(60)));
+    let group = group(QueryStrategy::Parallel, tracker.clone());
+
+    group.query(search());
+    group.query(search());
+    assert_eq!(tracker.ejected(), ["broken"]);
+
+    let result = group.query(search());
+    assert!(result.failures.is_empty());
+    assert_eq!(result.successes.len(), 2);
+}
@@ -0,0 +1,72 @@
+use std::sync::{Arc, Mutex};
+use serde_json::json;
+use supplier_kit::context::RequestContext;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+
+/// Records the request ID of every request it receives.
+struct IdRecorder {
+    name: &'static str,
+    seen: Arc<Mutex<Vec<Option<String>>>>,
+}
+
+impl Supplier for IdRecorder {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        self.seen.lock().unwrap().push(request.context.request_id);
+        Ok(SupplierResponse::new(json!({})))
+    }
+}
+
+fn recording_group(seen: &Arc<Mutex<Vec<Option<String>>>>) -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new("ids");
+    group.add_supplier(IdRecorder { name: "a", seen: seen.clone() });
+    group.add_supplier(IdRecorder { name: "b", seen: seen.clone() });
+    group
+}
+
+#[test]
+fn test_group_assigns_one_request_id_per_query() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let counter = Arc::new(Mutex::new(0));
+    let next = counter.clone();
+    let group = recording_group(&seen).with_id_generator(move || {
+        let mut n = next.lock().unwrap();
+        *n += 1;
+        format!("id-{}", n)
+    });
+
+    group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+    group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+
+    let seen = seen.lock().unwrap();
+    let ids: Vec<_> = seen.iter().map(|id| id.as_deref().unwrap()).collect();
+    assert_eq!(ids, ["id-1", "id-1", "id-2", "id-2"]);
+}
+
+#[test]
+fn test_existing_request_id_is_preserved() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let group = recording_group(&seen);
+
+    let request = SupplierRequest::new(SupplierOperation::Search, json!({}))
+        .with_context(RequestContext::new().with_request_id("upstream-trace"));
+    group.query(request);
+
+    assert!(seen.lock().unwrap().iter().all(|id| id.as_deref() == Some("upstream-trace")));
+}
+
+#[test]
+fn test_default_generator_produces_uuid_v7() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    recording_group(&seen).query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+
+    let id = seen.lock().unwrap()[0].clone().unwrap();
+    assert_eq!(id.len(), 36);
+    assert_eq!(&id[14..15], "7");
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::health::HealthMap;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::outlier::ErrorEjector;
+use supplier_kit::requirements::{SupplierRequirement, SupplierRequirements};
+use supplier_kit::routing::{RoutingRule, RoutingRules, Test};
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }))
+}
+
+fn shop(name: &str) -> MockSupplier {
+    MockSupplierBuilder::new(name).respond_default(json!([])).build()
+}
+
+fn marketplaces() -> SupplierRequirements {
+    SupplierRequirements::new().with_requirement(SupplierOperation::Search, SupplierRequirement::any_of(&["amazon", "ebay"]))
+}
+
+#[test]
+fn every_requirement_of_the_operation_must_be_met() {
+    let requirements = SupplierRequirements::new()
+        .with_requirement(SupplierOperation::Other("Price Check".into()), SupplierRequirement::all_of(&["pricing"]))
+        .with_requirement(SupplierOperation::Other("price_check".into()), SupplierRequirement::at_least(2, &["a", "b", "c"]));
+    let price_check = SupplierOperation::from("price_check");
+    assert_eq!(requirements.requirements(&price_check).len(), 2);
+    assert_eq!(requirements.required_suppliers(&price_check), ["a", "b", "c", "pricing"]);
+
+    let down = |names: &'static [&'static str]| move |name: &str| names.contains(&name).then(|| "not ready".to_string());
+    assert!(requirements.check(&price_check, down(&["c"])).is_ok());
+
+    let error = requirements.check(&price_check, down(&["b", "c"])).unwrap_err();
+    assert_eq!(error.code(), "insufficient_suppliers");
+    assert_eq!(error.to_string(), "insufficient suppliers: price_check requires 2 of a, b, c; b: not ready, c: not ready");
+    assert!(requirements.check(&price_check, down(&["pricing"])).is_err());
+    assert!(requirements.check(&SupplierOperation::Search, down(&["pricing"])).is_ok());
+}
+
+#[test]
+fn queries_fail_fast_once_required_suppliers_are_ejected() {
+    let ejector = Arc::new(ErrorEjector::new(Duration::from_secs(60), Duration::from_secs(600)));
+    let amazon = MockSupplierBuilder::new("amazon").then_fail(SupplierError::Unauthorized).build();
+    let ebay = MockSupplierBuilder::new("ebay").then_fail(SupplierError::Unauthorized).build();
+    let etsy = shop("etsy");
+    let mut group = BasicSupplierGroup::new("marketplaces")
+        .with_error_ejection(ejector)
+        .with_requirements(marketplaces());
+    group.add_supplier(amazon.clone());
+    group.add_supplier(ebay.clone());
+    group.add_supplier(etsy.clone());
+
+    let first = group.query(search());
+    assert_eq!((first.successes.len(), first.failures.len()), (1, 2));
+
+    let result = group.query(search());
+    assert!(result.successes.is_empty());
+    let names: Vec<&str> = result.failures.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["amazon", "ebay"]);
+    assert!(matches!(&result.failures[0].1, SupplierError::InsufficientSuppliers(message)
+        if message == "search requires 1 of amazon, ebay; amazon: ejected after unauthorized or throttled calls, ebay: ejected after unauthorized or throttled calls"));
+    assert_eq!((amazon.calls(), ebay.calls(), etsy.calls()), (1, 1, 1));
+
+    // Operations without requirements still fan out.
+    let detail = SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": 7 }));
+    assert_eq!(group.query(detail).successes.len(), 1);
+}
+
+#[test]
+fn routing_and_membership_count_towards_requirements() {
+    let rules = RoutingRules::new(vec![RoutingRule::to(&["etsy"]).when("$.category", Test::Equals(json!("crafts")))]);
+    let mut group = BasicSupplierGroup::new("marketplaces").with_routing(rules).with_requirements(marketplaces());
+    group.add_supplier(shop("amazon"));
+    group.add_supplier(shop("etsy"));
+
+    assert!(group.check_requirements(&search()).is_ok());
+    let crafts = SupplierRequest::new(SupplierOperation::Search, json!({ "category": "crafts" }));
+    let error = group.check_requirements(&crafts).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "insufficient suppliers: search requires 1 of amazon, ebay; amazon: not selected by routing rules, ebay: not a member of the group"
+    );
+    assert!(group.requirements().is_some());
+}
+
+#[test]
+fn every_query_kind_checks_requirements() {
+    let health = Arc::new(HealthMap::new());
+    let amazon = shop("amazon");
+    let etsy = shop("etsy");
+    let requirements = marketplaces()
+        .with_requirement(SupplierOperation::GetDetail, SupplierRequirement::any_of(&["etsy"]));
+    let mut group = BasicSupplierGroup::new("marketplaces").with_health(health.clone()).with_requirements(requirements);
+    group.add_supplier(amazon.clone());
+    group.add_supplier(etsy.clone());
+    health.record("amazon", Err(SupplierError::Timeout), Duration::ZERO, 1);
+
+    let unmet = |error: &SupplierError| matches!(error, SupplierError::InsufficientSuppliers(m) if m.contains("amazon: unhealthy"));
+    let streamed: Vec<_> = group.query_streamed(search()).collect();
+    assert_eq!(streamed.len(), 2);
+    assert!(matches!(&streamed[0], (name, Err(error)) if name == "amazon" && unmet(error)));
+
+    let transformed = group.query_transformed(search(), |_, response| Ok(response.data));
+    assert!(transformed.successes.is_empty() && unmet(&transformed.failures[0].1));
+
+    let detail = SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": 7 }));
+    let batch = group.query_batch(vec![search(), detail]);
+    assert!(batch[0].successes.is_empty() && unmet(&batch[0].failures[0].1));
+    assert_eq!(batch[1].successes.len(), 1);
+    assert_eq!((amazon.calls(), etsy.calls()), (0, 1));
+
+    // Targeted requests are not checked.
+    let each = group.query_each(HashMap::from([("etsy".to_string(), search())]));
+    assert_eq!(each.successes.len(), 1);
+}
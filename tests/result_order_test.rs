@@ -0,0 +1,93 @@
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, ResultOrder, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }))
+}
+
+/// A group whose earlier suppliers answer later: `a` after 60ms, `b` after 30ms, `c` at once.
+/// `b` fails once before answering.
+fn staggered(strategy: QueryStrategy, order: ResultOrder) -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new("marketplaces").with_strategy(strategy).with_result_order(order);
+    group.add_supplier(MockSupplierBuilder::new("a").with_delay(Duration::from_millis(60)).respond_default(json!([])).build());
+    group.add_supplier(
+        MockSupplierBuilder::new("b")
+            .with_delay(Duration::from_millis(30))
+            .then_fail(SupplierError::Timeout)
+            .respond_default(json!([]))
+            .build(),
+    );
+    group.add_supplier(MockSupplierBuilder::new("c").respond_default(json!([])).build());
+    group
+}
+
+fn names<T>(entries: &[(String, T)]) -> Vec<&str> {
+    entries.iter().map(|(name, _)| name.as_str()).collect()
+}
+
+#[test]
+fn parallel_results_follow_supplier_order_by_default() {
+    let group = staggered(QueryStrategy::Parallel, ResultOrder::default());
+    assert_eq!(group.result_order(), ResultOrder::Supplier);
+
+    let mut result = group.query(search());
+    assert_eq!(names(&result.successes), ["a", "c"]);
+    assert_eq!(names(&result.failures), ["b"]);
+    let outcomes: Vec<&str> = result.outcomes.iter().map(|o| o.supplier.as_str()).collect();
+    assert_eq!(outcomes, ["a", "b", "c"]);
+
+    // Recovered suppliers take their place in supplier order.
+    assert_eq!(result.retry_failures(&group, search()), 1);
+    assert_eq!(names(&result.successes), ["a", "b", "c"]);
+    let outcomes: Vec<&str> = result.outcomes.iter().map(|o| o.supplier.as_str()).collect();
+    assert_eq!(outcomes, ["a", "b", "c"]);
+}
+
+#[test]
+fn completion_order_lists_the_fastest_first() {
+    let group = staggered(QueryStrategy::Parallel, ResultOrder::Completion);
+    let result = group.query(search());
+    assert_eq!(names(&result.successes), ["c", "a"]);
+    let outcomes: Vec<&str> = result.outcomes.iter().map(|o| o.supplier.as_str()).collect();
+    assert_eq!(outcomes, ["c", "b", "a"]);
+
+    let transformed = group.query_transformed(search(), |_, response| Ok(response.data));
+    assert_eq!(names(&transformed.successes), ["c", "b", "a"]);
+}
+
+#[test]
+fn priorities_define_the_supplier_order() {
+    let mut group = staggered(QueryStrategy::Parallel, ResultOrder::Supplier);
+    group.set_priority("c", 10).unwrap();
+    let result = group.query(search());
+    assert_eq!(names(&result.successes), ["c", "a"]);
+
+    let transformed = group.query_transformed(search(), |_, response| Ok(response.data));
+    assert_eq!(names(&transformed.successes), ["c", "a", "b"]);
+    group.add_supplier(MockSupplierBuilder::new("d").respond_default(json!([])).build());
+    assert_eq!(names(&group.query(search()).successes), ["c", "a", "b", "d"]);
+}
+
+#[test]
+fn batches_and_races_are_ordered_too() {
+    let group = staggered(QueryStrategy::Parallel, ResultOrder::Supplier);
+    let batch = group.query_batch(vec![search(), search()]);
+    assert_eq!(names(&batch[0].successes), ["a", "c"]);
+    assert_eq!(names(&batch[0].failures), ["b"]);
+
+    let completion = staggered(QueryStrategy::Parallel, ResultOrder::Completion).query_batch(vec![search()]);
+    assert_eq!(names(&completion[0].successes), ["c", "a"]);
+
+    // A race keeps the first success but lists failures that arrived before it in supplier order.
+    let mut race = BasicSupplierGroup::new("race").with_strategy(QueryStrategy::Race);
+    race.add_supplier(MockSupplierBuilder::new("late").with_delay(Duration::from_millis(40)).respond_default(json!([])).build());
+    race.add_supplier(MockSupplierBuilder::new("broken").then_fail(SupplierError::Timeout).build());
+    race.add_supplier(MockSupplierBuilder::new("slow").with_delay(Duration::from_millis(20)).then_fail(SupplierError::Timeout).build());
+    let result = race.query_batch(vec![search()]).remove(0);
+    assert_eq!(names(&result.successes), ["late"]);
+    assert_eq!(names(&result.failures), ["broken", "slow"]);
+}
@@ -0,0 +1,83 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+
+/// Fails for the first `failures` calls, then succeeds.
+struct FlakySupplier {
+    name: String,
+    failures: usize,
+    calls: Arc<AtomicUsize>,
+}
+
+impl FlakySupplier {
+    fn new(name: &str, failures: usize) -> (Self, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let supplier = Self {
+            name: name.to_string(),
+            failures,
+            calls: calls.clone(),
+        };
+        (supplier, calls)
+    }
+}
+
+impl Supplier for FlakySupplier {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+            Err(SupplierError::Timeout)
+        } else {
+            Ok(SupplierResponse::new(json!({ "supplier": self.name })))
+        }
+    }
+}
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "query": "retry" }))
+}
+
+#[test]
+fn test_retry_only_requeries_failed_suppliers() {
+    let (stable, stable_calls) = FlakySupplier::new("stable", 0);
+    let (flaky, flaky_calls) = FlakySupplier::new("flaky", 1);
+    let (broken, broken_calls) = FlakySupplier::new("broken", 100);
+
+    let mut group = BasicSupplierGroup::new("g");
+    group.add_supplier(stable);
+    group.add_supplier(flaky);
+    group.add_supplier(broken);
+
+    let mut result = group.query(request());
+    assert_eq!(result.successes.len(), 1);
+    assert_eq!(result.failures.len(), 2);
+
+    assert_eq!(result.retry_failures(&group, request()), 1);
+    assert_eq!(stable_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(flaky_calls.load(Ordering::SeqCst), 2);
+    assert_eq!(broken_calls.load(Ordering::SeqCst), 2);
+
+    let success_names: Vec<_> = result.successes.iter().map(|(n, _)| n.as_str()).collect();
+    assert_eq!(success_names, ["stable", "flaky"]);
+    assert_eq!(result.failures.len(), 1);
+    assert_eq!(result.failures[0].0, "broken");
+}
+
+#[test]
+fn test_failed_writes_are_not_retried() {
+    let (flaky, flaky_calls) = FlakySupplier::new("flaky", 1);
+    let mut group = BasicSupplierGroup::new("g");
+    group.add_supplier(flaky);
+
+    let create = SupplierRequest::new(SupplierOperation::Create, json!({ "sku": "lamp" }));
+    let mut result = group.query(create.clone());
+    assert_eq!(result.retry_failures(&group, create), 0);
+    assert_eq!(flaky_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(result.failures.len(), 1);
+}
@@ -0,0 +1,146 @@
+use serde_json::json;
+use supplier_kit::config::{Manifest, SupplierFactory};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::routing::{Condition, RoutingRule, RoutingRules, Test};
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn search(params: serde_json::Value) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, params)
+}
+
+fn shop(name: &str) -> MockSupplier {
+    MockSupplierBuilder::new(name).respond_default(json!({ "shop": name })).build()
+}
+
+fn names(result: &supplier_kit::supplier_group::SupplierGroupResult) -> Vec<&str> {
+    let mut names: Vec<&str> = result.successes.iter().map(|(name, _)| name.as_str()).collect();
+    names.sort();
+    names
+}
+
+fn book_routing() -> RoutingRules {
+    RoutingRules::new(vec![
+        RoutingRule::to(&["book_depot", "paper_trail"])
+            .for_operation(SupplierOperation::Search)
+            .when("$.category", Test::Equals(json!("books"))),
+        RoutingRule::to(&["gadget_hub"]).when("/filters/brand", Test::In(vec![json!("acme"), json!("globex")])),
+    ])
+}
+
+fn marketplaces(strategy: QueryStrategy) -> (BasicSupplierGroup, Vec<MockSupplier>) {
+    let shops: Vec<MockSupplier> = ["book_depot", "paper_trail", "gadget_hub"].iter().map(|name| shop(name)).collect();
+    let mut group = BasicSupplierGroup::new("marketplaces").with_strategy(strategy).with_routing(book_routing());
+    for shop in &shops {
+        group.add_supplier(shop.clone());
+    }
+    (group, shops)
+}
+
+#[test]
+fn conditions_select_the_receiving_suppliers() {
+    let (group, shops) = marketplaces(QueryStrategy::Parallel);
+
+    assert_eq!(names(&group.query(search(json!({ "category": "books" })))), ["book_depot", "paper_trail"]);
+    assert_eq!(names(&group.query(search(json!({ "filters": { "brand": "acme" } })))), ["gadget_hub"]);
+    assert_eq!(names(&group.query(search(json!({ "category": "lamps" })))).len(), 3);
+    assert_eq!(shops[2].calls(), 2);
+}
+
+#[test]
+fn rules_only_apply_to_their_operations() {
+    let (group, _) = marketplaces(QueryStrategy::Sequential);
+    let detail = SupplierRequest::new(SupplierOperation::GetDetail, json!({ "category": "books" }));
+    assert_eq!(group.query(detail).successes.len(), 3);
+}
+
+#[test]
+fn streams_and_dry_runs_follow_the_rules() {
+    let (group, _) = marketplaces(QueryStrategy::Race);
+    let streamed: Vec<String> = group.query_streamed(search(json!({ "filters": { "brand": "globex" } }))).map(|(name, _)| name).collect();
+    assert_eq!(streamed, ["gadget_hub"]);
+
+    let plan = group.plan(&search(json!({ "category": "books" })));
+    let skipped: Vec<(&str, Option<&str>)> = plan.iter().map(|p| (p.name.as_str(), p.skip_reason.as_deref())).collect();
+    assert_eq!(
+        skipped,
+        [
+            ("book_depot", None),
+            ("paper_trail", None),
+            ("gadget_hub", Some("not selected by routing rules")),
+        ]
+    );
+}
+
+#[test]
+fn batches_send_each_supplier_only_its_requests() {
+    let (group, shops) = marketplaces(QueryStrategy::Parallel);
+    let results = group.query_batch(vec![
+        search(json!({ "category": "books" })),
+        search(json!({ "filters": { "brand": "acme" } })),
+        search(json!({})),
+    ]);
+
+    assert_eq!(names(&results[0]), ["book_depot", "paper_trail"]);
+    assert_eq!(names(&results[1]), ["gadget_hub"]);
+    assert_eq!(names(&results[2]).len(), 3);
+    assert_eq!(shops[0].requests().len(), 2);
+    assert_eq!(shops[2].requests().len(), 2);
+}
+
+#[test]
+fn jsonpath_and_pointer_paths_read_params() {
+    let params = json!({ "items": [{ "sku": "A-1" }], "a/b": 1, "note": null });
+    assert!(Condition::new("$.items[0].sku", Test::Equals(json!("A-1"))).matches(&params));
+    assert!(Condition::new("$.items[0]['sku']", Test::Equals(json!("A-1"))).matches(&params));
+    assert!(Condition::new("$['a/b']", Test::Equals(json!(1))).matches(&params));
+    assert!(Condition::new("/items/0/sku", Test::NotEquals(json!("B-2"))).matches(&params));
+    assert!(Condition::new("$.note", Test::Exists(true)).matches(&params));
+    assert!(Condition::new("$.missing", Test::Exists(false)).matches(&params));
+    assert!(!Condition::new("items.sku", Test::Exists(false)).matches(&params));
+
+    let invalid = RoutingRule::to(&["a"]).when("$.items[first]", Test::Exists(true));
+    assert!(matches!(invalid.validate(), Err(SupplierError::InvalidInput(message)) if message.contains("$.items[first]")));
+}
+
+#[test]
+fn manifests_declare_routes_per_group() {
+    let manifest = Manifest::from_json(
+        r#"{
+            "suppliers": [
+                { "name": "book_depot", "type": "mock", "config": { "default": [] } },
+                { "name": "gadget_hub", "type": "mock", "config": { "default": [] } }
+            ],
+            "groups": [{
+                "name": "marketplaces",
+                "suppliers": ["book_depot", "gadget_hub"],
+                "routes": [{
+                    "operations": ["search"],
+                    "conditions": [{ "path": "$.category", "equals": "books" }],
+                    "suppliers": ["book_depot"]
+                }]
+            }]
+        }"#,
+    )
+    .unwrap();
+    let loaded = SupplierFactory::new().load(&manifest).unwrap();
+    let group = &loaded.groups["marketplaces"];
+
+    assert_eq!(names(&group.query(search(json!({ "category": "books" })))), ["book_depot"]);
+    assert_eq!(group.routing().unwrap().rules().len(), 1);
+    assert_eq!(serde_json::to_value(&manifest.groups[0].routes[0]).unwrap()["conditions"][0]["equals"], "books");
+}
+
+#[test]
+fn manifests_reject_routes_to_non_members() {
+    let manifest = Manifest::from_json(
+        r#"{
+            "suppliers": [{ "name": "book_depot", "type": "mock", "config": {} }],
+            "groups": [{ "name": "g", "suppliers": ["book_depot"], "routes": [{ "suppliers": ["gadget_hub"] }] }]
+        }"#,
+    )
+    .unwrap();
+    let error = SupplierFactory::new().load(&manifest).err().unwrap();
+    assert!(matches!(error, SupplierError::InvalidInput(message) if message.contains("'gadget_hub'")));
+}
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::orchestration::Saga;
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn create(params: serde_json::Value) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Create, params)
+}
+
+fn cancel(data: &serde_json::Value) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Delete, data.clone())
+}
+
+fn booking(name: &str, id: &str) -> Arc<MockSupplier> {
+    Arc::new(
+        MockSupplierBuilder::new(name)
+            .respond(SupplierOperation::Create, json!({ "id": id }))
+            .respond(SupplierOperation::Delete, json!({ "cancelled": id }))
+            .build(),
+    )
+}
+
+#[test]
+fn steps_run_in_order_and_see_earlier_responses() {
+    let inventory = booking("inventory", "R-1");
+    let payments = booking("payments", "P-1");
+
+    let outcome = Saga::new("checkout")
+        .with_id_generator(Arc::new(|| "saga-1".to_string()))
+        .step("reserve", inventory.clone(), create(json!({ "sku": "A1" })))
+        .unwrap()
+        .step_with("charge", payments.clone(), |context| {
+            let reservation = &context.response("reserve").unwrap().data["id"];
+            create(json!({ "reservation": reservation, "amount": 42 }))
+        })
+        .unwrap()
+        .run();
+
+    assert!(outcome.is_success());
+    assert_eq!(outcome.saga_id, "saga-1");
+    assert_eq!(outcome.response("charge").unwrap().data["id"], "P-1");
+
+    let charge = payments.last_request().unwrap();
+    assert_eq!(charge.params["reservation"], "R-1");
+    assert_eq!(charge.idempotency_key.as_deref(), Some("saga-1:charge"));
+    assert_eq!(charge.context.request_id.as_deref(), Some("saga-1"));
+}
+
+#[test]
+fn failure_compensates_completed_steps_in_reverse() {
+    let hotel = booking("hotel", "H-1");
+    let car = booking("car", "C-1");
+    let note = Arc::new(MockSupplierBuilder::new("notes").respond_default(json!({})).build());
+    let flight = Arc::new(MockSupplierBuilder::new("flight").then_fail(SupplierError::Upstream("sold out".into())).build());
+
+    let outcome = Saga::new("trip")
+        .with_id_generator(Arc::new(|| "saga-2".to_string()))
+        .step("hotel", hotel.clone(), create(json!({})))
+        .unwrap()
+        .compensate(|_, booked| cancel(&booked.data))
+        .step("note", note.clone(), create(json!({})))
+        .unwrap()
+        .step("car", car.clone(), create(json!({})))
+        .unwrap()
+        .compensate(|_, booked| cancel(&booked.data))
+        .step("flight", flight, create(json!({})))
+        .unwrap()
+        .run();
+
+    assert!(!outcome.is_success());
+    assert!(outcome.is_rolled_back());
+    let failure = outcome.failure.as_ref().unwrap();
+    assert_eq!(failure.step, "flight");
+    assert!(matches!(failure.error, SupplierError::Upstream(_)));
+    assert_eq!(outcome.compensated, ["car", "hotel"]);
+    assert_eq!(note.calls(), 1, "steps without compensation are left alone");
+
+    let undo = hotel.last_request().unwrap();
+    assert_eq!(undo.operation, SupplierOperation::Delete);
+    assert_eq!(undo.params["id"], "H-1");
+    assert_eq!(undo.idempotency_key.as_deref(), Some("saga-2:hotel:compensate"));
+}
+
+#[test]
+fn compensations_are_retried_and_failures_reported() {
+    let flaky = Arc::new(
+        MockSupplierBuilder::new("flaky")
+            .then_respond(json!({ "id": "F-1" }))
+            .then_fail(SupplierError::Timeout)
+            .then_respond(json!({ "cancelled": true }))
+            .build(),
+    );
+    let broken = Arc::new(
+        MockSupplierBuilder::new("broken")
+            .then_respond(json!({ "id": "B-1" }))
+            .then_fail(SupplierError::Timeout)
+            .then_fail(SupplierError::Timeout)
+            .build(),
+    );
+    let failing = Arc::new(MockSupplierBuilder::new("failing").build());
+
+    let outcome = Saga::new("retries")
+        .with_compensation_retries(1)
+        .step("broken", broken.clone(), create(json!({})))
+        .unwrap()
+        .compensate(|_, r| cancel(&r.data))
+        .step("flaky", flaky.clone(), create(json!({})))
+        .unwrap()
+        .compensate(|_, r| cancel(&r.data))
+        .step("failing", failing, create(json!({})))
+        .unwrap()
+        .run();
+
+    assert_eq!(outcome.compensated, ["flaky"]);
+    assert_eq!(flaky.calls(), 3);
+    assert!(matches!(&outcome.compensation_failures[..], [(step, SupplierError::Timeout)] if step == "broken"));
+    assert_eq!(broken.calls(), 3);
+    assert!(!outcome.is_rolled_back());
+}
+
+#[test]
+fn duplicate_step_names_are_rejected() {
+    let saga = Saga::new("trip").step("hotel", booking("hotel", "H-1"), create(json!({}))).unwrap();
+    let again = saga.step("hotel", booking("hotel", "H-2"), create(json!({})));
+    assert!(matches!(again, Err(SupplierError::AlreadyExists(name)) if name == "hotel"));
+}
+
+#[test]
+fn panicking_actions_fail_the_step_and_compensate_earlier_steps() {
+    let hotel = booking("hotel", "H-1");
+    let car = booking("car", "C-1");
+
+    let outcome = Saga::new("trip")
+        .step("hotel", hotel.clone(), create(json!({})))
+        .unwrap()
+        .compensate(|_, booked| cancel(&booked.data))
+        .step("car", car.clone(), create(json!({})))
+        .unwrap()
+        .compensate(|_, _| panic!("no cancellation endpoint"))
+        .step_with("flight", booking("flight", "F-1"), |_| panic!("no seats"))
+        .unwrap()
+        .run();
+
+    let failure = outcome.failure.as_ref().unwrap();
+    assert_eq!(failure.step, "flight");
+    assert!(matches!(&failure.error, SupplierError::Internal(message) if message.contains("'flight' panicked")));
+    assert!(matches!(&outcome.compensation_failures[..], [(step, SupplierError::Internal(_))] if step == "car"));
+    assert_eq!(outcome.compensated, ["hotel"]);
+    assert_eq!(hotel.last_request().unwrap().operation, SupplierOperation::Delete);
+    assert_eq!(car.calls(), 1);
+}
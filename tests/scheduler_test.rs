@@ -0,0 +1,129 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, UNIX_EPOCH};
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::scheduler::{
+    CronExpr, LatestResults, Schedule, ScheduledJob, ScheduledOutcome, ScheduledResult, Scheduler,
+};
+use supplier_kit::supplier_group::BasicSupplierGroup;
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "category": "lamps" }))
+}
+
+/// Seconds since the epoch of a UTC date and time.
+fn at(days: u64, hour: u64, minute: u64) -> std::time::SystemTime {
+    UNIX_EPOCH + Duration::from_secs(days * 86_400 + hour * 3600 + minute * 60)
+}
+
+#[test]
+fn interval_jobs_deliver_to_channels() {
+    let catalog = MockSupplierBuilder::new("catalog").respond_default(json!({ "items": [] })).build();
+    let (tx, rx) = mpsc::channel::<ScheduledResult>();
+
+    let handle = Scheduler::new()
+        .job(
+            ScheduledJob::supplier("catalog", Arc::new(catalog.clone()), request(), Schedule::Every(Duration::from_millis(20)))
+                .sink(Arc::new(tx)),
+        )
+        .start();
+
+    for _ in 0..3 {
+        let result = rx.recv_timeout(Duration::from_secs(2)).unwrap();
+        assert_eq!(result.job, "catalog");
+        assert!(matches!(result.outcome, ScheduledOutcome::Supplier(Ok(_))));
+    }
+    handle.stop();
+
+    let calls = catalog.calls();
+    std::thread::sleep(Duration::from_millis(60));
+    assert_eq!(catalog.calls(), calls);
+}
+
+#[test]
+fn group_jobs_deliver_to_callbacks_and_caches() {
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.add_supplier(MockSupplierBuilder::new("shop_a").respond_default(json!({ "n": 1 })).build());
+    group.add_supplier(MockSupplierBuilder::new("shop_b").then_fail(SupplierError::Timeout).build());
+
+    let delivered = Arc::new(AtomicUsize::new(0));
+    let counter = delivered.clone();
+    let latest = Arc::new(LatestResults::new());
+
+    let handle = Scheduler::new()
+        .job(
+            ScheduledJob::group("refresh", Arc::new(group), request(), Schedule::Every(Duration::from_secs(3600)))
+                .run_on_start()
+                .sink(Arc::new(move |_: &ScheduledResult| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }))
+                .sink(latest.clone()),
+        )
+        .start();
+
+    while latest.get("refresh").is_none() {
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    handle.stop();
+
+    assert_eq!(delivered.load(Ordering::SeqCst), 1);
+    let ScheduledOutcome::Group(result) = latest.get("refresh").unwrap().outcome else {
+        panic!("expected a group outcome");
+    };
+    assert_eq!(result.successes.len(), 1);
+    assert_eq!(result.failures.len(), 1);
+}
+
+#[test]
+fn cron_finds_the_next_matching_minute() {
+    // 1970-01-01 was a Thursday.
+    let every_quarter = CronExpr::parse("*/15 * * * *").unwrap();
+    assert_eq!(every_quarter.next_after(at(0, 10, 7)), Some(at(0, 10, 15)));
+    assert_eq!(every_quarter.next_after(at(0, 10, 15)), Some(at(0, 10, 30)));
+    assert_eq!(every_quarter.next_after(at(0, 23, 50)), Some(at(1, 0, 0)));
+
+    let weekdays_at_six = CronExpr::parse("0 6 * * 1-5").unwrap();
+    assert_eq!(weekdays_at_six.next_after(at(1, 7, 0)), Some(at(4, 6, 0)));
+
+    let sundays = CronExpr::parse("30 2 * * 7").unwrap();
+    assert_eq!(sundays.next_after(at(0, 0, 0)), Some(at(3, 2, 30)));
+
+    // 1970-03-01 is day 59; either the 1st of the month or a Monday matches.
+    let first_or_monday = CronExpr::parse("0 0 1 3 1").unwrap();
+    assert_eq!(first_or_monday.next_after(at(0, 0, 0)), Some(at(59, 0, 0)));
+    assert_eq!(first_or_monday.next_after(at(59, 0, 0)), Some(at(60, 0, 0)));
+
+    let leap_day = CronExpr::parse("0 12 29 2 *").unwrap();
+    // 1972-02-29 is day 789.
+    assert_eq!(leap_day.next_after(at(0, 0, 0)), Some(at(789, 12, 0)));
+}
+
+#[test]
+fn invalid_cron_expressions_are_rejected() {
+    for expression in ["* * * *", "60 * * * *", "*/0 * * * *", "5-1 * * * *", "a * * * *", "* * 0 * *"] {
+        assert!(
+            matches!(Schedule::cron(expression), Err(SupplierError::InvalidInput(_))),
+            "{expression}"
+        );
+    }
+    assert!(Schedule::cron("0,30 9-17/2 1-15 */3 mon").is_err());
+    assert!(Schedule::cron("0,30 9-17/2 1-15 */3 1").is_ok());
+}
+
+#[test]
+fn dropping_the_handle_stops_jobs() {
+    let supplier = MockSupplierBuilder::new("poll").respond_default(json!({})).build();
+    let handle = Scheduler::new()
+        .job(ScheduledJob::supplier("poll", Arc::new(supplier.clone()), request(), Schedule::Every(Duration::from_millis(10))))
+        .start();
+    std::thread::sleep(Duration::from_millis(50));
+    drop(handle);
+
+    let calls = supplier.calls();
+    assert!(calls >= 1);
+    std::thread::sleep(Duration::from_millis(50));
+    assert_eq!(supplier.calls(), calls);
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::fs;
+use std::sync::{Arc, Mutex};
+use serde_json::{json, Value};
+use supplier_kit::config::{BuildContext, Manifest, SupplierFactory, SupplierSpec};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::secrets::{resolve_secrets, ChainedSecrets, EnvSecrets, FileSecrets, SecretsProvider};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn secrets(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+#[test]
+fn env_secrets_map_names_to_variables() {
+    let provider = EnvSecrets::with_prefix("SUPPLIER_KIT_TEST_");
+    assert_eq!(provider.variable("shop-a.api_key"), "SUPPLIER_KIT_TEST_SHOP_A_API_KEY");
+
+    // SAFETY: the variable is unique to this test and no other thread reads it.
+    unsafe { std::env::set_var("SUPPLIER_KIT_TEST_SHOP_A_API_KEY", "k-env") };
+    assert_eq!(provider.secret("shop-a.api_key").unwrap().as_deref(), Some("k-env"));
+    assert_eq!(provider.secret("shop-b.api_key").unwrap(), None);
+}
+
+#[test]
+fn file_secrets_read_one_file_per_secret() {
+    let dir = std::env::temp_dir().join(format!("supplier_kit_secrets_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("shop_a_key"), "k-file\n").unwrap();
+
+    let provider = FileSecrets::new(&dir);
+    assert_eq!(provider.secret("shop_a_key").unwrap().as_deref(), Some("k-file"));
+    assert_eq!(provider.secret("missing").unwrap(), None);
+    assert!(matches!(provider.secret("../etc/passwd"), Err(SupplierError::InvalidInput(_))));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn chains_ask_providers_in_order() {
+    let chain = ChainedSecrets::new()
+        .with(Arc::new(secrets(&[("a", "first")])))
+        .with(Arc::new(secrets(&[("a", "second"), ("b", "fallback")])))
+        .with(Arc::new(|name: &str| Ok((name == "c").then(|| "custom".to_string()))));
+
+    assert_eq!(chain.secret("a").unwrap().as_deref(), Some("first"));
+    assert_eq!(chain.secret("b").unwrap().as_deref(), Some("fallback"));
+    assert_eq!(chain.secret("c").unwrap().as_deref(), Some("custom"));
+    assert_eq!(chain.secret("d").unwrap(), None);
+}
+
+#[test]
+fn references_are_resolved_anywhere_in_a_config() {
+    let config = json!({
+        "headers": [{ "name": "X-Key", "value": { "$secret": "key" } }],
+        "literal": { "$secret": "key", "other": 1 },
+    });
+    let resolved = resolve_secrets(&config, &secrets(&[("key", "k-1")])).unwrap();
+
+    assert_eq!(resolved["headers"][0]["value"], "k-1");
+    assert_eq!(resolved["literal"], json!({ "$secret": "key", "other": 1 }));
+}
+
+fn capturing_factory(captured: Arc<Mutex<Option<Value>>>) -> SupplierFactory {
+    SupplierFactory::empty().register("http", move |spec: &SupplierSpec, _: &BuildContext| {
+        *captured.lock().unwrap() = Some(spec.config.clone());
+        Ok(Arc::new(MockSupplierBuilder::new(&spec.name).build()) as Arc<dyn Supplier>)
+    })
+}
+
+fn manifest() -> Manifest {
+    Manifest::from_json(
+        r#"{ "suppliers": [{ "name": "shop_a", "type": "http", "config": { "api_key": { "$secret": "shop_a_key" } } }] }"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn factory_resolves_secrets_before_construction() {
+    let captured = Arc::new(Mutex::new(None));
+    let factory = capturing_factory(captured.clone()).with_secrets(Arc::new(secrets(&[("shop_a_key", "k-123")])));
+
+    factory.load(&manifest()).unwrap();
+    assert_eq!(captured.lock().unwrap().as_ref().unwrap()["api_key"], "k-123");
+}
+
+#[test]
+fn unresolvable_secrets_fail_the_load_without_leaking_values() {
+    let captured = Arc::new(Mutex::new(None));
+
+    let missing = capturing_factory(captured.clone())
+        .with_secrets(Arc::new(secrets(&[("other", "do-not-print")])))
+        .load(&manifest());
+    assert!(matches!(
+        missing,
+        Err(SupplierError::InvalidInput(msg))
+            if msg.contains("shop_a") && msg.contains("'shop_a_key' is not defined") && !msg.contains("do-not-print")
+    ));
+
+    let unconfigured = capturing_factory(captured.clone()).load(&manifest());
+    assert!(matches!(unconfigured, Err(SupplierError::InvalidInput(msg)) if msg.contains("no secrets provider")));
+    assert!(captured.lock().unwrap().is_none());
+}
+
+#[test]
+fn constructors_can_read_secrets_from_the_context() {
+    let context = BuildContext {
+        secrets: Some(Arc::new(secrets(&[("token", "t-1")]))),
+        ..BuildContext::default()
+    };
+    assert_eq!(context.secret("token").unwrap(), "t-1");
+    assert!(BuildContext::default().secret("token").is_err());
+}
@@ -0,0 +1,238 @@
+#![cfg(feature = "axum")]
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use axum::Router;
+use serde_json::{json, Value};
+use supplier_kit::access::AccessPolicy;
+use supplier_kit::context::Priority;
+use supplier_kit::deprecation::Deprecation;
+use supplier_kit::descriptor::SupplierDescriptor;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::health::HealthMap;
+use supplier_kit::models::{SupplierRequest, SupplierResponse};
+use supplier_kit::server::GroupRouter;
+use supplier_kit::supplier::{Supplier, SupplierRegistry};
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroupResult};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+use tower::ServiceExt;
+
+struct Shop {
+    name: &'static str,
+    ready: bool,
+}
+
+impl Supplier for Shop {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        if self.name == "broken" {
+            return Err(SupplierError::Upstream("down".into()));
+        }
+        Ok(SupplierResponse::new(json!({ "shop": self.name, "q": request.params["q"] })))
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn describe(&self) -> SupplierDescriptor {
+        SupplierDescriptor::new(self.name).with_version("1.0.0")
+    }
+}
+
+fn router(broken_ready: bool) -> Router {
+    let mut group = BasicSupplierGroup::new("marketplace");
+    group.add_supplier(Shop { name: "shop_a", ready: true });
+    group.add_supplier(Shop { name: "broken", ready: true });
+
+    let mut registry = SupplierRegistry::new();
+    registry.register("shop_a", Shop { name: "shop_a", ready: true });
+    registry.register("broken", Shop { name: "broken", ready: broken_ready });
+
+    GroupRouter::new().group(group).with_registry(Arc::new(registry)).build()
+}
+
+async fn send(router: Router, request: Request<Body>) -> (StatusCode, Value) {
+    let response = router.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+fn post(uri: &str, body: Value) -> Request<Body> {
+    Request::post(uri)
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn query_endpoint_returns_the_group_result() {
+    let request = post("/groups/marketplace/query", json!({ "operation": "search", "params": { "q": "lamp" } }));
+    let (status, body) = send(router(true), request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let result: SupplierGroupResult = serde_json::from_value(body).unwrap();
+    assert_eq!(result.successes.len(), 1);
+    assert_eq!(result.successes[0].0, "shop_a");
+    assert_eq!(result.successes[0].1.data["q"], "lamp");
+    assert!(matches!(result.failures[0], (ref name, SupplierError::Upstream(_)) if name == "broken"));
+}
+
+#[tokio::test]
+async fn unknown_group_is_not_found() {
+    let request = post("/groups/missing/query", json!({ "operation": "search", "params": {} }));
+    let (status, body) = send(router(true), request).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["code"], "not_found");
+}
+
+#[tokio::test]
+async fn health_reports_supplier_readiness() {
+    let get = || Request::get("/health").body(Body::empty()).unwrap();
+
+    let (status, body) = send(router(true), get()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "ok");
+    assert_eq!(body["groups"], json!(["marketplace"]));
+
+    let (_, body) = send(router(false), get()).await;
+    assert_eq!(body["status"], "degraded");
+    assert_eq!(body["suppliers"]["broken"]["ready"], false);
+    assert_eq!(body["suppliers"]["shop_a"]["ready"], true);
+}
+
+#[tokio::test]
+async fn health_agrees_with_the_readiness_probe() {
+    let health = Arc::new(HealthMap::new());
+    health.record("broken", Err(SupplierError::Timeout), Duration::ZERO, 1);
+    let notices = Arc::new(Mutex::new(0));
+    let counter = notices.clone();
+    let mut registry = SupplierRegistry::new();
+    registry.register("shop_a", Shop { name: "shop_a", ready: true });
+    registry.register("broken", Shop { name: "broken", ready: true });
+    registry.set_critical("shop_a", true).unwrap();
+    registry.set_health_map(health, None);
+    registry.on_deprecation(move |_| *counter.lock().unwrap() += 1);
+    registry.deprecate("shop_a", Deprecation::new(SystemTime::now() + Duration::from_secs(3600), Duration::ZERO)).unwrap();
+    let registry = Arc::new(registry);
+    let router = || GroupRouter::new().with_registry(registry.clone()).build();
+    let get = |uri: &str| Request::get(uri).body(Body::empty()).unwrap();
+
+    // Only the critical supplier counts, so the unhealthy one does not degrade the service.
+    let (_, body) = send(router(), get("/health")).await;
+    assert_eq!(body["status"], "ok");
+    assert_eq!(body["suppliers"]["broken"]["ready"], false);
+    assert_eq!(body["suppliers"]["shop_a"]["ready"], true);
+    assert_eq!(send(router(), get("/ready")).await.0, StatusCode::OK);
+    assert_eq!(*notices.lock().unwrap(), 0);
+}
+
+#[tokio::test]
+async fn probes_answer_503_when_failing() {
+    let get = |uri: &str| Request::get(uri).body(Body::empty()).unwrap();
+
+    let (status, body) = send(router(true), get("/ready")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["passing"], json!(["broken", "shop_a"]));
+
+    let (status, body) = send(router(false), get("/ready")).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["failing"], json!(["broken"]));
+
+    let (status, body) = send(router(false), get("/live")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["ok"], true);
+}
+
+#[tokio::test]
+async fn describe_returns_registry_descriptors() {
+    let request = Request::get("/describe").body(Body::empty()).unwrap();
+    let (status, body) = send(router(true), request).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["shop_a"]["version"], "1.0.0");
+    assert_eq!(body["broken"]["name"], "broken");
+}
+
+#[tokio::test]
+async fn caller_header_replaces_the_body_caller() {
+    let router = || {
+        let mut group = BasicSupplierGroup::new("team_b").with_access_policy(AccessPolicy::new().allow_all("team-b"));
+        group.add_supplier(Shop { name: "shop_a", ready: true });
+        GroupRouter::new().group(group).with_caller_header("X-Caller").build()
+    };
+    let body = json!({ "operation": "create", "params": {}, "context": { "caller": "team-b" } });
+
+    let (_, spoofed) = send(router(), post("/groups/team_b/query", body.clone())).await;
+    assert_eq!(spoofed["failures"][0][1]["code"], "unauthorized");
+
+    let mut request = post("/groups/team_b/query", body);
+    request.headers_mut().insert("x-caller", "team-b".parse().unwrap());
+    let (_, allowed) = send(router(), request).await;
+    assert_eq!(allowed["successes"][0][0], "shop_a");
+}
+
+#[tokio::test]
+async fn accept_language_sets_the_locale_of_requests_without_one() {
+    let shop = MockSupplierBuilder::new("shop_a").respond_default(json!([])).with_locale("en").with_locale("id").build();
+    let router = || {
+        let mut group = BasicSupplierGroup::new("marketplaces");
+        group.add_supplier(shop.clone());
+        GroupRouter::new().group(group).build()
+    };
+    let body = json!({ "operation": "search", "params": {} });
+
+    let mut request = post("/groups/marketplaces/query", body.clone());
+    request.headers_mut().insert("accept-language", "fr;q=0.2, id-ID;q=0.9, *;q=0.1".parse().unwrap());
+    send(router(), request).await;
+    assert_eq!(shop.last_request().unwrap().context.locale.as_deref(), Some("id"));
+
+    let mut explicit = post("/groups/marketplaces/query", json!({ "operation": "search", "params": {}, "context": { "locale": "en-GB" } }));
+    explicit.headers_mut().insert("accept-language", "id".parse().unwrap());
+    send(router(), explicit).await;
+    assert_eq!(shop.last_request().unwrap().context.locale.as_deref(), Some("en"));
+
+    send(router(), post("/groups/marketplaces/query", body)).await;
+    assert_eq!(shop.last_request().unwrap().context.locale, None);
+}
+
+#[tokio::test]
+async fn body_caller_tenant_and_priority_are_ignored() {
+    let shop = MockSupplierBuilder::new("shop_a").respond_default(json!([])).build();
+    let router = |headers: bool| {
+        let mut group = BasicSupplierGroup::new("marketplaces");
+        group.add_supplier(shop.clone());
+        let router = GroupRouter::new().group(group);
+        match headers {
+            true => router.with_caller_header("X-Caller").with_tenant_header("X-Tenant").with_priority_header("X-Priority"),
+            false => router,
+        }
+        .build()
+    };
+    let body = json!({
+        "operation": "search",
+        "params": {},
+        "context": { "caller": "admin", "tenant": "acme", "priority": "critical" }
+    });
+
+    send(router(false), post("/groups/marketplaces/query", body.clone())).await;
+    let context = shop.last_request().unwrap().context;
+    assert_eq!((context.caller, context.tenant, context.priority), (None, None, None));
+
+    let mut request = post("/groups/marketplaces/query", body);
+    request.headers_mut().insert("x-caller", "team-b".parse().unwrap());
+    request.headers_mut().insert("x-tenant", "globex".parse().unwrap());
+    request.headers_mut().insert("x-priority", "low".parse().unwrap());
+    send(router(true), request).await;
+    let context = shop.last_request().unwrap().context;
+    assert_eq!(context.caller.as_deref(), Some("team-b"));
+    assert_eq!(context.tenant.as_deref(), Some("globex"));
+    assert_eq!(context.priority, Some(Priority::Low));
+}
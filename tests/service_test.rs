@@ -0,0 +1,97 @@
+#![cfg(feature = "tower")]
+
+use std::sync::Arc;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::service::{SupplierService, TowerSupplier};
+use supplier_kit::supplier::{Supplier, SupplierRegistry};
+use tower::{service_fn, ServiceBuilder, ServiceExt};
+
+fn request(q: &str) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": q }))
+}
+
+struct Echo;
+
+impl Supplier for Echo {
+    fn name(&self) -> &str {
+        "echo"
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        match request.params["q"].as_str() {
+            Some("boom") => panic!("exploded"),
+            Some("missing") => Err(SupplierError::NotFound),
+            _ => Ok(SupplierResponse::new(request.params)),
+        }
+    }
+}
+
+#[test]
+fn tower_service_can_be_registered_as_a_supplier() {
+    let service = service_fn(|request: SupplierRequest| async move {
+        Ok::<_, SupplierError>(SupplierResponse::new(json!({ "echo": request.params["q"] })))
+    });
+    let mut registry = SupplierRegistry::new();
+    registry.register("tower", TowerSupplier::new("tower", service).unwrap());
+
+    let response = registry.query("tower", request("lamp")).unwrap();
+    assert_eq!(response.data["echo"], "lamp");
+}
+
+#[test]
+fn tower_errors_are_mapped_to_supplier_errors() {
+    let failing = TowerSupplier::new(
+        "failing",
+        service_fn(|_request: SupplierRequest| async { Err::<SupplierResponse, _>(SupplierError::Unauthorized) }),
+    )
+    .unwrap();
+    assert!(matches!(failing.query(request("x")), Err(SupplierError::Unauthorized)));
+
+    let slow = ServiceBuilder::new()
+        .timeout(Duration::from_millis(10))
+        .service_fn(|request: SupplierRequest| async move {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            Ok::<_, SupplierError>(SupplierResponse::new(request.params))
+        });
+    let slow = TowerSupplier::new("slow", slow).unwrap();
+    assert!(matches!(slow.query(request("x")), Err(SupplierError::Timeout)));
+
+    let io = TowerSupplier::new(
+        "io",
+        service_fn(|_request: SupplierRequest| async {
+            Err::<SupplierResponse, _>(std::io::Error::other("connection reset"))
+        }),
+    )
+    .unwrap();
+    assert!(matches!(io.query(request("x")), Err(SupplierError::Upstream(message)) if message == "connection reset"));
+}
+
+#[tokio::test]
+async fn supplier_can_be_used_as_a_tower_service() {
+    let service = SupplierService::new(Echo);
+
+    let response = service.clone().oneshot(request("lamp")).await.unwrap();
+    assert_eq!(response.data["q"], "lamp");
+
+    let error = service.clone().oneshot(request("missing")).await.unwrap_err();
+    assert!(matches!(error, SupplierError::NotFound));
+
+    let error = service.oneshot(request("boom")).await.unwrap_err();
+    assert!(matches!(error, SupplierError::Internal(message) if message.contains("exploded")));
+}
+
+#[tokio::test]
+async fn tower_middleware_wraps_a_registered_supplier() {
+    let mut registry = SupplierRegistry::new();
+    registry.register("echo", Echo);
+    let supplier = registry.get("echo").unwrap();
+
+    let service = ServiceBuilder::new()
+        .concurrency_limit(1)
+        .service(SupplierService::from_arc(Arc::clone(&supplier)));
+    let response = service.oneshot(request("lamp")).await.unwrap();
+    assert_eq!(response.data["q"], "lamp");
+}
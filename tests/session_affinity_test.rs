@@ -0,0 +1,106 @@
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::balancing::{LoadTracker, SessionAffinity};
+use supplier_kit::context::RequestContext;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn request(session: Option<&str>) -> SupplierRequest {
+    let mut context = RequestContext::new();
+    if let Some(session) = session {
+        context = context.with_session_id(session);
+    }
+    SupplierRequest::new(SupplierOperation::Search, json!({})).with_context(context)
+}
+
+fn shop(name: &str) -> MockSupplier {
+    MockSupplierBuilder::new(name).respond_default(json!({ "shop": name })).build()
+}
+
+fn sticky_group(suppliers: &[&MockSupplier], affinity: Arc<SessionAffinity>) -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new("checkout")
+        .with_strategy(QueryStrategy::Adaptive)
+        .with_load_tracker(Arc::new(LoadTracker::new().with_seed(9)))
+        .with_session_affinity(affinity);
+    for supplier in suppliers {
+        group.add_supplier((*supplier).clone());
+    }
+    group
+}
+
+fn served_by(group: &BasicSupplierGroup, session: Option<&str>) -> String {
+    group.query(request(session)).successes[0].0.clone()
+}
+
+#[test]
+fn a_session_stays_on_its_first_supplier() {
+    let suppliers: Vec<MockSupplier> = ["a", "b", "c", "d"].iter().map(|name| shop(name)).collect();
+    let affinity = Arc::new(SessionAffinity::new(Duration::from_secs(60)));
+    let group = sticky_group(&suppliers.iter().collect::<Vec<_>>(), affinity.clone());
+
+    let first = served_by(&group, Some("checkout-1"));
+    for _ in 0..10 {
+        assert_eq!(served_by(&group, Some("checkout-1")), first);
+    }
+    assert_eq!(affinity.pinned("checkout-1"), Some(first));
+    assert_eq!(affinity.len(), 1);
+
+    for _ in 0..10 {
+        served_by(&group, None);
+    }
+    assert_eq!(affinity.len(), 1);
+}
+
+#[test]
+fn pins_expire_after_the_idle_ttl() {
+    let affinity = SessionAffinity::new(Duration::from_millis(100));
+    affinity.pin("checkout-1", "a");
+    thread::sleep(Duration::from_millis(60));
+    assert_eq!(affinity.pinned("checkout-1").as_deref(), Some("a"));
+    thread::sleep(Duration::from_millis(60));
+    assert_eq!(affinity.pinned("checkout-1").as_deref(), Some("a"));
+
+    thread::sleep(Duration::from_millis(150));
+    assert_eq!(affinity.pinned("checkout-1"), None);
+    assert!(affinity.is_empty());
+}
+
+#[test]
+fn sessions_move_when_their_supplier_leaves_the_group() {
+    let a = shop("a");
+    let b = shop("b");
+    let affinity = Arc::new(SessionAffinity::new(Duration::from_secs(60)));
+    affinity.pin("checkout-1", "gone");
+    let group = sticky_group(&[&a, &b], affinity.clone());
+
+    let served = served_by(&group, Some("checkout-1"));
+    assert_ne!(served, "gone");
+    assert_eq!(affinity.pinned("checkout-1"), Some(served));
+}
+
+#[test]
+fn batches_follow_the_session_of_their_requests() {
+    let a = shop("a");
+    let b = shop("b");
+    let affinity = Arc::new(SessionAffinity::new(Duration::from_secs(60)));
+    let group = sticky_group(&[&a, &b], affinity.clone());
+
+    let first = served_by(&group, Some("checkout-1"));
+    let results = group.query_batch(vec![request(Some("checkout-1")), request(Some("checkout-1"))]);
+    for result in results {
+        assert_eq!(result.successes.len(), 1);
+        assert_eq!(result.successes[0].0, first);
+    }
+}
+
+#[test]
+fn session_ids_round_trip_through_the_context() {
+    let context = RequestContext::new().with_session_id("checkout-1");
+    let json = serde_json::to_value(&context).unwrap();
+    assert_eq!(json, json!({ "session_id": "checkout-1" }));
+    assert_eq!(serde_json::from_value::<RequestContext>(json).unwrap(), context);
+    assert_eq!(serde_json::to_value(RequestContext::new()).unwrap(), json!({}));
+}
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use serde_json::json;
+use supplier_kit::errors::{SupplierError, SupplierGroupError};
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier_group::{BasicSupplierGroup, PartialFailurePolicy, SupplierGroup, SupplierGroupResult, SupplierSeverity};
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn detail() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::GetDetail, json!({ "id": 7 }))
+}
+
+fn product_page(policy: PartialFailurePolicy, inventory: MockSupplier) -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new("product_page").with_failure_policy(policy);
+    group.add_supplier(MockSupplierBuilder::new("reviews").then_fail(SupplierError::Timeout).build());
+    group.add_supplier(inventory);
+    group.add_supplier(MockSupplierBuilder::new("pricing").respond_default(json!({ "price": 12 })).build());
+    group.set_severity("inventory", SupplierSeverity::Critical).unwrap();
+    group.set_severity("pricing", SupplierSeverity::Critical).unwrap();
+    group
+}
+
+fn flaky_inventory() -> MockSupplier {
+    MockSupplierBuilder::new("inventory")
+        .then_fail(SupplierError::Upstream("HTTP 502".into()))
+        .respond_default(json!({ "stock": 3 }))
+        .build()
+}
+
+#[test]
+fn severities_default_to_optional() {
+    let group = product_page(PartialFailurePolicy::Tolerate, flaky_inventory());
+    assert_eq!(group.severity("inventory"), SupplierSeverity::Critical);
+    assert_eq!(group.severity("reviews"), SupplierSeverity::Optional);
+    assert!(matches!(group.set_severity("missing", SupplierSeverity::Critical), Err(SupplierError::NotFound)));
+    assert_eq!(group.failure_policy(), PartialFailurePolicy::Tolerate);
+    assert_eq!(serde_json::to_value(SupplierSeverity::Critical).unwrap(), json!("critical"));
+}
+
+#[test]
+fn results_list_critical_failures_without_failing_by_default() {
+    let group = product_page(PartialFailurePolicy::Tolerate, flaky_inventory());
+    let result = group.query(detail());
+    assert_eq!(result.failures.len(), 2);
+    assert_eq!(result.critical_failures, ["inventory"]);
+    assert!(result.has_critical_failure());
+    assert!(result.error.is_none());
+
+    let value = serde_json::to_value(&result).unwrap();
+    assert_eq!(value["critical_failures"], json!(["inventory"]));
+    assert!(value.get("error").is_none());
+    assert!(result.into_result().is_ok());
+}
+
+#[test]
+fn policies_turn_failures_into_an_overall_error() {
+    let group = product_page(PartialFailurePolicy::FailOnCritical, flaky_inventory());
+    let mut result = group.query(detail());
+    let error = result.error.clone().unwrap();
+    assert!(matches!(&error, SupplierGroupError::CriticalFailed { suppliers, .. } if suppliers == &["inventory"]));
+    assert!(matches!(error.supplier_error("inventory"), Some(SupplierError::Upstream(message)) if message == "HTTP 502"));
+    assert_eq!(error.failures().len(), 2);
+
+    // A recovered critical supplier clears the overall error.
+    assert_eq!(result.retry_failures(&group, detail()), 1);
+    assert!(!result.has_critical_failure());
+    assert!(result.error.is_none());
+    assert_eq!(result.failures.len(), 1);
+
+    let optional_only = product_page(PartialFailurePolicy::FailOnCritical, MockSupplierBuilder::new("inventory").respond_default(json!({})).build());
+    assert!(optional_only.query(detail()).into_result().is_ok());
+
+    let strict = product_page(PartialFailurePolicy::FailOnAny, MockSupplierBuilder::new("inventory").respond_default(json!({})).build());
+    let error = strict.query(detail()).into_result().unwrap_err();
+    assert!(matches!(error, SupplierGroupError::QuorumNotMet { required: 3, succeeded: 2, .. }));
+}
+
+#[test]
+fn targeted_and_batched_queries_are_assessed() {
+    let group = product_page(PartialFailurePolicy::FailOnCritical, flaky_inventory());
+    let each = group.query_each(HashMap::from([
+        ("inventory".to_string(), detail()),
+        ("warehouse".to_string(), detail()),
+    ]));
+    assert_eq!(each.critical_failures, ["inventory"]);
+    assert!(each.error.is_some());
+
+    let batch = group.query_batch(vec![detail(), detail()]);
+    assert!(batch.iter().all(|result| !result.has_critical_failure() && result.error.is_none()));
+
+    let decoded: SupplierGroupResult = serde_json::from_value(json!({ "successes": [], "failures": [] })).unwrap();
+    assert!(!decoded.has_critical_failure());
+}
@@ -0,0 +1,130 @@
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use serde_json::{json, Value};
+use supplier_kit::decorators::shadow::{ShadowDiff, ShadowSupplier};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::executor::BoundedExecutor;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+
+struct Fixed {
+    name: &'static str,
+    result: Result<Value, SupplierError>,
+    delay: Duration,
+}
+
+impl Fixed {
+    fn ok(name: &'static str, data: Value) -> Self {
+        Self { name, result: Ok(data), delay: Duration::ZERO }
+    }
+
+    fn err(name: &'static str, error: SupplierError) -> Self {
+        Self { name, result: Err(error), delay: Duration::ZERO }
+    }
+}
+
+impl Supplier for Fixed {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        std::thread::sleep(self.delay);
+        self.result.clone().map(SupplierResponse::new)
+    }
+}
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "phone" }))
+}
+
+fn shadowed(primary: Fixed, shadow: Fixed) -> (ShadowSupplier, mpsc::Receiver<ShadowDiff>) {
+    let (tx, rx) = mpsc::channel();
+    let tx = Mutex::new(tx);
+    let supplier = ShadowSupplier::new(primary, shadow).on_diff(move |diff| {
+        let _ = tx.lock().unwrap().send(diff.clone());
+    });
+    (supplier, rx)
+}
+
+#[test]
+fn matching_responses_are_not_reported() {
+    let (supplier, rx) = shadowed(
+        Fixed::ok("old", json!({ "items": [1, 2] })),
+        Fixed::ok("new", json!({ "items": [1, 2] })),
+    );
+    assert_eq!(supplier.name(), "old");
+    supplier.query(search()).unwrap();
+    assert!(rx.recv_timeout(Duration::from_millis(200)).is_err());
+}
+
+#[test]
+fn differing_fields_are_reported_as_pointers() {
+    let (supplier, rx) = shadowed(
+        Fixed::ok("old", json!({ "items": [{ "price": 1 }, { "price": 2 }], "total": 2, "a/b": 1 })),
+        Fixed::ok("new", json!({ "items": [{ "price": 1 }, { "price": 3 }], "extra": true, "a/b": 2 })),
+    );
+    let response = supplier.query(search()).unwrap();
+    assert_eq!(response.data["total"], 2);
+
+    let diff = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(diff.paths, vec!["/a~1b", "/extra", "/items/1/price", "/total"]);
+    assert_eq!(diff.request.params, json!({ "q": "phone" }));
+}
+
+#[test]
+fn shadow_failure_is_reported_but_not_returned() {
+    let (supplier, rx) = shadowed(
+        Fixed::ok("old", json!({})),
+        Fixed::err("new", SupplierError::Timeout),
+    );
+    assert!(supplier.query(search()).is_ok());
+
+    let diff = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert!(diff.primary.is_ok());
+    assert!(matches!(diff.shadow, Err(SupplierError::Timeout)));
+    assert!(diff.paths.is_empty());
+}
+
+#[test]
+fn same_errors_are_not_reported() {
+    let (supplier, rx) = shadowed(
+        Fixed::err("old", SupplierError::NotFound),
+        Fixed::err("new", SupplierError::NotFound),
+    );
+    assert!(matches!(supplier.query(search()), Err(SupplierError::NotFound)));
+    assert!(rx.recv_timeout(Duration::from_millis(200)).is_err());
+}
+
+#[test]
+fn slow_shadow_does_not_delay_primary() {
+    let (supplier, rx) = shadowed(
+        Fixed::ok("old", json!(1)),
+        Fixed { delay: Duration::from_millis(300), ..Fixed::ok("new", json!(2)) },
+    );
+    let started = std::time::Instant::now();
+    supplier.query(search()).unwrap();
+    assert!(started.elapsed() < Duration::from_millis(300));
+    assert_eq!(rx.recv_timeout(Duration::from_secs(5)).unwrap().paths, vec![""]);
+}
+
+#[test]
+fn executor_without_threads_still_reports_diffs() {
+    let (supplier, rx) = shadowed(Fixed::ok("old", json!(1)), Fixed::ok("new", json!(2)));
+    let supplier = supplier.with_executor(Arc::new(BoundedExecutor::new(0)));
+
+    assert_eq!(supplier.query(search()).unwrap().data, json!(1));
+    assert_eq!(rx.try_recv().unwrap().paths, vec![""]);
+}
+
+#[test]
+fn writes_are_not_mirrored() {
+    let (supplier, rx) = shadowed(
+        Fixed::ok("old", json!({ "id": 1 })),
+        Fixed::ok("new", json!({ "id": 2 })),
+    );
+    let create = SupplierRequest::new(SupplierOperation::Create, json!({ "sku": "phone" }));
+    assert_eq!(supplier.query(create).unwrap().data, json!({ "id": 1 }));
+    assert!(rx.recv_timeout(Duration::from_millis(200)).is_err());
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::context::{Priority, RequestContext};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::shedding::LoadShedder;
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn request(priority: Priority) -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({})).with_context(RequestContext::new().with_priority(priority))
+}
+
+fn shop(name: &str, delay: Duration) -> MockSupplier {
+    MockSupplierBuilder::new(name).respond_default(json!({})).with_delay(delay).build()
+}
+
+fn is_overloaded(error: &SupplierError) -> bool {
+    matches!(error, SupplierError::Overloaded(_))
+}
+
+#[test]
+fn low_priority_queries_are_shed_while_busy() {
+    let shedder = Arc::new(
+        LoadShedder::new()
+            .shed_at_in_flight(Priority::Low, 1)
+            .shed_at_in_flight(Priority::High, 2),
+    );
+    let slow = shop("slow", Duration::from_millis(300));
+    let mut group = BasicSupplierGroup::new("marketplaces").with_load_shedding(shedder.clone());
+    group.add_supplier(slow.clone());
+    let group = Arc::new(group);
+
+    let busy = {
+        let group = group.clone();
+        thread::spawn(move || group.query(request(Priority::Normal)))
+    };
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(shedder.in_flight(), 1);
+
+    let low = group.query(request(Priority::Low));
+    assert!(low.successes.is_empty());
+    assert!(is_overloaded(&low.failures[0].1));
+
+    let critical = group.query(request(Priority::Critical));
+    assert_eq!(critical.successes.len(), 1);
+
+    assert_eq!(busy.join().unwrap().successes.len(), 1);
+    assert_eq!(slow.calls(), 2);
+    assert_eq!(shedder.in_flight(), 0);
+    assert_eq!(shedder.shed_count(), 1);
+    assert_eq!(group.query(request(Priority::Low)).successes.len(), 1);
+}
+
+#[test]
+fn queue_depth_thresholds_cover_lower_priorities() {
+    let depth = Arc::new(AtomicUsize::new(0));
+    let probe = depth.clone();
+    let shedder = Arc::new(
+        LoadShedder::new()
+            .with_queue_depth(move || probe.load(Ordering::SeqCst))
+            .shed_at_queue_depth(Priority::Low, 10)
+            .shed_at_queue_depth(Priority::Normal, 100),
+    );
+    let mut group = BasicSupplierGroup::new("marketplaces").with_load_shedding(shedder);
+    group.add_supplier(shop("a", Duration::ZERO));
+    group.add_supplier(shop("b", Duration::ZERO));
+
+    depth.store(50, Ordering::SeqCst);
+    let low = group.query(request(Priority::Low));
+    assert_eq!(low.failures.len(), 2);
+    assert!(low.failures.iter().all(|(_, e)| is_overloaded(e)));
+    assert_eq!(group.query(SupplierRequest::new(SupplierOperation::Search, json!({}))).successes.len(), 2);
+
+    depth.store(100, Ordering::SeqCst);
+    assert!(is_overloaded(&group.query(request(Priority::Normal)).failures[0].1));
+    assert_eq!(group.query(request(Priority::High)).successes.len(), 2);
+}
+
+#[test]
+fn every_query_path_is_shed() {
+    let shedder = Arc::new(LoadShedder::new().with_queue_depth(|| 1).shed_at_queue_depth(Priority::Normal, 1));
+    for strategy in [QueryStrategy::Sequential, QueryStrategy::Parallel, QueryStrategy::Race] {
+        let shop_a = shop("a", Duration::ZERO);
+        let mut group = BasicSupplierGroup::new("marketplaces")
+            .with_strategy(strategy)
+            .with_load_shedding(shedder.clone());
+        group.add_supplier(shop_a.clone());
+
+        let streamed: Vec<_> = group.query_streamed(request(Priority::Normal)).collect();
+        assert!(streamed.iter().all(|(_, r)| r.as_ref().is_err_and(is_overloaded)));
+
+        let batch = group.query_batch(vec![request(Priority::Low), request(Priority::Normal)]);
+        assert!(batch.iter().all(|r| is_overloaded(&r.failures[0].1)));
+
+        let each = group.query_each(HashMap::from([("a".to_string(), request(Priority::Low))]));
+        assert!(is_overloaded(&each.failures[0].1));
+
+        let transformed = group.query_transformed(request(Priority::Normal), |_, response| Ok(response.data));
+        assert!(is_overloaded(&transformed.failures[0].1));
+        assert_eq!(shop_a.calls(), 0);
+
+        // A batch containing a high-priority request is admitted as a whole.
+        let batch = group.query_batch(vec![request(Priority::Low), request(Priority::High)]);
+        assert!(batch.iter().all(|r| r.successes.len() == 1));
+    }
+}
+
+#[test]
+fn streams_hold_their_permit_until_dropped() {
+    let shedder = Arc::new(LoadShedder::new().shed_at_in_flight(Priority::Normal, 1));
+    let mut group = BasicSupplierGroup::new("marketplaces").with_load_shedding(shedder.clone());
+    group.add_supplier(shop("a", Duration::ZERO));
+    group.add_supplier(shop("b", Duration::ZERO));
+
+    let mut stream = group.query_streamed(request(Priority::Normal));
+    assert!(stream.next().unwrap().1.is_ok());
+    assert_eq!(shedder.in_flight(), 1);
+    assert!(is_overloaded(&group.query(request(Priority::Normal)).failures[0].1));
+
+    drop(stream);
+    assert_eq!(shedder.in_flight(), 0);
+}
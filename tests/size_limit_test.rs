@@ -0,0 +1,75 @@
+use std::sync::Arc;
+use serde_json::json;
+use supplier_kit::config::{Manifest, SupplierFactory};
+use supplier_kit::decorators::size_limit::SizeLimitedSupplier;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+#[test]
+fn responses_up_to_the_limit_pass() {
+    let data = json!({ "items": [1, 2, 3] });
+    let size = serde_json::to_vec(&data).unwrap().len();
+    let partner = MockSupplierBuilder::new("partner").respond_default(data.clone()).build();
+
+    assert_eq!(SizeLimitedSupplier::new(partner.clone(), size).query(search()).unwrap().data, data);
+    let error = SizeLimitedSupplier::new(partner, size - 1).query(search()).unwrap_err();
+    assert!(matches!(&error, SupplierError::ResponseTooLarge(m) if m.contains("'partner'")));
+    assert_eq!(error.code(), "response_too_large");
+}
+
+#[test]
+fn errors_and_batches_are_passed_through_and_checked() {
+    let partner = MockSupplierBuilder::new("partner")
+        .then_fail(SupplierError::Timeout)
+        .then_respond(json!("x".repeat(100)))
+        .respond_default(json!("ok"))
+        .build();
+    let supplier = SizeLimitedSupplier::new(partner, 10);
+
+    let results = supplier.query_batch(vec![search(), search(), search()]);
+    assert!(matches!(results[0], Err(SupplierError::Timeout)));
+    assert!(matches!(results[1], Err(SupplierError::ResponseTooLarge(_))));
+    assert!(results[2].is_ok());
+}
+
+#[test]
+fn groups_report_oversized_responses_as_failures() {
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.add_supplier(SizeLimitedSupplier::new(
+        MockSupplierBuilder::new("flood").respond_default(json!(vec![0; 10_000])).build(),
+        1024,
+    ));
+    group.add_supplier(MockSupplierBuilder::new("shop").respond_default(json!([])).build());
+
+    let result = group.query(search());
+    assert_eq!(result.successes.len(), 1);
+    assert!(matches!(&result.failures[0], (name, SupplierError::ResponseTooLarge(_)) if name == "flood"));
+}
+
+#[test]
+fn manifests_configure_limits_per_supplier() {
+    let manifest = Manifest::from_json(
+        r#"{
+            "suppliers": [
+                { "name": "flood", "type": "mock", "config": { "default": "a long response" }, "max_response_bytes": 8 },
+                { "name": "shop", "type": "mock", "config": { "default": "a long response" } }
+            ]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(manifest.suppliers[0].max_response_bytes, Some(8));
+
+    let loaded = SupplierFactory::new().load(&manifest).unwrap();
+    assert!(matches!(loaded.registry.query("flood", search()), Err(SupplierError::ResponseTooLarge(_))));
+    assert!(loaded.registry.query("shop", search()).is_ok());
+
+    let flood: Arc<dyn Supplier> = loaded.registry.get("flood").unwrap();
+    assert_eq!(flood.name(), "flood");
+}
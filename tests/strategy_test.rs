@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::bench::StrategyBench;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+use supplier_kit::testing::assertions::{assert_failed, assert_succeeded};
+use supplier_kit::testing::mock::{MockSupplier, MockSupplierBuilder};
+
+fn ok(name: &str, delay_ms: u64) -> MockSupplier {
+    MockSupplierBuilder::new(name)
+        .respond_default(json!({ "from": name }))
+        .with_delay(Duration::from_millis(delay_ms))
+        .build()
+}
+
+fn failing(name: &str, delay_ms: u64) -> MockSupplier {
+    MockSupplierBuilder::new(name)
+        .with_delay(Duration::from_millis(delay_ms))
+        .build()
+}
+
+fn group(strategy: QueryStrategy, suppliers: &[&MockSupplier]) -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new("group").with_strategy(strategy);
+    for supplier in suppliers {
+        group.add_supplier((*supplier).clone());
+    }
+    group
+}
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+#[test]
+fn failover_stops_at_first_success() {
+    let (a, b, c) = (failing("a", 0), ok("b", 0), ok("c", 0));
+    let group = group(QueryStrategy::Failover, &[&a, &b, &c]);
+
+    let result = group.query(search());
+    assert_succeeded(&result, &["b"]);
+    assert_failed(&result, &["a"]);
+    assert_eq!(c.calls(), 0);
+
+    let streamed: Vec<String> = group.query_streamed(search()).map(|(name, _)| name).collect();
+    assert_eq!(streamed, vec!["a", "b"]);
+    assert_eq!(c.calls(), 0);
+}
+
+#[test]
+fn failover_batch_sends_only_unanswered_requests_onward() {
+    let a = MockSupplierBuilder::new("a")
+        .then_respond(json!(1))
+        .then_fail(SupplierError::Timeout)
+        .build();
+    let b = ok("b", 0);
+    let group = group(QueryStrategy::Failover, &[&a, &b]);
+
+    let results = group.query_batch(vec![search(), search()]);
+    assert_succeeded(&results[0], &["a"]);
+    assert_succeeded(&results[1], &["b"]);
+    assert_failed(&results[1], &["a"]);
+    assert_eq!(b.calls(), 1);
+}
+
+#[test]
+fn race_keeps_the_fastest_success() {
+    let (slow, fast, broken) = (ok("slow", 200), ok("fast", 10), failing("broken", 0));
+    let group = group(QueryStrategy::Race, &[&slow, &fast, &broken]);
+
+    let result = group.query(search());
+    assert_succeeded(&result, &["fast"]);
+    assert_failed(&result, &["broken"]);
+
+    let streamed: Vec<String> = group.query_streamed(search()).map(|(name, _)| name).collect();
+    assert_eq!(streamed, vec!["broken", "fast"]);
+}
+
+#[test]
+fn race_reports_every_failure_when_nothing_succeeds() {
+    let (a, b) = (failing("a", 0), failing("b", 5));
+    let group = group(QueryStrategy::Race, &[&a, &b]);
+
+    let result = group.query(search());
+    assert!(result.successes.is_empty());
+    assert_failed(&result, &["a", "b"]);
+}
+
+#[test]
+fn race_batch_keeps_earliest_finishing_success_per_request() {
+    let (slow, fast) = (ok("slow", 100), ok("fast", 0));
+    let group = group(QueryStrategy::Race, &[&slow, &fast]);
+
+    let results = group.query_batch(vec![search(), search()]);
+    for result in &results {
+        assert_succeeded(result, &["fast"]);
+    }
+}
+
+#[test]
+fn targeted_queries_follow_the_strategy() {
+    let (a, b) = (ok("a", 0), ok("b", 0));
+    let group = group(QueryStrategy::Failover, &[&a, &b]);
+
+    let requests = HashMap::from([("a".to_string(), search()), ("b".to_string(), search())]);
+    assert_succeeded(&group.query_each(requests), &["a"]);
+    assert_eq!(b.calls(), 0);
+}
+
+#[test]
+fn bench_reports_load_per_strategy() {
+    let suppliers: Vec<Arc<dyn Supplier>> = vec![Arc::new(failing("a", 0)), Arc::new(ok("b", 1)), Arc::new(ok("c", 1))];
+    let reports = StrategyBench::new(suppliers, vec![search(); 4]).compare(&[
+        QueryStrategy::Sequential,
+        QueryStrategy::Parallel,
+        QueryStrategy::Failover,
+    ]);
+
+    let calls = |index: usize| -> Vec<usize> { reports[index].suppliers.iter().map(|s| s.calls).collect() };
+    assert_eq!(calls(0), vec![4, 4, 4]);
+    assert_eq!(calls(1), vec![4, 4, 4]);
+    assert_eq!(calls(2), vec![4, 4, 0]);
+
+    let failover = &reports[2];
+    assert_eq!(failover.latencies.len(), 4);
+    assert_eq!((failover.successes, failover.failures), (4, 4));
+    assert_eq!(failover.suppliers[0].errors, 4);
+    assert!(failover.throughput() > 0.0);
+    assert!(failover.percentile(100.0) >= failover.percentile(50.0));
+    assert!(failover.to_string().starts_with("Failover: 4 queries"));
+}
@@ -0,0 +1,83 @@
+use std::thread;
+use std::time::{Duration, Instant};
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup};
+
+struct DelayedSupplier {
+    name: &'static str,
+    delay: Duration,
+}
+
+impl Supplier for DelayedSupplier {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        thread::sleep(self.delay);
+        if self.name == "broken" {
+            return Err(SupplierError::Timeout);
+        }
+        Ok(SupplierResponse::new(json!({ "supplier": self.name })))
+    }
+}
+
+fn delayed(name: &'static str, millis: u64) -> DelayedSupplier {
+    DelayedSupplier {
+        name,
+        delay: Duration::from_millis(millis),
+    }
+}
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "query": "laptop" }))
+}
+
+#[test]
+fn test_parallel_stream_yields_fast_suppliers_first() {
+    let mut group = BasicSupplierGroup::new("stream").with_strategy(QueryStrategy::Parallel);
+    group.add_supplier(delayed("slow", 400));
+    group.add_supplier(delayed("fast", 0));
+    group.add_supplier(delayed("broken", 50));
+
+    let started = Instant::now();
+    let mut stream = group.query_streamed(request());
+
+    let (first, result) = stream.next().unwrap();
+    assert_eq!(first, "fast");
+    assert!(result.is_ok());
+    assert!(started.elapsed() < Duration::from_millis(300));
+
+    let rest: Vec<_> = stream.map(|(name, result)| (name, result.is_ok())).collect();
+    assert_eq!(rest, [("broken".to_string(), false), ("slow".to_string(), true)]);
+}
+
+#[test]
+fn test_sequential_stream_follows_group_order() {
+    let mut group = BasicSupplierGroup::new("stream");
+    group.add_supplier(delayed("b", 0));
+    group.add_supplier(delayed("a", 0));
+
+    let names: Vec<_> = group.query_streamed(request()).map(|(name, _)| name).collect();
+    assert_eq!(names, ["b", "a"]);
+}
+
+#[test]
+fn test_callback_receives_every_outcome_through_a_channel() {
+    let mut group = BasicSupplierGroup::new("callback").with_strategy(QueryStrategy::Parallel);
+    group.add_supplier(delayed("a", 0));
+    group.add_supplier(delayed("broken", 0));
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    group.query_with_callback(request(), &mut |name, result| {
+        tx.send((name.to_string(), result.is_ok())).unwrap();
+    });
+    drop(tx);
+
+    let mut delivered: Vec<_> = rx.into_iter().collect();
+    delivered.sort();
+    assert_eq!(delivered, [("a".to_string(), true), ("broken".to_string(), false)]);
+}
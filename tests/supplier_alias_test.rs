@@ -0,0 +1,85 @@
+use std::time::{Duration, SystemTime};
+use serde_json::json;
+use supplier_kit::deprecation::Deprecation;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::events::SupplierEvent;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::SupplierRegistry;
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn registry() -> SupplierRegistry {
+    let mut registry = SupplierRegistry::new();
+    registry.register("amazon", MockSupplierBuilder::new("amazon").respond_default(json!({ "shop": "amazon" })).build());
+    registry.register("ebay", MockSupplierBuilder::new("ebay").build());
+    registry
+}
+
+#[test]
+fn aliases_find_the_same_supplier() {
+    let mut registry = registry();
+    registry.alias("amzn", "amazon").unwrap();
+    registry.alias("AMZ", "amzn").unwrap();
+
+    assert_eq!(registry.get("amzn").unwrap().name(), "amazon");
+    assert_eq!(registry.resolve("AMZ"), "amazon");
+    assert!(registry.contains("AMZ"));
+    let request = SupplierRequest::new(SupplierOperation::Search, json!({}));
+    assert_eq!(registry.query("amzn", request).unwrap().data["shop"], "amazon");
+
+    let mut names = registry.all_names();
+    names.sort();
+    assert_eq!(names, ["amazon", "ebay"]);
+    assert_eq!(registry.aliases_of("amzn"), ["AMZ", "amzn"]);
+}
+
+#[test]
+fn removing_through_an_alias_removes_the_supplier_and_its_aliases() {
+    let mut registry = registry();
+    registry.alias("amzn", "amazon").unwrap();
+    registry.alias("bay", "ebay").unwrap();
+
+    assert_eq!(registry.remove("amzn").unwrap().name(), "amazon");
+    assert!(!registry.contains("amazon"));
+    assert!(!registry.contains("amzn"));
+    assert_eq!(registry.resolve("amzn"), "amzn");
+    assert_eq!(registry.aliases_of("ebay"), ["bay"]);
+
+    assert_eq!(registry.unalias("bay").as_deref(), Some("ebay"));
+    assert!(registry.get("bay").is_none());
+}
+
+#[test]
+fn invalid_aliases_are_rejected() {
+    let mut registry = registry();
+    assert!(matches!(registry.alias("amzn", "missing"), Err(SupplierError::NotFound)));
+    assert!(matches!(registry.alias("ebay", "amazon"), Err(SupplierError::InvalidInput(_))));
+    assert!(matches!(registry.alias(" ", "amazon"), Err(SupplierError::InvalidInput(_))));
+}
+
+#[test]
+fn registering_under_an_alias_name_replaces_the_alias() {
+    let mut registry = registry();
+    registry.alias("legacy", "amazon").unwrap();
+    registry.register("legacy", MockSupplierBuilder::new("legacy").build());
+
+    assert_eq!(registry.get("legacy").unwrap().name(), "legacy");
+    assert!(registry.aliases_of("amazon").is_empty());
+}
+
+#[test]
+fn registry_features_accept_aliases_and_report_registered_names() {
+    let mut registry = registry();
+    let events = registry.events().subscribe_channel();
+    registry.alias("amzn", "amazon").unwrap();
+    registry
+        .deprecate("amzn", Deprecation::new(SystemTime::now() + Duration::from_secs(3600), Duration::from_secs(60)))
+        .unwrap();
+    assert!(registry.deprecation("amazon").is_some());
+
+    registry.query("amzn", SupplierRequest::new(SupplierOperation::Search, json!({}))).unwrap();
+    let started = events.try_iter().find_map(|event| match event {
+        SupplierEvent::QueryStarted { supplier, .. } => Some(supplier),
+        _ => None,
+    });
+    assert_eq!(started.as_deref(), Some("amazon"));
+}
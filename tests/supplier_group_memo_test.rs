@@ -0,0 +1,102 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use serde_json::json;
+use supplier_kit::composite::CompositeSupplier;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::orchestration::pipeline::Pipeline;
+use supplier_kit::supplier::{Supplier, SupplierRegistry};
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+use supplier_kit::utils::add_supplier_from_registry;
+
+struct CountingSupplier {
+    calls: Arc<AtomicUsize>,
+}
+
+impl Supplier for CountingSupplier {
+    fn name(&self) -> &str {
+        "counting"
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(SupplierResponse::new(json!({ "echo": request.params })))
+    }
+}
+
+#[test]
+fn test_same_supplier_is_queried_once_per_group_query() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut registry = SupplierRegistry::new();
+    registry.register("counting", CountingSupplier { calls: calls.clone() });
+
+    let mut group = BasicSupplierGroup::new("memo");
+    add_supplier_from_registry(&mut group, &registry, "counting").unwrap();
+    add_supplier_from_registry(&mut group, &registry, "counting").unwrap();
+
+    let request = SupplierRequest::new(SupplierOperation::Search, json!({ "query": "memo" }));
+
+    let result = group.query(request.clone());
+    assert_eq!(result.successes.len(), 2);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    group.query(request);
+    assert_eq!(calls.load(Ordering::SeqCst), 2, "memo must not outlive a single query");
+}
+
+#[test]
+fn test_distinct_instances_are_not_memoized() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut group = BasicSupplierGroup::new("memo");
+    group.add_supplier(CountingSupplier { calls: calls.clone() });
+    group.add_supplier(CountingSupplier { calls: calls.clone() });
+
+    group.query(SupplierRequest::new(SupplierOperation::Search, json!({})));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_memo_is_shared_with_nested_groups() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counting: Arc<dyn Supplier> = Arc::new(CountingSupplier { calls: calls.clone() });
+
+    let mut nested = BasicSupplierGroup::new("nested");
+    nested.add_supplier_arc(counting.clone());
+    let mut group = BasicSupplierGroup::new("outer");
+    group.add_supplier_arc(counting);
+    group.add_supplier(CompositeSupplier::new(Arc::new(nested)));
+
+    let request = SupplierRequest::new(SupplierOperation::Search, json!({ "query": "memo" }));
+    let result = group.query(request.clone());
+    assert_eq!(result.successes.len(), 2);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    group.query(request);
+    assert_eq!(calls.load(Ordering::SeqCst), 2, "memo must not outlive a single query");
+}
+
+#[test]
+fn test_memo_is_shared_across_pipeline_stages() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut registry = SupplierRegistry::new();
+    registry.register("counting", CountingSupplier { calls: calls.clone() });
+    let counting = registry.get("counting").unwrap();
+    let mut group = BasicSupplierGroup::new("group");
+    group.add_supplier_arc(counting.clone());
+
+    let request = || SupplierRequest::new(SupplierOperation::Search, json!({ "query": "memo" }));
+    let pipeline = Pipeline::new()
+        .query("direct", counting, move |_| Ok(request()))
+        .query_registry("registry", Arc::new(registry), move |_| Ok(("counting".to_string(), request())))
+        .after(&["direct"])
+        .query_group("group", Arc::new(group), move |_| Ok(request()))
+        .after(&["registry"]);
+
+    let result = pipeline.run().unwrap();
+    assert_eq!(result.output("direct"), Some(&json!({ "echo": { "query": "memo" } })));
+    assert_eq!(result.output("registry"), result.output("direct"));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    pipeline.run().unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2, "memo must not outlive a single run");
+}
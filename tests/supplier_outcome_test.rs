@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::decorators::cache::CachingSupplier;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, QueryStrategy, SupplierGroup, SupplierGroupResult};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }))
+}
+
+#[test]
+fn outcomes_record_timing_for_every_called_supplier() {
+    for strategy in [QueryStrategy::Sequential, QueryStrategy::Parallel] {
+        let mut group = BasicSupplierGroup::new("marketplaces").with_strategy(strategy);
+        group.add_supplier(MockSupplierBuilder::new("slow").with_delay(Duration::from_millis(30)).respond_default(json!([])).build());
+        group.add_supplier(MockSupplierBuilder::new("broken").then_fail(SupplierError::Upstream("down".into())).build());
+
+        let result = group.query(request());
+        let slow = result.outcome("slow").unwrap();
+        assert!(slow.succeeded);
+        assert!(slow.elapsed() >= Duration::from_millis(30));
+        assert_eq!((slow.attempts, slow.from_cache), (1, false));
+        assert!(!result.outcome("broken").unwrap().succeeded);
+        assert_eq!(result.outcomes.len(), 2);
+    }
+}
+
+#[test]
+fn duplicate_suppliers_reuse_the_first_call() {
+    let shop: Arc<dyn Supplier> = Arc::new(MockSupplierBuilder::new("shop").respond_default(json!([])).build());
+    let mut group = BasicSupplierGroup::new("marketplaces").with_strategy(QueryStrategy::Parallel);
+    group.add_supplier_arc(shop.clone());
+    group.add_supplier_arc(shop);
+
+    let result = group.query(request());
+    let attempts: Vec<(u32, bool)> = result.outcomes.iter().map(|o| (o.attempts, o.from_cache)).collect();
+    assert_eq!(attempts, [(1, false), (0, true)]);
+}
+
+#[test]
+fn cached_responses_are_flagged() {
+    let cached = CachingSupplier::new(MockSupplierBuilder::new("catalog").respond_default(json!([])).build(), Duration::from_secs(60));
+    let mut group = BasicSupplierGroup::new("catalogs");
+    group.add_supplier(cached);
+
+    assert!(!group.query(request()).outcome("catalog").unwrap().from_cache);
+    assert!(group.query(request()).outcome("catalog").unwrap().from_cache);
+}
+
+#[test]
+fn retries_accumulate_attempts() {
+    let flaky = MockSupplierBuilder::new("flaky")
+        .then_fail(SupplierError::Upstream("down".into()))
+        .then_fail(SupplierError::Upstream("down".into()))
+        .respond_default(json!([]))
+        .build();
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.add_supplier(flaky);
+
+    let mut result = group.query(request());
+    result.retry_failures(&group, request());
+    result.retry_failures(&group, request());
+
+    let outcome = result.outcome("flaky").unwrap();
+    assert!(outcome.succeeded);
+    assert_eq!(outcome.attempts, 3);
+    assert_eq!(result.outcomes.len(), 1);
+}
+
+#[test]
+fn batches_and_multiplexed_queries_report_outcomes() {
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.add_supplier(MockSupplierBuilder::new("shop").respond_default(json!([])).build());
+
+    let batch = group.query_batch(vec![request(), request()]);
+    assert!(batch.iter().all(|result| result.outcome("shop").is_some_and(|o| o.attempts == 1)));
+
+    let mut requests = HashMap::new();
+    requests.insert("shop".to_string(), request());
+    requests.insert("unknown".to_string(), request());
+    let result = group.query_each(requests);
+    assert!(result.outcome("shop").is_some());
+    assert!(result.outcome("unknown").is_none());
+}
+
+#[test]
+fn outcomes_round_trip_through_json() {
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.add_supplier(MockSupplierBuilder::new("shop").respond_default(json!([])).build());
+    let result = group.query(request());
+
+    let json = serde_json::to_value(&result).unwrap();
+    assert_eq!(json["outcomes"][0]["supplier"], "shop");
+    assert!(json["outcomes"][0]["duration_us"].is_u64());
+    let parsed: SupplierGroupResult = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed.outcomes, result.outcomes);
+
+    let legacy: SupplierGroupResult = serde_json::from_value(json!({ "successes": [], "failures": [] })).unwrap();
+    assert!(legacy.outcomes.is_empty());
+}
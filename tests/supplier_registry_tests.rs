@@ -2,7 +2,8 @@
 mod tests {
     use supplier_kit::errors::SupplierError;
     use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
-    use supplier_kit::supplier::{Supplier, SupplierRegistry};
+    use std::sync::Arc;
+    use supplier_kit::supplier::{RegistrationOutcome, Supplier, SupplierRegistry};
 
     #[derive(Debug)]
     struct FailingSupplier {
@@ -28,6 +29,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::useless_conversion)]
     fn test_supplier_query_returns_error() {
         let mut registry = SupplierRegistry::new();
         let failing_supplier = FailingSupplier {
@@ -38,14 +40,41 @@ mod tests {
         registry.register("bad", failing_supplier);
         let supplier = registry.get("bad").expect("Supplier should be registered");
 
-        let request = SupplierRequest {
-            operation: SupplierOperation::Other( "search".to_string()).into(),
-            params: serde_json::json!({}),
-        };
+        let request = SupplierRequest::new(
+            SupplierOperation::Other( "search".to_string()).into(),
+            serde_json::json!({}),
+        );
 
         let result = supplier.query(request);
         
         eprintln!("{:?}", supplier.name());
         assert!(matches!(result, Err(SupplierError::Timeout)));
     }
+
+    #[test]
+    fn test_register_all_reports_each_outcome() {
+        let failing = |name: &str| FailingSupplier {
+            name: name.to_string(),
+            error: SupplierError::Timeout,
+        };
+
+        let mut registry = SupplierRegistry::new();
+        registry.register("existing", failing("existing"));
+
+        let batch: Vec<(String, Arc<dyn Supplier>)> = vec![
+            ("new".into(), Arc::new(failing("new"))),
+            ("existing".into(), Arc::new(failing("existing"))),
+            ("".into(), Arc::new(failing(""))),
+        ];
+        let report = registry.register_all(batch);
+
+        assert_eq!(report.outcomes[0], ("new".to_string(), RegistrationOutcome::Added));
+        assert_eq!(report.outcomes[1], ("existing".to_string(), RegistrationOutcome::Replaced));
+        assert!(matches!(report.outcomes[2].1, RegistrationOutcome::Rejected(_)));
+        assert!(!report.is_clean());
+
+        let mut names = registry.all_names();
+        names.sort();
+        assert_eq!(names, ["existing", "new"]);
+    }
 }
@@ -1,4 +1,4 @@
-use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::models::{ResponseSource, SupplierOperation, SupplierRequest, SupplierResponse};
 
 #[test]
 fn test_deserialize_supplier_request() {
@@ -12,3 +12,28 @@ fn test_deserialize_supplier_request() {
     let req: SupplierRequest = serde_json::from_str(json).unwrap();
     assert_eq!(req.operation, SupplierOperation::Search);
 }
+
+#[test]
+fn test_response_source_defaults_to_live_when_missing() {
+    let json = r#"{ "data": { "id": 1 } }"#;
+
+    let resp: SupplierResponse = serde_json::from_str(json).unwrap();
+    assert_eq!(resp.source(), ResponseSource::Live);
+
+    let cached = resp.with_source(ResponseSource::Cache);
+    let value = serde_json::to_value(&cached).unwrap();
+    assert_eq!(value["metadata"]["source"], "cache");
+}
+
+#[test]
+fn test_supplier_error_serializes_with_code() {
+    use supplier_kit::errors::SupplierError;
+
+    let error = SupplierError::ConcurrencyLimitExceeded("bulkhead full".into());
+    let json = serde_json::to_value(&error).unwrap();
+    assert_eq!(json, serde_json::json!({ "code": error.code(), "message": "bulkhead full" }));
+    assert_eq!(serde_json::to_value(SupplierError::Timeout).unwrap(), serde_json::json!({ "code": "timeout" }));
+
+    let back: SupplierError = serde_json::from_value(json).unwrap();
+    assert_eq!(back.code(), "concurrency_limit_exceeded");
+}
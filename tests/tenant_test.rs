@@ -0,0 +1,133 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+use serde_json::json;
+use supplier_kit::context::RequestContext;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::metrics::prometheus::PrometheusRecorder;
+use supplier_kit::metrics::{GroupQuery, MetricsRecorder, SupplierCall};
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::{Supplier, SupplierRegistry};
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+use supplier_kit::tenant::TenantRegistry;
+
+/// Answers with its label and the tenant it was called for.
+struct Echo(&'static str);
+
+impl Supplier for Echo {
+    fn name(&self) -> &str {
+        self.0
+    }
+
+    fn query(&self, request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!({ "from": self.0, "tenant": request.context.tenant })))
+    }
+}
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+fn tenants() -> TenantRegistry {
+    let mut base = SupplierRegistry::new();
+    base.register("weather", Echo("shared-weather"));
+    base.register("erp", Echo("shared-erp"));
+
+    let mut acme = SupplierRegistry::new();
+    acme.register("erp", Echo("acme-erp"));
+    let mut globex = SupplierRegistry::new();
+    globex.register("crm", Echo("globex-crm"));
+
+    let tenants = TenantRegistry::new().with_base(Arc::new(base));
+    tenants.insert("acme", acme);
+    tenants.insert("globex", globex);
+    tenants
+}
+
+#[test]
+fn tenant_suppliers_shadow_the_base() {
+    let tenants = tenants();
+
+    assert_eq!(tenants.query("acme", "erp", search()).unwrap().data["from"], "acme-erp");
+    assert_eq!(tenants.query("globex", "erp", search()).unwrap().data["from"], "shared-erp");
+    assert_eq!(tenants.query("acme", "weather", search()).unwrap().data["from"], "shared-weather");
+    assert_eq!(tenants.get("acme", "erp").unwrap().name(), "acme-erp");
+}
+
+#[test]
+fn tenants_are_isolated_from_each_other() {
+    let tenants = tenants();
+
+    assert!(matches!(tenants.query("acme", "crm", search()), Err(SupplierError::NotFound)));
+    assert!(matches!(tenants.query("initech", "weather", search()), Err(SupplierError::NotFound)));
+    assert!(tenants.get("initech", "weather").is_none());
+
+    assert_eq!(tenants.supplier_names("acme").unwrap(), ["erp", "weather"]);
+    assert_eq!(tenants.supplier_names("globex").unwrap(), ["crm", "erp", "weather"]);
+    assert_eq!(tenants.supplier_names("initech"), None);
+}
+
+#[test]
+fn queries_carry_the_tenant_to_suppliers() {
+    let tenants = tenants();
+    let spoofed = search().with_context(RequestContext::new().with_tenant("globex"));
+
+    let response = tenants.query("acme", "erp", spoofed).unwrap();
+    assert_eq!(response.data["tenant"], "acme");
+}
+
+#[test]
+fn tenants_can_be_onboarded_and_removed_while_shared() {
+    let tenants = Arc::new(tenants());
+    let writer = {
+        let tenants = tenants.clone();
+        thread::spawn(move || {
+            let mut initech = SupplierRegistry::new();
+            initech.register("erp", Echo("initech-erp"));
+            tenants.insert("initech", initech);
+        })
+    };
+    writer.join().unwrap();
+
+    assert_eq!(tenants.tenants(), ["acme", "globex", "initech"]);
+    assert_eq!(tenants.query("initech", "erp", search()).unwrap().data["from"], "initech-erp");
+
+    let removed = tenants.remove("initech").unwrap();
+    assert!(removed.contains("erp"));
+    assert!(tenants.query("initech", "erp", search()).is_err());
+}
+
+#[derive(Default)]
+struct TenantLog(Mutex<Vec<(String, Option<String>)>>);
+
+impl MetricsRecorder for TenantLog {
+    fn on_supplier_call(&self, call: &SupplierCall<'_>) {
+        self.0.lock().unwrap().push((call.supplier.to_string(), call.tenant.map(str::to_string)));
+    }
+
+    fn on_group_query(&self, query: &GroupQuery<'_>) {
+        self.0.lock().unwrap().push((query.group.to_string(), query.tenant.map(str::to_string)));
+    }
+}
+
+#[test]
+fn group_metrics_report_the_tenant() {
+    let log = Arc::new(TenantLog::default());
+    let prometheus = Arc::new(PrometheusRecorder::new());
+    let mut group = BasicSupplierGroup::new("erp").with_metrics(log.clone());
+    group.add_supplier(Echo("acme-erp"));
+    let mut observed = BasicSupplierGroup::new("erp").with_metrics(prometheus.clone());
+    observed.add_supplier(Echo("acme-erp"));
+
+    let request = search().with_context(RequestContext::new().with_tenant("acme"));
+    group.query(request.clone());
+    group.query(search());
+    observed.query(request);
+
+    let log = log.0.lock().unwrap();
+    assert_eq!(log[0], ("acme-erp".to_string(), Some("acme".to_string())));
+    assert_eq!(log[1], ("erp".to_string(), Some("acme".to_string())));
+    assert_eq!(log[2], ("acme-erp".to_string(), None));
+
+    let rendered = prometheus.render();
+    assert!(rendered.contains(r#"supplier="acme-erp",operation="search",tenant="acme""#), "{}", rendered);
+}
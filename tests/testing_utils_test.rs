@@ -0,0 +1,88 @@
+use std::panic::catch_unwind;
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+use supplier_kit::testing::assertions::{assert_all_succeeded, assert_failed, assert_failed_with, assert_succeeded};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn request(operation: SupplierOperation) -> SupplierRequest {
+    SupplierRequest::new(operation, json!({ "id": 7 }))
+}
+
+#[test]
+fn canned_responses_are_chosen_by_operation() {
+    let mock = MockSupplierBuilder::new("shop")
+        .respond(SupplierOperation::Search, json!({ "items": [] }))
+        .respond(SupplierOperation::Other("stock".into()), json!({ "stock": 3 }))
+        .build();
+
+    assert_eq!(mock.query(request(SupplierOperation::Search)).unwrap().data, json!({ "items": [] }));
+    assert_eq!(mock.query(request(SupplierOperation::Other("stock".into()))).unwrap().data["stock"], 3);
+    assert!(matches!(
+        mock.query(request(SupplierOperation::GetDetail)),
+        Err(SupplierError::UnsupportedOperation(op)) if op == "get_detail"
+    ));
+}
+
+#[test]
+fn script_runs_before_canned_responses() {
+    let mock = MockSupplierBuilder::new("shop")
+        .respond_default(json!("canned"))
+        .then_fail(SupplierError::Timeout)
+        .then_respond(json!("scripted"))
+        .then_fail(SupplierError::Unauthorized)
+        .build();
+
+    let outcomes: Vec<String> = (0..4)
+        .map(|_| match mock.query(request(SupplierOperation::Search)) {
+            Ok(response) => response.data.as_str().unwrap().to_string(),
+            Err(error) => error.code().to_string(),
+        })
+        .collect();
+    assert_eq!(outcomes, vec!["timeout", "scripted", "unauthorized", "canned"]);
+}
+
+#[test]
+fn clones_share_calls_and_captured_requests() {
+    let mock = MockSupplierBuilder::new("shop").respond_default(json!({})).build();
+    let mut group = BasicSupplierGroup::new("group");
+    group.add_supplier(mock.clone());
+
+    group.query(request(SupplierOperation::Search));
+    group.query(request(SupplierOperation::GetDetail));
+
+    assert_eq!(mock.calls(), 2);
+    assert_eq!(mock.requests()[0].operation, SupplierOperation::Search);
+    assert_eq!(mock.last_request().unwrap().operation, SupplierOperation::GetDetail);
+    assert!(mock.last_request().unwrap().context.request_id.is_some());
+}
+
+#[test]
+fn assertions_accept_matching_results() {
+    let mut group = BasicSupplierGroup::new("group");
+    group.add_supplier(MockSupplierBuilder::new("a").respond_default(json!({})).build());
+    group.add_supplier(MockSupplierBuilder::new("b").then_fail(SupplierError::NotFound).build());
+    group.add_supplier(MockSupplierBuilder::new("c").respond_default(json!({})).build());
+
+    let result = group.query(request(SupplierOperation::Search));
+    assert_succeeded(&result, &["a", "c"]);
+    assert_failed(&result, &["b"]);
+    assert_failed_with(&result, "b", "not_found");
+
+    let result = group.query(request(SupplierOperation::Search));
+    assert_failed_with(&result, "b", "unsupported_operation");
+}
+
+#[test]
+fn assertions_panic_on_mismatch() {
+    let mut group = BasicSupplierGroup::new("group");
+    group.add_supplier(MockSupplierBuilder::new("a").then_fail(SupplierError::Timeout).build());
+    let result = group.query(request(SupplierOperation::Search));
+
+    assert!(catch_unwind(|| assert_all_succeeded(&result)).is_err());
+    assert!(catch_unwind(|| assert_succeeded(&result, &["a"])).is_err());
+    assert!(catch_unwind(|| assert_failed_with(&result, "a", "not_found")).is_err());
+    assert!(catch_unwind(|| assert_failed_with(&result, "missing", "timeout")).is_err());
+}
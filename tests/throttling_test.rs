@@ -0,0 +1,191 @@
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use serde_json::json;
+use supplier_kit::decorators::throttle::ThrottleGuard;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::localization::MessageCatalog;
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+use supplier_kit::throttling::{parse_retry_after, retry_after_from_headers, throttled_from_http};
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }))
+}
+
+fn throttled(millis: u64) -> SupplierError {
+    SupplierError::Throttled { retry_after: Some(Duration::from_millis(millis)) }
+}
+
+#[test]
+fn throttled_errors_serialize_their_wait() {
+    let error = throttled(1500);
+    assert_eq!(error.code(), "throttled");
+    assert_eq!(error.to_string(), "throttled: retry after 1.5s");
+    assert_eq!(serde_json::to_value(&error).unwrap(), json!({ "code": "throttled", "message": { "retry_after_ms": 1500 } }));
+
+    let decoded: SupplierError = serde_json::from_value(json!({ "code": "throttled", "message": {} })).unwrap();
+    assert!(matches!(decoded, SupplierError::Throttled { retry_after: None }));
+    assert_eq!(decoded.to_string(), "throttled");
+    assert_eq!(serde_json::from_value::<SupplierError>(json!({ "code": "throttled", "message": { "retry_after_ms": 20 } })).unwrap().retry_after(), Some(Duration::from_millis(20)));
+
+    let message = MessageCatalog::new("en").localize(&error, "partner", "en").message;
+    assert!(message.contains("too many requests"), "{}", message);
+}
+
+#[test]
+fn retry_after_accepts_seconds_and_http_dates() {
+    let now = UNIX_EPOCH + Duration::from_secs(784_111_700);
+    assert_eq!(parse_retry_after(" 0 ", now), Some(Duration::ZERO));
+    assert_eq!(parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT", now), Some(Duration::from_secs(77)));
+    assert_eq!(parse_retry_after("Sat, 05 Nov 1994 08:49:37 GMT", now), Some(Duration::ZERO));
+    assert_eq!(parse_retry_after("Thu, 29 Feb 2024 00:00:00 GMT", UNIX_EPOCH), Some(Duration::from_secs(1_709_164_800)));
+    for invalid in ["-5", "1.5", "Sunday, 06-Nov-94 08:49:37 GMT", "Sun, 06 Nov 1994 08:49:37 PST", "Sun, 06 Nov 1994 25:00:00 GMT", ""] {
+        assert_eq!(parse_retry_after(invalid, now), None, "{}", invalid);
+    }
+}
+
+#[test]
+fn rate_limit_headers_give_the_wait_once_exhausted() {
+    let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let wait = |headers: &[(&str, &str)]| retry_after_from_headers(headers.iter().copied(), now);
+
+    assert_eq!(wait(&[("Retry-After", "3"), ("RateLimit-Remaining", "0"), ("RateLimit-Reset", "60")]), Some(Duration::from_secs(3)));
+    assert_eq!(wait(&[("RateLimit-Remaining", "0"), ("RateLimit-Reset", "60")]), Some(Duration::from_secs(60)));
+    assert_eq!(wait(&[("X-RateLimit-Remaining", "0"), ("X-RateLimit-Reset", "1700000045")]), Some(Duration::from_secs(45)));
+    assert_eq!(wait(&[("X-RateLimit-Remaining", "0"), ("X-RateLimit-Reset", "1600000000")]), Some(Duration::ZERO));
+    assert_eq!(wait(&[("X-RateLimit-Reset", "60")]), None);
+    assert_eq!(wait(&[("Content-Type", "application/json")]), None);
+}
+
+#[test]
+fn http_statuses_become_throttled_errors() {
+    let busy = throttled_from_http(503, [("retry-after", "120")]).unwrap();
+    assert_eq!(busy.retry_after(), Some(Duration::from_secs(120)));
+
+    let exhausted = throttled_from_http(429, [("RateLimit-Remaining", "0"), ("RateLimit-Reset", "9")]).unwrap();
+    assert_eq!(exhausted.retry_after(), Some(Duration::from_secs(9)));
+
+    let future = httpdate(SystemTime::now() + Duration::from_secs(3600));
+    let wait = throttled_from_http(429, [("Retry-After", future.as_str())]).unwrap().retry_after().unwrap();
+    assert!(wait > Duration::from_secs(3590) && wait <= Duration::from_secs(3600), "{:?}", wait);
+
+    assert!(throttled_from_http(500, [("Retry-After", "1")]).is_none());
+}
+
+#[test]
+fn overflowing_header_values_never_panic() {
+    let now = SystemTime::now();
+    for date in ["Wed, 21 Oct 999999999999 07:28:00 GMT", "Wed, 21 Oct 9223372036854775807 07:28:00 GMT", "Wed, 21 Jan -9223372036854775808 07:28:00 GMT"] {
+        assert_eq!(parse_retry_after(date, now), None, "{}", date);
+    }
+    let headers = [("X-RateLimit-Remaining", "0"), ("X-RateLimit-Reset", "18446744073709551615")];
+    assert_eq!(retry_after_from_headers(headers, now), None);
+    assert_eq!(parse_retry_after("18446744073709551615", now), Some(Duration::from_secs(u64::MAX)));
+}
+
+#[test]
+fn guards_cap_waits_too_long_to_represent() {
+    let partner = MockSupplierBuilder::new("partner")
+        .then_fail(SupplierError::Throttled { retry_after: Some(Duration::from_secs(u64::MAX)) })
+        .build();
+    let supplier = ThrottleGuard::new(partner.clone());
+
+    assert!(matches!(supplier.query(search()), Err(SupplierError::Throttled { retry_after: Some(_) })));
+    let blocked = supplier.blocked_for().unwrap();
+    assert!(blocked > Duration::from_secs(3600) && blocked <= Duration::from_secs(24 * 60 * 60), "{:?}", blocked);
+    assert!(matches!(supplier.query(search()), Err(SupplierError::Throttled { .. })));
+    assert_eq!(partner.calls(), 1);
+}
+
+#[test]
+fn guards_hold_calls_back_until_the_requested_wait_passes() {
+    let partner = MockSupplierBuilder::new("partner")
+        .then_fail(throttled(80))
+        .respond_default(json!({ "ok": true }))
+        .build();
+    let supplier = ThrottleGuard::new(partner.clone());
+
+    assert!(matches!(supplier.query(search()), Err(SupplierError::Throttled { retry_after: Some(wait) }) if wait.as_millis() == 80));
+    let blocked = supplier.query(search()).unwrap_err().retry_after().unwrap();
+    assert!(blocked <= Duration::from_millis(80));
+    assert!(supplier.blocked_for().is_some());
+    assert_eq!(partner.calls(), 1);
+
+    std::thread::sleep(Duration::from_millis(100));
+    assert_eq!(supplier.blocked_for(), None);
+    assert_eq!(supplier.query(search()).unwrap().data, json!({ "ok": true }));
+    assert_eq!(partner.calls(), 2);
+}
+
+#[test]
+fn guards_wait_out_and_retry_short_throttles() {
+    let partner = MockSupplierBuilder::new("partner")
+        .then_fail(throttled(40))
+        .then_fail(SupplierError::Throttled { retry_after: None })
+        .then_fail(SupplierError::Throttled { retry_after: None })
+        .respond_default(json!({ "ok": true }))
+        .build();
+    let supplier = ThrottleGuard::new(partner.clone())
+        .with_default_backoff(Duration::from_millis(30))
+        .with_max_wait(Duration::from_millis(200));
+
+    let started = Instant::now();
+    // Throttled for 40ms, retried once, then throttled again without a hint.
+    assert!(matches!(supplier.query(search()), Err(SupplierError::Throttled { retry_after: Some(wait) }) if wait.as_millis() == 30));
+    assert!(started.elapsed() >= Duration::from_millis(40));
+    assert_eq!(partner.calls(), 2);
+
+    // The default backoff is slept off before the next call reaches the supplier.
+    assert_eq!(supplier.query(search()).unwrap().data, json!({ "ok": true }));
+    assert!(started.elapsed() >= Duration::from_millis(100));
+    assert_eq!(partner.calls(), 4);
+
+    let other = ThrottleGuard::new(MockSupplierBuilder::new("other").then_fail(SupplierError::Timeout).build());
+    assert!(matches!(other.query(search()), Err(SupplierError::Timeout)));
+    assert_eq!(other.blocked_for(), None);
+}
+
+#[test]
+fn group_retries_skip_throttled_suppliers() {
+    let flaky = MockSupplierBuilder::new("flaky").then_fail(SupplierError::Timeout).respond_default(json!([])).build();
+    let strict = MockSupplierBuilder::new("strict").then_fail(throttled(60_000)).respond_default(json!([])).build();
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.add_supplier(flaky.clone());
+    group.add_supplier(strict.clone());
+
+    let mut result = group.query(search());
+    assert_eq!(result.failures.len(), 2);
+    assert_eq!(result.retry_failures(&group, search()), 1);
+    assert_eq!((flaky.calls(), strict.calls()), (2, 1));
+    assert!(matches!(&result.failures[..], [(name, SupplierError::Throttled { .. })] if name == "strict"));
+    assert_eq!(result.retry_failures(&group, search()), 0);
+    assert_eq!(strict.calls(), 1);
+}
+
+/// Formats `time` as an IMF-fixdate.
+fn httpdate(time: SystemTime) -> String {
+    let seconds = time.duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let days = seconds / 86_400;
+    let z = days as i64 + 719_468;
+    let (era, doe) = (z.div_euclid(146_097), z.rem_euclid(146_097));
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    let weekday = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"][(days % 7) as usize];
+    let month = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"][month as usize - 1];
+    let time_of_day = seconds % 86_400;
+    format!(
+        "{}, {:02} {} {} {:02}:{:02}:{:02} GMT",
+        weekday,
+        day,
+        month,
+        year,
+        time_of_day / 3600,
+        time_of_day % 3600 / 60,
+        time_of_day % 60
+    )
+}
@@ -0,0 +1,146 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use serde_json::json;
+use supplier_kit::composite::CompositeSupplier;
+use supplier_kit::errors::{SupplierError, SupplierGroupError};
+use supplier_kit::models::{SupplierOperation, SupplierRequest};
+use supplier_kit::supplier_group::{BasicSupplierGroup, PartialFailurePolicy, QueryStrategy, SupplierSeverity};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({ "q": "lamp" }))
+}
+
+fn group(strategy: QueryStrategy) -> BasicSupplierGroup {
+    let mut group = BasicSupplierGroup::new("marketplaces").with_strategy(strategy);
+    group.add_supplier(
+        MockSupplierBuilder::new("cents")
+            .respond_default(json!({ "price_cents": 1999 }))
+            .with_delay(Duration::from_millis(40))
+            .build(),
+    );
+    group.add_supplier(MockSupplierBuilder::new("euros").respond_default(json!({ "price": "12.50" })).build());
+    group.add_supplier(MockSupplierBuilder::new("down").then_fail(SupplierError::Timeout).build());
+    group
+}
+
+fn normalize(name: &str, data: &serde_json::Value) -> Result<f64, SupplierError> {
+    match name {
+        "cents" => Ok(data["price_cents"].as_u64().unwrap_or_default() as f64 / 100.0),
+        _ => data["price"]
+            .as_str()
+            .and_then(|p| p.parse().ok())
+            .ok_or_else(|| SupplierError::Internal("unparseable price".into())),
+    }
+}
+
+#[test]
+fn parallel_transform_normalizes_each_success() {
+    let result = group(QueryStrategy::Parallel)
+        .with_max_concurrency(3)
+        .query_transformed(request(), |name, response| normalize(name, &response.data));
+
+    assert_eq!(result.successes, vec![("cents".to_string(), 19.99), ("euros".to_string(), 12.5)]);
+    assert_eq!(result.failures.len(), 1);
+    assert_eq!(result.failures[0].0, "down");
+}
+
+#[test]
+fn transform_runs_on_the_fan_out_workers() {
+    let caller = thread::current().id();
+    let off_caller = AtomicUsize::new(0);
+
+    group(QueryStrategy::Parallel).query_transformed(request(), |_, _| {
+        if thread::current().id() != caller {
+            off_caller.fetch_add(1, Ordering::SeqCst);
+        }
+        Ok(())
+    });
+
+    assert_eq!(off_caller.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn transform_errors_become_failures() {
+    let result = group(QueryStrategy::Sequential).query_transformed(request(), |name, _| {
+        if name == "euros" {
+            Err(SupplierError::Internal("schema changed".into()))
+        } else {
+            Ok(name.len())
+        }
+    });
+
+    assert_eq!(result.successes, vec![("cents".to_string(), 5)]);
+    let failed: Vec<&str> = result.failures.iter().map(|(n, _)| n.as_str()).collect();
+    assert_eq!(failed, vec!["euros", "down"]);
+}
+
+#[test]
+fn failover_and_race_skip_suppliers_whose_transform_fails() {
+    let reject_cents = |name: &str, response: supplier_kit::models::SupplierResponse| {
+        if name == "cents" {
+            Err(SupplierError::Internal("rejected".into()))
+        } else {
+            normalize(name, &response.data)
+        }
+    };
+
+    let failover = group(QueryStrategy::Failover).query_transformed(request(), reject_cents);
+    assert_eq!(failover.successes, vec![("euros".to_string(), 12.5)]);
+    assert_eq!(failover.failures[0].0, "cents");
+
+    let race = group(QueryStrategy::Race).query_transformed(request(), reject_cents);
+    assert_eq!(race.successes, vec![("euros".to_string(), 12.5)]);
+}
+
+#[test]
+fn panicking_transforms_become_failures() {
+    for strategy in [QueryStrategy::Sequential, QueryStrategy::Parallel, QueryStrategy::Race] {
+        let result = group(strategy).query_transformed(request(), |name, response| {
+            if name == "euros" {
+                panic!("bad payload");
+            }
+            normalize(name, &response.data)
+        });
+
+        assert_eq!(result.successes, vec![("cents".to_string(), 19.99)]);
+        let (name, error) = &result.failures[0];
+        assert_eq!(name, "euros");
+        assert!(matches!(error, SupplierError::Internal(msg) if msg.contains("bad payload")));
+    }
+}
+
+#[test]
+fn failure_policy_and_critical_suppliers_apply() {
+    let group = group(QueryStrategy::Sequential).with_failure_policy(PartialFailurePolicy::FailOnCritical);
+    group.set_severity("down", SupplierSeverity::Critical).unwrap();
+
+    let result = group.query_transformed(request(), |name, response| normalize(name, &response.data));
+    assert_eq!(result.successes.len(), 2);
+    assert_eq!(result.critical_failures, vec!["down".to_string()]);
+    let error = result.into_result().unwrap_err();
+    assert!(matches!(error, SupplierGroupError::CriticalFailed { suppliers, .. } if suppliers == ["down"]));
+}
+
+#[test]
+fn flattened_nested_groups_transform_each_member() {
+    let mut eu = BasicSupplierGroup::new("eu");
+    eu.add_supplier(MockSupplierBuilder::new("amazon_de").respond_default(json!({ "price": "9.90" })).build());
+    eu.add_supplier(MockSupplierBuilder::new("amazon_fr").then_fail(SupplierError::Timeout).build());
+    let mut global = BasicSupplierGroup::new("global").with_flattened_results();
+    global.add_supplier(CompositeSupplier::new(Arc::new(eu)));
+    global.add_supplier(MockSupplierBuilder::new("walmart").respond_default(json!({ "price": "8.00" })).build());
+
+    let seen = std::sync::Mutex::new(Vec::new());
+    let result = global.query_transformed(request(), |name, response| {
+        seen.lock().unwrap().push(name.to_string());
+        normalize(name, &response.data)
+    });
+
+    assert_eq!(result.successes, vec![("eu/amazon_de".to_string(), 9.9), ("walmart".to_string(), 8.0)]);
+    assert_eq!(result.failures.len(), 1);
+    assert_eq!(result.failures[0].0, "eu/amazon_fr");
+    assert_eq!(seen.into_inner().unwrap(), ["eu/amazon_de", "walmart"]);
+}
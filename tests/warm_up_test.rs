@@ -0,0 +1,136 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+use serde_json::{json, Value};
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup};
+
+#[derive(Default)]
+struct Gated {
+    warm_ups: AtomicUsize,
+    warm_up_fails: AtomicBool,
+    ready: AtomicBool,
+    warm_up_delay: Duration,
+}
+
+impl Supplier for Gated {
+    fn name(&self) -> &str {
+        "gated"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!({ "supplier": "gated" })))
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        sleep(self.warm_up_delay);
+        self.warm_ups.fetch_add(1, Ordering::SeqCst);
+        if self.warm_up_fails.load(Ordering::SeqCst) {
+            return Err(SupplierError::Unauthorized);
+        }
+        self.ready.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::SeqCst)
+    }
+}
+
+fn search() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, Value::Null)
+}
+
+#[test]
+fn supplier_is_warmed_up_when_added() {
+    let supplier = Arc::new(Gated::default());
+    let mut group = BasicSupplierGroup::new("group");
+    group.add_supplier_arc(supplier.clone());
+
+    assert_eq!(supplier.warm_ups.load(Ordering::SeqCst), 1);
+    assert_eq!(group.query(search()).successes.len(), 1);
+}
+
+#[test]
+fn failed_warm_up_excludes_supplier_until_retried() {
+    let supplier = Arc::new(Gated::default());
+    supplier.warm_up_fails.store(true, Ordering::SeqCst);
+    let mut group = BasicSupplierGroup::new("group");
+    group.add_supplier_arc(supplier.clone());
+
+    assert_eq!(group.cold_suppliers(), vec!["gated".to_string()]);
+    assert!(group.query(search()).successes.is_empty());
+    assert_eq!(group.query_streamed(search()).count(), 0);
+    assert_eq!(group.warm_up().len(), 1);
+
+    supplier.warm_up_fails.store(false, Ordering::SeqCst);
+    assert!(group.warm_up().is_empty());
+    assert!(group.cold_suppliers().is_empty());
+    assert_eq!(group.query(search()).successes.len(), 1);
+}
+
+#[test]
+fn not_ready_supplier_is_skipped() {
+    let supplier = Arc::new(Gated::default());
+    let mut group = BasicSupplierGroup::new("group");
+    group.add_supplier_arc(supplier.clone());
+
+    supplier.ready.store(false, Ordering::SeqCst);
+    assert!(group.query(search()).successes.is_empty());
+    assert!(group.query_batch(vec![search()])[0].successes.is_empty());
+}
+
+#[test]
+fn background_warm_up_does_not_block_adding() {
+    let supplier = Arc::new(Gated {
+        warm_up_delay: Duration::from_millis(100),
+        ..Gated::default()
+    });
+    let mut group = BasicSupplierGroup::new("group").with_background_warm_up();
+
+    let started = Instant::now();
+    group.add_supplier_arc(supplier.clone());
+    assert!(started.elapsed() < Duration::from_millis(100));
+    assert!(group.query(search()).successes.is_empty());
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !group.cold_suppliers().is_empty() && Instant::now() < deadline {
+        sleep(Duration::from_millis(10));
+    }
+    assert_eq!(group.query(search()).successes.len(), 1);
+}
+
+/// A supplier whose warm-up panics.
+struct Crashing;
+
+impl Supplier for Crashing {
+    fn name(&self) -> &str {
+        "crashing"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::new(json!({})))
+    }
+
+    fn warm_up(&self) -> Result<(), SupplierError> {
+        panic!("warm-up crashed")
+    }
+}
+
+#[test]
+fn panicking_warm_up_counts_as_failed() {
+    let mut group = BasicSupplierGroup::new("group");
+    group.add_supplier(Crashing);
+    let mut background = BasicSupplierGroup::new("group").with_background_warm_up();
+    background.add_supplier(Crashing);
+    sleep(Duration::from_millis(50));
+
+    for group in [&group, &background] {
+        assert_eq!(group.cold_suppliers(), ["crashing"]);
+        let failures = group.warm_up();
+        assert!(matches!(&failures[0].1, SupplierError::Internal(m) if m.contains("warm-up crashed")));
+    }
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "axum")]
+
+use std::sync::Arc;
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use axum::Router;
+use serde_json::{json, Value};
+use supplier_kit::push::webhook::WebhookReceiver;
+use supplier_kit::push::PushStore;
+use tower::ServiceExt;
+
+async fn send(router: Router, request: Request<Body>) -> (StatusCode, Value) {
+    let response = router.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+fn push(supplier: &str) -> axum::http::request::Builder {
+    Request::post(format!("/push/{supplier}")).header("content-type", "application/json")
+}
+
+#[tokio::test]
+async fn accepted_pushes_reach_the_sink() {
+    let store = Arc::new(PushStore::new("/sku"));
+    let router = WebhookReceiver::new(store.clone()).build();
+
+    let body = json!([{ "sku": "A", "qty": 2 }]).to_string();
+    let request = push("partner").header("x-event-id", "evt-1").body(Body::from(body)).unwrap();
+    let (status, response) = send(router.clone(), request).await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    assert_eq!(response["accepted"], true);
+    assert_eq!(store.get("partner", "A").unwrap()["qty"], 2);
+
+    let body = json!({ "sku": "A" }).to_string();
+    let request = push("partner").header("x-push-operation", "delete").body(Body::from(body)).unwrap();
+    assert_eq!(send(router, request).await.0, StatusCode::ACCEPTED);
+    assert!(store.get("partner", "A").is_none());
+}
+
+#[tokio::test]
+async fn invalid_pushes_answer_bad_request() {
+    let store = Arc::new(PushStore::new("/sku"));
+    let router = WebhookReceiver::new(store).build();
+
+    let (status, error) = send(router.clone(), push("partner").body(Body::from("{not json")).unwrap()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error["code"], "invalid_input");
+
+    let body = json!({ "qty": 1 }).to_string();
+    let (status, _) = send(router, push("partner").body(Body::from(body)).unwrap()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn configured_tokens_are_required() {
+    let store = Arc::new(PushStore::new("/sku"));
+    let router = WebhookReceiver::new(store.clone()).with_token("partner", "s3cret").build();
+    let body = json!({ "sku": "A" }).to_string();
+
+    let anonymous = push("partner").body(Body::from(body.clone())).unwrap();
+    assert_eq!(send(router.clone(), anonymous).await.0, StatusCode::UNAUTHORIZED);
+
+    let wrong = push("partner").header("authorization", "Bearer nope").body(Body::from(body.clone())).unwrap();
+    assert_eq!(send(router.clone(), wrong).await.0, StatusCode::UNAUTHORIZED);
+
+    let unknown = push("stranger").header("authorization", "Bearer s3cret").body(Body::from(body.clone())).unwrap();
+    assert_eq!(send(router.clone(), unknown).await.0, StatusCode::UNAUTHORIZED);
+
+    let valid = push("partner").header("authorization", "Bearer s3cret").body(Body::from(body)).unwrap();
+    assert_eq!(send(router, valid).await.0, StatusCode::ACCEPTED);
+    assert!(store.get("partner", "A").is_some());
+}
@@ -0,0 +1,81 @@
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{ResponseSource, SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier_group::{BasicSupplierGroup, SupplierGroup, SupplierGroupResult};
+use supplier_kit::testing::mock::MockSupplierBuilder;
+use supplier_kit::wire::{decode, encode, WireFormat};
+
+fn formats() -> Vec<WireFormat> {
+    vec![
+        WireFormat::Json,
+        #[cfg(feature = "msgpack")]
+        WireFormat::MessagePack,
+        #[cfg(feature = "cbor")]
+        WireFormat::Cbor,
+    ]
+}
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Other("track_order".into()), json!({ "id": 7, "tags": ["a", null], "ratio": 0.5 }))
+        .with_idempotency_key("order-7")
+        .with_version(2)
+}
+
+fn group_result() -> SupplierGroupResult {
+    let mut group = BasicSupplierGroup::new("marketplaces");
+    group.add_supplier(MockSupplierBuilder::new("shop").respond_default(json!({ "items": [{ "sku": "A1", "price": -12.5 }] })).build());
+    group.add_supplier(MockSupplierBuilder::new("down").then_fail(SupplierError::Upstream("502".into())).build());
+    group.add_supplier(MockSupplierBuilder::new("late").then_fail(SupplierError::Timeout).build());
+    group.query(SupplierRequest::new(SupplierOperation::Search, json!({})))
+}
+
+#[test]
+fn requests_and_responses_round_trip() {
+    let response = SupplierResponse::new(json!({ "invoice": "INV-7", "total": u64::MAX }))
+        .with_body("application/pdf", vec![0, 159, 146, 150])
+        .with_source(ResponseSource::Cache);
+    for format in formats() {
+        let bytes = encode(&request(), format).unwrap();
+        assert_eq!(decode::<SupplierRequest>(&bytes, format).unwrap(), request(), "{format:?}");
+        let bytes = encode(&response, format).unwrap();
+        assert_eq!(decode::<SupplierResponse>(&bytes, format).unwrap(), response, "{format:?}");
+    }
+}
+
+#[test]
+fn group_results_round_trip() {
+    let result = group_result();
+    for format in formats() {
+        let decoded: SupplierGroupResult = decode(&encode(&result, format).unwrap(), format).unwrap();
+        assert_eq!(decoded.successes, result.successes);
+        assert_eq!(decoded.outcomes, result.outcomes);
+        assert_eq!(decoded.duration_us, result.duration_us);
+        let codes: Vec<_> = decoded.failures.iter().map(|(name, e)| (name.as_str(), e.code(), e.to_string())).collect();
+        assert_eq!(codes, [("down", "upstream", "upstream error: 502".to_string()), ("late", "timeout", "timeout".to_string())]);
+    }
+}
+
+#[test]
+fn corrupt_payloads_are_invalid_input() {
+    for format in formats() {
+        let bytes = encode(&request(), format).unwrap();
+        let truncated = &bytes[..bytes.len() / 2];
+        assert!(matches!(decode::<SupplierRequest>(truncated, format), Err(SupplierError::InvalidInput(_))), "{format:?}");
+        assert_eq!(WireFormat::from_content_type(format.content_type()), Some(format));
+    }
+}
+
+#[cfg(any(feature = "msgpack", feature = "cbor"))]
+#[test]
+fn binary_formats_are_smaller_than_json() {
+    let result = group_result();
+    let json = encode(&result, WireFormat::Json).unwrap().len();
+    for format in formats().into_iter().skip(1) {
+        assert!(encode(&result, format).unwrap().len() < json, "{format:?}");
+    }
+    // Bodies are stored as raw bytes; base64 alone would take 400 bytes.
+    let body = SupplierResponse::binary("image/png", vec![7; 300]);
+    for format in formats().into_iter().skip(1) {
+        assert!(encode(&body, format).unwrap().len() < 400, "{format:?}");
+    }
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "xml")]
+
+use serde_json::json;
+use supplier_kit::errors::SupplierError;
+use supplier_kit::models::{SupplierOperation, SupplierRequest, SupplierResponse};
+use supplier_kit::supplier::Supplier;
+use supplier_kit::testing::mock::MockSupplierBuilder;
+use supplier_kit::xml::{XmlNormalizer, XmlSupplier};
+
+const STOCK: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/" xmlns:inv="urn:inventory">
+  <soap:Body>
+    <inv:StockResponse warehouse="north">
+      <!-- one line per sku -->
+      <inv:line sku="A1"><inv:stock>4</inv:stock><inv:price>12.50</inv:price></inv:line>
+      <inv:line sku="007"><inv:stock>0</inv:stock><inv:discontinued>true</inv:discontinued></inv:line>
+      <inv:note><![CDATA[Prices <incl.> VAT]]> &amp; shipping &#x2713;</inv:note>
+    </inv:StockResponse>
+  </soap:Body>
+</soap:Envelope>"#;
+
+struct Legacy(&'static str, &'static str);
+
+impl Supplier for Legacy {
+    fn name(&self) -> &str {
+        "legacy"
+    }
+
+    fn query(&self, _request: SupplierRequest) -> Result<SupplierResponse, SupplierError> {
+        Ok(SupplierResponse::binary(self.1, self.0.as_bytes().to_vec()))
+    }
+}
+
+fn request() -> SupplierRequest {
+    SupplierRequest::new(SupplierOperation::Search, json!({}))
+}
+
+#[test]
+fn soap_envelopes_are_unwrapped_and_mapped() {
+    let data = XmlNormalizer::new().with_root("Envelope/Body/StockResponse").normalize(STOCK).unwrap();
+    assert_eq!(
+        data,
+        json!({
+            "@warehouse": "north",
+            "line": [
+                { "@sku": "A1", "stock": "4", "price": "12.50" },
+                { "@sku": "007", "stock": "0", "discontinued": "true" }
+            ],
+            "note": "Prices <incl.> VAT & shipping \u{2713}"
+        })
+    );
+}
+
+#[test]
+fn mapping_is_configurable() {
+    let normalizer = XmlNormalizer::new()
+        .with_namespaces(true)
+        .with_attribute_prefix("_")
+        .with_root("soap:Envelope/soap:Body/inv:StockResponse")
+        .with_type_inference(true);
+    let data = normalizer.normalize(STOCK).unwrap();
+    assert_eq!(data["_warehouse"], "north");
+    assert_eq!(data["inv:line"][0]["inv:price"], 12.5);
+    assert_eq!(data["inv:line"][1]["_sku"], "007");
+    assert_eq!(data["inv:line"][1]["inv:discontinued"], true);
+
+    let data = XmlNormalizer::new()
+        .with_array("item")
+        .with_text_key("value")
+        .normalize(r#"<order id="7"><item unit="pc">3</item><empty/></order>"#)
+        .unwrap();
+    assert_eq!(data, json!({ "order": { "@id": "7", "item": [{ "@unit": "pc", "value": "3" }], "empty": null } }));
+}
+
+#[test]
+fn malformed_documents_are_upstream_errors() {
+    let normalizer = XmlNormalizer::new();
+    for xml in ["<a><b></a>", "<a>", "", "<a/><b/>", "<a>&nbsp;</a>"] {
+        assert!(matches!(normalizer.normalize(xml), Err(SupplierError::Upstream(_))), "{xml}");
+    }
+    let missing = XmlNormalizer::new().with_root("Envelope/Header").normalize(STOCK);
+    assert!(matches!(missing, Err(SupplierError::Upstream(m)) if m.contains("Envelope/Header")));
+}
+
+#[test]
+fn suppliers_with_xml_bodies_return_json_data() {
+    let supplier = XmlSupplier::new(
+        Legacy(STOCK, "text/xml; charset=utf-8"),
+        XmlNormalizer::new().with_root("Envelope/Body/StockResponse"),
+    );
+    let response = supplier.query(request()).unwrap();
+    assert_eq!(response.data["line"][0]["@sku"], "A1");
+    assert!(response.body.is_none());
+
+    let batch = supplier.query_batch(vec![request(), request()]);
+    assert!(batch.iter().all(|result| result.as_ref().is_ok_and(|r| r.data["@warehouse"] == "north")));
+}
+
+#[test]
+fn other_responses_pass_through() {
+    let pdf = XmlSupplier::new(Legacy("%PDF", "application/pdf"), XmlNormalizer::new());
+    assert_eq!(pdf.query(request()).unwrap().content_type(), Some("application/pdf"));
+
+    let json_supplier = XmlSupplier::new(MockSupplierBuilder::new("shop").respond_default(json!([1])).build(), XmlNormalizer::new());
+    assert_eq!(json_supplier.query(request()).unwrap().data, json!([1]));
+
+    let broken = XmlSupplier::new(Legacy("<a>", "application/soap+xml"), XmlNormalizer::new());
+    assert!(matches!(broken.query(request()), Err(SupplierError::Upstream(_))));
+}